@@ -0,0 +1,600 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines decimal arithmetic kernels following SQL precision and scale rules
+//!
+//! Unlike the decimal support of [`crate::numeric`], these kernels derive the output
+//! precision and scale following the rules used by SQL Server and Spark, see
+//! [`result_type`], and round the result to the output scale, reducing the scale where
+//! the exact result would exceed the maximum precision of the decimal type.
+//!
+//! Results that do not fit in the output precision either return an error, e.g. [`add`],
+//! or saturate to the largest representable magnitude, e.g. [`add_saturating`].
+//! Division by zero is always an error.
+//!
+//! ```
+//! # use arrow_array::{cast::AsArray, types::Decimal128Type, Decimal128Array};
+//! # use arrow_arith::decimal::mul;
+//! # use arrow_schema::DataType;
+//! let a = Decimal128Array::from(vec![150, -225]) // [1.50, -2.25]
+//!     .with_precision_and_scale(5, 2)
+//!     .unwrap();
+//! let b = Decimal128Array::from(vec![3, 21]) // [0.3, 2.1]
+//!     .with_precision_and_scale(3, 1)
+//!     .unwrap();
+//!
+//! let r = mul(&a, &b).unwrap();
+//! assert_eq!(r.data_type(), &DataType::Decimal128(9, 3));
+//! assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[450, -4725]);
+//! ```
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal128Type, Decimal256Type, DecimalType};
+use arrow_array::{Array, ArrayRef, Datum, PrimitiveArray};
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, DataType};
+
+use crate::arity::try_binary;
+
+/// The minimum scale retained when reducing the scale of a result to fit
+/// within the maximum precision
+const MIN_ADJUSTED_SCALE: i32 = 6;
+
+/// A decimal arithmetic operation, see [`result_type`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecimalOp {
+    /// Addition, see [`add`]
+    Add,
+    /// Subtraction, see [`sub`]
+    Sub,
+    /// Multiplication, see [`mul`]
+    Mul,
+    /// Division, see [`div`]
+    Div,
+}
+
+impl std::fmt::Display for DecimalOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+/// Returns the output [`DataType`] of applying `op` to decimals of type `lhs` and `rhs`
+///
+/// Given inputs of precision and scale `(p1, s1)` and `(p2, s2)`, the exact result has
+///
+/// | Operation | Precision                           | Scale                  |
+/// |-----------|-------------------------------------|------------------------|
+/// | `+`, `-`  | `max(s1, s2) + max(p1-s1, p2-s2) + 1` | `max(s1, s2)`        |
+/// | `*`       | `p1 + p2 + 1`                       | `s1 + s2`              |
+/// | `/`       | `p1 - s1 + s2 + max(6, s1 + p2 + 1)` | `max(6, s1 + p2 + 1)` |
+///
+/// If the precision exceeds the maximum precision of the decimal type, it is capped at the
+/// maximum and the scale reduced to preserve the integral digits, but not below
+/// `min(scale, 6)`
+///
+/// Returns an error if `lhs` and `rhs` are not both [`DataType::Decimal128`] or
+/// both [`DataType::Decimal256`]
+pub fn result_type(op: DecimalOp, lhs: &DataType, rhs: &DataType) -> Result<DataType, ArrowError> {
+    match (lhs, rhs) {
+        (DataType::Decimal128(p1, s1), DataType::Decimal128(p2, s2)) => {
+            let (p, s) = result_precision_scale::<Decimal128Type>(op, (*p1, *s1), (*p2, *s2));
+            Ok(DataType::Decimal128(p, s))
+        }
+        (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => {
+            let (p, s) = result_precision_scale::<Decimal256Type>(op, (*p1, *s1), (*p2, *s2));
+            Ok(DataType::Decimal256(p, s))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid decimal arithmetic operation: {lhs} {op} {rhs}"
+        ))),
+    }
+}
+
+/// Perform `lhs + rhs` on decimals, returning an error if the result overflows
+/// the output precision, see [`result_type`]
+pub fn add(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Add, lhs, rhs, false)
+}
+
+/// Perform `lhs + rhs` on decimals, saturating to the largest magnitude representable
+/// by the output precision on overflow, see [`result_type`]
+pub fn add_saturating(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Add, lhs, rhs, true)
+}
+
+/// Perform `lhs - rhs` on decimals, returning an error if the result overflows
+/// the output precision, see [`result_type`]
+pub fn sub(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Sub, lhs, rhs, false)
+}
+
+/// Perform `lhs - rhs` on decimals, saturating to the largest magnitude representable
+/// by the output precision on overflow, see [`result_type`]
+pub fn sub_saturating(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Sub, lhs, rhs, true)
+}
+
+/// Perform `lhs * rhs` on decimals, returning an error if the result overflows
+/// the output precision, see [`result_type`]
+pub fn mul(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Mul, lhs, rhs, false)
+}
+
+/// Perform `lhs * rhs` on decimals, saturating to the largest magnitude representable
+/// by the output precision on overflow, see [`result_type`]
+pub fn mul_saturating(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Mul, lhs, rhs, true)
+}
+
+/// Perform `lhs / rhs` on decimals, returning an error if the result overflows
+/// the output precision or on division by zero, see [`result_type`]
+pub fn div(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Div, lhs, rhs, false)
+}
+
+/// Perform `lhs / rhs` on decimals, saturating to the largest magnitude representable
+/// by the output precision on overflow, see [`result_type`]
+///
+/// Division by zero still returns an error
+pub fn div_saturating(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    decimal_op(DecimalOp::Div, lhs, rhs, true)
+}
+
+/// A [`DecimalType`] whose values can be losslessly widened to [`i256`]
+trait WideDecimal: DecimalType {
+    fn widen(v: Self::Native) -> i256;
+
+    /// Narrows a value known to fit within [`DecimalType::MAX_PRECISION`]
+    fn narrow(v: i256) -> Self::Native;
+}
+
+impl WideDecimal for Decimal128Type {
+    fn widen(v: i128) -> i256 {
+        i256::from_i128(v)
+    }
+
+    fn narrow(v: i256) -> i128 {
+        v.as_i128()
+    }
+}
+
+impl WideDecimal for Decimal256Type {
+    fn widen(v: i256) -> i256 {
+        v
+    }
+
+    fn narrow(v: i256) -> i256 {
+        v
+    }
+}
+
+/// Computes the output precision and scale of `op`, see [`result_type`]
+fn result_precision_scale<T: DecimalType>(
+    op: DecimalOp,
+    (p1, s1): (u8, i8),
+    (p2, s2): (u8, i8),
+) -> (u8, i8) {
+    let (p1, s1, p2, s2) = (p1 as i32, s1 as i32, p2 as i32, s2 as i32);
+    let (precision, scale) = match op {
+        DecimalOp::Add | DecimalOp::Sub => {
+            let scale = s1.max(s2);
+            (scale + (p1 - s1).max(p2 - s2) + 1, scale)
+        }
+        DecimalOp::Mul => (p1 + p2 + 1, s1 + s2),
+        DecimalOp::Div => {
+            let scale = MIN_ADJUSTED_SCALE.max(s1 + p2 + 1);
+            (p1 - s1 + s2 + scale, scale)
+        }
+    };
+
+    let max_precision = T::MAX_PRECISION as i32;
+    let (precision, scale) = match precision > max_precision {
+        true => {
+            let integral = precision - scale;
+            let min_scale = scale.min(MIN_ADJUSTED_SCALE);
+            (max_precision, (max_precision - integral).max(min_scale))
+        }
+        false => (precision.max(1), scale),
+    };
+    (
+        precision as u8,
+        scale.clamp(-(T::MAX_SCALE as i32), T::MAX_SCALE as i32) as i8,
+    )
+}
+
+/// Returns `10^exp`, or `None` if this overflows an [`i256`]
+fn pow10(exp: i32) -> Option<i256> {
+    i256::from_i128(10).checked_pow(exp.try_into().ok()?)
+}
+
+/// Multiplies `v` by `10^exp`, the error contains the sign of the overflowing result
+fn scale_up(v: i256, exp: i32) -> Result<i256, bool> {
+    pow10(exp)
+        .and_then(|m| v.checked_mul(m))
+        .ok_or(v.is_negative())
+}
+
+/// Divides `v` by `10^exp` rounding half away from zero, or multiplies it by `10^-exp`
+/// if `exp` is negative, as for [`scale_up`]
+fn scale_down(v: i256, exp: i32) -> Result<i256, bool> {
+    if exp < 0 {
+        return scale_up(v, -exp);
+    }
+    Ok(match pow10(exp) {
+        Some(d) => div_round(v, d),
+        // |v| < 10^77 <= 10^exp / 2
+        None => i256::ZERO,
+    })
+}
+
+/// Divides `l` by non-zero `r` rounding half away from zero
+fn div_round(l: i256, r: i256) -> i256 {
+    let q = l.wrapping_div(r);
+    let rem = l.wrapping_rem(r).wrapping_abs();
+    let r_abs = r.wrapping_abs();
+    // rem * 2 >= |r|, without overflowing
+    match rem >= r_abs.wrapping_sub(rem) {
+        true if l.is_negative() != r.is_negative() => q.wrapping_sub(i256::ONE),
+        true => q.wrapping_add(i256::ONE),
+        false => q,
+    }
+}
+
+/// Computes `op` on `l` of scale `s1` and `r` of scale `s2`, returning the result with
+/// scale `scale`, the error contains the sign of the overflowing result
+fn apply(
+    op: DecimalOp,
+    l: i256,
+    s1: i32,
+    r: i256,
+    s2: i32,
+    scale: i32,
+) -> Result<i256, ArithmeticError> {
+    let overflow = ArithmeticError::Overflow;
+    match op {
+        DecimalOp::Add | DecimalOp::Sub => {
+            let r = match op {
+                DecimalOp::Sub => r.checked_neg().ok_or(overflow(!r.is_negative()))?,
+                _ => r,
+            };
+            let full = s1.max(s2);
+            let l = scale_up(l, full - s1).map_err(overflow)?;
+            let r = scale_up(r, full - s2).map_err(overflow)?;
+            let sum = l.checked_add(r).ok_or(overflow(l.is_negative()))?;
+            scale_down(sum, full - scale).map_err(overflow)
+        }
+        DecimalOp::Mul => {
+            let negative = l.is_negative() != r.is_negative();
+            let product = l.checked_mul(r).ok_or(overflow(negative))?;
+            scale_down(product, s1 + s2 - scale).map_err(overflow)
+        }
+        DecimalOp::Div => {
+            if r == i256::ZERO {
+                return Err(ArithmeticError::DivideByZero);
+            }
+            let negative = l.is_negative() != r.is_negative();
+            let exp = scale - s1 + s2;
+            if exp < 0 {
+                return Ok(match scale_up(r, -exp) {
+                    Ok(r) => div_round(l, r),
+                    // |r * 10^-exp| > |l|
+                    Err(_) => i256::ZERO,
+                });
+            }
+            if let Ok(l) = scale_up(l, exp) {
+                return Ok(div_round(l, r));
+            }
+
+            // Fall back to long division, computing one decimal digit at a time
+            let ten = i256::from_i128(10);
+            let mut q = l.wrapping_div(r);
+            let mut rem = l.wrapping_rem(r);
+            for _ in 0..exp {
+                rem = rem.checked_mul(ten).ok_or(overflow(negative))?;
+                q = q.checked_mul(ten).ok_or(overflow(negative))?;
+                q = q.wrapping_add(rem.wrapping_div(r));
+                rem = rem.wrapping_rem(r);
+            }
+            let round = rem.wrapping_abs() >= r.wrapping_abs().wrapping_sub(rem.wrapping_abs());
+            Ok(match (round, negative) {
+                (true, true) => q.wrapping_sub(i256::ONE),
+                (true, false) => q.wrapping_add(i256::ONE),
+                (false, _) => q,
+            })
+        }
+    }
+}
+
+/// An error computing a decimal operation on a single pair of values
+enum ArithmeticError {
+    /// The result overflowed, containing whether the result is negative
+    Overflow(bool),
+    DivideByZero,
+}
+
+/// Perform an arithmetic operation on decimals, see [`result_type`]
+fn decimal_op(
+    op: DecimalOp,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    saturating: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    match (l.data_type(), r.data_type()) {
+        (DataType::Decimal128(_, _), DataType::Decimal128(_, _)) => {
+            decimal_op_typed::<Decimal128Type>(op, l, l_s, r, r_s, saturating)
+        }
+        (DataType::Decimal256(_, _), DataType::Decimal256(_, _)) => {
+            decimal_op_typed::<Decimal256Type>(op, l, l_s, r, r_s, saturating)
+        }
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid decimal arithmetic operation: {l_t} {op} {r_t}"
+        ))),
+    }
+}
+
+fn decimal_op_typed<T: WideDecimal>(
+    op: DecimalOp,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+    saturating: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let l = l.as_primitive::<T>();
+    let r = r.as_primitive::<T>();
+    let (p1, s1) = (l.precision(), l.scale());
+    let (p2, s2) = (r.precision(), r.scale());
+    let (precision, scale) = result_precision_scale::<T>(op, (p1, s1), (p2, s2));
+
+    // The largest magnitude representable with the output precision
+    let max = pow10(precision as i32).unwrap().wrapping_sub(i256::ONE);
+
+    let f = |a: T::Native, b: T::Native| -> Result<T::Native, ArrowError> {
+        let (wa, wb) = (T::widen(a), T::widen(b));
+        let result = match apply(op, wa, s1 as i32, wb, s2 as i32, scale as i32) {
+            Ok(v) if v > max => Err(false),
+            Ok(v) if v < max.wrapping_neg() => Err(true),
+            Ok(v) => Ok(v),
+            Err(ArithmeticError::Overflow(negative)) => Err(negative),
+            Err(ArithmeticError::DivideByZero) => return Err(ArrowError::DivideByZero),
+        };
+        match result {
+            Ok(v) => Ok(T::narrow(v)),
+            Err(true) if saturating => Ok(T::narrow(max.wrapping_neg())),
+            Err(false) if saturating => Ok(T::narrow(max)),
+            Err(_) => Err(ArrowError::ArithmeticOverflow(format!(
+                "Overflow happened on: {} {op} {}, the result does not fit in {}",
+                T::format_decimal(a, p1, s1),
+                T::format_decimal(b, p2, s2),
+                T::TYPE_CONSTRUCTOR(precision, scale)
+            ))),
+        }
+    };
+
+    let array: PrimitiveArray<T> = match (l_s, r_s) {
+        (true, true) | (false, false) => try_binary(l, r, f)?,
+        (true, false) => match (l.null_count() == 0).then(|| l.value(0)) {
+            None => PrimitiveArray::new_null(r.len()),
+            Some(a) => r.try_unary(|b| f(a, b))?,
+        },
+        (false, true) => match (r.null_count() == 0).then(|| r.value(0)) {
+            None => PrimitiveArray::new_null(l.len()),
+            Some(b) => l.try_unary(|a| f(a, b))?,
+        },
+    };
+    Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Decimal128Type;
+    use arrow_array::{Decimal128Array, Decimal256Array};
+
+    fn decimal128(values: Vec<i128>, p: u8, s: i8) -> Decimal128Array {
+        Decimal128Array::from(values)
+            .with_precision_and_scale(p, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_result_type() {
+        let cases = [
+            (DecimalOp::Add, (5, 2), (3, 1), (6, 2)),
+            (DecimalOp::Sub, (10, 0), (10, 5), (16, 5)),
+            (DecimalOp::Mul, (5, 2), (3, 1), (9, 3)),
+            (DecimalOp::Div, (5, 2), (3, 1), (10, 6)),
+            (DecimalOp::Add, (38, 10), (38, 20), (38, 9)),
+            (DecimalOp::Mul, (38, 10), (38, 10), (38, 6)),
+            (DecimalOp::Mul, (20, 2), (10, 2), (31, 4)),
+            (DecimalOp::Div, (38, 10), (38, 10), (38, 6)),
+        ];
+        for (op, (p1, s1), (p2, s2), (p, s)) in cases {
+            let l = DataType::Decimal128(p1, s1);
+            let r = DataType::Decimal128(p2, s2);
+            let actual = result_type(op, &l, &r).unwrap();
+            assert_eq!(actual, DataType::Decimal128(p, s), "{l} {op} {r}");
+        }
+
+        let l = DataType::Decimal256(76, 10);
+        let actual = result_type(DecimalOp::Mul, &l, &l).unwrap();
+        assert_eq!(actual, DataType::Decimal256(76, 6));
+
+        let err = result_type(DecimalOp::Add, &l, &DataType::Decimal128(5, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid decimal arithmetic operation: Decimal256(76, 10) + Decimal128(5, 2)"
+        );
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = decimal128(vec![150, -225, 99999], 5, 2);
+        let b = decimal128(vec![3, 21, 1], 3, 1);
+
+        let r = add(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(6, 2));
+        assert_eq!(r.values(), &[180, -15, 100009]);
+
+        let r = sub(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.values(), &[120, -435, 99989]);
+
+        let scalar = Decimal128Array::new_scalar(5)
+            .into_inner()
+            .with_precision_and_scale(1, 0)
+            .unwrap();
+        let r = sub(&arrow_array::Scalar::new(scalar), &a).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(6, 2));
+        assert_eq!(r.values(), &[350, 725, -99499]);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = decimal128(vec![150, -225, 0], 5, 2);
+        let b = decimal128(vec![3, 21, 7], 3, 1);
+
+        let r = mul(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(9, 3));
+        assert_eq!(r.values(), &[450, -4725, 0]);
+
+        // 1.50 / 0.3 = 5, -2.25 / 2.1 = -1.0714285714..., 0 / 0.7 = 0
+        let r = div(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(10, 6));
+        assert_eq!(r.values(), &[5_000000, -1_071429, 0]);
+
+        let zero = decimal128(vec![1, 0, 1], 3, 1);
+        let err = div(&a, &zero).unwrap_err();
+        assert_eq!(err.to_string(), "Divide by zero error");
+        let err = div_saturating(&a, &zero).unwrap_err();
+        assert_eq!(err.to_string(), "Divide by zero error");
+    }
+
+    #[test]
+    fn test_clamped_scale() {
+        // The scale of -60 is clamped to -38, and so the product is scaled up by 10^22
+        let a = decimal128(vec![1, 0], 5, -30);
+        let b = decimal128(vec![2, 3], 5, -30);
+        let r = result_type(DecimalOp::Mul, a.data_type(), b.data_type()).unwrap();
+        assert_eq!(r, DataType::Decimal128(11, -38));
+
+        let err = mul(&a, &b).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+        let r = mul_saturating(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.values(), &[99999999999, 0]);
+
+        let b = decimal128(vec![0, 3], 5, -30);
+        let r = mul(&a, &b).unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[0, 0]);
+    }
+
+    #[test]
+    fn test_reduced_scale() {
+        // 1.5 * 1.5 = 2.25 with the scale reduced from 20 to 6
+        let a = decimal128(vec![15 * 10_i128.pow(9)], 38, 10);
+        let r = mul(&a, &a).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(38, 6));
+        assert_eq!(r.values(), &[2_250000]);
+
+        // 2 / 3 = 0.666667 rounded to the reduced scale of 6
+        let a = decimal128(vec![2 * 10_i128.pow(10)], 38, 10);
+        let b = decimal128(vec![3 * 10_i128.pow(10)], 38, 10);
+        let r = div(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal128(38, 6));
+        assert_eq!(r.values(), &[666667]);
+
+        // Large dividends require long division
+        let a = Decimal256Array::from(vec![pow10(75).unwrap()])
+            .with_precision_and_scale(76, 70)
+            .unwrap();
+        let b = Decimal256Array::from(vec![i256::from_i128(3).wrapping_mul(pow10(75).unwrap())])
+            .with_precision_and_scale(76, 75)
+            .unwrap();
+        let r = div(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal256Type>();
+        assert_eq!(r.data_type(), &DataType::Decimal256(76, 6));
+        assert_eq!(r.values(), &[i256::from_i128(33333_333333)]);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = 10_i128.pow(38) - 1;
+        let a = decimal128(vec![max, -max, 1], 38, 0);
+        let b = decimal128(vec![1, -1, 1], 38, 0);
+
+        let err = add(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 99999999999999999999999999999999999999 + 1, the result does not fit in Decimal128(38, 0)"
+        );
+
+        let r = add_saturating(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.values(), &[max, -max, 2]);
+
+        let r = mul_saturating(&a, &a).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.values(), &[max, max, 1]);
+
+        let r = sub_saturating(&b, &a).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.values(), &[1 - max, max - 1, 0]);
+
+        let c = Decimal256Array::from(vec![i256::from_i128(2)])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let big = Decimal256Array::from(vec![i256::MAX.wrapping_div(i256::from_i128(2))])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        assert!(mul(&big, &c).is_err());
+        let r = mul_saturating(&big, &c).unwrap();
+        let max = pow10(76).unwrap().wrapping_sub(i256::ONE);
+        assert_eq!(r.as_primitive::<Decimal256Type>().values(), &[max]);
+    }
+
+    #[test]
+    fn test_nulls() {
+        let a = Decimal128Array::from(vec![Some(1), None, Some(3)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let b = Decimal128Array::from(vec![None, Some(0), Some(1)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let r = div(&a, &b).unwrap();
+        let r = r.as_primitive::<Decimal128Type>();
+        assert_eq!(r.null_count(), 2);
+        assert_eq!(r.value(2), 3_00000000);
+    }
+}
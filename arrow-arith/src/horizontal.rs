@@ -0,0 +1,353 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines row-wise aggregations across multiple Arrow arrays
+//!
+//! Where the kernels in [`crate::aggregate`] reduce a single array to a scalar, the kernels
+//! in this module combine the values at the same index of several arrays of the same type
+//! and length, for example to implement the SQL `GREATEST`, `LEAST` and `COALESCE` functions.
+//!
+//! Null values are ignored, with an output row only null if the row is null in every input.
+//! The columns of a [`StructArray`](arrow_array::StructArray) can be aggregated by passing
+//! [`StructArray::columns`](arrow_array::StructArray::columns).
+//!
+//! ```
+//! # use arrow_array::{cast::AsArray, types::Int32Type, Array, Int32Array};
+//! # use arrow_arith::horizontal::greatest;
+//! let a = Int32Array::from(vec![Some(1), None, Some(5), None]);
+//! let b = Int32Array::from(vec![Some(4), Some(2), Some(3), None]);
+//!
+//! let r = greatest(&[&a, &b]).unwrap();
+//! let r = r.as_primitive::<Int32Type>();
+//! assert_eq!(r, &Int32Array::from(vec![Some(4), Some(2), Some(5), None]));
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_data::transform::MutableArrayData;
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns the largest non-null value of each row across `arrays`
+///
+/// For floating point arrays NaN values are considered to be greater than any other value
+pub fn greatest(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let data_type = check_arrays(arrays)?;
+    macro_rules! helper {
+        ($t:ty) => {
+            fold_primitive::<$t, _>(arrays, |a, b| Ok(if a.is_lt(b) { b } else { a }))
+        };
+    }
+    downcast_primitive! {
+        data_type => (helper),
+        d => Err(unsupported("greatest", d)),
+    }
+}
+
+/// Returns the smallest non-null value of each row across `arrays`
+///
+/// For floating point arrays NaN values are considered to be greater than any other value
+pub fn least(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let data_type = check_arrays(arrays)?;
+    macro_rules! helper {
+        ($t:ty) => {
+            fold_primitive::<$t, _>(arrays, |a, b| Ok(if a.is_gt(b) { b } else { a }))
+        };
+    }
+    downcast_primitive! {
+        data_type => (helper),
+        d => Err(unsupported("least", d)),
+    }
+}
+
+/// Returns the sum of the non-null values of each row across `arrays`
+///
+/// This doesn't detect overflow, with the result wrapping around. For an overflow-checking
+/// variant, use [`sum_checked`] instead.
+pub fn sum(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let data_type = check_arrays(arrays)?;
+    macro_rules! helper {
+        ($t:ty) => {
+            fold_primitive::<$t, _>(arrays, |a, b| Ok(a.add_wrapping(b)))
+        };
+    }
+    downcast_primitive! {
+        data_type => (helper),
+        d => Err(unsupported("sum", d)),
+    }
+}
+
+/// Returns the sum of the non-null values of each row across `arrays`
+///
+/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
+/// use [`sum`] instead.
+pub fn sum_checked(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let data_type = check_arrays(arrays)?;
+    macro_rules! helper {
+        ($t:ty) => {
+            fold_primitive::<$t, _>(arrays, |a, b| a.add_checked(b))
+        };
+    }
+    downcast_primitive! {
+        data_type => (helper),
+        d => Err(unsupported("sum_checked", d)),
+    }
+}
+
+/// Returns the first non-null value of each row across `arrays`
+///
+/// Unlike the other kernels in this module, this supports arrays of any [`DataType`]
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let data_type = check_arrays(arrays)?;
+    macro_rules! helper {
+        ($t:ty) => {
+            fold_primitive::<$t, _>(arrays, |a, _| Ok(a))
+        };
+    }
+    downcast_primitive! {
+        data_type => (helper),
+        _ => coalesce_fallback(arrays),
+    }
+}
+
+/// Verifies `arrays` is non-empty and contains arrays of the same type and length,
+/// returning the common [`DataType`]
+fn check_arrays<'a>(arrays: &[&'a dyn Array]) -> Result<&'a DataType, ArrowError> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Expected at least one array".to_string())
+    })?;
+    for array in &arrays[1..] {
+        if array.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Arrays must have the same data type, got {} and {}",
+                first.data_type(),
+                array.data_type()
+            )));
+        }
+        if array.len() != first.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Arrays must have the same length, got {} and {}",
+                first.len(),
+                array.len()
+            )));
+        }
+    }
+    Ok(first.data_type())
+}
+
+fn unsupported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::NotYetImplemented(format!("horizontal {name} not supported for {data_type}"))
+}
+
+/// Combines the non-null values of each row of `arrays` with `f`, a row is only
+/// null if it is null in all of `arrays`
+fn fold_primitive<T, F>(arrays: &[&dyn Array], f: F) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    let first = arrays[0].as_primitive::<T>();
+    let mut values = first.values().to_vec();
+    let mut nulls = first.nulls().cloned();
+
+    for array in &arrays[1..] {
+        let array = array.as_primitive::<T>();
+        match (&nulls, array.nulls()) {
+            (None, None) => {
+                for (a, b) in values.iter_mut().zip(array.values()) {
+                    *a = f(*a, *b)?;
+                }
+            }
+            _ => {
+                for (idx, (a, b)) in values.iter_mut().zip(array.values()).enumerate() {
+                    let a_valid = nulls.as_ref().map(|n| n.is_valid(idx)).unwrap_or(true);
+                    *a = match (a_valid, array.is_valid(idx)) {
+                        (true, true) => f(*a, *b)?,
+                        (false, true) => *b,
+                        (_, false) => *a,
+                    };
+                }
+            }
+        }
+        nulls = union_valid(nulls.as_ref(), array.nulls());
+    }
+
+    let array = PrimitiveArray::<T>::new(ScalarBuffer::from(values), nulls)
+        .with_data_type(first.data_type().clone());
+    Ok(Arc::new(array))
+}
+
+/// Returns a [`NullBuffer`] that is valid where either `a` or `b` is valid
+fn union_valid(a: Option<&NullBuffer>, b: Option<&NullBuffer>) -> Option<NullBuffer> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(NullBuffer::new(a.inner() | b.inner())),
+        _ => None,
+    }
+}
+
+/// Coalesces arrays of non-primitive types by copying runs of rows from the
+/// first array that is valid for each row
+fn coalesce_fallback(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let len = arrays[0].len();
+    let data: Vec<_> = arrays.iter().map(|a| a.to_data()).collect();
+    let mut mutable = MutableArrayData::new(data.iter().collect(), true, len);
+
+    // The index of the first valid array for each row, or None if all are null
+    let source = |idx: usize| arrays.iter().position(|a| a.is_valid(idx));
+
+    let mut start = 0;
+    while start < len {
+        let current = source(start);
+        let end = (start + 1..len)
+            .find(|idx| source(*idx) != current)
+            .unwrap_or(len);
+        match current {
+            Some(array) => mutable.extend(array, start, end),
+            None => mutable.extend_nulls(end - start),
+        }
+        start = end;
+    }
+    Ok(make_array(mutable.freeze()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_greatest_least() {
+        let a = Int32Array::from(vec![Some(1), None, Some(5), None, Some(-3)]);
+        let b = Int32Array::from(vec![Some(4), Some(2), Some(3), None, None]);
+        let c = Int32Array::from(vec![2, 8, 1, 0, -4]);
+
+        let r = greatest(&[&a, &b]).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(2), Some(5), None, Some(-3)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = least(&[&a, &b]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(2), Some(3), None, Some(-3)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = greatest(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![4, 8, 5, 0, -3]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = least(&[&a]).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>(), &a);
+    }
+
+    #[test]
+    fn test_float_nan() {
+        let a = Float64Array::from(vec![1.0, f64::NAN, -0.0]);
+        let b = Float64Array::from(vec![f64::NAN, 2.0, 0.0]);
+
+        let r = greatest(&[&a, &b]).unwrap();
+        let r = r.as_primitive::<arrow_array::types::Float64Type>();
+        assert!(r.value(0).is_nan());
+        assert!(r.value(1).is_nan());
+        assert_eq!(r.value(2).to_bits(), 0.0_f64.to_bits());
+
+        let r = least(&[&a, &b]).unwrap();
+        let r = r.as_primitive::<arrow_array::types::Float64Type>();
+        assert_eq!(r.values(), &[1.0, 2.0, -0.0]);
+    }
+
+    #[test]
+    fn test_sum() {
+        let a = Int32Array::from(vec![Some(1), None, Some(i32::MAX), None]);
+        let b = Int32Array::from(vec![Some(4), Some(2), Some(1), None]);
+
+        let r = sum(&[&a, &b]).unwrap();
+        let expected = Int32Array::from(vec![Some(5), Some(2), Some(i32::MIN), None]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let err = sum_checked(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 2147483647 + 1"
+        );
+
+        let a = a.slice(0, 2);
+        let b = b.slice(0, 2);
+        let r = sum_checked(&[&a, &b]).unwrap();
+        let expected = Int32Array::from(vec![Some(5), Some(2)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let a = Int32Array::from(vec![Some(1), None, None, None]);
+        let b = Int32Array::from(vec![Some(4), None, Some(3), None]);
+        let c = Int32Array::from(vec![None, Some(2), Some(5), None]);
+
+        let r = coalesce(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(2), Some(3), None]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let a = StringArray::from(vec![Some("a"), None, None, None, Some("e")]);
+        let b = StringArray::from(vec![Some("x"), Some("b"), Some("c"), None, None]);
+        let r = coalesce(&[&a, &b]).unwrap();
+        let expected = StringArray::from(vec![Some("a"), Some("b"), Some("c"), None, Some("e")]);
+        assert_eq!(r.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_struct_columns() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 7]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![3, 2]));
+        let s = StructArray::try_from(vec![("a", a), ("b", b)]).unwrap();
+
+        let columns: Vec<_> = s.columns().iter().map(|c| c.as_ref()).collect();
+        let r = greatest(&columns).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>().values(), &[3, 7]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int64Array::from(vec![1, 2]);
+        let c = Int32Array::from(vec![1]);
+        let s = StringArray::from(vec!["a"]);
+
+        let err = greatest(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected at least one array"
+        );
+
+        let err = least(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Arrays must have the same data type, got Int32 and Int64"
+        );
+
+        let err = sum(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Arrays must have the same length, got 2 and 1"
+        );
+
+        let err = sum(&[&s]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: horizontal sum not supported for Utf8"
+        );
+    }
+}
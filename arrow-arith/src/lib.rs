@@ -24,5 +24,7 @@ pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod decimal;
+pub mod horizontal;
 pub mod numeric;
 pub mod temporal;
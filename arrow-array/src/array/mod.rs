@@ -336,6 +336,16 @@ pub trait Array: std::fmt::Debug + Send + Sync {
     /// This value will always be greater than returned by `get_buffer_memory_size()` and
     /// includes the overhead of the data structures that contain the pointers to the various buffers.
     fn get_array_memory_size(&self) -> usize;
+
+    /// Returns the number of bytes of memory retained by this array's buffers.
+    ///
+    /// Unlike [`Array::get_buffer_memory_size`], buffers that share an underlying
+    /// allocation, such as slices of the same buffer referenced by multiple children,
+    /// are only counted once. To account for memory shared across multiple arrays,
+    /// see [`MemoryTracker`](arrow_data::MemoryTracker).
+    fn get_retained_memory_size(&self) -> usize {
+        self.to_data().get_retained_memory_size()
+    }
 }
 
 /// A reference-counted reference to a generic `Array`
@@ -25,7 +25,7 @@ use crate::timezone::Tz;
 use crate::trusted_len::trusted_len_unzip;
 use crate::types::*;
 use crate::{Array, ArrayAccessor, ArrayRef, Scalar};
-use arrow_buffer::{i256, ArrowNativeType, Buffer, NullBuffer, ScalarBuffer};
+use arrow_buffer::{i256, ArrowNativeType, BooleanBuffer, Buffer, NullBuffer, ScalarBuffer};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType};
@@ -604,6 +604,69 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
         })
     }
 
+    /// Create a new [`PrimitiveArray`] from a `Vec` of values and a `Vec<bool>` of validity,
+    /// where `false` marks the corresponding value as null
+    ///
+    /// The values are used without copying, with the validity packed into a bitmap that is
+    /// omitted if all values are valid. See [`Self::from_vec_with_validity_bitmap`] to
+    /// also avoid copying the validity.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int32Array};
+    /// let array = Int32Array::from_vec_with_nulls(vec![1, 0, 3], vec![true, false, true]).unwrap();
+    /// assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3)]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if `values.len() != validity.len()`
+    pub fn from_vec_with_nulls(
+        values: Vec<T::Native>,
+        validity: Vec<bool>,
+    ) -> Result<Self, ArrowError> {
+        if values.len() != validity.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect length of validity for PrimitiveArray, expected {} got {}",
+                values.len(),
+                validity.len()
+            )));
+        }
+        let nulls = NullBuffer::from(validity);
+        let nulls = (nulls.null_count() != 0).then_some(nulls);
+        Self::try_new(values.into(), nulls)
+    }
+
+    /// Create a new [`PrimitiveArray`] from a `Vec` of values and a packed validity bitmap,
+    /// where an unset bit marks the corresponding value as null
+    ///
+    /// Bits are in LSB order, as in the Arrow specification. Neither the values nor the
+    /// bitmap are copied, with the bitmap omitted if all values are valid.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int32Array};
+    /// let array = Int32Array::from_vec_with_validity_bitmap(vec![1, 0, 3], vec![0b101]).unwrap();
+    /// assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3)]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if `bitmap` has fewer than `values.len()` bits
+    pub fn from_vec_with_validity_bitmap(
+        values: Vec<T::Native>,
+        bitmap: Vec<u8>,
+    ) -> Result<Self, ArrowError> {
+        let len = values.len();
+        if bitmap.len() < arrow_buffer::bit_util::ceil(len, 8) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Validity bitmap of {} bytes is too short for PrimitiveArray of length {len}",
+                bitmap.len()
+            )));
+        }
+        let nulls = NullBuffer::new(BooleanBuffer::new(Buffer::from_vec(bitmap), 0, len));
+        let nulls = (nulls.null_count() != 0).then_some(nulls);
+        Self::try_new(values.into(), nulls)
+    }
+
     /// Create a new [`Scalar`] from `value`
     pub fn new_scalar(value: T::Native) -> Scalar<Self> {
         Scalar::new(Self {
@@ -2634,6 +2697,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_vec_with_nulls() {
+        let values = vec![1, 2, 3, 4];
+        let ptr = values.as_ptr();
+        let array =
+            Int32Array::from_vec_with_nulls(values, vec![true, false, true, false]).unwrap();
+        assert_eq!(array.values().as_ptr(), ptr);
+        assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3), None]));
+
+        let array = Int32Array::from_vec_with_nulls(vec![1, 2], vec![true, true]).unwrap();
+        assert!(array.nulls().is_none());
+
+        let err = Int32Array::from_vec_with_nulls(vec![1, 2], vec![true]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect length of validity for PrimitiveArray, expected 2 got 1"
+        );
+
+        let bitmap = vec![0b1111_0110, 0b1];
+        let bitmap_ptr = bitmap.as_ptr();
+        let array = Int64Array::from_vec_with_validity_bitmap((0..9).collect(), bitmap).unwrap();
+        assert_eq!(array.nulls().unwrap().buffer().as_ptr(), bitmap_ptr);
+        assert_eq!(array.null_count(), 2);
+        assert!(array.is_null(0) && array.is_null(3));
+        assert_eq!(array.value(8), 8);
+
+        let array = Int64Array::from_vec_with_validity_bitmap(vec![1, 2], vec![0b11]).unwrap();
+        assert!(array.nulls().is_none());
+
+        let err = Int64Array::from_vec_with_validity_bitmap((0..9).collect(), vec![0]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Validity bitmap of 1 bytes is too short for PrimitiveArray of length 9"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "PrimitiveArray expected data type Int32 got Date32")]
     fn test_with_data_type() {
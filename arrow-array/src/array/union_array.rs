@@ -1573,6 +1573,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nested_children() {
+        use crate::builder::{Int32Builder, ListBuilder, StringBuilder, StructBuilder};
+        use arrow_schema::DataType;
+
+        fn create_union(mut builder: UnionBuilder) -> UnionArray {
+            let fields = vec![Field::new("x", DataType::Int32, true)];
+            builder.add_child("s", StringBuilder::new()).unwrap();
+            builder
+                .add_child("l", ListBuilder::new(Int32Builder::new()))
+                .unwrap();
+            builder
+                .add_child("st", StructBuilder::from_fields(fields, 0))
+                .unwrap();
+
+            builder
+                .append_with("s", |b: &mut StringBuilder| b.append_value("foo"))
+                .unwrap();
+            builder.append::<Int32Type>("i", 1).unwrap();
+            builder
+                .append_with("l", |b: &mut ListBuilder<Int32Builder>| {
+                    b.append_value([Some(1), None, Some(3)])
+                })
+                .unwrap();
+            builder
+                .append_with("st", |b: &mut StructBuilder| {
+                    b.field_builder::<Int32Builder>(0).unwrap().append_value(5);
+                    b.append(true);
+                })
+                .unwrap();
+            builder
+                .append_with("s", |b: &mut StringBuilder| b.append_null())
+                .unwrap();
+            builder
+                .append_with("s", |b: &mut StringBuilder| b.append_value("bar"))
+                .unwrap();
+            builder.build().unwrap()
+        }
+
+        for (union, dense) in [
+            (create_union(UnionBuilder::new_dense()), true),
+            (create_union(UnionBuilder::new_sparse()), false),
+        ] {
+            assert_eq!(union.len(), 6);
+            assert_eq!(union.is_dense(), dense);
+            assert_eq!(union.type_ids(), &[0, 3, 1, 2, 0, 0]);
+            let child_len = |type_id| union.child(type_id).len();
+            match dense {
+                true => assert_eq!(
+                    [child_len(0), child_len(1), child_len(2), child_len(3)],
+                    [3, 1, 1, 1]
+                ),
+                false => assert!((0..4).all(|t| child_len(t) == 6)),
+            }
+
+            assert_eq!(union.value(0).as_string::<i32>().value(0), "foo");
+            assert_eq!(union.value(1).as_primitive::<Int32Type>().value(0), 1);
+            let list = union.value(2);
+            let list = list.as_list::<i32>().value(0);
+            assert_eq!(
+                list.as_primitive::<Int32Type>(),
+                &Int32Array::from(vec![Some(1), None, Some(3)])
+            );
+            let s = union.value(3);
+            let s = s.as_struct().column(0).as_primitive::<Int32Type>().value(0);
+            assert_eq!(s, 5);
+            assert!(union.value(4).is_null(0));
+            assert_eq!(union.value(5).as_string::<i32>().value(0), "bar");
+        }
+    }
+
+    #[test]
+    fn test_nested_children_errors() {
+        use crate::builder::StringBuilder;
+
+        let mut builder = UnionBuilder::new_dense();
+        builder.add_child("s", StringBuilder::new()).unwrap();
+        builder.append::<Int32Type>("i", 1).unwrap();
+
+        let err = builder.add_child("s", StringBuilder::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Union child \"s\" already exists"
+        );
+
+        let mut non_empty = StringBuilder::new();
+        non_empty.append_value("a");
+        let err = builder.add_child("t", non_empty).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Builder for union child \"t\" must be empty, found 1 slots"
+        );
+
+        let err = builder
+            .append_with("i", |b: &mut StringBuilder| b.append_value("a"))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Attempt to write col \"i\""),
+            "{err}"
+        );
+
+        let err = builder.append::<Int32Type>("s", 1).unwrap_err();
+        assert!(
+            err.to_string().contains("Attempt to write col \"s\""),
+            "{err}"
+        );
+
+        let err = builder
+            .append_with("s", |_: &mut StringBuilder| {})
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected exactly one value to be appended to union child \"s\", got 0"
+        );
+
+        let err = builder
+            .append_with("x", |b: &mut StringBuilder| b.append_value("a"))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Union child \"x\" must be registered with add_child"
+        );
+
+        builder
+            .append_with("s", |b: &mut StringBuilder| b.append_value("a"))
+            .unwrap();
+        let union = builder.build().unwrap();
+        assert_eq!(union.type_ids(), &[1, 0]);
+        assert_eq!(union.offsets().unwrap(), &[0, 0]);
+    }
+
     #[test]
     fn slice_union_array() {
         // [1, null, 3.0, null, 4]
@@ -61,6 +61,7 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     field_names: MapFieldNames,
     key_builder: K,
     value_builder: V,
+    key_field: Option<FieldRef>,
     value_field: Option<FieldRef>,
 }
 
@@ -107,10 +108,35 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             field_names: field_names.unwrap_or_default(),
             key_builder,
             value_builder,
+            key_field: None,
             value_field: None,
         }
     }
 
+    /// Override the key field created by [`MapBuilder::new`]
+    ///
+    /// By default a non-nullable field is created with the name `keys`. This can be
+    /// used to preserve the metadata of the key field, for example when the keys are
+    /// dictionary encoded using a [`StringDictionaryBuilder`](crate::builder::StringDictionaryBuilder)
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the
+    /// field's data type does not match that of `K`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` is nullable, as map keys cannot be null
+    pub fn with_keys_field(self, field: impl Into<FieldRef>) -> Self {
+        let field = field.into();
+        assert!(
+            !field.is_nullable(),
+            "Keys field must not be nullable: {field:?}"
+        );
+        Self {
+            key_field: Some(field),
+            ..self
+        }
+    }
+
     /// Override the field passed to [`MapBuilder::new`]
     ///
     /// By default a nullable field is created with the name `values`
@@ -194,11 +220,14 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             keys_arr.null_count()
         );
 
-        let keys_field = Arc::new(Field::new(
-            self.field_names.key.as_str(),
-            keys_arr.data_type().clone(),
-            false, // always non-nullable
-        ));
+        let keys_field = match &self.key_field {
+            Some(f) => f.clone(),
+            None => Arc::new(Field::new(
+                self.field_names.key.as_str(),
+                keys_arr.data_type().clone(),
+                false, // always non-nullable
+            )),
+        };
         let values_field = match &self.value_field {
             Some(f) => f.clone(),
             None => Arc::new(Field::new(
@@ -262,8 +291,11 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 
 #[cfg(test)]
 mod tests {
-    use crate::builder::{make_builder, Int32Builder, StringBuilder};
+    use crate::builder::{make_builder, Int32Builder, StringBuilder, StringDictionaryBuilder};
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
     use crate::{Int32Array, StringArray};
+    use std::collections::HashMap;
 
     use super::*;
 
@@ -377,4 +409,87 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    #[should_panic(expected = "Keys field must not be nullable")]
+    fn test_map_builder_with_nullable_keys_field_panics() {
+        let keys_field = Field::new_dictionary("keys", DataType::Int32, DataType::Utf8, true);
+        MapBuilder::new(
+            None,
+            StringDictionaryBuilder::<Int32Type>::new(),
+            Int32Builder::new(),
+        )
+        .with_keys_field(keys_field);
+    }
+
+    #[test]
+    fn test_dictionary_keys() {
+        let keys_field = Arc::new(
+            Field::new_dictionary("keys", DataType::Int32, DataType::Utf8, false)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+        );
+        let mut builder = MapBuilder::new(
+            None,
+            StringDictionaryBuilder::<Int32Type>::new(),
+            Int32Builder::new(),
+        )
+        .with_keys_field(keys_field.clone());
+
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("a");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+
+        let map = builder.finish();
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 3]);
+        assert_eq!(map.entries().fields()[0], keys_field);
+
+        let keys = map.keys().as_dictionary::<Int32Type>();
+        assert_eq!(keys.keys().values(), &[0, 1, 0]);
+        assert_eq!(
+            keys.values().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b"])
+        );
+
+        // Construct the same map from its data type
+        let map_field = Field::new_map(
+            "map",
+            "entries",
+            keys_field,
+            Field::new("values", DataType::Int32, true),
+            false,
+            false,
+        );
+        let data_type = map_field.data_type().clone();
+        let mut builder = make_builder(&data_type, 2);
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>()
+            .unwrap();
+        builder
+            .keys()
+            .as_any_mut()
+            .downcast_mut::<StringDictionaryBuilder<Int32Type>>()
+            .unwrap()
+            .append_value("c");
+        builder
+            .values()
+            .as_any_mut()
+            .downcast_mut::<Int32Builder>()
+            .unwrap()
+            .append_value(4);
+        builder.append(true).unwrap();
+
+        let map = builder.finish();
+        assert_eq!(map.data_type(), &data_type);
+        assert_eq!(
+            map.keys().as_dictionary::<Int32Type>().keys().values(),
+            &[0]
+        );
+    }
 }
@@ -289,6 +289,8 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                 };
                 let key_builder = make_builder(fields[0].data_type(), capacity);
                 let value_builder = make_builder(fields[1].data_type(), capacity);
+                // Map keys cannot be null, regardless of the nullability of the keys field
+                let key_field = fields[0].as_ref().clone().with_nullable(false);
                 Box::new(
                     MapBuilder::with_capacity(
                         Some(map_field_names),
@@ -296,6 +298,7 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                         value_builder,
                         capacity,
                     )
+                    .with_keys_field(key_field)
                     .with_values_field(fields[1].clone()),
                 )
             }
@@ -765,6 +768,35 @@ mod tests {
         assert_eq!(array.column(0).len(), 1);
     }
 
+    #[test]
+    fn test_make_builder_nullable_map_keys() {
+        let entries = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, true),
+            Field::new("values", DataType::Int32, true),
+        ]);
+        let map_type = DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+            false,
+        );
+        let mut builder = make_builder(&map_type, 1);
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>()
+            .unwrap();
+        builder.append(false).unwrap();
+
+        let array = builder.finish();
+        let DataType::Map(entries, _) = array.data_type() else {
+            unreachable!()
+        };
+        let DataType::Struct(fields) = entries.data_type() else {
+            unreachable!()
+        };
+        assert_eq!(fields[0].name(), "keys");
+        assert!(!fields[0].is_nullable());
+        assert!(fields[1].is_nullable());
+    }
+
     #[test]
     #[should_panic(
         expected = "Data type Dictionary(UInt64, Utf8) with key type UInt64 is not currently supported"
@@ -16,10 +16,11 @@
 // under the License.
 
 use crate::builder::buffer_builder::{Int32BufferBuilder, Int8BufferBuilder};
-use crate::builder::BufferBuilder;
-use crate::{make_array, ArrowPrimitiveType, UnionArray};
+use crate::builder::{ArrayBuilder, BufferBuilder};
+use crate::{make_array, Array, ArrayRef, ArrowPrimitiveType, UnionArray};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{ArrowNativeType, Buffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{ArrowError, DataType, Field};
 use std::any::Any;
@@ -31,14 +32,44 @@ use std::sync::Arc;
 struct FieldData {
     /// The type id for this field
     type_id: i8,
-    /// The Arrow data type represented in the `values_buffer`, which is untyped
+    /// The Arrow data type of this field
     data_type: DataType,
-    /// A buffer containing the values for this field in raw bytes
-    values_buffer: Box<dyn FieldDataValues>,
-    ///  The number of array slots represented by the buffer
+    /// The values of this field
+    values: FieldValues,
+    ///  The number of array slots represented by `values`
     slots: usize,
-    /// A builder for the null bitmap
-    null_buffer_builder: NullBufferBuilder,
+}
+
+/// The values of a [`FieldData`]
+enum FieldValues {
+    /// Primitive values appended with [`UnionBuilder::append`]
+    Primitive {
+        /// A buffer containing the values for this field in raw bytes
+        values_buffer: Box<dyn FieldDataValues>,
+        /// A builder for the null bitmap
+        null_buffer_builder: NullBufferBuilder,
+    },
+    /// Values appended with [`UnionBuilder::append_with`]
+    ///
+    /// For sparse unions these only contain the slots of this field, with the
+    /// remaining slots padded with nulls by [`UnionBuilder::build`]
+    Builder(Box<dyn ArrayBuilder>),
+}
+
+impl std::fmt::Debug for FieldValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primitive {
+                values_buffer,
+                null_buffer_builder,
+            } => f
+                .debug_struct("Primitive")
+                .field("values_buffer", values_buffer)
+                .field("null_buffer_builder", null_buffer_builder)
+                .finish(),
+            Self::Builder(builder) => f.debug_tuple("Builder").field(&builder.len()).finish(),
+        }
+    }
 }
 
 /// A type-erased [`BufferBuilder`] used by [`FieldData`]
@@ -71,28 +102,90 @@ impl FieldData {
             type_id,
             data_type,
             slots: 0,
-            values_buffer: Box::new(BufferBuilder::<T::Native>::new(capacity)),
-            null_buffer_builder: NullBufferBuilder::new(capacity),
+            values: FieldValues::Primitive {
+                values_buffer: Box::new(BufferBuilder::<T::Native>::new(capacity)),
+                null_buffer_builder: NullBufferBuilder::new(capacity),
+            },
         }
     }
 
     /// Appends a single value to this `FieldData`'s `values_buffer`.
     fn append_value<T: ArrowPrimitiveType>(&mut self, v: T::Native) {
-        self.values_buffer
+        let FieldValues::Primitive {
+            values_buffer,
+            null_buffer_builder,
+        } = &mut self.values
+        else {
+            unreachable!("Tried to append primitive value to builder field")
+        };
+        values_buffer
             .as_mut_any()
             .downcast_mut::<BufferBuilder<T::Native>>()
             .expect("Tried to append unexpected type")
             .append(v);
 
-        self.null_buffer_builder.append(true);
+        null_buffer_builder.append(true);
         self.slots += 1;
     }
 
     /// Appends a null to this `FieldData`.
+    ///
+    /// This is a no-op for [`FieldValues::Builder`], which are padded on build
     fn append_null(&mut self) {
-        self.values_buffer.append_null();
-        self.null_buffer_builder.append(false);
-        self.slots += 1;
+        if let FieldValues::Primitive {
+            values_buffer,
+            null_buffer_builder,
+        } = &mut self.values
+        {
+            values_buffer.append_null();
+            null_buffer_builder.append(false);
+            self.slots += 1;
+        }
+    }
+
+    /// Builds the child array of this field, `type_ids` are the type ids of
+    /// a sparse union, or `None` for a dense union
+    fn finish(self, type_ids: Option<&[i8]>) -> ArrayRef {
+        match self.values {
+            FieldValues::Primitive {
+                mut values_buffer,
+                mut null_buffer_builder,
+            } => make_array(unsafe {
+                ArrayDataBuilder::new(self.data_type)
+                    .add_buffer(values_buffer.finish())
+                    .len(self.slots)
+                    .nulls(null_buffer_builder.finish())
+                    .build_unchecked()
+            }),
+            FieldValues::Builder(mut builder) => {
+                let values = builder.finish();
+                let Some(type_ids) = type_ids else {
+                    return values;
+                };
+
+                // Pad the slots of other fields with nulls
+                let data = values.to_data();
+                let mut mutable = MutableArrayData::new(vec![&data], true, type_ids.len());
+                let mut offset = 0;
+                let mut start = 0;
+                while start < type_ids.len() {
+                    let selected = type_ids[start] == self.type_id;
+                    let end = type_ids[start..]
+                        .iter()
+                        .position(|t| (*t == self.type_id) != selected)
+                        .map_or(type_ids.len(), |p| start + p);
+                    match selected {
+                        true => {
+                            mutable.extend(0, offset, offset + end - start);
+                            offset += end - start;
+                        }
+                        false => mutable.extend_nulls(end - start),
+                    }
+                    start = end;
+                }
+                make_array(mutable.freeze())
+            }
+        }
     }
 }
 
@@ -138,6 +231,33 @@ impl FieldData {
 /// assert_eq!(union.value_offset(1), 1);
 /// assert_eq!(union.value_offset(2), 2);
 /// ```
+///
+/// Example: **Nested and Variable-Length Children**
+///
+/// Children of any type can be built with an [`ArrayBuilder`] registered with
+/// [`UnionBuilder::add_child`], and appended to with [`UnionBuilder::append_with`]
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, ListBuilder, StringBuilder, UnionBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// let mut builder = UnionBuilder::new_dense();
+/// builder.add_child("s", StringBuilder::new()).unwrap();
+/// builder.add_child("l", ListBuilder::new(Int32Builder::new())).unwrap();
+///
+/// builder.append_with("s", |b: &mut StringBuilder| b.append_value("foo")).unwrap();
+/// builder.append::<Int32Type>("i", 1).unwrap();
+/// builder
+///     .append_with("l", |b: &mut ListBuilder<Int32Builder>| {
+///         b.append_value([Some(1), None])
+///     })
+///     .unwrap();
+/// let union = builder.build().unwrap();
+///
+/// assert_eq!(union.value(0).as_string::<i32>().value(0), "foo");
+/// assert_eq!(union.value(1).as_primitive::<Int32Type>().value(0), 1);
+/// assert_eq!(union.value(2).as_list::<i32>().value(0).len(), 2);
+/// ```
 #[derive(Debug)]
 pub struct UnionBuilder {
     /// The current number of slots in the array
@@ -218,13 +338,16 @@ impl UnionBuilder {
 
         let mut field_data = match self.fields.remove(&type_name) {
             Some(data) => {
-                if data.data_type != T::DATA_TYPE {
-                    return Err(ArrowError::InvalidArgumentError(format!(
+                if data.data_type != T::DATA_TYPE || matches!(data.values, FieldValues::Builder(_))
+                {
+                    let err = ArrowError::InvalidArgumentError(format!(
                         "Attempt to write col \"{}\" with type {} doesn't match existing type {}",
                         type_name,
                         T::DATA_TYPE,
                         data.data_type
-                    )));
+                    ));
+                    self.fields.insert(type_name, data);
+                    return Err(err);
                 }
                 data
             }
@@ -248,6 +371,101 @@ impl UnionBuilder {
                 }
             },
         };
+        self.append_slot(&field_data);
+
+        match v {
+            Some(v) => field_data.append_value::<T>(v),
+            None => field_data.append_null(),
+        }
+
+        self.fields.insert(type_name, field_data);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Registers a child `type_name` whose values are built with `builder`
+    ///
+    /// This allows building children of any type, such as strings, lists or structs,
+    /// with values appended by [`Self::append_with`]
+    ///
+    /// Returns an error if a child named `type_name` already exists or `builder` is not empty
+    pub fn add_child(
+        &mut self,
+        type_name: &str,
+        builder: impl ArrayBuilder,
+    ) -> Result<(), ArrowError> {
+        if self.fields.contains_key(type_name) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Union child \"{type_name}\" already exists"
+            )));
+        }
+        if !builder.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Builder for union child \"{type_name}\" must be empty, found {} slots",
+                builder.len()
+            )));
+        }
+        let data_type = builder.finish_cloned().data_type().clone();
+        let field_data = FieldData {
+            type_id: self.fields.len() as i8,
+            data_type,
+            values: FieldValues::Builder(Box::new(builder)),
+            slots: 0,
+        };
+        self.fields.insert(type_name.to_string(), field_data);
+        Ok(())
+    }
+
+    /// Appends a value to the child `type_name` registered with [`Self::add_child`]
+    ///
+    /// `f` is called with the builder of the child, and must append exactly one value,
+    /// which may be null
+    ///
+    /// Returns an error if no child named `type_name` was registered with a builder of type `B`,
+    /// or if `f` does not append exactly one value
+    pub fn append_with<B: ArrayBuilder, F: FnOnce(&mut B)>(
+        &mut self,
+        type_name: &str,
+        f: F,
+    ) -> Result<(), ArrowError> {
+        let Some(mut field_data) = self.fields.remove(type_name) else {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Union child \"{type_name}\" must be registered with add_child"
+            )));
+        };
+        let builder = match &mut field_data.values {
+            FieldValues::Builder(b) => b.as_any_mut().downcast_mut::<B>(),
+            FieldValues::Primitive { .. } => None,
+        };
+        let Some(builder) = builder else {
+            let data_type = field_data.data_type.clone();
+            self.fields.insert(type_name.to_string(), field_data);
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Attempt to write col \"{type_name}\" with builder {} doesn't match existing type {data_type}",
+                std::any::type_name::<B>()
+            )));
+        };
+
+        let slots = builder.len();
+        f(builder);
+        let appended = builder.len() - slots;
+        if appended != 1 {
+            self.fields.insert(type_name.to_string(), field_data);
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected exactly one value to be appended to union child \"{type_name}\", got {appended}"
+            )));
+        }
+
+        self.append_slot(&field_data);
+        field_data.slots += 1;
+        self.fields.insert(type_name.to_string(), field_data);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Records the type id and offset of a new slot of `field_data`, which must
+    /// not be in `self.fields`, before its value is appended
+    fn append_slot(&mut self, field_data: &FieldData) {
         self.type_id_builder.append(field_data.type_id);
 
         match &mut self.value_offset_builder {
@@ -263,45 +481,24 @@ impl UnionBuilder {
                 }
             }
         }
-
-        match v {
-            Some(v) => field_data.append_value::<T>(v),
-            None => field_data.append_null(),
-        }
-
-        self.fields.insert(type_name, field_data);
-        self.len += 1;
-        Ok(())
     }
 
     /// Builds this builder creating a new `UnionArray`.
     pub fn build(self) -> Result<UnionArray, ArrowError> {
+        let type_ids = match self.value_offset_builder {
+            Some(_) => None,
+            None => Some(self.type_id_builder.as_slice()),
+        };
         let mut children = Vec::with_capacity(self.fields.len());
         let union_fields = self
             .fields
             .into_iter()
-            .map(
-                |(
-                    name,
-                    FieldData {
-                        type_id,
-                        data_type,
-                        mut values_buffer,
-                        slots,
-                        mut null_buffer_builder,
-                    },
-                )| {
-                    let array_ref = make_array(unsafe {
-                        ArrayDataBuilder::new(data_type.clone())
-                            .add_buffer(values_buffer.finish())
-                            .len(slots)
-                            .nulls(null_buffer_builder.finish())
-                            .build_unchecked()
-                    });
-                    children.push(array_ref);
-                    (type_id, Arc::new(Field::new(name, data_type, false)))
-                },
-            )
+            .map(|(name, field_data)| {
+                let type_id = field_data.type_id;
+                let data_type = field_data.data_type.clone();
+                children.push(field_data.finish(type_ids));
+                (type_id, Arc::new(Field::new(name, data_type, false)))
+            })
             .collect();
         UnionArray::try_new(
             union_fields,
@@ -19,6 +19,7 @@
 //! [schema](arrow_schema::Schema).
 
 use crate::{new_empty_array, Array, ArrayRef, StructArray};
+use arrow_data::MemoryTracker;
 use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaBuilder, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
@@ -620,6 +621,19 @@ impl RecordBatch {
             .map(|array| array.get_array_memory_size())
             .sum()
     }
+
+    /// Returns the number of bytes of memory retained by the buffers of this batch.
+    ///
+    /// Unlike [`Self::get_array_memory_size`], buffers shared between columns, or
+    /// between children of the same column, are only counted once. Sliced columns
+    /// account for the full size of the allocations they keep alive.
+    pub fn get_retained_memory_size(&self) -> usize {
+        let mut tracker = MemoryTracker::new();
+        for column in self.columns() {
+            tracker.track_array_data(&column.to_data());
+        }
+        tracker.retained_bytes()
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -832,6 +846,28 @@ mod tests {
         assert_eq!(record_batch.get_array_memory_size(), 364);
     }
 
+    #[test]
+    fn retained_memory_size() {
+        let a: ArrayRef = Arc::new(Int32Array::from_iter_values(0..4));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b.clone())]).unwrap();
+        let expected = a.get_buffer_memory_size() + b.get_buffer_memory_size();
+        assert_eq!(batch.get_retained_memory_size(), expected);
+
+        // Slicing does not release the underlying allocations
+        assert_eq!(batch.slice(1, 2).get_retained_memory_size(), expected);
+
+        // Columns sharing buffers are only counted once
+        let batch =
+            RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", a.clone()), ("c", b.clone())])
+                .unwrap();
+        assert_eq!(batch.get_retained_memory_size(), expected);
+        assert_eq!(
+            a.slice(1, 2).get_retained_memory_size(),
+            a.get_buffer_memory_size()
+        );
+    }
+
     fn check_batch(record_batch: RecordBatch, num_rows: usize) {
         assert_eq!(num_rows, record_batch.num_rows());
         assert_eq!(2, record_batch.num_columns());
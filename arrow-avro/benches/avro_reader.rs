@@ -0,0 +1,264 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compares decoding Avro object container files with [`arrow_avro`] against the
+//! `apache-avro` crate
+//!
+//! In addition to the usual criterion output, a JSON summary of the median time
+//! per file of each benchmark is written to the path in the `AVRO_BENCH_JSON`
+//! environment variable, or to stdout if not set. Schemas not yet supported by
+//! [`arrow_avro`] are reported with `"supported": false`.
+
+use apache_avro::types::Value;
+use arrow_avro::reader::ReaderBuilder;
+use criterion::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const ROWS: usize = 8192;
+
+/// Decodes an object container file, returning the number of rows
+type DecodeFn = fn(&[u8]) -> Result<usize, String>;
+
+/// A random string of up to `max_len` lowercase characters
+fn random_string(rng: &mut StdRng, max_len: usize) -> String {
+    let len = rng.gen_range(0..=max_len);
+    (0..len)
+        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+/// A value of the union `["null", T]`, null half of the time
+fn nullable(rng: &mut StdRng, value: Value) -> Value {
+    match rng.gen_bool(0.5) {
+        true => Value::Union(1, Box::new(value)),
+        false => Value::Union(0, Box::new(Value::Null)),
+    }
+}
+
+fn flat_numeric(rng: &mut StdRng) -> (String, Value) {
+    let mut fields = vec![];
+    let mut values = vec![];
+    for i in 0..4 {
+        fields.push(json!({"name": format!("i{i}"), "type": "int"}));
+        values.push((format!("i{i}"), Value::Int(rng.gen())));
+        fields.push(json!({"name": format!("l{i}"), "type": "long"}));
+        values.push((format!("l{i}"), Value::Long(rng.gen())));
+        fields.push(json!({"name": format!("f{i}"), "type": "float"}));
+        values.push((format!("f{i}"), Value::Float(rng.gen())));
+        fields.push(json!({"name": format!("d{i}"), "type": "double"}));
+        values.push((format!("d{i}"), Value::Double(rng.gen())));
+    }
+    let schema = json!({"type": "record", "name": "flat_numeric", "fields": fields});
+    (schema.to_string(), Value::Record(values))
+}
+
+fn wide_string(rng: &mut StdRng) -> (String, Value) {
+    let (fields, values): (Vec<_>, Vec<_>) = (0..32)
+        .map(|i| {
+            let name = format!("s{i}");
+            let value = Value::String(random_string(rng, 32));
+            (json!({"name": name, "type": "string"}), (name, value))
+        })
+        .unzip();
+    let schema = json!({"type": "record", "name": "wide_string", "fields": fields});
+    (schema.to_string(), Value::Record(values))
+}
+
+fn nested(rng: &mut StdRng) -> (String, Value) {
+    let schema = json!({
+        "type": "record",
+        "name": "nested",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "attributes", "type": {"type": "map", "values": "long"}},
+            {"name": "points", "type": {"type": "array", "items": {
+                "type": "record",
+                "name": "point",
+                "fields": [{"name": "x", "type": "double"}, {"name": "y", "type": "double"}]
+            }}}
+        ]
+    });
+    let tags = (0..rng.gen_range(0..8))
+        .map(|_| Value::String(random_string(rng, 16)))
+        .collect();
+    let attributes = (0..rng.gen_range(0..8))
+        .map(|_| (random_string(rng, 8), Value::Long(rng.gen())))
+        .collect::<HashMap<_, _>>();
+    let points = (0..rng.gen_range(0..8))
+        .map(|_| {
+            Value::Record(vec![
+                ("x".to_string(), Value::Double(rng.gen())),
+                ("y".to_string(), Value::Double(rng.gen())),
+            ])
+        })
+        .collect();
+    let value = Value::Record(vec![
+        ("id".to_string(), Value::Long(rng.gen())),
+        ("tags".to_string(), Value::Array(tags)),
+        ("attributes".to_string(), Value::Map(attributes)),
+        ("points".to_string(), Value::Array(points)),
+    ]);
+    (schema.to_string(), value)
+}
+
+fn union_heavy(rng: &mut StdRng) -> (String, Value) {
+    let mut fields = vec![];
+    let mut values = vec![];
+    for i in 0..8 {
+        fields.push(json!({"name": format!("l{i}"), "type": ["null", "long"]}));
+        let value = Value::Long(rng.gen());
+        values.push((format!("l{i}"), nullable(rng, value)));
+        fields.push(json!({"name": format!("s{i}"), "type": ["null", "string"]}));
+        let value = Value::String(random_string(rng, 16));
+        values.push((format!("s{i}"), nullable(rng, value)));
+    }
+    let schema = json!({"type": "record", "name": "union_heavy", "fields": fields});
+    (schema.to_string(), Value::Record(values))
+}
+
+/// Writes an uncompressed object container file of [`ROWS`] records generated by `f`
+fn write_file(f: fn(&mut StdRng) -> (String, Value)) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(42);
+    let (schema, _) = f(&mut rng);
+    let schema = apache_avro::Schema::parse_str(&schema).unwrap();
+    let mut writer = apache_avro::Writer::new(&schema, Vec::new());
+    for _ in 0..ROWS {
+        writer.append(f(&mut rng).1).unwrap();
+    }
+    writer.into_inner().unwrap()
+}
+
+fn decode_arrow(file: &[u8]) -> Result<usize, String> {
+    let reader = ReaderBuilder::new()
+        .with_batch_size(ROWS)
+        .build(file)
+        .map_err(|e| e.to_string())?;
+    reader
+        .map(|b| b.map(|b| b.num_rows()).map_err(|e| e.to_string()))
+        .sum()
+}
+
+fn decode_apache_avro(file: &[u8]) -> Result<usize, String> {
+    let reader = apache_avro::Reader::new(file).map_err(|e| e.to_string())?;
+    let mut rows = 0;
+    for value in reader {
+        black_box(value.map_err(|e| e.to_string())?);
+        rows += 1;
+    }
+    Ok(rows)
+}
+
+/// Median time per iteration of each benchmark, recorded with [`Bencher::iter_custom`]
+#[derive(Default)]
+struct Results {
+    samples: Vec<(String, &'static str, Vec<f64>)>,
+    unsupported: Vec<(String, &'static str, String)>,
+}
+
+impl Results {
+    fn to_json(&self) -> serde_json::Value {
+        // Benchmarks excluded by a filter have no samples
+        let measured = self.samples.iter().filter(|(_, _, ns)| !ns.is_empty());
+        let supported = measured.map(|(schema, implementation, ns)| {
+            let mut ns = ns.clone();
+            ns.sort_by(f64::total_cmp);
+            let median = ns[ns.len() / 2];
+            json!({
+                "schema": schema,
+                "implementation": implementation,
+                "supported": true,
+                "rows": ROWS,
+                "median_ns": median,
+                "rows_per_second": ROWS as f64 / median * 1e9,
+            })
+        });
+        let unsupported = self
+            .unsupported
+            .iter()
+            .map(|(schema, implementation, err)| {
+                json!({
+                    "schema": schema,
+                    "implementation": implementation,
+                    "supported": false,
+                    "error": err,
+                })
+            });
+        supported.chain(unsupported).collect()
+    }
+}
+
+fn bench_schema(
+    c: &mut Criterion,
+    results: &mut Results,
+    name: &str,
+    f: fn(&mut StdRng) -> (String, Value),
+) {
+    let file = write_file(f);
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Elements(ROWS as u64));
+
+    let implementations: [(&str, DecodeFn); 2] =
+        [("arrow", decode_arrow), ("apache_avro", decode_apache_avro)];
+    for (implementation, decode) in implementations {
+        if let Err(e) = decode(&file) {
+            results
+                .unsupported
+                .push((name.to_string(), implementation, e));
+            continue;
+        }
+        let mut samples = vec![];
+        group.bench_function(implementation, |b| {
+            b.iter_custom(|iters| {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    black_box(decode(&file).unwrap());
+                }
+                let elapsed = start.elapsed();
+                samples.push(elapsed.as_nanos() as f64 / iters as f64);
+                elapsed
+            })
+        });
+        results
+            .samples
+            .push((name.to_string(), implementation, samples));
+    }
+    group.finish();
+}
+
+fn main() {
+    let mut c = Criterion::default()
+        .measurement_time(Duration::from_secs(5))
+        .configure_from_args();
+    let mut results = Results::default();
+
+    bench_schema(&mut c, &mut results, "flat_numeric", flat_numeric);
+    bench_schema(&mut c, &mut results, "wide_string", wide_string);
+    bench_schema(&mut c, &mut results, "nested", nested);
+    bench_schema(&mut c, &mut results, "union_heavy", union_heavy);
+    c.final_summary();
+
+    let summary = serde_json::to_string_pretty(&results.to_json()).unwrap();
+    match std::env::var("AVRO_BENCH_JSON") {
+        Ok(path) => std::fs::write(path, summary).unwrap(),
+        Err(_) => println!("{summary}"),
+    }
+}
@@ -15,14 +15,130 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::schema::{Attributes, ComplexType, PrimitiveType, Record, Schema, TypeName};
+use crate::schema::{
+    Array, Attributes, ComplexType, Enum, Field as AvroSchemaField, Fixed, Map, PrimitiveType,
+    Record, Schema, Type, TypeName,
+};
 use arrow_schema::{
-    ArrowError, DataType, Field, FieldRef, IntervalUnit, SchemaBuilder, SchemaRef, TimeUnit,
+    ArrowError, DataType, Field, FieldRef, IntervalUnit, SchemaBuilder, SchemaRef, SortOptions,
+    TimeUnit, UnionFields, UnionMode, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
+use serde::{Serialize, Serializer};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub use arrow_schema::extension::{
+    EXTENSION_TYPE_NAME_KEY, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME,
+};
+
+/// The field metadata key used to store the field ID of a field, as read from
+/// the `field-id` attribute of a record field
+///
+/// This matches the key used by the parquet crate, allowing field IDs to be
+/// preserved when writing the decoded data to parquet
+pub use arrow_schema::FIELD_ID_META_KEY as FIELD_ID_METADATA_KEY;
+
+/// The record field attribute containing the field ID
+const FIELD_ID_ATTRIBUTE: &str = "field-id";
+
+/// The field metadata key used to store the sort order of a field, as read from the
+/// `order` attribute of a record field, one of `ascending`, `descending` or `ignore`
+///
+/// See [`sort_options`] and <https://avro.apache.org/docs/1.11.1/specification/#sort-order>
+pub const SORT_ORDER_METADATA_KEY: &str = "avro.order";
+
+/// The record field attribute containing the sort order
+const SORT_ORDER_ATTRIBUTE: &str = "order";
+
+/// The field metadata key used to store whether nulls sort `first` or `last` in
+/// ascending order, for a nullable field with a sort order under [`SORT_ORDER_METADATA_KEY`]
+///
+/// Avro orders the branches of a union by their position, with nulls therefore
+/// sorting first if `null` is the first branch of the union, and last otherwise
+pub const SORT_NULLS_METADATA_KEY: &str = "avro.order.nulls";
+
+/// Returns the [`SortOptions`] for the sort order of `field`, as stored under
+/// [`SORT_ORDER_METADATA_KEY`], or `None` if the field is ignored when sorting
+///
+/// Fields without a sort order are ascending, the default order in Avro
+///
+/// Nulls sort according to the position of `null` in the union of the field, as
+/// stored under [`SORT_NULLS_METADATA_KEY`], with the order of both reversed when
+/// descending. This is only stored for fields with a sort order, nulls otherwise
+/// sorting first in ascending order
+pub fn sort_options(field: &Field) -> Option<SortOptions> {
+    let order = field.metadata().get(SORT_ORDER_METADATA_KEY);
+    let descending = match order.map(String::as_str) {
+        None | Some("ascending") => false,
+        Some("descending") => true,
+        _ => return None,
+    };
+    let nulls = field.metadata().get(SORT_NULLS_METADATA_KEY);
+    let nulls_last = nulls.is_some_and(|n| n == "last");
+    Some(SortOptions {
+        descending,
+        nulls_first: nulls_last == descending,
+    })
+}
+
+/// The map attribute containing the type to parse the string keys of the map as,
+/// see [`MapKey`]
+pub const MAP_KEY_TYPE_ATTRIBUTE: &str = "arrow.key-type";
+
+/// The field metadata key used to store the symbols of an Avro enum as a JSON array,
+/// in the order of the dictionary values the enum is decoded to
+///
+/// See [`enum_symbols`] and <https://avro.apache.org/docs/1.11.1/specification/#enums>
+pub const ENUM_SYMBOLS_METADATA_KEY: &str = "avro.enum.symbols";
+
+/// The field metadata key used to store the default symbol of an Avro enum, if any
+pub const ENUM_DEFAULT_METADATA_KEY: &str = "avro.enum.default";
+
+/// The field metadata key used to store the symbols of the writer enum as a JSON array,
+/// if it was resolved against a reader enum with different symbols
+///
+/// See [`enum_symbol_mapping`]
+pub const ENUM_WRITER_SYMBOLS_METADATA_KEY: &str = "avro.enum.writer_symbols";
+
+/// Returns the symbols of the Avro enum decoded to `field`, as stored under
+/// [`ENUM_SYMBOLS_METADATA_KEY`], with the index of each symbol being its
+/// dictionary key
+pub fn enum_symbols(field: &Field) -> Option<Vec<String>> {
+    let symbols = field.metadata().get(ENUM_SYMBOLS_METADATA_KEY)?;
+    serde_json::from_str(symbols).ok()
+}
+
+/// Returns, for each symbol of the writer enum, the index of the symbol in
+/// [`enum_symbols`] it is decoded to, or `None` if decoding it is an error, or
+/// `None` if `field` was not resolved against a reader enum with different symbols
+///
+/// Writer symbols not present in the reader enum are decoded to its default symbol,
+/// stored under [`ENUM_DEFAULT_METADATA_KEY`], if any
+pub fn enum_symbol_mapping(field: &Field) -> Option<Vec<Option<usize>>> {
+    let writer = field.metadata().get(ENUM_WRITER_SYMBOLS_METADATA_KEY)?;
+    let writer: Vec<String> = serde_json::from_str(writer).ok()?;
+    let default = field.metadata().get(ENUM_DEFAULT_METADATA_KEY);
+    Some(map_enum_symbols(
+        &writer,
+        &enum_symbols(field)?,
+        default.map(|x| x.as_str()),
+    ))
+}
+
+/// Returns the index of the symbol in `reader` that each symbol in `writer` resolves to
+fn map_enum_symbols(
+    writer: &[String],
+    reader: &[String],
+    default: Option<&str>,
+) -> Vec<Option<usize>> {
+    let default = default.and_then(|d| reader.iter().position(|s| s == d));
+    writer
+        .iter()
+        .map(|w| reader.iter().position(|s| s == w).or(default))
+        .collect()
+}
+
 /// Avro types are not nullable, with nullability instead encoded as a union
 /// where one of the variants is the null type.
 ///
@@ -42,13 +158,31 @@ pub struct AvroDataType {
     nullability: Option<Nullability>,
     metadata: HashMap<String, String>,
     codec: Codec,
+    resolution: Option<ResolvedRecord>,
 }
 
 impl AvroDataType {
     /// Returns an arrow [`Field`] with the given name
     pub fn field_with_name(&self, name: &str) -> Field {
         let d = self.codec.data_type();
-        Field::new(name, d, self.nullability.is_some()).with_metadata(self.metadata.clone())
+        let mut metadata = self.metadata.clone();
+        if let Some(extension) = self.codec.extension_name() {
+            metadata.insert(EXTENSION_TYPE_NAME_KEY.to_string(), extension.to_string());
+        }
+        if let Codec::Enum(e) = &self.codec {
+            e.add_metadata(&mut metadata);
+        }
+        // Values of the null type are always null
+        let nullable = self.nullability.is_some()
+            || match &self.codec {
+                Codec::Null => true,
+                Codec::Decimal(_, _, _, Some(r)) => r.overflow == DecimalOverflowHandling::Null,
+                Codec::Union(branches) => branches
+                    .iter()
+                    .any(|b| matches!(b.data_type.codec, Codec::Null)),
+                _ => false,
+            };
+        Field::new(name, d, nullable).with_metadata(metadata)
     }
 
     pub fn codec(&self) -> &Codec {
@@ -58,6 +192,80 @@ impl AvroDataType {
     pub fn nullability(&self) -> Option<Nullability> {
         self.nullability
     }
+
+    /// Returns a copy of this data type with the provided [`Nullability`]
+    pub(crate) fn with_nullability(&self, nullability: Option<Nullability>) -> Self {
+        Self {
+            nullability,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this data type with any `string` or `bytes` types, including
+    /// those of nested fields, decoded as [`Codec::Json`] if they have the attribute
+    /// `key` set to the string `value`
+    pub(crate) fn with_json_attribute(&self, key: &str, value: &str) -> Self {
+        let codec = match &self.codec {
+            Codec::Utf8 | Codec::Binary => {
+                let expected = serde_json::Value::String(value.to_string()).to_string();
+                match self.metadata.get(key) {
+                    Some(v) if *v == expected => Codec::Json,
+                    _ => self.codec.clone(),
+                }
+            }
+            Codec::List(item) => Codec::List(Arc::new(item.with_json_attribute(key, value))),
+            Codec::Map(k, v) => Codec::Map(*k, Arc::new(v.with_json_attribute(key, value))),
+            Codec::Union(branches) => Codec::Union(
+                branches
+                    .iter()
+                    .map(|b| b.with_json_attribute(key, value))
+                    .collect(),
+            ),
+            Codec::Struct(fields) => Codec::Struct(
+                fields
+                    .iter()
+                    .map(|f| f.with_json_attribute(key, value))
+                    .collect(),
+            ),
+            codec => codec.clone(),
+        };
+        Self {
+            codec,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the [`ResolvedRecord`] if this is a record resolved against a reader schema
+    pub fn resolution(&self) -> Option<&ResolvedRecord> {
+        self.resolution.as_ref()
+    }
+
+    /// Returns the Avro [`Schema`] for this data type
+    ///
+    /// `name` is used to name any named types, such as records and fixed
+    pub fn to_schema<'a>(&'a self, name: &'a str) -> Schema<'a> {
+        let mut attributes = Attributes::default();
+        for (k, v) in &self.metadata {
+            match k.as_str() {
+                "logicalType" => attributes.logical_type = Some(v),
+                // Written as attributes of the record field, see Codec::to_schema
+                FIELD_ID_METADATA_KEY | SORT_ORDER_METADATA_KEY | SORT_NULLS_METADATA_KEY => {}
+                _ => {
+                    let value = serde_json::from_str(v)
+                        .unwrap_or_else(|_| serde_json::Value::String(v.clone()));
+                    attributes.additional.insert(k, value);
+                }
+            }
+        }
+
+        let schema = self.codec.to_schema(name, attributes);
+        let null = Schema::TypeName(TypeName::Primitive(PrimitiveType::Null));
+        match self.nullability {
+            None => schema,
+            Some(Nullability::NullFirst) => Schema::Union(vec![null, schema]),
+            Some(Nullability::NullSecond) => Schema::Union(vec![schema, null]),
+        }
+    }
 }
 
 /// A named [`AvroDataType`]
@@ -81,6 +289,26 @@ impl AvroField {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Returns the Avro [`Schema`] for this field
+    pub fn to_schema(&self) -> Schema<'_> {
+        self.data_type.to_schema(&self.name)
+    }
+
+    /// Returns a copy of this field with fields with the attribute `key` set to the
+    /// string `value` decoded as JSON, see [`AvroDataType::with_json_attribute`]
+    pub(crate) fn with_json_attribute(&self, key: &str, value: &str) -> Self {
+        Self {
+            name: self.name.clone(),
+            data_type: self.data_type.with_json_attribute(key, value),
+        }
+    }
+}
+
+impl Serialize for AvroField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_schema().serialize(serializer)
+    }
 }
 
 impl<'a> TryFrom<&Schema<'a>> for AvroField {
@@ -103,6 +331,272 @@ impl<'a> TryFrom<&Schema<'a>> for AvroField {
     }
 }
 
+impl AvroField {
+    /// Resolves data written with the `writer` schema against the `reader` schema
+    ///
+    /// The returned [`AvroField`] decodes data encoded with the `writer` schema, producing
+    /// records with the fields of the `reader` schema, matched according to `options`:
+    ///
+    /// * Writer fields not present in the reader schema are skipped
+    /// * Reader fields not present in the writer schema are read as null, and must be nullable
+    ///
+    /// <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+    pub fn resolve(
+        writer: &Schema<'_>,
+        reader: &Schema<'_>,
+        options: &ResolutionOptions,
+    ) -> Result<Self, ArrowError> {
+        let writer = Self::try_from(writer)?;
+        let reader = Self::try_from(reader)?;
+        Ok(Self {
+            data_type: resolve_data_type(&writer.data_type, &reader.data_type, options)?,
+            name: reader.name,
+        })
+    }
+}
+
+/// Options controlling how a writer schema is resolved against a reader schema
+///
+/// See [`AvroField::resolve`]
+#[derive(Debug, Clone, Default)]
+pub struct ResolutionOptions {
+    case_insensitive: bool,
+    field_mapping: HashMap<String, String>,
+    decimal_overflow: DecimalOverflowHandling,
+}
+
+impl ResolutionOptions {
+    /// Match record fields ignoring ASCII case, defaults to `false`
+    ///
+    /// Fields with names that match exactly are always preferred
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Match the writer field named `writer` to the reader field named `reader`
+    ///
+    /// This applies to fields in any record, and takes precedence over matching by name
+    pub fn with_field_mapping(
+        mut self,
+        writer: impl Into<String>,
+        reader: impl Into<String>,
+    ) -> Self {
+        self.field_mapping.insert(writer.into(), reader.into());
+        self
+    }
+
+    /// Set how decimal values that cannot be represented by the reader decimal they
+    /// are resolved to are decoded, defaults to [`DecimalOverflowHandling::Error`]
+    ///
+    /// Values of a writer decimal with a different scale than the reader decimal are
+    /// rescaled as they are decoded, with any digits beyond the reader scale rounded
+    /// half away from zero
+    pub fn with_decimal_overflow(mut self, handling: DecimalOverflowHandling) -> Self {
+        self.decimal_overflow = handling;
+        self
+    }
+
+    /// Returns the index of the field in `writer` that matches `reader`, if any
+    fn find_writer_field(&self, writer: &[AvroField], reader: &str) -> Option<usize> {
+        let mapped = writer
+            .iter()
+            .position(|w| self.field_mapping.get(&w.name).is_some_and(|m| m == reader));
+        let mapped_away = |w: &AvroField| self.field_mapping.contains_key(&w.name);
+
+        mapped
+            .or_else(|| {
+                writer
+                    .iter()
+                    .position(|w| w.name == reader && !mapped_away(w))
+            })
+            .or_else(|| {
+                self.case_insensitive.then(|| {
+                    writer
+                        .iter()
+                        .position(|w| w.name.eq_ignore_ascii_case(reader) && !mapped_away(w))
+                })?
+            })
+    }
+}
+
+/// The resolution of a writer record against a reader record
+///
+/// See [`AvroField::resolve`]
+#[derive(Debug, Clone)]
+pub struct ResolvedRecord {
+    writer_to_reader: Arc<[Option<usize>]>,
+    writer_fields: Arc<[AvroField]>,
+}
+
+impl ResolvedRecord {
+    /// For each field in the writer record, the index of the reader field it is
+    /// decoded to, or `None` if it should be skipped
+    pub fn writer_to_reader(&self) -> &[Option<usize>] {
+        &self.writer_to_reader
+    }
+
+    /// The fields of the writer record
+    pub fn writer_fields(&self) -> &[AvroField] {
+        &self.writer_fields
+    }
+}
+
+/// How a decimal value is decoded when it exceeds the precision of the reader
+/// decimal it is resolved to, after rescaling it to the reader scale
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalOverflowHandling {
+    /// Return an error identifying the value and field
+    #[default]
+    Error,
+    /// Decode the value as null, with the decoded field always nullable
+    Null,
+}
+
+/// The resolution of a writer decimal against a reader decimal with a different
+/// precision or scale, see [`Codec::Decimal`]
+///
+/// Values are rescaled to the reader scale as they are decoded, with any digits
+/// beyond it rounded half away from zero. Values exceeding the reader precision
+/// after rescaling are handled according to [`DecimalOverflowHandling`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalRescale {
+    writer_precision: u8,
+    writer_scale: i8,
+    overflow: DecimalOverflowHandling,
+}
+
+impl DecimalRescale {
+    /// Returns the precision of the writer decimal
+    pub fn writer_precision(&self) -> u8 {
+        self.writer_precision
+    }
+
+    /// Returns the scale of the writer decimal
+    pub fn writer_scale(&self) -> i8 {
+        self.writer_scale
+    }
+
+    /// Returns how values that overflow the reader decimal are decoded
+    pub fn overflow(&self) -> DecimalOverflowHandling {
+        self.overflow
+    }
+}
+
+/// Resolves the `writer` data type against the `reader` data type
+fn resolve_data_type(
+    writer: &AvroDataType,
+    reader: &AvroDataType,
+    options: &ResolutionOptions,
+) -> Result<AvroDataType, ArrowError> {
+    match (&writer.codec, &reader.codec) {
+        (Codec::Struct(writer_fields), Codec::Struct(reader_fields)) => {
+            let mut writer_to_reader = vec![None; writer_fields.len()];
+            let fields = reader_fields
+                .iter()
+                .enumerate()
+                .map(|(reader_idx, reader_field)| {
+                    match options.find_writer_field(writer_fields, &reader_field.name) {
+                        Some(writer_idx) if writer_to_reader[writer_idx].is_none() => {
+                            writer_to_reader[writer_idx] = Some(reader_idx);
+                            let data_type = resolve_data_type(
+                                &writer_fields[writer_idx].data_type,
+                                &reader_field.data_type,
+                                options,
+                            )?;
+                            Ok(AvroField {
+                                name: reader_field.name.clone(),
+                                data_type,
+                            })
+                        }
+                        Some(writer_idx) => Err(ArrowError::SchemaError(format!(
+                            "Writer field \"{}\" matches multiple reader fields",
+                            writer_fields[writer_idx].name
+                        ))),
+                        None if reader_field.field().is_nullable() => {
+                            Ok(reader_field.clone())
+                        }
+                        None => Err(ArrowError::SchemaError(format!(
+                            "Reader field \"{}\" is not nullable and not present in the writer schema",
+                            reader_field.name
+                        ))),
+                    }
+                })
+                .collect::<Result<_, ArrowError>>()?;
+
+            Ok(AvroDataType {
+                nullability: writer.nullability,
+                metadata: reader.metadata.clone(),
+                codec: Codec::Struct(fields),
+                resolution: Some(ResolvedRecord {
+                    writer_to_reader: writer_to_reader.into(),
+                    writer_fields: Arc::clone(writer_fields),
+                }),
+            })
+        }
+        (Codec::List(writer_item), Codec::List(reader_item)) => Ok(AvroDataType {
+            nullability: writer.nullability,
+            metadata: reader.metadata.clone(),
+            codec: Codec::List(Arc::new(resolve_data_type(
+                writer_item,
+                reader_item,
+                options,
+            )?)),
+            resolution: None,
+        }),
+        (Codec::Map(_, writer_value), Codec::Map(key, reader_value)) => Ok(AvroDataType {
+            nullability: writer.nullability,
+            metadata: reader.metadata.clone(),
+            codec: Codec::Map(
+                *key,
+                Arc::new(resolve_data_type(writer_value, reader_value, options)?),
+            ),
+            resolution: None,
+        }),
+        (Codec::Enum(writer_enum), Codec::Enum(reader_enum)) => Ok(AvroDataType {
+            nullability: writer.nullability,
+            metadata: reader.metadata.clone(),
+            codec: Codec::Enum(reader_enum.resolve(writer_enum)),
+            resolution: None,
+        }),
+        (
+            Codec::Decimal(writer_precision, writer_scale, size, _),
+            Codec::Decimal(precision, scale, _, _),
+        ) => {
+            // Values are decoded from the writer encoding, and rescaled if necessary
+            let rescale = (writer_precision != precision || writer_scale != scale).then_some(
+                DecimalRescale {
+                    writer_precision: *writer_precision,
+                    writer_scale: *writer_scale,
+                    overflow: options.decimal_overflow,
+                },
+            );
+            Ok(AvroDataType {
+                nullability: writer.nullability,
+                metadata: reader.metadata.clone(),
+                codec: Codec::Decimal(*precision, *scale, *size, rescale),
+                resolution: None,
+            })
+        }
+        (w, r) if w.data_type() == r.data_type() => Ok(AvroDataType {
+            nullability: writer.nullability,
+            metadata: reader.metadata.clone(),
+            // Values are decoded from the writer encoding, which is only the same as that
+            // of the reader for strings, e.g. not for a fixed writer and uuid reader
+            codec: match (w, r) {
+                (Codec::Utf8 | Codec::Json, Codec::Utf8 | Codec::Json) => r.clone(),
+                _ => w.clone(),
+            },
+            resolution: None,
+        }),
+        (w, r) => Err(ArrowError::NotYetImplemented(format!(
+            "Resolving {} to {} not currently supported",
+            w.data_type(),
+            r.data_type()
+        ))),
+    }
+}
+
 /// An Avro encoding
 ///
 /// <https://avro.apache.org/docs/1.11.1/specification/#encodings>
@@ -116,6 +610,10 @@ pub enum Codec {
     Float64,
     Binary,
     Utf8,
+    /// A string containing a UUID, decoded to the canonical `arrow.uuid` extension type
+    Uuid,
+    /// A string containing JSON, decoded to the canonical `arrow.json` extension type
+    Json,
     Date32,
     TimeMillis,
     TimeMicros,
@@ -124,9 +622,179 @@ pub enum Codec {
     /// TimestampMicros(is_utc)
     TimestampMicros(bool),
     Fixed(i32),
+    /// Decimal(precision, scale, size, rescale) with `size` the size of the fixed
+    /// type storing the value, or `None` if stored as bytes, and `rescale` the
+    /// [`DecimalRescale`] of values written with a different precision or scale
+    Decimal(u8, i8, Option<usize>, Option<DecimalRescale>),
     List(Arc<AvroDataType>),
+    /// Map(key type, value type)
+    Map(MapKey, Arc<AvroDataType>),
     Struct(Arc<[AvroField]>),
     Interval,
+    /// An enum, decoded as a dictionary of its symbols
+    Enum(AvroEnum),
+    /// A union other than of `null` and one other type, which is instead decoded
+    /// as that type with [`Nullability`]
+    ///
+    /// Each branch is named by its type, or the name of a named type, and is decoded
+    /// according to the `UnionHandling` of the field
+    Union(Arc<[AvroField]>),
+}
+
+/// The symbols of an Avro enum, see [`Codec::Enum`]
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#enums>
+#[derive(Debug, Clone)]
+pub struct AvroEnum {
+    symbols: Arc<[String]>,
+    default: Option<String>,
+    /// The symbols of the writer enum, if resolved against this enum with different symbols
+    writer_symbols: Option<Arc<[String]>>,
+    /// The index of the symbol each of `writer_symbols` is decoded to, if any
+    writer_to_reader: Option<Arc<[Option<usize>]>>,
+}
+
+impl AvroEnum {
+    fn try_new(symbols: &[&str], default: Option<&str>) -> Result<Self, ArrowError> {
+        if let Some(default) = default.filter(|d| !symbols.contains(d)) {
+            return Err(ArrowError::ParseError(format!(
+                "Enum default \"{default}\" is not one of its symbols"
+            )));
+        }
+        Ok(Self {
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+            default: default.map(ToString::to_string),
+            writer_symbols: None,
+            writer_to_reader: None,
+        })
+    }
+
+    /// Returns the symbols of this enum, with the index of each symbol being its
+    /// dictionary key
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Returns the default symbol of this enum, if any
+    pub fn default_symbol(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Returns the symbols of the writer enum, if resolved against this enum with
+    /// different symbols
+    pub fn writer_symbols(&self) -> Option<&[String]> {
+        self.writer_symbols.as_deref()
+    }
+
+    /// For each symbol of the writer enum, the index of the symbol it is decoded to,
+    /// or `None` if decoding it is an error, if resolved against this enum with
+    /// different symbols
+    pub fn writer_to_reader(&self) -> Option<&[Option<usize>]> {
+        self.writer_to_reader.as_deref()
+    }
+
+    /// Resolves the `writer` enum against this reader enum
+    fn resolve(&self, writer: &Self) -> Self {
+        if writer.symbols == self.symbols {
+            return self.clone();
+        }
+        let mapping = map_enum_symbols(&writer.symbols, &self.symbols, self.default_symbol());
+        Self {
+            writer_symbols: Some(Arc::clone(&writer.symbols)),
+            writer_to_reader: Some(mapping.into()),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the index of the symbol that the writer symbol with index `idx`
+    /// is decoded to
+    pub(crate) fn reader_index(&self, idx: i32) -> Result<i32, ArrowError> {
+        let writer_symbols = self.writer_symbols.as_ref().unwrap_or(&self.symbols);
+        let idx = usize::try_from(idx)
+            .ok()
+            .filter(|idx| *idx < writer_symbols.len())
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Enum symbol index {idx} out of range for {} symbols",
+                    writer_symbols.len()
+                ))
+            })?;
+        let idx = match &self.writer_to_reader {
+            Some(mapping) => mapping[idx].ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Enum symbol \"{}\" not present in reader enum, which has no default",
+                    writer_symbols[idx]
+                ))
+            })?,
+            None => idx,
+        };
+        Ok(idx as i32)
+    }
+
+    /// Add the [`ENUM_SYMBOLS_METADATA_KEY`], [`ENUM_DEFAULT_METADATA_KEY`] and
+    /// [`ENUM_WRITER_SYMBOLS_METADATA_KEY`] field metadata
+    fn add_metadata(&self, metadata: &mut HashMap<String, String>) {
+        let to_json = |symbols: &[String]| serde_json::to_string(symbols).unwrap();
+        metadata.insert(
+            ENUM_SYMBOLS_METADATA_KEY.to_string(),
+            to_json(&self.symbols),
+        );
+        if let Some(default) = &self.default {
+            metadata.insert(ENUM_DEFAULT_METADATA_KEY.to_string(), default.clone());
+        }
+        if let Some(writer) = &self.writer_symbols {
+            metadata.insert(
+                ENUM_WRITER_SYMBOLS_METADATA_KEY.to_string(),
+                to_json(writer),
+            );
+        }
+    }
+}
+
+/// The type the keys of an Avro map are decoded to
+///
+/// The keys of Avro maps are always strings, however, the [`MAP_KEY_TYPE_ATTRIBUTE`]
+/// attribute of a map may be used to parse them as integers, e.g.
+/// `{"type": "map", "values": "string", "arrow.key-type": "long"}`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapKey {
+    /// Keys are decoded as strings, the default
+    Utf8,
+    /// Keys are parsed as 32-bit integers, with the attribute value `"int"`
+    Int32,
+    /// Keys are parsed as 64-bit integers, with the attribute value `"long"`
+    Int64,
+}
+
+impl MapKey {
+    /// Returns the [`MapKey`] for the value of the [`MAP_KEY_TYPE_ATTRIBUTE`] attribute
+    fn try_from_attribute(value: &serde_json::Value) -> Result<Self, ArrowError> {
+        match value.as_str() {
+            Some("string") => Ok(Self::Utf8),
+            Some("int") => Ok(Self::Int32),
+            Some("long") => Ok(Self::Int64),
+            _ => Err(ArrowError::ParseError(format!(
+                "Unsupported {MAP_KEY_TYPE_ATTRIBUTE}, expected \"string\", \"int\" or \"long\", got {value}"
+            ))),
+        }
+    }
+
+    /// Returns the arrow [`DataType`] of the decoded keys
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::Utf8 => DataType::Utf8,
+            Self::Int32 => DataType::Int32,
+            Self::Int64 => DataType::Int64,
+        }
+    }
+}
+
+/// Returns the entries field of a [`DataType::Map`] with keys of type `key`
+/// and values described by `value`
+pub(crate) fn map_entries_field(key: MapKey, value: Field) -> Field {
+    let key = Field::new("key", key.data_type(), false);
+    let fields = vec![key, value.with_name("value")];
+    Field::new("entries", DataType::Struct(fields.into()), false)
 }
 
 impl Codec {
@@ -139,7 +807,8 @@ impl Codec {
             Self::Float32 => DataType::Float32,
             Self::Float64 => DataType::Float64,
             Self::Binary => DataType::Binary,
-            Self::Utf8 => DataType::Utf8,
+            Self::Utf8 | Self::Json => DataType::Utf8,
+            Self::Uuid => DataType::FixedSizeBinary(16),
             Self::Date32 => DataType::Date32,
             Self::TimeMillis => DataType::Time32(TimeUnit::Millisecond),
             Self::TimeMicros => DataType::Time64(TimeUnit::Microsecond),
@@ -151,14 +820,190 @@ impl Codec {
             }
             Self::Interval => DataType::Interval(IntervalUnit::MonthDayNano),
             Self::Fixed(size) => DataType::FixedSizeBinary(*size),
+            Self::Decimal(precision, scale, _, _) => match *precision <= DECIMAL128_MAX_PRECISION {
+                true => DataType::Decimal128(*precision, *scale),
+                false => DataType::Decimal256(*precision, *scale),
+            },
             Self::List(f) => {
                 DataType::List(Arc::new(f.field_with_name(Field::LIST_FIELD_DEFAULT_NAME)))
             }
+            Self::Map(key, value) => {
+                let entries = map_entries_field(*key, value.field_with_name("value"));
+                DataType::Map(Arc::new(entries), false)
+            }
             Self::Struct(f) => DataType::Struct(f.iter().map(|x| x.field()).collect()),
+            Self::Enum(_) => {
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            }
+            Self::Union(branches) => {
+                let fields = branches.iter().map(|b| b.field());
+                let type_ids = 0..branches.len() as i8;
+                DataType::Union(UnionFields::new(type_ids, fields), UnionMode::Sparse)
+            }
+        }
+    }
+
+    /// Returns the name of the canonical arrow extension type for this codec, if any
+    fn extension_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Uuid => Some(UUID_EXTENSION_NAME),
+            Self::Json => Some(JSON_EXTENSION_NAME),
+            _ => None,
+        }
+    }
+
+    /// Returns the Avro [`Schema`] for this codec, with the provided `attributes`
+    fn to_schema<'a>(&'a self, name: &'a str, attributes: Attributes<'a>) -> Schema<'a> {
+        match self {
+            Self::Null => primitive_schema(PrimitiveType::Null, attributes),
+            Self::Boolean => primitive_schema(PrimitiveType::Boolean, attributes),
+            Self::Int32 => primitive_schema(PrimitiveType::Int, attributes),
+            Self::Int64 => primitive_schema(PrimitiveType::Long, attributes),
+            Self::Float32 => primitive_schema(PrimitiveType::Float, attributes),
+            Self::Float64 => primitive_schema(PrimitiveType::Double, attributes),
+            Self::Binary => primitive_schema(PrimitiveType::Bytes, attributes),
+            Self::Utf8 => primitive_schema(PrimitiveType::String, attributes),
+            Self::Uuid => logical_schema(PrimitiveType::String, "uuid", attributes),
+            Self::Json => logical_schema(PrimitiveType::String, "json", attributes),
+            Self::Date32 => logical_schema(PrimitiveType::Int, "date", attributes),
+            Self::TimeMillis => logical_schema(PrimitiveType::Int, "time-millis", attributes),
+            Self::TimeMicros => logical_schema(PrimitiveType::Long, "time-micros", attributes),
+            Self::TimestampMillis(true) => {
+                logical_schema(PrimitiveType::Long, "timestamp-millis", attributes)
+            }
+            Self::TimestampMillis(false) => {
+                logical_schema(PrimitiveType::Long, "local-timestamp-millis", attributes)
+            }
+            Self::TimestampMicros(true) => {
+                logical_schema(PrimitiveType::Long, "timestamp-micros", attributes)
+            }
+            Self::TimestampMicros(false) => {
+                logical_schema(PrimitiveType::Long, "local-timestamp-micros", attributes)
+            }
+            Self::Fixed(size) => Schema::Complex(ComplexType::Fixed(Fixed {
+                name,
+                namespace: None,
+                aliases: vec![],
+                size: *size as usize,
+                attributes,
+            })),
+            Self::Interval => Schema::Complex(ComplexType::Fixed(Fixed {
+                name,
+                namespace: None,
+                aliases: vec![],
+                size: 12,
+                attributes: Attributes {
+                    logical_type: Some("duration"),
+                    ..attributes
+                },
+            })),
+            Self::Decimal(precision, scale, size, _) => {
+                let mut attributes = Attributes {
+                    logical_type: Some("decimal"),
+                    ..attributes
+                };
+                attributes
+                    .additional
+                    .insert("precision", (*precision).into());
+                attributes.additional.insert("scale", (*scale).into());
+                match size {
+                    Some(size) => Schema::Complex(ComplexType::Fixed(Fixed {
+                        name,
+                        namespace: None,
+                        aliases: vec![],
+                        size: *size,
+                        attributes,
+                    })),
+                    None => Schema::Type(Type {
+                        r#type: TypeName::Primitive(PrimitiveType::Bytes),
+                        attributes,
+                    }),
+                }
+            }
+            Self::List(item) => Schema::Complex(ComplexType::Array(Array {
+                items: Box::new(item.to_schema(name)),
+                attributes,
+            })),
+            // The key type is written as an attribute from the metadata of the map
+            Self::Map(_, values) => Schema::Complex(ComplexType::Map(Map {
+                values: Box::new(values.to_schema(name)),
+                attributes,
+            })),
+            Self::Struct(fields) => Schema::Complex(ComplexType::Record(Record {
+                name,
+                namespace: None,
+                doc: None,
+                aliases: vec![],
+                fields: fields
+                    .iter()
+                    .map(|f| {
+                        let mut attributes = Attributes::default();
+                        if let Some(id) = f.data_type.metadata.get(FIELD_ID_METADATA_KEY) {
+                            let id = serde_json::from_str(id)
+                                .unwrap_or_else(|_| serde_json::Value::String(id.clone()));
+                            attributes.additional.insert(FIELD_ID_ATTRIBUTE, id);
+                        }
+                        if let Some(order) = f.data_type.metadata.get(SORT_ORDER_METADATA_KEY) {
+                            let order = serde_json::Value::String(order.clone());
+                            attributes.additional.insert(SORT_ORDER_ATTRIBUTE, order);
+                        }
+                        AvroSchemaField {
+                            name: &f.name,
+                            doc: None,
+                            r#type: f.data_type.to_schema(&f.name),
+                            default: None,
+                            attributes,
+                        }
+                    })
+                    .collect(),
+                attributes,
+            })),
+            Self::Enum(e) => Schema::Complex(ComplexType::Enum(Enum {
+                name,
+                namespace: None,
+                doc: None,
+                aliases: vec![],
+                symbols: e.symbols.iter().map(|s| s.as_str()).collect(),
+                default: e.default_symbol(),
+                attributes,
+            })),
+            Self::Union(branches) => Schema::Union(
+                branches
+                    .iter()
+                    .map(|b| b.data_type.to_schema(&b.name))
+                    .collect(),
+            ),
         }
     }
 }
 
+/// Returns the [`Schema`] for a [`PrimitiveType`], only using [`Schema::Type`]
+/// if there are `attributes` to encode
+fn primitive_schema(primitive: PrimitiveType, attributes: Attributes<'_>) -> Schema<'_> {
+    match attributes == Attributes::default() {
+        true => Schema::TypeName(TypeName::Primitive(primitive)),
+        false => Schema::Type(Type {
+            r#type: TypeName::Primitive(primitive),
+            attributes,
+        }),
+    }
+}
+
+/// Returns the [`Schema`] for a [`PrimitiveType`] annotated with a logical type
+fn logical_schema<'a>(
+    primitive: PrimitiveType,
+    logical_type: &'a str,
+    attributes: Attributes<'a>,
+) -> Schema<'a> {
+    Schema::Type(Type {
+        r#type: TypeName::Primitive(primitive),
+        attributes: Attributes {
+            logical_type: Some(logical_type),
+            ..attributes
+        },
+    })
+}
+
 impl From<PrimitiveType> for Codec {
     fn from(value: PrimitiveType) -> Self {
         match value {
@@ -184,7 +1029,7 @@ struct Resolver<'a> {
 
 impl<'a> Resolver<'a> {
     fn register(&mut self, name: &'a str, namespace: Option<&'a str>, schema: AvroDataType) {
-        self.map.insert((name, namespace.unwrap_or("")), schema);
+        self.map.insert((namespace.unwrap_or(""), name), schema);
     }
 
     fn resolve(&self, name: &str, namespace: Option<&'a str>) -> Result<AvroDataType, ArrowError> {
@@ -215,6 +1060,7 @@ fn make_data_type<'a>(
             nullability: None,
             metadata: Default::default(),
             codec: (*p).into(),
+            resolution: None,
         }),
         Schema::TypeName(TypeName::Ref(name)) => resolver.resolve(name, namespace),
         Schema::Union(f) => {
@@ -233,9 +1079,28 @@ fn make_data_type<'a>(
                     field.nullability = Some(Nullability::NullSecond);
                     Ok(field)
                 }
-                _ => Err(ArrowError::NotYetImplemented(format!(
-                    "Union of {f:?} not currently supported"
+                _ if f.len() > i8::MAX as usize => Err(ArrowError::ParseError(format!(
+                    "Union with {} branches exceeds the maximum of {}",
+                    f.len(),
+                    i8::MAX
                 ))),
+                _ => {
+                    let branches = f
+                        .iter()
+                        .map(|branch| {
+                            Ok(AvroField {
+                                name: union_branch_name(branch),
+                                data_type: make_data_type(branch, namespace, resolver)?,
+                            })
+                        })
+                        .collect::<Result<_, ArrowError>>()?;
+                    Ok(AvroDataType {
+                        nullability: None,
+                        metadata: Default::default(),
+                        codec: Codec::Union(branches),
+                        resolution: None,
+                    })
+                }
             }
         }
         Schema::Complex(c) => match c {
@@ -245,9 +1110,43 @@ fn make_data_type<'a>(
                     .fields
                     .iter()
                     .map(|field| {
+                        let mut data_type = make_data_type(&field.r#type, namespace, resolver)?;
+                        if let Some(id) = field.attributes.additional.get(FIELD_ID_ATTRIBUTE) {
+                            let id = match id {
+                                serde_json::Value::String(s) => s.clone(),
+                                v => v.to_string(),
+                            };
+                            data_type
+                                .metadata
+                                .insert(FIELD_ID_METADATA_KEY.to_string(), id);
+                        }
+                        if let Some(order) = field.attributes.additional.get(SORT_ORDER_ATTRIBUTE) {
+                            let order = match order.as_str() {
+                                Some(o @ ("ascending" | "descending" | "ignore")) => o,
+                                _ => {
+                                    return Err(ArrowError::ParseError(format!(
+                                        "Invalid sort order for field {}, got {order}",
+                                        field.name
+                                    )))
+                                }
+                            };
+                            data_type
+                                .metadata
+                                .insert(SORT_ORDER_METADATA_KEY.to_string(), order.to_string());
+                            let nulls = match data_type.nullability {
+                                Some(Nullability::NullFirst) => Some("first"),
+                                Some(Nullability::NullSecond) => Some("last"),
+                                None => None,
+                            };
+                            if let Some(nulls) = nulls {
+                                data_type
+                                    .metadata
+                                    .insert(SORT_NULLS_METADATA_KEY.to_string(), nulls.to_string());
+                            }
+                        }
                         Ok(AvroField {
                             name: field.name.to_string(),
-                            data_type: make_data_type(&field.r#type, namespace, resolver)?,
+                            data_type,
                         })
                     })
                     .collect::<Result<_, ArrowError>>()?;
@@ -255,6 +1154,7 @@ fn make_data_type<'a>(
                 let field = AvroDataType {
                     nullability: None,
                     codec: Codec::Struct(fields),
+                    resolution: None,
                     metadata: r.attributes.field_metadata(),
                 };
                 resolver.register(r.name, namespace, field.clone());
@@ -266,60 +1166,67 @@ fn make_data_type<'a>(
                     nullability: None,
                     metadata: a.attributes.field_metadata(),
                     codec: Codec::List(Arc::new(field)),
+                    resolution: None,
                 })
             }
             ComplexType::Fixed(f) => {
+                let namespace = f.namespace.or(namespace);
                 let size = f.size.try_into().map_err(|e| {
                     ArrowError::ParseError(format!("Overflow converting size to i32: {e}"))
                 })?;
 
-                let field = AvroDataType {
+                let mut field = AvroDataType {
                     nullability: None,
                     metadata: f.attributes.field_metadata(),
                     codec: Codec::Fixed(size),
+                    resolution: None,
                 };
+                apply_logical_type(&f.attributes, &mut field)?;
+                if matches!(field.codec, Codec::Decimal(..)) {
+                    field.metadata.remove("precision");
+                    field.metadata.remove("scale");
+                }
                 resolver.register(f.name, namespace, field.clone());
                 Ok(field)
             }
-            ComplexType::Enum(e) => Err(ArrowError::NotYetImplemented(format!(
-                "Enum of {e:?} not currently supported"
-            ))),
-            ComplexType::Map(m) => Err(ArrowError::NotYetImplemented(format!(
-                "Map of {m:?} not currently supported"
-            ))),
+            ComplexType::Enum(e) => {
+                let namespace = e.namespace.or(namespace);
+                let field = AvroDataType {
+                    nullability: None,
+                    metadata: e.attributes.field_metadata(),
+                    codec: Codec::Enum(AvroEnum::try_new(&e.symbols, e.default)?),
+                    resolution: None,
+                };
+                resolver.register(e.name, namespace, field.clone());
+                Ok(field)
+            }
+            ComplexType::Map(m) => {
+                let values = make_data_type(m.values.as_ref(), namespace, resolver)?;
+                let key = match m.attributes.additional.get(MAP_KEY_TYPE_ATTRIBUTE) {
+                    Some(v) => MapKey::try_from_attribute(v)?,
+                    None => MapKey::Utf8,
+                };
+                Ok(AvroDataType {
+                    nullability: None,
+                    metadata: m.attributes.field_metadata(),
+                    codec: Codec::Map(key, Arc::new(values)),
+                    resolution: None,
+                })
+            }
         },
         Schema::Type(t) => {
             let mut field =
                 make_data_type(&Schema::TypeName(t.r#type.clone()), namespace, resolver)?;
 
-            // https://avro.apache.org/docs/1.11.1/specification/#logical-types
-            match (t.attributes.logical_type, &mut field.codec) {
-                (Some("decimal"), c @ Codec::Fixed(_)) => {
-                    return Err(ArrowError::NotYetImplemented(
-                        "Decimals are not currently supported".to_string(),
-                    ))
-                }
-                (Some("date"), c @ Codec::Int32) => *c = Codec::Date32,
-                (Some("time-millis"), c @ Codec::Int32) => *c = Codec::TimeMillis,
-                (Some("time-micros"), c @ Codec::Int64) => *c = Codec::TimeMicros,
-                (Some("timestamp-millis"), c @ Codec::Int64) => *c = Codec::TimestampMillis(true),
-                (Some("timestamp-micros"), c @ Codec::Int64) => *c = Codec::TimestampMicros(true),
-                (Some("local-timestamp-millis"), c @ Codec::Int64) => {
-                    *c = Codec::TimestampMillis(false)
-                }
-                (Some("local-timestamp-micros"), c @ Codec::Int64) => {
-                    *c = Codec::TimestampMicros(false)
-                }
-                (Some("duration"), c @ Codec::Fixed(12)) => *c = Codec::Interval,
-                (Some(logical), _) => {
-                    // Insert unrecognized logical type into metadata map
-                    field.metadata.insert("logicalType".into(), logical.into());
-                }
-                (None, _) => {}
-            }
+            apply_logical_type(&t.attributes, &mut field)?;
 
             if !t.attributes.additional.is_empty() {
                 for (k, v) in &t.attributes.additional {
+                    if matches!(field.codec, Codec::Decimal(..))
+                        && matches!(*k, "precision" | "scale")
+                    {
+                        continue;
+                    }
                     field.metadata.insert(k.to_string(), v.to_string());
                 }
             }
@@ -327,3 +1234,394 @@ fn make_data_type<'a>(
         }
     }
 }
+
+/// Returns the name of the union branch `schema`, the name of its type, or of the
+/// named type it refers to or defines
+fn union_branch_name(schema: &Schema<'_>) -> String {
+    match schema {
+        Schema::TypeName(TypeName::Primitive(p))
+        | Schema::Type(Type {
+            r#type: TypeName::Primitive(p),
+            ..
+        }) => match serde_json::to_value(p) {
+            Ok(serde_json::Value::String(name)) => name,
+            _ => unreachable!("primitive types serialize as strings"),
+        },
+        Schema::TypeName(TypeName::Ref(name))
+        | Schema::Type(Type {
+            r#type: TypeName::Ref(name),
+            ..
+        }) => name.to_string(),
+        Schema::Complex(ComplexType::Record(r)) => r.name.to_string(),
+        Schema::Complex(ComplexType::Enum(e)) => e.name.to_string(),
+        Schema::Complex(ComplexType::Fixed(f)) => f.name.to_string(),
+        Schema::Complex(ComplexType::Array(_)) => "array".to_string(),
+        Schema::Complex(ComplexType::Map(_)) => "map".to_string(),
+        Schema::Union(_) => "union".to_string(),
+    }
+}
+
+/// Applies the logical type of `attributes`, if any, to `field`
+///
+/// This is applied to both primitive and fixed types, wherever they occur, e.g. as
+/// the items of an array or a field of a nested record
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#logical-types>
+fn apply_logical_type(
+    attributes: &Attributes<'_>,
+    field: &mut AvroDataType,
+) -> Result<(), ArrowError> {
+    match (attributes.logical_type, &mut field.codec) {
+        (Some("decimal"), c @ Codec::Binary) => *c = make_decimal(attributes, None)?,
+        (Some("decimal"), c @ Codec::Fixed(_)) => {
+            let Codec::Fixed(size) = *c else {
+                unreachable!()
+            };
+            *c = make_decimal(attributes, Some(size as usize))?
+        }
+        (Some("uuid"), c @ Codec::Utf8) => *c = Codec::Uuid,
+        (Some("json"), c @ (Codec::Utf8 | Codec::Binary)) => *c = Codec::Json,
+        (Some("date"), c @ Codec::Int32) => *c = Codec::Date32,
+        (Some("time-millis"), c @ Codec::Int32) => *c = Codec::TimeMillis,
+        (Some("time-micros"), c @ Codec::Int64) => *c = Codec::TimeMicros,
+        (Some("timestamp-millis"), c @ Codec::Int64) => *c = Codec::TimestampMillis(true),
+        (Some("timestamp-micros"), c @ Codec::Int64) => *c = Codec::TimestampMicros(true),
+        (Some("local-timestamp-millis"), c @ Codec::Int64) => *c = Codec::TimestampMillis(false),
+        (Some("local-timestamp-micros"), c @ Codec::Int64) => *c = Codec::TimestampMicros(false),
+        (Some("duration"), c @ Codec::Fixed(12)) => *c = Codec::Interval,
+        (Some(logical), _) => {
+            // Insert unrecognized logical type into metadata map
+            field.metadata.insert("logicalType".into(), logical.into());
+        }
+        (None, _) => {}
+    }
+    Ok(())
+}
+
+/// Returns the [`Codec::Decimal`] for a decimal logical type with the given `attributes`
+///
+/// `size` is the size of the underlying fixed type, or `None` for bytes
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#decimal>
+fn make_decimal(attributes: &Attributes<'_>, size: Option<usize>) -> Result<Codec, ArrowError> {
+    let get = |key: &str| {
+        attributes
+            .additional
+            .get(key)
+            .map(|v| {
+                v.as_u64().ok_or_else(|| {
+                    ArrowError::ParseError(format!("Invalid decimal {key}, got {v}"))
+                })
+            })
+            .transpose()
+    };
+
+    let precision = get("precision")?
+        .ok_or_else(|| ArrowError::ParseError("Decimal is missing precision".to_string()))?;
+    let scale = get("scale")?.unwrap_or_default();
+
+    if precision == 0 || precision > DECIMAL256_MAX_PRECISION as u64 {
+        return Err(ArrowError::ParseError(format!(
+            "Decimal precision must be between 1 and {DECIMAL256_MAX_PRECISION}, got {precision}"
+        )));
+    }
+    if scale > precision {
+        return Err(ArrowError::ParseError(format!(
+            "Decimal scale {scale} cannot exceed precision {precision}"
+        )));
+    }
+    if let Some(size) = size {
+        // The maximum number of base-10 digits that can be stored in `size` bytes
+        let max = ((8. * size as f64 - 1.) * 2_f64.log10()).floor() as u64;
+        if precision > max {
+            return Err(ArrowError::ParseError(format!(
+                "Decimal precision {precision} cannot be stored in fixed of size {size}"
+            )));
+        }
+    }
+    Ok(Codec::Decimal(precision as u8, scale as i8, size, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::Fields;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_schema() {
+        let json = json!({
+            "type": "record",
+            "name": "topLevelRecord",
+            "fields": [
+                {"name": "id", "type": ["int", "null"]},
+                {"name": "name", "type": ["null", "string"]},
+                {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                {"name": "date", "type": {"type": "int", "logicalType": "date"}},
+                {"name": "local", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+                {"name": "hash", "type": {"type": "fixed", "name": "hash", "size": 16}},
+                {"name": "list", "type": {"type": "array", "items": "double"}},
+                {"name": "custom", "type": {"type": "string", "logicalType": "custom", "foo": 1}},
+                {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+                {"name": "key", "type": "long", "field-id": 7},
+                {"name": "seq", "type": ["null", "long"], "order": "descending"},
+                {"name": "rank", "type": ["long", "null"], "order": "ascending"},
+                {"name": "note", "type": "string", "order": "ignore"},
+                {
+                    "name": "amount",
+                    "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}
+                },
+                {
+                    "name": "large",
+                    "type": {
+                        "type": "fixed",
+                        "name": "large",
+                        "size": 32,
+                        "logicalType": "decimal",
+                        "precision": 50,
+                        "scale": 0
+                    }
+                },
+                {"name": "json", "type": ["null", {"type": "string", "logicalType": "json"}]},
+                {"name": "loose", "type": ["string", "long", {"type": "fixed", "name": "f", "size": 2}]},
+                {
+                    "name": "suit",
+                    "type": {
+                        "type": "enum",
+                        "name": "suit",
+                        "symbols": ["SPADES", "HEARTS", "UNKNOWN"],
+                        "default": "UNKNOWN"
+                    }
+                },
+                {
+                    "name": "nested",
+                    "type": {
+                        "type": "record",
+                        "name": "nested",
+                        "fields": [{"name": "flag", "type": "boolean"}]
+                    }
+                }
+            ]
+        });
+
+        let s = json.to_string();
+        let schema: Schema = serde_json::from_str(&s).unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        assert_eq!(serde_json::to_value(&field).unwrap(), json);
+
+        // Round trip through the serialized schema
+        let serialized = serde_json::to_string(&field).unwrap();
+        let schema: Schema = serde_json::from_str(&serialized).unwrap();
+        let roundtrip = AvroField::try_from(&schema).unwrap();
+        assert_eq!(roundtrip.field(), field.field());
+
+        let DataType::Struct(fields) = field.field().data_type().clone() else {
+            unreachable!()
+        };
+        let key = fields.find("key").unwrap().1;
+        assert_eq!(key.metadata()[FIELD_ID_METADATA_KEY], "7");
+        assert_eq!(sort_options(key), Some(SortOptions::default()));
+        let seq = fields.find("seq").unwrap().1;
+        assert_eq!(seq.metadata()[SORT_ORDER_METADATA_KEY], "descending");
+        let expected = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        assert_eq!(sort_options(seq), Some(expected));
+        // Nulls are last in ascending order with `null` the second branch of the union
+        let rank = fields.find("rank").unwrap().1;
+        let expected = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        assert_eq!(sort_options(rank), Some(expected));
+        let note = fields.find("note").unwrap().1;
+        assert_eq!(sort_options(note), None);
+        let amount = fields.find("amount").unwrap().1;
+        assert_eq!(amount.data_type(), &DataType::Decimal128(10, 2));
+        let large = fields.find("large").unwrap().1;
+        assert_eq!(large.data_type(), &DataType::Decimal256(50, 0));
+        let loose = fields.find("loose").unwrap().1;
+        let DataType::Union(branches, _) = loose.data_type() else {
+            unreachable!()
+        };
+        let names: Vec<_> = branches.iter().map(|(_, f)| f.name().as_str()).collect();
+        assert_eq!(names, ["string", "long", "f"]);
+        let suit = fields.find("suit").unwrap().1;
+        assert_eq!(
+            suit.metadata()[ENUM_SYMBOLS_METADATA_KEY],
+            r#"["SPADES","HEARTS","UNKNOWN"]"#
+        );
+        assert_eq!(suit.metadata()[ENUM_DEFAULT_METADATA_KEY], "UNKNOWN");
+        assert_eq!(enum_symbols(suit).unwrap(), ["SPADES", "HEARTS", "UNKNOWN"]);
+        assert_eq!(enum_symbol_mapping(suit), None);
+    }
+
+    #[test]
+    fn test_nested_logical_types() {
+        let json = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "durations", "type": {"type": "array", "items": {
+                    "type": "fixed", "name": "duration", "size": 12, "logicalType": "duration"
+                }}},
+                {"name": "dates", "type": {"type": "array", "items": ["null", {
+                    "type": "int", "logicalType": "date"
+                }]}},
+                {"name": "nested", "type": {
+                    "type": "record",
+                    "name": "nested",
+                    "fields": [
+                        {"name": "elapsed", "type": "duration"},
+                        {"name": "amount", "type": {
+                            "type": "fixed", "name": "amount", "size": 8, "logicalType": "decimal", "precision": 10
+                        }},
+                        {"name": "other", "type": {
+                            "type": "fixed", "name": "other", "size": 4, "logicalType": "custom"
+                        }}
+                    ]
+                }}
+            ]
+        }"#;
+        let schema: Schema = serde_json::from_str(json).unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+
+        let interval = DataType::Interval(IntervalUnit::MonthDayNano);
+        let nested = Fields::from(vec![
+            Field::new("elapsed", interval.clone(), false),
+            Field::new("amount", DataType::Decimal128(10, 0), false),
+            Field::new("other", DataType::FixedSizeBinary(4), false).with_metadata(HashMap::from(
+                [("logicalType".to_string(), "custom".to_string())],
+            )),
+        ]);
+        let expected = Fields::from(vec![
+            Field::new_list("durations", Field::new_list_field(interval, false), false),
+            Field::new_list(
+                "dates",
+                Field::new_list_field(DataType::Date32, true),
+                false,
+            ),
+            Field::new_struct("nested", nested, false),
+        ]);
+        assert_eq!(field.field().data_type(), &DataType::Struct(expected));
+
+        // Round trip through the serialized schema
+        let serialized = serde_json::to_string(&field).unwrap();
+        let schema: Schema = serde_json::from_str(&serialized).unwrap();
+        let roundtrip = AvroField::try_from(&schema).unwrap();
+        assert_eq!(roundtrip.field(), field.field());
+    }
+
+    #[test]
+    fn test_json() {
+        let json = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "bytes", "logicalType": "json"}},
+                {"name": "b", "type": {"type": "string", "connect.name": "json"}},
+                {"name": "c", "type": {"type": "array", "items": {
+                    "type": "bytes", "connect.name": "json"
+                }}},
+                {"name": "d", "type": {"type": "string", "connect.name": "other"}},
+                {"name": "e", "type": {"type": "map", "values": {
+                    "type": "bytes", "connect.name": "json"
+                }}}
+            ]
+        }"#;
+        let schema: Schema = serde_json::from_str(json).unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        let extension = |field: &AvroField, idx: usize| {
+            let DataType::Struct(fields) = field.field().data_type().clone() else {
+                unreachable!()
+            };
+            let field = match fields[idx].data_type() {
+                DataType::List(item) => item.clone(),
+                DataType::Map(entries, _) => match entries.data_type() {
+                    DataType::Struct(entries) => entries[1].clone(),
+                    _ => unreachable!(),
+                },
+                _ => fields[idx].clone(),
+            };
+            field.metadata().get(EXTENSION_TYPE_NAME_KEY).cloned()
+        };
+        assert_eq!(extension(&field, 0).unwrap(), JSON_EXTENSION_NAME);
+        let a = field.field();
+        let DataType::Struct(fields) = a.data_type() else {
+            unreachable!()
+        };
+        assert_eq!(fields[0].data_type(), &DataType::Utf8);
+        assert!(extension(&field, 1).is_none());
+
+        let field = field.with_json_attribute("connect.name", "json");
+        for idx in [0, 1, 2, 4] {
+            assert_eq!(extension(&field, idx).unwrap(), JSON_EXTENSION_NAME);
+        }
+        assert!(extension(&field, 3).is_none());
+        let Codec::Struct(fields) = field.data_type().codec() else {
+            unreachable!()
+        };
+        assert!(matches!(fields[1].data_type().codec(), Codec::Json));
+    }
+
+    #[test]
+    fn test_resolve_same_type() {
+        let writer = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": "string"},
+                {"name": "b", "type": {"type": "fixed", "name": "b", "size": 16}}
+            ]
+        }"#;
+        let reader = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "string", "logicalType": "json", "x": "y"}},
+                {"name": "b", "type": {"type": "fixed", "name": "b", "size": 16, "x": "z"}}
+            ]
+        }"#;
+        let writer: Schema = serde_json::from_str(writer).unwrap();
+        let reader: Schema = serde_json::from_str(reader).unwrap();
+        let resolved = AvroField::resolve(&writer, &reader, &Default::default()).unwrap();
+        let expected = AvroField::try_from(&reader).unwrap();
+        assert_eq!(resolved.field(), expected.field());
+        let Codec::Struct(fields) = resolved.data_type().codec() else {
+            unreachable!()
+        };
+        assert!(matches!(fields[0].data_type().codec(), Codec::Json));
+        assert!(matches!(fields[1].data_type().codec(), Codec::Fixed(16)));
+    }
+
+    #[test]
+    fn test_invalid_decimal() {
+        let cases = [
+            (
+                r#"{"type": "bytes", "logicalType": "decimal"}"#,
+                "Parser error: Decimal is missing precision",
+            ),
+            (
+                r#"{"type": "bytes", "logicalType": "decimal", "precision": 2, "scale": 3}"#,
+                "Parser error: Decimal scale 3 cannot exceed precision 2",
+            ),
+            (
+                r#"{"type": "bytes", "logicalType": "decimal", "precision": 77}"#,
+                "Parser error: Decimal precision must be between 1 and 76, got 77",
+            ),
+            (
+                r#"{"type": "fixed", "name": "f", "size": 4, "logicalType": "decimal", "precision": 10}"#,
+                "Parser error: Decimal precision 10 cannot be stored in fixed of size 4",
+            ),
+        ];
+        for (field_type, expected) in cases {
+            let json = format!(
+                r#"{{"type": "record", "name": "r", "fields": [{{"name": "d", "type": {field_type}}}]}}"#
+            );
+            let schema: Schema = serde_json::from_str(&json).unwrap();
+            let err = AvroField::try_from(&schema).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+}
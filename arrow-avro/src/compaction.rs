@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compaction of many small Avro
+//! [Object Container Files](https://avro.apache.org/docs/1.11.1/specification/#object-container-files)
+//! into one, without decoding their records
+
+use crate::compression::{CompressionCodec, CODEC_METADATA_KEY};
+use crate::reader::{BlockReader, CorruptBlockError, MAGIC};
+use crate::writer::{encode_bytes, encode_long};
+use arrow_schema::ArrowError;
+use std::io::{BufRead, Write};
+
+/// Concatenates Avro object container files with identical schemas into a single file
+/// written to `output`, returning the total number of records written
+///
+/// The blocks of each file are copied without decoding their records. Blocks already
+/// compressed with `codec` are copied verbatim, with all others decompressed and then
+/// compressed with `codec`, or written uncompressed if `None`.
+///
+/// The header metadata and sync marker of the output are taken from the first file.
+/// Returns an error if there are no files, or if the schemas of the files differ
+///
+/// ```
+/// # use arrow_avro::compaction::concat_files;
+/// # use arrow_schema::ArrowError;
+/// fn compact(files: &[Vec<u8>]) -> Result<Vec<u8>, ArrowError> {
+///     let mut out = vec![];
+///     concat_files(files.iter().map(|f| f.as_slice()), None, &mut out)?;
+///     Ok(out)
+/// }
+/// ```
+pub fn concat_files<R: BufRead, W: Write>(
+    inputs: impl IntoIterator<Item = R>,
+    codec: Option<CompressionCodec>,
+    mut output: W,
+) -> Result<usize, ArrowError> {
+    let mut inputs = inputs.into_iter();
+    let first = inputs.next().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("No Avro files to concatenate".to_string())
+    })?;
+    let first = BlockReader::try_new(first)?;
+    let header = first.header().clone();
+    let schema = header.schema()?.ok_or_else(|| {
+        ArrowError::ParseError("No Avro schema present in file header".to_string())
+    })?;
+    let sync = header.sync();
+
+    let metadata: Vec<_> = header
+        .metadata()
+        .filter(|(k, _)| *k != CODEC_METADATA_KEY.as_bytes())
+        .collect();
+    let mut out = MAGIC.to_vec();
+    encode_long(&mut out, metadata.len() as i64 + 1);
+    for (k, v) in metadata {
+        encode_bytes(&mut out, k);
+        encode_bytes(&mut out, v);
+    }
+    encode_bytes(&mut out, CODEC_METADATA_KEY.as_bytes());
+    let codec_name = codec.map(|c| c.name()).unwrap_or("null");
+    encode_bytes(&mut out, codec_name.as_bytes());
+    encode_long(&mut out, 0);
+    out.extend_from_slice(&sync);
+    output.write_all(&out)?;
+
+    let mut count = copy_blocks(first, codec, &sync, &mut output)?;
+    for input in inputs {
+        let reader = BlockReader::try_new(input)?;
+        if reader.header().schema()?.as_ref() != Some(&schema) {
+            return Err(ArrowError::SchemaError(
+                "Cannot concatenate Avro files with different schemas".to_string(),
+            ));
+        }
+        count += copy_blocks(reader, codec, &sync, &mut output)?;
+    }
+    output.flush()?;
+    Ok(count)
+}
+
+/// Copies the blocks of `reader` to `output`, compressed with `codec`, returning
+/// the number of records copied
+fn copy_blocks<R: BufRead, W: Write>(
+    reader: BlockReader<R>,
+    codec: Option<CompressionCodec>,
+    sync: &[u8; 16],
+    output: &mut W,
+) -> Result<usize, ArrowError> {
+    let input_codec = reader.header().compression()?;
+    let mut count = 0;
+    let mut prefix = Vec::with_capacity(20);
+    for block in reader {
+        let block = block?;
+        let data = match (input_codec, codec) {
+            (input, output) if input == output => block.data,
+            (input, output) => {
+                let data = match input {
+                    Some(c) => c
+                        .decompress(&block.data, true)
+                        .map_err(|e| CorruptBlockError::new(block.offset, e.to_string()))?,
+                    None => block.data,
+                };
+                match output {
+                    Some(c) => c.compress(&data)?,
+                    None => data,
+                }
+            }
+        };
+        prefix.clear();
+        encode_long(&mut prefix, block.count as i64);
+        encode_long(&mut prefix, data.len() as i64);
+        output.write_all(&prefix)?;
+        output.write_all(&data)?;
+        output.write_all(sync)?;
+        count += block.count;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reader::{BlockReader, ReaderBuilder};
+    use crate::test_util::{encode_long, write_ocf, write_ocf_with_metadata};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+
+    const SCHEMA: &str =
+        r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+
+    fn block(ids: &[i64]) -> (usize, Vec<u8>) {
+        let mut data = vec![];
+        ids.iter().for_each(|x| encode_long(&mut data, *x));
+        (ids.len(), data)
+    }
+
+    fn read_ids(file: &[u8]) -> Vec<i64> {
+        let reader = ReaderBuilder::new().build(file).unwrap();
+        reader
+            .flat_map(|b| {
+                let b = b.unwrap();
+                b.column(0).as_primitive::<Int64Type>().values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_files() {
+        let files = [
+            write_ocf_with_metadata(SCHEMA, &[("key", b"value")], &[block(&[1, 2])]),
+            write_ocf(SCHEMA, &[block(&[3]), block(&[4, 5])]),
+        ];
+        let mut out = vec![];
+        let count = concat_files(files.iter().map(|f| f.as_slice()), None, &mut out).unwrap();
+        assert_eq!(count, 5);
+        assert_eq!(read_ids(&out), [1, 2, 3, 4, 5]);
+
+        let reader = ReaderBuilder::new().build(out.as_slice()).unwrap();
+        assert_eq!(reader.metadata()["key"], "value");
+        let blocks = BlockReader::try_new(out.as_slice()).unwrap();
+        assert_eq!(blocks.count(), 3);
+
+        let other = r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "int"}]}"#;
+        let files = [files[0].clone(), write_ocf(other, &[block(&[6])])];
+        let err = concat_files(files.iter().map(|f| f.as_slice()), None, vec![]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Cannot concatenate Avro files with different schemas"
+        );
+
+        let err = concat_files(Vec::<&[u8]>::new(), None, vec![]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: No Avro files to concatenate"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "snappy", feature = "zstd"))]
+    fn test_concat_files_recompress() {
+        let snappy = CompressionCodec::Snappy;
+        let compressed = snappy.compress(&block(&[3, 4]).1).unwrap();
+        let metadata: &[(&str, &[u8])] = &[("avro.codec", b"snappy")];
+        let files = [
+            write_ocf(SCHEMA, &[block(&[1, 2])]),
+            write_ocf_with_metadata(SCHEMA, metadata, &[(2, compressed.clone())]),
+        ];
+
+        // Blocks already compressed with the output codec are copied verbatim
+        let mut out = vec![];
+        concat_files(files.iter().map(|f| f.as_slice()), Some(snappy), &mut out).unwrap();
+        assert_eq!(read_ids(&out), [1, 2, 3, 4]);
+        let blocks: Vec<_> = BlockReader::try_new(out.as_slice())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(blocks[1].data, compressed);
+
+        let zstd = Some(CompressionCodec::ZStandard);
+        let mut out = vec![];
+        concat_files(files.iter().map(|f| f.as_slice()), zstd, &mut out).unwrap();
+        assert_eq!(read_ids(&out), [1, 2, 3, 4]);
+        let reader = BlockReader::try_new(out.as_slice()).unwrap();
+        assert_eq!(reader.header().compression().unwrap(), zstd);
+    }
+}
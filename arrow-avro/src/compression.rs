@@ -22,15 +22,24 @@ use std::io::Read;
 /// The metadata key used for storing the JSON encoded [`CompressionCodec`]
 pub const CODEC_METADATA_KEY: &str = "avro.codec";
 
+/// The compression codec of the blocks of an Avro object container file
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CompressionCodec {
+    /// Deflate, requires the `deflate` feature
     Deflate,
+    /// Snappy, with a CRC32 checksum, requires the `snappy` feature
     Snappy,
+    /// ZStandard, requires the `zstd` feature
     ZStandard,
 }
 
 impl CompressionCodec {
-    pub(crate) fn decompress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    /// Decompress `block`, verifying its checksum, if any, if `verify_checksums` is true
+    pub(crate) fn decompress(
+        &self,
+        block: &[u8],
+        verify_checksums: bool,
+    ) -> Result<Vec<u8>, ArrowError> {
         match self {
             #[cfg(feature = "deflate")]
             CompressionCodec::Deflate => {
@@ -47,6 +56,11 @@ impl CompressionCodec {
             CompressionCodec::Snappy => {
                 // Each compressed block is followed by the 4-byte, big-endian CRC32
                 // checksum of the uncompressed data in the block.
+                if block.len() < 4 {
+                    return Err(ArrowError::ParseError(
+                        "Snappy block is missing CRC".to_string(),
+                    ));
+                }
                 let crc = &block[block.len() - 4..];
                 let block = &block[..block.len() - 4];
 
@@ -56,7 +70,7 @@ impl CompressionCodec {
                     .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
 
                 let checksum = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(&decoded);
-                if checksum != u32::from_be_bytes(crc.try_into().unwrap()) {
+                if verify_checksums && checksum != u32::from_be_bytes(crc.try_into().unwrap()) {
                     return Err(ArrowError::ParseError("Snappy CRC mismatch".to_string()));
                 }
                 Ok(decoded)
@@ -79,4 +93,52 @@ impl CompressionCodec {
             )),
         }
     }
+
+    /// Compress `block`
+    pub(crate) fn compress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            CompressionCodec::Deflate => {
+                let mut encoder = flate2::read::DeflateEncoder::new(block, Default::default());
+                let mut out = Vec::new();
+                encoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            #[cfg(not(feature = "deflate"))]
+            CompressionCodec::Deflate => Err(ArrowError::ParseError(
+                "Deflate codec requires deflate feature".to_string(),
+            )),
+            #[cfg(feature = "snappy")]
+            CompressionCodec::Snappy => {
+                let mut encoder = snap::raw::Encoder::new();
+                let mut out = encoder
+                    .compress_vec(block)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+
+                let checksum = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(block);
+                out.extend_from_slice(&checksum.to_be_bytes());
+                Ok(out)
+            }
+            #[cfg(not(feature = "snappy"))]
+            CompressionCodec::Snappy => Err(ArrowError::ParseError(
+                "Snappy codec requires snappy feature".to_string(),
+            )),
+
+            #[cfg(feature = "zstd")]
+            CompressionCodec::ZStandard => Ok(zstd::encode_all(block, 0)?),
+            #[cfg(not(feature = "zstd"))]
+            CompressionCodec::ZStandard => Err(ArrowError::ParseError(
+                "ZStandard codec requires zstd feature".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the name of this codec, as stored under [`CODEC_METADATA_KEY`]
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            CompressionCodec::Deflate => "deflate",
+            CompressionCodec::Snappy => "snappy",
+            CompressionCodec::ZStandard => "zstandard",
+        }
+    }
 }
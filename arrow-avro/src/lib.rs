@@ -23,19 +23,14 @@
 #![warn(missing_docs)]
 #![allow(unused)] // Temporary
 
+pub mod compaction;
 pub mod reader;
-mod schema;
+pub mod schema;
+pub mod writer;
 
 mod compression;
 
 mod codec;
 
-#[cfg(test)]
-mod test_util {
-    pub fn arrow_test_data(path: &str) -> String {
-        match std::env::var("ARROW_TEST_DATA") {
-            Ok(dir) => format!("{dir}/{path}"),
-            Err(_) => format!("../testing/data/{path}"),
-        }
-    }
-}
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_util;
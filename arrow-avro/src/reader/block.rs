@@ -17,8 +17,12 @@
 
 //! Decoder for [`Block`]
 
+use crate::reader::header::Header;
+use crate::reader::read_header;
 use crate::reader::vlq::VLQDecoder;
 use arrow_schema::ArrowError;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 
 /// A file data block
 ///
@@ -33,6 +37,58 @@ pub struct Block {
     pub sync: [u8; 16],
 }
 
+/// An error returned when a block of an Avro object container file is corrupt,
+/// such as when its sync marker does not match the file header, or its checksum
+/// does not match its contents
+///
+/// Returned as [`ArrowError::ExternalError`], from which it can be recovered with
+/// `downcast_ref`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptBlockError {
+    offset: u64,
+    reason: String,
+}
+
+impl CorruptBlockError {
+    pub(crate) fn new(offset: u64, reason: impl Into<String>) -> Self {
+        Self {
+            offset,
+            reason: reason.into(),
+        }
+    }
+
+    /// The byte offset of the start of the corrupt block within the file
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// A description of why the block is corrupt
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for CorruptBlockError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Corrupt Avro block at byte offset {}: {}",
+            self.offset, self.reason
+        )
+    }
+}
+
+impl std::error::Error for CorruptBlockError {}
+
+impl From<CorruptBlockError> for ArrowError {
+    fn from(e: CorruptBlockError) -> Self {
+        ArrowError::ExternalError(Box::new(e))
+    }
+}
+
+/// The maximum number of bytes to reserve for a block based on its encoded size
+const MAX_BLOCK_RESERVATION: usize = 8 * 1024 * 1024;
+
 /// A decoder for [`Block`]
 #[derive(Debug)]
 pub struct BlockDecoder {
@@ -98,7 +154,10 @@ impl BlockDecoder {
                             ))
                         })?;
 
-                        self.in_progress.data.reserve(self.bytes_remaining);
+                        // The size is untrusted, so avoid reserving more than a bounded
+                        // amount up front, with the remainder growing as data arrives
+                        let reserve = self.bytes_remaining.min(MAX_BLOCK_RESERVATION);
+                        self.in_progress.data.reserve(reserve);
                         self.state = BlockDecoderState::Data;
                     }
                 }
@@ -114,7 +173,7 @@ impl BlockDecoder {
                 }
                 BlockDecoderState::Sync => {
                     let to_decode = buf.len().min(self.bytes_remaining);
-                    let write = &mut self.in_progress.sync[16 - to_decode..];
+                    let write = &mut self.in_progress.sync[16 - self.bytes_remaining..];
                     write[..to_decode].copy_from_slice(&buf[..to_decode]);
                     self.bytes_remaining -= to_decode;
                     buf = &buf[to_decode..];
@@ -138,4 +197,144 @@ impl BlockDecoder {
             _ => None,
         }
     }
+
+    /// Returns `true` if part of a [`Block`] has been decoded, that cannot yet
+    /// be returned by [`Self::flush`]
+    pub fn in_progress(&self) -> bool {
+        match self.state {
+            BlockDecoderState::Count => self.vlq_decoder.in_progress(),
+            BlockDecoderState::Finished => false,
+            _ => true,
+        }
+    }
+}
+
+/// A block of an Avro object container file read by [`BlockReader`], with its
+/// contents neither decompressed nor decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawBlock {
+    /// The number of objects in this block
+    pub count: usize,
+    /// The serialized objects within this block, compressed with the
+    /// [`CompressionCodec`](crate::reader::CompressionCodec) of the file, if any
+    pub data: Vec<u8>,
+    /// The byte offset of the start of this block within the file
+    pub offset: u64,
+}
+
+/// Reads the [`RawBlock`]s of an Avro
+/// [Object Container File](https://avro.apache.org/docs/1.11.1/specification/#object-container-files)
+/// without decoding them
+///
+/// This allows cheaply counting the rows of a file, splitting it, or copying blocks
+/// verbatim to another file with the same [`Header`], for example when compacting files.
+/// The sync marker of each block is verified against the [`Header`]
+///
+/// ```
+/// # use arrow_avro::reader::BlockReader;
+/// # use arrow_schema::ArrowError;
+/// fn count_rows(file: &[u8]) -> Result<usize, ArrowError> {
+///     BlockReader::try_new(file)?.map(|b| b.map(|b| b.count)).sum()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BlockReader<R> {
+    reader: R,
+    header: Header,
+    /// The byte offset in the file of the next byte to be read from `reader`
+    offset: u64,
+    decoder: BlockDecoder,
+}
+
+impl<R: BufRead> BlockReader<R> {
+    /// Create a new [`BlockReader`], reading the [`Header`] from `reader`
+    pub fn try_new(mut reader: R) -> Result<Self, ArrowError> {
+        let (header, offset) = read_header(&mut reader)?;
+        Ok(Self {
+            reader,
+            header,
+            offset,
+            decoder: Default::default(),
+        })
+    }
+
+    /// Returns the [`Header`] of the file
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns a mutable reference to the underlying reader
+    pub(crate) fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Read the next [`RawBlock`], returning `None` at the end of the file
+    fn read(&mut self) -> Result<Option<RawBlock>, ArrowError> {
+        let offset = self.offset;
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            let read = buf.len();
+            let decoded = self.decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            self.offset += decoded as u64;
+            if decoded != read {
+                break;
+            }
+        }
+
+        let Some(block) = self.decoder.flush() else {
+            if self.decoder.in_progress() {
+                let reason = "unexpected end of file";
+                return Err(CorruptBlockError::new(offset, reason).into());
+            }
+            return Ok(None);
+        };
+        if block.sync != self.header.sync() {
+            let reason = "sync marker does not match file header";
+            return Err(CorruptBlockError::new(offset, reason).into());
+        }
+        Ok(Some(RawBlock {
+            count: block.count,
+            data: block.data,
+            offset,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for BlockReader<R> {
+    type Item = Result<RawBlock, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::writer::{encode_bytes, encode_long};
+
+    #[test]
+    fn test_block_decoder_chunked() {
+        let sync: [u8; 16] = std::array::from_fn(|i| i as u8 + 1);
+        let mut encoded = vec![];
+        encode_long(&mut encoded, 3);
+        encode_bytes(&mut encoded, b"hello");
+        encoded.extend_from_slice(&sync);
+
+        // The sync marker is split across calls to decode
+        for chunk_size in [1, 2, 3, 7, 11, encoded.len()] {
+            let mut decoder = BlockDecoder::default();
+            for chunk in encoded.chunks(chunk_size) {
+                assert_eq!(decoder.decode(chunk).unwrap(), chunk.len());
+            }
+            let block = decoder.flush().unwrap();
+            assert_eq!(block.count, 3);
+            assert_eq!(block.data, b"hello");
+            assert_eq!(block.sync, sync);
+        }
+    }
 }
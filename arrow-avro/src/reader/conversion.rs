@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Per-row reporting of values that cannot be converted to their decoded type
+
+use arrow_array::{Array, ArrayRef, RecordBatch, StringArray, UInt64Array};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_cast::CastOptions;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaRef};
+use std::sync::Arc;
+
+/// Returns the schema of the [`RecordBatch`] of conversion errors collected if enabled
+/// with [`ReaderBuilder::with_conversion_errors`](crate::reader::ReaderBuilder::with_conversion_errors)
+///
+/// This has a row for each value that could not be converted, with the columns:
+///
+/// * `row`: the index of the row within the decoded [`RecordBatch`], in which the
+///   value is null
+/// * `column`: the path of the field of the value, with nested fields separated by `.`
+/// * `error`: a description of why the value could not be converted
+pub fn conversion_errors_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("row", DataType::UInt64, false),
+        Field::new("column", DataType::Utf8, false),
+        Field::new("error", DataType::Utf8, false),
+    ]))
+}
+
+/// A value that could not be converted to its decoded type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConversionError {
+    /// The index of the value, within its decoder until flushed, and then its row
+    pub(crate) row: usize,
+    pub(crate) column: String,
+    pub(crate) error: String,
+}
+
+/// Returns the [`RecordBatch`] of `errors`, see [`conversion_errors_schema`]
+pub(crate) fn conversion_errors_batch(errors: &[ConversionError]) -> RecordBatch {
+    let rows = UInt64Array::from_iter_values(errors.iter().map(|e| e.row as u64));
+    let columns = StringArray::from_iter_values(errors.iter().map(|e| &e.column));
+    let messages = StringArray::from_iter_values(errors.iter().map(|e| &e.error));
+    let columns: Vec<ArrayRef> = vec![Arc::new(rows), Arc::new(columns), Arc::new(messages)];
+    RecordBatch::try_new(conversion_errors_schema(), columns).unwrap()
+}
+
+/// Cast `array` to the data type of `field`, casting values that cannot be cast to
+/// null, and appending a [`ConversionError`] for each to `errors`
+///
+/// Only values of top-level columns are attributed to rows, and so the cast error
+/// is returned if any other values cannot be cast
+pub(crate) fn cast_collecting_errors(
+    array: &ArrayRef,
+    field: &FieldRef,
+    errors: &mut Vec<ConversionError>,
+) -> Result<ArrayRef, ArrowError> {
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let error = match arrow_cast::cast_with_options(array, field.data_type(), &options) {
+        Ok(cast) => return Ok(cast),
+        Err(e) => e,
+    };
+    let cast = arrow_cast::cast(array, field.data_type())?;
+    let formatter = ArrayFormatter::try_new(array.as_ref(), &FormatOptions::default())?;
+    let failed = (0..array.len()).filter(|idx| array.is_valid(*idx) && cast.is_null(*idx));
+    let start = errors.len();
+    errors.extend(failed.map(|row| ConversionError {
+        row,
+        column: field.name().clone(),
+        error: format!(
+            "Cannot cast {} to {}",
+            formatter.value(row),
+            field.data_type()
+        ),
+    }));
+    match errors.len() > start {
+        true => Ok(cast),
+        false => Err(error),
+    }
+}
@@ -15,107 +15,337 @@
 // specific language governing permissions and limitations
 // under the License.
 
+//! Low-level decoding of the Avro binary encoding
+
 use crate::reader::vlq::read_varint;
 use arrow_schema::ArrowError;
+use std::ops::{Deref, DerefMut};
 
 /// A wrapper around a byte slice, providing low-level decoding for Avro
 ///
 /// <https://avro.apache.org/docs/1.11.1/specification/#encodings>
-#[derive(Debug)]
-pub(crate) struct AvroCursor<'a> {
+///
+/// Every read is bounds checked against the remaining bytes, returning
+/// [`ArrowError::ParseError`] for truncated or malformed input, such as a varint
+/// longer than 10 bytes or a negative length. The cursor does not advance when
+/// a read fails
+///
+/// ```
+/// # use arrow_avro::reader::AvroCursor;
+/// // The long 2, followed by the bytes "ab"
+/// let mut cursor = AvroCursor::new(&[4, 4, b'a', b'b']);
+/// assert_eq!(cursor.get_long().unwrap(), 2);
+/// assert_eq!(cursor.get_bytes().unwrap(), b"ab");
+/// assert_eq!(cursor.position(), 4);
+/// assert!(cursor.get_u8().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AvroCursor<'a> {
     buf: &'a [u8],
     start_len: usize,
 }
 
 impl<'a> AvroCursor<'a> {
-    pub(crate) fn new(buf: &'a [u8]) -> Self {
+    /// Create a new [`AvroCursor`] positioned at the start of `buf`
+    pub fn new(buf: &'a [u8]) -> Self {
         Self {
             buf,
             start_len: buf.len(),
         }
     }
 
+    /// Create a new [`AvroCursor`] over `buf`, a sub-slice starting at `position` of
+    /// some enclosing input, such that positions are reported relative to that input
+    pub(crate) fn new_at(buf: &'a [u8], position: usize) -> Self {
+        Self {
+            buf,
+            start_len: buf.len() + position,
+        }
+    }
+
     /// Returns the current cursor position
     #[inline]
-    pub(crate) fn position(&self) -> usize {
+    pub fn position(&self) -> usize {
         self.start_len - self.buf.len()
     }
 
+    /// Returns the bytes remaining after the current cursor position
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    /// Returns `true` if there are no bytes remaining
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    fn eof(&self, reading: &str) -> ArrowError {
+        ArrowError::ParseError(format!(
+            "Unexpected EOF reading {reading} at position {}",
+            self.position()
+        ))
+    }
+
+    /// Advance the cursor by `len` bytes
+    pub fn skip(&mut self, len: usize) -> Result<(), ArrowError> {
+        self.get_fixed(len).map(|_| ())
+    }
+
     /// Read a single `u8`
     #[inline]
-    pub(crate) fn get_u8(&mut self) -> Result<u8, ArrowError> {
+    pub fn get_u8(&mut self) -> Result<u8, ArrowError> {
         match self.buf.first().copied() {
             Some(x) => {
                 self.buf = &self.buf[1..];
                 Ok(x)
             }
-            None => Err(ArrowError::ParseError("Unexpected EOF".to_string())),
+            None => Err(self.eof("u8")),
         }
     }
 
+    /// Read an Avro boolean, a single byte where any non-zero value is `true`
     #[inline]
-    pub(crate) fn get_bool(&mut self) -> Result<bool, ArrowError> {
+    pub fn get_bool(&mut self) -> Result<bool, ArrowError> {
         Ok(self.get_u8()? != 0)
     }
 
-    pub(crate) fn read_vlq(&mut self) -> Result<u64, ArrowError> {
-        let (val, offset) = read_varint(self.buf)
-            .ok_or_else(|| ArrowError::ParseError("bad varint".to_string()))?;
+    /// Read an unsigned variable-length integer, without zig-zag decoding
+    pub fn read_vlq(&mut self) -> Result<u64, ArrowError> {
+        let (val, offset) = read_varint(self.buf).ok_or_else(|| {
+            ArrowError::ParseError(format!("bad varint at position {}", self.position()))
+        })?;
         self.buf = &self.buf[offset..];
         Ok(val)
     }
 
+    /// Read a zig-zag encoded Avro int
     #[inline]
-    pub(crate) fn get_int(&mut self) -> Result<i32, ArrowError> {
+    pub fn get_int(&mut self) -> Result<i32, ArrowError> {
+        let start = self.buf;
         let varint = self.read_vlq()?;
-        let val: u32 = varint
-            .try_into()
-            .map_err(|_| ArrowError::ParseError("varint overflow".to_string()))?;
+        let val: u32 = match varint.try_into() {
+            Ok(val) => val,
+            Err(_) => {
+                self.buf = start;
+                return Err(ArrowError::ParseError(format!(
+                    "varint overflow at position {}",
+                    self.position()
+                )));
+            }
+        };
         Ok((val >> 1) as i32 ^ -((val & 1) as i32))
     }
 
+    /// Read a zig-zag encoded Avro long
     #[inline]
-    pub(crate) fn get_long(&mut self) -> Result<i64, ArrowError> {
+    pub fn get_long(&mut self) -> Result<i64, ArrowError> {
         let val = self.read_vlq()?;
         Ok((val >> 1) as i64 ^ -((val & 1) as i64))
     }
 
-    pub(crate) fn get_bytes(&mut self) -> Result<&'a [u8], ArrowError> {
-        let len: usize = self.get_long()?.try_into().map_err(|_| {
-            ArrowError::ParseError("offset overflow reading avro bytes".to_string())
-        })?;
-
-        if (self.buf.len() < len) {
-            return Err(ArrowError::ParseError(
-                "Unexpected EOF reading bytes".to_string(),
-            ));
+    /// Read Avro bytes, a long length followed by that many bytes
+    ///
+    /// This is also the encoding of an Avro string, without UTF-8 validation
+    pub fn get_bytes(&mut self) -> Result<&'a [u8], ArrowError> {
+        let start = self.buf;
+        let len = self.get_long()?;
+        let ret = usize::try_from(len)
+            .ok()
+            .and_then(|len| self.buf.get(..len));
+        match ret {
+            Some(ret) => {
+                self.buf = &self.buf[ret.len()..];
+                Ok(ret)
+            }
+            None => {
+                self.buf = start;
+                match len < 0 {
+                    true => Err(ArrowError::ParseError(format!(
+                        "Negative length {len} reading bytes at position {}",
+                        self.position()
+                    ))),
+                    false => Err(self.eof("bytes")),
+                }
+            }
         }
-        let ret = &self.buf[..len];
+    }
+
+    /// Read `len` bytes, as encoded by an Avro fixed
+    pub fn get_fixed(&mut self, len: usize) -> Result<&'a [u8], ArrowError> {
+        let ret = self.buf.get(..len).ok_or_else(|| self.eof("fixed"))?;
         self.buf = &self.buf[len..];
         Ok(ret)
     }
 
+    /// Read a little-endian Avro float
     #[inline]
-    pub(crate) fn get_float(&mut self) -> Result<f32, ArrowError> {
-        if (self.buf.len() < 4) {
-            return Err(ArrowError::ParseError(
-                "Unexpected EOF reading float".to_string(),
-            ));
-        }
-        let ret = f32::from_le_bytes(self.buf[..4].try_into().unwrap());
+    pub fn get_float(&mut self) -> Result<f32, ArrowError> {
+        let ret = self.buf.get(..4).ok_or_else(|| self.eof("float"))?;
         self.buf = &self.buf[4..];
-        Ok(ret)
+        Ok(f32::from_le_bytes(ret.try_into().unwrap()))
     }
 
+    /// Read a little-endian Avro double
     #[inline]
-    pub(crate) fn get_double(&mut self) -> Result<f64, ArrowError> {
-        if (self.buf.len() < 8) {
-            return Err(ArrowError::ParseError(
-                "Unexpected EOF reading float".to_string(),
-            ));
-        }
-        let ret = f64::from_le_bytes(self.buf[..8].try_into().unwrap());
+    pub fn get_double(&mut self) -> Result<f64, ArrowError> {
+        let ret = self.buf.get(..8).ok_or_else(|| self.eof("double"))?;
         self.buf = &self.buf[8..];
-        Ok(ret)
+        Ok(f64::from_le_bytes(ret.try_into().unwrap()))
+    }
+}
+
+/// An [`AvroCursor`] that can additionally seek to any position within its buffer,
+/// for example to revisit a length-prefixed frame after reading its header
+///
+/// The reads of [`AvroCursor`] are available through [`Deref`]
+///
+/// ```
+/// # use arrow_avro::reader::SeekableAvroCursor;
+/// let mut cursor = SeekableAvroCursor::new(&[2, 4, 6]);
+/// cursor.seek(2).unwrap();
+/// assert_eq!(cursor.get_long().unwrap(), 3);
+/// cursor.seek(0).unwrap();
+/// assert_eq!(cursor.get_long().unwrap(), 1);
+/// assert!(cursor.seek(4).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeekableAvroCursor<'a> {
+    data: &'a [u8],
+    cursor: AvroCursor<'a>,
+}
+
+impl<'a> SeekableAvroCursor<'a> {
+    /// Create a new [`SeekableAvroCursor`] positioned at the start of `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            cursor: AvroCursor::new(data),
+        }
+    }
+
+    /// Returns the entire underlying buffer, regardless of the cursor position
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Move the cursor to `position` bytes from the start of the buffer
+    ///
+    /// Returns an error, leaving the cursor unchanged, if `position` is beyond
+    /// the end of the buffer
+    pub fn seek(&mut self, position: usize) -> Result<(), ArrowError> {
+        let buf = self.data.get(position..).ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Cannot seek to position {position} of buffer with length {}",
+                self.data.len()
+            ))
+        })?;
+        self.cursor = AvroCursor {
+            buf,
+            start_len: self.data.len(),
+        };
+        Ok(())
+    }
+}
+
+impl<'a> Deref for SeekableAvroCursor<'a> {
+    type Target = AvroCursor<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cursor
+    }
+}
+
+impl<'a> DerefMut for SeekableAvroCursor<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cursor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncated() {
+        // A varint missing its final byte
+        let mut cursor = AvroCursor::new(&[0x80, 0x80]);
+        let err = cursor.get_long().unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: bad varint at position 0");
+        assert_eq!(cursor.position(), 0);
+
+        // An overlong varint
+        let mut cursor = AvroCursor::new(&[0xFF; 11]);
+        assert!(cursor.get_long().is_err());
+
+        // An int that does not fit in 32 bits
+        let mut cursor = AvroCursor::new(&[0xFF, 0xFF, 0xFF, 0xFF, 0x7F]);
+        let err = cursor.get_int().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: varint overflow at position 0"
+        );
+        assert_eq!(cursor.get_long().unwrap(), -17179869184);
+
+        // Bytes longer than the remaining buffer
+        let mut cursor = AvroCursor::new(&[2, 6, b'a', b'b']);
+        assert_eq!(cursor.get_long().unwrap(), 1);
+        let err = cursor.get_bytes().unwrap_err();
+        let expected = "Parser error: Unexpected EOF reading bytes at position 1";
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(cursor.position(), 1);
+
+        // Bytes with a negative length
+        let mut cursor = AvroCursor::new(&[1, b'a']);
+        let err = cursor.get_bytes().unwrap_err();
+        let expected = "Parser error: Negative length -1 reading bytes at position 0";
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(cursor.remaining(), &[1, b'a']);
+
+        let mut cursor = AvroCursor::new(&[0, 0, 0, 0, 0, 0, 0]);
+        assert!(cursor.get_double().is_err());
+        assert_eq!(cursor.get_float().unwrap(), 0.);
+        assert!(cursor.get_float().is_err());
+        assert!(cursor.skip(4).is_err());
+        cursor.skip(3).unwrap();
+        assert!(cursor.is_empty());
+        assert!(cursor.get_bool().is_err());
+        assert!(cursor.get_fixed(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_new_at() {
+        let data = [2, 4, b'a', b'b', 1, 0x80];
+        let mut cursor = AvroCursor::new_at(&data[1..], 1);
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.get_bytes().unwrap(), b"ab");
+        assert!(cursor.get_bool().unwrap());
+        assert_eq!(cursor.position(), 5);
+        let err = cursor.get_long().unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: bad varint at position 5");
+    }
+
+    #[test]
+    fn test_seek() {
+        let data = [2, 4, b'a', b'b', 1];
+        let mut cursor = SeekableAvroCursor::new(&data);
+        assert_eq!(cursor.get_long().unwrap(), 1);
+        assert_eq!(cursor.get_bytes().unwrap(), b"ab");
+        assert_eq!(cursor.position(), 4);
+
+        cursor.seek(1).unwrap();
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.get_bytes().unwrap(), b"ab");
+        assert!(cursor.get_bool().unwrap());
+        assert!(cursor.is_empty());
+
+        cursor.seek(5).unwrap();
+        assert!(cursor.is_empty());
+        let err = cursor.seek(6).unwrap_err();
+        let expected = "Parser error: Cannot seek to position 6 of buffer with length 5";
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(cursor.position(), 5);
+        assert_eq!(cursor.data(), &data);
     }
 }
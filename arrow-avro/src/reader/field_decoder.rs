@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! User-provided decoding of specific fields
+
+use arrow_array::ArrayRef;
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field};
+use std::fmt::{Debug, Formatter};
+
+/// Decodes the values of a field in place of the default decoding of its Avro type,
+/// for example to decrypt a field, or to parse an embedded protobuf message
+///
+/// Created for each decoded schema by a [`FieldDecoderFactory`] registered with
+/// [`ReaderBuilder::with_field_decoder`](crate::reader::ReaderBuilder::with_field_decoder)
+pub trait FieldDecoder: Debug + Send {
+    /// Returns the [`DataType`] of the arrays returned by [`Self::flush`]
+    fn data_type(&self) -> DataType;
+
+    /// Decode a single value
+    ///
+    /// For fields of the Avro `bytes` and `string` types, `value` is the contents of the
+    /// value, otherwise it is the Avro binary encoding of the value. Values of nullable
+    /// fields, i.e. unions of `null` and a single other type, exclude the union branch
+    fn decode(&mut self, value: &[u8]) -> Result<(), ArrowError>;
+
+    /// Append a placeholder for a null value, that will be masked by the nulls
+    /// subsequently provided to [`Self::flush`]
+    fn append_null(&mut self);
+
+    /// Discard all but the first `len` values decoded since the last call to
+    /// [`Self::flush`], used to discard the values of a record that failed to decode
+    ///
+    /// The default implementation discards nothing, in which case the values of a
+    /// record that fails to decode after this field are retained, and the next call
+    /// to [`Self::flush`] returns an error as the columns differ in length
+    fn truncate(&mut self, _len: usize) {}
+
+    /// Flush the values decoded since the last call to an [`ArrayRef`], with the
+    /// provided `nulls`
+    fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError>;
+}
+
+/// Creates the [`FieldDecoder`] for a field
+///
+/// Implemented for closures of the form `Fn(&Field) -> Result<Box<dyn FieldDecoder>, ArrowError>`
+pub trait FieldDecoderFactory: Send + Sync {
+    /// Create a [`FieldDecoder`] for `field`, the arrow [`Field`] the values of the
+    /// field would otherwise be decoded to
+    fn create(&self, field: &Field) -> Result<Box<dyn FieldDecoder>, ArrowError>;
+}
+
+impl<F> FieldDecoderFactory for F
+where
+    F: Fn(&Field) -> Result<Box<dyn FieldDecoder>, ArrowError> + Send + Sync,
+{
+    fn create(&self, field: &Field) -> Result<Box<dyn FieldDecoder>, ArrowError> {
+        self(field)
+    }
+}
+
+impl Debug for dyn FieldDecoderFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FieldDecoderFactory")
+    }
+}
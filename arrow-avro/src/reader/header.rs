@@ -139,7 +139,8 @@ impl Default for HeaderDecoder {
     }
 }
 
-const MAGIC: &[u8; 4] = b"Obj\x01";
+/// The magic bytes at the start of an Avro object container file
+pub(crate) const MAGIC: &[u8; 4] = b"Obj\x01";
 
 impl HeaderDecoder {
     /// Parse [`Header`] from `buf`, returning the number of bytes read
@@ -231,7 +232,7 @@ impl HeaderDecoder {
                 }
                 HeaderDecoderState::Sync => {
                     let to_decode = buf.len().min(self.bytes_remaining);
-                    let write = &mut self.sync_marker[16 - to_decode..];
+                    let write = &mut self.sync_marker[16 - self.bytes_remaining..];
                     write[..to_decode].copy_from_slice(&buf[..to_decode]);
                     self.bytes_remaining -= to_decode;
                     buf = &buf[to_decode..];
@@ -267,7 +268,7 @@ mod test {
     use crate::codec::{AvroDataType, AvroField};
     use crate::reader::read_header;
     use crate::schema::SCHEMA_METADATA_KEY;
-    use crate::test_util::arrow_test_data;
+    use crate::test_util::{arrow_test_data, write_ocf, SYNC};
     use arrow_schema::{DataType, Field, Fields, TimeUnit};
     use std::fs::File;
     use std::io::{BufRead, BufReader};
@@ -288,9 +289,26 @@ mod test {
         assert_eq!(err, "Parser error: Incorrect avro magic");
     }
 
+    #[test]
+    fn test_header_decode_chunked() {
+        let schema = r#"{"type": "record", "name": "r", "fields": []}"#;
+        let file = write_ocf(schema, &[]);
+
+        // The sync marker is split across calls to decode
+        for chunk_size in [1, 2, 3, 7, 11, file.len()] {
+            let mut decoder = HeaderDecoder::default();
+            for chunk in file.chunks(chunk_size) {
+                assert_eq!(decoder.decode(chunk).unwrap(), chunk.len());
+            }
+            let header = decoder.flush().unwrap();
+            assert_eq!(header.get(SCHEMA_METADATA_KEY).unwrap(), schema.as_bytes());
+            assert_eq!(header.sync(), SYNC);
+        }
+    }
+
     fn decode_file(file: &str) -> Header {
         let file = File::open(file).unwrap();
-        read_header(BufReader::with_capacity(100, file)).unwrap()
+        read_header(BufReader::with_capacity(100, file)).unwrap().0
     }
 
     #[test]
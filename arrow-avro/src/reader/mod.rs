@@ -17,22 +17,877 @@
 
 //! Read Avro data to Arrow
 
+use crate::codec::AvroField;
 use crate::reader::block::{Block, BlockDecoder};
-use crate::reader::header::{Header, HeaderDecoder};
-use arrow_schema::ArrowError;
+use crate::reader::conversion::cast_collecting_errors;
+use crate::reader::header::HeaderDecoder;
+use crate::reader::pipeline::{BlockPipeline, DecompressedBlocks};
+use crate::reader::record::{DecoderOptions, RecordDecoder};
+#[cfg(feature = "arrow_schema")]
+use crate::schema::ARROW_SCHEMA_METADATA_KEY;
+use crate::schema::{validate_schema_limits, Schema, SchemaLimits, SCHEMA_METADATA_KEY};
+use arrow_array::builder::ArrayBuilder;
+#[cfg(feature = "ffi")]
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use arrow_array::{RecordBatch, RecordBatchReader, UInt64Array};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema as ArrowSchema, SchemaRef};
+use std::collections::HashMap;
 use std::io::BufRead;
+use std::sync::Arc;
+
+pub use crate::codec::{
+    enum_symbol_mapping, enum_symbols, sort_options, DecimalOverflowHandling, ResolutionOptions,
+    ENUM_DEFAULT_METADATA_KEY, ENUM_SYMBOLS_METADATA_KEY, ENUM_WRITER_SYMBOLS_METADATA_KEY,
+    SORT_NULLS_METADATA_KEY, SORT_ORDER_METADATA_KEY,
+};
+pub use crate::compression::CompressionCodec;
+pub use block::{BlockReader, CorruptBlockError, RawBlock};
+pub use conversion::conversion_errors_schema;
+pub use cursor::{AvroCursor, SeekableAvroCursor};
+pub use field_decoder::{FieldDecoder, FieldDecoderFactory};
+pub use header::Header;
+pub(crate) use header::MAGIC;
+pub use multi::MultiSchemaDecoder;
+pub use record::{NullTypeHandling, UnionHandling};
+pub use schemaless::{infer_schemaless, InferredType, SchemalessOptions, LOSSY_METADATA_KEY};
+pub use statistics::{ColumnStatistics, StatisticsValue};
+#[cfg(feature = "object_store")]
+pub use store::{AsyncReader, ObjectStoreReader};
+pub use transform::ColumnTransform;
 
 mod header;
 
 mod block;
 
+mod conversion;
 mod cursor;
+mod field_decoder;
+mod multi;
+mod pipeline;
 mod record;
+mod schemaless;
+mod statistics;
+#[cfg(feature = "object_store")]
+mod store;
+mod transform;
 mod vlq;
 
-/// Read a [`Header`] from the provided [`BufRead`]
-fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
+/// A builder to create a [`Reader`] of [`RecordBatch`] from an Avro
+/// [Object Container File](https://avro.apache.org/docs/1.11.1/specification/#object-container-files)
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+    reader_schema: Option<String>,
+    resolution: ResolutionOptions,
+    embed_schema: bool,
+    limits: SchemaLimits,
+    statistics: bool,
+    struct_dictionary: bool,
+    null_type: NullTypeHandling,
+    verify_checksums: bool,
+    field_decoders: HashMap<String, Arc<dyn FieldDecoderFactory>>,
+    json_attribute: Option<(String, String)>,
+    validate_json: bool,
+    column_transforms: HashMap<String, Arc<dyn ColumnTransform>>,
+    row_index_column: Option<String>,
+    block_index_column: Option<String>,
+    skip: usize,
+    limit: Option<usize>,
+    strict_validation: bool,
+    #[cfg(feature = "arrow_schema")]
+    arrow_schema: bool,
+    union_handling: HashMap<String, UnionHandling>,
+    conversion_errors: bool,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self {
+            batch_size: 1024,
+            reader_schema: None,
+            resolution: Default::default(),
+            embed_schema: false,
+            limits: Default::default(),
+            statistics: false,
+            struct_dictionary: false,
+            null_type: NullTypeHandling::Null,
+            verify_checksums: true,
+            field_decoders: HashMap::new(),
+            json_attribute: None,
+            validate_json: false,
+            column_transforms: HashMap::new(),
+            row_index_column: None,
+            block_index_column: None,
+            skip: 0,
+            limit: None,
+            strict_validation: false,
+            #[cfg(feature = "arrow_schema")]
+            arrow_schema: false,
+            union_handling: HashMap::new(),
+            conversion_errors: false,
+        }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new [`ReaderBuilder`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows per [`RecordBatch`], defaults to 1024
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the reader schema, as Avro schema JSON
+    ///
+    /// If set, the schema the file was written with is resolved against this schema, and the
+    /// returned [`RecordBatch`] have the fields of this schema. Otherwise the fields of the
+    /// file's schema are returned.
+    ///
+    /// See [`ResolutionOptions`] for how fields are matched between the two schemas
+    pub fn with_reader_schema(mut self, schema: impl Into<String>) -> Self {
+        self.reader_schema = Some(schema.into());
+        self
+    }
+
+    /// Set the [`ResolutionOptions`] used when resolving against a reader schema
+    pub fn with_resolution_options(mut self, options: ResolutionOptions) -> Self {
+        self.resolution = options;
+        self
+    }
+
+    /// Store the Avro schema JSON of the file in the metadata of the arrow schema
+    /// of the returned [`RecordBatch`], under [`SCHEMA_METADATA_KEY`], defaults to `false`
+    ///
+    /// This preserves the original Avro schema when, for example, writing the
+    /// decoded data to parquet
+    pub fn with_embedded_schema(mut self, embed_schema: bool) -> Self {
+        self.embed_schema = embed_schema;
+        self
+    }
+
+    /// Restore the arrow types stored in the file header under [`ARROW_SCHEMA_METADATA_KEY`],
+    /// if any, casting decoded columns to them, defaults to `false`
+    ///
+    /// This restores arrow types that cannot be expressed by Avro exactly, such as
+    /// nanosecond timestamps or dictionaries. Stored fields are matched to decoded columns
+    /// by name, and only applied to columns whose type is not changed by a reader schema
+    /// or a [`ColumnTransform`]. Building fails if the stored schema cannot be parsed
+    ///
+    /// This crate does not write Avro object container files, and so does not store the
+    /// arrow schema itself: writers should store the output of [`encode_arrow_schema`]
+    /// in the file header under [`ARROW_SCHEMA_METADATA_KEY`]
+    ///
+    /// [`encode_arrow_schema`]: crate::schema::encode_arrow_schema
+    #[cfg(feature = "arrow_schema")]
+    pub fn with_arrow_schema(mut self, arrow_schema: bool) -> Self {
+        self.arrow_schema = arrow_schema;
+        self
+    }
+
+    /// Set the [`SchemaLimits`] the writer and reader schemas are validated against
+    ///
+    /// Building fails with [`ArrowError::ResourceExhausted`] if either schema exceeds these limits
+    pub fn with_schema_limits(mut self, limits: SchemaLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Collect [`ColumnStatistics`] for each column while decoding, defaults to `false`
+    ///
+    /// The statistics of the last returned [`RecordBatch`] are available from
+    /// [`Reader::statistics`] and [`MessageDecoder::statistics`]
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Decode struct columns to a [`DictionaryArray`](arrow_array::DictionaryArray) of
+    /// [`StructArray`](arrow_array::StructArray), defaults to `false`
+    ///
+    /// Identical struct values within a [`RecordBatch`] are only decoded once, which can
+    /// significantly reduce the memory required for low-cardinality struct columns, such
+    /// as the mostly constant sections of telemetry data. The encoded bytes of each
+    /// distinct value are retained until the batch is flushed, and so this should not be
+    /// used for high-cardinality columns
+    pub fn with_struct_dictionary(mut self, struct_dictionary: bool) -> Self {
+        self.struct_dictionary = struct_dictionary;
+        self
+    }
+
+    /// Set how record fields of the Avro `null` type are decoded, defaults to
+    /// [`NullTypeHandling::Null`]
+    ///
+    /// As these fields carry no data, they can be omitted with [`NullTypeHandling::Drop`],
+    /// or decoded to an all-null column accepted by consumers that do not support
+    /// [`DataType::Null`] with [`NullTypeHandling::AllNull`]
+    pub fn with_null_type_handling(mut self, null_type: NullTypeHandling) -> Self {
+        self.null_type = null_type;
+        self
+    }
+
+    /// Verify the checksums of compressed blocks, defaults to `true`
+    ///
+    /// Currently only the snappy codec stores checksums. The sync marker of each block
+    /// is always verified against the file header, regardless of this setting.
+    /// Corrupt blocks are reported as a [`CorruptBlockError`] containing the byte
+    /// offset of the block within the file
+    pub fn with_verify_checksums(mut self, verify_checksums: bool) -> Self {
+        self.verify_checksums = verify_checksums;
+        self
+    }
+
+    /// Decode the record field at `path` with a [`FieldDecoder`] created by `factory`,
+    /// instead of the default decoding for its Avro type
+    ///
+    /// `path` is the dot-separated names of the field and its parent records, e.g.
+    /// `"payload"` or `"request.body"`, with the items of arrays not named. Paths not
+    /// present in the schema are ignored
+    pub fn with_field_decoder(
+        mut self,
+        path: impl Into<String>,
+        factory: impl FieldDecoderFactory + 'static,
+    ) -> Self {
+        self.field_decoders.insert(path.into(), Arc::new(factory));
+        self
+    }
+
+    /// Decode the record field at `path`, of an Avro union type such as `["string", "long"]`,
+    /// according to `handling`
+    ///
+    /// `path` is as for [`Self::with_field_decoder`]. Unions of `null` and one other type
+    /// are always decoded as a nullable column of that type, while decoding a field of
+    /// any other union type without a [`UnionHandling`] returns an error
+    pub fn with_union_handling(mut self, path: impl Into<String>, handling: UnionHandling) -> Self {
+        self.union_handling.insert(path.into(), handling);
+        self
+    }
+
+    /// Additionally decode `string` and `bytes` types with the attribute `key` set to
+    /// the string `value` as JSON, e.g. `("connect.name", "io.debezium.data.Json")`
+    ///
+    /// Types with the `json` logical type are always decoded as JSON, to
+    /// [`DataType::Utf8`] with the canonical `arrow.json`
+    /// extension type
+    pub fn with_json_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.json_attribute = Some((key.into(), value.into()));
+        self
+    }
+
+    /// Return an error when decoding JSON values that are not well-formed JSON,
+    /// defaults to `false`
+    pub fn with_validate_json(mut self, validate_json: bool) -> Self {
+        self.validate_json = validate_json;
+        self
+    }
+
+    /// Fully validate each decoded [`RecordBatch`], returning an error if any column is
+    /// inconsistent, defaults to `false`
+    ///
+    /// This includes checking that the validity of each struct is distinct from, and
+    /// consistent with, the validity of its children, e.g. that non-nullable children
+    /// are only null where the struct is null. As this requires a further pass over the
+    /// data, it is intended for debug builds and testing, e.g. with
+    /// `with_strict_validation(cfg!(debug_assertions))`
+    pub fn with_strict_validation(mut self, strict_validation: bool) -> Self {
+        self.strict_validation = strict_validation;
+        self
+    }
+
+    /// Decode values that cannot be converted to their decoded type as null, instead of
+    /// failing the whole batch, defaults to `false`
+    ///
+    /// Such values include decimals that exceed the size or precision of their decimal
+    /// type, and timestamps that overflow the unit of a stored arrow schema, see
+    /// `with_arrow_schema`. Each is reported as a row of a separate [`RecordBatch`],
+    /// with the schema [`conversion_errors_schema`], identifying its row and column,
+    /// available from [`Reader::conversion_errors`] and [`MessageDecoder::conversion_errors`].
+    /// This allows data-quality pipelines to quarantine rows with bad values.
+    ///
+    /// The fields of values that may not be converted are always nullable
+    pub fn with_conversion_errors(mut self, conversion_errors: bool) -> Self {
+        self.conversion_errors = conversion_errors;
+        self
+    }
+
+    /// Apply `transform` to each decoded array of the top-level column named `column`
+    ///
+    /// This allows light normalization, such as parsing string IP addresses or lowercasing
+    /// values, to be performed as part of decoding, without a further pass over each
+    /// [`RecordBatch`]. The [`ColumnStatistics`] of the column, if enabled, are those of
+    /// the values prior to the transform. Columns not present in the schema are ignored
+    pub fn with_column_transform(
+        mut self,
+        column: impl Into<String>,
+        transform: impl ColumnTransform + 'static,
+    ) -> Self {
+        self.column_transforms
+            .insert(column.into(), Arc::new(transform));
+        self
+    }
+
+    /// Append a non-nullable [`UInt64`](arrow_schema::DataType::UInt64) column named `name`
+    /// to the [`RecordBatch`] returned by [`Reader`], containing the index of each record
+    /// within the file, starting from 0
+    ///
+    /// Along with [`Self::with_block_index_column`], this allows each row to be traced
+    /// back to its position in the file, for example to deduplicate rows or report errors
+    pub fn with_row_index_column(mut self, name: impl Into<String>) -> Self {
+        self.row_index_column = Some(name.into());
+        self
+    }
+
+    /// Append a non-nullable [`UInt64`](arrow_schema::DataType::UInt64) column named `name`
+    /// to the [`RecordBatch`] returned by [`Reader`], containing the index of the block
+    /// of each record within the file, starting from 0
+    pub fn with_block_index_column(mut self, name: impl Into<String>) -> Self {
+        self.block_index_column = Some(name.into());
+        self
+    }
+
+    /// Skip the first `skip` records of the file, defaults to 0
+    ///
+    /// Blocks containing only skipped records are skipped without being decompressed or
+    /// decoded, using the record count of each block
+    pub fn with_skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    /// Return at most `limit` records, after any skipped with [`Self::with_skip`]
+    ///
+    /// No further blocks are read once the limit is reached
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Create a [`Reader`] reading from the provided [`BufRead`]
+    ///
+    /// Each block is read and decompressed on the calling thread, see
+    /// [`Self::build_with_worker_thread`] to instead overlap this with decoding
+    pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
+        let blocks = BlockReader::try_new(reader)?;
+        let header = blocks.header();
+        let compression = header.compression()?;
+        let writer_schema = header.schema()?.ok_or_else(|| {
+            ArrowError::ParseError("No Avro schema present in file header".to_string())
+        })?;
+
+        let embedded = match header.get(SCHEMA_METADATA_KEY) {
+            Some(schema) if self.embed_schema => {
+                Some(String::from_utf8(schema.to_vec()).map_err(|e| {
+                    ArrowError::ParseError(format!("Avro schema is not valid UTF-8: {e}"))
+                })?)
+            }
+            _ => None,
+        };
+        let decoder = self.record_decoder(&writer_schema, embedded)?;
+
+        let metadata = header
+            .metadata()
+            .filter(|(k, _)| !k.starts_with(b"avro."))
+            .filter_map(|(k, v)| {
+                let k = std::str::from_utf8(k).ok()?;
+                let v = std::str::from_utf8(v).ok()?;
+                Some((k.to_string(), v.to_string()))
+            })
+            .collect();
+
+        let mut fields = decoder.schema().fields().to_vec();
+        let mut schema_metadata = decoder.schema().metadata().clone();
+        #[cfg(feature = "arrow_schema")]
+        let cast = match header.get(ARROW_SCHEMA_METADATA_KEY) {
+            Some(stored) if self.arrow_schema => self.restore_arrow_schema(
+                stored,
+                &writer_schema,
+                &mut fields,
+                &mut schema_metadata,
+            )?,
+            _ => false,
+        };
+        #[cfg(not(feature = "arrow_schema"))]
+        let cast = false;
+        for name in [&self.row_index_column, &self.block_index_column]
+            .into_iter()
+            .flatten()
+        {
+            if fields.iter().any(|f| f.name() == name) {
+                return Err(ArrowError::SchemaError(format!(
+                    "Index column \"{name}\" conflicts with an existing column"
+                )));
+            }
+            fields.push(Arc::new(Field::new(name, DataType::UInt64, false)));
+        }
+        let schema = Arc::new(ArrowSchema::new_with_metadata(fields, schema_metadata));
+
+        let blocks = DecompressedBlocks::new(blocks, compression, self.verify_checksums, self.skip);
+        Ok(Reader {
+            blocks: BlockPipeline::Inline(blocks),
+            decoder,
+            schema,
+            metadata,
+            row_index_column: self.row_index_column.is_some(),
+            block_index_column: self.block_index_column.is_some(),
+            cast,
+            rows_read: 0,
+            blocks_read: 0,
+            block_indices: vec![],
+            skip: self.skip,
+            limit: self.limit,
+            block_data: vec![],
+            block_offset: 0,
+            block_remaining: 0,
+            batch_size: self.batch_size,
+        })
+    }
+
+    /// Create a [`Reader`] reading from the provided [`BufRead`], with each block
+    /// read and decompressed on a worker thread while the previous block is decoded
+    ///
+    /// This hides the latency of slow readers, such as network file systems, and the
+    /// cost of decompression, at the cost of reading up to two blocks ahead of the
+    /// block being decoded, including past any limit set with [`Self::with_limit`].
+    /// Any error reading a block is returned once the preceding blocks are decoded
+    pub fn build_with_worker_thread<R: BufRead + Send + 'static>(
+        self,
+        reader: R,
+    ) -> Result<Reader<R>, ArrowError> {
+        let reader = self.build(reader)?;
+        Ok(Reader {
+            blocks: reader.blocks.spawn_worker()?,
+            ..reader
+        })
+    }
+
+    /// Create a [`MessageDecoder`] decoding individually encoded Avro records,
+    /// written with the Avro schema JSON `writer_schema`
+    pub fn build_message_decoder(self, writer_schema: &str) -> Result<MessageDecoder, ArrowError> {
+        let schema: Schema<'_> = serde_json::from_str(writer_schema).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse Avro writer schema JSON: {e}"))
+        })?;
+        let embedded = self.embed_schema.then(|| writer_schema.to_string());
+        let decoder = self.record_decoder(&schema, embedded)?;
+
+        Ok(MessageDecoder {
+            decoder,
+            buffered: 0,
+            batch_size: self.batch_size,
+        })
+    }
+
+    /// Create a [`MultiSchemaDecoder`] decoding a stream of individually encoded Avro
+    /// records, each prefixed with the ID of the schema it was written with
+    ///
+    /// The options of this builder are applied to the decoder of each schema
+    pub fn build_multi_schema_decoder(self) -> MultiSchemaDecoder {
+        MultiSchemaDecoder::new(self)
+    }
+
+    /// Replace the types of `fields` with those of the IPC encoded arrow schema `stored`,
+    /// returning `true` if any decoded column must be cast
+    ///
+    /// Only fields decoded with the type of `writer_schema` are replaced
+    #[cfg(feature = "arrow_schema")]
+    fn restore_arrow_schema(
+        &self,
+        stored: &[u8],
+        writer_schema: &Schema<'_>,
+        fields: &mut [FieldRef],
+        metadata: &mut HashMap<String, String>,
+    ) -> Result<bool, ArrowError> {
+        let stored = arrow_ipc::convert::try_schema_from_ipc_buffer(stored).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse stored arrow schema: {e}"))
+        })?;
+        // Without a reader schema every field is decoded with the type of the writer schema
+        let original = match &self.reader_schema {
+            Some(_) => {
+                let decoder = self.new_record_decoder(AvroField::try_from(writer_schema)?)?;
+                Some(decoder.schema().clone())
+            }
+            None => None,
+        };
+
+        let mut cast = false;
+        for field in fields.iter_mut() {
+            let Some((_, restored)) = stored.fields().find(field.name()) else {
+                continue;
+            };
+            let unchanged = original.as_ref().map_or(true, |original| {
+                original
+                    .field_with_name(field.name())
+                    .is_ok_and(|f| f.data_type() == field.data_type())
+            });
+            if !unchanged
+                || restored.data_type() == field.data_type()
+                || self.column_transforms.contains_key(field.name())
+                || !arrow_cast::can_cast_types(field.data_type(), restored.data_type())
+            {
+                continue;
+            }
+            // Values that cannot be cast to the stored type are decoded as null
+            let nullable = field.is_nullable() || self.conversion_errors;
+            *field = Arc::new(
+                field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(restored.data_type().clone())
+                    .with_nullable(nullable),
+            );
+            cast = true;
+        }
+        metadata.extend(stored.metadata().clone());
+        Ok(cast)
+    }
+
+    /// Create a [`RecordDecoder`] for data written with `writer_schema`, resolving it
+    /// against the reader schema if any, and storing the `embedded` Avro schema JSON
+    /// in the arrow schema metadata
+    fn record_decoder(
+        &self,
+        writer_schema: &Schema<'_>,
+        embedded: Option<String>,
+    ) -> Result<RecordDecoder, ArrowError> {
+        validate_schema_limits(writer_schema, &self.limits)?;
+        let root = match &self.reader_schema {
+            Some(reader_schema) => {
+                let reader_schema: Schema<'_> =
+                    serde_json::from_str(reader_schema).map_err(|e| {
+                        ArrowError::ParseError(format!(
+                            "Failed to parse Avro reader schema JSON: {e}"
+                        ))
+                    })?;
+                validate_schema_limits(&reader_schema, &self.limits)?;
+                AvroField::resolve(writer_schema, &reader_schema, &self.resolution)?
+            }
+            None => AvroField::try_from(writer_schema)?,
+        };
+
+        let mut decoder = self.new_record_decoder(root)?;
+        if let Some(schema) = embedded {
+            let metadata = HashMap::from([(SCHEMA_METADATA_KEY.to_string(), schema)]);
+            decoder = decoder.with_schema_metadata(metadata);
+        }
+        if self.statistics {
+            decoder = decoder.with_statistics();
+        }
+        Ok(decoder)
+    }
+
+    /// Create a [`RecordDecoder`] for the resolved `root`, with the options of this builder
+    fn new_record_decoder(&self, root: AvroField) -> Result<RecordDecoder, ArrowError> {
+        let root = match &self.json_attribute {
+            Some((key, value)) => root.with_json_attribute(key, value),
+            None => root,
+        };
+
+        let options = DecoderOptions {
+            struct_dictionary: self.struct_dictionary,
+            null_type: self.null_type.clone(),
+            field_decoders: self.field_decoders.clone(),
+            validate_json: self.validate_json,
+            column_transforms: self.column_transforms.clone(),
+            strict_validation: self.strict_validation,
+            union_handling: self.union_handling.clone(),
+            conversion_errors: self.conversion_errors,
+        };
+        RecordDecoder::try_new_with_options(root.data_type(), &options)
+    }
+}
+
+/// Decodes individually encoded Avro records, such as the values of Kafka messages,
+/// into [`RecordBatch`]
+///
+/// Unlike [`Reader`], which reads Avro object container files, each record is
+/// passed to [`Self::decode`] separately, without any header or framing. Decoded
+/// records are buffered until [`Self::flush`] is called.
+///
+/// Created with [`ReaderBuilder::build_message_decoder`]
+#[derive(Debug)]
+pub struct MessageDecoder {
+    decoder: RecordDecoder,
+    /// The number of records decoded since the last flush
+    buffered: usize,
+    batch_size: usize,
+}
+
+impl MessageDecoder {
+    /// Returns the arrow schema of the [`RecordBatch`] returned by this decoder
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema().clone()
+    }
+
+    /// Decode a single record from `data`, which must contain exactly one record
+    ///
+    /// On error the record is discarded, with any previously decoded records retained
+    pub fn decode(&mut self, data: &[u8]) -> Result<(), ArrowError> {
+        let read = self.decoder.decode(data, 1)?;
+        if read != data.len() {
+            self.decoder.truncate(self.buffered);
+            return Err(ArrowError::ParseError(format!(
+                "Avro record of {read} bytes followed by {} unexpected bytes",
+                data.len() - read
+            )));
+        }
+        self.buffered += 1;
+        Ok(())
+    }
+
+    /// Decode a single record from `data`, which must contain exactly one record,
+    /// appending it to the caller-provided `builders` instead of buffering it
+    ///
+    /// `builders` must contain a builder for each field of [`Self::schema`], as
+    /// created by [`make_builder`](arrow_array::builder::make_builder), allowing
+    /// records to be materialized directly into builders managed by the caller.
+    /// Statistics are not collected for such records, and [`ColumnTransform`],
+    /// [`FieldDecoder`], and unions decoded with [`UnionHandling::Utf8`] are not supported
+    ///
+    /// On error the builders may contain a partially appended record
+    pub fn decode_into(
+        &self,
+        data: &[u8],
+        builders: &mut [Box<dyn ArrayBuilder>],
+    ) -> Result<(), ArrowError> {
+        let read = self.decoder.decode_into(data, 1, builders)?;
+        match read == data.len() {
+            true => Ok(()),
+            false => Err(ArrowError::ParseError(format!(
+                "Avro record of {read} bytes followed by {} unexpected bytes",
+                data.len() - read
+            ))),
+        }
+    }
+
+    /// Returns the number of records decoded since the last flush
+    pub fn num_buffered(&self) -> usize {
+        self.buffered
+    }
+
+    /// Returns `true` if the number of buffered records has reached the
+    /// batch size of the [`ReaderBuilder`]
+    pub fn is_full(&self) -> bool {
+        self.buffered >= self.batch_size
+    }
+
+    /// Flush the buffered records into a [`RecordBatch`], returning `None`
+    /// if there are no buffered records
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        if self.buffered == 0 {
+            return Ok(None);
+        }
+        self.buffered = 0;
+        self.decoder.flush().map(Some)
+    }
+
+    /// Returns the [`ColumnStatistics`] of the last [`RecordBatch`] returned by
+    /// [`Self::flush`], if enabled with [`ReaderBuilder::with_statistics`]
+    pub fn statistics(&self) -> Option<&[ColumnStatistics]> {
+        self.decoder.statistics()
+    }
+
+    /// Returns the conversion errors of the last [`RecordBatch`] returned by
+    /// [`Self::flush`], if enabled with [`ReaderBuilder::with_conversion_errors`]
+    pub fn conversion_errors(&self) -> Option<RecordBatch> {
+        self.decoder.conversion_errors()
+    }
+}
+
+/// Reads [`RecordBatch`] from an Avro
+/// [Object Container File](https://avro.apache.org/docs/1.11.1/specification/#object-container-files)
+///
+/// Created with [`ReaderBuilder`]
+#[derive(Debug)]
+pub struct Reader<R> {
+    blocks: BlockPipeline<R>,
+    decoder: RecordDecoder,
+    /// The schema of the returned [`RecordBatch`], including any index columns
+    schema: SchemaRef,
+    /// The user metadata of the file header
+    metadata: HashMap<String, String>,
+    /// Whether to append a row index column
+    row_index_column: bool,
+    /// Whether to append a block index column
+    block_index_column: bool,
+    /// Whether to cast the decoded columns to the types of `schema`
+    cast: bool,
+    /// The number of records returned so far
+    rows_read: u64,
+    /// The number of blocks read so far
+    blocks_read: u64,
+    /// The block index of each record of the current batch, if `block_index_column`
+    block_indices: Vec<u64>,
+    /// The number of records remaining to be skipped
+    skip: usize,
+    /// The number of records remaining to be returned, if limited
+    limit: Option<usize>,
+    /// The decompressed data of the current block
+    block_data: Vec<u8>,
+    /// The offset of the next record in `block_data`
+    block_offset: usize,
+    /// The number of records remaining in the current block
+    block_remaining: usize,
+    batch_size: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Returns the arrow schema of the [`RecordBatch`] returned by this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the user metadata of the file header
+    ///
+    /// Keys starting with `avro.` are reserved by the Avro specification and excluded,
+    /// as are any keys or values that are not valid UTF-8
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the [`ColumnStatistics`] of the last returned [`RecordBatch`],
+    /// if enabled with [`ReaderBuilder::with_statistics`]
+    pub fn statistics(&self) -> Option<&[ColumnStatistics]> {
+        self.decoder.statistics()
+    }
+
+    /// Returns the conversion errors of the last returned [`RecordBatch`], if enabled
+    /// with [`ReaderBuilder::with_conversion_errors`]
+    pub fn conversion_errors(&self) -> Option<RecordBatch> {
+        self.decoder.conversion_errors()
+    }
+
+    /// Returns the size of the next batch, accounting for any limit
+    fn next_batch_size(&self) -> usize {
+        match self.limit {
+            Some(limit) => self.batch_size.min(limit),
+            None => self.batch_size,
+        }
+    }
+
+    /// Returns the number of records that the next call to [`Self::read`] may read
+    /// from subsequent blocks, including any to be skipped
+    #[cfg(feature = "object_store")]
+    fn rows_needed(&self) -> usize {
+        match self.next_batch_size() {
+            0 => 0,
+            batch_size => (self.skip + batch_size).saturating_sub(self.block_remaining),
+        }
+    }
+
+    /// Read the next [`RecordBatch`], returning `None` at the end of the file
+    fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let batch_size = self.next_batch_size();
+        let mut rows = 0;
+        while rows < batch_size {
+            if self.block_remaining == 0 {
+                match self.next_block()? {
+                    true => continue,
+                    false => break,
+                }
+            }
+            let to_read = self.block_remaining.min(batch_size - rows);
+            let data = &self.block_data[self.block_offset..];
+            self.block_offset += self.decoder.decode(data, to_read)?;
+            self.block_remaining -= to_read;
+            rows += to_read;
+            if self.block_index_column {
+                let index = std::iter::repeat(self.blocks_read - 1).take(to_read);
+                self.block_indices.extend(index);
+            }
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+        if let Some(limit) = &mut self.limit {
+            *limit -= rows;
+        }
+        let batch = self.decoder.flush()?;
+        let start = self.rows_read;
+        self.rows_read += rows as u64;
+        if !self.cast && !self.row_index_column && !self.block_index_column {
+            return Ok(Some(batch));
+        }
+
+        let mut columns = batch.columns().to_vec();
+        if self.cast {
+            for (column, field) in columns.iter_mut().zip(self.schema.fields()) {
+                if column.data_type() == field.data_type() {
+                    continue;
+                }
+                *column = match self.decoder.conversion_errors_mut() {
+                    Some(errors) => cast_collecting_errors(column, field, errors)?,
+                    None => arrow_cast::cast(column, field.data_type())?,
+                };
+            }
+        }
+        if self.row_index_column {
+            let indices = UInt64Array::from_iter_values(start..self.rows_read);
+            columns.push(Arc::new(indices));
+        }
+        if self.block_index_column {
+            let indices = std::mem::take(&mut self.block_indices);
+            columns.push(Arc::new(UInt64Array::from(indices)));
+        }
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+
+    /// Read the next [`RawBlock`], returning `false` at the end of the file
+    fn next_block(&mut self) -> Result<bool, ArrowError> {
+        let block = loop {
+            let Some(block) = self.blocks.next_block()? else {
+                return Ok(false);
+            };
+            self.blocks_read += 1;
+            if self.skip < block.count {
+                break block;
+            }
+            // The entire block is skipped, and was not decompressed
+            self.skip -= block.count;
+            self.rows_read += block.count as u64;
+        };
+        self.block_data = block.data;
+        self.block_offset = self.decoder.skip(&self.block_data, self.skip)?;
+        self.block_remaining = block.count - self.skip;
+        self.rows_read += std::mem::take(&mut self.skip) as u64;
+        Ok(true)
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+impl<R: BufRead> RecordBatchReader for Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+#[cfg(feature = "ffi")]
+impl<R: BufRead + Send + 'static> Reader<R> {
+    /// Exports this reader as an [`FFI_ArrowArrayStream`], allowing the decoded
+    /// [`RecordBatch`]es to be consumed over the
+    /// [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+    /// e.g. by pyarrow or DuckDB, without copying
+    ///
+    /// Any [`RecordBatchReader`] can be exported with [`FFI_ArrowArrayStream::new`],
+    /// and imported with [`ArrowArrayStreamReader`](arrow_array::ffi_stream::ArrowArrayStreamReader)
+    pub fn into_ffi_stream(self) -> FFI_ArrowArrayStream {
+        FFI_ArrowArrayStream::new(Box::new(self))
+    }
+}
+
+/// Read a [`Header`] from the provided [`BufRead`], returning it along with its length in bytes
+fn read_header<R: BufRead>(mut reader: R) -> Result<(Header, u64), ArrowError> {
     let mut decoder = HeaderDecoder::default();
+    let mut len = 0;
     loop {
         let buf = reader.fill_buf()?;
         if buf.is_empty() {
@@ -41,14 +896,16 @@ fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
         let read = buf.len();
         let decoded = decoder.decode(buf)?;
         reader.consume(decoded);
+        len += decoded as u64;
         if decoded != read {
             break;
         }
     }
 
-    decoder
+    let header = decoder
         .flush()
-        .ok_or_else(|| ArrowError::ParseError("Unexpected EOF".to_string()))
+        .ok_or_else(|| ArrowError::ParseError("Unexpected EOF".to_string()))?;
+    Ok((header, len))
 }
 
 /// Return an iterator of [`Block`] from the provided [`BufRead`]
@@ -76,19 +933,34 @@ fn read_blocks<R: BufRead>(mut reader: R) -> impl Iterator<Item = Result<Block,
 #[cfg(test)]
 mod test {
     use crate::codec::AvroField;
+    use crate::codec::{EXTENSION_TYPE_NAME_KEY, FIELD_ID_METADATA_KEY, JSON_EXTENSION_NAME};
     use crate::compression::CompressionCodec;
     use crate::reader::record::RecordDecoder;
+    use crate::reader::{
+        conversion_errors_schema, enum_symbol_mapping, enum_symbols, BlockReader,
+        CorruptBlockError, DecimalOverflowHandling, FieldDecoder, NullTypeHandling, ReaderBuilder,
+        ResolutionOptions, StatisticsValue, UnionHandling, ENUM_DEFAULT_METADATA_KEY,
+        ENUM_SYMBOLS_METADATA_KEY, ENUM_WRITER_SYMBOLS_METADATA_KEY,
+    };
     use crate::reader::{read_blocks, read_header};
-    use crate::test_util::arrow_test_data;
+    #[cfg(feature = "arrow_schema")]
+    use crate::schema::{encode_arrow_schema, ARROW_SCHEMA_METADATA_KEY};
+    use crate::schema::{AvroSchemaId, SchemaLimits, SCHEMA_METADATA_KEY};
+    use crate::test_util::{
+        arrow_test_data, encode_bytes, encode_long, write_ocf, write_ocf_with_metadata, SYNC,
+    };
+    use arrow_array::cast::AsArray;
     use arrow_array::*;
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit};
     use std::fs::File;
-    use std::io::BufReader;
+    use std::io::{BufRead, BufReader};
     use std::sync::Arc;
 
     fn read_file(file: &str, batch_size: usize) -> RecordBatch {
         let file = File::open(file).unwrap();
         let mut reader = BufReader::new(file);
-        let header = read_header(&mut reader).unwrap();
+        let (header, _) = read_header(&mut reader).unwrap();
         let compression = header.compression().unwrap();
         let schema = header.schema().unwrap().unwrap();
         let root = AvroField::try_from(&schema).unwrap();
@@ -98,7 +970,7 @@ mod test {
             let block = result.unwrap();
             assert_eq!(block.sync, header.sync());
             if let Some(c) = compression {
-                let decompressed = c.decompress(&block.data).unwrap();
+                let decompressed = c.decompress(&block.data, true).unwrap();
 
                 let mut offset = 0;
                 let mut remaining = block.count;
@@ -215,4 +1087,2161 @@ mod test {
             assert_eq!(read_file(&file, 3), expected);
         }
     }
+
+    const WRITER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "event",
+        "fields": [
+            {"name": "userId", "type": "long"},
+            {"name": "UserName", "type": ["null", "string"]},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "score", "type": "double"}
+        ]
+    }"#;
+
+    /// Returns `count` records encoded with [`WRITER_SCHEMA`], starting at id `start`
+    fn write_events(start: i64, count: usize) -> (usize, Vec<u8>) {
+        let mut out = vec![];
+        for id in start..start + count as i64 {
+            encode_long(&mut out, id);
+            encode_long(&mut out, 1);
+            encode_bytes(&mut out, format!("user{id}").as_bytes());
+            // A block of two tags, followed by a block of one tag with its size in bytes
+            encode_long(&mut out, 2);
+            encode_bytes(&mut out, b"a");
+            encode_bytes(&mut out, b"b");
+            encode_long(&mut out, -1);
+            encode_long(&mut out, 2);
+            encode_bytes(&mut out, b"c");
+            encode_long(&mut out, 0);
+            out.extend_from_slice(&(id as f64).to_le_bytes());
+        }
+        (count, out)
+    }
+
+    #[test]
+    fn test_reader_batch_size() {
+        let file = write_ocf(WRITER_SCHEMA, &[write_events(0, 3), write_events(3, 4)]);
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [
+                {"name": "userId", "type": "long"},
+                {"name": "score", "type": "double"}
+            ]
+        }"#;
+        let reader = ReaderBuilder::new()
+            .with_batch_size(2)
+            .with_reader_schema(reader_schema)
+            .build(file.as_slice())
+            .unwrap();
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![2, 2, 2, 1]
+        );
+        let ids: Vec<_> = batches
+            .iter()
+            .flat_map(|b| {
+                b.column(0)
+                    .as_primitive::<types::Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(ids, (0..7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn test_reader_ffi_stream() {
+        use arrow_array::ffi_stream::ArrowArrayStreamReader;
+
+        let file = write_ocf(WRITER_SCHEMA, &[write_events(0, 3), write_events(3, 2)]);
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [
+                {"name": "userId", "type": "long"},
+                {"name": "UserName", "type": ["null", "string"]}
+            ]
+        }"#;
+        let builder = || {
+            ReaderBuilder::new()
+                .with_batch_size(4)
+                .with_reader_schema(reader_schema)
+        };
+        let reader = builder().build(file.as_slice()).unwrap();
+        let expected: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+
+        let reader = builder().build(std::io::Cursor::new(file)).unwrap();
+        let schema = reader.schema();
+        let stream = reader.into_ffi_stream();
+
+        let imported = ArrowArrayStreamReader::try_new(stream).unwrap();
+        assert_eq!(imported.schema(), schema);
+        let batches: Vec<_> = imported.collect::<Result<_, _>>().unwrap();
+        assert_eq!(batches, expected);
+        assert_eq!(batches.len(), 2);
+    }
+
+    #[test]
+    fn test_reader_field_resolution() {
+        let file = write_ocf(WRITER_SCHEMA, &[write_events(0, 2)]);
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [
+                {"name": "score", "type": "double"},
+                {"name": "user_id", "type": "long"},
+                {"name": "username", "type": ["null", "string"]},
+                {"name": "missing", "type": ["null", "int"]}
+            ]
+        }"#;
+        let options = ResolutionOptions::default()
+            .with_case_insensitive(true)
+            .with_field_mapping("userId", "user_id");
+        let mut reader = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .with_resolution_options(options)
+            .build(file.as_slice())
+            .unwrap();
+
+        let expected_schema = Schema::new(vec![
+            Field::new("score", DataType::Float64, false),
+            Field::new("user_id", DataType::Int64, false),
+            Field::new("username", DataType::Utf8, true),
+            Field::new("missing", DataType::Int32, true),
+        ]);
+        assert_eq!(reader.schema().as_ref(), &expected_schema);
+
+        let batch = reader.next().unwrap().unwrap();
+        let expected = RecordBatch::try_new(
+            Arc::new(expected_schema),
+            vec![
+                Arc::new(Float64Array::from(vec![0., 1.])),
+                Arc::new(Int64Array::from(vec![0, 1])),
+                Arc::new(StringArray::from(vec!["user0", "user1"])),
+                Arc::new(Int32Array::from(vec![None, None])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batch, expected);
+        assert!(reader.next().is_none());
+
+        // Without case-insensitive matching the field is missing, and so read as null
+        let options = ResolutionOptions::default().with_field_mapping("userId", "user_id");
+        let batch = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .with_resolution_options(options)
+            .build(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.column(2).null_count(), 2);
+
+        // Non-nullable reader fields must be present in the writer schema
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [{"name": "user_id", "type": "long"}]
+        }"#;
+        let err = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(file.as_slice())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Reader field \"user_id\" is not nullable and not present in the writer schema"
+        );
+    }
+
+    #[test]
+    fn test_decimal() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                {"name": "b", "type": ["null", {
+                    "type": "fixed",
+                    "name": "b",
+                    "size": 3,
+                    "logicalType": "decimal",
+                    "precision": 6,
+                    "scale": 1
+                }]},
+                {"name": "c", "type": {"type": "bytes", "logicalType": "decimal", "precision": 40}}
+            ]
+        }"#;
+
+        let mut data = vec![];
+        // 123.45, -1.0 and 2^127
+        encode_bytes(&mut data, &[0x30, 0x39]);
+        encode_long(&mut data, 1);
+        data.extend_from_slice(&[0xFF, 0xFF, 0xF6]);
+        let mut big = vec![0x00, 0x80];
+        big.extend_from_slice(&[0; 15]);
+        encode_bytes(&mut data, &big);
+        // -0.01, null and -1 with redundant sign extension
+        encode_bytes(&mut data, &[0xFF]);
+        encode_long(&mut data, 0);
+        encode_bytes(&mut data, &[0xFF; 40]);
+
+        let file = write_ocf(schema, &[(2, data)]);
+        let batch = ReaderBuilder::new()
+            .build(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let a = Decimal128Array::from(vec![12345, -1])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let b = Decimal128Array::from(vec![Some(-10), None])
+            .with_precision_and_scale(6, 1)
+            .unwrap();
+        let c = Decimal256Array::from(vec![
+            arrow_buffer::i256::from_i128(i128::MAX) + arrow_buffer::i256::ONE,
+            arrow_buffer::i256::MINUS_ONE,
+        ])
+        .with_precision_and_scale(40, 0)
+        .unwrap();
+        assert_eq!(batch.column(0).as_ref(), &a as &dyn Array);
+        assert_eq!(batch.column(1).as_ref(), &b as &dyn Array);
+        assert_eq!(batch.column(2).as_ref(), &c as &dyn Array);
+
+        // Values that do not fit in 16 bytes are rejected
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "a", "type": {"type": "bytes", "logicalType": "decimal", "precision": 38}}]
+        }"#;
+        let mut data = vec![];
+        encode_bytes(&mut data, &big);
+        let file = write_ocf(schema, &[(1, data)]);
+        let err = ReaderBuilder::new()
+            .build(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal of 17 bytes exceeds 16 bytes"
+        );
+    }
+
+    #[test]
+    fn test_embedded_schema() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "id", "type": "long", "field-id": 1}]
+        }"#;
+        let mut data = vec![];
+        encode_long(&mut data, 42);
+        let file = write_ocf(schema, &[(1, data)]);
+
+        let reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        assert!(reader.schema().metadata().is_empty());
+
+        let reader = ReaderBuilder::new()
+            .with_embedded_schema(true)
+            .build(file.as_slice())
+            .unwrap();
+        let arrow_schema = reader.schema();
+        assert_eq!(arrow_schema.metadata()[SCHEMA_METADATA_KEY], schema);
+        assert_eq!(arrow_schema.field(0).metadata()[FIELD_ID_METADATA_KEY], "1");
+    }
+
+    #[test]
+    fn test_metadata() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let metadata: [(&str, &[u8]); 3] = [
+            ("avro.codec", b"null"),
+            ("created_by", b"test"),
+            ("binary", &[0xFF, 0xFE]),
+        ];
+        let file = write_ocf_with_metadata(schema, &metadata, &[]);
+
+        let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        assert_eq!(reader.metadata().len(), 1);
+        assert_eq!(reader.metadata()["created_by"], "test");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_schema_limits() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "a", "type": "long"}, {"name": "b", "type": "long"}]
+        }"#;
+        let file = write_ocf(schema, &[]);
+        ReaderBuilder::new().build(file.as_slice()).unwrap();
+
+        let limits = SchemaLimits::default().with_max_fields(1);
+        let err = ReaderBuilder::new()
+            .with_schema_limits(limits)
+            .build(file.as_slice())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Resource exhausted: Avro schema fields exceed the limit of 1"
+        );
+
+        let writer_schema = r#"{"type": "record", "name": "r", "fields": []}"#;
+        let err = ReaderBuilder::new()
+            .with_schema_limits(limits)
+            .with_reader_schema(schema)
+            .build_message_decoder(writer_schema)
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::ResourceExhausted(_)), "{err}");
+    }
+
+    #[test]
+    fn test_oversized_block() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let mut file = write_ocf(schema, &[]);
+        let header_len = file.len();
+        // A block claiming to contain far more data than is present
+        encode_long(&mut file, 1);
+        encode_long(&mut file, i64::MAX);
+        file.extend_from_slice(&[0; 8]);
+
+        let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let err = match reader.next() {
+            Some(Err(e)) => e,
+            r => panic!("expected error, got {r:?}"),
+        };
+        assert_eq!(
+            err.to_string(),
+            format!("External error: Corrupt Avro block at byte offset {header_len}: unexpected end of file")
+        );
+    }
+
+    #[test]
+    fn test_corrupt_block() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let block = |ids: &[i64]| {
+            let mut data = vec![];
+            ids.iter().for_each(|x| encode_long(&mut data, *x));
+            (ids.len(), data)
+        };
+        let mut file = write_ocf(schema, &[block(&[1, 2]), block(&[3])]);
+        let second_block = write_ocf(schema, &[block(&[1, 2])]).len();
+        let last = file.len() - 1;
+        file[last] ^= 1;
+
+        let mut reader = ReaderBuilder::new()
+            .with_batch_size(2)
+            .build(file.as_slice())
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap().num_rows(), 2);
+        let err = reader.next().unwrap().unwrap_err();
+        let ArrowError::ExternalError(e) = &err else {
+            panic!("{err}")
+        };
+        let e = e.downcast_ref::<CorruptBlockError>().unwrap();
+        assert_eq!(e.offset(), second_block as u64);
+        assert_eq!(
+            err.to_string(),
+            format!("External error: Corrupt Avro block at byte offset {second_block}: sync marker does not match file header")
+        );
+    }
+
+    #[test]
+    fn test_block_reader() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let block = |ids: &[i64]| {
+            let mut data = vec![];
+            ids.iter().for_each(|x| encode_long(&mut data, *x));
+            (ids.len(), data)
+        };
+        let blocks = [block(&[1, 2]), block(&[3]), block(&[4, 5, 6])];
+        let file = write_ocf(schema, &blocks);
+
+        let reader = BlockReader::try_new(file.as_slice()).unwrap();
+        assert_eq!(reader.header().sync(), SYNC);
+        let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(read.len(), 3);
+        for (i, (raw, (count, data))) in read.iter().zip(&blocks).enumerate() {
+            assert_eq!(raw.count, *count);
+            assert_eq!(&raw.data, data);
+            assert_eq!(raw.offset, write_ocf(schema, &blocks[..i]).len() as u64);
+        }
+
+        // Copy the header and the last two blocks verbatim
+        let mut compacted = file[..read[0].offset as usize].to_vec();
+        compacted.extend_from_slice(&file[read[1].offset as usize..]);
+        let batches = ReaderBuilder::new()
+            .build(compacted.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let ids = batches[0].column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(ids.values().as_ref(), &[3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[cfg(feature = "snappy")]
+    fn test_verify_checksums() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let mut data = vec![];
+        encode_long(&mut data, 42);
+        let mut block = snap::raw::Encoder::new().compress_vec(&data).unwrap();
+        let crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(&data);
+        block.extend_from_slice(&(crc ^ 1).to_be_bytes());
+        let metadata: &[(&str, &[u8])] = &[("avro.codec", b"snappy")];
+        let file = write_ocf_with_metadata(schema, metadata, &[(1, block)]);
+        let offset = write_ocf_with_metadata(schema, metadata, &[]).len();
+
+        let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        let expected = format!(
+            "Corrupt Avro block at byte offset {offset}: Parser error: Snappy CRC mismatch"
+        );
+        assert_eq!(err.to_string(), format!("External error: {expected}"));
+
+        let mut reader = ReaderBuilder::new()
+            .with_verify_checksums(false)
+            .build(file.as_slice())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let ids = batch.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(ids.values().as_ref(), &[42]);
+    }
+
+    #[test]
+    #[cfg(feature = "snappy")]
+    fn test_worker_thread() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let snappy_block = |ids: std::ops::Range<i64>| {
+            let mut data = vec![];
+            ids.clone().for_each(|x| encode_long(&mut data, x));
+            let crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(&data);
+            let mut block = snap::raw::Encoder::new().compress_vec(&data).unwrap();
+            block.extend_from_slice(&crc.to_be_bytes());
+            (ids.count(), block)
+        };
+        let blocks: Vec<_> = (0..10).map(|b| snappy_block(b * 5..b * 5 + 5)).collect();
+        let metadata: &[(&str, &[u8])] = &[("avro.codec", b"snappy")];
+        let mut file = write_ocf_with_metadata(schema, metadata, &blocks);
+
+        let read = |builder: ReaderBuilder, file: &[u8]| {
+            let reader = builder
+                .with_batch_size(3)
+                .build_with_worker_thread(std::io::Cursor::new(file.to_vec()))
+                .unwrap();
+            let mut ids: Vec<i64> = vec![];
+            for batch in reader {
+                match batch {
+                    Ok(b) => ids.extend(b.column(0).as_primitive::<types::Int64Type>().values()),
+                    Err(e) => return (ids, Some(e)),
+                }
+            }
+            (ids, None)
+        };
+        let (ids, err) = read(ReaderBuilder::new(), &file);
+        assert!(err.is_none());
+        assert_eq!(ids, (0..50).collect::<Vec<_>>());
+
+        let (ids, err) = read(ReaderBuilder::new().with_skip(12).with_limit(20), &file);
+        assert!(err.is_none());
+        assert_eq!(ids, (12..32).collect::<Vec<_>>());
+
+        // Blocks preceding a corrupt block are returned before the error
+        let last_block = write_ocf_with_metadata(schema, metadata, &blocks[..9]).len();
+        let len = file.len();
+        file[len - 17] ^= 1;
+        let (ids, err) = read(ReaderBuilder::new(), &file);
+        assert_eq!(ids, (0..45).collect::<Vec<_>>());
+        let err = err.unwrap().to_string();
+        let expected = format!("Corrupt Avro block at byte offset {last_block}");
+        assert!(err.contains(&expected), "{err}");
+    }
+
+    #[test]
+    fn test_worker_thread_panic() {
+        /// Panics when reading beyond `limit` bytes
+        struct PanicReader {
+            data: std::io::Cursor<Vec<u8>>,
+            limit: u64,
+        }
+
+        impl std::io::Read for PanicReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = std::io::BufRead::fill_buf(self)?.len().min(buf.len());
+                std::io::Read::read(&mut self.data, &mut buf[..n])
+            }
+        }
+
+        impl BufRead for PanicReader {
+            fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+                if self.data.position() >= self.limit {
+                    panic!("read failed");
+                }
+                self.data.fill_buf()
+            }
+
+            fn consume(&mut self, amt: usize) {
+                self.data.consume(amt)
+            }
+        }
+
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let mut data = vec![];
+        encode_long(&mut data, 1);
+        let file = write_ocf(schema, &[(1, data)]);
+        let limit = write_ocf(schema, &[]).len() as u64;
+        let reader = PanicReader {
+            data: std::io::Cursor::new(file),
+            limit,
+        };
+
+        let mut reader = ReaderBuilder::new()
+            .build_with_worker_thread(reader)
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External error: Avro reader worker thread panicked: read failed"
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "name", "type": ["null", "string"]},
+                {"name": "score", "type": "double"}
+            ]
+        }"#;
+        let mut decoder = ReaderBuilder::new()
+            .with_statistics(true)
+            .build_message_decoder(schema)
+            .unwrap();
+        assert!(decoder.statistics().unwrap().is_empty());
+
+        let rows = [
+            (3, Some("b"), 1.5),
+            (-1, None, f64::NAN),
+            (7, Some("ab"), -2.0),
+            (2, None, 0.0),
+        ];
+        for (id, name, score) in rows {
+            let mut data = vec![];
+            encode_long(&mut data, id);
+            match name {
+                Some(name) => {
+                    encode_long(&mut data, 1);
+                    encode_bytes(&mut data, name.as_bytes());
+                }
+                None => encode_long(&mut data, 0),
+            }
+            data.extend_from_slice(&f64::to_le_bytes(score));
+            decoder.decode(&data).unwrap();
+        }
+        decoder.flush().unwrap().unwrap();
+
+        let stats = decoder.statistics().unwrap();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0].null_count(), 0);
+        assert_eq!(stats[0].min(), Some(&StatisticsValue::Int64(-1)));
+        assert_eq!(stats[0].max(), Some(&StatisticsValue::Int64(7)));
+        assert_eq!(stats[1].null_count(), 2);
+        assert_eq!(
+            stats[1].min(),
+            Some(&StatisticsValue::Bytes(b"ab".to_vec()))
+        );
+        assert_eq!(stats[1].max(), Some(&StatisticsValue::Bytes(b"b".to_vec())));
+        assert_eq!(stats[2].min(), Some(&StatisticsValue::Float64(-2.0)));
+        assert_eq!(stats[2].max(), Some(&StatisticsValue::Float64(1.5)));
+
+        // Statistics are reset on flush
+        let mut data = vec![];
+        encode_long(&mut data, 10);
+        encode_long(&mut data, 0);
+        data.extend_from_slice(&f64::to_le_bytes(f64::NAN));
+        decoder.decode(&data).unwrap();
+        decoder.flush().unwrap().unwrap();
+
+        let stats = decoder.statistics().unwrap();
+        assert_eq!(stats[0].min(), Some(&StatisticsValue::Int64(10)));
+        assert_eq!(stats[0].max(), Some(&StatisticsValue::Int64(10)));
+        assert_eq!(stats[1].null_count(), 1);
+        assert_eq!(stats[1].min(), None);
+        assert_eq!(stats[2].max(), None);
+
+        let decoder = ReaderBuilder::new().build_message_decoder(schema).unwrap();
+        assert!(decoder.statistics().is_none());
+    }
+
+    #[test]
+    fn test_struct_dictionary() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "host", "type": ["null", {
+                    "type": "record",
+                    "name": "host",
+                    "fields": [{"name": "name", "type": "string"}, {"name": "dc", "type": "string"}]
+                }]}
+            ]
+        }"#;
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "host", "type": ["null", {
+                    "type": "record",
+                    "name": "host",
+                    "fields": [{"name": "name", "type": "string"}]
+                }]}
+            ]
+        }"#;
+        let rows = [
+            (1, Some(("a", "x"))),
+            (2, Some(("b", "x"))),
+            (3, None),
+            (4, Some(("a", "x"))),
+            (5, Some(("a", "y"))),
+        ];
+        let mut records = vec![];
+        for (id, host) in rows {
+            let mut data = vec![];
+            encode_long(&mut data, id);
+            match host {
+                Some((name, dc)) => {
+                    encode_long(&mut data, 1);
+                    encode_bytes(&mut data, name.as_bytes());
+                    encode_bytes(&mut data, dc.as_bytes());
+                }
+                None => encode_long(&mut data, 0),
+            }
+            records.push(data);
+        }
+
+        let mut decoder = ReaderBuilder::new()
+            .with_struct_dictionary(true)
+            .build_message_decoder(schema)
+            .unwrap();
+        records.iter().for_each(|r| decoder.decode(r).unwrap());
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let host = batch.column(1).as_dictionary::<types::Int32Type>();
+        assert_eq!(host.keys().null_count(), 1);
+        assert_eq!(host.keys().values().as_ref(), &[0, 1, 0, 0, 2]);
+        let values = host.values().as_struct();
+        assert_eq!(values.len(), 3);
+        let names = values.column(0).as_string::<i32>();
+        assert_eq!(names.iter().flatten().collect::<Vec<_>>(), ["a", "b", "a"]);
+
+        let mut decoder = ReaderBuilder::new()
+            .with_struct_dictionary(true)
+            .with_reader_schema(reader_schema)
+            .build_message_decoder(schema)
+            .unwrap();
+        records.iter().for_each(|r| decoder.decode(r).unwrap());
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let host = batch.column(1).as_dictionary::<types::Int32Type>();
+        assert_eq!(host.keys().values().as_ref(), &[0, 1, 0, 0, 2]);
+        let values = host.values().as_struct();
+        assert_eq!(values.num_columns(), 1);
+        assert_eq!(values.len(), 3);
+
+        // Dictionaries are not shared across batches
+        decoder.decode(&records[1]).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        let host = batch.column(1).as_dictionary::<types::Int32Type>();
+        assert_eq!(host.keys().values().as_ref(), &[0]);
+        assert_eq!(host.values().len(), 1);
+    }
+
+    #[test]
+    fn test_multi_schema_decoder() {
+        let clicks = r#"{
+            "type": "record",
+            "name": "click",
+            "fields": [{"name": "url", "type": "string"}]
+        }"#;
+        let views = r#"{
+            "type": "record",
+            "name": "view",
+            "fields": [{"name": "id", "type": "long"}, {"name": "ms", "type": "long"}]
+        }"#;
+        let mut decoder = ReaderBuilder::new()
+            .with_batch_size(2)
+            .build_multi_schema_decoder();
+        decoder.register_schema(7, clicks).unwrap();
+        decoder.register_schema(3, views).unwrap();
+        let err = decoder.register_schema(3, clicks).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Avro schema ID 3 is already registered"
+        );
+        assert_eq!(decoder.schema(7).unwrap().field(0).name(), "url");
+        assert!(decoder.schema(1).is_none());
+
+        let message = |id: u32, body: &dyn Fn(&mut Vec<u8>)| {
+            let mut out = vec![];
+            body(&mut out);
+            AvroSchemaId::Confluent(id).encode(&out)
+        };
+        let messages = [
+            message(7, &|out| encode_bytes(out, b"a")),
+            message(3, &|out| [1, 10].iter().for_each(|v| encode_long(out, *v))),
+            message(7, &|out| encode_bytes(out, b"b")),
+        ];
+        let ids: Vec<_> = messages
+            .iter()
+            .map(|m| decoder.decode(m).unwrap())
+            .collect();
+        assert_eq!(ids, [7, 3, 7]);
+        assert_eq!(decoder.num_buffered(), 3);
+        assert!(decoder.is_full());
+
+        let batches = decoder.flush().unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].0, 3);
+        let expected = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int64Array::from(vec![1])) as _),
+            ("ms", Arc::new(Int64Array::from(vec![10])) as _),
+        ])
+        .unwrap();
+        assert_eq!(batches[0].1, expected);
+        assert_eq!(batches[1].0, 7);
+        let urls = batches[1].1.column(0).as_string::<i32>();
+        assert_eq!(urls.iter().flatten().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(decoder.num_buffered(), 0);
+        assert!(decoder.flush().unwrap().is_empty());
+
+        decoder.decode(&messages[1]).unwrap();
+        assert_eq!(decoder.flush_schema(7).unwrap(), None);
+        assert_eq!(decoder.flush_schema(3).unwrap().unwrap(), expected);
+
+        let err = decoder.decode(&message(1, &|_| {})).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Unknown Avro schema ID 1");
+        for data in [
+            [1, 0, 0, 0, 7].as_slice(),
+            &AvroSchemaId::SingleObject(7).encode(&[]),
+        ] {
+            let err = decoder.decode(data).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parser error: Avro message is not in the Confluent wire format"
+            );
+        }
+
+        // A rejected message is not buffered
+        let mut trailing = messages[1].clone();
+        trailing.push(0);
+        decoder.decode(&trailing).unwrap_err();
+        decoder.decode(&messages[1][..6]).unwrap_err();
+        assert_eq!(decoder.num_buffered(), 0);
+        assert!(decoder.flush().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_null_type_handling() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": "null"},
+                {"name": "id", "type": "long"},
+                {"name": "nested", "type": {
+                    "type": "record",
+                    "name": "nested",
+                    "fields": [{"name": "b", "type": "null"}, {"name": "c", "type": "int"}]
+                }}
+            ]
+        }"#;
+        let records: Vec<_> = (1..=3)
+            .map(|x| {
+                let mut data = vec![];
+                encode_long(&mut data, x);
+                encode_long(&mut data, x * 10);
+                data
+            })
+            .collect();
+        let decode = |builder: ReaderBuilder| {
+            let mut decoder = builder.build_message_decoder(schema).unwrap();
+            records.iter().for_each(|r| decoder.decode(r).unwrap());
+            decoder.flush().unwrap().unwrap()
+        };
+
+        let batch = decode(ReaderBuilder::new());
+        assert_eq!(batch.column(0).data_type(), &DataType::Null);
+        assert_eq!(batch.column(0).len(), 3);
+
+        let batch = decode(ReaderBuilder::new().with_null_type_handling(NullTypeHandling::Drop));
+        let schema_names: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(schema_names, ["id", "nested"]);
+        let nested = batch.column(1).as_struct();
+        assert_eq!(nested.num_columns(), 1);
+        assert_eq!(nested.column_names(), ["c"]);
+        let c = nested.column(0).as_primitive::<types::Int32Type>();
+        assert_eq!(c.values().as_ref(), &[10, 20, 30]);
+
+        let handling = NullTypeHandling::AllNull(DataType::Utf8);
+        let batch = decode(ReaderBuilder::new().with_null_type_handling(handling.clone()));
+        let a = batch.schema().field(0).clone();
+        assert_eq!(a, Field::new("a", DataType::Utf8, true));
+        assert_eq!(batch.column(0).null_count(), 3);
+        let nested = batch.column(2).as_struct();
+        assert_eq!(nested.column(0).data_type(), &DataType::Utf8);
+        assert_eq!(nested.column(0).null_count(), 3);
+
+        // Null fields are also handled when resolving against a reader schema
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "a", "type": "null"},
+                {"name": "d", "type": "null", "default": null}
+            ]
+        }"#;
+        let batch = decode(
+            ReaderBuilder::new()
+                .with_reader_schema(reader_schema)
+                .with_null_type_handling(NullTypeHandling::Drop),
+        );
+        assert_eq!(batch.num_columns(), 1);
+        let id = batch.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(id.values().as_ref(), &[1, 2, 3]);
+
+        let batch = decode(
+            ReaderBuilder::new()
+                .with_reader_schema(reader_schema)
+                .with_null_type_handling(handling),
+        );
+        assert_eq!(batch.num_columns(), 3);
+        assert_eq!(batch.column(2).data_type(), &DataType::Utf8);
+        assert_eq!(batch.column(2).null_count(), 3);
+    }
+
+    /// Decodes XOR "encrypted" bytes to strings
+    #[derive(Debug, Default)]
+    struct XorDecoder(Vec<String>);
+
+    impl FieldDecoder for XorDecoder {
+        fn data_type(&self) -> DataType {
+            DataType::Utf8
+        }
+
+        fn decode(&mut self, value: &[u8]) -> Result<(), ArrowError> {
+            let decrypted: Vec<_> = value.iter().map(|x| x ^ 0x2a).collect();
+            let s =
+                String::from_utf8(decrypted).map_err(|e| ArrowError::ExternalError(e.into()))?;
+            self.0.push(s);
+            Ok(())
+        }
+
+        fn append_null(&mut self) {
+            self.0.push(String::new());
+        }
+
+        fn truncate(&mut self, len: usize) {
+            self.0.truncate(len);
+        }
+
+        fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
+            let values = StringArray::from(std::mem::take(&mut self.0));
+            let (offsets, values, _) = values.into_parts();
+            Ok(Arc::new(StringArray::try_new(offsets, values, nulls)?))
+        }
+    }
+
+    /// A [`XorDecoder`] using the default [`FieldDecoder::truncate`]
+    #[derive(Debug, Default)]
+    struct NoTruncateDecoder(XorDecoder);
+
+    impl FieldDecoder for NoTruncateDecoder {
+        fn data_type(&self) -> DataType {
+            self.0.data_type()
+        }
+
+        fn decode(&mut self, value: &[u8]) -> Result<(), ArrowError> {
+            self.0.decode(value)
+        }
+
+        fn append_null(&mut self) {
+            self.0.append_null()
+        }
+
+        fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
+            self.0.flush(nulls)
+        }
+    }
+
+    #[test]
+    fn test_field_decoder() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "secret", "type": ["null", "bytes"]},
+                {"name": "meta", "type": {
+                    "type": "record",
+                    "name": "meta",
+                    "fields": [{"name": "id", "type": "long"}, {"name": "token", "type": "bytes"}]
+                }}
+            ]
+        }"#;
+        let encrypt = |s: &str| s.bytes().map(|x| x ^ 0x2a).collect::<Vec<_>>();
+        let rows = [(Some("a"), 1, "x"), (None, 2, "yz")];
+        let mut records = vec![];
+        for (secret, id, token) in rows {
+            let mut data = vec![];
+            match secret {
+                Some(secret) => {
+                    encode_long(&mut data, 1);
+                    encode_bytes(&mut data, &encrypt(secret));
+                }
+                None => encode_long(&mut data, 0),
+            }
+            encode_long(&mut data, id);
+            encode_bytes(&mut data, &encrypt(token));
+            records.push(data);
+        }
+
+        let factory = |field: &Field| -> Result<Box<dyn FieldDecoder>, ArrowError> {
+            assert_eq!(field.data_type(), &DataType::Binary);
+            Ok(Box::<XorDecoder>::default())
+        };
+        let mut decoder = ReaderBuilder::new()
+            .with_field_decoder("secret", factory)
+            .with_field_decoder("meta.token", factory)
+            .with_field_decoder("missing", factory)
+            .build_message_decoder(schema)
+            .unwrap();
+        records.iter().for_each(|r| decoder.decode(r).unwrap());
+        let batch = decoder.flush().unwrap().unwrap();
+
+        assert_eq!(
+            batch.schema().field(0),
+            &Field::new("secret", DataType::Utf8, true)
+        );
+        let secret = batch.column(0).as_string::<i32>();
+        assert_eq!(secret.iter().collect::<Vec<_>>(), [Some("a"), None]);
+        let meta = batch.column(1).as_struct();
+        let id = meta.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(id.values().as_ref(), &[1, 2]);
+        let token = meta.column(1).as_string::<i32>();
+        assert_eq!(token.iter().flatten().collect::<Vec<_>>(), ["x", "yz"]);
+
+        // The value of a record that fails to decode after the field is retained
+        let factory = |_: &Field| -> Result<Box<dyn FieldDecoder>, ArrowError> {
+            Ok(Box::<NoTruncateDecoder>::default())
+        };
+        let mut decoder = ReaderBuilder::new()
+            .with_field_decoder("secret", factory)
+            .build_message_decoder(schema)
+            .unwrap();
+        decoder.decode(&records[1]).unwrap();
+        let partial = &records[0][..records[0].len() - 1];
+        decoder.decode(partial).unwrap_err();
+        decoder.flush().unwrap_err();
+    }
+
+    #[test]
+    fn test_nested_nulls() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "s", "type": ["null", {
+                    "type": "record",
+                    "name": "s",
+                    "fields": [
+                        {"name": "a", "type": "long"},
+                        {"name": "b", "type": ["null", "string"]}
+                    ]
+                }]}
+            ]
+        }"#;
+        let record = |s: Option<(i64, Option<&str>)>| {
+            let mut data = vec![];
+            encode_long(&mut data, s.is_some() as i64);
+            if let Some((a, b)) = s {
+                encode_long(&mut data, a);
+                encode_long(&mut data, b.is_some() as i64);
+                if let Some(b) = b {
+                    encode_bytes(&mut data, b.as_bytes());
+                }
+            }
+            data
+        };
+
+        let mut decoder = ReaderBuilder::new()
+            .with_strict_validation(true)
+            .build_message_decoder(schema)
+            .unwrap();
+        decoder.decode(&record(Some((1, Some("x"))))).unwrap();
+        decoder.decode(&record(None)).unwrap();
+        decoder.decode(&record(Some((3, None)))).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let s = batch.column(0).as_struct();
+        let validity = |a: &dyn Array| (0..a.len()).map(|i| a.is_valid(i)).collect::<Vec<_>>();
+        assert_eq!(validity(s), [true, false, true]);
+        // The non-nullable child has no nulls of its own, with the struct's nulls distinct
+        assert_eq!(s.column(0).null_count(), 0);
+        assert_eq!(validity(s.column(1).as_ref()), [true, false, false]);
+        assert_eq!(s.column(1).as_string::<i32>().value(0), "x");
+
+        // A transform replacing the string values with invalid UTF-8
+        let invalid_utf8 = |array: ArrayRef| {
+            let (fields, columns, nulls) = array.as_struct().clone().into_parts();
+            let (offsets, values, b_nulls) = columns[1].as_string::<i32>().clone().into_parts();
+            let values = arrow_buffer::Buffer::from_vec(vec![0xFF_u8; values.len()]);
+            // SAFETY: deliberately invalid, to be detected by strict validation
+            let b = unsafe { StringArray::new_unchecked(offsets, values, b_nulls) };
+            let columns = vec![columns[0].clone(), Arc::new(b) as ArrayRef];
+            Ok(Arc::new(StructArray::try_new(fields, columns, nulls)?) as ArrayRef)
+        };
+        for strict_validation in [false, true] {
+            let mut decoder = ReaderBuilder::new()
+                .with_strict_validation(strict_validation)
+                .with_column_transform("s", invalid_utf8)
+                .build_message_decoder(schema)
+                .unwrap();
+            decoder.decode(&record(Some((1, Some("x"))))).unwrap();
+            let result = decoder.flush();
+            match strict_validation {
+                true => assert!(
+                    result
+                        .as_ref()
+                        .unwrap_err()
+                        .to_string()
+                        .contains("Invalid UTF8"),
+                    "{result:?}"
+                ),
+                false => assert_eq!(result.unwrap().unwrap().num_rows(), 1),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arrow_schema")]
+    fn test_arrow_schema() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                {"name": "empty", "type": "null"},
+                {"name": "tag", "type": "string"}
+            ]
+        }"#;
+        let mut data = vec![];
+        for (ts, tag) in [(1_000_000, "a"), (2_000_001, "b"), (3_000_000, "a")] {
+            encode_long(&mut data, ts);
+            encode_bytes(&mut data, tag.as_bytes());
+        }
+
+        let ts_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into()));
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let arrow_schema = Schema::new(vec![
+            Field::new("ts", ts_type.clone(), false),
+            Field::new("empty", DataType::Null, true),
+            Field::new("tag", dict_type.clone(), false),
+        ]);
+        let encoded = encode_arrow_schema(&arrow_schema);
+        let metadata: &[(&str, &[u8])] = &[(ARROW_SCHEMA_METADATA_KEY, &encoded)];
+        let file = write_ocf_with_metadata(schema, metadata, &[(3, data)]);
+
+        let reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .build(file.as_slice())
+            .unwrap();
+        assert_eq!(reader.schema().fields(), arrow_schema.fields());
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let ts = batches[0]
+            .column(0)
+            .as_primitive::<types::TimestampNanosecondType>();
+        assert_eq!(ts.values(), &[1_000_000_000, 2_000_001_000, 3_000_000_000]);
+        let tag = batches[0].column(2).as_dictionary::<types::Int32Type>();
+        assert_eq!(tag.keys().values(), &[0, 1, 0]);
+
+        // Not restored by default
+        let reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let micros = DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()));
+        assert_eq!(reader.schema().field(0).data_type(), &micros);
+
+        // Fields are matched by name, with dropped and projected fields ignored
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "tag", "type": "string"}]
+        }"#;
+        let reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .with_reader_schema(reader_schema)
+            .build(file.as_slice())
+            .unwrap();
+        assert_eq!(reader.schema().fields().len(), 1);
+        assert_eq!(reader.schema().field(0).data_type(), &dict_type);
+        let reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .with_null_type_handling(NullTypeHandling::Drop)
+            .build(file.as_slice())
+            .unwrap();
+        let types: Vec<_> = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(types, [ts_type.clone(), dict_type.clone()]);
+
+        // The output of a column transform is not cast, even if of the decoded type
+        let identity = |array: ArrayRef| -> Result<ArrayRef, ArrowError> { Ok(array) };
+        let reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .with_column_transform("ts", identity)
+            .build(file.as_slice())
+            .unwrap();
+        assert_eq!(reader.schema().field(0).data_type(), &micros);
+        assert_eq!(reader.schema().field(2).data_type(), &dict_type);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches[0].column(0).data_type(), &micros);
+
+        let metadata: &[(&str, &[u8])] = &[(ARROW_SCHEMA_METADATA_KEY, b"invalid")];
+        let file = write_ocf_with_metadata(schema, metadata, &[]);
+        let err = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .build(file.as_slice())
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Failed to parse stored arrow schema"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow_schema")]
+    fn test_arrow_schema_with_builder_options() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "tag", "type": "string"},
+                {"name": "u", "type": ["int", "string"]}
+            ]
+        }"#;
+        let mut data = vec![];
+        encode_bytes(&mut data, b"a");
+        encode_long(&mut data, 0);
+        encode_long(&mut data, 7);
+
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let arrow_schema = Schema::new(vec![
+            Field::new("tag", dict_type.clone(), false),
+            Field::new("u", DataType::LargeUtf8, false),
+        ]);
+        let encoded = encode_arrow_schema(&arrow_schema);
+        let metadata: &[(&str, &[u8])] = &[(ARROW_SCHEMA_METADATA_KEY, &encoded)];
+        let file = write_ocf_with_metadata(schema, metadata, &[(1, data)]);
+
+        // The writer schema is decoded with the union handling of the builder
+        let reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .with_reader_schema(schema)
+            .with_union_handling("u", UnionHandling::Utf8)
+            .build(file.as_slice())
+            .unwrap();
+        assert_eq!(reader.schema().fields(), arrow_schema.fields());
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches[0].column(1).as_string::<i64>().value(0), "7");
+    }
+
+    #[test]
+    fn test_map_key_type() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "m", "type": {"type": "map", "values": "string", "arrow.key-type": "long"}}
+            ]
+        }"#;
+        let record = |entries: &[(&str, &str)]| {
+            let mut data = vec![];
+            if !entries.is_empty() {
+                // Encode as a block with its size in bytes
+                let mut block = vec![];
+                for (k, v) in entries {
+                    encode_bytes(&mut block, k.as_bytes());
+                    encode_bytes(&mut block, v.as_bytes());
+                }
+                encode_long(&mut data, -(entries.len() as i64));
+                encode_long(&mut data, block.len() as i64);
+                data.extend_from_slice(&block);
+            }
+            encode_long(&mut data, 0);
+            data
+        };
+
+        let mut decoder = ReaderBuilder::new().build_message_decoder(schema).unwrap();
+        decoder
+            .decode(&record(&[("1", "a"), ("-20", "b")]))
+            .unwrap();
+        decoder.decode(&record(&[])).unwrap();
+        decoder.decode(&record(&[("300", "c")])).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let map = batch.column(0).as_map();
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 3]);
+        let keys = map.keys().as_primitive::<types::Int64Type>();
+        assert_eq!(keys.values(), &[1, -20, 300]);
+        let values = map.values().as_string::<i32>();
+        assert_eq!(values.iter().flatten().collect::<Vec<_>>(), ["a", "b", "c"]);
+        let DataType::Map(entries, false) = batch.schema().field(0).data_type().clone() else {
+            panic!("expected map")
+        };
+        let DataType::Struct(fields) = entries.data_type() else {
+            panic!("expected struct")
+        };
+        assert_eq!(fields[0].data_type(), &DataType::Int64);
+        assert!(!fields[0].is_nullable());
+
+        let err = decoder.decode(&record(&[("x", "a")])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Failed to parse map key \"x\" as long"
+        );
+
+        let invalid = schema.replace(r#""long""#, r#""double""#);
+        let err = ReaderBuilder::new()
+            .build_message_decoder(&invalid)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported arrow.key-type"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "bytes", "logicalType": "json"}},
+                {"name": "b", "type": ["null", {"type": "string", "connect.name": "json"}]}
+            ]
+        }"#;
+        let record = |a: &str, b: &str| {
+            let mut data = vec![];
+            encode_bytes(&mut data, a.as_bytes());
+            encode_long(&mut data, 1);
+            encode_bytes(&mut data, b.as_bytes());
+            data
+        };
+        let valid = record(r#"{"x": 1}"#, "[1, 2]");
+        let invalid = record("{", "[1, 2]");
+
+        let mut decoder = ReaderBuilder::new()
+            .with_json_attribute("connect.name", "json")
+            .build_message_decoder(schema)
+            .unwrap();
+        decoder.decode(&valid).unwrap();
+        decoder.decode(&invalid).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        for field in batch.schema().fields() {
+            assert_eq!(field.data_type(), &DataType::Utf8);
+            assert_eq!(
+                field.metadata()[EXTENSION_TYPE_NAME_KEY],
+                JSON_EXTENSION_NAME
+            );
+        }
+        let a = batch.column(0).as_string::<i32>();
+        assert_eq!(a.value(1), "{");
+
+        // Bytes that are not valid UTF-8 are an error, rather than a panic
+        let mut data = vec![];
+        encode_bytes(&mut data, &[0xff, 0xfe]);
+        encode_long(&mut data, 0);
+        decoder.decode(&data).unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert!(err.to_string().contains("UTF-8"), "{err}");
+
+        let mut decoder = ReaderBuilder::new()
+            .with_json_attribute("connect.name", "json")
+            .with_validate_json(true)
+            .build_message_decoder(schema)
+            .unwrap();
+        decoder.decode(&valid).unwrap();
+        let err = decoder.decode(&invalid).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Parser error: Invalid JSON value"),
+            "{err}"
+        );
+        let err = decoder.decode(&record("1", "[")).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Parser error: Invalid JSON value"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_column_transform() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "ip", "type": ["null", "string"]},
+                {"name": "key", "type": "string"}
+            ]
+        }"#;
+        let parse_ip = |array: ArrayRef| -> Result<ArrayRef, ArrowError> {
+            let ips = array
+                .as_string::<i32>()
+                .iter()
+                .map(|ip| {
+                    ip.map(|ip| match ip.parse::<std::net::IpAddr>() {
+                        Ok(std::net::IpAddr::V4(ip)) => Ok(ip.to_ipv6_mapped().octets()),
+                        Ok(std::net::IpAddr::V6(ip)) => Ok(ip.octets()),
+                        Err(e) => Err(ArrowError::ParseError(format!("{ip}: {e}"))),
+                    })
+                    .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let ips = FixedSizeBinaryArray::try_from_sparse_iter_with_size(ips.into_iter(), 16)?;
+            Ok(Arc::new(ips))
+        };
+        let lowercase = |array: ArrayRef| -> Result<ArrayRef, ArrowError> {
+            let keys = array.as_string::<i32>();
+            let lower: StringArray = keys.iter().map(|x| x.map(str::to_lowercase)).collect();
+            Ok(Arc::new(lower))
+        };
+        let mut decoder = ReaderBuilder::new()
+            .with_column_transform("ip", parse_ip)
+            .with_column_transform("key", lowercase)
+            .build_message_decoder(schema)
+            .unwrap();
+        assert_eq!(
+            decoder.schema().field(0),
+            &Field::new("ip", DataType::FixedSizeBinary(16), true)
+        );
+
+        let record = |ip: Option<&str>, key: &str| {
+            let mut data = vec![];
+            match ip {
+                Some(ip) => {
+                    encode_long(&mut data, 1);
+                    encode_bytes(&mut data, ip.as_bytes());
+                }
+                None => encode_long(&mut data, 0),
+            }
+            encode_bytes(&mut data, key.as_bytes());
+            data
+        };
+        decoder.decode(&record(Some("10.0.0.1"), "Host")).unwrap();
+        decoder.decode(&record(None, "PORT")).unwrap();
+        decoder.decode(&record(Some("::1"), "id")).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let ips = batch.column(0).as_fixed_size_binary();
+        assert_eq!(ips.null_count(), 1);
+        assert_eq!(ips.value(0)[10..], [0xff, 0xff, 10, 0, 0, 1]);
+        assert_eq!(ips.value(2), std::net::Ipv6Addr::LOCALHOST.octets());
+        let keys = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            keys.iter().flatten().collect::<Vec<_>>(),
+            ["host", "port", "id"]
+        );
+
+        decoder.decode(&record(Some("invalid"), "a")).unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert!(
+            err.to_string().starts_with("Parser error: invalid"),
+            "{err}"
+        );
+
+        // All columns are flushed, despite the failed transform
+        decoder.decode(&record(None, "B")).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.column(1).as_string::<i32>().value(0), "b");
+    }
+
+    #[test]
+    fn test_index_columns() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let block = |ids: &[i64]| {
+            let mut data = vec![];
+            ids.iter().for_each(|x| encode_long(&mut data, *x));
+            (ids.len(), data)
+        };
+        let file = write_ocf(schema, &[block(&[1, 2, 3]), block(&[]), block(&[4, 5])]);
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(2)
+            .with_row_index_column("_row")
+            .with_block_index_column("_block")
+            .build(file.as_slice())
+            .unwrap();
+        let schema = reader.schema();
+        assert_eq!(
+            schema.field(1),
+            &Field::new("_row", DataType::UInt64, false)
+        );
+        assert_eq!(
+            schema.field(2),
+            &Field::new("_block", DataType::UInt64, false)
+        );
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let column = |idx: usize| -> Vec<u64> {
+            batches
+                .iter()
+                .flat_map(|b| {
+                    b.column(idx)
+                        .as_primitive::<types::UInt64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect()
+        };
+        assert_eq!(column(1), [0, 1, 2, 3, 4]);
+        assert_eq!(column(2), [0, 0, 0, 2, 2]);
+
+        let err = ReaderBuilder::new()
+            .with_row_index_column("id")
+            .build(file.as_slice())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Index column \"id\" conflicts with an existing column"
+        );
+    }
+
+    #[test]
+    fn test_skip_limit() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let block = |ids: std::ops::Range<i64>| {
+            let mut data = vec![];
+            ids.clone().for_each(|x| encode_long(&mut data, x));
+            (ids.count(), data)
+        };
+        let mut file = write_ocf(schema, &[block(0..3), block(3..5), block(5..9)]);
+        // Corrupt the first block, which is skipped without being decoded
+        let first_block = write_ocf(schema, &[]).len();
+        file[first_block + 2] = 0xff;
+
+        let read = |skip: usize, limit: Option<usize>| -> Vec<i64> {
+            let mut builder = ReaderBuilder::new()
+                .with_batch_size(2)
+                .with_skip(skip)
+                .with_row_index_column("_row");
+            if let Some(limit) = limit {
+                builder = builder.with_limit(limit);
+            }
+            let reader = builder.build(file.as_slice()).unwrap();
+            reader
+                .flat_map(|b| {
+                    let b = b.unwrap();
+                    let ids = b.column(0).as_primitive::<types::Int64Type>();
+                    let rows = b.column(1).as_primitive::<types::UInt64Type>();
+                    assert!(ids
+                        .values()
+                        .iter()
+                        .zip(rows.values())
+                        .all(|(a, b)| *a as u64 == *b));
+                    ids.values().to_vec()
+                })
+                .collect()
+        };
+        assert_eq!(read(3, None), [3, 4, 5, 6, 7, 8]);
+        assert_eq!(read(4, Some(3)), [4, 5, 6]);
+        assert_eq!(read(6, Some(0)), Vec::<i64>::new());
+        assert_eq!(read(8, Some(10)), [8]);
+        assert_eq!(read(9, None), Vec::<i64>::new());
+        assert_eq!(read(100, None), Vec::<i64>::new());
+
+        let batches: Vec<_> = ReaderBuilder::new()
+            .with_batch_size(2)
+            .with_skip(3)
+            .with_limit(5)
+            .build(file.as_slice())
+            .unwrap()
+            .map(|b| b.unwrap().num_rows())
+            .collect();
+        assert_eq!(batches, [2, 2, 1]);
+    }
+
+    #[test]
+    fn test_message_decoder() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": "string"}]
+        }"#;
+        let mut decoder = ReaderBuilder::new()
+            .with_batch_size(2)
+            .build_message_decoder(schema)
+            .unwrap();
+        assert!(decoder.flush().unwrap().is_none());
+
+        for (id, name) in [(1, "a"), (2, "bc")] {
+            let mut data = vec![];
+            encode_long(&mut data, id);
+            encode_bytes(&mut data, name.as_bytes());
+            decoder.decode(&data).unwrap();
+        }
+        assert!(decoder.is_full());
+
+        let batch = decoder.flush().unwrap().unwrap();
+        let expected = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int64Array::from(vec![1, 2])) as _),
+            ("name", Arc::new(StringArray::from(vec!["a", "bc"])) as _),
+        ])
+        .unwrap();
+        assert_eq!(batch, expected);
+        assert_eq!(decoder.num_buffered(), 0);
+
+        let mut data = vec![];
+        encode_long(&mut data, 3);
+        encode_bytes(&mut data, b"d");
+        data.push(0);
+        let err = decoder.decode(&data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Avro record of 3 bytes followed by 1 unexpected bytes"
+        );
+        assert_eq!(decoder.num_buffered(), 0);
+        assert!(decoder.flush().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_message_decoder_partial_record() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "tags", "type": {"type": "map", "values": "string"}},
+                {"name": "name", "type": ["null", "string"]}
+            ]
+        }"#;
+        let mut decoder = ReaderBuilder::new()
+            .with_statistics(true)
+            .build_message_decoder(schema)
+            .unwrap();
+
+        let record = |id: i64, tags: &[(&str, &str)], name: Option<&[u8]>| {
+            let mut out = vec![];
+            encode_long(&mut out, id);
+            if !tags.is_empty() {
+                encode_long(&mut out, tags.len() as i64);
+            }
+            for (k, v) in tags {
+                encode_bytes(&mut out, k.as_bytes());
+                encode_bytes(&mut out, v.as_bytes());
+            }
+            encode_long(&mut out, 0);
+            match name {
+                Some(name) => {
+                    encode_long(&mut out, 1);
+                    encode_bytes(&mut out, name);
+                }
+                None => encode_long(&mut out, 0),
+            }
+            out
+        };
+
+        decoder
+            .decode(&record(5, &[("a", "b")], Some(b"x")))
+            .unwrap();
+        // Fails decoding the name, after decoding the other fields
+        let mut partial = record(1, &[("c", "d"), ("e", "f")], Some(b"long name"));
+        partial.truncate(partial.len() - 2);
+        decoder.decode(&partial).unwrap_err();
+        // Fails decoding the second map entry
+        let partial = record(0, &[("g", "h"), ("i", "j")], None);
+        decoder.decode(&partial[..partial.len() - 4]).unwrap_err();
+        decoder.decode(&record(7, &[], None)).unwrap();
+        assert_eq!(decoder.num_buffered(), 2);
+
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        let ids = batch.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(ids.values().as_ref(), &[5, 7]);
+        let tags = batch.column(1).as_map();
+        assert_eq!(tags.value_offsets(), &[0, 1, 1]);
+        assert_eq!(tags.keys().as_string::<i32>().value(0), "a");
+        assert_eq!(tags.values().as_string::<i32>().value(0), "b");
+        let names = batch.column(2).as_string::<i32>();
+        assert_eq!(names.iter().collect::<Vec<_>>(), [Some("x"), None]);
+
+        let stats = decoder.statistics().unwrap();
+        assert_eq!(stats[0].min(), Some(&StatisticsValue::Int64(5)));
+        assert_eq!(stats[0].max(), Some(&StatisticsValue::Int64(7)));
+        assert_eq!(stats[2].null_count(), 1);
+        assert_eq!(stats[2].max(), Some(&StatisticsValue::Bytes(b"x".to_vec())));
+    }
+
+    #[test]
+    fn test_enum() {
+        let enum_schema = |symbols: &str, default: &str| {
+            format!(
+                r#"{{"type": "record", "name": "r", "fields": [{{"name": "e", "type": {{
+                    "type": "enum", "name": "e", "symbols": {symbols}{default}
+                }}}}]}}"#
+            )
+        };
+        let writer = enum_schema(r#"["A", "B", "C"]"#, "");
+        let mut data = vec![];
+        [0, 1, 2, 1].iter().for_each(|x| encode_long(&mut data, *x));
+        let file = write_ocf(&writer, &[(4, data)]);
+
+        let read = |reader_schema: Option<String>| {
+            let mut builder = ReaderBuilder::new();
+            if let Some(schema) = reader_schema {
+                builder = builder.with_reader_schema(schema);
+            }
+            let mut reader = builder.build(file.as_slice()).unwrap();
+            reader.next().unwrap()
+        };
+        let values = |batch: &RecordBatch| -> Vec<String> {
+            let dict = batch.column(0).as_dictionary::<types::Int32Type>();
+            let symbols = dict.values().as_string::<i32>();
+            let keys = dict.keys().values().iter();
+            keys.map(|k| symbols.value(*k as usize).to_string())
+                .collect()
+        };
+
+        let batch = read(None).unwrap();
+        assert_eq!(values(&batch), ["A", "B", "C", "B"]);
+        let field = batch.schema().field(0).clone();
+        assert_eq!(
+            field.metadata()[ENUM_SYMBOLS_METADATA_KEY],
+            r#"["A","B","C"]"#
+        );
+        assert_eq!(enum_symbol_mapping(&field), None);
+
+        // Writer symbols not present in the reader are decoded to the reader default
+        let reader = enum_schema(r#"["C", "B", "X"]"#, r#", "default": "X""#);
+        let batch = read(Some(reader)).unwrap();
+        assert_eq!(values(&batch), ["X", "B", "C", "B"]);
+        let field = batch.schema().field(0).clone();
+        assert_eq!(enum_symbols(&field).unwrap(), ["C", "B", "X"]);
+        assert_eq!(field.metadata()[ENUM_DEFAULT_METADATA_KEY], "X");
+        assert_eq!(
+            field.metadata()[ENUM_WRITER_SYMBOLS_METADATA_KEY],
+            r#"["A","B","C"]"#
+        );
+        let mapping = enum_symbol_mapping(&field).unwrap();
+        assert_eq!(mapping, [Some(2), Some(1), Some(0)]);
+
+        let reader = enum_schema(r#"["C", "B"]"#, "");
+        let err = read(Some(reader)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Enum symbol \"A\" not present in reader enum, which has no default"
+        );
+
+        let mut data = vec![];
+        encode_long(&mut data, 3);
+        let file = write_ocf(&writer, &[(1, data)]);
+        let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Enum symbol index 3 out of range for 3 symbols"
+        );
+
+        let invalid = enum_schema(r#"["A"]"#, r#", "default": "B""#);
+        let err = ReaderBuilder::new()
+            .build_message_decoder(&invalid)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Enum default \"B\" is not one of its symbols"
+        );
+    }
+
+    #[test]
+    fn test_decimal_rescale() {
+        let writer_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                {"name": "b", "type": ["null", {
+                    "type": "fixed",
+                    "name": "b",
+                    "size": 3,
+                    "logicalType": "decimal",
+                    "precision": 6,
+                    "scale": 1
+                }]},
+                {"name": "c", "type": {"type": "bytes", "logicalType": "decimal", "precision": 40}}
+            ]
+        }"#;
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": {"type": "bytes", "logicalType": "decimal", "precision": 6, "scale": 3}},
+                {"name": "b", "type": ["null", {"type": "bytes", "logicalType": "decimal", "precision": 5}]},
+                {"name": "c", "type": {"type": "bytes", "logicalType": "decimal", "precision": 38}}
+            ]
+        }"#;
+
+        let mut data = vec![];
+        // 123.45, -1.0 and 2^127
+        encode_bytes(&mut data, &[0x30, 0x39]);
+        encode_long(&mut data, 1);
+        data.extend_from_slice(&[0xFF, 0xFF, 0xF6]);
+        let mut big = vec![0x00, 0x80];
+        big.extend_from_slice(&[0; 15]);
+        encode_bytes(&mut data, &big);
+        // -0.01, null and -1 with redundant sign extension
+        encode_bytes(&mut data, &[0xFF]);
+        encode_long(&mut data, 0);
+        encode_bytes(&mut data, &[0xFF; 40]);
+        // 1234.56, 2.5 and 5
+        encode_bytes(&mut data, &[0x01, 0xE2, 0x40]);
+        encode_long(&mut data, 1);
+        data.extend_from_slice(&[0x00, 0x00, 0x19]);
+        encode_bytes(&mut data, &[0x05]);
+        // 0, -2.5 and 0
+        encode_bytes(&mut data, &[0x00]);
+        encode_long(&mut data, 1);
+        data.extend_from_slice(&[0xFF, 0xFF, 0xE7]);
+        encode_bytes(&mut data, &[0x00]);
+        let file = write_ocf(writer_schema, &[(4, data)]);
+
+        let err = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal value 170141183460469231731687303715884105728 of field \"c\" cannot be represented as Decimal128(38, 0)"
+        );
+
+        let options =
+            ResolutionOptions::default().with_decimal_overflow(DecimalOverflowHandling::Null);
+        let batch = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .with_resolution_options(options)
+            .build(file.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!(batch.schema().fields().iter().all(|f| f.is_nullable()));
+
+        let a = Decimal128Array::from(vec![Some(123450), Some(-10), None, Some(0)])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        // Rounded half away from zero
+        let b = Decimal128Array::from(vec![Some(-1), None, Some(3), Some(-3)])
+            .with_precision_and_scale(5, 0)
+            .unwrap();
+        let c = Decimal128Array::from(vec![None, Some(-1), Some(5), Some(0)])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        assert_eq!(batch.column(0).as_ref(), &a as &dyn Array);
+        assert_eq!(batch.column(1).as_ref(), &b as &dyn Array);
+        assert_eq!(batch.column(2).as_ref(), &c as &dyn Array);
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "amount", "type": {"type": "bytes", "logicalType": "decimal", "precision": 4, "scale": 2}},
+                {"name": "m", "type": {"type": "map", "values": {"type": "bytes", "logicalType": "decimal", "precision": 4}}}
+            ]
+        }"#;
+        // A value that does not fit in 16 bytes
+        let mut big = vec![0x01];
+        big.extend_from_slice(&[0; 16]);
+        let mut data = vec![];
+        encode_bytes(&mut data, &[0x01]);
+        encode_long(&mut data, 0);
+
+        encode_bytes(&mut data, &big);
+        encode_long(&mut data, 2);
+        encode_bytes(&mut data, b"a");
+        encode_bytes(&mut data, &[0x02]);
+        encode_bytes(&mut data, b"b");
+        encode_bytes(&mut data, &big);
+        encode_long(&mut data, 0);
+
+        encode_bytes(&mut data, &[0x03]);
+        encode_long(&mut data, 1);
+        encode_bytes(&mut data, b"c");
+        encode_bytes(&mut data, &big);
+        encode_long(&mut data, 0);
+        let file = write_ocf(schema, &[(3, data)]);
+
+        let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Decimal of 17 bytes exceeds 16 bytes"
+        );
+        assert!(reader.conversion_errors().is_none());
+
+        let mut reader = ReaderBuilder::new()
+            .with_conversion_errors(true)
+            .build(file.as_slice())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert!(batch.schema().field(0).is_nullable());
+        let amount = Decimal128Array::from(vec![Some(1), None, Some(3)])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        assert_eq!(batch.column(0).as_ref(), &amount as &dyn Array);
+        let m = batch.column(1).as_map();
+        assert_eq!(m.value_offsets(), &[0, 0, 2, 3]);
+        let values = m.values().as_primitive::<types::Decimal128Type>();
+        assert_eq!(values.iter().collect::<Vec<_>>(), [Some(2), None, None]);
+
+        let errors = reader.conversion_errors().unwrap();
+        assert_eq!(errors.schema(), conversion_errors_schema());
+        let rows = errors.column(0).as_primitive::<types::UInt64Type>();
+        assert_eq!(rows.values(), &[1, 1, 2]);
+        let columns = errors.column(1).as_string::<i32>();
+        assert_eq!(columns, &StringArray::from(vec!["amount", "m", "m"]));
+        let messages = errors.column(2).as_string::<i32>();
+        let expected = "Parser error: Decimal of 17 bytes exceeds 16 bytes";
+        assert!(messages.iter().all(|m| m == Some(expected)));
+    }
+
+    #[test]
+    fn test_conversion_errors_precision() {
+        // Values that fit in 16 bytes but exceed the precision of the decimal
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "amount", "type": {"type": "bytes", "logicalType": "decimal", "precision": 4, "scale": 2}}
+            ]
+        }"#;
+        let mut data = vec![];
+        encode_bytes(&mut data, &9999_i16.to_be_bytes());
+        encode_bytes(&mut data, &10000_i16.to_be_bytes());
+        encode_bytes(&mut data, &(-10000_i16).to_be_bytes());
+        let file = write_ocf(schema, &[(3, data)]);
+
+        let mut reader = ReaderBuilder::new()
+            .with_conversion_errors(true)
+            .build(file.as_slice())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let amount = Decimal128Array::from(vec![Some(9999), None, None])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        assert_eq!(batch.column(0).as_ref(), &amount as &dyn Array);
+
+        let errors = reader.conversion_errors().unwrap();
+        let rows = errors.column(0).as_primitive::<types::UInt64Type>();
+        assert_eq!(rows.values(), &[1, 2]);
+        let messages = errors.column(2).as_string::<i32>();
+        assert_eq!(
+            messages.value(0),
+            "Parser error: Decimal value 100.00 of field \"amount\" cannot be represented as Decimal128(4, 2)"
+        );
+        assert_eq!(
+            messages.value(1),
+            "Parser error: Decimal value -100.00 of field \"amount\" cannot be represented as Decimal128(4, 2)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow_schema")]
+    fn test_arrow_schema_conversion_errors() {
+        // Timestamps that overflow the unit of a stored arrow schema
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}}]
+        }"#;
+        let mut data = vec![];
+        encode_long(&mut data, 1_000_000);
+        encode_long(&mut data, i64::MAX / 100);
+        let ts_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into()));
+        let arrow_schema = Schema::new(vec![Field::new("ts", ts_type, false)]);
+        let encoded = encode_arrow_schema(&arrow_schema);
+        let metadata: &[(&str, &[u8])] = &[(ARROW_SCHEMA_METADATA_KEY, &encoded)];
+        let file = write_ocf_with_metadata(schema, metadata, &[(2, data)]);
+
+        let mut reader = ReaderBuilder::new()
+            .with_arrow_schema(true)
+            .with_conversion_errors(true)
+            .build(file.as_slice())
+            .unwrap();
+        assert!(reader.schema().field(0).is_nullable());
+        let batch = reader.next().unwrap().unwrap();
+        let ts = batch
+            .column(0)
+            .as_primitive::<types::TimestampNanosecondType>();
+        assert_eq!(ts.iter().collect::<Vec<_>>(), [Some(1_000_000_000), None]);
+        let errors = reader.conversion_errors().unwrap();
+        assert_eq!(errors.num_rows(), 1);
+        let rows = errors.column(0).as_primitive::<types::UInt64Type>();
+        assert_eq!(rows.values(), &[1]);
+        assert_eq!(errors.column(1).as_string::<i32>().value(0), "ts");
+        let expected =
+            "Cannot cast 4892-10-07T21:52:48.547758Z to Timestamp(Nanosecond, Some(\"+00:00\"))";
+        assert_eq!(errors.column(2).as_string::<i32>().value(0), expected);
+    }
+
+    #[test]
+    fn test_union_handling() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "value", "type": ["string", "long"]},
+                {"name": "extra", "type": ["null", "double", "boolean"]},
+                {"name": "id", "type": "long"}
+            ]
+        }"#;
+        let mut data = vec![];
+        encode_long(&mut data, 0);
+        encode_bytes(&mut data, b"a");
+        encode_long(&mut data, 0);
+        encode_long(&mut data, 1);
+
+        encode_long(&mut data, 1);
+        encode_long(&mut data, 5);
+        encode_long(&mut data, 1);
+        data.extend_from_slice(&1.5_f64.to_le_bytes());
+        encode_long(&mut data, 2);
+
+        encode_long(&mut data, 1);
+        encode_long(&mut data, -7);
+        encode_long(&mut data, 2);
+        data.push(1);
+        encode_long(&mut data, 3);
+        let file = write_ocf(schema, &[(3, data)]);
+
+        let err = ReaderBuilder::new().build(file.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: decoding union field \"value\" without a UnionHandling"
+        );
+
+        let read = |handling: UnionHandling| {
+            let mut reader = ReaderBuilder::new()
+                .with_union_handling("value", handling)
+                .with_union_handling("extra", handling)
+                .build(file.as_slice())
+                .unwrap();
+            reader.next().unwrap().unwrap()
+        };
+
+        let batch = read(UnionHandling::Utf8);
+        let value = batch.column(0).as_string::<i32>();
+        assert_eq!(value, &StringArray::from(vec!["a", "5", "-7"]));
+        let extra = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            extra,
+            &StringArray::from(vec![None, Some("1.5"), Some("true")])
+        );
+        assert!(!batch.schema().field(0).is_nullable());
+        assert!(batch.schema().field(1).is_nullable());
+        let ids = batch.column(2).as_primitive::<types::Int64Type>();
+        assert_eq!(ids.values(), &[1, 2, 3]);
+
+        let batch = read(UnionHandling::Struct);
+        let value = batch.column(0).as_struct();
+        assert_eq!(value.column_names(), ["string", "long"]);
+        assert_eq!(value.null_count(), 0);
+        let strings = value.column(0).as_string::<i32>();
+        assert_eq!(strings, &StringArray::from(vec![Some("a"), None, None]));
+        let longs = value.column(1).as_primitive::<types::Int64Type>();
+        assert_eq!(longs, &Int64Array::from(vec![None, Some(5), Some(-7)]));
+        let extra = batch.column(1).as_struct();
+        assert_eq!(extra.column_names(), ["double", "boolean"]);
+        assert_eq!(
+            extra.nulls().unwrap().iter().collect::<Vec<_>>(),
+            [false, true, true]
+        );
+        let booleans = extra.column(1).as_boolean();
+        assert_eq!(booleans, &BooleanArray::from(vec![None, None, Some(true)]));
+
+        // Union fields not in the reader schema are skipped
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "id", "type": "long"}]
+        }"#;
+        let mut reader = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(file.as_slice())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let ids = batch.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(ids.values(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_union_duplicate_names() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{
+                "name": "value",
+                "type": ["null", "long", {"type": "long", "logicalType": "timestamp-millis"}]
+            }]
+        }"#;
+        let mut data = vec![];
+        encode_long(&mut data, 1);
+        encode_long(&mut data, 5);
+        encode_long(&mut data, 2);
+        encode_long(&mut data, 1000);
+        let file = write_ocf(schema, &[(2, data)]);
+
+        let mut reader = ReaderBuilder::new()
+            .with_union_handling("value", UnionHandling::Struct)
+            .build(file.as_slice())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let value = batch.column(0).as_struct();
+        assert_eq!(value.column_names(), ["long_1", "long_2"]);
+        let longs = value.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(longs, &Int64Array::from(vec![Some(5), None]));
+        let timestamps = value
+            .column(1)
+            .as_primitive::<types::TimestampMillisecondType>();
+        assert_eq!(timestamps.iter().collect::<Vec<_>>(), [None, Some(1000)]);
+    }
+
+    #[test]
+    fn test_decode_into() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "tags", "type": ["null", {"type": "array", "items": "string"}]},
+                {"name": "u", "type": ["null", "int", "string"]}
+            ]
+        }"#;
+        let decoder = ReaderBuilder::new()
+            .with_union_handling("u", UnionHandling::Struct)
+            .build_message_decoder(schema)
+            .unwrap();
+        let record = |id: i64, tags: Option<&[&str]>, u: (i64, Option<&str>)| {
+            let mut data = vec![];
+            encode_long(&mut data, id);
+            match tags {
+                Some(tags) => {
+                    encode_long(&mut data, 1);
+                    if !tags.is_empty() {
+                        encode_long(&mut data, tags.len() as i64);
+                        tags.iter()
+                            .for_each(|t| encode_bytes(&mut data, t.as_bytes()));
+                    }
+                    encode_long(&mut data, 0);
+                }
+                None => encode_long(&mut data, 0),
+            }
+            encode_long(&mut data, u.0);
+            match u {
+                (1, Some(v)) => encode_long(&mut data, v.parse().unwrap()),
+                (2, Some(v)) => encode_bytes(&mut data, v.as_bytes()),
+                _ => {}
+            }
+            data
+        };
+        let records = [
+            record(7, None, (0, None)),
+            record(8, Some(&["a", "b"]), (1, Some("5"))),
+            record(9, Some(&[]), (2, Some("x"))),
+        ];
+
+        let mut builders: Vec<Box<dyn builder::ArrayBuilder>> =
+            vec![Box::new(builder::Int64Builder::new())];
+        let err = decoder.decode_into(&records[0], &mut builders).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected 3 builders, got 1"
+        );
+
+        let schema = decoder.schema();
+        builders.push(builder::make_builder(schema.field(1).data_type(), 0));
+        builders.push(builder::make_builder(schema.field(2).data_type(), 0));
+        for data in &records {
+            decoder.decode_into(data, &mut builders).unwrap();
+        }
+        let columns: Vec<_> = builders.iter_mut().map(|b| b.finish()).collect();
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+
+        assert_eq!(
+            batch.column(0).as_primitive::<types::Int64Type>().values(),
+            &[7, 8, 9]
+        );
+        let DataType::List(item) = schema.field(1).data_type() else {
+            unreachable!()
+        };
+        let mut tags =
+            builder::ListBuilder::new(builder::StringBuilder::new()).with_field(item.clone());
+        tags.append_null();
+        tags.append_value([Some("a"), Some("b")]);
+        tags.append_value(Vec::<Option<&str>>::new());
+        assert_eq!(batch.column(1).to_data(), tags.finish().to_data());
+        let DataType::Struct(fields) = schema.field(2).data_type() else {
+            unreachable!()
+        };
+        let u = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(5), None])),
+                Arc::new(StringArray::from(vec![None, None, Some("x")])),
+            ],
+            Some(NullBuffer::from(vec![false, true, true])),
+        );
+        assert_eq!(batch.column(2).as_struct(), &u);
+
+        builders[0] = Box::new(builder::Int32Builder::new());
+        let err = decoder.decode_into(&records[0], &mut builders).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Schema error: Expected builder of type"));
+    }
 }
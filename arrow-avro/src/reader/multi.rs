@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::reader::{MessageDecoder, ReaderBuilder};
+use crate::schema::AvroSchemaId;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::BTreeMap;
+
+/// Decodes a stream of Avro messages written with different schemas, such as a Kafka
+/// topic containing multiple event types, into a [`RecordBatch`] per schema
+///
+/// Each message is expected in the Confluent wire format, see [`AvroSchemaId::Confluent`],
+/// that is a zero byte, followed by the 4-byte big-endian ID of the writer schema,
+/// followed by the Avro encoded record. The writer schemas must be
+/// registered with [`Self::register_schema`] prior to decoding any messages using them.
+///
+/// A separate decoder is maintained for each schema ID, allowing interleaved messages
+/// to be decoded without first partitioning the stream by schema.
+///
+/// Created with [`ReaderBuilder::build_multi_schema_decoder`]
+#[derive(Debug)]
+pub struct MultiSchemaDecoder {
+    builder: ReaderBuilder,
+    decoders: BTreeMap<u32, MessageDecoder>,
+}
+
+impl MultiSchemaDecoder {
+    pub(crate) fn new(builder: ReaderBuilder) -> Self {
+        Self {
+            builder,
+            decoders: Default::default(),
+        }
+    }
+
+    /// Register the Avro schema JSON `writer_schema` with the schema ID `id`
+    ///
+    /// Returns an error if the schema is invalid, or if `id` is already registered
+    pub fn register_schema(&mut self, id: u32, writer_schema: &str) -> Result<(), ArrowError> {
+        if self.decoders.contains_key(&id) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Avro schema ID {id} is already registered"
+            )));
+        }
+        let decoder = self.builder.clone().build_message_decoder(writer_schema)?;
+        self.decoders.insert(id, decoder);
+        Ok(())
+    }
+
+    /// Returns the arrow schema of the [`RecordBatch`] returned for the schema ID `id`,
+    /// if registered
+    pub fn schema(&self, id: u32) -> Option<SchemaRef> {
+        self.decoders.get(&id).map(|d| d.schema())
+    }
+
+    /// Decode a single message from `data`, returning the ID of its schema
+    pub fn decode(&mut self, data: &[u8]) -> Result<u32, ArrowError> {
+        let (id, record) = match AvroSchemaId::parse(data) {
+            Ok((AvroSchemaId::Confluent(id), record)) => (id, record),
+            _ => {
+                return Err(ArrowError::ParseError(
+                    "Avro message is not in the Confluent wire format".to_string(),
+                ))
+            }
+        };
+        let decoder = self
+            .decoders
+            .get_mut(&id)
+            .ok_or_else(|| ArrowError::ParseError(format!("Unknown Avro schema ID {id}")))?;
+        decoder.decode(record)?;
+        Ok(id)
+    }
+
+    /// Returns the total number of records decoded since the last flush
+    pub fn num_buffered(&self) -> usize {
+        self.decoders.values().map(|d| d.num_buffered()).sum()
+    }
+
+    /// Returns `true` if the number of records buffered for any schema has reached
+    /// the batch size of the [`ReaderBuilder`]
+    pub fn is_full(&self) -> bool {
+        self.decoders.values().any(|d| d.is_full())
+    }
+
+    /// Flush the records buffered for the schema ID `id` into a [`RecordBatch`],
+    /// returning `None` if there are no buffered records or `id` is not registered
+    pub fn flush_schema(&mut self, id: u32) -> Result<Option<RecordBatch>, ArrowError> {
+        match self.decoders.get_mut(&id) {
+            Some(decoder) => decoder.flush(),
+            None => Ok(None),
+        }
+    }
+
+    /// Flush the buffered records into a [`RecordBatch`] per schema, tagged with
+    /// the schema ID, in ascending order of schema ID
+    ///
+    /// Schemas without any buffered records are omitted
+    pub fn flush(&mut self) -> Result<Vec<(u32, RecordBatch)>, ArrowError> {
+        let mut batches = vec![];
+        for (id, decoder) in &mut self.decoders {
+            if let Some(batch) = decoder.flush()? {
+                batches.push((*id, batch));
+            }
+        }
+        Ok(batches)
+    }
+}
@@ -0,0 +1,161 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The first stage of [`Reader`](crate::reader::Reader), reading and decompressing
+//! blocks ahead of them being decoded
+
+use crate::compression::CompressionCodec;
+use crate::reader::block::{BlockReader, CorruptBlockError, RawBlock};
+use arrow_schema::ArrowError;
+use std::io::BufRead;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::JoinHandle;
+
+/// The number of decompressed blocks buffered by the worker thread, in addition
+/// to the block being decoded and the block being read
+const WORKER_BUFFERED_BLOCKS: usize = 1;
+
+/// Reads the [`RawBlock`]s of a file, decompressing those containing records
+/// that are not skipped
+#[derive(Debug)]
+pub(crate) struct DecompressedBlocks<R> {
+    blocks: BlockReader<R>,
+    compression: Option<CompressionCodec>,
+    verify_checksums: bool,
+    /// The number of records remaining to be skipped
+    skip: usize,
+}
+
+impl<R: BufRead> DecompressedBlocks<R> {
+    pub(crate) fn new(
+        blocks: BlockReader<R>,
+        compression: Option<CompressionCodec>,
+        verify_checksums: bool,
+        skip: usize,
+    ) -> Self {
+        Self {
+            blocks,
+            compression,
+            verify_checksums,
+            skip,
+        }
+    }
+
+    /// Read the next block, with its data left empty if all its records are skipped
+    fn read(&mut self) -> Result<Option<RawBlock>, ArrowError> {
+        let Some(mut block) = self.blocks.next().transpose()? else {
+            return Ok(None);
+        };
+        if self.skip >= block.count {
+            self.skip -= block.count;
+            block.data.clear();
+            return Ok(Some(block));
+        }
+        self.skip = 0;
+        if let Some(c) = self.compression {
+            block.data = c
+                .decompress(&block.data, self.verify_checksums)
+                .map_err(|e| CorruptBlockError::new(block.offset, e.to_string()))?;
+        }
+        Ok(Some(block))
+    }
+}
+
+impl<R: BufRead> Iterator for DecompressedBlocks<R> {
+    type Item = Result<RawBlock, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+/// The source of the decompressed [`RawBlock`]s decoded by a
+/// [`Reader`](crate::reader::Reader)
+#[derive(Debug)]
+pub(crate) enum BlockPipeline<R> {
+    /// Each block is read and decompressed on the calling thread, once the
+    /// previous block has been decoded
+    Inline(DecompressedBlocks<R>),
+    /// Blocks are read and decompressed on a worker thread, overlapping with
+    /// the decoding of the previous block, with the handle of the thread taken
+    /// once it has exited
+    Worker(
+        Receiver<Result<RawBlock, ArrowError>>,
+        Option<JoinHandle<()>>,
+    ),
+}
+
+impl<R: BufRead> BlockPipeline<R> {
+    /// Returns the next decompressed block, returning `None` at the end of the file
+    pub(crate) fn next_block(&mut self) -> Result<Option<RawBlock>, ArrowError> {
+        match self {
+            Self::Inline(blocks) => blocks.read(),
+            Self::Worker(receiver, handle) => match receiver.recv() {
+                Ok(block) => block.map(Some),
+                // The worker thread exits at the end of the file, after an error, or
+                // if it panics, which is reported as an error
+                Err(_) => match handle.take().map(JoinHandle::join) {
+                    Some(Err(panic)) => {
+                        let message = panic
+                            .downcast_ref::<&str>()
+                            .copied()
+                            .or_else(|| panic.downcast_ref::<String>().map(|s| s.as_str()))
+                            .unwrap_or("unknown panic");
+                        Err(ArrowError::ExternalError(
+                            format!("Avro reader worker thread panicked: {message}").into(),
+                        ))
+                    }
+                    _ => Ok(None),
+                },
+            },
+        }
+    }
+
+    /// Returns a mutable reference to the underlying reader, if read on this thread
+    #[cfg(feature = "object_store")]
+    pub(crate) fn get_mut(&mut self) -> Option<&mut R> {
+        match self {
+            Self::Inline(blocks) => Some(blocks.blocks.get_mut()),
+            Self::Worker(_, _) => None,
+        }
+    }
+}
+
+impl<R: BufRead + Send + 'static> BlockPipeline<R> {
+    /// Move the reading and decompression of blocks to a new worker thread
+    ///
+    /// The worker thread exits once the returned pipeline is dropped, after
+    /// completing any read in progress
+    pub(crate) fn spawn_worker(self) -> Result<Self, ArrowError> {
+        let blocks = match self {
+            Self::Inline(blocks) => blocks,
+            worker => return Ok(worker),
+        };
+        let (sender, receiver) = sync_channel(WORKER_BUFFERED_BLOCKS);
+        let handle = std::thread::Builder::new()
+            .name("arrow-avro-reader".to_string())
+            .spawn(move || {
+                for block in blocks {
+                    let failed = block.is_err();
+                    if sender.send(block).is_err() || failed {
+                        break;
+                    }
+                }
+            })?;
+        Ok(Self::Worker(receiver, Some(handle)))
+    }
+}
@@ -15,70 +15,349 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::codec::{AvroDataType, Codec, Nullability};
+use crate::codec::{
+    map_entries_field, AvroDataType, AvroEnum, AvroField, Codec, DecimalOverflowHandling,
+    DecimalRescale, MapKey, Nullability, ResolvedRecord,
+};
 use crate::reader::block::{Block, BlockDecoder};
+use crate::reader::conversion::{conversion_errors_batch, ConversionError};
 use crate::reader::cursor::AvroCursor;
+use crate::reader::field_decoder::{FieldDecoder, FieldDecoderFactory};
 use crate::reader::header::Header;
+use crate::reader::statistics::{ColumnStatistics, StatisticsValue};
+use crate::reader::transform::ColumnTransform;
 use crate::schema::*;
+use arrow_array::builder::{
+    ArrayBuilder, BinaryBuilder, BooleanBuilder, Date32Builder, Decimal128Builder,
+    Decimal256Builder, FixedSizeBinaryBuilder, Float32Builder, Float64Builder, Int32Builder,
+    Int64Builder, ListBuilder, MapBuilder, NullBuilder, StringBuilder, StringDictionaryBuilder,
+    StructBuilder, Time32MillisecondBuilder, Time64MicrosecondBuilder, TimestampMicrosecondBuilder,
+    TimestampMillisecondBuilder,
+};
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::*;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
 use arrow_schema::{
     ArrowError, DataType, Field as ArrowField, FieldRef, Fields, Schema as ArrowSchema, SchemaRef,
+    DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::io::Read;
 use std::sync::Arc;
 
 /// Decodes avro encoded data into [`RecordBatch`]
+#[derive(Debug)]
 pub struct RecordDecoder {
+    data_type: AvroDataType,
     schema: SchemaRef,
     fields: Vec<Decoder>,
+    projection: Option<Projection>,
+    /// The number of records decoded since the last flush
+    num_rows: usize,
+    /// The statistics of the last flushed [`RecordBatch`], if enabled
+    statistics: Option<Vec<ColumnStatistics>>,
+    /// The conversion errors of the last flushed [`RecordBatch`], if enabled
+    conversion_errors: Option<Vec<ConversionError>>,
+    /// The [`ColumnTransform`] applied to each column, if any
+    transforms: Vec<Option<Arc<dyn ColumnTransform>>>,
+    /// Fully validate each flushed [`RecordBatch`]
+    strict_validation: bool,
+}
+
+/// How fields of the Avro `null` type are decoded
+///
+/// Many consumers of arrow data do not support [`DataType::Null`], and so this allows
+/// such fields to instead be omitted, or decoded as a column of a supported type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum NullTypeHandling {
+    /// Decode to a [`NullArray`]
+    #[default]
+    Null,
+    /// Omit the field from the decoded [`RecordBatch`]
+    Drop,
+    /// Decode to an all-null array of the given [`DataType`]
+    AllNull(DataType),
+}
+
+/// How a record field of an Avro union type is decoded, other than a union of `null`
+/// and one other type, which is always decoded as a nullable column of that type
+///
+/// Such unions, e.g. `["string", "long"]`, are commonly used for loosely typed fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnionHandling {
+    /// Decode to a [`StringArray`], with the values of non-string branches formatted
+    /// as by [`ArrayFormatter`](arrow_cast::display::ArrayFormatter)
+    Utf8,
+    /// Decode to a [`StructArray`] with a nullable child for each branch other than
+    /// `null`, named by the type of the branch, with only the child of the branch of
+    /// each value being valid
+    ///
+    /// Branches with the same name, such as named types in different namespaces, are
+    /// suffixed with their index in the union, e.g. `long_0` and `long_1`
+    Struct,
+}
+
+/// Options for [`RecordDecoder`]
+#[derive(Debug, Clone, Default)]
+pub struct DecoderOptions {
+    /// Decode top-level struct columns to dictionary encoded [`DictionaryArray`]
+    ///
+    /// Identical struct values within a [`RecordBatch`] are only decoded once, by
+    /// memoizing their encoded bytes, reducing the memory required for columns that
+    /// are mostly constant
+    pub struct_dictionary: bool,
+    /// How record fields of the Avro `null` type are decoded
+    pub null_type: NullTypeHandling,
+    /// The [`FieldDecoderFactory`] for fields decoded by a [`FieldDecoder`], by field path
+    pub field_decoders: HashMap<String, Arc<dyn FieldDecoderFactory>>,
+    /// Return an error if the value of a JSON field is not well-formed JSON
+    pub validate_json: bool,
+    /// The [`ColumnTransform`] applied to each flushed column, by column name
+    pub column_transforms: HashMap<String, Arc<dyn ColumnTransform>>,
+    /// Fully validate the columns of each flushed [`RecordBatch`], see
+    /// `ArrayData::validate_full`
+    pub strict_validation: bool,
+    /// The [`UnionHandling`] of fields of union types, by field path
+    pub union_handling: HashMap<String, UnionHandling>,
+    /// Decode values that cannot be converted to their decoded type, such as decimals
+    /// exceeding their precision, as null instead of returning an error, collecting them
+    /// into [`RecordDecoder::conversion_errors`]
+    ///
+    /// Fields containing such values are always nullable
+    pub conversion_errors: bool,
 }
 
 impl RecordDecoder {
     pub fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
-        match Decoder::try_new(data_type)? {
-            Decoder::Record(fields, encodings) => Ok(Self {
-                schema: Arc::new(ArrowSchema::new(fields)),
-                fields: encodings,
-            }),
-            encoding => Err(ArrowError::ParseError(format!(
-                "Expected record got {encoding:?}"
-            ))),
+        Self::try_new_with_options(data_type, &DecoderOptions::default())
+    }
+
+    /// Create a new [`RecordDecoder`] with the provided [`DecoderOptions`]
+    pub fn try_new_with_options(
+        data_type: &AvroDataType,
+        options: &DecoderOptions,
+    ) -> Result<Self, ArrowError> {
+        let (fields, mut encodings, projection) = match Decoder::try_new(data_type, options, "")? {
+            Decoder::Record(fields, encodings, projection) => (fields, encodings, projection),
+            encoding => {
+                return Err(ArrowError::ParseError(format!(
+                    "Expected record got {encoding:?}"
+                )))
+            }
+        };
+
+        let mut fields: Vec<_> = fields.iter().cloned().collect();
+        if let (true, Codec::Struct(avro_fields)) = (options.struct_dictionary, data_type.codec()) {
+            let avro_fields = avro_fields.iter().filter(|f| !options.is_dropped(f));
+            let iter = fields.iter_mut().zip(&mut encodings).zip(avro_fields);
+            for ((field, encoding), avro_field) in iter {
+                if let DataType::Struct(_) = field.data_type() {
+                    let value_type = Box::new(field.data_type().clone());
+                    let data_type = DataType::Dictionary(Box::new(DataType::Int32), value_type);
+                    *field = Arc::new(field.as_ref().clone().with_data_type(data_type));
+                    let decoder = std::mem::replace(encoding, Decoder::Null(DataType::Null, 0));
+                    *encoding = decoder.into_dictionary(avro_field.data_type());
+                }
+            }
+        }
+
+        let mut transforms = Vec::with_capacity(fields.len());
+        for field in &mut fields {
+            let transform = options.column_transforms.get(field.name()).cloned();
+            if let Some(transform) = &transform {
+                let empty = transform.transform(new_empty_array(field.data_type()))?;
+                let data_type = arrow_array::Array::data_type(empty.as_ref()).clone();
+                *field = Arc::new(field.as_ref().clone().with_data_type(data_type));
+            }
+            transforms.push(transform);
         }
+
+        Ok(Self {
+            data_type: data_type.clone(),
+            schema: Arc::new(ArrowSchema::new(fields)),
+            fields: encodings,
+            projection,
+            num_rows: 0,
+            statistics: None,
+            conversion_errors: options.conversion_errors.then(Vec::new),
+            transforms,
+            strict_validation: options.strict_validation,
+        })
     }
 
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
 
+    /// Set the metadata of the arrow schema of the decoded [`RecordBatch`]
+    pub fn with_schema_metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.schema = Arc::new(self.schema.as_ref().clone().with_metadata(metadata));
+        self
+    }
+
+    /// Collect [`ColumnStatistics`] for each column while decoding, available
+    /// after each flush from [`Self::statistics`]
+    pub fn with_statistics(mut self) -> Self {
+        self.fields = self
+            .fields
+            .into_iter()
+            .map(Decoder::with_statistics)
+            .collect();
+        self.statistics = Some(vec![]);
+        self
+    }
+
+    /// Returns the [`ColumnStatistics`] of the last flushed [`RecordBatch`],
+    /// if enabled with [`Self::with_statistics`]
+    pub fn statistics(&self) -> Option<&[ColumnStatistics]> {
+        self.statistics.as_deref()
+    }
+
+    /// Returns the conversion errors of the last flushed [`RecordBatch`], if enabled
+    /// with [`DecoderOptions::conversion_errors`], see
+    /// [`conversion_errors_schema`](crate::reader::conversion_errors_schema)
+    pub fn conversion_errors(&self) -> Option<RecordBatch> {
+        self.conversion_errors
+            .as_deref()
+            .map(conversion_errors_batch)
+    }
+
+    /// Returns the conversion errors of the last flushed [`RecordBatch`], if enabled,
+    /// allowing further errors to be added
+    pub(crate) fn conversion_errors_mut(&mut self) -> Option<&mut Vec<ConversionError>> {
+        self.conversion_errors.as_mut()
+    }
+
     /// Decode `count` records from `buf`
+    ///
+    /// On error, any partially decoded record is discarded, with the records
+    /// decoded prior to it retained
     pub fn decode(&mut self, buf: &[u8], count: usize) -> Result<usize, ArrowError> {
         let mut cursor = AvroCursor::new(buf);
         for _ in 0..count {
-            for field in &mut self.fields {
-                field.decode(&mut cursor)?;
+            let result = match &self.projection {
+                Some(projection) => projection.decode(&mut self.fields, &mut cursor),
+                None => self
+                    .fields
+                    .iter_mut()
+                    .try_for_each(|field| field.decode(&mut cursor)),
+            };
+            if let Err(e) = result {
+                self.truncate(self.num_rows);
+                return Err(e);
             }
+            self.num_rows += 1;
+        }
+        Ok(cursor.position())
+    }
+
+    /// Returns the number of records decoded since the last flush
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Discard all but the first `len` records decoded since the last flush
+    ///
+    /// The statistics of at most the last record can be discarded, and so this
+    /// must not discard more than one record if [`Self::with_statistics`]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.num_rows {
+            self.num_rows = len;
+        }
+        self.fields
+            .iter_mut()
+            .for_each(|f| f.truncate(self.num_rows));
+    }
+
+    /// Decode `count` records from `buf`, appending them to `builders`, which must
+    /// contain a builder for each field of [`Self::schema`], such as those created
+    /// by [`make_builder`](arrow_array::builder::make_builder)
+    ///
+    /// The records are not buffered by this decoder, and so statistics are not collected.
+    /// On error the builders may contain partially appended records
+    pub fn decode_into(
+        &self,
+        buf: &[u8],
+        count: usize,
+        builders: &mut [Box<dyn ArrayBuilder>],
+    ) -> Result<usize, ArrowError> {
+        if builders.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected {} builders, got {}",
+                self.fields.len(),
+                builders.len()
+            )));
+        }
+        if self.transforms.iter().any(Option::is_some) {
+            return Err(ArrowError::NotYetImplemented(
+                "Decoding into builders with column transforms".to_string(),
+            ));
+        }
+        let mut cursor = AvroCursor::new(buf);
+        for _ in 0..count {
+            let mut children = ChildBuilders::Root(builders);
+            decode_record_into(&self.fields, &self.projection, &mut cursor, &mut children)?;
+        }
+        Ok(cursor.position())
+    }
+
+    /// Skip over `count` records in `buf` without decoding them
+    pub fn skip(&self, buf: &[u8], count: usize) -> Result<usize, ArrowError> {
+        let mut cursor = AvroCursor::new(buf);
+        for _ in 0..count {
+            skip_value(&self.data_type, &mut cursor)?;
         }
         Ok(cursor.position())
     }
 
     /// Flush the decoded records into a [`RecordBatch`]
     pub fn flush(&mut self) -> Result<RecordBatch, ArrowError> {
-        let arrays = self
-            .fields
-            .iter_mut()
-            .map(|x| x.flush(None))
+        self.num_rows = 0;
+        if let Some(errors) = &mut self.conversion_errors {
+            errors.clear();
+            for field in &mut self.fields {
+                field.take_conversion_errors(errors);
+            }
+        }
+        // Flush every column prior to returning any error, so that none retain records
+        let flushed: Vec<_> = self.fields.iter_mut().map(|x| x.flush(None)).collect();
+        if let Some(statistics) = &mut self.statistics {
+            statistics.clear();
+            statistics.extend(self.fields.iter_mut().map(|x| x.take_statistics()));
+        }
+        let arrays = flushed
+            .into_iter()
+            .zip(&self.transforms)
+            .map(|(x, transform)| match transform {
+                Some(transform) => transform.transform(x?),
+                None => x,
+            })
             .collect::<Result<Vec<_>, _>>()?;
-
+        if self.strict_validation {
+            for array in &arrays {
+                arrow_array::Array::to_data(array.as_ref()).validate_full()?;
+            }
+        }
         RecordBatch::try_new(self.schema.clone(), arrays)
     }
 }
 
+impl DecoderOptions {
+    /// Returns true if `field` should be omitted from the decoded output
+    fn is_dropped(&self, field: &AvroField) -> bool {
+        self.null_type == NullTypeHandling::Drop
+            && matches!(field.data_type().codec(), Codec::Null)
+            && field.data_type().nullability().is_none()
+    }
+}
+
 #[derive(Debug)]
 enum Decoder {
-    Null(usize),
+    /// Null(data type, length), decoded to an all-null array of the given type
+    Null(DataType, usize),
     Boolean(BooleanBufferBuilder),
     Int32(Vec<i32>),
     Int64(Vec<i64>),
@@ -91,17 +370,53 @@ enum Decoder {
     TimestampMicros(bool, Vec<i64>),
     Binary(OffsetBufferBuilder<i32>, Vec<u8>),
     String(OffsetBufferBuilder<i32>, Vec<u8>),
+    /// A string that is validated to be well-formed JSON
+    Json(OffsetBufferBuilder<i32>, Vec<u8>),
+    Uuid(Vec<u8>),
+    /// Decimal128(precision, scale, fixed size, converter, values)
+    Decimal128(u8, i8, Option<usize>, Option<DecimalConverter>, Vec<i128>),
+    /// Decimal256(precision, scale, fixed size, converter, values)
+    Decimal256(u8, i8, Option<usize>, Option<DecimalConverter>, Vec<i256>),
+    /// Enum(enum, symbols, keys)
+    Enum(AvroEnum, ArrayRef, Vec<i32>),
+    /// Union(handling, fields, decoders, branches) with the field and decoder of each
+    /// branch other than `null`, and the branch of each record, if not null
+    Union(
+        UnionHandling,
+        Fields,
+        Vec<Option<Decoder>>,
+        Vec<Option<usize>>,
+    ),
     List(FieldRef, OffsetBufferBuilder<i32>, Box<Decoder>),
-    Record(Fields, Vec<Decoder>),
+    /// Map(entries field, offsets, keys, values)
+    Map(
+        FieldRef,
+        OffsetBufferBuilder<i32>,
+        Box<Decoder>,
+        Box<Decoder>,
+    ),
+    Record(Fields, Vec<Decoder>, Option<Projection>),
     Nullable(Nullability, NullBufferBuilder, Box<Decoder>),
+    /// Collects statistics of the values decoded by the wrapped [`Decoder`]
+    Statistics(StatisticsBuilder, Box<Decoder>),
+    /// Dictionary(value type, keys by encoded value, keys, values)
+    Dictionary(AvroDataType, HashMap<Vec<u8>, i32>, Vec<i32>, Box<Decoder>),
+    /// Custom(value type, decoder), decoded by a user-provided [`FieldDecoder`]
+    Custom(AvroDataType, Box<dyn FieldDecoder>),
 }
 
 impl Decoder {
-    fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+    /// Create a new [`Decoder`] for `data_type`, where `path` is the path of the
+    /// record field being decoded, used to lookup any [`FieldDecoder`]
+    fn try_new(
+        data_type: &AvroDataType,
+        options: &DecoderOptions,
+        path: &str,
+    ) -> Result<Self, ArrowError> {
         let nyi = |s: &str| Err(ArrowError::NotYetImplemented(s.to_string()));
 
         let decoder = match data_type.codec() {
-            Codec::Null => Self::Null(0),
+            Codec::Null => Self::Null(DataType::Null, 0),
             Codec::Boolean => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
             Codec::Int32 => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
             Codec::Int64 => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
@@ -111,10 +426,15 @@ impl Decoder {
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
-            Codec::Utf8 => Self::String(
+            Codec::Json if options.validate_json => Self::Json(
+                OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                Vec::with_capacity(DEFAULT_CAPACITY),
+            ),
+            Codec::Utf8 | Codec::Json => Self::String(
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
+            Codec::Uuid => Self::Uuid(Vec::with_capacity(DEFAULT_CAPACITY)),
             Codec::Date32 => Self::Date32(Vec::with_capacity(DEFAULT_CAPACITY)),
             Codec::TimeMillis => Self::TimeMillis(Vec::with_capacity(DEFAULT_CAPACITY)),
             Codec::TimeMicros => Self::TimeMicros(Vec::with_capacity(DEFAULT_CAPACITY)),
@@ -125,41 +445,287 @@ impl Decoder {
                 Self::TimestampMicros(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
             }
             Codec::Fixed(_) => return nyi("decoding fixed"),
+            Codec::Decimal(precision, scale, size, rescale) => {
+                let converter =
+                    (rescale.is_some() || options.conversion_errors).then(|| DecimalConverter {
+                        rescale: *rescale,
+                        path: path.to_string(),
+                        nulls: NullBufferBuilder::new(DEFAULT_CAPACITY),
+                        errors: options.conversion_errors.then(Vec::new),
+                    });
+                match *precision <= DECIMAL128_MAX_PRECISION {
+                    true => Self::Decimal128(
+                        *precision,
+                        *scale,
+                        *size,
+                        converter,
+                        Vec::with_capacity(DEFAULT_CAPACITY),
+                    ),
+                    false => Self::Decimal256(
+                        *precision,
+                        *scale,
+                        *size,
+                        converter,
+                        Vec::with_capacity(DEFAULT_CAPACITY),
+                    ),
+                }
+            }
             Codec::Interval => return nyi("decoding interval"),
+            Codec::Union(branches) => {
+                let Some(handling) = options.union_handling.get(path) else {
+                    return nyi(&format!(
+                        "decoding union field \"{path}\" without a UnionHandling"
+                    ));
+                };
+                let mut fields = Vec::with_capacity(branches.len());
+                let mut decoders = Vec::with_capacity(branches.len());
+                for branch in branches.iter() {
+                    if let Codec::Null = branch.data_type().codec() {
+                        decoders.push(None);
+                        continue;
+                    }
+                    let decoder = Self::try_new(branch.data_type(), options, path)?;
+                    let mut field = branch.field().with_nullable(true);
+                    if let Some(data_type) = decoder.data_type_override() {
+                        field = field.with_data_type(data_type);
+                    }
+                    fields.push(field);
+                    decoders.push(Some(decoder));
+                }
+                // Disambiguate the children of branches with the same name
+                let branch_idx = decoders.iter().enumerate().filter(|(_, d)| d.is_some());
+                let fields: Vec<_> = fields
+                    .iter()
+                    .zip(branch_idx)
+                    .map(|(field, (idx, _))| {
+                        let name = field.name();
+                        match fields.iter().filter(|f| f.name() == name).count() {
+                            1 => field.clone(),
+                            _ => field.clone().with_name(format!("{name}_{idx}")),
+                        }
+                    })
+                    .collect();
+                Self::Union(
+                    *handling,
+                    fields.into(),
+                    decoders,
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                )
+            }
+            Codec::Enum(e) => {
+                let symbols = StringArray::from_iter_values(e.symbols());
+                Self::Enum(
+                    e.clone(),
+                    Arc::new(symbols),
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                )
+            }
             Codec::List(item) => {
-                let decoder = Self::try_new(item)?;
+                let decoder = Self::try_new(item, options, path)?;
+                let mut field = item.field_with_name("item");
+                if let Some(data_type) = decoder.data_type_override() {
+                    field = field.with_data_type(data_type);
+                }
+                if decoder.nulls_conversion_errors() {
+                    field = field.with_nullable(true);
+                }
                 Self::List(
-                    Arc::new(item.field_with_name("item")),
+                    Arc::new(field),
                     OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                     Box::new(decoder),
                 )
             }
+            Codec::Map(key, value) => {
+                let decoder = Self::try_new(value, options, path)?;
+                let mut field = value.field_with_name("value");
+                if let Some(data_type) = decoder.data_type_override() {
+                    field = field.with_data_type(data_type);
+                }
+                if decoder.nulls_conversion_errors() {
+                    field = field.with_nullable(true);
+                }
+                let keys = match key {
+                    MapKey::Utf8 => Self::String(
+                        OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                        Vec::with_capacity(DEFAULT_CAPACITY),
+                    ),
+                    MapKey::Int32 => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
+                    MapKey::Int64 => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
+                };
+                Self::Map(
+                    Arc::new(map_entries_field(*key, field)),
+                    OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                    Box::new(keys),
+                    Box::new(decoder),
+                )
+            }
             Codec::Struct(fields) => {
                 let mut arrow_fields = Vec::with_capacity(fields.len());
                 let mut encodings = Vec::with_capacity(fields.len());
+                // The index of the decoder of each field, or None if dropped
+                let mut indices = Vec::with_capacity(fields.len());
                 for avro_field in fields.iter() {
-                    let encoding = Self::try_new(avro_field.data_type())?;
-                    arrow_fields.push(avro_field.field());
+                    if options.is_dropped(avro_field) {
+                        indices.push(None);
+                        continue;
+                    }
+                    let path = match path {
+                        "" => avro_field.name().to_string(),
+                        _ => format!("{path}.{}", avro_field.name()),
+                    };
+                    let mut field = avro_field.field();
+                    let mut encoding = match options.field_decoders.get(&path) {
+                        Some(factory) => {
+                            let data_type = avro_field.data_type().with_nullability(None);
+                            Self::Custom(data_type, factory.create(&field)?)
+                                .with_nullability(avro_field.data_type().nullability())
+                        }
+                        None => Self::try_new(avro_field.data_type(), options, &path)?,
+                    };
+                    if let (Self::Null(data_type, _), NullTypeHandling::AllNull(d)) =
+                        (&mut encoding, &options.null_type)
+                    {
+                        *data_type = d.clone();
+                    }
+                    if let Some(data_type) = encoding.data_type_override() {
+                        field = field.with_data_type(data_type);
+                    }
+                    if encoding.nulls_conversion_errors() {
+                        field = field.with_nullable(true);
+                    }
+                    indices.push(Some(encodings.len()));
+                    arrow_fields.push(field);
                     encodings.push(encoding);
                 }
-                Self::Record(arrow_fields.into(), encodings)
+                let projection = data_type.resolution().map(|r| Projection::new(r, &indices));
+                Self::Record(arrow_fields.into(), encodings, projection)
             }
         };
 
-        Ok(match data_type.nullability() {
+        Ok(decoder.with_nullability(data_type.nullability()))
+    }
+
+    /// Wraps this [`Decoder`] in [`Decoder::Nullable`] if `nullability` is not `None`
+    fn with_nullability(self, nullability: Option<Nullability>) -> Self {
+        match nullability {
             Some(nullability) => Self::Nullable(
                 nullability,
                 NullBufferBuilder::new(DEFAULT_CAPACITY),
-                Box::new(decoder),
+                Box::new(self),
             ),
-            None => decoder,
-        })
+            None => self,
+        }
+    }
+
+    /// Returns the [`DataType`] of the flushed array if it may differ from that of
+    /// the Avro type being decoded, as a result of the [`DecoderOptions`]
+    fn data_type_override(&self) -> Option<DataType> {
+        match self {
+            Self::Null(data_type, _) => Some(data_type.clone()),
+            Self::List(field, _, _) => Some(DataType::List(field.clone())),
+            Self::Map(field, _, _, _) => Some(DataType::Map(field.clone(), false)),
+            Self::Record(fields, _, _) => Some(DataType::Struct(fields.clone())),
+            Self::Union(UnionHandling::Utf8, _, _, _) => Some(DataType::Utf8),
+            Self::Union(UnionHandling::Struct, fields, _, _) => {
+                Some(DataType::Struct(fields.clone()))
+            }
+            Self::Custom(_, decoder) => Some(decoder.data_type()),
+            Self::Nullable(_, _, e) => e.data_type_override(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this decoder decodes values that cannot be converted as null,
+    /// see [`DecoderOptions::conversion_errors`]
+    fn nulls_conversion_errors(&self) -> bool {
+        match self {
+            Self::Decimal128(_, _, _, Some(c), _) | Self::Decimal256(_, _, _, Some(c), _) => {
+                c.errors.is_some()
+            }
+            Self::Statistics(_, e) => e.nulls_conversion_errors(),
+            _ => false,
+        }
+    }
+
+    /// Append the conversion errors collected since the last flush to `errors`, with
+    /// the index of each value within this decoder, see [`DecoderOptions::conversion_errors`]
+    fn take_conversion_errors(&mut self, errors: &mut Vec<ConversionError>) {
+        let start = errors.len();
+        match self {
+            Self::Decimal128(_, _, _, Some(c), _) | Self::Decimal256(_, _, _, Some(c), _) => {
+                errors.append(c.errors.get_or_insert_with(Vec::new))
+            }
+            Self::Record(_, e, _) => e.iter_mut().for_each(|e| e.take_conversion_errors(errors)),
+            Self::Nullable(_, _, e) | Self::Statistics(_, e) => e.take_conversion_errors(errors),
+            Self::Union(_, _, decoders, _) => decoders
+                .iter_mut()
+                .flatten()
+                .for_each(|d| d.take_conversion_errors(errors)),
+            Self::List(_, offsets, e) | Self::Map(_, offsets, _, e) => {
+                e.take_conversion_errors(errors);
+                // Map the index of each child value to the index of its list or map
+                for error in &mut errors[start..] {
+                    error.row = offsets.partition_point(|o| *o as usize <= error.row) - 1;
+                }
+            }
+            Self::Dictionary(_, _, keys, e) => {
+                // Each dictionary value is decoded once, for the first of its keys
+                let mut values = vec![];
+                e.take_conversion_errors(&mut values);
+                for error in values {
+                    let rows = keys
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, k)| **k as usize == error.row);
+                    errors.extend(rows.map(|(row, _)| ConversionError {
+                        row,
+                        ..error.clone()
+                    }));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Wrap this decoder to collect [`ColumnStatistics`], see [`Self::take_statistics`]
+    fn with_statistics(self) -> Self {
+        match self {
+            Self::Nullable(nullability, nulls, e) => {
+                Self::Nullable(nullability, nulls, Box::new(e.with_statistics()))
+            }
+            e => Self::Statistics(StatisticsBuilder::default(), Box::new(e)),
+        }
+    }
+
+    /// Wrap this decoder to decode values of `data_type` into a [`DictionaryArray`],
+    /// only decoding each distinct encoded value once
+    fn into_dictionary(self, data_type: &AvroDataType) -> Self {
+        match self {
+            Self::Nullable(nullability, nulls, e) => {
+                Self::Nullable(nullability, nulls, Box::new(e.into_dictionary(data_type)))
+            }
+            e => Self::Dictionary(
+                data_type.with_nullability(None),
+                HashMap::new(),
+                Vec::with_capacity(DEFAULT_CAPACITY),
+                Box::new(e),
+            ),
+        }
+    }
+
+    /// Returns the [`ColumnStatistics`] collected since the last call
+    fn take_statistics(&mut self) -> ColumnStatistics {
+        match self {
+            Self::Nullable(_, _, e) => e.take_statistics(),
+            Self::Statistics(stats, _) => std::mem::take(stats).finish(),
+            _ => ColumnStatistics::default(),
+        }
     }
 
     /// Append a null record
     fn append_null(&mut self) {
         match self {
-            Self::Null(count) => *count += 1,
+            Self::Null(_, count) => *count += 1,
             Self::Boolean(b) => b.append(false),
             Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) => v.push(0),
             Self::Int64(v)
@@ -168,20 +734,108 @@ impl Decoder {
             | Self::TimestampMicros(_, v) => v.push(0),
             Self::Float32(v) => v.push(0.),
             Self::Float64(v) => v.push(0.),
-            Self::Binary(offsets, _) | Self::String(offsets, _) => offsets.push_length(0),
+            Self::Binary(offsets, _) | Self::String(offsets, _) | Self::Json(offsets, _) => {
+                offsets.push_length(0)
+            }
+            Self::Uuid(v) => v.extend_from_slice(&[0; 16]),
+            Self::Decimal128(_, _, _, converter, v) => {
+                converter.iter_mut().for_each(|r| r.nulls.append_non_null());
+                v.push(0)
+            }
+            Self::Decimal256(_, _, _, converter, v) => {
+                converter.iter_mut().for_each(|r| r.nulls.append_non_null());
+                v.push(i256::ZERO)
+            }
             Self::List(_, offsets, e) => {
                 offsets.push_length(0);
                 e.append_null();
             }
-            Self::Record(_, e) => e.iter_mut().for_each(|e| e.append_null()),
-            Self::Nullable(_, _, _) => unreachable!("Nulls cannot be nested"),
+            Self::Map(_, offsets, _, _) => offsets.push_length(0),
+            Self::Record(_, e, _) => e.iter_mut().for_each(|e| e.append_null()),
+            Self::Nullable(_, nulls, e) => {
+                nulls.append(false);
+                e.append_null();
+            }
+            Self::Statistics(stats, e) => {
+                stats.append_null();
+                e.append_null();
+            }
+            Self::Enum(_, _, keys) | Self::Dictionary(_, _, keys, _) => keys.push(0),
+            Self::Union(_, _, decoders, branches) => {
+                decoders.iter_mut().flatten().for_each(|d| d.append_null());
+                branches.push(None);
+            }
+            Self::Custom(_, decoder) => decoder.append_null(),
+        }
+    }
+
+    /// Discard all but the first `len` records, such as those appended by a record
+    /// that failed to decode
+    fn truncate(&mut self, len: usize) {
+        match self {
+            Self::Null(_, count) => *count = (*count).min(len),
+            Self::Boolean(b) => b.truncate(len),
+            Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) | Self::Enum(_, _, v) => {
+                v.truncate(len)
+            }
+            Self::Int64(v)
+            | Self::TimeMicros(v)
+            | Self::TimestampMillis(_, v)
+            | Self::TimestampMicros(_, v) => v.truncate(len),
+            Self::Float32(v) => v.truncate(len),
+            Self::Float64(v) => v.truncate(len),
+            Self::Binary(offsets, values)
+            | Self::String(offsets, values)
+            | Self::Json(offsets, values) => {
+                offsets.truncate(len);
+                values.truncate(offsets[offsets.len() - 1].as_usize());
+            }
+            Self::Uuid(v) => v.truncate(len * 16),
+            Self::Decimal128(_, _, _, converter, v) => {
+                converter.iter_mut().for_each(|r| r.truncate(len));
+                v.truncate(len)
+            }
+            Self::Decimal256(_, _, _, converter, v) => {
+                converter.iter_mut().for_each(|r| r.truncate(len));
+                v.truncate(len)
+            }
+            Self::List(_, offsets, e) => {
+                offsets.truncate(len);
+                e.truncate(len);
+            }
+            Self::Map(_, offsets, keys, values) => {
+                offsets.truncate(len);
+                let entries = offsets[offsets.len() - 1].as_usize();
+                keys.truncate(entries);
+                values.truncate(entries);
+            }
+            Self::Record(_, e, _) => e.iter_mut().for_each(|e| e.truncate(len)),
+            Self::Nullable(_, nulls, e) => {
+                nulls.truncate(len);
+                e.truncate(len);
+            }
+            Self::Statistics(stats, e) => {
+                stats.truncate(len);
+                e.truncate(len);
+            }
+            Self::Dictionary(_, index, keys, values) => {
+                // Any distinct values decoded by discarded records are retained, but
+                // a partially decoded value has not yet been assigned a key
+                keys.truncate(len);
+                values.truncate(index.len());
+            }
+            Self::Union(_, _, decoders, branches) => {
+                decoders.iter_mut().flatten().for_each(|d| d.truncate(len));
+                branches.truncate(len);
+            }
+            Self::Custom(_, decoder) => decoder.truncate(len),
         }
     }
 
     /// Decode a single record from `buf`
     fn decode(&mut self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
         match self {
-            Self::Null(x) => *x += 1,
+            Self::Null(_, x) => *x += 1,
             Self::Boolean(values) => values.append(buf.get_bool()?),
             Self::Int32(values) | Self::Date32(values) | Self::TimeMillis(values) => {
                 values.push(buf.get_int()?)
@@ -197,16 +851,70 @@ impl Decoder {
                 offsets.push_length(data.len());
                 values.extend_from_slice(data);
             }
+            Self::Json(offsets, values) => {
+                let data = buf.get_bytes()?;
+                serde_json::from_slice::<serde::de::IgnoredAny>(data)
+                    .map_err(|e| ArrowError::ParseError(format!("Invalid JSON value: {e}")))?;
+                offsets.push_length(data.len());
+                values.extend_from_slice(data);
+            }
+            Self::Uuid(values) => values.extend_from_slice(&parse_uuid(buf.get_bytes()?)?),
+            Self::Decimal128(precision, scale, size, converter, values) => {
+                let bytes = get_decimal_bytes(buf, *size)?;
+                match converter {
+                    Some(r) => values.push(r.decode(bytes, *precision, *scale)?.as_i128()),
+                    None => values.push(i128::from_be_bytes(sign_extend(bytes)?)),
+                }
+            }
+            Self::Decimal256(precision, scale, size, converter, values) => {
+                let bytes = get_decimal_bytes(buf, *size)?;
+                match converter {
+                    Some(r) => values.push(r.decode(bytes, *precision, *scale)?),
+                    None => values.push(i256::from_be_bytes(sign_extend(bytes)?)),
+                }
+            }
+            Self::Enum(e, _, keys) => keys.push(e.reader_index(buf.get_int()?)?),
+            Self::Union(_, _, decoders, branches) => {
+                let branch = get_union_branch(buf, decoders.len())?;
+                for (idx, decoder) in decoders.iter_mut().enumerate() {
+                    match decoder {
+                        Some(decoder) if idx == branch => decoder.decode(buf)?,
+                        Some(decoder) => decoder.append_null(),
+                        None => {}
+                    }
+                }
+                branches.push(decoders[branch].is_some().then_some(branch));
+            }
             Self::List(_, _, _) => {
                 return Err(ArrowError::NotYetImplemented(
                     "Decoding ListArray".to_string(),
                 ))
             }
-            Self::Record(_, encodings) => {
+            Self::Map(_, offsets, keys, values) => {
+                let mut len = 0;
+                loop {
+                    let count = buf.get_long()?;
+                    if count == 0 {
+                        break;
+                    }
+                    if count < 0 {
+                        // A negative count is followed by the size of the block in bytes
+                        buf.get_long()?;
+                    }
+                    for _ in 0..count.unsigned_abs() {
+                        keys.decode_map_key(buf.get_bytes()?)?;
+                        values.decode(buf)?;
+                    }
+                    len += count.unsigned_abs() as usize;
+                }
+                offsets.push_length(len);
+            }
+            Self::Record(_, encodings, None) => {
                 for encoding in encodings {
                     encoding.decode(buf)?;
                 }
             }
+            Self::Record(_, encodings, Some(projection)) => projection.decode(encodings, buf)?,
             Self::Nullable(nullability, nulls, e) => {
                 let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
                 nulls.append(is_valid);
@@ -215,6 +923,66 @@ impl Decoder {
                     false => e.append_null(),
                 }
             }
+            Self::Statistics(stats, e) => {
+                let offset = match e.as_ref() {
+                    Self::Binary(_, values) | Self::String(_, values) | Self::Uuid(values) => {
+                        values.len()
+                    }
+                    _ => 0,
+                };
+                e.decode(buf)?;
+                stats.update(e, offset);
+            }
+            Self::Dictionary(data_type, index, keys, values) => {
+                let position = buf.position();
+                let start = buf.remaining();
+                skip_value(data_type, buf)?;
+                let encoded = &start[..start.len() - buf.remaining().len()];
+                let key = match index.get(encoded) {
+                    Some(key) => *key,
+                    None => {
+                        let key = i32::try_from(index.len())
+                            .map_err(|_| ArrowError::DictionaryKeyOverflowError)?;
+                        values.decode(&mut AvroCursor::new_at(encoded, position))?;
+                        index.insert(encoded.to_vec(), key);
+                        key
+                    }
+                };
+                keys.push(key);
+            }
+            Self::Custom(data_type, decoder) => match data_type.codec() {
+                Codec::Binary | Codec::Utf8 | Codec::Json => decoder.decode(buf.get_bytes()?)?,
+                _ => {
+                    let start = buf.remaining();
+                    skip_value(data_type, buf)?;
+                    decoder.decode(&start[..start.len() - buf.remaining().len()])?
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Decode the string `key` of a map entry, parsing it if this is not a string decoder
+    fn decode_map_key(&mut self, key: &[u8]) -> Result<(), ArrowError> {
+        let parse_err = |t: &str| {
+            let key = String::from_utf8_lossy(key);
+            ArrowError::ParseError(format!("Failed to parse map key \"{key}\" as {t}"))
+        };
+        let key_str = std::str::from_utf8(key).ok();
+        match self {
+            Self::String(offsets, values) => {
+                offsets.push_length(key.len());
+                values.extend_from_slice(key);
+            }
+            Self::Int32(values) => {
+                let v = key_str.and_then(|s| s.parse().ok());
+                values.push(v.ok_or_else(|| parse_err("int"))?);
+            }
+            Self::Int64(values) => {
+                let v = key_str.and_then(|s| s.parse().ok());
+                values.push(v.ok_or_else(|| parse_err("long"))?);
+            }
+            _ => unreachable!("unexpected map key decoder"),
         }
         Ok(())
     }
@@ -223,7 +991,36 @@ impl Decoder {
     fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
         Ok(match self {
             Self::Nullable(_, n, e) => e.flush(n.finish())?,
-            Self::Null(size) => Arc::new(NullArray::new(std::mem::replace(size, 0))),
+            Self::Statistics(_, e) => e.flush(nulls)?,
+            Self::Dictionary(_, index, keys, values) => {
+                index.clear();
+                let keys = flush_primitive::<Int32Type>(keys, nulls);
+                Arc::new(DictionaryArray::try_new(keys, values.flush(None)?)?)
+            }
+            Self::Enum(_, symbols, keys) => {
+                let keys = flush_primitive::<Int32Type>(keys, nulls);
+                Arc::new(DictionaryArray::try_new(keys, symbols.clone())?)
+            }
+            Self::Union(handling, fields, decoders, branches) => {
+                let branches = std::mem::take(branches);
+                let valid = branches.iter().map(Option::is_some).collect();
+                let nulls = NullBuffer::union(nulls.as_ref(), Some(&NullBuffer::new(valid)));
+                let mut arrays = Vec::with_capacity(fields.len());
+                for (idx, decoder) in decoders.iter_mut().enumerate() {
+                    if let Some(decoder) = decoder {
+                        let valid = branches.iter().map(|b| *b == Some(idx)).collect();
+                        arrays.push(decoder.flush(Some(NullBuffer::new(valid)))?);
+                    }
+                }
+                match handling {
+                    UnionHandling::Struct => {
+                        Arc::new(StructArray::try_new(fields.clone(), arrays, nulls)?)
+                    }
+                    UnionHandling::Utf8 => Arc::new(format_union(&arrays, branches.len(), nulls)?),
+                }
+            }
+            Self::Null(data_type, size) => new_null_array(data_type, std::mem::replace(size, 0)),
+            Self::Custom(_, decoder) => decoder.flush(nulls)?,
             Self::Boolean(b) => Arc::new(BooleanArray::new(b.finish(), nulls)),
             Self::Int32(values) => Arc::new(flush_primitive::<Int32Type>(values, nulls)),
             Self::Date32(values) => Arc::new(flush_primitive::<Date32Type>(values, nulls)),
@@ -250,27 +1047,905 @@ impl Decoder {
                 let values = flush_values(values).into();
                 Arc::new(BinaryArray::new(offsets, values, nulls))
             }
-            Self::String(offsets, values) => {
+            Self::String(offsets, values) | Self::Json(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values).into();
-                Arc::new(StringArray::new(offsets, values, nulls))
+                // Values of Avro bytes decoded as JSON may not be valid UTF-8
+                Arc::new(StringArray::try_new(offsets, values, nulls)?)
+            }
+            Self::Uuid(values) => {
+                let values = flush_values(values).into();
+                Arc::new(FixedSizeBinaryArray::new(16, values, nulls))
+            }
+            Self::Decimal128(precision, scale, _, converter, values) => {
+                let nulls = DecimalConverter::flush_nulls(converter, nulls);
+                Arc::new(
+                    flush_primitive::<Decimal128Type>(values, nulls)
+                        .with_precision_and_scale(*precision, *scale)?,
+                )
+            }
+            Self::Decimal256(precision, scale, _, converter, values) => {
+                let nulls = DecimalConverter::flush_nulls(converter, nulls);
+                Arc::new(
+                    flush_primitive::<Decimal256Type>(values, nulls)
+                        .with_precision_and_scale(*precision, *scale)?,
+                )
             }
             Self::List(field, offsets, values) => {
                 let values = values.flush(None)?;
                 let offsets = flush_offsets(offsets);
                 Arc::new(ListArray::new(field.clone(), offsets, values, nulls))
             }
-            Self::Record(fields, encodings) => {
+            Self::Map(field, offsets, keys, values) => {
+                let DataType::Struct(fields) = field.data_type() else {
+                    unreachable!("map entries must be a struct")
+                };
+                let columns = vec![keys.flush(None)?, values.flush(None)?];
+                let entries = StructArray::new(fields.clone(), columns, None);
+                let offsets = flush_offsets(offsets);
+                Arc::new(MapArray::new(field.clone(), offsets, entries, nulls, false))
+            }
+            Self::Record(fields, encodings, _) => {
+                // The children of a null record are themselves appended as null where
+                // nullable, and so keep their own validity, distinct from `nulls`
                 let arrays = encodings
                     .iter_mut()
                     .map(|x| x.flush(None))
                     .collect::<Result<Vec<_>, _>>()?;
-                Arc::new(StructArray::new(fields.clone(), arrays, nulls))
+                Arc::new(StructArray::try_new(fields.clone(), arrays, nulls)?)
             }
         })
     }
 }
 
+/// Reads the index of the branch of a union with `len` branches from `buf`
+fn get_union_branch(buf: &mut AvroCursor<'_>, len: usize) -> Result<usize, ArrowError> {
+    let branch = buf.get_long()?;
+    usize::try_from(branch)
+        .ok()
+        .filter(|b| *b < len)
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Union branch {branch} out of range for {len} branches"
+            ))
+        })
+}
+
+/// A builder appended to by [`Decoder::decode_into`]
+enum BuilderRef<'a> {
+    /// A builder provided by the caller, or the keys or values of a [`MapBuilder`]
+    Dyn(&'a mut dyn ArrayBuilder),
+    /// The builder of the child field with the given index of a [`StructBuilder`],
+    /// which can only be accessed by its concrete type
+    Field(&'a mut StructBuilder, usize),
+}
+
+impl BuilderRef<'_> {
+    /// Returns the builder as a `T`, or an error if it is of a different type
+    fn get<T: ArrayBuilder>(&mut self) -> Result<&mut T, ArrowError> {
+        let builder = match self {
+            Self::Dyn(builder) => builder.as_any_mut().downcast_mut::<T>(),
+            Self::Field(builder, idx) => builder.field_builder::<T>(*idx),
+        };
+        builder.ok_or_else(|| {
+            let expected = std::any::type_name::<T>();
+            ArrowError::SchemaError(format!("Expected builder of type {expected}"))
+        })
+    }
+}
+
+/// The builders of the fields of a record
+enum ChildBuilders<'a> {
+    Root(&'a mut [Box<dyn ArrayBuilder>]),
+    Struct(&'a mut StructBuilder),
+}
+
+impl ChildBuilders<'_> {
+    fn get(&mut self, idx: usize) -> BuilderRef<'_> {
+        match self {
+            Self::Root(builders) => BuilderRef::Dyn(builders[idx].as_mut()),
+            Self::Struct(builder) => BuilderRef::Field(builder, idx),
+        }
+    }
+}
+
+/// Decode a single record from `buf` into `children`, the builders of `decoders`
+fn decode_record_into(
+    decoders: &[Decoder],
+    projection: &Option<Projection>,
+    buf: &mut AvroCursor<'_>,
+    children: &mut ChildBuilders<'_>,
+) -> Result<(), ArrowError> {
+    match projection {
+        Some(projection) => projection.decode_into(decoders, buf, children),
+        None => {
+            for (idx, decoder) in decoders.iter().enumerate() {
+                decoder.decode_into(buf, children.get(idx))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Decoder {
+    /// Decode a single record from `buf`, appending it to `builder`
+    fn decode_into(
+        &self,
+        buf: &mut AvroCursor<'_>,
+        mut builder: BuilderRef<'_>,
+    ) -> Result<(), ArrowError> {
+        match self {
+            Self::Null(DataType::Null, _) => builder.get::<NullBuilder>()?.append_null(),
+            Self::Boolean(_) => builder
+                .get::<BooleanBuilder>()?
+                .append_value(buf.get_bool()?),
+            Self::Int32(_) => builder.get::<Int32Builder>()?.append_value(buf.get_int()?),
+            Self::Date32(_) => builder.get::<Date32Builder>()?.append_value(buf.get_int()?),
+            Self::TimeMillis(_) => builder
+                .get::<Time32MillisecondBuilder>()?
+                .append_value(buf.get_int()?),
+            Self::Int64(_) => builder.get::<Int64Builder>()?.append_value(buf.get_long()?),
+            Self::TimeMicros(_) => builder
+                .get::<Time64MicrosecondBuilder>()?
+                .append_value(buf.get_long()?),
+            Self::TimestampMillis(_, _) => builder
+                .get::<TimestampMillisecondBuilder>()?
+                .append_value(buf.get_long()?),
+            Self::TimestampMicros(_, _) => builder
+                .get::<TimestampMicrosecondBuilder>()?
+                .append_value(buf.get_long()?),
+            Self::Float32(_) => builder
+                .get::<Float32Builder>()?
+                .append_value(buf.get_float()?),
+            Self::Float64(_) => builder
+                .get::<Float64Builder>()?
+                .append_value(buf.get_double()?),
+            Self::Binary(_, _) => builder
+                .get::<BinaryBuilder>()?
+                .append_value(buf.get_bytes()?),
+            Self::String(_, _) | Self::Json(_, _) => {
+                let data = buf.get_bytes()?;
+                if let Self::Json(_, _) = self {
+                    serde_json::from_slice::<serde::de::IgnoredAny>(data)
+                        .map_err(|e| ArrowError::ParseError(format!("Invalid JSON value: {e}")))?;
+                }
+                let value = std::str::from_utf8(data)
+                    .map_err(|e| ArrowError::ParseError(format!("Invalid UTF-8 string: {e}")))?;
+                builder.get::<StringBuilder>()?.append_value(value)
+            }
+            Self::Uuid(_) => builder
+                .get::<FixedSizeBinaryBuilder>()?
+                .append_value(parse_uuid(buf.get_bytes()?)?)?,
+            Self::Decimal128(precision, scale, size, converter, _) => {
+                let bytes = get_decimal_bytes(buf, *size)?;
+                let value = match converter {
+                    Some(r) => r.convert(bytes, *precision, *scale)?.map(|v| v.as_i128()),
+                    None => Some(i128::from_be_bytes(sign_extend(bytes)?)),
+                };
+                builder.get::<Decimal128Builder>()?.append_option(value)
+            }
+            Self::Decimal256(precision, scale, size, converter, _) => {
+                let bytes = get_decimal_bytes(buf, *size)?;
+                let value = match converter {
+                    Some(r) => r.convert(bytes, *precision, *scale)?,
+                    None => Some(i256::from_be_bytes(sign_extend(bytes)?)),
+                };
+                builder.get::<Decimal256Builder>()?.append_option(value)
+            }
+            Self::Enum(e, _, _) => {
+                let symbol = &e.symbols()[e.reader_index(buf.get_int()?)? as usize];
+                builder
+                    .get::<StringDictionaryBuilder<Int32Type>>()?
+                    .append_value(symbol);
+            }
+            Self::List(_, _, values) => {
+                let list = builder.get::<ListBuilder<Box<dyn ArrayBuilder>>>()?;
+                loop {
+                    let count = buf.get_long()?;
+                    if count == 0 {
+                        break;
+                    }
+                    if count < 0 {
+                        // A negative count is followed by the size of the block in bytes
+                        buf.get_long()?;
+                    }
+                    for _ in 0..count.unsigned_abs() {
+                        values.decode_into(buf, BuilderRef::Dyn(list.values().as_mut()))?;
+                    }
+                }
+                list.append(true)
+            }
+            Self::Map(_, _, keys, values) => {
+                let map =
+                    builder.get::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>()?;
+                loop {
+                    let count = buf.get_long()?;
+                    if count == 0 {
+                        break;
+                    }
+                    if count < 0 {
+                        // A negative count is followed by the size of the block in bytes
+                        buf.get_long()?;
+                    }
+                    for _ in 0..count.unsigned_abs() {
+                        let (key_builder, value_builder) = map.entries();
+                        keys.decode_map_key_into(buf.get_bytes()?, key_builder.as_mut())?;
+                        values.decode_into(buf, BuilderRef::Dyn(value_builder.as_mut()))?;
+                    }
+                }
+                map.append(true)?
+            }
+            Self::Record(_, encodings, projection) => {
+                let builder = builder.get::<StructBuilder>()?;
+                let mut children = ChildBuilders::Struct(builder);
+                decode_record_into(encodings, projection, buf, &mut children)?;
+                builder.append(true)
+            }
+            Self::Nullable(nullability, _, e) => {
+                let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
+                match is_valid {
+                    true => e.decode_into(buf, builder)?,
+                    false => e.append_null_into(builder)?,
+                }
+            }
+            Self::Union(UnionHandling::Struct, _, decoders, _) => {
+                let branch = get_union_branch(buf, decoders.len())?;
+                let builder = builder.get::<StructBuilder>()?;
+                // Each branch other than `null` has a child, in the order of the branches
+                let children = decoders
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, d)| Some((idx, d.as_ref()?)));
+                for (child, (idx, decoder)) in children.enumerate() {
+                    let child = BuilderRef::Field(builder, child);
+                    match idx == branch {
+                        true => decoder.decode_into(buf, child)?,
+                        false => decoder.append_null_into(child)?,
+                    }
+                }
+                builder.append(decoders[branch].is_some())
+            }
+            Self::Statistics(_, e) => e.decode_into(buf, builder)?,
+            _ => return Err(self.builder_not_supported()),
+        }
+        Ok(())
+    }
+
+    /// Append a null record to `builder`
+    fn append_null_into(&self, mut builder: BuilderRef<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null(DataType::Null, _) => builder.get::<NullBuilder>()?.append_null(),
+            Self::Boolean(_) => builder.get::<BooleanBuilder>()?.append_null(),
+            Self::Int32(_) => builder.get::<Int32Builder>()?.append_null(),
+            Self::Date32(_) => builder.get::<Date32Builder>()?.append_null(),
+            Self::TimeMillis(_) => builder.get::<Time32MillisecondBuilder>()?.append_null(),
+            Self::Int64(_) => builder.get::<Int64Builder>()?.append_null(),
+            Self::TimeMicros(_) => builder.get::<Time64MicrosecondBuilder>()?.append_null(),
+            Self::TimestampMillis(_, _) => {
+                builder.get::<TimestampMillisecondBuilder>()?.append_null()
+            }
+            Self::TimestampMicros(_, _) => {
+                builder.get::<TimestampMicrosecondBuilder>()?.append_null()
+            }
+            Self::Float32(_) => builder.get::<Float32Builder>()?.append_null(),
+            Self::Float64(_) => builder.get::<Float64Builder>()?.append_null(),
+            Self::Binary(_, _) => builder.get::<BinaryBuilder>()?.append_null(),
+            Self::String(_, _) | Self::Json(_, _) => builder.get::<StringBuilder>()?.append_null(),
+            Self::Uuid(_) => builder.get::<FixedSizeBinaryBuilder>()?.append_null(),
+            Self::Decimal128(..) => builder.get::<Decimal128Builder>()?.append_null(),
+            Self::Decimal256(..) => builder.get::<Decimal256Builder>()?.append_null(),
+            Self::Enum(_, _, _) => builder
+                .get::<StringDictionaryBuilder<Int32Type>>()?
+                .append_null(),
+            Self::List(_, _, _) => builder
+                .get::<ListBuilder<Box<dyn ArrayBuilder>>>()?
+                .append(false),
+            Self::Map(_, _, _, _) => builder
+                .get::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>()?
+                .append(false)?,
+            Self::Record(_, encodings, _) => {
+                let builder = builder.get::<StructBuilder>()?;
+                for (idx, encoding) in encodings.iter().enumerate() {
+                    encoding.append_null_into(BuilderRef::Field(builder, idx))?;
+                }
+                builder.append_null()
+            }
+            Self::Union(UnionHandling::Struct, _, decoders, _) => {
+                let builder = builder.get::<StructBuilder>()?;
+                for (child, decoder) in decoders.iter().flatten().enumerate() {
+                    decoder.append_null_into(BuilderRef::Field(builder, child))?;
+                }
+                builder.append_null()
+            }
+            Self::Nullable(_, _, e) | Self::Statistics(_, e) => e.append_null_into(builder)?,
+            _ => return Err(self.builder_not_supported()),
+        }
+        Ok(())
+    }
+
+    /// Decode the string `key` of a map entry into `builder`, see [`Self::decode_map_key`]
+    fn decode_map_key_into(
+        &self,
+        key: &[u8],
+        builder: &mut dyn ArrayBuilder,
+    ) -> Result<(), ArrowError> {
+        let mut builder = BuilderRef::Dyn(builder);
+        let parse_err = |t: &str| {
+            let key = String::from_utf8_lossy(key);
+            ArrowError::ParseError(format!("Failed to parse map key \"{key}\" as {t}"))
+        };
+        let key_str = std::str::from_utf8(key).ok();
+        match self {
+            Self::String(_, _) => {
+                let key = key_str.ok_or_else(|| parse_err("string"))?;
+                builder.get::<StringBuilder>()?.append_value(key)
+            }
+            Self::Int32(_) => {
+                let v = key_str.and_then(|s| s.parse().ok());
+                builder
+                    .get::<Int32Builder>()?
+                    .append_value(v.ok_or_else(|| parse_err("int"))?)
+            }
+            Self::Int64(_) => {
+                let v = key_str.and_then(|s| s.parse().ok());
+                builder
+                    .get::<Int64Builder>()?
+                    .append_value(v.ok_or_else(|| parse_err("long"))?)
+            }
+            _ => unreachable!("unexpected map key decoder"),
+        }
+        Ok(())
+    }
+
+    /// Returns the error for a [`Decoder`] that does not support decoding into builders
+    fn builder_not_supported(&self) -> ArrowError {
+        let decoder = match self {
+            Self::Null(_, _) => "null fields of a non-null type",
+            Self::Union(UnionHandling::Utf8, _, _, _) => "unions decoded as strings",
+            Self::Dictionary(_, _, _, _) => "dictionary encoded structs",
+            Self::Custom(_, _) => "fields with a custom FieldDecoder",
+            _ => "this type",
+        };
+        ArrowError::NotYetImplemented(format!("Decoding {decoder} into builders"))
+    }
+}
+
+/// Formats the `len` values of a union as a [`StringArray`], where `arrays` contains the
+/// values of each branch other than `null`, with at most one valid value for each record
+fn format_union(
+    arrays: &[ArrayRef],
+    len: usize,
+    nulls: Option<NullBuffer>,
+) -> Result<StringArray, ArrowError> {
+    let options = FormatOptions::default();
+    let formatters = arrays
+        .iter()
+        .map(|a| ArrayFormatter::try_new(a.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut builder = StringBuilder::with_capacity(len, len * 8);
+    for idx in 0..len {
+        let valid = (0..arrays.len()).find(|a| arrow_array::Array::is_valid(&arrays[*a], idx));
+        match valid.filter(|_| nulls.as_ref().map_or(true, |n| n.is_valid(idx))) {
+            Some(branch) => {
+                write!(builder, "{}", formatters[branch].value(idx))
+                    .map_err(|e| ArrowError::ComputeError(e.to_string()))?;
+                builder.append_value("");
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Accumulates [`ColumnStatistics`] from the values of a [`Decoder`]
+#[derive(Debug, Default)]
+struct StatisticsBuilder {
+    null_count: usize,
+    min: Option<StatisticsValue>,
+    max: Option<StatisticsValue>,
+    /// The number of values, including nulls
+    len: usize,
+    /// Reverts the last value, see [`Self::truncate`]
+    undo: StatisticsUndo,
+}
+
+/// Reverts the update of a [`StatisticsBuilder`] by its last value
+#[derive(Debug, Default)]
+enum StatisticsUndo {
+    #[default]
+    None,
+    /// Decrement the null count
+    Null,
+    /// Restore the minimum and maximum replaced by the value, if any
+    Value(
+        Option<Option<StatisticsValue>>,
+        Option<Option<StatisticsValue>>,
+    ),
+}
+
+impl StatisticsBuilder {
+    /// Record a null value
+    fn append_null(&mut self) {
+        self.len += 1;
+        self.null_count += 1;
+        self.undo = StatisticsUndo::Null;
+    }
+
+    /// Discard all but the first `len` values, which must not discard more than
+    /// the last value
+    fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        debug_assert_eq!(len + 1, self.len, "only the last value can be discarded");
+        self.len = len;
+        match std::mem::take(&mut self.undo) {
+            StatisticsUndo::None => {}
+            StatisticsUndo::Null => self.null_count -= 1,
+            StatisticsUndo::Value(min, max) => {
+                if let Some(min) = min {
+                    self.min = min;
+                }
+                if let Some(max) = max {
+                    self.max = max;
+                }
+            }
+        }
+    }
+
+    /// Update the statistics with the value last decoded by `decoder`, where `offset`
+    /// is the length of the values of a byte array decoder prior to decoding it
+    fn update(&mut self, decoder: &Decoder, offset: usize) {
+        self.len += 1;
+        self.undo = StatisticsUndo::Value(None, None);
+        let value = match decoder {
+            Decoder::Null(_, _) => {
+                self.null_count += 1;
+                self.undo = StatisticsUndo::Null;
+                return;
+            }
+            Decoder::Boolean(b) => StatisticsValue::Boolean(b.get_bit(b.len() - 1)),
+            Decoder::Int32(v) | Decoder::Date32(v) | Decoder::TimeMillis(v) => {
+                StatisticsValue::Int32(v[v.len() - 1])
+            }
+            Decoder::Int64(v)
+            | Decoder::TimeMicros(v)
+            | Decoder::TimestampMillis(_, v)
+            | Decoder::TimestampMicros(_, v) => StatisticsValue::Int64(v[v.len() - 1]),
+            Decoder::Float32(v) => match v[v.len() - 1] {
+                x if x.is_nan() => return,
+                x => StatisticsValue::Float32(x),
+            },
+            Decoder::Float64(v) => match v[v.len() - 1] {
+                x if x.is_nan() => return,
+                x => StatisticsValue::Float64(x),
+            },
+            Decoder::Decimal128(_, _, _, Some(r), _) | Decoder::Decimal256(_, _, _, Some(r), _)
+                if r.last_is_null() =>
+            {
+                self.null_count += 1;
+                self.undo = StatisticsUndo::Null;
+                return;
+            }
+            Decoder::Decimal128(_, _, _, _, v) => StatisticsValue::Decimal128(v[v.len() - 1]),
+            Decoder::Decimal256(_, _, _, _, v) => StatisticsValue::Decimal256(v[v.len() - 1]),
+            Decoder::Binary(_, v)
+            | Decoder::String(_, v)
+            | Decoder::Json(_, v)
+            | Decoder::Uuid(v) => return self.update_bytes(&v[offset..]),
+            _ => return,
+        };
+        let mut undo = (None, None);
+        if !matches!(&self.min, Some(min) if *min <= value) {
+            undo.0 = Some(self.min.replace(value.clone()));
+        }
+        if !matches!(&self.max, Some(max) if *max >= value) {
+            undo.1 = Some(self.max.replace(value));
+        }
+        self.undo = StatisticsUndo::Value(undo.0, undo.1);
+    }
+
+    /// Update the statistics with a byte array value, only allocating if it is
+    /// a new minimum or maximum
+    fn update_bytes(&mut self, value: &[u8]) {
+        let mut undo = (None, None);
+        if !matches!(&self.min, Some(StatisticsValue::Bytes(min)) if min.as_slice() <= value) {
+            undo.0 = Some(self.min.replace(StatisticsValue::Bytes(value.to_vec())));
+        }
+        if !matches!(&self.max, Some(StatisticsValue::Bytes(max)) if max.as_slice() >= value) {
+            undo.1 = Some(self.max.replace(StatisticsValue::Bytes(value.to_vec())));
+        }
+        self.undo = StatisticsUndo::Value(undo.0, undo.1);
+    }
+
+    fn finish(self) -> ColumnStatistics {
+        ColumnStatistics {
+            null_count: self.null_count,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+/// Decodes the fields of a writer record into the [`Decoder`] of a reader record
+#[derive(Debug)]
+struct Projection {
+    /// The action for each field in the writer record
+    writer_fields: Vec<WriterField>,
+    /// The indexes of the reader fields not present in the writer record
+    missing: Vec<usize>,
+}
+
+#[derive(Debug)]
+enum WriterField {
+    /// Decode the field into the reader field with the given index
+    Read(usize),
+    /// Skip over a field of the given type
+    Skip(AvroDataType),
+}
+
+impl Projection {
+    /// Create a new [`Projection`], where `indices` contains the index of the
+    /// [`Decoder`] of each reader field, or `None` if the field is not decoded
+    fn new(resolution: &ResolvedRecord, indices: &[Option<usize>]) -> Self {
+        let writer_fields = resolution
+            .writer_to_reader()
+            .iter()
+            .zip(resolution.writer_fields())
+            .map(
+                |(reader_idx, field)| match reader_idx.and_then(|idx| indices[idx]) {
+                    Some(idx) => WriterField::Read(idx),
+                    None => WriterField::Skip(field.data_type().clone()),
+                },
+            )
+            .collect();
+
+        let read: Vec<_> = resolution.writer_to_reader().iter().flatten().collect();
+        let missing = (0..indices.len())
+            .filter(|x| !read.contains(&x))
+            .filter_map(|x| indices[x])
+            .collect();
+        Self {
+            writer_fields,
+            missing,
+        }
+    }
+
+    /// Decode a single record from `buf` into `decoders`
+    fn decode(&self, decoders: &mut [Decoder], buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        for field in &self.writer_fields {
+            match field {
+                WriterField::Read(idx) => decoders[*idx].decode(buf)?,
+                WriterField::Skip(data_type) => skip_value(data_type, buf)?,
+            }
+        }
+        for idx in &self.missing {
+            decoders[*idx].append_null();
+        }
+        Ok(())
+    }
+
+    /// Decode a single record from `buf` into `children`, the builders of `decoders`
+    fn decode_into(
+        &self,
+        decoders: &[Decoder],
+        buf: &mut AvroCursor<'_>,
+        children: &mut ChildBuilders<'_>,
+    ) -> Result<(), ArrowError> {
+        for field in &self.writer_fields {
+            match field {
+                WriterField::Read(idx) => decoders[*idx].decode_into(buf, children.get(*idx))?,
+                WriterField::Skip(data_type) => skip_value(data_type, buf)?,
+            }
+        }
+        for idx in &self.missing {
+            decoders[*idx].append_null_into(children.get(*idx))?;
+        }
+        Ok(())
+    }
+}
+
+/// Skips over a single value of `data_type` in `buf`
+fn skip_value(data_type: &AvroDataType, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+    if let Some(nullability) = data_type.nullability() {
+        let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
+        if !is_valid {
+            return Ok(());
+        }
+    }
+
+    match data_type.codec() {
+        Codec::Null => {}
+        Codec::Boolean => {
+            buf.get_u8()?;
+        }
+        Codec::Int32
+        | Codec::Int64
+        | Codec::Date32
+        | Codec::TimeMillis
+        | Codec::TimeMicros
+        | Codec::TimestampMillis(_)
+        | Codec::TimestampMicros(_) => {
+            buf.get_long()?;
+        }
+        Codec::Float32 => {
+            buf.get_fixed(4)?;
+        }
+        Codec::Float64 => {
+            buf.get_fixed(8)?;
+        }
+        Codec::Binary | Codec::Utf8 | Codec::Uuid | Codec::Json => {
+            buf.get_bytes()?;
+        }
+        Codec::Fixed(size) => {
+            buf.get_fixed(*size as usize)?;
+        }
+        Codec::Decimal(_, _, size, _) => {
+            get_decimal_bytes(buf, *size)?;
+        }
+        Codec::Interval => {
+            buf.get_fixed(12)?;
+        }
+        Codec::Enum(_) => {
+            buf.get_int()?;
+        }
+        Codec::Union(branches) => {
+            let branch = buf.get_long()?;
+            let branch = usize::try_from(branch)
+                .ok()
+                .and_then(|b| branches.get(b))
+                .ok_or_else(|| {
+                    ArrowError::ParseError(format!(
+                        "Union branch {branch} out of range for {} branches",
+                        branches.len()
+                    ))
+                })?;
+            skip_value(branch.data_type(), buf)?;
+        }
+        Codec::List(item) => loop {
+            let count = buf.get_long()?;
+            if count == 0 {
+                break;
+            }
+            if count < 0 {
+                // A negative count is followed by the size of the block in bytes
+                let size = buf.get_long()?;
+                buf.get_fixed(size.try_into().map_err(|_| {
+                    ArrowError::ParseError(format!("Invalid array block size {size}"))
+                })?)?;
+                continue;
+            }
+            for _ in 0..count {
+                skip_value(item, buf)?;
+            }
+        },
+        Codec::Map(_, value) => loop {
+            let count = buf.get_long()?;
+            if count == 0 {
+                break;
+            }
+            if count < 0 {
+                // A negative count is followed by the size of the block in bytes
+                let size = buf.get_long()?;
+                buf.get_fixed(size.try_into().map_err(|_| {
+                    ArrowError::ParseError(format!("Invalid map block size {size}"))
+                })?)?;
+                continue;
+            }
+            for _ in 0..count {
+                buf.get_bytes()?;
+                skip_value(value, buf)?;
+            }
+        },
+        Codec::Struct(fields) => {
+            // A resolved record is encoded with the fields of the writer schema
+            let fields = match data_type.resolution() {
+                Some(resolution) => resolution.writer_fields(),
+                None => fields,
+            };
+            for field in fields {
+                skip_value(field.data_type(), buf)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the two's-complement big-endian bytes of a decimal, stored in a fixed
+/// of `size` bytes, or as bytes if `None`
+fn get_decimal_bytes<'a>(
+    buf: &mut AvroCursor<'a>,
+    size: Option<usize>,
+) -> Result<&'a [u8], ArrowError> {
+    match size {
+        Some(size) => buf.get_fixed(size),
+        None => buf.get_bytes(),
+    }
+}
+
+/// Converts decimal values to the decoded decimal type, rescaling those of a writer
+/// decimal with a different precision or scale, see [`DecimalRescale`], and collecting
+/// any values that cannot be converted if [`DecoderOptions::conversion_errors`]
+#[derive(Debug)]
+struct DecimalConverter {
+    rescale: Option<DecimalRescale>,
+    /// The path of the field being decoded, for error messages
+    path: String,
+    /// The validity of the decoded values, with those that cannot be converted
+    /// invalid if decoded as null
+    nulls: NullBufferBuilder,
+    /// The values that could not be converted, if collected
+    errors: Option<Vec<ConversionError>>,
+}
+
+impl DecimalConverter {
+    /// Convert the two's-complement big-endian `bytes` to the decoded `precision` and
+    /// `scale`, returning `None` if it overflows and is decoded as null
+    fn convert(&self, bytes: &[u8], precision: u8, scale: i8) -> Result<Option<i256>, ArrowError> {
+        let data_type = || match precision <= DECIMAL128_MAX_PRECISION {
+            true => DataType::Decimal128(precision, scale),
+            false => DataType::Decimal256(precision, scale),
+        };
+        let Some(rescale) = &self.rescale else {
+            let value = match precision <= DECIMAL128_MAX_PRECISION {
+                true => i256::from_i128(i128::from_be_bytes(sign_extend(bytes)?)),
+                false => i256::from_be_bytes(sign_extend(bytes)?),
+            };
+            if Decimal256Type::is_valid_decimal_precision(value, precision) {
+                return Ok(Some(value));
+            }
+            let value = Decimal256Type::format_decimal(value, DECIMAL256_MAX_PRECISION, scale);
+            return Err(ArrowError::ParseError(format!(
+                "Decimal value {value} of field \"{}\" cannot be represented as {}",
+                self.path,
+                data_type()
+            )));
+        };
+        let value = i256::from_be_bytes(sign_extend(bytes)?);
+        let rescaled = rescale_decimal(value, rescale.writer_scale(), scale)
+            .filter(|v| Decimal256Type::is_valid_decimal_precision(*v, precision));
+        match (rescaled, rescale.overflow()) {
+            (Some(v), _) => Ok(Some(v)),
+            (None, DecimalOverflowHandling::Null) => Ok(None),
+            (None, DecimalOverflowHandling::Error) => {
+                let value = Decimal256Type::format_decimal(
+                    value,
+                    rescale.writer_precision(),
+                    rescale.writer_scale(),
+                );
+                Err(ArrowError::ParseError(format!(
+                    "Decimal value {value} of field \"{}\" cannot be represented as {}",
+                    self.path,
+                    data_type()
+                )))
+            }
+        }
+    }
+
+    /// Convert the two's-complement big-endian `bytes`, as for [`Self::convert`],
+    /// recording its validity and returning zero if decoded as null
+    fn decode(&mut self, bytes: &[u8], precision: u8, scale: i8) -> Result<i256, ArrowError> {
+        let value = match (self.convert(bytes, precision, scale), &mut self.errors) {
+            (Ok(value), _) => value,
+            (Err(e), Some(errors)) => {
+                errors.push(ConversionError {
+                    row: self.nulls.len(),
+                    column: self.path.clone(),
+                    error: e.to_string(),
+                });
+                None
+            }
+            (Err(e), None) => return Err(e),
+        };
+        self.nulls.append(value.is_some());
+        Ok(value.unwrap_or(i256::ZERO))
+    }
+
+    /// Discard all but the first `len` values, along with any errors converting them
+    fn truncate(&mut self, len: usize) {
+        self.nulls.truncate(len);
+        if let Some(errors) = &mut self.errors {
+            errors.retain(|e| e.row < len);
+        }
+    }
+
+    /// Returns true if the last decoded value could not be converted and was decoded as null
+    fn last_is_null(&self) -> bool {
+        let len = self.nulls.len();
+        self.nulls
+            .as_slice()
+            .is_some_and(|s| !bit_util::get_bit(s, len - 1))
+    }
+
+    /// Combines `nulls` with the values of `converter` decoded as null, if any
+    fn flush_nulls(converter: &mut Option<Self>, nulls: Option<NullBuffer>) -> Option<NullBuffer> {
+        let overflowed = converter.as_mut().and_then(|r| r.nulls.finish());
+        NullBuffer::union(nulls.as_ref(), overflowed.as_ref())
+    }
+}
+
+/// Rescales the decimal `value` from scale `from` to scale `to`, rounding half away
+/// from zero, returning `None` if it overflows
+fn rescale_decimal(value: i256, from: i8, to: i8) -> Option<i256> {
+    let delta = to as i32 - from as i32;
+    let Some(factor) = i256::from_i128(10).checked_pow(delta.unsigned_abs()) else {
+        // All values are smaller than such a factor, and so are rounded to zero
+        return (delta < 0).then_some(i256::ZERO);
+    };
+    match delta.cmp(&0) {
+        Ordering::Equal => Some(value),
+        Ordering::Greater => value.checked_mul(factor),
+        Ordering::Less => {
+            let quotient = value.wrapping_div(factor);
+            let remainder = value.wrapping_rem(factor);
+            let half = factor.wrapping_div(i256::from_i128(2));
+            Some(match remainder {
+                r if r >= half => quotient.wrapping_add(i256::ONE),
+                r if r <= half.wrapping_neg() => quotient.wrapping_sub(i256::ONE),
+                _ => quotient,
+            })
+        }
+    }
+}
+
+/// Sign extends the two's-complement big-endian `bytes` to `N` bytes
+fn sign_extend<const N: usize>(bytes: &[u8]) -> Result<[u8; N], ArrowError> {
+    let fill = match bytes.first() {
+        Some(b) if *b & 0x80 != 0 => 0xFF,
+        _ => 0,
+    };
+    let mut out = [fill; N];
+    if bytes.len() > N {
+        // Permit redundant sign extension beyond N bytes
+        let (extra, bytes) = bytes.split_at(bytes.len() - N);
+        if extra.iter().any(|b| *b != fill) || (bytes[0] & 0x80 != 0) != (fill != 0) {
+            return Err(ArrowError::ParseError(format!(
+                "Decimal of {} bytes exceeds {N} bytes",
+                extra.len() + N
+            )));
+        }
+        out.copy_from_slice(bytes);
+    } else {
+        out[N - bytes.len()..].copy_from_slice(bytes);
+    }
+    Ok(out)
+}
+
+/// Parses the string representation of a UUID, as described in [RFC 4122]
+///
+/// [RFC 4122]: https://www.rfc-editor.org/rfc/rfc4122#section-3
+fn parse_uuid(s: &[u8]) -> Result<[u8; 16], ArrowError> {
+    let err = || ArrowError::ParseError(format!("Invalid UUID \"{}\"", String::from_utf8_lossy(s)));
+
+    let valid = match s.len() {
+        32 => true,
+        36 => [8, 13, 18, 23].iter().all(|i| s[*i] == b'-'),
+        _ => false,
+    };
+    if !valid {
+        return Err(err());
+    }
+
+    let mut digits = s.iter().filter(|b| **b != b'-').map(|b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(err()),
+    });
+
+    let mut out = [0_u8; 16];
+    for o in out.iter_mut() {
+        match (digits.next(), digits.next()) {
+            (Some(hi), Some(lo)) => *o = (hi? << 4) | lo?,
+            _ => return Err(err()),
+        }
+    }
+    if digits.next().is_some() {
+        return Err(err());
+    }
+    Ok(out)
+}
+
 #[inline]
 fn flush_values<T>(values: &mut Vec<T>) -> Vec<T> {
     std::mem::replace(values, Vec::with_capacity(DEFAULT_CAPACITY))
@@ -290,3 +1965,100 @@ fn flush_primitive<T: ArrowPrimitiveType>(
 }
 
 const DEFAULT_CAPACITY: usize = 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{AvroField, EXTENSION_TYPE_NAME_KEY};
+    use arrow_array::cast::AsArray;
+
+    fn encode_string(out: &mut Vec<u8>, s: &str) {
+        // Lengths are zig-zag encoded, all test strings are shorter than 64 bytes
+        assert!(s.len() < 64);
+        out.push((s.len() as u8) << 1);
+        out.extend_from_slice(s.as_bytes());
+    }
+
+    #[test]
+    fn test_uuid_json() {
+        let schema = r#"{
+            "type": "record",
+            "name": "test",
+            "fields": [
+                {"name": "id", "type": {"type": "string", "logicalType": "uuid"}},
+                {"name": "doc", "type": ["null", {"type": "string", "logicalType": "json"}]}
+            ]
+        }"#;
+        let schema: Schema = serde_json::from_str(schema).unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        let mut decoder = RecordDecoder::try_new(field.data_type()).unwrap();
+
+        let fields = decoder.schema().fields();
+        assert_eq!(fields[0].data_type(), &DataType::FixedSizeBinary(16));
+        assert_eq!(
+            fields[0].metadata().get(EXTENSION_TYPE_NAME_KEY).unwrap(),
+            "arrow.uuid"
+        );
+        assert_eq!(fields[1].data_type(), &DataType::Utf8);
+        assert_eq!(
+            fields[1].metadata().get(EXTENSION_TYPE_NAME_KEY).unwrap(),
+            "arrow.json"
+        );
+
+        let mut buf = vec![];
+        encode_string(&mut buf, "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        buf.push(2); // Union branch 1
+        encode_string(&mut buf, r#"{"a":1}"#);
+        encode_string(&mut buf, "6BA7B8119DAD11D180B400C04FD430C8");
+        buf.push(0); // Union branch 0, null
+
+        assert_eq!(decoder.decode(&buf, 2).unwrap(), buf.len());
+        let batch = decoder.flush().unwrap();
+
+        let ids = batch.column(0).as_fixed_size_binary();
+        assert_eq!(
+            ids.value(0),
+            &[
+                0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+                0x30, 0xc8
+            ]
+        );
+        assert_eq!(
+            ids.value(1),
+            &[
+                0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+                0x30, 0xc8
+            ]
+        );
+
+        let docs = batch.column(1).as_string::<i32>();
+        assert_eq!(docs, &StringArray::from(vec![Some(r#"{"a":1}"#), None]));
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let expected = [
+            0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ];
+        assert_eq!(
+            parse_uuid(b"6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_uuid(b"6ba7b8109dad11d180b400c04fd430c8").unwrap(),
+            expected
+        );
+
+        for invalid in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c",
+            "6ba7b810-9dad-11d1-80b4-00c04fd430cg",
+            "6ba7b8109dad-11d1-80b4-00c04fd430c8",
+            "6ba7b810-9dad-11d1-80b4-00c04f-430c8",
+            "",
+        ] {
+            let err = parse_uuid(invalid.as_bytes()).unwrap_err().to_string();
+            assert_eq!(err, format!("Parser error: Invalid UUID \"{invalid}\""));
+        }
+    }
+}
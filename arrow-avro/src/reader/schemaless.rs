@@ -0,0 +1,347 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Best-effort decoding of Avro datums whose writer schema is unavailable
+
+use crate::reader::cursor::AvroCursor;
+use arrow_array::builder::{BinaryBuilder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The schema metadata key marking a [`RecordBatch`] returned by [`infer_schemaless`]
+/// as the lossy result of structural inference
+pub const LOSSY_METADATA_KEY: &str = "avro.schemaless.lossy";
+
+/// The type guessed for a field by [`infer_schemaless`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InferredType {
+    /// A zig-zag encoded variable length integer, which may equally have been an
+    /// Avro `int`, `boolean`, `enum`, union branch, or array or map block count
+    Long,
+    /// A length-prefixed UTF-8 string
+    String,
+    /// A length-prefixed byte array
+    Bytes,
+}
+
+impl InferredType {
+    /// Returns the arrow [`DataType`] values of this type are decoded to
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::Long => DataType::Int64,
+            Self::String => DataType::Utf8,
+            Self::Bytes => DataType::Binary,
+        }
+    }
+}
+
+/// Options controlling the heuristics of [`infer_schemaless`]
+#[derive(Debug, Clone, Copy)]
+pub struct SchemalessOptions {
+    prefix_len: usize,
+    min_string_len: usize,
+    max_fields: usize,
+    trailing_bytes: bool,
+}
+
+impl Default for SchemalessOptions {
+    fn default() -> Self {
+        Self {
+            prefix_len: 0,
+            min_string_len: 1,
+            max_fields: 256,
+            trailing_bytes: true,
+        }
+    }
+}
+
+impl SchemalessOptions {
+    /// Set the number of bytes to ignore at the start of each datum, such as the
+    /// 10-byte header of the Avro single object encoding, defaults to 0
+    pub fn with_prefix_len(mut self, prefix_len: usize) -> Self {
+        self.prefix_len = prefix_len;
+        self
+    }
+
+    /// Set the minimum length of a length-prefixed value that may be guessed to be a
+    /// string, defaults to 1
+    ///
+    /// Short values are otherwise often indistinguishable from a pair of integers
+    pub fn with_min_string_len(mut self, min_string_len: usize) -> Self {
+        self.min_string_len = min_string_len;
+        self
+    }
+
+    /// Set the maximum number of fields guessed per datum, defaults to 256
+    ///
+    /// The remaining bytes of a datum with more fields are returned as the raw
+    /// bytes of its last field
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields.max(1);
+        self
+    }
+
+    /// Guess a length-prefixed value that is not a string, and that extends exactly
+    /// to the end of the datum, to be bytes, defaults to `true`
+    ///
+    /// Otherwise, such values are guessed to be a long followed by further fields
+    pub fn with_trailing_bytes(mut self, trailing_bytes: bool) -> Self {
+        self.trailing_bytes = trailing_bytes;
+        self
+    }
+}
+
+/// A single value guessed from a datum
+#[derive(Debug)]
+struct Token<'a> {
+    value: TokenValue<'a>,
+    /// The Avro encoding of the value
+    raw: &'a [u8],
+}
+
+#[derive(Debug)]
+enum TokenValue<'a> {
+    Long(i64),
+    String(&'a str),
+    Bytes(&'a [u8]),
+}
+
+impl TokenValue<'_> {
+    fn inferred_type(&self) -> InferredType {
+        match self {
+            Self::Long(_) => InferredType::Long,
+            Self::String(_) => InferredType::String,
+            Self::Bytes(_) => InferredType::Bytes,
+        }
+    }
+}
+
+/// Returns `value` as a string if it is likely to be text, i.e. UTF-8 without control characters
+/// other than whitespace
+fn as_text(value: &[u8]) -> Option<&str> {
+    let s = std::str::from_utf8(value).ok()?;
+    let printable = s.chars().all(|c| !c.is_control() || c.is_whitespace());
+    printable.then_some(s)
+}
+
+/// Splits `datum` into a sequence of guessed values
+fn tokenize<'a>(datum: &'a [u8], options: &SchemalessOptions) -> Vec<Token<'a>> {
+    let mut tokens = vec![];
+    let mut cursor = AvroCursor::new(datum.get(options.prefix_len..).unwrap_or_default());
+    while !cursor.remaining().is_empty() {
+        let start = cursor.remaining();
+        let long = match tokens.len() + 1 < options.max_fields {
+            true => cursor.get_long().ok(),
+            false => None,
+        };
+        let Some(long) = long else {
+            // Truncated varint or too many fields
+            tokens.push(Token {
+                value: TokenValue::Bytes(start),
+                raw: start,
+            });
+            break;
+        };
+
+        let remaining = cursor.remaining();
+        let mut value = TokenValue::Long(long);
+        if let Some(contents) = usize::try_from(long).ok().and_then(|l| remaining.get(..l)) {
+            let text = as_text(contents).filter(|_| contents.len() >= options.min_string_len);
+            if let Some(s) = text {
+                value = TokenValue::String(s);
+            } else if options.trailing_bytes && contents.len() == remaining.len() && long > 0 {
+                value = TokenValue::Bytes(contents);
+            }
+            if !matches!(value, TokenValue::Long(_)) {
+                cursor.get_fixed(contents.len()).unwrap();
+            }
+        }
+        let raw = &start[..start.len() - cursor.remaining().len()];
+        tokens.push(Token { value, raw });
+    }
+    tokens
+}
+
+/// Decodes Avro binary encoded `datums` written with an unknown schema into a
+/// [`RecordBatch`], by guessing the structure of each datum
+///
+/// **This is lossy, and intended only for forensic purposes where the writer schema
+/// is irrecoverable**. Avro binary encoding is not self-describing, and so each
+/// datum is split into a sequence of longs, strings and bytes, without attempting
+/// to recover records, arrays, maps, unions or floating point values. The guesses
+/// are not guaranteed to correspond to the fields of the writer schema.
+///
+/// The returned batch has a nullable column `field_{i}` for the `i`-th value of the
+/// datums, null for datums with fewer values. If the guessed types of a column differ
+/// between datums, it is decoded as bytes containing the raw Avro encoding of each value.
+/// The schema of the batch has the metadata [`LOSSY_METADATA_KEY`] set to `"true"`
+///
+/// ```
+/// # use arrow_avro::reader::{infer_schemaless, SchemalessOptions};
+/// # use arrow_schema::DataType;
+/// // The long 1 followed by the string "hi"
+/// let datum: &[u8] = &[2, 4, b'h', b'i'];
+/// let batch = infer_schemaless([datum], &SchemalessOptions::default()).unwrap();
+/// assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+/// assert_eq!(batch.schema().field(1).data_type(), &DataType::Utf8);
+/// ```
+pub fn infer_schemaless<'a>(
+    datums: impl IntoIterator<Item = &'a [u8]>,
+    options: &SchemalessOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let datums: Vec<_> = datums.into_iter().map(|d| tokenize(d, options)).collect();
+    let num_fields = datums.iter().map(|d| d.len()).max().unwrap_or_default();
+
+    let mut fields = Vec::with_capacity(num_fields);
+    let mut columns = Vec::with_capacity(num_fields);
+    for idx in 0..num_fields {
+        let mut values = datums.iter().map(|d| d.get(idx));
+        let mut types = values.clone().flatten().map(|t| t.value.inferred_type());
+        let first = types.next().unwrap();
+        let consistent = types.all(|t| t == first);
+
+        let column: ArrayRef = match (first, consistent) {
+            (InferredType::Long, true) => Arc::new(
+                values
+                    .map(|t| match t.map(|t| &t.value) {
+                        Some(TokenValue::Long(v)) => Some(*v),
+                        _ => None,
+                    })
+                    .collect::<arrow_array::Int64Array>(),
+            ),
+            (InferredType::String, true) => {
+                let mut builder = StringBuilder::new();
+                values.for_each(|t| match t.map(|t| &t.value) {
+                    Some(TokenValue::String(v)) => builder.append_value(v),
+                    _ => builder.append_null(),
+                });
+                Arc::new(builder.finish())
+            }
+            (InferredType::Bytes, true) => {
+                let mut builder = BinaryBuilder::new();
+                values.for_each(|t| match t.map(|t| &t.value) {
+                    Some(TokenValue::Bytes(v)) => builder.append_value(v),
+                    _ => builder.append_null(),
+                });
+                Arc::new(builder.finish())
+            }
+            (_, false) => {
+                let mut builder = BinaryBuilder::new();
+                values.for_each(|t| builder.append_option(t.map(|t| t.raw)));
+                Arc::new(builder.finish())
+            }
+        };
+        fields.push(Field::new(
+            format!("field_{idx}"),
+            column.data_type().clone(),
+            true,
+        ));
+        columns.push(column);
+    }
+
+    let metadata = HashMap::from([(LOSSY_METADATA_KEY.to_string(), "true".to_string())]);
+    let schema = Arc::new(Schema::new_with_metadata(fields, metadata));
+    let options = RecordBatchOptions::new().with_row_count(Some(datums.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{encode_bytes, encode_long};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::Array;
+
+    #[test]
+    fn test_infer_schemaless() {
+        let mut a = vec![];
+        encode_long(&mut a, 42);
+        encode_bytes(&mut a, b"hello");
+        encode_long(&mut a, -1);
+        encode_bytes(&mut a, &[0, 1, 2]);
+
+        let mut b = vec![];
+        encode_long(&mut b, 7);
+        encode_bytes(&mut b, b"world");
+
+        let options = SchemalessOptions::default();
+        let batch = infer_schemaless([a.as_slice(), b.as_slice()], &options).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().metadata()[LOSSY_METADATA_KEY], "true");
+
+        let types: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                DataType::Int64,
+                DataType::Utf8,
+                DataType::Int64,
+                DataType::Binary
+            ]
+        );
+        let longs = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(longs.values(), &[42, 7]);
+        let strings = batch.column(1).as_string::<i32>();
+        assert_eq!(strings.value(0), "hello");
+        assert_eq!(strings.value(1), "world");
+        let longs = batch.column(2).as_primitive::<Int64Type>();
+        assert_eq!(longs.value(0), -1);
+        assert!(longs.is_null(1));
+        assert_eq!(batch.column(3).as_binary::<i32>().value(0), &[0, 1, 2]);
+
+        // Conflicting guesses are returned as raw bytes
+        let mut c = vec![];
+        encode_bytes(&mut c, b"abc");
+        let batch = infer_schemaless([a.as_slice(), c.as_slice()], &options).unwrap();
+        let raw = batch.column(0).as_binary::<i32>();
+        assert_eq!(raw.value(0), &[84]);
+        assert_eq!(raw.value(1), &[6, b'a', b'b', b'c']);
+
+        // Trailing bytes disabled
+        let options = SchemalessOptions::default().with_trailing_bytes(false);
+        let batch = infer_schemaless([a.as_slice()], &options).unwrap();
+        let types: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(types[3..], vec![DataType::Int64; 4]);
+
+        // Maximum fields and prefix
+        let mut prefixed = vec![0xC3, 0x01];
+        prefixed.extend_from_slice(&a);
+        let options = SchemalessOptions::default()
+            .with_prefix_len(2)
+            .with_max_fields(2);
+        let batch = infer_schemaless([prefixed.as_slice()], &options).unwrap();
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().value(0), 42);
+        assert_eq!(batch.column(1).as_binary::<i32>().value(0), &a[1..]);
+
+        let batch = infer_schemaless([], &options).unwrap();
+        assert_eq!((batch.num_rows(), batch.num_columns()), (0, 0));
+    }
+}
@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_buffer::i256;
+
+/// Statistics of a column of a decoded [`RecordBatch`](arrow_array::RecordBatch),
+/// collected while decoding if enabled with
+/// [`ReaderBuilder::with_statistics`](crate::reader::ReaderBuilder::with_statistics)
+///
+/// These allow building zone maps, or similar indexes, without rescanning the
+/// decoded data. The minimum and maximum are only collected for primitive,
+/// string and binary columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnStatistics {
+    pub(crate) null_count: usize,
+    pub(crate) min: Option<StatisticsValue>,
+    pub(crate) max: Option<StatisticsValue>,
+}
+
+impl ColumnStatistics {
+    /// Returns the number of null values
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// Returns the minimum non-null value, if any
+    pub fn min(&self) -> Option<&StatisticsValue> {
+        self.min.as_ref()
+    }
+
+    /// Returns the maximum non-null value, if any
+    pub fn max(&self) -> Option<&StatisticsValue> {
+        self.max.as_ref()
+    }
+}
+
+/// A minimum or maximum value of [`ColumnStatistics`]
+///
+/// Values are stored in their physical representation, for example the
+/// [`DataType::Timestamp`](arrow_schema::DataType::Timestamp) columns have
+/// [`StatisticsValue::Int64`] values, and must be interpreted according to the
+/// data type of the column. Floating point NaN values are ignored.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum StatisticsValue {
+    /// A boolean value
+    Boolean(bool),
+    /// A 32-bit integer, such as an `int`, `date` or `time-millis`
+    Int32(i32),
+    /// A 64-bit integer, such as a `long`, `time-micros` or timestamp
+    Int64(i64),
+    /// A 32-bit floating point value
+    Float32(f32),
+    /// A 64-bit floating point value
+    Float64(f64),
+    /// The unscaled value of a decimal with a precision of at most 38
+    Decimal128(i128),
+    /// The unscaled value of a decimal with a precision greater than 38
+    Decimal256(i256),
+    /// The bytes of a string, binary or UUID, compared lexicographically
+    Bytes(Vec<u8>),
+}
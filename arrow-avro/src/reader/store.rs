@@ -0,0 +1,435 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading Avro object container files from an [`ObjectStore`]
+
+use crate::reader::vlq::read_varint;
+use crate::reader::{Reader, ReaderBuilder};
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::Stream;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use std::io::{BufRead, Read};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+/// The default size of each range request, 1 MiB
+const DEFAULT_RANGE_SIZE: usize = 1024 * 1024;
+
+/// The default number of times a failed range request is retried
+const DEFAULT_MAX_RETRIES: usize = 3;
+
+/// The default delay before the first retry of a failed range request
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// A [`BufRead`] over an object in an [`ObjectStore`], fetched with sequential range requests
+///
+/// When used with [`Reader`], each range is fetched on demand once the previous one is
+/// consumed, blocking the calling thread on the tokio runtime provided with
+/// [`Self::with_runtime`], without which reading returns an error. As with
+/// [`Handle::block_on`], this must not be used from within an async context, where
+/// [`AsyncReader`] should be used instead
+#[derive(Debug)]
+pub struct ObjectStoreReader {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    /// The size of the object in bytes
+    size: usize,
+    /// The number of bytes of the object fetched so far
+    fetched: usize,
+    buffer: Vec<u8>,
+    /// The offset of the next unconsumed byte in `buffer`
+    pos: usize,
+    range_size: usize,
+    max_retries: usize,
+    retry_backoff: Duration,
+    /// The runtime on which to fetch further ranges on demand
+    runtime: Option<Handle>,
+    /// If the ranges are fetched ahead of reading by [`AsyncReader`], in which case
+    /// the buffered data is treated as the remainder of the object
+    prefetched: bool,
+}
+
+impl ObjectStoreReader {
+    /// Create a new [`ObjectStoreReader`] for the object at `path` in `store`
+    pub async fn try_new(store: Arc<dyn ObjectStore>, path: Path) -> Result<Self, ArrowError> {
+        let meta = store
+            .head(&path)
+            .await
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        Ok(Self {
+            store,
+            path,
+            size: meta.size,
+            fetched: 0,
+            buffer: vec![],
+            pos: 0,
+            range_size: DEFAULT_RANGE_SIZE,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+            runtime: None,
+            prefetched: false,
+        })
+    }
+
+    /// Fetch ranges on demand by blocking on `runtime`, as required when used with [`Reader`]
+    ///
+    /// The [`ObjectStore`] must be usable from `runtime`, which should therefore be a
+    /// multi-threaded runtime if the store performs IO, see [`Handle::block_on`]
+    pub fn with_runtime(mut self, runtime: Handle) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Set the size of each range request in bytes, defaults to 1 MiB
+    pub fn with_range_size(mut self, range_size: usize) -> Self {
+        self.range_size = range_size.max(1);
+        self
+    }
+
+    /// Set the number of times a failed range request is retried, defaults to 3
+    ///
+    /// Only transient errors, reported by the [`ObjectStore`] as
+    /// [`object_store::Error::Generic`], are retried. This is in addition to any
+    /// retries performed by the [`ObjectStore`] itself
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry of a failed range request, defaults to 100ms
+    ///
+    /// The delay doubles with each subsequent retry of the same request
+    pub fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Returns the buffered data not yet consumed
+    fn buffered(&self) -> &[u8] {
+        &self.buffer[self.pos..]
+    }
+
+    /// Fetch the next range of the object into the buffer, returning `false` if the
+    /// entire object has already been fetched
+    async fn fetch(&mut self) -> Result<bool, ArrowError> {
+        if self.fetched == self.size {
+            return Ok(false);
+        }
+        let range = self.fetched..self.size.min(self.fetched + self.range_size);
+        let mut retries = 0;
+        let bytes = loop {
+            match self.store.get_range(&self.path, range.clone()).await {
+                Ok(bytes) => break bytes,
+                Err(e) if is_transient(&e) && retries < self.max_retries => {
+                    tokio::time::sleep(retry_backoff(self.retry_backoff, retries)).await;
+                    retries += 1;
+                }
+                Err(e) => return Err(ArrowError::ExternalError(Box::new(e))),
+            }
+        };
+        self.buffer.drain(..self.pos);
+        self.pos = 0;
+        self.buffer.extend_from_slice(&bytes);
+        self.fetched = range.end;
+        Ok(true)
+    }
+
+    /// Fetch ranges until the buffer contains complete blocks with at least `rows`
+    /// records in total, or the remainder of the object
+    async fn prefetch_blocks(&mut self, rows: usize) -> Result<(), ArrowError> {
+        let mut offset = 0;
+        let mut found = 0;
+        while found < rows {
+            match block_len(&self.buffered()[offset..]) {
+                Some((count, len)) => {
+                    offset += len;
+                    found += count;
+                }
+                None if self.fetch().await? => {}
+                None => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if `e` may succeed if the request is retried, as opposed to errors
+/// such as a missing object or insufficient permissions
+fn is_transient(e: &object_store::Error) -> bool {
+    matches!(e, object_store::Error::Generic { .. })
+}
+
+/// Returns the delay before retry number `retries`, counting from zero, doubling
+/// `backoff` for each prior retry
+fn retry_backoff(backoff: Duration, retries: usize) -> Duration {
+    let factor = 1_u32.checked_shl(retries as u32).unwrap_or(u32::MAX);
+    backoff.saturating_mul(factor)
+}
+
+/// Returns the record count and encoded length of the block at the start of `buf`,
+/// if it is entirely contained within `buf`
+fn block_len(buf: &[u8]) -> Option<(usize, usize)> {
+    let (count, count_len) = read_varint(buf)?;
+    let (size, size_len) = read_varint(&buf[count_len..])?;
+    // Block counts and sizes are zig-zag encoded longs
+    let count = (count >> 1) as usize;
+    let len = count_len + size_len + (size >> 1) as usize + 16;
+    (len <= buf.len()).then_some((count, len))
+}
+
+impl Read for ObjectStoreReader {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        let buf = self.fill_buf()?;
+        let len = buf.len().min(out.len());
+        out[..len].copy_from_slice(&buf[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl BufRead for ObjectStoreReader {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if self.pos == self.buffer.len() && !self.prefetched {
+            let runtime = self.runtime.clone().ok_or_else(|| {
+                let e = ArrowError::InvalidArgumentError(
+                    "ObjectStoreReader requires a runtime to fetch ranges, see with_runtime".into(),
+                );
+                std::io::Error::new(std::io::ErrorKind::Other, e)
+            })?;
+            runtime
+                .block_on(self.fetch())
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        }
+        Ok(self.buffered())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+impl ReaderBuilder {
+    /// Create an [`AsyncReader`] reading from the provided [`ObjectStoreReader`]
+    ///
+    /// See [`Self::build`] to instead create a blocking [`Reader`]
+    pub async fn build_async(
+        self,
+        mut reader: ObjectStoreReader,
+    ) -> Result<AsyncReader, ArrowError> {
+        reader.runtime = None;
+        reader.prefetched = true;
+        // Fetch until the header is buffered, it is typically small
+        loop {
+            let mut decoder = crate::reader::header::HeaderDecoder::default();
+            let buffered = reader.buffered();
+            if decoder.decode(buffered)? < buffered.len() || decoder.flush().is_some() {
+                break;
+            }
+            if !reader.fetch().await? {
+                break;
+            }
+        }
+        Ok(AsyncReader {
+            reader: self.build(reader)?,
+        })
+    }
+}
+
+impl Reader<ObjectStoreReader> {
+    /// Create a [`Reader`] with the default options reading the Avro file at `path`
+    /// in `store`, with range requests blocking on the current tokio runtime
+    ///
+    /// Returns an error if not called within the context of a tokio runtime, such as
+    /// after [`Runtime::enter`]. Use [`ReaderBuilder::build`] with an [`ObjectStoreReader`]
+    /// to customize the options, and [`AsyncReader::open_store`] within an async context
+    ///
+    /// [`Runtime::enter`]: tokio::runtime::Runtime::enter
+    pub fn open_store(store: Arc<dyn ObjectStore>, path: Path) -> Result<Self, ArrowError> {
+        let runtime = Handle::try_current().map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        let reader = runtime.block_on(ObjectStoreReader::try_new(store, path))?;
+        ReaderBuilder::new().build(reader.with_runtime(runtime))
+    }
+}
+
+/// Asynchronously reads [`RecordBatch`] from an Avro file in an [`ObjectStore`]
+///
+/// Prior to decoding each batch, the complete blocks containing its records are fetched
+/// with range requests, after which the batch is decoded without blocking
+///
+/// Created with [`ReaderBuilder::build_async`]
+#[derive(Debug)]
+pub struct AsyncReader {
+    reader: Reader<ObjectStoreReader>,
+}
+
+impl AsyncReader {
+    /// Create an [`AsyncReader`] with the default options reading the Avro file at
+    /// `path` in `store`
+    ///
+    /// Use [`ReaderBuilder::build_async`] to customize the options
+    pub async fn open_store(store: Arc<dyn ObjectStore>, path: Path) -> Result<Self, ArrowError> {
+        let reader = ObjectStoreReader::try_new(store, path).await?;
+        ReaderBuilder::new().build_async(reader).await
+    }
+
+    /// Returns the arrow schema of the [`RecordBatch`] returned by this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+
+    /// Read the next [`RecordBatch`], returning `None` at the end of the file
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let rows = self.reader.rows_needed();
+        if let Some(reader) = self.reader.blocks.get_mut() {
+            reader.prefetch_blocks(rows).await?;
+        }
+        self.reader.read()
+    }
+
+    /// Convert this reader into a [`Stream`] of [`RecordBatch`]
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch, ArrowError>> + Send {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{encode_long, write_ocf};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use object_store::PutPayload;
+
+    fn ids(batches: &[RecordBatch]) -> Vec<i64> {
+        batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_object_store() {
+        let schema =
+            r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+        let blocks: Vec<_> = (0..10)
+            .map(|b| {
+                let mut data = vec![];
+                (b * 3..b * 3 + 3).for_each(|x| encode_long(&mut data, x));
+                (3, data)
+            })
+            .collect();
+        let file = write_ocf(schema, &blocks);
+        let expected: Vec<i64> = (0..30).collect();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let handle = runtime.handle().clone();
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("data/file.avro");
+        runtime
+            .block_on(store.put(&path, PutPayload::from(file)))
+            .unwrap();
+
+        let err = Reader::open_store(store.clone(), path.clone()).unwrap_err();
+        assert!(err.to_string().contains("no reactor running"), "{err}");
+
+        let guard = runtime.enter();
+        let reader = Reader::open_store(store.clone(), path.clone()).unwrap();
+        drop(guard);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(ids(&batches), expected);
+
+        // Fetching ranges on demand requires a runtime
+        let reader = runtime
+            .block_on(ObjectStoreReader::try_new(store.clone(), path.clone()))
+            .unwrap();
+        let err = ReaderBuilder::new().build(reader).unwrap_err();
+        assert!(err.to_string().contains("requires a runtime"), "{err}");
+
+        let reader = runtime
+            .block_on(AsyncReader::open_store(store.clone(), path.clone()))
+            .unwrap();
+        let batches: Vec<_> = runtime
+            .block_on(reader.into_stream().try_collect())
+            .unwrap();
+        assert_eq!(ids(&batches), expected);
+
+        // Small ranges, requiring several requests per block and per batch
+        let store_reader = |range_size| {
+            let reader = ObjectStoreReader::try_new(store.clone(), path.clone());
+            let reader = runtime.block_on(reader).unwrap();
+            reader
+                .with_range_size(range_size)
+                .with_runtime(handle.clone())
+        };
+        for range_size in [1, 5, 7, 100] {
+            let builder = ReaderBuilder::new().with_batch_size(4).with_skip(2);
+            let mut reader = runtime
+                .block_on(builder.build_async(store_reader(range_size)))
+                .unwrap();
+            let mut batches = vec![];
+            while let Some(batch) = runtime.block_on(reader.next_batch()).unwrap() {
+                batches.push(batch);
+            }
+            assert_eq!(ids(&batches), expected[2..]);
+            assert!(batches.iter().all(|b| b.num_rows() <= 4));
+
+            let reader = ReaderBuilder::new()
+                .build(store_reader(range_size))
+                .unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(ids(&batches), expected);
+        }
+
+        let _guard = runtime.enter();
+        let missing = Path::from("missing.avro");
+        let err = Reader::open_store(store, missing).unwrap_err();
+        assert!(err.to_string().contains("not found"), "{err}");
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        let generic = object_store::Error::Generic {
+            store: "test",
+            source: "connection reset".into(),
+        };
+        assert!(is_transient(&generic));
+        let not_found = object_store::Error::NotFound {
+            path: "missing.avro".into(),
+            source: "not found".into(),
+        };
+        assert!(!is_transient(&not_found));
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let backoff = Duration::from_millis(100);
+        assert_eq!(retry_backoff(backoff, 0), backoff);
+        assert_eq!(retry_backoff(backoff, 1), Duration::from_millis(200));
+        assert_eq!(retry_backoff(backoff, 3), Duration::from_millis(800));
+        assert_eq!(retry_backoff(backoff, 100), backoff * u32::MAX);
+    }
+}
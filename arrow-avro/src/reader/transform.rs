@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! User-provided transformation of decoded columns
+
+use arrow_array::ArrayRef;
+use arrow_schema::ArrowError;
+use std::fmt::{Debug, Formatter};
+
+/// Transforms each decoded array of a column, for example to normalize its values
+/// or convert it to a different type, see
+/// [`ReaderBuilder::with_column_transform`](crate::reader::ReaderBuilder::with_column_transform)
+///
+/// Implemented for closures of the form `Fn(ArrayRef) -> Result<ArrayRef, ArrowError>`
+pub trait ColumnTransform: Send + Sync {
+    /// Transform `array`, returning an array of the same length
+    ///
+    /// The returned array must have the same [`DataType`](arrow_schema::DataType)
+    /// for every input, including an empty array, which is used to determine the
+    /// [`DataType`](arrow_schema::DataType) of the column
+    fn transform(&self, array: ArrayRef) -> Result<ArrayRef, ArrowError>;
+}
+
+impl<F> ColumnTransform for F
+where
+    F: Fn(ArrayRef) -> Result<ArrayRef, ArrowError> + Send + Sync,
+{
+    fn transform(&self, array: ArrayRef) -> Result<ArrayRef, ArrowError> {
+        self(array)
+    }
+}
+
+impl Debug for dyn ColumnTransform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ColumnTransform")
+    }
+}
@@ -43,6 +43,11 @@ impl VLQDecoder {
         }
         None
     }
+
+    /// Returns `true` if part of a value has been decoded
+    pub fn in_progress(&self) -> bool {
+        self.shift != 0
+    }
 }
 
 /// Read a varint from `buf` returning the decoded `u64` and the number of bytes read
@@ -15,8 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+//! Avro schema definitions, and conversion from arrow schemas
+//!
+//! <https://avro.apache.org/docs/1.11.1/specification/>
+
+use crate::codec::{EXTENSION_TYPE_NAME_KEY, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
+use arrow_schema::{
+    ArrowError, DataType, Field as ArrowField, IntervalUnit, Schema as ArrowSchema, TimeUnit,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{json, Map as JsonMap, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
 
 /// The metadata key used for storing the JSON encoded [`Schema`]
 pub const SCHEMA_METADATA_KEY: &str = "avro.schema";
@@ -27,7 +37,9 @@ pub const SCHEMA_METADATA_KEY: &str = "avro.schema";
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TypeName<'a> {
+    /// A primitive type
     Primitive(PrimitiveType),
+    /// A reference to a named type, such as a record, enum or fixed
     Ref(&'a str),
 }
 
@@ -37,13 +49,21 @@ pub enum TypeName<'a> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PrimitiveType {
+    /// No value
     Null,
+    /// A binary value
     Boolean,
+    /// A 32-bit signed integer
     Int,
+    /// A 64-bit signed integer
     Long,
+    /// A single precision (32-bit) IEEE 754 floating-point number
     Float,
+    /// A double precision (64-bit) IEEE 754 floating-point number
     Double,
+    /// A sequence of 8-bit unsigned bytes
     Bytes,
+    /// A unicode character sequence
     String,
 }
 
@@ -56,7 +76,7 @@ pub struct Attributes<'a> {
     /// A logical type name
     ///
     /// <https://avro.apache.org/docs/1.11.1/specification/#logical-types>
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logical_type: Option<&'a str>,
 
     /// Additional JSON attributes
@@ -78,8 +98,10 @@ impl Attributes<'_> {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Type<'a> {
+    /// The underlying type
     #[serde(borrow)]
     pub r#type: TypeName<'a>,
+    /// The attributes of this type
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
@@ -88,12 +110,16 @@ pub struct Type<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Schema<'a> {
+    /// A [`TypeName`], e.g. `"int"`
     #[serde(borrow)]
     TypeName(TypeName<'a>),
+    /// A union of schemas, e.g. `["null", "int"]`
     #[serde(borrow)]
     Union(Vec<Schema<'a>>),
+    /// A [`ComplexType`], e.g. `{"type": "array", "items": "int"}`
     #[serde(borrow)]
     Complex(ComplexType<'a>),
+    /// A [`Type`] with additional attributes, e.g. `{"type": "int", "logicalType": "date"}`
     #[serde(borrow)]
     Type(Type<'a>),
 }
@@ -104,14 +130,19 @@ pub enum Schema<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ComplexType<'a> {
+    /// A [`Record`]
     #[serde(borrow)]
     Record(Record<'a>),
+    /// An [`Enum`]
     #[serde(borrow)]
     Enum(Enum<'a>),
+    /// An [`Array`]
     #[serde(borrow)]
     Array(Array<'a>),
+    /// A [`Map`]
     #[serde(borrow)]
     Map(Map<'a>),
+    /// A [`Fixed`]
     #[serde(borrow)]
     Fixed(Fixed<'a>),
 }
@@ -121,16 +152,22 @@ pub enum ComplexType<'a> {
 /// <https://avro.apache.org/docs/1.11.1/specification/#schema-record>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Record<'a> {
+    /// The name of this record
     #[serde(borrow)]
     pub name: &'a str,
-    #[serde(borrow, default)]
+    /// The namespace of this record, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub namespace: Option<&'a str>,
-    #[serde(borrow, default)]
+    /// The documentation of this record, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub doc: Option<&'a str>,
-    #[serde(borrow, default)]
+    /// Alternate names for this record
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub aliases: Vec<&'a str>,
+    /// The fields of this record
     #[serde(borrow)]
     pub fields: Vec<Field<'a>>,
+    /// The attributes of this record
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
@@ -138,14 +175,32 @@ pub struct Record<'a> {
 /// A field within a [`Record`]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Field<'a> {
+    /// The name of this field
     #[serde(borrow)]
     pub name: &'a str,
-    #[serde(borrow, default)]
+    /// The documentation of this field, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub doc: Option<&'a str>,
+    /// The type of this field
     #[serde(borrow)]
     pub r#type: Schema<'a>,
-    #[serde(borrow, default)]
-    pub default: Option<&'a str>,
+    /// The default value of this field, if any
+    ///
+    /// Note: this is `Some(Value::Null)` for a field with a default of `null`
+    #[serde(
+        default,
+        deserialize_with = "deserialize_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default: Option<Value>,
+    /// The attributes of this field
+    #[serde(flatten)]
+    pub attributes: Attributes<'a>,
+}
+
+/// Deserializes a present default value, including `null`, as `Some`
+fn deserialize_default<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(d).map(Some)
 }
 
 /// An enumeration
@@ -153,18 +208,25 @@ pub struct Field<'a> {
 /// <https://avro.apache.org/docs/1.11.1/specification/#enums>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Enum<'a> {
+    /// The name of this enum
     #[serde(borrow)]
     pub name: &'a str,
-    #[serde(borrow, default)]
+    /// The namespace of this enum, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub namespace: Option<&'a str>,
-    #[serde(borrow, default)]
+    /// The documentation of this enum, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub doc: Option<&'a str>,
-    #[serde(borrow, default)]
+    /// Alternate names for this enum
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub aliases: Vec<&'a str>,
+    /// The symbols of this enum
     #[serde(borrow)]
     pub symbols: Vec<&'a str>,
-    #[serde(borrow, default)]
+    /// The default symbol of this enum, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub default: Option<&'a str>,
+    /// The attributes of this enum
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
@@ -174,8 +236,10 @@ pub struct Enum<'a> {
 /// <https://avro.apache.org/docs/1.11.1/specification/#arrays>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Array<'a> {
+    /// The type of the items in this array
     #[serde(borrow)]
     pub items: Box<Schema<'a>>,
+    /// The attributes of this array
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
@@ -185,8 +249,10 @@ pub struct Array<'a> {
 /// <https://avro.apache.org/docs/1.11.1/specification/#maps>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Map<'a> {
+    /// The type of the values in this map
     #[serde(borrow)]
     pub values: Box<Schema<'a>>,
+    /// The attributes of this map
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
@@ -196,17 +262,739 @@ pub struct Map<'a> {
 /// <https://avro.apache.org/docs/1.11.1/specification/#fixed>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Fixed<'a> {
+    /// The name of this fixed
     #[serde(borrow)]
     pub name: &'a str,
-    #[serde(borrow, default)]
+    /// The namespace of this fixed, if any
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub namespace: Option<&'a str>,
-    #[serde(borrow, default)]
+    /// Alternate names for this fixed
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub aliases: Vec<&'a str>,
+    /// The number of bytes per value
     pub size: usize,
+    /// The attributes of this fixed
     #[serde(flatten)]
     pub attributes: Attributes<'a>,
 }
 
+/// A change between two Avro schemas, as returned by [`diff`]
+///
+/// Each change identifies the affected field by its `path`, the `.` separated names of the
+/// field and its enclosing records. Array items are identified by a `[]` suffix, and map
+/// values by a `{}` suffix, for example `tags[]` or `attributes{}.value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChange {
+    /// A field was added, with the given type
+    FieldAdded {
+        /// The path of the field
+        path: String,
+        /// The JSON schema of the added field
+        r#type: Value,
+    },
+    /// A field was removed, which had the given type
+    FieldRemoved {
+        /// The path of the field
+        path: String,
+        /// The JSON schema of the removed field
+        r#type: Value,
+    },
+    /// The type of a field or nested type was changed
+    TypeChanged {
+        /// The path of the field
+        path: String,
+        /// The JSON schema of the old type
+        from: Value,
+        /// The JSON schema of the new type
+        to: Value,
+    },
+    /// A field or nested type was changed to or from a union with null
+    NullabilityChanged {
+        /// The path of the field
+        path: String,
+        /// If the old type was nullable
+        from: bool,
+        /// If the new type is nullable
+        to: bool,
+    },
+    /// The default value of a field was changed
+    DefaultChanged {
+        /// The path of the field
+        path: String,
+        /// The old default value, if any
+        from: Option<Value>,
+        /// The new default value, if any
+        to: Option<Value>,
+    },
+}
+
+impl SchemaChange {
+    /// Returns the path of the field affected by this change
+    pub fn path(&self) -> &str {
+        match self {
+            Self::FieldAdded { path, .. }
+            | Self::FieldRemoved { path, .. }
+            | Self::TypeChanged { path, .. }
+            | Self::NullabilityChanged { path, .. }
+            | Self::DefaultChanged { path, .. } => path,
+        }
+    }
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nullable = |n: &bool| match n {
+            true => "nullable",
+            false => "non-nullable",
+        };
+        match self {
+            Self::FieldAdded { path, r#type } => write!(f, "added field {path} of type {type}"),
+            Self::FieldRemoved { path, r#type } => {
+                write!(f, "removed field {path} of type {type}")
+            }
+            Self::TypeChanged { path, from, to } => {
+                write!(f, "changed type of {path} from {from} to {to}")
+            }
+            Self::NullabilityChanged { path, from, to } => write!(
+                f,
+                "changed {path} from {} to {}",
+                nullable(from),
+                nullable(to)
+            ),
+            Self::DefaultChanged { path, from, to } => match (from, to) {
+                (None, Some(to)) => write!(f, "added default {to} to {path}"),
+                (Some(from), None) => write!(f, "removed default {from} from {path}"),
+                (Some(from), Some(to)) => {
+                    write!(f, "changed default of {path} from {from} to {to}")
+                }
+                (None, None) => write!(f, "unchanged default of {path}"),
+            },
+        }
+    }
+}
+
+/// Returns the [`SchemaChange`] required to transform the schema `a` into `b`
+///
+/// Records are compared field by field, matching fields by name, with nested
+/// records, arrays and maps compared recursively. Any other types are compared
+/// by their JSON representation.
+///
+/// ```
+/// # use arrow_avro::schema::{diff, Schema};
+/// let a: Schema = serde_json::from_str(r#"{
+///     "type": "record",
+///     "name": "r",
+///     "fields": [{"name": "a", "type": "int"}, {"name": "b", "type": "string"}]
+/// }"#).unwrap();
+/// let b: Schema = serde_json::from_str(r#"{
+///     "type": "record",
+///     "name": "r",
+///     "fields": [{"name": "a", "type": "long"}, {"name": "c", "type": "string"}]
+/// }"#).unwrap();
+///
+/// let changes: Vec<_> = diff(&a, &b).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(changes, [
+///     "changed type of a from \"int\" to \"long\"",
+///     "removed field b of type \"string\"",
+///     "added field c of type \"string\"",
+/// ]);
+/// ```
+pub fn diff(a: &Schema<'_>, b: &Schema<'_>) -> Vec<SchemaChange> {
+    let mut out = vec![];
+    diff_schema("", a, b, &mut out);
+    out
+}
+
+fn diff_schema(path: &str, a: &Schema<'_>, b: &Schema<'_>, out: &mut Vec<SchemaChange>) {
+    let (a_nullable, a) = split_nullable(a);
+    let (b_nullable, b) = split_nullable(b);
+    if a_nullable != b_nullable {
+        out.push(SchemaChange::NullabilityChanged {
+            path: path.to_string(),
+            from: a_nullable,
+            to: b_nullable,
+        });
+    }
+
+    match (a, b) {
+        (Schema::Complex(ComplexType::Record(a)), Schema::Complex(ComplexType::Record(b))) => {
+            diff_fields(path, &a.fields, &b.fields, out)
+        }
+        (Schema::Complex(ComplexType::Array(a)), Schema::Complex(ComplexType::Array(b))) => {
+            diff_schema(&format!("{path}[]"), &a.items, &b.items, out)
+        }
+        (Schema::Complex(ComplexType::Map(a)), Schema::Complex(ComplexType::Map(b))) => {
+            diff_schema(&format!("{path}{{}}"), &a.values, &b.values, out)
+        }
+        (a, b) => {
+            let (from, to) = (schema_json(a), schema_json(b));
+            if from != to {
+                out.push(SchemaChange::TypeChanged {
+                    path: path.to_string(),
+                    from,
+                    to,
+                })
+            }
+        }
+    }
+}
+
+fn diff_fields(path: &str, a: &[Field<'_>], b: &[Field<'_>], out: &mut Vec<SchemaChange>) {
+    let field_path = |name: &str| match path {
+        "" => name.to_string(),
+        _ => format!("{path}.{name}"),
+    };
+
+    for a_field in a {
+        let path = field_path(a_field.name);
+        match b.iter().find(|b| b.name == a_field.name) {
+            Some(b_field) => {
+                if a_field.default != b_field.default {
+                    out.push(SchemaChange::DefaultChanged {
+                        path: path.clone(),
+                        from: a_field.default.clone(),
+                        to: b_field.default.clone(),
+                    });
+                }
+                diff_schema(&path, &a_field.r#type, &b_field.r#type, out)
+            }
+            None => out.push(SchemaChange::FieldRemoved {
+                path,
+                r#type: schema_json(&a_field.r#type),
+            }),
+        }
+    }
+
+    for b_field in b {
+        if !a.iter().any(|a| a.name == b_field.name) {
+            out.push(SchemaChange::FieldAdded {
+                path: field_path(b_field.name),
+                r#type: schema_json(&b_field.r#type),
+            })
+        }
+    }
+}
+
+/// Splits a two-variant union containing null into its nullability and non-null variant
+fn split_nullable<'s, 'a>(schema: &'s Schema<'a>) -> (bool, &'s Schema<'a>) {
+    let null = Schema::TypeName(TypeName::Primitive(PrimitiveType::Null));
+    match schema {
+        Schema::Union(u) if u.len() == 2 && u[0] == null => (true, &u[1]),
+        Schema::Union(u) if u.len() == 2 && u[1] == null => (true, &u[0]),
+        s => (false, s),
+    }
+}
+
+/// Returns the JSON representation of `schema`, with types that have no
+/// attributes represented by their type name alone
+fn schema_json(schema: &Schema<'_>) -> Value {
+    match schema {
+        Schema::Type(t) if t.attributes == Attributes::default() => {
+            serde_json::to_value(&t.r#type).unwrap_or_default()
+        }
+        _ => serde_json::to_value(schema).unwrap_or_default(),
+    }
+}
+
+/// Limits on the complexity of an Avro [`Schema`], see [`validate_schema_limits`]
+///
+/// The defaults are generous enough for any reasonable schema, while bounding the
+/// resources required to decode data written with an untrusted schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaLimits {
+    max_depth: usize,
+    max_fields: usize,
+    max_enum_symbols: usize,
+    max_fixed_size: usize,
+    max_union_variants: usize,
+}
+
+impl Default for SchemaLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_fields: 10_000,
+            max_enum_symbols: 10_000,
+            max_fixed_size: 1024 * 1024,
+            max_union_variants: 256,
+        }
+    }
+}
+
+impl SchemaLimits {
+    /// Set the maximum depth of nested records, arrays, maps and unions, defaults to 64
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the maximum total number of record fields, defaults to 10,000
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Set the maximum number of symbols of an enum, defaults to 10,000
+    pub fn with_max_enum_symbols(mut self, max_enum_symbols: usize) -> Self {
+        self.max_enum_symbols = max_enum_symbols;
+        self
+    }
+
+    /// Set the maximum size in bytes of a fixed, defaults to 1 MiB
+    pub fn with_max_fixed_size(mut self, max_fixed_size: usize) -> Self {
+        self.max_fixed_size = max_fixed_size;
+        self
+    }
+
+    /// Set the maximum number of variants of a union, defaults to 256
+    pub fn with_max_union_variants(mut self, max_union_variants: usize) -> Self {
+        self.max_union_variants = max_union_variants;
+        self
+    }
+}
+
+/// Validates that `schema` is within `limits`, returning
+/// [`ArrowError::ResourceExhausted`] if not
+///
+/// This allows services accepting user provided schemas to reject overly complex
+/// schemas before attempting to decode any data. The same checks are performed by
+/// [`ReaderBuilder`](crate::reader::ReaderBuilder) against its configured limits.
+///
+/// ```
+/// # use arrow_avro::schema::{validate_schema_limits, Schema, SchemaLimits};
+/// let schema: Schema = serde_json::from_str(
+///     r#"{"type": "fixed", "name": "hash", "size": 1048576000}"#,
+/// )
+/// .unwrap();
+/// let err = validate_schema_limits(&schema, &SchemaLimits::default()).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Resource exhausted: Avro fixed hash of size 1048576000 exceeds the limit of 1048576"
+/// );
+/// ```
+pub fn validate_schema_limits(
+    schema: &Schema<'_>,
+    limits: &SchemaLimits,
+) -> Result<(), ArrowError> {
+    let mut fields = 0;
+    check_limits(schema, limits, 1, &mut fields)
+}
+
+fn check_limits(
+    schema: &Schema<'_>,
+    limits: &SchemaLimits,
+    depth: usize,
+    fields: &mut usize,
+) -> Result<(), ArrowError> {
+    let exhausted = |msg: String| Err(ArrowError::ResourceExhausted(msg));
+    let nested = matches!(
+        schema,
+        Schema::Union(_)
+            | Schema::Complex(ComplexType::Record(_) | ComplexType::Array(_) | ComplexType::Map(_))
+    );
+    if nested && depth > limits.max_depth {
+        return exhausted(format!(
+            "Avro schema nesting depth exceeds the limit of {}",
+            limits.max_depth
+        ));
+    }
+    match schema {
+        Schema::TypeName(_) | Schema::Type(_) => Ok(()),
+        Schema::Union(variants) => {
+            if variants.len() > limits.max_union_variants {
+                return exhausted(format!(
+                    "Avro union of {} variants exceeds the limit of {}",
+                    variants.len(),
+                    limits.max_union_variants
+                ));
+            }
+            variants
+                .iter()
+                .try_for_each(|v| check_limits(v, limits, depth + 1, fields))
+        }
+        Schema::Complex(ComplexType::Record(r)) => {
+            *fields += r.fields.len();
+            if *fields > limits.max_fields {
+                return exhausted(format!(
+                    "Avro schema fields exceed the limit of {}",
+                    limits.max_fields
+                ));
+            }
+            r.fields
+                .iter()
+                .try_for_each(|f| check_limits(&f.r#type, limits, depth + 1, fields))
+        }
+        Schema::Complex(ComplexType::Enum(e)) => match e.symbols.len() > limits.max_enum_symbols {
+            true => exhausted(format!(
+                "Avro enum {} of {} symbols exceeds the limit of {}",
+                e.name,
+                e.symbols.len(),
+                limits.max_enum_symbols
+            )),
+            false => Ok(()),
+        },
+        Schema::Complex(ComplexType::Fixed(f)) => match f.size > limits.max_fixed_size {
+            true => exhausted(format!(
+                "Avro fixed {} of size {} exceeds the limit of {}",
+                f.name, f.size, limits.max_fixed_size
+            )),
+            false => Ok(()),
+        },
+        Schema::Complex(ComplexType::Array(a)) => check_limits(&a.items, limits, depth + 1, fields),
+        Schema::Complex(ComplexType::Map(m)) => check_limits(&m.values, limits, depth + 1, fields),
+    }
+}
+
+/// The metadata key used for storing the IPC encoded arrow schema of the data in
+/// an Avro object container file, see [`encode_arrow_schema`]
+#[cfg(feature = "arrow_schema")]
+pub const ARROW_SCHEMA_METADATA_KEY: &str = "arrow.schema";
+
+/// Returns the IPC encoding of `schema`, to be stored in the header metadata of an
+/// Avro object container file under [`ARROW_SCHEMA_METADATA_KEY`]
+///
+/// This allows arrow types that cannot be expressed by Avro, such as
+/// [`DataType::Timestamp`] with [`TimeUnit::Nanosecond`],
+/// or [`DataType::Dictionary`], to be restored exactly when reading the file, see
+/// [`ReaderBuilder::with_arrow_schema`](crate::reader::ReaderBuilder::with_arrow_schema)
+#[cfg(feature = "arrow_schema")]
+pub fn encode_arrow_schema(schema: &ArrowSchema) -> Vec<u8> {
+    use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+
+    let options = IpcWriteOptions::default();
+    let mut tracker = DictionaryTracker::new(false);
+    let generator = IpcDataGenerator::default();
+    let encoded = generator.schema_to_bytes_with_dictionary_tracker(schema, &mut tracker, &options);
+
+    // Prefix the message with the continuation marker and its length, as parquet does
+    let len = encoded.ipc_message.len() as u32;
+    let mut out = Vec::with_capacity(encoded.ipc_message.len() + 8);
+    out.extend_from_slice(&[255, 255, 255, 255]);
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&encoded.ipc_message);
+    out
+}
+
+/// The default name of the top-level record generated by [`SchemaGenerator`]
+pub const DEFAULT_RECORD_NAME: &str = "topLevelRecord";
+
+/// The magic byte of the Confluent wire format
+const CONFLUENT_MAGIC: u8 = 0;
+
+/// The magic bytes of the Avro single object encoding
+const SINGLE_OBJECT_MAGIC: [u8; 2] = [0xC3, 0x01];
+
+/// Identifies the Avro schema an individually encoded Avro message was written with
+///
+/// Each message starts with a header identifying its schema, followed by the Avro
+/// encoded record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AvroSchemaId {
+    /// The id of the schema in a Confluent schema registry, framed by a zero byte
+    /// and the 4-byte big-endian id, see the
+    /// [Confluent wire format](https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format)
+    Confluent(u32),
+    /// The CRC-64-AVRO fingerprint of the schema, as used by the Avro
+    /// [single object encoding](https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding),
+    /// framed by the bytes `0xC3 0x01` and the 8-byte little-endian fingerprint
+    SingleObject(u64),
+}
+
+impl AvroSchemaId {
+    /// Parses the header of the Avro `message`, returning the [`AvroSchemaId`] and
+    /// the remaining Avro encoded record
+    pub fn parse(message: &[u8]) -> Result<(Self, &[u8]), ArrowError> {
+        match message {
+            [CONFLUENT_MAGIC, a, b, c, d, record @ ..] => Ok((
+                Self::Confluent(u32::from_be_bytes([*a, *b, *c, *d])),
+                record,
+            )),
+            [a, b, fingerprint @ ..]
+                if [*a, *b] == SINGLE_OBJECT_MAGIC && fingerprint.len() >= 8 =>
+            {
+                let (fingerprint, record) = fingerprint.split_at(8);
+                let fingerprint = u64::from_le_bytes(fingerprint.try_into().unwrap());
+                Ok((Self::SingleObject(fingerprint), record))
+            }
+            _ => Err(ArrowError::ParseError(
+                "Avro message does not start with a Confluent or single object encoding header"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Returns the Avro message for the Avro encoded `record`, starting with
+    /// the header for this [`AvroSchemaId`]
+    pub fn encode(&self, record: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(record.len() + 10);
+        match self {
+            Self::Confluent(id) => {
+                out.push(CONFLUENT_MAGIC);
+                out.extend_from_slice(&id.to_be_bytes());
+            }
+            Self::SingleObject(fingerprint) => {
+                out.extend_from_slice(&SINGLE_OBJECT_MAGIC);
+                out.extend_from_slice(&fingerprint.to_le_bytes());
+            }
+        }
+        out.extend_from_slice(record);
+        out
+    }
+}
+
+/// Generates an Avro schema from an arrow [`ArrowSchema`]
+///
+/// Avro requires that every record and fixed type has a name, and that each fully
+/// qualified name is defined only once. Named types are therefore assigned the name of
+/// the field they are defined by, within a namespace derived from the fully qualified
+/// name of the enclosing record. For example, a struct field `b` nested within a struct
+/// field `a` of the top-level record `topLevelRecord` is given the name `b` within the
+/// namespace `topLevelRecord.a`.
+///
+/// Structurally identical named types are only defined once, with subsequent occurrences
+/// referring to the first definition by its fully qualified name. Field names that are
+/// not valid Avro names have any invalid characters replaced with `_`, and should this
+/// collide with the name of another type in the same namespace, such as for fields
+/// `a b` and `a_b`, a numeric suffix is appended, e.g. `a_b_1`.
+#[derive(Debug, Clone)]
+pub struct SchemaGenerator {
+    name: String,
+    namespace: Option<String>,
+}
+
+impl Default for SchemaGenerator {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_RECORD_NAME.to_string(),
+            namespace: None,
+        }
+    }
+}
+
+impl SchemaGenerator {
+    /// Create a new [`SchemaGenerator`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of the top-level record, defaults to [`DEFAULT_RECORD_NAME`]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the namespace of the top-level record, and by extension all nested named types
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Generate the Avro schema JSON for `schema`
+    pub fn generate(&self, schema: &ArrowSchema) -> Result<Value, ArrowError> {
+        let mut names = NamedTypes::default();
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| names.field(f, &full_name(self.namespace.as_deref(), &self.name)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut record = JsonMap::new();
+        record.insert("type".into(), "record".into());
+        record.insert("name".into(), self.name.clone().into());
+        if let Some(namespace) = &self.namespace {
+            record.insert("namespace".into(), namespace.clone().into());
+        }
+        record.insert("fields".into(), fields.into());
+        Ok(record.into())
+    }
+}
+
+/// Tracks the named types defined by [`SchemaGenerator`]
+#[derive(Debug, Default)]
+struct NamedTypes {
+    /// The fully qualified name of the first definition of a given named type
+    defined: HashMap<DataType, String>,
+    /// The fully qualified names of all defined types
+    names: HashSet<String>,
+}
+
+impl NamedTypes {
+    /// Returns the record field for `field` within the record named `parent`
+    fn field(&mut self, field: &ArrowField, parent: &str) -> Result<Value, ArrowError> {
+        let schema = self.nullable(field.name(), field, parent)?;
+        Ok(json!({"name": field.name(), "type": schema}))
+    }
+
+    /// Returns the schema for `field`, wrapped in a union with null if it is nullable
+    fn nullable(
+        &mut self,
+        name: &str,
+        field: &ArrowField,
+        parent: &str,
+    ) -> Result<Value, ArrowError> {
+        let schema = self.data_type(name, field, parent)?;
+        Ok(match field.is_nullable() && !field.data_type().is_null() {
+            true => json!(["null", schema]),
+            false => schema,
+        })
+    }
+
+    /// Returns the schema for the type of `field` within the record named `parent`
+    ///
+    /// `name` is used to name any named types, and is the name of the record field
+    /// that `field` belongs to
+    fn data_type(
+        &mut self,
+        name: &str,
+        field: &ArrowField,
+        parent: &str,
+    ) -> Result<Value, ArrowError> {
+        let extension = field.metadata().get(EXTENSION_TYPE_NAME_KEY);
+        let data_type = field.data_type();
+        Ok(match data_type {
+            DataType::Null => "null".into(),
+            DataType::Boolean => "boolean".into(),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 => "int".into(),
+            DataType::UInt8 | DataType::UInt16 => "int".into(),
+            DataType::Int64 | DataType::UInt32 => "long".into(),
+            DataType::Float32 => "float".into(),
+            DataType::Float64 => "double".into(),
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView => "bytes".into(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                match extension.map(|x| x.as_str()) {
+                    Some(JSON_EXTENSION_NAME) => json!({"type": "string", "logicalType": "json"}),
+                    _ => "string".into(),
+                }
+            }
+            DataType::FixedSizeBinary(16)
+                if extension.map(|x| x.as_str()) == Some(UUID_EXTENSION_NAME) =>
+            {
+                json!({"type": "string", "logicalType": "uuid"})
+            }
+            DataType::FixedSizeBinary(size) => self.named(data_type, name, parent, |_, _| {
+                Ok(json!({"type": "fixed", "size": size}))
+            })?,
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                self.named(data_type, name, parent, |_, _| {
+                    Ok(json!({"type": "fixed", "size": 12, "logicalType": "duration"}))
+                })?
+            }
+            DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+            DataType::Time32(TimeUnit::Millisecond) => {
+                json!({"type": "int", "logicalType": "time-millis"})
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                json!({"type": "long", "logicalType": "time-micros"})
+            }
+            DataType::Timestamp(unit @ (TimeUnit::Millisecond | TimeUnit::Microsecond), tz) => {
+                let unit = match unit {
+                    TimeUnit::Millisecond => "millis",
+                    _ => "micros",
+                };
+                let logical_type = match tz {
+                    Some(_) => format!("timestamp-{unit}"),
+                    None => format!("local-timestamp-{unit}"),
+                };
+                json!({"type": "long", "logicalType": logical_type})
+            }
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                json!({"type": "bytes", "logicalType": "decimal", "precision": precision, "scale": scale})
+            }
+            DataType::List(item) | DataType::LargeList(item) => {
+                let items = self.nullable(name, item, parent)?;
+                json!({"type": "array", "items": items})
+            }
+            DataType::Map(entries, _) => {
+                let DataType::Struct(kv) = entries.data_type() else {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Expected struct for map entries, got {}",
+                        entries.data_type()
+                    )));
+                };
+                if kv.len() != 2 || !matches!(kv[0].data_type(), DataType::Utf8) {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Avro maps must have string keys, got {data_type}"
+                    )));
+                }
+                let values = self.nullable(name, &kv[1], parent)?;
+                json!({"type": "map", "values": values})
+            }
+            DataType::Struct(fields) => self.named(data_type, name, parent, |s, name| {
+                let fields = fields
+                    .iter()
+                    .map(|f| s.field(f, name))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(json!({"type": "record", "fields": fields}))
+            })?,
+            d => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Conversion of {d} to Avro not currently supported"
+                )))
+            }
+        })
+    }
+
+    /// Returns the schema for a named type, or a reference to it if a structurally
+    /// identical type has already been defined
+    ///
+    /// `f` is called with the fully qualified name of the new type, and should
+    /// return its definition without a name or namespace
+    fn named(
+        &mut self,
+        data_type: &DataType,
+        name: &str,
+        parent: &str,
+        f: impl FnOnce(&mut Self, &str) -> Result<Value, ArrowError>,
+    ) -> Result<Value, ArrowError> {
+        if let Some(existing) = self.defined.get(data_type) {
+            return Ok(existing.clone().into());
+        }
+
+        let base = sanitize_name(name);
+        let mut name = base.clone();
+        let mut full_name = full_name(Some(parent), &name);
+        let mut suffix = 0;
+        while self.names.contains(&full_name) {
+            suffix += 1;
+            name = format!("{base}_{suffix}");
+            full_name = self::full_name(Some(parent), &name);
+        }
+        self.names.insert(full_name.clone());
+        let mut schema = f(self, &full_name)?;
+        if let Value::Object(o) = &mut schema {
+            o.insert("name".into(), name.into());
+            o.insert("namespace".into(), parent.into());
+        }
+        self.defined.insert(data_type.clone(), full_name);
+        Ok(schema)
+    }
+}
+
+/// Returns the fully qualified name of `name` within `namespace`
+fn full_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) if !namespace.is_empty() => format!("{namespace}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+/// Converts `name` into a valid Avro name
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#names>
+fn sanitize_name(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+    if !out.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        out.insert(0, '_');
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +1002,69 @@ mod tests {
     use arrow_schema::{DataType, Fields, TimeUnit};
     use serde_json::json;
 
+    #[test]
+    fn test_schema_id() {
+        for id in [
+            AvroSchemaId::Confluent(42),
+            AvroSchemaId::SingleObject(u64::MAX - 3),
+        ] {
+            let message = id.encode(b"record");
+            assert_eq!(
+                AvroSchemaId::parse(&message).unwrap(),
+                (id, b"record".as_slice())
+            );
+        }
+        assert_eq!(AvroSchemaId::Confluent(1).encode(&[]), [0, 0, 0, 0, 1]);
+
+        let err = AvroSchemaId::parse(&[0xC3, 0x01, 0]).unwrap_err();
+        assert!(err.to_string().contains("single object encoding header"));
+    }
+
+    #[test]
+    fn test_validate_schema_limits() {
+        let check = |json: &str, limits: SchemaLimits| {
+            let schema: Schema = serde_json::from_str(json).unwrap();
+            validate_schema_limits(&schema, &limits).map_err(|e| e.to_string())
+        };
+        let record = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "a", "type": ["null", {"type": "array", "items": "int"}]},
+                {"name": "b", "type": {"type": "enum", "name": "e", "symbols": ["X", "Y", "Z"]}},
+                {"name": "c", "type": {"type": "map", "values": {"type": "fixed", "name": "f", "size": 16}}}
+            ]
+        }"#;
+        check(record, SchemaLimits::default()).unwrap();
+
+        let cases = [
+            (
+                SchemaLimits::default().with_max_depth(2),
+                "Resource exhausted: Avro schema nesting depth exceeds the limit of 2",
+            ),
+            (
+                SchemaLimits::default().with_max_fields(2),
+                "Resource exhausted: Avro schema fields exceed the limit of 2",
+            ),
+            (
+                SchemaLimits::default().with_max_enum_symbols(2),
+                "Resource exhausted: Avro enum e of 3 symbols exceeds the limit of 2",
+            ),
+            (
+                SchemaLimits::default().with_max_fixed_size(8),
+                "Resource exhausted: Avro fixed f of size 16 exceeds the limit of 8",
+            ),
+            (
+                SchemaLimits::default().with_max_union_variants(1),
+                "Resource exhausted: Avro union of 2 variants exceeds the limit of 1",
+            ),
+        ];
+        for (limits, expected) in cases {
+            assert_eq!(check(record, limits).unwrap_err(), expected);
+        }
+        check(record, SchemaLimits::default().with_max_depth(3)).unwrap();
+    }
+
     #[test]
     fn test_deserialize() {
         let t: Schema = serde_json::from_str("\"string\"").unwrap();
@@ -317,6 +1168,7 @@ mod tests {
                         Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                     ]),
                     default: None,
+                    attributes: Default::default(),
                 },],
                 attributes: Default::default(),
             }))
@@ -348,6 +1200,7 @@ mod tests {
                         doc: None,
                         r#type: Schema::TypeName(TypeName::Primitive(PrimitiveType::Long)),
                         default: None,
+                        attributes: Default::default(),
                     },
                     Field {
                         name: "next",
@@ -357,6 +1210,7 @@ mod tests {
                             Schema::TypeName(TypeName::Ref("LongList")),
                         ]),
                         default: None,
+                        attributes: Default::default(),
                     }
                 ],
                 attributes: Attributes::default(),
@@ -410,6 +1264,7 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                         ]),
                         default: None,
+                        attributes: Default::default(),
                     },
                     Field {
                         name: "timestamp_col",
@@ -419,6 +1274,7 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                         ]),
                         default: None,
+                        attributes: Default::default(),
                     }
                 ],
                 attributes: Default::default(),
@@ -474,6 +1330,7 @@ mod tests {
                             attributes: Default::default(),
                         })),
                         default: None,
+                        attributes: Default::default(),
                     },
                     Field {
                         name: "clientProtocol",
@@ -483,12 +1340,14 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::String)),
                         ]),
                         default: None,
+                        attributes: Default::default(),
                     },
                     Field {
                         name: "serverHash",
                         doc: None,
                         r#type: Schema::TypeName(TypeName::Ref("MD5")),
                         default: None,
+                        attributes: Default::default(),
                     },
                     Field {
                         name: "meta",
@@ -503,10 +1362,254 @@ mod tests {
                             })),
                         ]),
                         default: None,
+                        attributes: Default::default(),
                     }
                 ],
                 attributes: Default::default(),
             }))
         );
     }
+
+    #[test]
+    fn test_schema_generator() {
+        let point = DataType::Struct(Fields::from(vec![
+            arrow_schema::Field::new("x", DataType::Float64, false),
+            arrow_schema::Field::new("y", DataType::Float64, false),
+        ]));
+        let schema = ArrowSchema::new(vec![
+            arrow_schema::Field::new("id", DataType::Int64, false),
+            arrow_schema::Field::new("start", point.clone(), false),
+            arrow_schema::Field::new("end", point.clone(), true),
+            arrow_schema::Field::new(
+                "meta",
+                DataType::Struct(Fields::from(vec![
+                    arrow_schema::Field::new("hash", DataType::FixedSizeBinary(16), false),
+                    arrow_schema::Field::new("hash2", DataType::FixedSizeBinary(16), false),
+                    arrow_schema::Field::new("origin", point, false),
+                ])),
+                false,
+            ),
+            arrow_schema::Field::new("uuid", DataType::FixedSizeBinary(16), false).with_metadata(
+                HashMap::from([(
+                    EXTENSION_TYPE_NAME_KEY.to_string(),
+                    UUID_EXTENSION_NAME.to_string(),
+                )]),
+            ),
+            arrow_schema::Field::new_list(
+                "tags",
+                arrow_schema::Field::new_list_field(DataType::Utf8, true),
+                true,
+            ),
+        ]);
+
+        let generated = SchemaGenerator::new()
+            .with_namespace("com.example")
+            .generate(&schema)
+            .unwrap();
+
+        assert_eq!(
+            generated,
+            json!({
+                "type": "record",
+                "name": "topLevelRecord",
+                "namespace": "com.example",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "start", "type": {
+                        "type": "record",
+                        "name": "start",
+                        "namespace": "com.example.topLevelRecord",
+                        "fields": [
+                            {"name": "x", "type": "double"},
+                            {"name": "y", "type": "double"}
+                        ]
+                    }},
+                    {"name": "end", "type": ["null", "com.example.topLevelRecord.start"]},
+                    {"name": "meta", "type": {
+                        "type": "record",
+                        "name": "meta",
+                        "namespace": "com.example.topLevelRecord",
+                        "fields": [
+                            {"name": "hash", "type": {
+                                "type": "fixed",
+                                "name": "hash",
+                                "namespace": "com.example.topLevelRecord.meta",
+                                "size": 16
+                            }},
+                            {"name": "hash2", "type": "com.example.topLevelRecord.meta.hash"},
+                            {"name": "origin", "type": "com.example.topLevelRecord.start"}
+                        ]
+                    }},
+                    {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+                    {"name": "tags", "type": ["null", {
+                        "type": "array",
+                        "items": ["null", "string"]
+                    }]}
+                ]
+            })
+        );
+
+        // The generated schema can be parsed, and resolves the named references
+        let s = generated.to_string();
+        let parsed: Schema = serde_json::from_str(&s).unwrap();
+        let field = AvroField::try_from(&parsed).unwrap();
+        let DataType::Struct(fields) = field.field().data_type().clone() else {
+            unreachable!()
+        };
+        assert_eq!(fields[1].data_type(), fields[2].data_type());
+        assert!(fields[2].is_nullable());
+    }
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("valid_name1"), "valid_name1");
+        assert_eq!(sanitize_name("with space"), "with_space");
+        assert_eq!(sanitize_name("1st"), "_1st");
+        assert_eq!(sanitize_name("a.b-c"), "a_b_c");
+    }
+
+    #[test]
+    fn test_generate_colliding_names() {
+        let struct_of =
+            |t: DataType| DataType::Struct(Fields::from(vec![ArrowField::new("x", t, false)]));
+        let schema = ArrowSchema::new(vec![
+            ArrowField::new("a b", struct_of(DataType::Int32), false),
+            ArrowField::new("a_b", struct_of(DataType::Utf8), false),
+            ArrowField::new("a-b", DataType::FixedSizeBinary(3), false),
+        ]);
+        let generated = SchemaGenerator::new().generate(&schema).unwrap();
+        let names: Vec<_> = generated["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["type"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["a_b", "a_b_1", "a_b_2"]);
+
+        let s = generated.to_string();
+        let parsed: Schema = serde_json::from_str(&s).unwrap();
+        let field = AvroField::try_from(&parsed).unwrap();
+        let DataType::Struct(fields) = field.field().data_type().clone() else {
+            unreachable!()
+        };
+        assert_eq!(fields[0].data_type(), &struct_of(DataType::Int32));
+        assert_eq!(fields[1].data_type(), &struct_of(DataType::Utf8));
+    }
+
+    #[test]
+    fn test_diff() {
+        let a: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "r",
+                "fields": [
+                    {"name": "id", "type": "int"},
+                    {"name": "name", "type": "string", "default": "foo"},
+                    {"name": "removed", "type": "boolean"},
+                    {"name": "nested", "type": {
+                        "type": "record",
+                        "name": "nested",
+                        "fields": [
+                            {"name": "a", "type": {"type": "long"}},
+                            {"name": "b", "type": ["null", "double"], "default": null}
+                        ]
+                    }},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "attributes", "type": {"type": "map", "values": "int"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let b: Schema = serde_json::from_str(
+            r#"{
+                "type": "record",
+                "name": "r",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "name", "type": ["null", "string"], "default": null},
+                    {"name": "nested", "type": ["null", {
+                        "type": "record",
+                        "name": "nested",
+                        "fields": [
+                            {"name": "a", "type": "long"},
+                            {"name": "b", "type": ["null", "double"]},
+                            {"name": "c", "type": {"type": "int", "logicalType": "date"}}
+                        ]
+                    }]},
+                    {"name": "tags", "type": {"type": "array", "items": ["null", "string"]}},
+                    {"name": "attributes", "type": {"type": "map", "values": "long"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(diff(&a, &a), vec![]);
+
+        let changes = diff(&a, &b);
+        assert_eq!(
+            changes,
+            vec![
+                SchemaChange::TypeChanged {
+                    path: "id".to_string(),
+                    from: json!("int"),
+                    to: json!("long"),
+                },
+                SchemaChange::DefaultChanged {
+                    path: "name".to_string(),
+                    from: Some(json!("foo")),
+                    to: Some(Value::Null),
+                },
+                SchemaChange::NullabilityChanged {
+                    path: "name".to_string(),
+                    from: false,
+                    to: true,
+                },
+                SchemaChange::FieldRemoved {
+                    path: "removed".to_string(),
+                    r#type: json!("boolean"),
+                },
+                SchemaChange::NullabilityChanged {
+                    path: "nested".to_string(),
+                    from: false,
+                    to: true,
+                },
+                SchemaChange::DefaultChanged {
+                    path: "nested.b".to_string(),
+                    from: Some(Value::Null),
+                    to: None,
+                },
+                SchemaChange::FieldAdded {
+                    path: "nested.c".to_string(),
+                    r#type: json!({"type": "int", "logicalType": "date"}),
+                },
+                SchemaChange::NullabilityChanged {
+                    path: "tags[]".to_string(),
+                    from: false,
+                    to: true,
+                },
+                SchemaChange::TypeChanged {
+                    path: "attributes{}".to_string(),
+                    from: json!("int"),
+                    to: json!("long"),
+                },
+            ]
+        );
+
+        let report: Vec<_> = changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            report,
+            vec![
+                r#"changed type of id from "int" to "long""#,
+                r#"changed default of name from "foo" to null"#,
+                "changed name from non-nullable to nullable",
+                r#"removed field removed of type "boolean""#,
+                "changed nested from non-nullable to nullable",
+                "removed default null from nested.b",
+                r#"added field nested.c of type {"logicalType":"date","type":"int"}"#,
+                "changed tags[] from non-nullable to nullable",
+                r#"changed type of attributes{} from "int" to "long""#,
+            ]
+        );
+    }
 }
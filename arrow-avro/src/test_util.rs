@@ -0,0 +1,470 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities for generating Avro test data
+//!
+//! Requires the `test_utils` feature
+
+use crate::codec::{map_entries_field, AvroDataType, AvroField, Codec, MapKey, Nullability};
+use crate::schema::Schema;
+use crate::writer::encode_value;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{i256, BooleanBuffer, IntervalMonthDayNano, NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType, Field, Schema as ArrowSchema, DECIMAL128_MAX_PRECISION};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::sync::Arc;
+
+/// Returns the path of `path` within the arrow test data directory, which can be
+/// overridden with the `ARROW_TEST_DATA` environment variable
+#[cfg(test)]
+pub(crate) fn arrow_test_data(path: &str) -> String {
+    match std::env::var("ARROW_TEST_DATA") {
+        Ok(dir) => format!("{dir}/{path}"),
+        Err(_) => format!("../testing/data/{path}"),
+    }
+}
+
+/// Appends the zig-zag variable length encoding of `v` to `out`
+pub fn encode_long(out: &mut Vec<u8>, v: i64) {
+    crate::writer::encode_long(out, v)
+}
+
+/// Appends the Avro encoding of the bytes or string `b` to `out`
+pub fn encode_bytes(out: &mut Vec<u8>, b: &[u8]) {
+    crate::writer::encode_bytes(out, b)
+}
+
+/// The sync marker used by [`write_ocf`]
+pub const SYNC: [u8; 16] = *b"0123456789abcdef";
+
+/// Returns an uncompressed Avro object container file with the given `schema`,
+/// containing `blocks` of `(count, data)` with `data` the encoded records
+pub fn write_ocf(schema: &str, blocks: &[(usize, Vec<u8>)]) -> Vec<u8> {
+    write_ocf_with_metadata(schema, &[], blocks)
+}
+
+/// Returns an uncompressed Avro object container file as [`write_ocf`], with the
+/// additional header `metadata`
+pub fn write_ocf_with_metadata(
+    schema: &str,
+    metadata: &[(&str, &[u8])],
+    blocks: &[(usize, Vec<u8>)],
+) -> Vec<u8> {
+    let mut out = b"Obj\x01".to_vec();
+    encode_long(&mut out, metadata.len() as i64 + 1);
+    encode_bytes(&mut out, b"avro.schema");
+    encode_bytes(&mut out, schema.as_bytes());
+    for (k, v) in metadata {
+        encode_bytes(&mut out, k.as_bytes());
+        encode_bytes(&mut out, v);
+    }
+    encode_long(&mut out, 0);
+    out.extend_from_slice(&SYNC);
+    for (count, data) in blocks {
+        encode_long(&mut out, *count as i64);
+        encode_bytes(&mut out, data);
+        out.extend_from_slice(&SYNC);
+    }
+    out
+}
+
+/// Randomly generated data, see [`generate`]
+#[derive(Debug, Clone)]
+pub struct GeneratedData {
+    /// The Avro schema JSON the data was generated for
+    pub schema: String,
+    /// The generated data, as it is expected to be decoded
+    pub batch: RecordBatch,
+    /// The Avro binary encoding of each row of [`Self::batch`]
+    pub records: Vec<Vec<u8>>,
+}
+
+impl GeneratedData {
+    /// Returns an uncompressed Avro object container file containing [`Self::records`]
+    /// in a single block
+    pub fn to_ocf(&self) -> Vec<u8> {
+        write_ocf(&self.schema, &[(self.records.len(), self.records.concat())])
+    }
+}
+
+/// Generates `rows` random records of the Avro record `schema`, returning both the
+/// expected [`RecordBatch`] and the Avro binary encoding of each record
+///
+/// The same `seed` always generates the same data, allowing round-trip fixtures to
+/// be created without external tools. Nullable values are null roughly a fifth of
+/// the time.
+///
+/// ```
+/// # use arrow_avro::reader::ReaderBuilder;
+/// # use arrow_avro::schema::Schema;
+/// # use arrow_avro::test_util::generate;
+/// let schema: Schema = serde_json::from_str(r#"{
+///     "type": "record",
+///     "name": "r",
+///     "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": ["null", "string"]}]
+/// }"#).unwrap();
+/// let data = generate(&schema, 100, 42).unwrap();
+///
+/// let file = data.to_ocf();
+/// let mut reader = ReaderBuilder::new().build(file.as_slice()).unwrap();
+/// assert_eq!(reader.next().unwrap().unwrap(), data.batch);
+/// ```
+pub fn generate(schema: &Schema<'_>, rows: usize, seed: u64) -> Result<GeneratedData, ArrowError> {
+    let root = AvroField::try_from(schema)?;
+    let Codec::Struct(fields) = root.data_type().codec() else {
+        return Err(ArrowError::SchemaError(format!(
+            "Expected Avro record schema, got {schema:?}"
+        )));
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let columns = fields
+        .iter()
+        .map(|f| generate_array(&mut rng, f.data_type(), rows))
+        .collect::<Result<Vec<_>, _>>()?;
+    let arrow_fields: Vec<_> = fields.iter().map(|f| f.field()).collect();
+    let options = RecordBatchOptions::new().with_row_count(Some(rows));
+    let batch = RecordBatch::try_new_with_options(
+        Arc::new(ArrowSchema::new(arrow_fields)),
+        columns,
+        &options,
+    )?;
+
+    let records = (0..rows)
+        .map(|idx| {
+            let mut out = vec![];
+            for (field, column) in fields.iter().zip(batch.columns()) {
+                encode_value(field.data_type(), column.as_ref(), idx, &mut out)?;
+            }
+            Ok(out)
+        })
+        .collect::<Result<_, ArrowError>>()?;
+
+    Ok(GeneratedData {
+        schema: serde_json::to_string(schema).unwrap(),
+        batch,
+        records,
+    })
+}
+
+/// Generates an array of `len` random values of `data_type`
+fn generate_array(
+    rng: &mut StdRng,
+    data_type: &AvroDataType,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let nulls = data_type.nullability().map(|_| {
+        let valid = BooleanBuffer::collect_bool(len, |_| rng.gen_bool(0.8));
+        NullBuffer::new(valid)
+    });
+
+    Ok(match data_type.codec() {
+        Codec::Null => Arc::new(NullArray::new(len)),
+        Codec::Boolean => {
+            let values = BooleanBuffer::collect_bool(len, |_| rng.gen_bool(0.5));
+            Arc::new(BooleanArray::new(values, nulls))
+        }
+        Codec::Int32 => Arc::new(primitive::<Int32Type>(len, nulls, || rng.gen())),
+        Codec::Int64 => Arc::new(primitive::<Int64Type>(len, nulls, || rng.gen())),
+        Codec::Float32 => Arc::new(primitive::<Float32Type>(len, nulls, || rng.gen())),
+        Codec::Float64 => Arc::new(primitive::<Float64Type>(len, nulls, || rng.gen())),
+        Codec::Date32 => Arc::new(primitive::<Date32Type>(len, nulls, || {
+            rng.gen_range(-100_000..100_000)
+        })),
+        Codec::TimeMillis => Arc::new(primitive::<Time32MillisecondType>(len, nulls, || {
+            rng.gen_range(0..86_400_000)
+        })),
+        Codec::TimeMicros => Arc::new(primitive::<Time64MicrosecondType>(len, nulls, || {
+            rng.gen_range(0..86_400_000_000)
+        })),
+        Codec::TimestampMillis(is_utc) => Arc::new(
+            primitive::<TimestampMillisecondType>(len, nulls, || {
+                rng.gen_range(-10_000_000_000_000..10_000_000_000_000)
+            })
+            .with_timezone_opt(is_utc.then(|| "+00:00")),
+        ),
+        Codec::TimestampMicros(is_utc) => Arc::new(
+            primitive::<TimestampMicrosecondType>(len, nulls, || {
+                rng.gen_range(-10_000_000_000_000_000..10_000_000_000_000_000)
+            })
+            .with_timezone_opt(is_utc.then(|| "+00:00")),
+        ),
+        Codec::Binary => {
+            let values: Vec<_> = (0..len).map(|_| random_bytes(rng, 0..16)).collect();
+            let array = BinaryArray::from_iter_values(values);
+            Arc::new(BinaryArray::new(
+                array.offsets().clone(),
+                array.values().clone(),
+                nulls,
+            ))
+        }
+        Codec::Utf8 => {
+            let values: Vec<_> = (0..len)
+                .map(|_| {
+                    let len = rng.gen_range(0..16);
+                    (0..len)
+                        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                        .collect::<String>()
+                })
+                .collect();
+            Arc::new(string_array(values, nulls))
+        }
+        Codec::Json => {
+            let values: Vec<_> = (0..len)
+                .map(|_| format!(r#"{{"v":{}}}"#, rng.gen::<i32>()))
+                .collect();
+            Arc::new(string_array(values, nulls))
+        }
+        Codec::Uuid => fixed_size_binary(rng, 16, len, nulls)?,
+        Codec::Fixed(size) => fixed_size_binary(rng, *size, len, nulls)?,
+        Codec::Decimal(precision, scale, _, _) => {
+            let max = 10_i128.pow((*precision).min(DECIMAL128_MAX_PRECISION) as u32) - 1;
+            match *precision <= DECIMAL128_MAX_PRECISION {
+                true => Arc::new(
+                    primitive::<Decimal128Type>(len, nulls, || rng.gen_range(-max..=max))
+                        .with_precision_and_scale(*precision, *scale)?,
+                ),
+                false => Arc::new(
+                    primitive::<Decimal256Type>(len, nulls, || {
+                        i256::from_i128(rng.gen_range(-max..=max))
+                    })
+                    .with_precision_and_scale(*precision, *scale)?,
+                ),
+            }
+        }
+        Codec::Enum(e) => {
+            let count = e.symbols().len() as i32;
+            let keys = primitive::<Int32Type>(len, nulls, || rng.gen_range(0..count));
+            let values = StringArray::from_iter_values(e.symbols());
+            Arc::new(DictionaryArray::try_new(keys, Arc::new(values))?)
+        }
+        Codec::Union(branches) => {
+            // Generated as a sparse union, with the values of each branch for every record
+            let count = branches.len() as i8;
+            let type_ids: Vec<i8> = (0..len).map(|_| rng.gen_range(0..count)).collect();
+            let children = branches
+                .iter()
+                .map(|b| generate_array(rng, b.data_type(), len))
+                .collect::<Result<Vec<_>, _>>()?;
+            let DataType::Union(fields, _) = data_type.field_with_name("").data_type().clone()
+            else {
+                unreachable!("unions are decoded to DataType::Union")
+            };
+            Arc::new(UnionArray::try_new(
+                fields,
+                type_ids.into(),
+                None,
+                children,
+            )?)
+        }
+        Codec::Interval => Arc::new(primitive::<IntervalMonthDayNanoType>(len, nulls, || {
+            IntervalMonthDayNano::new(
+                rng.gen_range(0..1000),
+                rng.gen_range(0..1000),
+                rng.gen_range(0..86_400_000) * 1_000_000,
+            )
+        })),
+        Codec::List(item) => {
+            let lengths: Vec<usize> = (0..len).map(|_| rng.gen_range(0..4)).collect();
+            let values = generate_array(rng, item, lengths.iter().sum())?;
+            let offsets = OffsetBuffer::from_lengths(lengths);
+            let field = Arc::new(item.field_with_name(Field::LIST_FIELD_DEFAULT_NAME));
+            Arc::new(ListArray::try_new(field, offsets, values, nulls)?)
+        }
+        Codec::Map(key, value) => {
+            let lengths: Vec<usize> = (0..len).map(|_| rng.gen_range(0..4)).collect();
+            let count = lengths.iter().sum();
+            let keys: ArrayRef = match key {
+                MapKey::Utf8 => {
+                    let keys = (0..count)
+                        .map(|_| format!("k{}", rng.gen::<u16>()))
+                        .collect();
+                    Arc::new(string_array(keys, None))
+                }
+                MapKey::Int32 => Arc::new(primitive::<Int32Type>(count, None, || rng.gen())),
+                MapKey::Int64 => Arc::new(primitive::<Int64Type>(count, None, || rng.gen())),
+            };
+            let values = generate_array(rng, value, count)?;
+            let entries = map_entries_field(*key, value.field_with_name("value"));
+            let DataType::Struct(fields) = entries.data_type() else {
+                unreachable!()
+            };
+            let entries_array = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+            let offsets = OffsetBuffer::from_lengths(lengths);
+            Arc::new(MapArray::try_new(
+                Arc::new(entries),
+                offsets,
+                entries_array,
+                nulls,
+                false,
+            )?)
+        }
+        Codec::Struct(fields) => {
+            let arrays = fields
+                .iter()
+                .map(|f| generate_array(rng, f.data_type(), len))
+                .collect::<Result<Vec<_>, _>>()?;
+            let fields = fields.iter().map(|f| f.field()).collect();
+            Arc::new(StructArray::try_new(fields, arrays, nulls)?)
+        }
+    })
+}
+
+fn primitive<T: ArrowPrimitiveType>(
+    len: usize,
+    nulls: Option<NullBuffer>,
+    mut f: impl FnMut() -> T::Native,
+) -> PrimitiveArray<T> {
+    let values: Vec<_> = (0..len).map(|_| f()).collect();
+    PrimitiveArray::new(values.into(), nulls)
+}
+
+fn string_array(values: Vec<String>, nulls: Option<NullBuffer>) -> StringArray {
+    let array = StringArray::from_iter_values(values);
+    StringArray::new(array.offsets().clone(), array.values().clone(), nulls)
+}
+
+fn fixed_size_binary(
+    rng: &mut StdRng,
+    size: i32,
+    len: usize,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef, ArrowError> {
+    let mut values = vec![0; size as usize * len];
+    rng.fill_bytes(&mut values);
+    let array = FixedSizeBinaryArray::try_new(size, values.into(), nulls)?;
+    Ok(Arc::new(array))
+}
+
+fn random_bytes(rng: &mut StdRng, len: std::ops::Range<usize>) -> Vec<u8> {
+    let mut out = vec![0; rng.gen_range(len)];
+    rng.fill_bytes(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::builder::make_builder;
+
+    #[test]
+    fn test_generate_round_trip() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "null", "type": "null"},
+                {"name": "bool", "type": "boolean"},
+                {"name": "int", "type": ["null", "int"]},
+                {"name": "long", "type": ["long", "null"]},
+                {"name": "float", "type": "float"},
+                {"name": "double", "type": ["null", "double"]},
+                {"name": "bytes", "type": "bytes"},
+                {"name": "string", "type": ["null", "string"]},
+                {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+                {"name": "json", "type": {"type": "string", "logicalType": "json"}},
+                {"name": "date", "type": {"type": "int", "logicalType": "date"}},
+                {"name": "time_ms", "type": {"type": "int", "logicalType": "time-millis"}},
+                {"name": "time_us", "type": {"type": "long", "logicalType": "time-micros"}},
+                {"name": "ts_ms", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+                {"name": "ts_us", "type": {"type": "long", "logicalType": "local-timestamp-micros"}},
+                {"name": "dec", "type": {
+                    "type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2
+                }},
+                {"name": "dec_fixed", "type": {
+                    "type": "fixed", "name": "d", "size": 9, "logicalType": "decimal", "precision": 20
+                }},
+                {"name": "dec256", "type": {"type": "bytes", "logicalType": "decimal", "precision": 60}},
+                {"name": "map", "type": {"type": "map", "values": ["null", "long"]}},
+                {"name": "int_map", "type": {"type": "map", "values": "string", "arrow.key-type": "int"}},
+                {"name": "enum", "type": ["null", {"type": "enum", "name": "e", "symbols": ["A", "B", "C"]}]},
+                {"name": "nested", "type": ["null", {
+                    "type": "record",
+                    "name": "nested",
+                    "fields": [{"name": "a", "type": "long"}, {"name": "b", "type": ["null", "string"]}]
+                }]}
+            ]
+        }"#;
+        let schema: Schema = serde_json::from_str(schema).unwrap();
+        let data = generate(&schema, 200, 1).unwrap();
+        assert_eq!(data.batch.num_rows(), 200);
+        assert_eq!(data.records.len(), 200);
+        assert!(data.batch.column(2).null_count() > 0);
+
+        let file = data.to_ocf();
+        let reader = ReaderBuilder::new()
+            .with_batch_size(200)
+            .build(file.as_slice())
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![data.batch.clone()]);
+
+        let mut decoder = ReaderBuilder::new()
+            .build_message_decoder(&data.schema)
+            .unwrap();
+        data.records.iter().for_each(|r| decoder.decode(r).unwrap());
+        assert_eq!(decoder.flush().unwrap().unwrap(), data.batch);
+
+        let fields = decoder.schema().fields().clone();
+        let mut builders: Vec<_> = fields
+            .iter()
+            .map(|f| make_builder(f.data_type(), 0))
+            .collect();
+        for record in &data.records {
+            decoder.decode_into(record, &mut builders).unwrap();
+        }
+        let columns = builders.iter_mut().map(|b| b.finish()).collect();
+        let batch = RecordBatch::try_new(decoder.schema(), columns).unwrap();
+        assert_eq!(batch, data.batch);
+
+        // The same seed generates the same data
+        let again = generate(&schema, 200, 1).unwrap();
+        assert_eq!(again.batch, data.batch);
+        assert_eq!(again.records, data.records);
+        let other = generate(&schema, 200, 2).unwrap();
+        assert_ne!(other.records, data.records);
+    }
+
+    #[test]
+    fn test_generate_unsupported_decoding() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [
+                {"name": "list", "type": {"type": "array", "items": ["null", "int"]}},
+                {"name": "fixed", "type": {"type": "fixed", "name": "f", "size": 3}},
+                {"name": "duration", "type": {
+                    "type": "fixed", "name": "i", "size": 12, "logicalType": "duration"
+                }}
+            ]
+        }"#;
+        let schema: Schema = serde_json::from_str(schema).unwrap();
+        let data = generate(&schema, 10, 0).unwrap();
+        assert_eq!(data.batch.num_rows(), 10);
+
+        let fixed = data.batch.column(1).as_fixed_size_binary();
+        for (idx, record) in data.records.iter().enumerate() {
+            // Each record ends with 3 bytes of fixed and 12 bytes of duration
+            let end = record.len() - 12;
+            assert_eq!(&record[end - 3..end], fixed.value(idx));
+        }
+    }
+}
@@ -0,0 +1,407 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encode [`RecordBatch`] to Avro
+//! [binary encoded](https://avro.apache.org/docs/1.11.1/specification/#binary-encoding) records
+
+use crate::codec::{AvroDataType, AvroField, Codec, MapKey, Nullability};
+use crate::schema::Schema;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, RecordBatch};
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, Fields, SchemaRef, DECIMAL128_MAX_PRECISION};
+use std::sync::Arc;
+
+/// Appends the zig-zag variable length encoding of `v` to `out`
+pub(crate) fn encode_long(out: &mut Vec<u8>, v: i64) {
+    let mut n = ((v << 1) ^ (v >> 63)) as u64;
+    while n >= 0x80 {
+        out.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+/// Appends the Avro encoding of the bytes or string `b` to `out`
+pub(crate) fn encode_bytes(out: &mut Vec<u8>, b: &[u8]) {
+    encode_long(out, b.len() as i64);
+    out.extend_from_slice(b);
+}
+
+/// Encodes each row of a [`RecordBatch`] as an individual Avro record, such as the
+/// values of Kafka messages, the inverse of [`MessageDecoder`]
+///
+/// The columns of the [`RecordBatch`] must have the types of [`Self::schema`], that is
+/// those a [`MessageDecoder`] for the same schema decodes to with the default options
+///
+/// [`MessageDecoder`]: crate::reader::MessageDecoder
+#[derive(Debug)]
+pub struct MessageEncoder {
+    encoder: RecordEncoder,
+    schema: SchemaRef,
+}
+
+impl MessageEncoder {
+    /// Create a new [`MessageEncoder`] encoding records with the Avro schema JSON
+    /// `writer_schema`
+    pub fn try_new(writer_schema: &str) -> Result<Self, ArrowError> {
+        let encoder = RecordEncoder::try_new(writer_schema)?;
+        let schema = Arc::new(arrow_schema::Schema::new(encoder.fields().clone()));
+        Ok(Self { encoder, schema })
+    }
+
+    /// Returns the arrow schema of the encoded [`RecordBatch`]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Returns the Avro encoding of each row of `batch`
+    pub fn encode(&self, batch: &RecordBatch) -> Result<Vec<Vec<u8>>, ArrowError> {
+        self.encoder.encode(batch)
+    }
+}
+
+/// Encodes the rows of a [`RecordBatch`] with the Avro schema of an [`AvroField`]
+#[derive(Debug)]
+pub(crate) struct RecordEncoder {
+    root: AvroField,
+    fields: Fields,
+}
+
+impl RecordEncoder {
+    /// Create a new [`RecordEncoder`] encoding records with the Avro schema JSON
+    /// `writer_schema`
+    pub(crate) fn try_new(writer_schema: &str) -> Result<Self, ArrowError> {
+        let schema: Schema<'_> = serde_json::from_str(writer_schema).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse Avro writer schema JSON: {e}"))
+        })?;
+        let root = AvroField::try_from(&schema)?;
+        let Codec::Struct(fields) = root.data_type().codec() else {
+            return Err(ArrowError::SchemaError(format!(
+                "Expected Avro record schema, got {writer_schema}"
+            )));
+        };
+        let fields = fields.iter().map(|f| f.field()).collect();
+        Ok(Self { root, fields })
+    }
+
+    /// Returns the arrow fields of the columns of the encoded [`RecordBatch`]
+    pub(crate) fn fields(&self) -> &Fields {
+        &self.fields
+    }
+
+    /// Returns the Avro encoding of each row of `batch`
+    pub(crate) fn encode(&self, batch: &RecordBatch) -> Result<Vec<Vec<u8>>, ArrowError> {
+        self.check_schema(batch)?;
+        (0..batch.num_rows())
+            .map(|idx| {
+                let mut out = vec![];
+                self.encode_row(batch, idx, &mut out)?;
+                Ok(out)
+            })
+            .collect()
+    }
+
+    /// Returns an error if the columns of `batch` do not match [`Self::fields`]
+    fn check_schema(&self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.num_columns() != self.fields.len() {
+            return Err(ArrowError::SchemaError(format!(
+                "Expected {} columns to encode as Avro, got {}",
+                self.fields.len(),
+                batch.num_columns()
+            )));
+        }
+        for (field, column) in self.fields.iter().zip(batch.columns()) {
+            if !field.data_type().equals_datatype(column.data_type()) {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot encode column of type {} as Avro field \"{}\" of type {}",
+                    column.data_type(),
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends the Avro encoding of the row `idx` of `batch`, which must have been
+    /// checked by [`Self::check_schema`], to `out`
+    fn encode_row(
+        &self,
+        batch: &RecordBatch,
+        idx: usize,
+        out: &mut Vec<u8>,
+    ) -> Result<(), ArrowError> {
+        let Codec::Struct(fields) = self.root.data_type().codec() else {
+            unreachable!("checked by RecordEncoder::try_new")
+        };
+        for (field, column) in fields.iter().zip(batch.columns()) {
+            encode_value(field.data_type(), column.as_ref(), idx, out).map_err(|e| match e {
+                ArrowError::InvalidArgumentError(e) => ArrowError::InvalidArgumentError(format!(
+                    "Failed to encode row {idx} of field \"{}\": {e}",
+                    field.name()
+                )),
+                e => e,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Appends the Avro binary encoding of the value at `idx` of `array` to `out`
+///
+/// `array` must have the type of `data_type`, as checked by [`RecordEncoder`]
+pub(crate) fn encode_value(
+    data_type: &AvroDataType,
+    array: &dyn Array,
+    idx: usize,
+    out: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    match data_type.nullability() {
+        Some(nullability) => {
+            let null_first = matches!(nullability, Nullability::NullFirst);
+            let is_valid = array.is_valid(idx);
+            encode_long(out, (is_valid == null_first) as i64);
+            if !is_valid {
+                return Ok(());
+            }
+        }
+        None if !matches!(data_type.codec(), Codec::Null) && array.is_null(idx) => {
+            return Err(ArrowError::InvalidArgumentError(
+                "null value of non-nullable Avro type".to_string(),
+            ))
+        }
+        None => {}
+    }
+
+    match data_type.codec() {
+        Codec::Null => {}
+        Codec::Boolean => out.push(array.as_boolean().value(idx) as u8),
+        Codec::Int32 => encode_long(out, array.as_primitive::<Int32Type>().value(idx) as _),
+        Codec::Date32 => encode_long(out, array.as_primitive::<Date32Type>().value(idx) as _),
+        Codec::TimeMillis => {
+            let v = array.as_primitive::<Time32MillisecondType>().value(idx);
+            encode_long(out, v as _)
+        }
+        Codec::TimeMicros => {
+            let v = array.as_primitive::<Time64MicrosecondType>().value(idx);
+            encode_long(out, v)
+        }
+        Codec::Int64 => encode_long(out, array.as_primitive::<Int64Type>().value(idx)),
+        Codec::TimestampMillis(_) => {
+            let v = array.as_primitive::<TimestampMillisecondType>().value(idx);
+            encode_long(out, v)
+        }
+        Codec::TimestampMicros(_) => {
+            let v = array.as_primitive::<TimestampMicrosecondType>().value(idx);
+            encode_long(out, v)
+        }
+        Codec::Float32 => {
+            let v = array.as_primitive::<Float32Type>().value(idx);
+            out.extend_from_slice(&v.to_le_bytes())
+        }
+        Codec::Float64 => {
+            let v = array.as_primitive::<Float64Type>().value(idx);
+            out.extend_from_slice(&v.to_le_bytes())
+        }
+        Codec::Binary => encode_bytes(out, array.as_binary::<i32>().value(idx)),
+        Codec::Utf8 | Codec::Json => {
+            encode_bytes(out, array.as_string::<i32>().value(idx).as_bytes())
+        }
+        Codec::Uuid => {
+            let hex: String = array
+                .as_fixed_size_binary()
+                .value(idx)
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect();
+            let uuid = format!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            );
+            encode_bytes(out, uuid.as_bytes())
+        }
+        Codec::Fixed(_) => out.extend_from_slice(array.as_fixed_size_binary().value(idx)),
+        Codec::Decimal(precision, _, size, _) => {
+            let bytes = match *precision <= DECIMAL128_MAX_PRECISION {
+                true => i256::from_i128(array.as_primitive::<Decimal128Type>().value(idx)),
+                false => array.as_primitive::<Decimal256Type>().value(idx),
+            }
+            .to_be_bytes();
+            let fill = bytes[0];
+            match size {
+                Some(size) => {
+                    out.extend(std::iter::repeat(fill).take(size.saturating_sub(32)));
+                    out.extend_from_slice(&bytes[32 - (*size).min(32)..])
+                }
+                None => {
+                    // Strip redundant sign extension, retaining the sign bit
+                    let skip = bytes
+                        .windows(2)
+                        .take_while(|w| w[0] == fill && (w[1] & 0x80) == (fill & 0x80))
+                        .count();
+                    encode_bytes(out, &bytes[skip..])
+                }
+            }
+        }
+        Codec::Enum(e) => {
+            let dictionary = array.as_dictionary::<Int32Type>();
+            let key = dictionary.keys().value(idx) as usize;
+            let symbol = dictionary.values().as_string::<i32>().value(key);
+            let index = e
+                .symbols()
+                .iter()
+                .position(|s| s == symbol)
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!("\"{symbol}\" is not an enum symbol"))
+                })?;
+            encode_long(out, index as _)
+        }
+        Codec::Union(branches) => {
+            let union = array.as_union();
+            let branch = union.type_id(idx);
+            encode_long(out, branch as _);
+            let child = union.child(branch);
+            let data_type = branches[branch as usize].data_type();
+            encode_value(data_type, child.as_ref(), union.value_offset(idx), out)?
+        }
+        Codec::Interval => {
+            let v = array.as_primitive::<IntervalMonthDayNanoType>().value(idx);
+            let millis = (v.nanoseconds % 1_000_000 == 0)
+                .then(|| u32::try_from(v.nanoseconds / 1_000_000).ok())
+                .flatten();
+            let (Ok(months), Ok(days), Some(millis)) =
+                (u32::try_from(v.months), u32::try_from(v.days), millis)
+            else {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{v:?} cannot be represented as an Avro duration"
+                )));
+            };
+            out.extend_from_slice(&months.to_le_bytes());
+            out.extend_from_slice(&days.to_le_bytes());
+            out.extend_from_slice(&millis.to_le_bytes());
+        }
+        Codec::List(item) => {
+            let list = array.as_list::<i32>();
+            let offsets = list.value_offsets();
+            let range = offsets[idx] as usize..offsets[idx + 1] as usize;
+            if !range.is_empty() {
+                encode_long(out, range.len() as i64);
+                for i in range {
+                    encode_value(item, list.values().as_ref(), i, out)?;
+                }
+            }
+            encode_long(out, 0);
+        }
+        Codec::Map(key, value) => {
+            let map = array.as_map();
+            let offsets = map.value_offsets();
+            let range = offsets[idx] as usize..offsets[idx + 1] as usize;
+            if !range.is_empty() {
+                encode_long(out, range.len() as i64);
+                for i in range {
+                    let k = match key {
+                        MapKey::Utf8 => map.keys().as_string::<i32>().value(i).to_string(),
+                        MapKey::Int32 => {
+                            map.keys().as_primitive::<Int32Type>().value(i).to_string()
+                        }
+                        MapKey::Int64 => {
+                            map.keys().as_primitive::<Int64Type>().value(i).to_string()
+                        }
+                    };
+                    encode_bytes(out, k.as_bytes());
+                    encode_value(value, map.values().as_ref(), i, out)?;
+                }
+            }
+            encode_long(out, 0);
+        }
+        Codec::Struct(fields) => {
+            let array = array.as_struct();
+            for (field, column) in fields.iter().zip(array.columns()) {
+                encode_value(field.data_type(), column.as_ref(), idx, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::{Int64Array, StringArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_encode_long() {
+        let mut out = vec![];
+        for v in [0, -1, 1, -64, 64, i64::MAX, i64::MIN] {
+            encode_long(&mut out, v);
+        }
+        let mut expected = vec![0, 1, 2, 127, 128, 1];
+        expected.extend([0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+        expected.extend([0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_message_encoder() {
+        let schema = r#"{
+            "type": "record",
+            "name": "r",
+            "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": ["null", "string"]}]
+        }"#;
+        let encoder = MessageEncoder::try_new(schema).unwrap();
+        let batch = RecordBatch::try_new(
+            encoder.schema(),
+            vec![
+                Arc::new(Int64Array::from(vec![1, -2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )
+        .unwrap();
+        let records = encoder.encode(&batch).unwrap();
+        assert_eq!(records, [vec![2, 2, 2, b'a'], vec![3, 0]]);
+
+        let mut decoder = ReaderBuilder::new().build_message_decoder(schema).unwrap();
+        for record in &records {
+            decoder.decode(record).unwrap();
+        }
+        assert_eq!(decoder.flush().unwrap().unwrap(), batch);
+
+        let ids = Arc::new(Int64Array::from(vec![Some(1), None])) as _;
+        let batch = RecordBatch::try_from_iter([("id", ids), ("name", batch.column(1).clone())]);
+        let err = encoder.encode(&batch.unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Failed to encode row 1 of field \"id\": null value of non-nullable Avro type"
+        );
+
+        let names = Arc::new(StringArray::from(vec!["a"])) as _;
+        let batch = RecordBatch::try_from_iter([("name", names)]).unwrap();
+        let err = encoder.encode(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Expected 2 columns to encode as Avro, got 1"
+        );
+    }
+}
@@ -23,8 +23,11 @@ use std::panic::RefUnwindSafe;
 use std::sync::Arc;
 
 mod alignment;
+mod pool;
 
 pub use alignment::ALIGNMENT;
+pub use pool::{set_memory_pool, MemoryPool, TrackingMemoryPool};
+pub(crate) use pool::{track_allocation, track_deallocation, track_reallocation};
 
 /// The owner of an allocation.
 /// The trait implementation is responsible for dropping the allocations once no more references exist.
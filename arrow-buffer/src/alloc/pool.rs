@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+/// A process-wide hook notified of the memory owned by arrow buffers
+///
+/// Once registered with [`set_memory_pool`], the pool is notified whenever a
+/// [`MutableBuffer`] allocates, grows, shrinks or frees memory, including once it
+/// has been frozen into a [`Buffer`], and whenever a [`Vec`] is converted to or
+/// from a buffer without copying. Memory from custom allocations, such as
+/// those imported over FFI, is not reported.
+///
+/// This allows hosts to account arrow allocations against query memory limits.
+/// To route the allocations themselves to a different allocator, such as jemalloc,
+/// register it as the [`#[global_allocator]`](std::alloc::GlobalAlloc).
+///
+/// [`MutableBuffer`]: crate::MutableBuffer
+/// [`Buffer`]: crate::Buffer
+pub trait MemoryPool: Send + Sync + Debug {
+    /// Called when `size` bytes are allocated by, or transferred to, an arrow buffer
+    fn allocate(&self, size: usize);
+
+    /// Called when `size` bytes previously reported to [`Self::allocate`] are
+    /// freed by, or transferred out of, an arrow buffer
+    fn deallocate(&self, size: usize);
+}
+
+/// The [`MemoryPool`] used if none is registered
+#[derive(Debug)]
+struct NoopMemoryPool;
+
+impl MemoryPool for NoopMemoryPool {
+    fn allocate(&self, _size: usize) {}
+
+    fn deallocate(&self, _size: usize) {}
+}
+
+static MEMORY_POOL: OnceLock<&'static dyn MemoryPool> = OnceLock::new();
+
+/// Registers the process-wide [`MemoryPool`]
+///
+/// The pool is fixed by the first buffer allocation, so that every deallocation
+/// is reported to the same pool as the corresponding allocation. This must
+/// therefore be called before any buffers are created, and returns `Err(pool)`
+/// if a pool has already been registered or any buffer has been allocated.
+///
+/// ```
+/// # use arrow_buffer::alloc::{set_memory_pool, TrackingMemoryPool};
+/// # use arrow_buffer::{Buffer, MutableBuffer};
+/// static POOL: TrackingMemoryPool = TrackingMemoryPool::new();
+/// set_memory_pool(&POOL).unwrap();
+///
+/// let mut buffer = MutableBuffer::with_capacity(1024);
+/// assert_eq!(POOL.used(), 1024);
+///
+/// buffer.reserve(2048);
+/// let buffer = Buffer::from(buffer);
+/// assert_eq!(POOL.used(), 2048);
+///
+/// drop(buffer);
+/// assert_eq!(POOL.used(), 0);
+/// assert_eq!(POOL.peak(), 2048);
+/// ```
+pub fn set_memory_pool(pool: &'static dyn MemoryPool) -> Result<(), &'static dyn MemoryPool> {
+    MEMORY_POOL.set(pool)
+}
+
+#[inline]
+fn memory_pool() -> &'static dyn MemoryPool {
+    *MEMORY_POOL.get_or_init(|| &NoopMemoryPool)
+}
+
+/// Reports `size` bytes allocated to the registered [`MemoryPool`]
+#[inline]
+pub(crate) fn track_allocation(size: usize) {
+    if size != 0 {
+        memory_pool().allocate(size)
+    }
+}
+
+/// Reports `size` bytes freed to the registered [`MemoryPool`]
+#[inline]
+pub(crate) fn track_deallocation(size: usize) {
+    if size != 0 {
+        memory_pool().deallocate(size)
+    }
+}
+
+/// Reports an allocation resized from `old_size` to `new_size` bytes
+#[inline]
+pub(crate) fn track_reallocation(old_size: usize, new_size: usize) {
+    if new_size > old_size {
+        track_allocation(new_size - old_size)
+    } else {
+        track_deallocation(old_size - new_size)
+    }
+}
+
+/// A [`MemoryPool`] that tracks the current and peak number of bytes allocated
+#[derive(Debug, Default)]
+pub struct TrackingMemoryPool {
+    used: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl TrackingMemoryPool {
+    /// Create a new [`TrackingMemoryPool`]
+    pub const fn new() -> Self {
+        Self {
+            used: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of bytes currently allocated
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns the largest number of bytes allocated at any one time
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+}
+
+impl MemoryPool for TrackingMemoryPool {
+    fn allocate(&self, size: usize) {
+        let used = self.used.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(used, Ordering::Relaxed);
+    }
+
+    fn deallocate(&self, size: usize) {
+        self.used.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracking_memory_pool() {
+        let pool = TrackingMemoryPool::new();
+        pool.allocate(64);
+        pool.allocate(128);
+        pool.deallocate(64);
+        assert_eq!(pool.used(), 128);
+        assert_eq!(pool.peak(), 192);
+        pool.deallocate(128);
+        assert_eq!(pool.used(), 0);
+        assert_eq!(pool.peak(), 192);
+    }
+}
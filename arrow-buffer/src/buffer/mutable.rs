@@ -19,7 +19,9 @@ use std::alloc::{handle_alloc_error, Layout};
 use std::mem;
 use std::ptr::NonNull;
 
-use crate::alloc::{Deallocation, ALIGNMENT};
+use crate::alloc::{
+    track_allocation, track_deallocation, track_reallocation, Deallocation, ALIGNMENT,
+};
 use crate::{
     bytes::Bytes,
     native::{ArrowNativeType, ToByteSlice},
@@ -84,7 +86,9 @@ impl MutableBuffer {
             _ => {
                 // Safety: Verified size != 0
                 let raw_ptr = unsafe { std::alloc::alloc(layout) };
-                NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                let ptr = NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout));
+                track_allocation(layout.size());
+                ptr
             }
         };
         Self {
@@ -112,7 +116,9 @@ impl MutableBuffer {
             _ => {
                 // Safety: Verified size != 0
                 let raw_ptr = unsafe { std::alloc::alloc_zeroed(layout) };
-                NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                let ptr = NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout));
+                track_allocation(layout.size());
+                ptr
             }
         };
         Self { data, len, layout }
@@ -204,6 +210,7 @@ impl MutableBuffer {
             if self.layout.size() != 0 {
                 // Safety: data was allocated with layout
                 unsafe { std::alloc::dealloc(self.as_mut_ptr(), self.layout) };
+                track_deallocation(self.layout.size());
                 self.layout = new_layout
             }
             return;
@@ -216,6 +223,7 @@ impl MutableBuffer {
             _ => unsafe { std::alloc::realloc(self.as_mut_ptr(), self.layout, capacity) },
         };
         self.data = NonNull::new(data).unwrap_or_else(|| handle_alloc_error(new_layout));
+        track_reallocation(self.layout.size(), new_layout.size());
         self.layout = new_layout;
     }
 
@@ -505,6 +513,7 @@ impl<T: ArrowNativeType> From<Vec<T>> for MutableBuffer {
         // This is based on `RawVec::current_memory`
         let layout = unsafe { Layout::array::<T>(value.capacity()).unwrap_unchecked() };
         mem::forget(value);
+        track_allocation(layout.size());
         Self { data, len, layout }
     }
 }
@@ -680,6 +689,7 @@ impl Drop for MutableBuffer {
         if self.layout.size() != 0 {
             // Safety: data was allocated with standard allocator with given layout
             unsafe { std::alloc::dealloc(self.data.as_ptr() as _, self.layout) };
+            track_deallocation(self.layout.size());
         }
     }
 }
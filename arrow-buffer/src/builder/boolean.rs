@@ -191,6 +191,18 @@ impl BooleanBufferBuilder {
     pub fn append_packed_range(&mut self, range: Range<usize>, to_set: &[u8]) {
         let offset_write = self.len;
         let len = range.end - range.start;
+        if offset_write % 8 == 0 && range.start % 8 == 0 {
+            // Both sides are byte-aligned, copy the bytes directly
+            let bytes = &to_set[range.start / 8..bit_util::ceil(range.end, 8)];
+            self.buffer.extend_from_slice(bytes);
+            self.len += len;
+            let remainder = self.len % 8;
+            if remainder != 0 {
+                // Clear any bits read past the end of the range
+                *self.buffer.as_slice_mut().last_mut().unwrap() &= (1 << remainder) - 1;
+            }
+            return;
+        }
         self.advance(len);
         bit_mask::set_bits(
             self.buffer.as_slice_mut(),
@@ -419,6 +431,22 @@ mod tests {
         assert_eq!(buffer.finish(), compacted.finish())
     }
 
+    #[test]
+    fn test_append_packed_range_aligned() {
+        let src = [0b1111_0101_u8, 0b1111_1110, 0b1000_0001];
+        let mut buffer = BooleanBufferBuilder::new(0);
+        buffer.append_packed_range(8..13, &src);
+        assert_eq!(buffer.as_slice(), &[0b0001_1110]);
+        buffer.append_packed_range(0..3, &src);
+        assert_eq!(buffer.as_slice(), &[0b1011_1110]);
+        buffer.append_packed_range(0..20, &src);
+        assert_eq!(buffer.len(), 28);
+        assert_eq!(
+            buffer.as_slice(),
+            &[0b1011_1110, 0b1111_0101, 0b1111_1110, 0b0001]
+        );
+    }
+
     #[test]
     fn test_boolean_array_builder_resize() {
         let mut builder = BooleanBufferBuilder::new(20);
@@ -15,7 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::bit_chunk_iterator::UnalignedBitChunk;
 use crate::{BooleanBufferBuilder, MutableBuffer, NullBuffer};
+use std::ops::Range;
 
 /// Builder for creating the null bit buffer.
 ///
@@ -126,6 +128,51 @@ impl NullBufferBuilder {
         }
     }
 
+    /// Appends `range` validity bits from `to_set` into the builder
+    ///
+    /// `to_set` is a slice of bits packed LSB-first into `[u8]`, avoiding
+    /// materializing the buffer if all the bits in `range` are set
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to_set` does not contain `ceil(range.end / 8)` bytes
+    pub fn append_packed_range(&mut self, range: Range<usize>, to_set: &[u8]) {
+        if self.bitmap_builder.is_none() {
+            let len = range.end - range.start;
+            if UnalignedBitChunk::new(to_set, range.start, len).count_ones() == len {
+                self.len += len;
+                return;
+            }
+            self.materialize_if_needed();
+        }
+        let buf = self.bitmap_builder.as_mut().unwrap();
+        buf.append_packed_range(range, to_set)
+    }
+
+    /// Appends the validity of `nulls` into the builder
+    pub fn append_buffer(&mut self, nulls: &NullBuffer) {
+        if nulls.null_count() == 0 {
+            self.append_n_non_nulls(nulls.len());
+        } else {
+            self.materialize_if_needed();
+            self.bitmap_builder
+                .as_mut()
+                .unwrap()
+                .append_buffer(nulls.inner());
+        }
+    }
+
+    /// Truncates the builder to the given length
+    ///
+    /// If `len` is greater than the buffer's current length, this has no effect
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        match self.bitmap_builder.as_mut() {
+            Some(b) => b.truncate(len),
+            None => self.len = self.len.min(len),
+        }
+    }
+
     /// Builds the null buffer and resets the builder.
     /// Returns `None` if the builder only contains `true`s.
     pub fn finish(&mut self) -> Option<NullBuffer> {
@@ -242,4 +289,38 @@ mod tests {
         let buf = builder.finish().unwrap();
         assert_eq!(&[0b1011_u8], buf.validity());
     }
+
+    #[test]
+    fn test_null_buffer_builder_append_packed() {
+        let mut builder = NullBufferBuilder::new(0);
+        builder.append_packed_range(2..7, &[0b0111_1100]);
+        assert_eq!(5, builder.len());
+        assert!(builder.as_slice().is_none());
+
+        builder.append_packed_range(9..12, &[0, 0b0000_1010]);
+        builder.append_buffer(&NullBuffer::new_valid(2));
+        builder.append_buffer(&NullBuffer::from(vec![false, true, false]).slice(1, 2));
+        assert_eq!(12, builder.len());
+
+        let buf = builder.finish().unwrap();
+        assert_eq!(&[0b1011_1111_u8, 0b0111], buf.validity());
+    }
+
+    #[test]
+    fn test_null_buffer_builder_truncate() {
+        let mut builder = NullBufferBuilder::new(0);
+        builder.append_n_non_nulls(5);
+        builder.truncate(7);
+        assert_eq!(builder.len(), 5);
+        builder.truncate(3);
+        assert_eq!(builder.len(), 3);
+        assert!(builder.finish().is_none());
+
+        builder.append_slice(&[true, false, true, true]);
+        builder.truncate(2);
+        builder.append_non_null();
+        assert_eq!(builder.len(), 3);
+        let buf = builder.finish().unwrap();
+        assert_eq!(&[0b101_u8], buf.validity());
+    }
 }
@@ -54,6 +54,16 @@ impl<O: ArrowNativeType> OffsetBufferBuilder<O> {
         self.offsets.reserve(additional);
     }
 
+    /// Truncates the builder to `len` slices
+    ///
+    /// If `len` is greater than the current number of slices, this has no effect
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.offsets.len() - 1 {
+            self.offsets.truncate(len + 1);
+            self.last_offset = self.offsets[len].as_usize();
+        }
+    }
+
     /// Takes the builder itself and returns an [`OffsetBuffer`]
     ///
     /// # Panics
@@ -108,6 +118,19 @@ mod tests {
         assert_eq!(&*finished, &[0, 2, 8, 8, 21]);
     }
 
+    #[test]
+    fn test_truncate() {
+        let mut builder = OffsetBufferBuilder::<i32>::new(5);
+        builder.push_length(2);
+        builder.push_length(6);
+        builder.truncate(5);
+        assert_eq!(&*builder, &[0, 2, 8]);
+
+        builder.truncate(1);
+        builder.push_length(3);
+        assert_eq!(&*builder.finish(), &[0, 2, 5]);
+    }
+
     #[test]
     #[should_panic(expected = "overflow")]
     fn test_usize_overflow() {
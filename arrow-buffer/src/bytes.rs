@@ -23,7 +23,7 @@ use core::slice;
 use std::ptr::NonNull;
 use std::{fmt::Debug, fmt::Formatter};
 
-use crate::alloc::Deallocation;
+use crate::alloc::{track_deallocation, track_reallocation, Deallocation};
 use crate::buffer::dangling_ptr;
 
 /// A continuous, fixed-size, immutable memory region that knows how to de-allocate itself.
@@ -132,6 +132,7 @@ impl Bytes {
                 };
 
                 if let Some(ptr) = new_ptr {
+                    track_reallocation(old_layout.size(), new_len);
                     self.ptr = ptr;
                     self.len = new_len;
                     self.deallocation = Deallocation::Standard(new_layout);
@@ -160,7 +161,10 @@ impl Drop for Bytes {
         match &self.deallocation {
             Deallocation::Standard(layout) => match layout.size() {
                 0 => {} // Nothing to do
-                _ => unsafe { std::alloc::dealloc(self.ptr.as_ptr(), *layout) },
+                _ => {
+                    unsafe { std::alloc::dealloc(self.ptr.as_ptr(), *layout) };
+                    track_deallocation(layout.size());
+                }
             },
             // The automatic drop implementation will free the memory once the reference count reaches zero
             Deallocation::Custom(_allocation, _size) => (),
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::cast::*;
+use arrow_buffer::{NullBuffer, NullBufferBuilder};
 
 /// A utility trait that provides checked conversions between
 /// decimal types inspired by [`NumCast`]
@@ -94,18 +95,28 @@ where
         .unwrap()
         .pow_checked((input_scale - output_scale) as u32)?;
 
-    let half = div.div_wrapping(I::Native::from_usize(2).unwrap());
+    let two = I::Native::from_usize(2).unwrap();
+    let half = div.div_wrapping(two);
     let half_neg = half.neg_wrapping();
+    let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::HalfUp);
 
     let f = |x: I::Native| {
         // div is >= 10 and so this cannot overflow
         let d = x.div_wrapping(div);
         let r = x.mod_wrapping(div);
+        let odd = d.mod_wrapping(two) != I::Native::ZERO;
 
         // Round result
-        let adjusted = match x >= I::Native::ZERO {
-            true if r >= half => d.add_wrapping(I::Native::ONE),
-            false if r <= half_neg => d.sub_wrapping(I::Native::ONE),
+        let adjusted = match (mode, x >= I::Native::ZERO) {
+            (RoundingMode::Truncate, _) => d,
+            (RoundingMode::HalfUp, true) if r >= half => d.add_wrapping(I::Native::ONE),
+            (RoundingMode::HalfUp, false) if r <= half_neg => d.sub_wrapping(I::Native::ONE),
+            (RoundingMode::HalfEven, true) if r > half || (r == half && odd) => {
+                d.add_wrapping(I::Native::ONE)
+            }
+            (RoundingMode::HalfEven, false) if r < half_neg || (r == half_neg && odd) => {
+                d.sub_wrapping(I::Native::ONE)
+            }
             _ => d,
         };
         O::Native::from_decimal(adjusted)
@@ -230,108 +241,129 @@ where
     )?))
 }
 
-/// Parses given string to specified decimal native (i128/i256) based on given
-/// scale. Returns an `Err` if it cannot parse given string.
-pub(crate) fn parse_string_to_decimal_native<T: DecimalType>(
-    value_str: &str,
-    scale: usize,
-) -> Result<T::Native, ArrowError>
-where
-    T::Native: DecimalCast + ArrowNativeTypeOp,
-{
-    let value_str = value_str.trim();
-    let parts: Vec<&str> = value_str.split('.').collect();
-    if parts.len() > 2 {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Invalid decimal format: {value_str:?}"
-        )));
-    }
-
-    let (negative, first_part) = if parts[0].is_empty() {
-        (false, parts[0])
-    } else {
-        match parts[0].as_bytes()[0] {
-            b'-' => (true, &parts[0][1..]),
-            b'+' => (false, &parts[0][1..]),
-            _ => (false, parts[0]),
-        }
-    };
+/// Returns true if all 8 bytes of `v` are ASCII digits
+#[inline]
+fn is_8_digits(v: u64) -> bool {
+    // A byte above b'9' overflows into the high bit when adding 0x46, and a byte
+    // below b'0' wraps into the high bit when subtracting 0x30
+    let a = v.wrapping_add(0x4646464646464646);
+    let b = v.wrapping_sub(0x3030303030303030);
+    (a | b) & 0x8080808080808080 == 0
+}
 
-    let integers = first_part;
-    let decimals = if parts.len() == 2 { parts[1] } else { "" };
+/// Parses 8 ASCII digits stored in little-endian order in `v`
+#[inline]
+fn parse_8_digits(v: u64) -> u64 {
+    let v = v.wrapping_sub(0x3030303030303030);
+    let v = (v.wrapping_mul(10) + (v >> 8)) & 0x00FF00FF00FF00FF;
+    let v = (v.wrapping_mul(100) + (v >> 16)) & 0x0000FFFF0000FFFF;
+    (v.wrapping_mul(10000) + (v >> 32)) & 0x00000000FFFFFFFF
+}
 
-    if !integers.is_empty() && !integers.as_bytes()[0].is_ascii_digit() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Invalid decimal format: {value_str:?}"
-        )));
+/// Appends the decimal digits in `s` to `acc`, processing 8 digits at a time
+///
+/// Returns `None` if `s` contains a non-digit byte or on overflow
+fn accumulate_digits<N: ArrowNativeTypeOp>(mut acc: N, s: &[u8]) -> Option<N> {
+    let mut chunks = s.chunks_exact(8);
+    for chunk in &mut chunks {
+        let v = u64::from_le_bytes(chunk.try_into().unwrap());
+        if !is_8_digits(v) {
+            return None;
+        }
+        let digits = N::usize_as(parse_8_digits(v) as usize);
+        acc = acc
+            .mul_checked(N::usize_as(100_000_000))
+            .ok()?
+            .add_checked(digits)
+            .ok()?;
     }
-
-    if !decimals.is_empty() && !decimals.as_bytes()[0].is_ascii_digit() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Invalid decimal format: {value_str:?}"
-        )));
+    for b in chunks.remainder() {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        let digit = N::usize_as((b - b'0') as usize);
+        acc = acc
+            .mul_checked(N::usize_as(10))
+            .ok()?
+            .add_checked(digit)
+            .ok()?;
     }
+    Some(acc)
+}
 
-    // Adjust decimal based on scale
-    let mut number_decimals = if decimals.len() > scale {
-        let decimal_number = i256::from_string(decimals).ok_or_else(|| {
-            ArrowError::InvalidArgumentError(format!("Cannot parse decimal format: {value_str}"))
-        })?;
-
-        let div = i256::from_i128(10_i128).pow_checked((decimals.len() - scale) as u32)?;
-
-        let half = div.div_wrapping(i256::from_i128(2));
-        let half_neg = half.neg_wrapping();
-
-        let d = decimal_number.div_wrapping(div);
-        let r = decimal_number.mod_wrapping(div);
+/// Parses the UTF-8 bytes of a decimal string to a decimal native (i128/i256) with
+/// the given `scale`, rounding any additional fractional digits half away from zero
+///
+/// Leading and trailing ASCII whitespace is ignored. Returns `None` if `s` is not a
+/// valid decimal or the value does not fit in `T::Native`
+fn parse_bytes_to_decimal_native<T: DecimalType>(s: &[u8], scale: usize) -> Option<T::Native>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let mut s = s;
+    while let [first, rest @ ..] = s {
+        match first.is_ascii_whitespace() {
+            true => s = rest,
+            false => break,
+        }
+    }
+    while let [rest @ .., last] = s {
+        match last.is_ascii_whitespace() {
+            true => s = rest,
+            false => break,
+        }
+    }
 
-        // Round result
-        let adjusted = match decimal_number >= i256::ZERO {
-            true if r >= half => d.add_wrapping(i256::ONE),
-            false if r <= half_neg => d.sub_wrapping(i256::ONE),
-            _ => d,
-        };
+    let (negative, s) = match s.first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
 
-        let integers = if !integers.is_empty() {
-            i256::from_string(integers)
-                .ok_or_else(|| {
-                    ArrowError::InvalidArgumentError(format!(
-                        "Cannot parse decimal format: {value_str}"
-                    ))
-                })
-                .map(|v| v.mul_wrapping(i256::from_i128(10_i128).pow_wrapping(scale as u32)))?
-        } else {
-            i256::ZERO
-        };
+    let (integers, decimals) = match s.iter().position(|b| *b == b'.') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, &s[s.len()..]),
+    };
 
-        format!("{}", integers.add_wrapping(adjusted))
-    } else {
-        let padding = if scale > decimals.len() { scale } else { 0 };
+    if integers.is_empty() && decimals.is_empty() && scale == 0 {
+        return None;
+    }
 
-        let decimals = format!("{decimals:0<padding$}");
-        format!("{integers}{decimals}")
+    let (decimals, round_up) = match decimals.len() > scale {
+        true => {
+            let (decimals, truncated) = decimals.split_at(scale);
+            if !truncated.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            (decimals, truncated[0] >= b'5')
+        }
+        false => (decimals, false),
     };
 
-    if negative {
-        number_decimals.insert(0, '-');
+    let value = accumulate_digits(T::Native::ZERO, integers)?;
+    let value = accumulate_digits(value, decimals)?;
+    let mul = T::Native::usize_as(10)
+        .pow_checked((scale - decimals.len()) as u32)
+        .ok()?;
+    let mut value = value.mul_checked(mul).ok()?;
+    if round_up {
+        value = value.add_checked(T::Native::ONE).ok()?;
     }
 
-    let value = i256::from_string(number_decimals.as_str()).ok_or_else(|| {
-        ArrowError::InvalidArgumentError(format!(
-            "Cannot convert {} to {}: Overflow",
-            value_str,
-            T::PREFIX
-        ))
-    })?;
-
-    T::Native::from_decimal(value).ok_or_else(|| {
-        ArrowError::InvalidArgumentError(format!("Cannot convert {} to {}", value_str, T::PREFIX))
+    Some(match negative {
+        true => value.neg_wrapping(),
+        false => value,
     })
 }
 
-pub(crate) fn generic_string_to_decimal_cast<'a, T, S>(
-    from: &'a S,
+/// Casts the UTF-8 bytes of each row yielded by `values` to a decimal, where `nulls`
+/// is the null buffer of the source array
+///
+/// Returns an error identifying the row and its text if a value cannot be parsed and
+/// `cast_options.safe` is false
+fn cast_string_bytes_to_decimal<'a, T, I>(
+    values: I,
+    nulls: Option<&NullBuffer>,
     precision: u8,
     scale: i8,
     cast_options: &CastOptions,
@@ -339,48 +371,46 @@ pub(crate) fn generic_string_to_decimal_cast<'a, T, S>(
 where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
-    &'a S: StringArrayType<'a>,
+    I: ExactSizeIterator<Item = &'a [u8]>,
 {
-    if cast_options.safe {
-        let iter = from.iter().map(|v| {
-            v.and_then(|v| parse_string_to_decimal_native::<T>(v, scale as usize).ok())
-                .and_then(|v| T::is_valid_decimal_precision(v, precision).then_some(v))
-        });
-        // Benefit:
-        //     20% performance improvement
-        // Soundness:
-        //     The iterator is trustedLen because it comes from an `StringArray`.
-        Ok(unsafe {
-            PrimitiveArray::<T>::from_trusted_len_iter(iter)
-                .with_precision_and_scale(precision, scale)?
-        })
-    } else {
-        let vec = from
-            .iter()
-            .map(|v| {
-                v.map(|v| {
-                    parse_string_to_decimal_native::<T>(v, scale as usize)
-                        .map_err(|_| {
-                            ArrowError::CastError(format!(
-                                "Cannot cast string '{}' to value of {:?} type",
-                                v,
-                                T::DATA_TYPE,
-                            ))
-                        })
-                        .and_then(|v| T::validate_decimal_precision(v, precision).map(|_| v))
-                })
-                .transpose()
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        // Benefit:
-        //     20% performance improvement
-        // Soundness:
-        //     The iterator is trustedLen because it comes from an `StringArray`.
-        Ok(unsafe {
-            PrimitiveArray::<T>::from_trusted_len_iter(vec.iter())
-                .with_precision_and_scale(precision, scale)?
-        })
+    let len = values.len();
+    let mut out = Vec::with_capacity(len);
+    let mut out_nulls = NullBufferBuilder::new(len);
+
+    for (idx, value) in values.enumerate() {
+        if nulls.is_some_and(|n| n.is_null(idx)) {
+            out.push(T::Native::ZERO);
+            out_nulls.append_null();
+            continue;
+        }
+
+        let parsed = parse_bytes_to_decimal_native::<T>(value, scale as usize);
+        let parsed = match (parsed, cast_options.safe) {
+            (Some(v), true) => T::is_valid_decimal_precision(v, precision).then_some(v),
+            (None, true) => None,
+            (Some(v), false) => {
+                T::validate_decimal_precision(v, precision).map_err(|e| match e {
+                    ArrowError::InvalidArgumentError(msg) => {
+                        ArrowError::InvalidArgumentError(format!("{msg} at row {idx}"))
+                    }
+                    e => e,
+                })?;
+                Some(v)
+            }
+            (None, false) => {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast string '{}' to value of {:?} type at row {idx}",
+                    String::from_utf8_lossy(value),
+                    T::TYPE_CONSTRUCTOR(precision, scale),
+                )))
+            }
+        };
+        out.push(parsed.unwrap_or(T::Native::ZERO));
+        out_nulls.append(parsed.is_some());
     }
+
+    PrimitiveArray::<T>::new(out.into(), out_nulls.finish())
+        .with_precision_and_scale(precision, scale)
 }
 
 pub(crate) fn string_to_decimal_cast<T, Offset: OffsetSizeTrait>(
@@ -393,12 +423,12 @@ where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    generic_string_to_decimal_cast::<T, GenericStringArray<Offset>>(
-        from,
-        precision,
-        scale,
-        cast_options,
-    )
+    let data = from.value_data();
+    let values = from
+        .value_offsets()
+        .windows(2)
+        .map(|w| &data[w[0].as_usize()..w[1].as_usize()]);
+    cast_string_bytes_to_decimal(values, from.nulls(), precision, scale, cast_options)
 }
 
 pub(crate) fn string_view_to_decimal_cast<T>(
@@ -411,7 +441,8 @@ where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    generic_string_to_decimal_cast::<T, StringViewArray>(from, precision, scale, cast_options)
+    let values = (0..from.len()).map(|idx| from.value(idx).as_bytes());
+    cast_string_bytes_to_decimal(values, from.nulls(), precision, scale, cast_options)
 }
 
 /// Cast Utf8 to decimal
@@ -628,42 +659,89 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_string_to_decimal_native() -> Result<(), ArrowError> {
-        assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("0", 0)?,
-            0_i128
-        );
-        assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("0", 5)?,
-            0_i128
-        );
+    fn test_accumulate_digits() {
+        let digits = "12345678901234567890123456789";
+        for len in 0..digits.len() {
+            let s = &digits[..len];
+            let expected = s.parse::<i128>().unwrap_or_default();
+            assert_eq!(accumulate_digits(0_i128, s.as_bytes()), Some(expected));
+
+            // Each invalid byte is detected regardless of its position within a chunk
+            for invalid in [b'/', b':', b'.', b' ', b'a', 0xC3] {
+                let mut bytes = s.as_bytes().to_vec();
+                for idx in 0..len {
+                    let original = std::mem::replace(&mut bytes[idx], invalid);
+                    assert_eq!(accumulate_digits(0_i128, &bytes), None, "{bytes:?}");
+                    bytes[idx] = original;
+                }
+            }
+        }
 
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123", 0)?,
-            123_i128
+            accumulate_digits(0_i128, i128::MAX.to_string().as_bytes()),
+            Some(i128::MAX)
         );
+        let overflow = "170141183460469231731687303715884105728";
+        assert_eq!(accumulate_digits(0_i128, overflow.as_bytes()), None);
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123", 5)?,
-            12300000_i128
+            accumulate_digits(i256::ZERO, overflow.as_bytes()),
+            i256::from_string(overflow)
         );
+    }
 
+    #[test]
+    fn test_parse_bytes_to_decimal_native() {
+        let cases = [
+            (" 1.005 ", 2, Some(101)),
+            ("-1.004", 2, Some(-100)),
+            ("+000000000000012.5", 0, Some(13)),
+            ("1.", 2, Some(100)),
+            (".", 2, Some(0)),
+            (".", 0, None),
+            ("", 0, None),
+            ("1.2.3", 2, None),
+            ("1.23x", 1, None),
+            ("1e5", 0, None),
+            ("- 1", 0, None),
+        ];
+        for (s, scale, expected) in cases {
+            let parsed = parse_bytes_to_decimal_native::<Decimal128Type>(s.as_bytes(), scale);
+            assert_eq!(parsed, expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_decimal_error_row() {
+        let array = StringArray::from(vec![Some("1.5"), None, Some("2.25"), Some("1,5")]);
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::Decimal128(10, 2), &options).unwrap_err();
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123.45", 0)?,
-            123_i128
+            err.to_string(),
+            "Cast error: Cannot cast string '1,5' to value of Decimal128(10, 2) type at row 3"
         );
+
+        let array = StringViewArray::from(vec![Some("1.5"), Some("x"), None]);
+        let err = cast_with_options(&array, &DataType::Decimal256(10, 2), &options).unwrap_err();
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123.45", 5)?,
-            12345000_i128
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Decimal256(10, 2) type at row 1"
         );
 
+        let casted = cast(&array, &DataType::Decimal128(10, 2)).unwrap();
+        let casted = casted.as_primitive::<Decimal128Type>();
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123.4567891", 0)?,
-            123_i128
+            casted.iter().collect::<Vec<_>>(),
+            vec![Some(150), None, None]
         );
+
+        let array = StringArray::from(vec![Some("1.5"), None, Some("1000")]);
+        let err = cast_with_options(&array, &DataType::Decimal128(5, 2), &options).unwrap_err();
         assert_eq!(
-            parse_string_to_decimal_native::<Decimal128Type>("123.4567891", 5)?,
-            12345679_i128
+            err.to_string(),
+            "Invalid argument error: 100000 is too large to store in a Decimal128 of precision 5. Max is 99999 at row 2"
         );
-        Ok(())
     }
 }
@@ -55,6 +55,53 @@ pub(crate) fn cast_map_values(
     )))
 }
 
+/// Reinterprets a map as a list of its entry structs, casting the entries to `to`
+pub(crate) fn cast_map_to_list<O: OffsetSizeTrait>(
+    from: &MapArray,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let values = cast_with_options(from.entries(), to.data_type(), cast_options)?;
+    let offsets = from.offsets().iter().map(|x| O::usize_as(x.as_usize()));
+    let offsets = OffsetBuffer::new(offsets.collect());
+    let list = GenericListArray::<O>::try_new(to.clone(), offsets, values, from.nulls().cloned())?;
+    Ok(Arc::new(list))
+}
+
+/// Reinterprets a list of structs as a map, casting the structs to the entries of `to_data_type`
+pub(crate) fn cast_list_to_map<O: OffsetSizeTrait>(
+    from: &GenericListArray<O>,
+    to_data_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let (entries_field, ordered) = if let DataType::Map(entries_field, ordered) = to_data_type {
+        (entries_field, *ordered)
+    } else {
+        return Err(ArrowError::CastError(
+            "Internal Error: to_data_type is not a map type.".to_string(),
+        ));
+    };
+
+    let entries = cast_with_options(from.values(), entries_field.data_type(), cast_options)?;
+    let offsets = from
+        .offsets()
+        .iter()
+        .map(|x| {
+            i32::try_from(x.as_usize()).map_err(|_| {
+                ArrowError::CastError("list offset overflow casting to map".to_string())
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Arc::new(MapArray::try_new(
+        entries_field.clone(),
+        OffsetBuffer::new(offsets.into()),
+        entries.as_struct().clone(),
+        from.nulls().cloned(),
+        ordered,
+    )?))
+}
+
 /// Gets the key field from the entries of a map.  For all other types returns None.
 pub(crate) fn key_field(entries_field: &FieldRef) -> Option<FieldRef> {
     if let DataType::Struct(fields) = entries_field.data_type() {
@@ -57,7 +57,7 @@ use std::sync::Arc;
 use crate::display::{ArrayFormatter, FormatOptions};
 use crate::parse::{
     parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
-    string_to_datetime, Parser,
+    string_to_datetime, string_to_datetime_with_format, Parser,
 };
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
 use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer};
@@ -75,6 +75,27 @@ pub struct CastOptions<'a> {
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// Optional [strftime]-style format used to parse strings when casting to timestamps,
+    /// see [`string_to_datetime_with_format`]. If `None`, the formats accepted by
+    /// [`string_to_datetime`] are supported
+    ///
+    /// [strftime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    pub timestamp_parse_format: Option<&'a str>,
+    /// Optional timezone in which to interpret strings without an explicit offset when
+    /// casting to timestamps. If `None`, the timezone of the target type is used, or UTC
+    /// if it has no timezone
+    pub default_timezone: Option<&'a str>,
+    /// Optional [`RoundingMode`] used when casting floating point values to integers and
+    /// when reducing the scale of decimals. If `None`, floating point values are truncated
+    /// and decimals are rounded with [`RoundingMode::HalfUp`]
+    pub rounding_mode: Option<RoundingMode>,
+    /// If `true`, the fields of a struct are matched by name when casting to another struct,
+    /// with nullable target fields missing from the source filled with nulls. Otherwise
+    /// fields are matched by position
+    ///
+    /// As [`can_cast_types`] does not take the options into account, it returns `true`
+    /// if the fields can be cast either by position or by name
+    pub match_struct_fields_by_name: bool,
 }
 
 impl Default for CastOptions<'_> {
@@ -82,10 +103,63 @@ impl Default for CastOptions<'_> {
         Self {
             safe: true,
             format_options: FormatOptions::default(),
+            timestamp_parse_format: None,
+            default_timezone: None,
+            rounding_mode: None,
+            match_struct_fields_by_name: false,
         }
     }
 }
 
+/// How to round values that cannot be represented exactly in the target type,
+/// see [`CastOptions::rounding_mode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards zero, discarding any fractional part
+    Truncate,
+    /// Round to the nearest value, with ties rounded away from zero
+    HalfUp,
+    /// Round to the nearest value, with ties rounded to the nearest even value
+    HalfEven,
+}
+
+impl RoundingMode {
+    fn round_f64(self, v: f64) -> f64 {
+        match self {
+            Self::Truncate => v.trunc(),
+            Self::HalfUp => v.round(),
+            Self::HalfEven if (v - v.trunc()).abs() == 0.5 => 2.0 * (v / 2.0).round(),
+            Self::HalfEven => v.round(),
+        }
+    }
+}
+
+/// Rounds the values of a floating point array to integral values using `mode`
+fn round_float_array(array: &dyn Array, mode: RoundingMode) -> Result<ArrayRef, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Float16 => Arc::new(
+            array
+                .as_primitive::<Float16Type>()
+                .unary::<_, Float16Type>(|v| half::f16::from_f64(mode.round_f64(v.to_f64()))),
+        ),
+        DataType::Float32 => Arc::new(
+            array
+                .as_primitive::<Float32Type>()
+                .unary::<_, Float32Type>(|v| mode.round_f64(v as f64) as f32),
+        ),
+        DataType::Float64 => Arc::new(
+            array
+                .as_primitive::<Float64Type>()
+                .unary::<_, Float64Type>(|v| mode.round_f64(v)),
+        ),
+        d => {
+            return Err(ArrowError::CastError(format!(
+                "Internal Error: cannot round {d}"
+            )))
+        }
+    })
+}
+
 /// Return true if a value of type `from_type` can be cast into a value of `to_type`.
 ///
 /// See [`cast_with_options`] for more information
@@ -141,6 +215,12 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (List(list_from) | LargeList(list_from), List(list_to) | LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
+        (List(list_from) | LargeList(list_from), Map(to_entries, _)) => {
+            can_cast_types(list_from.data_type(), to_entries.data_type())
+        }
+        (Map(from_entries, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(from_entries.data_type(), list_to.data_type())
+        }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => {
             can_cast_types(list_from.data_type(), to_type)
         }
@@ -187,12 +267,18 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         // string to decimal
         (Utf8View | Utf8 | LargeUtf8, Decimal128(_, _) | Decimal256(_, _)) => true,
         (Struct(from_fields), Struct(to_fields)) => {
-            from_fields.len() == to_fields.len() &&
+            let by_position = from_fields.len() == to_fields.len() &&
                 from_fields.iter().zip(to_fields.iter()).all(|(f1, f2)| {
                     // Assume that nullability between two structs are compatible, if not,
                     // cast kernel will return error.
                     can_cast_types(f1.data_type(), f2.data_type())
-                })
+                });
+            // See CastOptions::match_struct_fields_by_name
+            let by_name = || to_fields.iter().all(|f2| match from_fields.find(f2.name()) {
+                Some((_, f1)) => can_cast_types(f1.data_type(), f2.data_type()),
+                None => f2.is_nullable(),
+            });
+            by_position || by_name()
         }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
@@ -392,6 +478,9 @@ fn cast_interval_day_time_to_interval_month_day_nano(
 }
 
 /// Cast the array from interval to duration
+///
+/// Days are converted assuming they are 24 hours long, whereas months, which have no fixed
+/// duration, are truncated, or an error if non-zero and `cast_options.safe` is false
 fn cast_month_day_nano_to_duration<D: ArrowTemporalType<Native = i64>>(
     array: &dyn Array,
     cast_options: &CastOptions,
@@ -405,26 +494,31 @@ fn cast_month_day_nano_to_duration<D: ArrowTemporalType<Native = i64>>(
         _ => unreachable!(),
     };
 
+    let convert = |v: IntervalMonthDayNano| {
+        if v.months != 0 && !cast_options.safe {
+            return Err(ArrowError::ComputeError(
+                "Cannot convert interval containing non-zero months to duration".to_string(),
+            ));
+        }
+        // Cannot overflow, as days and nanoseconds are at most 32 and 64 bits
+        let nanos = v.days as i128 * NANOSECONDS_IN_DAY as i128 + v.nanoseconds as i128;
+        i64::try_from(nanos / scale as i128).map_err(|_| {
+            ArrowError::ComputeError(format!(
+                "Cannot cast to {}. Overflowing on {v:?}",
+                D::DATA_TYPE
+            ))
+        })
+    };
+
     if cast_options.safe {
-        let iter = array.iter().map(|v| {
-            v.and_then(|v| (v.days == 0 && v.months == 0).then_some(v.nanoseconds / scale))
-        });
+        let iter = array.iter().map(|v| v.and_then(|v| convert(v).ok()));
         Ok(Arc::new(unsafe {
             PrimitiveArray::<D>::from_trusted_len_iter(iter)
         }))
     } else {
         let vec = array
             .iter()
-            .map(|v| {
-                v.map(|v| match v.days == 0 && v.months == 0 {
-                    true => Ok((v.nanoseconds) / scale),
-                    _ => Err(ArrowError::ComputeError(
-                        "Cannot convert interval containing non-zero months or days to duration"
-                            .to_string(),
-                    )),
-                })
-                .transpose()
-            })
+            .map(|v| v.map(convert).transpose())
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Arc::new(unsafe {
             PrimitiveArray::<D>::from_trusted_len_iter(vec.iter())
@@ -580,6 +674,53 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
     Ok(Arc::new(array))
 }
 
+/// Cast a [`StructArray`] to a struct with `to_fields`, matching fields by name if
+/// [`CastOptions::match_struct_fields_by_name`] is set, and otherwise by position
+fn cast_struct_to_struct(
+    array: &StructArray,
+    from_fields: &Fields,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let columns = if cast_options.match_struct_fields_by_name {
+        to_fields
+            .iter()
+            .map(|to| match from_fields.find(to.name()) {
+                Some((idx, _)) => {
+                    cast_with_options(array.column(idx), to.data_type(), cast_options)
+                }
+                None if to.is_nullable() => Ok(new_null_array(to.data_type(), array.len())),
+                None => Err(ArrowError::CastError(format!(
+                    "Cannot cast struct: non-nullable field {:?} is missing from the source",
+                    to.name()
+                ))),
+            })
+            .collect::<Result<Vec<ArrayRef>, ArrowError>>()?
+    } else {
+        array
+            .columns()
+            .iter()
+            .zip(to_fields.iter())
+            .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
+            .collect::<Result<Vec<ArrayRef>, ArrowError>>()?
+    };
+    // Casts may produce a null buffer without any nulls, which is not permitted for
+    // the children of non-nullable fields
+    let columns = columns
+        .into_iter()
+        .zip(to_fields.iter())
+        .map(|(column, field)| match column.nulls() {
+            Some(n) if n.null_count() == 0 && !field.is_nullable() => {
+                let data = column.into_data().into_builder().nulls(None).build()?;
+                Ok(make_array(data))
+            }
+            _ => Ok(column),
+        })
+        .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+    let array = StructArray::try_new(to_fields.clone(), columns, array.nulls().cloned())?;
+    Ok(Arc::new(array))
+}
+
 /// Try to cast `array` to `to_type` if possible.
 ///
 /// Returns a new Array with type `to_type` if possible.
@@ -593,21 +734,30 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 /// * `Utf8` to Numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to `Boolean`: 0 returns `false`, any other value returns `true`
+/// * Floating point to integer: the fractional part is discarded, or rounded according
+///   to [`CastOptions::rounding_mode`]
 /// * `List` to `List`: the underlying data type is cast
 /// * `List` to `FixedSizeList`: the underlying data type is cast. If safe is true and a list element
 ///   has the wrong length it will be replaced with NULL, otherwise an error will be returned
 /// * Primitive to `List`: a list array with 1 value per slot is created
+/// * `Map` to `List` and `List` to `Map`: the map entries are reinterpreted as a list of
+///   entry structs, which are cast to the target struct type. Map keys must not be null
+/// * `Struct` to `Struct`: fields are matched by position, or by name if
+///   [`CastOptions::match_struct_fields_by_name`] is set
 /// * `Date32` and `Date64`: precision lost when going to higher interval
 /// * `Time32 and `Time64`: precision lost when going to higher interval
 /// * `Timestamp` and `Date{32|64}`: precision lost when going to higher interval
 /// * Temporal to/from backing Primitive: zero-copy with data type change
 /// * `Float32/Float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
 ///   (i.e. casting `6.4999` to `Decimal(10, 1)` becomes `6.5`).
+/// * `Interval(MonthDayNano)` to `Duration`: days are treated as 24 hours and precision
+///   lost when going to a coarser unit. Months are truncated, or an error if non-zero
+///   and `safe` is false
 ///
 /// Unsupported Casts (check with `can_cast_types` before calling):
-/// * To or from `StructArray`
+/// * Between `StructArray` and non-struct types
 /// * `List` to `Primitive`
-/// * `Interval` and `Duration`
+/// * `Interval(YearMonth)` and `Interval(DayTime)` to `Duration`
 ///
 /// # Timestamps and Timezones
 ///
@@ -765,10 +915,35 @@ pub fn cast_with_options(
                 "Casting from type {from_type:?} to dictionary type {to_type:?} not supported",
             ))),
         },
+        (
+            Float16 | Float32 | Float64,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64,
+        ) if matches!(
+            cast_options.rounding_mode,
+            Some(RoundingMode::HalfUp | RoundingMode::HalfEven)
+        ) =>
+        {
+            let rounded = round_float_array(array, cast_options.rounding_mode.unwrap())?;
+            let cast_options = CastOptions {
+                rounding_mode: Some(RoundingMode::Truncate),
+                ..cast_options.clone()
+            };
+            cast_with_options(&rounded, to_type, &cast_options)
+        }
         (List(_), List(to)) => cast_list_values::<i32>(array, to, cast_options),
         (LargeList(_), LargeList(to)) => cast_list_values::<i64>(array, to, cast_options),
         (List(_), LargeList(list_to)) => cast_list::<i32, i64>(array, list_to, cast_options),
         (LargeList(_), List(list_to)) => cast_list::<i64, i32>(array, list_to, cast_options),
+        (List(_), Map(_, _)) => cast_list_to_map(array.as_list::<i32>(), to_type, cast_options),
+        (LargeList(_), Map(_, _)) => {
+            cast_list_to_map(array.as_list::<i64>(), to_type, cast_options)
+        }
+        (Map(_, _), List(list_to)) => {
+            cast_map_to_list::<i32>(array.as_map(), list_to, cast_options)
+        }
+        (Map(_, _), LargeList(list_to)) => {
+            cast_map_to_list::<i64>(array.as_map(), list_to, cast_options)
+        }
         (List(_), FixedSizeList(field, size)) => {
             let array = array.as_list::<i32>();
             cast_list_to_fixed_size_list::<i32>(array, field, *size, cast_options)
@@ -1178,16 +1353,8 @@ pub fn cast_with_options(
                 ))),
             }
         }
-        (Struct(_), Struct(to_fields)) => {
-            let array = array.as_struct();
-            let fields = array
-                .columns()
-                .iter()
-                .zip(to_fields.iter())
-                .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
-                .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
-            let array = StructArray::try_new(to_fields.clone(), fields, array.nulls().cloned())?;
-            Ok(Arc::new(array) as ArrayRef)
+        (Struct(from_fields), Struct(to_fields)) => {
+            cast_struct_to_struct(array.as_struct(), from_fields, to_fields, cast_options)
         }
         (Struct(_), _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type:?} to {to_type:?} not supported"
@@ -2519,7 +2686,7 @@ mod tests {
 
             let cast_option = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -2753,7 +2920,7 @@ mod tests {
             &output_type,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -2773,7 +2940,7 @@ mod tests {
             &output_type,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
@@ -2812,7 +2979,7 @@ mod tests {
             &output_type,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -2831,7 +2998,7 @@ mod tests {
             &output_type,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -2983,7 +3150,7 @@ mod tests {
             &DataType::UInt8,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -2996,7 +3163,7 @@ mod tests {
             &DataType::UInt8,
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3010,7 +3177,7 @@ mod tests {
             &DataType::Int8,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3023,7 +3190,7 @@ mod tests {
             &DataType::Int8,
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3186,7 +3353,7 @@ mod tests {
             &DataType::Int8,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3199,7 +3366,7 @@ mod tests {
             &DataType::Int8,
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3616,7 +3783,7 @@ mod tests {
         // overflow with the error
         let cast_option = CastOptions {
             safe: false,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -3828,7 +3995,7 @@ mod tests {
             &DataType::Int32,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match result {
@@ -3867,7 +4034,7 @@ mod tests {
             &DataType::Boolean,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match casted {
@@ -4210,7 +4377,7 @@ mod tests {
 
         let options = CastOptions {
             safe: true,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let res = cast_with_options(&str, &DataType::Int16, &options).expect("should cast to i16");
         let expected =
@@ -4283,7 +4450,7 @@ mod tests {
 
                 let options = CastOptions {
                     safe: false,
-                    format_options: FormatOptions::default(),
+                    ..Default::default()
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -4302,6 +4469,75 @@ mod tests {
         assert_eq!(result.values(), &[247112596800]);
     }
 
+    #[test]
+    fn test_cast_string_to_timestamp_with_format() {
+        let values = vec![Some("03/04/2024 17:00"), None, Some("2024-04-03 17:00")];
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(values.clone())),
+            Arc::new(LargeStringArray::from(values.clone())),
+            Arc::new(StringViewArray::from(values)),
+        ];
+
+        let options = CastOptions {
+            timestamp_parse_format: Some("%d/%m/%Y %H:%M"),
+            ..Default::default()
+        };
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        for array in &arrays {
+            let result = cast_with_options(array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<TimestampSecondType>();
+            // 2024-04-03T17:00:00Z
+            assert_eq!(result.value(0), 1712163600);
+            assert!(result.is_null(1));
+            assert!(result.is_null(2));
+
+            let options = CastOptions {
+                safe: false,
+                ..options.clone()
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert!(
+                err.to_string().starts_with(
+                    "Parser error: Error parsing timestamp from '2024-04-03 17:00' with format '%d/%m/%Y %H:%M'"
+                ),
+                "{err}"
+            );
+        }
+
+        // Strings are interpreted in the default timezone, otherwise the target timezone
+        let array = StringArray::from(vec!["03/04/2024 17:00", "03/04/2024 17:00 -0100"]);
+        let options = CastOptions {
+            timestamp_parse_format: Some("%d/%m/%Y %H:%M"),
+            default_timezone: Some("+02:00"),
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<TimestampSecondType>();
+        assert_eq!(result.value(0), 1712163600 - 2 * 3600);
+        assert!(result.is_null(1));
+
+        let options = CastOptions {
+            timestamp_parse_format: Some("%d/%m/%Y %H:%M %z"),
+            ..Default::default()
+        };
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".into()));
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<TimestampSecondType>();
+        assert!(result.is_null(0));
+        assert_eq!(result.value(1), 1712163600 + 3600);
+        assert_eq!(result.timezone(), Some("+02:00"));
+
+        // The default timezone also applies without a format
+        let array = StringArray::from(vec!["2024-04-03 17:00:00"]);
+        let options = CastOptions {
+            default_timezone: Some("-01:00"),
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<TimestampSecondType>();
+        assert_eq!(result.value(0), 1712163600 + 3600);
+    }
+
     #[test]
     fn test_cast_string_to_date32() {
         let a0 = Arc::new(StringViewArray::from(vec![
@@ -4329,7 +4565,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4358,7 +4594,7 @@ mod tests {
             let to_type = DataType::Date32;
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options(&array, &to_type, &options).unwrap();
             let c = result.as_primitive::<Date32Type>();
@@ -4408,7 +4644,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -4450,7 +4686,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -4484,7 +4720,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -4518,7 +4754,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -4552,7 +4788,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4568,7 +4804,7 @@ mod tests {
 
             let options = CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
 
             let target_interval_array = cast_with_options(
@@ -4695,7 +4931,7 @@ mod tests {
             let string_array = Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
             let options = CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -4804,7 +5040,7 @@ mod tests {
             &DataType::FixedSizeBinary(5),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -4814,7 +5050,7 @@ mod tests {
             &DataType::FixedSizeBinary(5),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -4952,7 +5188,7 @@ mod tests {
         let array = TimestampSecondArray::from(vec![Some(i64::MAX)]);
         let options = CastOptions {
             safe: false,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -5310,6 +5546,7 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            ..Default::default()
         };
 
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
@@ -8208,7 +8445,7 @@ mod tests {
             &DataType::Decimal128(38, 30),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8219,7 +8456,7 @@ mod tests {
             &DataType::Decimal128(38, 30),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8234,7 +8471,7 @@ mod tests {
             &DataType::Decimal256(76, 76),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8245,7 +8482,7 @@ mod tests {
             &DataType::Decimal256(76, 76),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8260,7 +8497,7 @@ mod tests {
             &DataType::Decimal128(2, 2),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8271,7 +8508,7 @@ mod tests {
             &DataType::Decimal128(2, 2),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8291,7 +8528,7 @@ mod tests {
             &DataType::Decimal256(2, 2),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8302,7 +8539,7 @@ mod tests {
             &DataType::Decimal256(2, 2),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8322,7 +8559,7 @@ mod tests {
             &DataType::Decimal128(38, 30),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8333,7 +8570,7 @@ mod tests {
             &DataType::Decimal128(38, 30),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8353,7 +8590,7 @@ mod tests {
             &DataType::Decimal256(76, 50),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8364,7 +8601,7 @@ mod tests {
             &DataType::Decimal256(76, 50),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8482,95 +8719,33 @@ mod tests {
 
     #[test]
     fn test_parse_string_to_decimal() {
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("123.45", 2).unwrap(),
-                38,
-                2,
-            ),
-            "123.45"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "12345.00"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("0.12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.12"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.12"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".1265", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.13"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".1265", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.13"
-        );
-
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("123.45", 3).unwrap(),
-                38,
-                3,
-            ),
-            "123.450"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "12345.000"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("0.12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.123"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>(".12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.123"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>(".1265", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.127"
-        );
+        let cases = [
+            ("123.45", DataType::Decimal128(38, 2), "123.45"),
+            ("12345", DataType::Decimal128(38, 2), "12345.00"),
+            ("0.12345", DataType::Decimal128(38, 2), "0.12"),
+            (".12345", DataType::Decimal128(38, 2), "0.12"),
+            (".1265", DataType::Decimal128(38, 2), "0.13"),
+            ("0", DataType::Decimal128(38, 0), "0"),
+            ("0", DataType::Decimal128(38, 5), "0.00000"),
+            ("123", DataType::Decimal128(38, 0), "123"),
+            ("123.4567891", DataType::Decimal128(38, 0), "123"),
+            ("123.4567891", DataType::Decimal128(38, 5), "123.45679"),
+            ("123.45", DataType::Decimal256(38, 3), "123.450"),
+            ("12345", DataType::Decimal256(38, 3), "12345.000"),
+            ("0.12345", DataType::Decimal256(38, 3), "0.123"),
+            (".12345", DataType::Decimal256(38, 3), "0.123"),
+            (".1265", DataType::Decimal256(38, 3), "0.127"),
+        ];
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        for (value, data_type, expected) in cases {
+            let array = StringArray::from(vec![value]);
+            let casted = cast_with_options(&array, &data_type, &options).unwrap();
+            let actual = crate::display::array_value_to_string(&casted, 0).unwrap();
+            assert_eq!(actual, expected, "{value} as {data_type}");
+        }
     }
 
     fn test_cast_string_to_decimal(array: ArrayRef) {
@@ -8828,19 +9003,19 @@ mod tests {
         let array = Arc::new(str_array) as ArrayRef;
         let option = CastOptions {
             safe: false,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
             .to_string()
-            .contains("Cannot cast string '4.4.5' to value of Decimal128(38, 10) type"));
+            .contains("Cannot cast string '4.4.5' to value of Decimal128(38, 2) type"));
 
         let str_array = StringArray::from(vec![". 0.123"]);
         let array = Arc::new(str_array) as ArrayRef;
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
             .to_string()
-            .contains("Cannot cast string '. 0.123' to value of Decimal128(38, 10) type"));
+            .contains("Cannot cast string '. 0.123' to value of Decimal128(38, 2) type"));
     }
 
     fn test_cast_string_to_decimal128_overflow(overflow_array: ArrayRef) {
@@ -8870,7 +9045,7 @@ mod tests {
             &DataType::Decimal128(10, 8),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8881,10 +9056,10 @@ mod tests {
             &DataType::Decimal128(10, 8),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
-        assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
+        assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999 at row 0", err.unwrap_err().to_string());
     }
 
     #[test]
@@ -8953,7 +9128,7 @@ mod tests {
             &DataType::Decimal256(10, 8),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8964,10 +9139,10 @@ mod tests {
             &DataType::Decimal256(10, 8),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
-        assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
+        assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999 at row 0", err.unwrap_err().to_string());
     }
 
     #[test]
@@ -9010,7 +9185,7 @@ mod tests {
 
         let cast_options = CastOptions {
             safe: false,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         let result = cast_string_to_timestamp::<i32, TimestampNanosecondType>(
@@ -9138,7 +9313,7 @@ mod tests {
                 &DataType::Timestamp(TimeUnit::Nanosecond, Some(tz.clone())),
                 &CastOptions {
                     safe: false,
-                    format_options: FormatOptions::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -9189,7 +9364,7 @@ mod tests {
         let s = BinaryArray::from(vec![v1, v2]);
         let options = CastOptions {
             safe: true,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -9321,7 +9496,7 @@ mod tests {
             &DataType::Decimal128(7, 3),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -9332,7 +9507,7 @@ mod tests {
             &DataType::Decimal128(7, 3),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9347,7 +9522,7 @@ mod tests {
             &DataType::Decimal256(7, 3),
             &CastOptions {
                 safe: true,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -9358,7 +9533,7 @@ mod tests {
             &DataType::Decimal256(7, 3),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9405,7 +9580,7 @@ mod tests {
             array,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9438,7 +9613,7 @@ mod tests {
             array,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9471,7 +9646,7 @@ mod tests {
             array,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9497,7 +9672,7 @@ mod tests {
             array,
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -9527,7 +9702,7 @@ mod tests {
         let nullable = CastOptions::default();
         let fallible = CastOptions {
             safe: false,
-            format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let v = IntervalMonthDayNano::new(0, 0, 1234567);
 
@@ -9537,10 +9712,12 @@ mod tests {
             cast_from_interval_to_duration(&array, &nullable).unwrap();
         assert_eq!(casted_array.value(0), 0);
 
+        // Seconds and milliseconds cannot overflow once the months are truncated
         let array = vec![IntervalMonthDayNano::MAX].into();
         let casted_array: DurationSecondArray =
             cast_from_interval_to_duration(&array, &nullable).unwrap();
-        assert!(!casted_array.is_valid(0));
+        let expected = i32::MAX as i64 * 86_400 + i64::MAX / 1_000_000_000;
+        assert_eq!(casted_array.value(0), expected);
 
         let res = cast_from_interval_to_duration::<DurationSecondType>(&array, &fallible);
         assert!(res.is_err());
@@ -9554,7 +9731,8 @@ mod tests {
         let array = vec![IntervalMonthDayNano::MAX].into();
         let casted_array: DurationMillisecondArray =
             cast_from_interval_to_duration(&array, &nullable).unwrap();
-        assert!(!casted_array.is_valid(0));
+        let expected = i32::MAX as i64 * 86_400_000 + i64::MAX / 1_000_000;
+        assert_eq!(casted_array.value(0), expected);
 
         let res = cast_from_interval_to_duration::<DurationMillisecondType>(&array, &fallible);
         assert!(res.is_err());
@@ -9595,16 +9773,90 @@ mod tests {
             IntervalMonthDayNanoType::make_value(1, 1, 0),
             IntervalMonthDayNanoType::make_value(1, 0, 1),
             IntervalMonthDayNanoType::make_value(0, 0, -1),
+            IntervalMonthDayNanoType::make_value(0, -2, 1),
         ]
         .into();
         let casted_array =
             cast_from_interval_to_duration::<DurationNanosecondType>(&array, &nullable).unwrap();
-        assert!(!casted_array.is_valid(0));
-        assert!(!casted_array.is_valid(1));
-        assert!(!casted_array.is_valid(2));
-        assert!(!casted_array.is_valid(3));
-        assert!(casted_array.is_valid(4));
+        assert_eq!(casted_array.null_count(), 0);
+        assert_eq!(casted_array.value(0), NANOSECONDS_IN_DAY);
+        // Months are truncated
+        assert_eq!(casted_array.value(1), 0);
+        assert_eq!(casted_array.value(2), NANOSECONDS_IN_DAY);
+        assert_eq!(casted_array.value(3), 1);
         assert_eq!(casted_array.value(4), -1);
+        assert_eq!(casted_array.value(5), -2 * NANOSECONDS_IN_DAY + 1);
+
+        let err = cast_from_interval_to_duration::<DurationNanosecondType>(&array, &fallible)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot convert interval containing non-zero months to duration"
+        );
+    }
+
+    #[test]
+    fn test_cast_interval_with_days_to_duration() {
+        let fallible = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        // Intervals with a days component, as produced from Avro durations
+        let array: IntervalMonthDayNanoArray = vec![
+            Some(IntervalMonthDayNano::new(0, 2, 3_500_000_000)),
+            None,
+            Some(IntervalMonthDayNano::new(0, -1, -1_500_000_000)),
+        ]
+        .into();
+
+        let s: DurationSecondArray = cast_from_interval_to_duration(&array, &fallible).unwrap();
+        assert_eq!(
+            s.iter().collect::<Vec<_>>(),
+            vec![Some(172_803), None, Some(-86_401)]
+        );
+
+        let ms: DurationMillisecondArray =
+            cast_from_interval_to_duration(&array, &fallible).unwrap();
+        assert_eq!(
+            ms.iter().collect::<Vec<_>>(),
+            vec![Some(172_803_500), None, Some(-86_401_500)]
+        );
+
+        // Round trips via duration
+        let back = cast_with_options(&ms, array.data_type(), &fallible).unwrap();
+        let back = back.as_primitive::<IntervalMonthDayNanoType>();
+        assert_eq!(
+            back.value(0),
+            IntervalMonthDayNano::new(0, 0, 172_803_500_000_000)
+        );
+        assert!(back.is_null(1));
+
+        // Overflow
+        let array: IntervalMonthDayNanoArray =
+            vec![IntervalMonthDayNano::new(0, i32::MAX, 0)].into();
+        let ns: DurationNanosecondArray =
+            cast_from_interval_to_duration(&array, &CastOptions::default()).unwrap();
+        assert!(ns.is_null(0));
+        let err = cast_from_interval_to_duration::<DurationNanosecondType>(&array, &fallible)
+            .unwrap_err();
+        assert!(err.to_string().contains("Overflowing"), "{err}");
+        let s: DurationSecondArray = cast_from_interval_to_duration(&array, &fallible).unwrap();
+        assert_eq!(s.value(0), i32::MAX as i64 * 86_400);
+
+        // The total is computed before converting, so components of opposite signs offset
+        let array: IntervalMonthDayNanoArray = vec![
+            IntervalMonthDayNano::new(0, 1, -1),
+            IntervalMonthDayNano::new(0, 106_752, -NANOSECONDS_IN_DAY),
+        ]
+        .into();
+        let s: DurationSecondArray = cast_from_interval_to_duration(&array, &fallible).unwrap();
+        assert_eq!(s.values(), &[86_399, 106_751 * 86_400]);
+        let ns: DurationNanosecondArray =
+            cast_from_interval_to_duration(&array, &fallible).unwrap();
+        assert_eq!(
+            ns.values(),
+            &[NANOSECONDS_IN_DAY - 1, 106_751 * NANOSECONDS_IN_DAY]
+        );
     }
 
     /// helper function to test casting from interval year month to interval month day nano
@@ -9694,7 +9946,7 @@ mod tests {
             &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
             &CastOptions {
                 safe: false,
-                format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -9756,6 +10008,10 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        timestamp_parse_format: None,
+        default_timezone: None,
+        rounding_mode: None,
+        match_struct_fields_by_name: false,
     };
 
     #[test]
@@ -9764,11 +10020,83 @@ mod tests {
         assert!(CAST_OPTIONS.safe)
     }
 
+    #[test]
+    fn test_cast_float_to_int_rounding_mode() {
+        let values = [2.5, 3.5, -2.5, 1.4, -1.6];
+        let cases = [
+            (None, [2, 3, -2, 1, -1]),
+            (Some(RoundingMode::Truncate), [2, 3, -2, 1, -1]),
+            (Some(RoundingMode::HalfUp), [3, 4, -3, 1, -2]),
+            (Some(RoundingMode::HalfEven), [2, 4, -2, 1, -2]),
+        ];
+        let f16_array = Float16Array::from_iter_values(values.iter().map(|v| f16::from_f64(*v)));
+        let f32_array = Float32Array::from_iter_values(values.iter().map(|v| *v as f32));
+        let f64_array = Float64Array::from_iter_values(values);
+
+        for (rounding_mode, expected) in cases {
+            let options = CastOptions {
+                rounding_mode,
+                ..Default::default()
+            };
+            let expected = Int32Array::from_iter_values(expected);
+            for array in [&f16_array as &dyn Array, &f32_array, &f64_array] {
+                let casted = cast_with_options(array, &DataType::Int32, &options).unwrap();
+                assert_eq!(casted.as_primitive::<Int32Type>(), &expected);
+            }
+        }
+
+        // out of range values are still null or an error
+        let array = Float64Array::from(vec![Some(255.5), None]);
+        let options = CastOptions {
+            rounding_mode: Some(RoundingMode::HalfUp),
+            ..Default::default()
+        };
+        let casted = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        assert_eq!(casted.null_count(), 2);
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        cast_with_options(&array, &DataType::UInt8, &options).unwrap_err();
+    }
+
+    #[test]
+    fn test_cast_decimal_rescale_rounding_mode() {
+        let values = [125, 135, -125, 124, -126];
+        let cases: [(_, [i128; 5]); 4] = [
+            (None, [13, 14, -13, 12, -13]),
+            (Some(RoundingMode::Truncate), [12, 13, -12, 12, -12]),
+            (Some(RoundingMode::HalfUp), [13, 14, -13, 12, -13]),
+            (Some(RoundingMode::HalfEven), [12, 14, -12, 12, -13]),
+        ];
+        let array = Decimal128Array::from_iter_values(values)
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+
+        for (rounding_mode, expected) in cases {
+            let options = CastOptions {
+                rounding_mode,
+                ..Default::default()
+            };
+
+            let casted = cast_with_options(&array, &DataType::Decimal128(10, 1), &options).unwrap();
+            assert_eq!(casted.as_primitive::<Decimal128Type>().values(), &expected);
+
+            let casted = cast_with_options(&array, &DataType::Decimal256(10, 1), &options).unwrap();
+            let expected_256 = expected.map(i256::from_i128);
+            assert_eq!(
+                casted.as_primitive::<Decimal256Type>().values(),
+                &expected_256
+            );
+        }
+    }
+
     #[test]
     fn test_list_format_options() {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            ..Default::default()
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -9966,6 +10294,211 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_struct_to_struct_by_name() {
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as ArrayRef,
+            ),
+        ]);
+
+        let options = CastOptions {
+            match_struct_fields_by_name: true,
+            ..Default::default()
+        };
+
+        // reordered fields with an additional nullable field
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("b", DataType::Utf8, true),
+                Field::new("c", DataType::Float64, true),
+                Field::new("a", DataType::Int64, false),
+            ]
+            .into(),
+        );
+        let casted = cast_with_options(&struct_array, &to_type, &options).unwrap();
+        let casted = casted.as_struct();
+        assert_eq!(casted.data_type(), &to_type);
+        assert_eq!(
+            casted.column(0).as_string::<i32>(),
+            &StringArray::from(vec![Some("x"), None, Some("z")])
+        );
+        assert_eq!(casted.column(1).null_count(), 3);
+        assert_eq!(
+            casted.column(2).as_primitive::<Int64Type>().values(),
+            &[1, 2, 3]
+        );
+
+        assert!(can_cast_types(struct_array.data_type(), &to_type));
+
+        // missing non-nullable field
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("c", DataType::Float64, false),
+            ]
+            .into(),
+        );
+        let err = cast_with_options(&struct_array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct: non-nullable field \"c\" is missing from the source"
+        );
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("b", DataType::Utf8, true),
+                Field::new("c", DataType::Float64, false),
+            ]
+            .into(),
+        );
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+
+        // fields are matched by position by default
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("b", DataType::Int64, false),
+                Field::new("a", DataType::Utf8, true),
+            ]
+            .into(),
+        );
+        let casted = cast(&struct_array, &to_type).unwrap();
+        assert_eq!(
+            casted
+                .as_struct()
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values(),
+            &[1, 2, 3]
+        );
+    }
+
+    fn string_int_map_type(value_nullable: bool) -> DataType {
+        DataType::Map(
+            Arc::new(Field::new(
+                "entries",
+                DataType::Struct(
+                    vec![
+                        Field::new("keys", DataType::Utf8, false),
+                        Field::new("values", DataType::Int32, value_nullable),
+                    ]
+                    .into(),
+                ),
+                false,
+            )),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_cast_map_to_list() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let entry_type = DataType::Struct(
+            vec![
+                Field::new("keys", DataType::LargeUtf8, false),
+                Field::new("values", DataType::Int64, true),
+            ]
+            .into(),
+        );
+        let to_type = DataType::LargeList(Arc::new(Field::new_list_field(entry_type, false)));
+        assert!(can_cast_types(map.data_type(), &to_type));
+
+        let list = cast(&map, &to_type).unwrap();
+        assert_eq!(list.data_type(), &to_type);
+        let list = list.as_list::<i64>();
+        assert_eq!(list.value_offsets(), &[0, 2, 2, 3]);
+        assert!(list.is_null(1));
+        let entries = list.values().as_struct();
+        assert_eq!(
+            entries.column(0).as_string::<i64>(),
+            &LargeStringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            entries.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(1), None, Some(3)])
+        );
+
+        // and back again
+        let map_type = string_int_map_type(true);
+        assert!(can_cast_types(&to_type, &map_type));
+        let round_trip = cast(&list, &map_type).unwrap();
+        assert_eq!(round_trip.as_map(), &map);
+    }
+
+    #[test]
+    fn test_cast_list_to_map() {
+        let entry_fields = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, true),
+            Field::new("values", DataType::Utf8, true),
+        ]);
+        let entries = StructArray::new(
+            entry_fields.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["a", "b"])),
+                Arc::new(StringArray::from(vec!["1", "2"])),
+            ],
+            None,
+        );
+        let field = Arc::new(Field::new_list_field(
+            DataType::Struct(entry_fields.clone()),
+            false,
+        ));
+        let list = ListArray::new(
+            field.clone(),
+            OffsetBuffer::from_lengths([2]),
+            Arc::new(entries),
+            None,
+        );
+
+        let map_type = string_int_map_type(false);
+        assert!(can_cast_types(list.data_type(), &map_type));
+        let map = cast(&list, &map_type).unwrap();
+        let map = map.as_map();
+        assert_eq!(map.value_length(0), 2);
+        assert_eq!(
+            map.keys().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b"])
+        );
+        assert_eq!(map.values().as_primitive::<Int32Type>().values(), &[1, 2]);
+
+        // null keys are not permitted
+        let entries = StructArray::new(
+            entry_fields,
+            vec![
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+                Arc::new(StringArray::from(vec!["1", "2"])),
+            ],
+            None,
+        );
+        let list = ListArray::new(
+            field,
+            OffsetBuffer::from_lengths([2]),
+            Arc::new(entries),
+            None,
+        );
+        cast(&list, &map_type).unwrap_err();
+
+        // list elements must be structs
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert!(!can_cast_types(list.data_type(), &map_type));
+    }
+
     #[test]
     fn test_decimal_to_decimal_throw_error_on_precision_overflow_same_scale() {
         let array = vec![Some(123456789)];
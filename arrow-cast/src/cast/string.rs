@@ -128,9 +128,10 @@ pub(crate) fn cast_string_to_timestamp<O: OffsetSizeTrait, T: ArrowTimestampType
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let array = array.as_string::<O>();
-    let out: PrimitiveArray<T> = match to_tz {
+    let tz = cast_options.default_timezone.or(to_tz.as_deref());
+    let out: PrimitiveArray<T> = match tz {
         Some(tz) => {
-            let tz: Tz = tz.as_ref().parse()?;
+            let tz: Tz = tz.parse()?;
             cast_string_to_timestamp_impl(array.iter(), &tz, cast_options)?
         }
         None => cast_string_to_timestamp_impl(array.iter(), &Utc, cast_options)?,
@@ -145,9 +146,10 @@ pub(crate) fn cast_view_to_timestamp<T: ArrowTimestampType>(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let array = array.as_string_view();
-    let out: PrimitiveArray<T> = match to_tz {
+    let tz = cast_options.default_timezone.or(to_tz.as_deref());
+    let out: PrimitiveArray<T> = match tz {
         Some(tz) => {
-            let tz: Tz = tz.as_ref().parse()?;
+            let tz: Tz = tz.parse()?;
             cast_string_to_timestamp_impl(array.iter(), &tz, cast_options)?
         }
         None => cast_string_to_timestamp_impl(array.iter(), &Utc, cast_options)?,
@@ -165,6 +167,11 @@ fn cast_string_to_timestamp_impl<
     tz: &Tz,
     cast_options: &CastOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
+    let string_to_datetime = |tz: &Tz, v: &str| match cast_options.timestamp_parse_format {
+        Some(format) => string_to_datetime_with_format(tz, v, format),
+        None => string_to_datetime(tz, v),
+    };
+
     if cast_options.safe {
         let iter = iter.map(|v| {
             v.and_then(|v| {
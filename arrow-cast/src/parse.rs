@@ -227,6 +227,57 @@ pub fn string_to_datetime<T: TimeZone>(timezone: &T, s: &str) -> Result<DateTime
     Ok(parsed.with_timezone(timezone))
 }
 
+/// Accepts a string and parses it according to the [strftime]-style `format`,
+/// relative to the provided `timezone`
+///
+/// If `format` contains an offset specifier, such as `%z`, the parsed offset is used,
+/// otherwise the timestamp is interpreted as a local time in `timezone`. If `format`
+/// only contains a date, the time is midnight.
+///
+/// ```
+/// # use arrow_cast::parse::string_to_datetime_with_format;
+/// # use chrono::{FixedOffset, TimeZone, Utc};
+/// let parsed = string_to_datetime_with_format(&Utc, "03/04/2024 17:00", "%d/%m/%Y %H:%M").unwrap();
+/// assert_eq!(parsed, Utc.with_ymd_and_hms(2024, 4, 3, 17, 0, 0).unwrap());
+///
+/// let tz = FixedOffset::east_opt(3600).unwrap();
+/// let parsed = string_to_datetime_with_format(&tz, "2024-04-03", "%Y-%m-%d").unwrap();
+/// assert_eq!(parsed, tz.with_ymd_and_hms(2024, 4, 3, 0, 0, 0).unwrap());
+/// ```
+///
+/// If a timestamp is ambiguous, for example as a result of daylight-savings time, an error
+/// will be returned
+///
+/// [strftime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+pub fn string_to_datetime_with_format<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    format: &str,
+) -> Result<DateTime<T>, ArrowError> {
+    let err = |ctx: &dyn std::fmt::Display| {
+        ArrowError::ParseError(format!(
+            "Error parsing timestamp from '{s}' with format '{format}': {ctx}"
+        ))
+    };
+
+    if let Ok(parsed) = DateTime::parse_from_str(s, format) {
+        return Ok(parsed.with_timezone(timezone));
+    }
+
+    let naive = match NaiveDateTime::parse_from_str(s, format) {
+        Ok(naive) => naive,
+        Err(e) => match NaiveDate::parse_from_str(s, format) {
+            Ok(date) => date.and_time(NaiveTime::MIN),
+            Err(_) => return Err(err(&e)),
+        },
+    };
+
+    timezone
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or_else(|| err(&"error computing timezone offset"))
+}
+
 /// Accepts a string in RFC3339 / ISO8601 standard format and some
 /// variants and converts it to a nanosecond precision timestamp.
 ///
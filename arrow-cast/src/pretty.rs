@@ -328,6 +328,41 @@ mod tests {
         assert_eq!(expected, actual, "Actual result:\n{table}");
     }
 
+    #[test]
+    fn test_pretty_format_list_view() {
+        // [[1, 2], null, [], [2, 3]] with overlapping values
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let array = ListViewArray::new(
+            field.clone(),
+            vec![0, 0, 0, 1].into(),
+            vec![2, 0, 0, 2].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Some(vec![true, false, true, true].into()),
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "d1",
+            DataType::ListView(field),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(array)]).unwrap();
+        let table = pretty_format_batches(&[batch]).unwrap().to_string();
+        let expected = vec![
+            "+--------+",
+            "| d1     |",
+            "+--------+",
+            "| [1, 2] |",
+            "|        |",
+            "| []     |",
+            "| [2, 3] |",
+            "+--------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
     #[test]
     fn test_pretty_format_string_view() {
         let schema = Arc::new(Schema::new(vec![Field::new(
@@ -0,0 +1,500 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An async CSV reader that parses chunks of the input in parallel
+
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_schema::ArrowError;
+use futures::stream::{self, BoxStream};
+use futures::{Stream, StreamExt, TryStreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::reader::{Format, ReaderBuilder};
+
+/// The default size in bytes of the chunks parsed in parallel
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Builder for [`AsyncReader`]
+///
+/// The input is split into chunks of approximately [`Self::with_chunk_size`] bytes,
+/// aligned to record boundaries, which are then parsed in parallel on tokio's blocking
+/// thread pool. Batches are yielded in the order they appear in the input.
+///
+/// As each chunk is parsed independently, batches do not span chunks, and so the final
+/// batch of each chunk may contain fewer than the configured batch size rows.
+/// Similarly, line numbers reported in errors and to a bad line handler are relative to
+/// the start of the chunk.
+///
+/// Record boundaries are found by scanning for the terminator outside of quoted fields,
+/// and therefore escaped quote characters, i.e. those preceded by the escape character,
+/// are not supported. As with [`ReaderBuilder`], without a custom terminator `\r\n`,
+/// `\n` and `\r` all terminate a record.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_csv::reader::{AsyncReaderBuilder, ReaderBuilder};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "multi_thread")]
+/// # async fn main() {
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("city", DataType::Utf8, false),
+///     Field::new("lat", DataType::Float64, false),
+///     Field::new("lng", DataType::Float64, false),
+/// ]));
+///
+/// let file = tokio::fs::File::open("test/data/uk_cities.csv").await.unwrap();
+/// let reader = AsyncReaderBuilder::new(ReaderBuilder::new(schema))
+///     .with_chunk_size(1024)
+///     .build(file)
+///     .unwrap();
+///
+/// let batches: Vec<_> = reader.try_collect().await.unwrap();
+/// let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+/// assert_eq!(rows, 37);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncReaderBuilder {
+    builder: ReaderBuilder,
+    chunk_size: usize,
+    concurrency: usize,
+}
+
+impl AsyncReaderBuilder {
+    /// Create a new [`AsyncReaderBuilder`] parsing each chunk with `builder`
+    pub fn new(builder: ReaderBuilder) -> Self {
+        Self {
+            builder,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            concurrency: std::thread::available_parallelism()
+                .map(|x| x.get())
+                .unwrap_or(1),
+        }
+    }
+
+    /// Set the approximate size in bytes of each chunk, defaults to 8 MiB
+    ///
+    /// Chunks are extended to the end of the record containing the `chunk_size` byte
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Set the maximum number of chunks to parse concurrently, defaults to the
+    /// available parallelism
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Create a new [`AsyncReader`] from `reader`
+    ///
+    /// Returns an error if the [`ReaderBuilder`] has bounds, see [`ReaderBuilder::with_bounds`],
+    /// as these cannot be applied to the individual chunks
+    pub fn build<R: AsyncRead + Unpin + Send + 'static>(
+        self,
+        reader: R,
+    ) -> Result<AsyncReader, ArrowError> {
+        if self.builder.bounds.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "AsyncReader does not support bounds".to_string(),
+            ));
+        }
+
+        let terminator = self.builder.format.terminator;
+        let quote = self.builder.format.quote.unwrap_or(b'"');
+        let chunks = Chunker {
+            reader,
+            buffer: Vec::with_capacity(self.chunk_size),
+            chunk_size: self.chunk_size,
+            terminator,
+            quote,
+            comment: self.builder.format.comment,
+            scan: ScanState::default(),
+            eof: false,
+        };
+
+        let builder = self.builder;
+        let stream = chunks
+            .into_stream()
+            .enumerate()
+            .map(move |(idx, chunk)| {
+                let builder = match idx {
+                    0 => builder.clone(),
+                    // Only the first chunk contains the header
+                    _ => ReaderBuilder {
+                        format: Format {
+                            header: false,
+                            ..builder.format.clone()
+                        },
+                        ..builder.clone()
+                    },
+                };
+                async move {
+                    let chunk = chunk?;
+                    tokio::task::spawn_blocking(move || decode_chunk(builder, &chunk))
+                        .await
+                        .map_err(|e| ArrowError::ExternalError(Box::new(e)))?
+                }
+            })
+            .buffered(self.concurrency)
+            .map_ok(|batches| stream::iter(batches.into_iter().map(Ok)))
+            .try_flatten();
+
+        Ok(AsyncReader {
+            stream: stream.boxed(),
+        })
+    }
+}
+
+/// An async CSV reader that parses chunks of the input in parallel
+///
+/// See [`AsyncReaderBuilder`]
+pub struct AsyncReader {
+    stream: BoxStream<'static, Result<RecordBatch, ArrowError>>,
+}
+
+impl Debug for AsyncReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncReader").finish_non_exhaustive()
+    }
+}
+
+impl Stream for AsyncReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+/// Decodes a chunk containing only complete records
+fn decode_chunk(builder: ReaderBuilder, mut chunk: &[u8]) -> Result<Vec<RecordBatch>, ArrowError> {
+    let mut decoder = builder.build_decoder();
+    let mut batches = vec![];
+    loop {
+        let decoded = decoder.decode(chunk)?;
+        chunk = &chunk[decoded..];
+        if decoded == 0 || decoder.capacity() == 0 {
+            match decoder.flush()? {
+                Some(batch) => batches.push(batch),
+                None if decoded == 0 => break,
+                None => {}
+            }
+        }
+    }
+    Ok(batches)
+}
+
+/// Splits an [`AsyncRead`] into chunks aligned to record boundaries
+struct Chunker<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    /// The record terminator, or `None` for any of `\r\n`, `\n` and `\r`
+    terminator: Option<u8>,
+    quote: u8,
+    comment: Option<u8>,
+    /// The progress of the search for a record boundary in `buffer`
+    scan: ScanState,
+    eof: bool,
+}
+
+/// The state of the search for a record boundary, allowing it to resume as
+/// further input is read
+#[derive(Debug)]
+struct ScanState {
+    /// The offset in the buffer of the next byte to scan
+    offset: usize,
+    /// Whether the next byte is within a quoted field
+    quoted: bool,
+    /// Whether the next byte is within a comment line
+    comment: bool,
+    /// Whether the next byte is the first of a line
+    line_start: bool,
+}
+
+impl Default for ScanState {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            quoted: false,
+            comment: false,
+            line_start: true,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> Chunker<R> {
+    fn into_stream(self) -> BoxStream<'static, Result<Vec<u8>, ArrowError>> {
+        stream::try_unfold(self, |mut this| async move {
+            let chunk = this.next_chunk().await?;
+            Ok(chunk.map(|chunk| (chunk, this)))
+        })
+        .boxed()
+    }
+
+    /// Reads the next chunk, returning `None` once the input is exhausted
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, ArrowError> {
+        loop {
+            if self.buffer.len() >= self.chunk_size {
+                if let Some(end) = self.record_boundary() {
+                    let remainder = self.buffer.split_off(end);
+                    self.scan = ScanState::default();
+                    return Ok(Some(std::mem::replace(&mut self.buffer, remainder)));
+                }
+            }
+
+            if self.eof {
+                return Ok((!self.buffer.is_empty()).then(|| std::mem::take(&mut self.buffer)));
+            }
+
+            let to_read = self.chunk_size.saturating_sub(self.buffer.len()).max(4096);
+            let read = (&mut self.reader)
+                .take(to_read as u64)
+                .read_to_end(&mut self.buffer)
+                .await?;
+            self.eof = read == 0;
+        }
+    }
+
+    /// Returns the offset after the first record terminator at or after the
+    /// `chunk_size` byte, if any
+    ///
+    /// Scanning resumes from where the previous call stopped, as quotes must be
+    /// tracked from the start of the chunk. Quotes within comment lines are ignored
+    fn record_boundary(&mut self) -> Option<usize> {
+        let target = self.chunk_size - 1;
+        let scan = &mut self.scan;
+        while let Some(b) = self.buffer.get(scan.offset).copied() {
+            let idx = scan.offset;
+            let is_terminator = match self.terminator {
+                Some(t) => b == t,
+                // A `\r` followed by `\n` is terminated by the `\n`
+                None if b == b'\r' && !scan.quoted => match self.buffer.get(idx + 1) {
+                    Some(next) => *next != b'\n',
+                    // Wait for the next byte, the remainder is a chunk at the end of the input
+                    None => return None,
+                },
+                None => b == b'\n',
+            };
+            scan.offset += 1;
+
+            let line_start = std::mem::replace(&mut scan.line_start, false);
+            if line_start && Some(b) == self.comment {
+                scan.comment = true;
+            }
+            if b == self.quote && !scan.comment {
+                scan.quoted = !scan.quoted;
+            } else if is_terminator && !scan.quoted {
+                scan.comment = false;
+                scan.line_start = true;
+                if idx >= target {
+                    return Some(idx + 1);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("text", DataType::Utf8, false),
+        ]))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_read() {
+        let mut csv = "id,text\n".to_string();
+        for i in 0..1000 {
+            match i % 3 {
+                0 => csv.push_str(&format!("{i},\"multi\nline\"\n")),
+                1 => csv.push_str(&format!("{i},\"quoted \"\" ,\"\n")),
+                _ => csv.push_str(&format!("{i},plain\n")),
+            }
+        }
+
+        for chunk_size in [1, 7, 100, 1024, 1 << 20] {
+            let reader = AsyncReaderBuilder::new(
+                ReaderBuilder::new(schema())
+                    .with_header(true)
+                    .with_batch_size(64),
+            )
+            .with_chunk_size(chunk_size)
+            .with_concurrency(4)
+            .build(std::io::Cursor::new(csv.clone()))
+            .unwrap();
+
+            let batches: Vec<_> = reader.try_collect().await.unwrap();
+            assert!(batches.iter().all(|b| b.num_rows() <= 64));
+
+            let ids: Vec<_> = batches
+                .iter()
+                .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+                .collect();
+            assert_eq!(ids, (0..1000).collect::<Vec<_>>());
+
+            let text: Vec<_> = batches
+                .iter()
+                .flat_map(|b| b.column(1).as_string::<i32>().iter().flatten())
+                .collect();
+            assert_eq!(text[0], "multi\nline");
+            assert_eq!(text[1], "quoted \" ,");
+            assert_eq!(text[2], "plain");
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_read_error() {
+        let csv = "1,a\n2,b\nc,3\n4,d\n";
+        let reader = AsyncReaderBuilder::new(ReaderBuilder::new(schema()))
+            .with_chunk_size(4)
+            .build(csv.as_bytes())
+            .unwrap();
+
+        let results: Vec<_> = reader.collect().await;
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        // Line numbers are relative to the chunk
+        assert_eq!(
+            results[2].as_ref().unwrap_err().to_string(),
+            "Parser error: Error while parsing value c for column 0 at line 0"
+        );
+        assert!(results[3].is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_read_comments() {
+        let mut csv = String::new();
+        for i in 0..100 {
+            csv.push_str(&format!("# row \"{i}\n{i},\"a\nb\"\n"));
+        }
+
+        for chunk_size in [1, 7, 100, 1024] {
+            let reader = AsyncReaderBuilder::new(ReaderBuilder::new(schema()).with_comment(b'#'))
+                .with_chunk_size(chunk_size)
+                .build(std::io::Cursor::new(csv.clone()))
+                .unwrap();
+
+            let batches: Vec<_> = reader.try_collect().await.unwrap();
+            let ids: Vec<_> = batches
+                .iter()
+                .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+                .collect();
+            assert_eq!(ids, (0..100).collect::<Vec<_>>());
+            let text = batches.iter().flat_map(|b| b.column(1).as_string::<i32>());
+            assert!(text.flatten().all(|t| t == "a\nb"));
+        }
+    }
+
+    #[test]
+    fn test_record_boundary_resumes() {
+        let mut chunker = Chunker {
+            reader: &b""[..],
+            buffer: b"1,\"a\n".to_vec(),
+            chunk_size: 2,
+            terminator: Some(b'\n'),
+            quote: b'"',
+            comment: Some(b'#'),
+            scan: ScanState::default(),
+            eof: false,
+        };
+        // The terminator is quoted
+        assert_eq!(chunker.record_boundary(), None);
+        assert_eq!(chunker.scan.offset, 5);
+        assert!(chunker.scan.quoted);
+
+        chunker.buffer.extend_from_slice(b"b\"\n#\"\n2,c\n");
+        assert_eq!(chunker.record_boundary(), Some(8));
+        assert_eq!(chunker.record_boundary(), Some(11));
+        assert_eq!(chunker.record_boundary(), Some(15));
+        assert_eq!(chunker.record_boundary(), None);
+    }
+
+    #[test]
+    fn test_record_boundary_crlf() {
+        let mut chunker = Chunker {
+            reader: &b""[..],
+            buffer: b"1,a\r\n2,\"b\rc\"\r3,d\r".to_vec(),
+            chunk_size: 1,
+            terminator: None,
+            quote: b'"',
+            comment: None,
+            scan: ScanState::default(),
+            eof: false,
+        };
+        assert_eq!(chunker.record_boundary(), Some(5));
+        // The `\r` within quotes is not a terminator
+        assert_eq!(chunker.record_boundary(), Some(13));
+        // The trailing `\r` may be followed by `\n`
+        assert_eq!(chunker.record_boundary(), None);
+        assert_eq!(chunker.scan.offset, 16);
+
+        chunker.buffer.extend_from_slice(b"\n4,e\n");
+        assert_eq!(chunker.record_boundary(), Some(18));
+        assert_eq!(chunker.record_boundary(), Some(22));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_parallel_read_cr() {
+        let csv: String = (0..100).map(|i| format!("{i},\"a\rb\"\r")).collect();
+        for chunk_size in [1, 7, 100] {
+            let reader = AsyncReaderBuilder::new(ReaderBuilder::new(schema()))
+                .with_chunk_size(chunk_size)
+                .build(std::io::Cursor::new(csv.clone()))
+                .unwrap();
+
+            let batches: Vec<_> = reader.try_collect().await.unwrap();
+            let ids: Vec<_> = batches
+                .iter()
+                .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+                .collect();
+            assert_eq!(ids, (0..100).collect::<Vec<_>>());
+            // Records are split at each `\r`, each being its own chunk
+            if chunk_size == 1 {
+                assert_eq!(batches.len(), 100);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounds_unsupported() {
+        let err = AsyncReaderBuilder::new(ReaderBuilder::new(schema()).with_bounds(0, 10))
+            .build(&b""[..])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: AsyncReader does not support bounds"
+        );
+    }
+}
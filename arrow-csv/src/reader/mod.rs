@@ -123,17 +123,24 @@
 //! ```
 //!
 
+#[cfg(feature = "async")]
+mod async_reader;
 mod records;
 
+#[cfg(feature = "async")]
+pub use async_reader::{AsyncReader, AsyncReaderBuilder};
+
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
 use arrow_schema::*;
+use arrow_select::filter::filter;
 use chrono::{TimeZone, Utc};
 use csv::StringRecord;
 use lazy_static::lazy_static;
 use regex::{Regex, RegexSet};
+use std::borrow::Cow;
 use std::fmt::{self, Debug};
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read};
@@ -157,19 +164,226 @@ lazy_static! {
     ]).unwrap();
 }
 
-/// A wrapper over `Option<Regex>` to check if the value is `NULL`.
+/// A wrapper over `Option<Regex>` and a list of literal tokens to check if the value is `NULL`.
 #[derive(Debug, Clone, Default)]
-struct NullRegex(Option<Regex>);
+struct NullRegex {
+    regex: Option<Regex>,
+    tokens: Vec<String>,
+}
 
 impl NullRegex {
     /// Returns true if the value should be considered as `NULL` according to
-    /// the provided regular expression.
+    /// the provided regular expression or null tokens.
     #[inline]
     fn is_null(&self, s: &str) -> bool {
-        match &self.0 {
+        let matched = match &self.regex {
             Some(r) => r.is_match(s),
             None => s.is_empty(),
+        };
+        matched || self.tokens.iter().any(|t| t == s)
+    }
+}
+
+/// Options controlling how integer, floating point and decimal values are parsed
+#[derive(Debug, Clone, Copy, Default)]
+struct NumberFormat {
+    /// Separator between groups of digits, removed before parsing
+    thousands_separator: Option<u8>,
+    /// Separator between the integral and fractional part, replaced with `'.'` before parsing
+    decimal_separator: Option<u8>,
+    /// Whether integer columns accept scientific notation, e.g. `1.5e3`
+    scientific_notation: bool,
+}
+
+impl NumberFormat {
+    /// Rewrites `s` into the canonical form expected by the numeric parsers,
+    /// borrowing if no rewrite is necessary
+    fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let thousands = self.thousands_separator;
+        let decimal = self.decimal_separator.filter(|d| *d != b'.');
+        let is_separator = |b: u8| Some(b) == thousands || Some(b) == decimal;
+        if !s.bytes().any(is_separator) {
+            return Cow::Borrowed(s);
+        }
+
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c.is_ascii().then_some(c as u8) {
+                Some(b) if Some(b) == thousands => {}
+                Some(b) if Some(b) == decimal => out.push('.'),
+                _ => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+
+    /// Parses a normalized integer, falling back to scientific notation if enabled
+    fn parse_integer<T: ArrowPrimitiveType + Parser>(&self, s: &str) -> Option<T::Native> {
+        T::parse(s).or_else(|| match self.scientific_notation {
+            true => parse_scientific_integer::<T>(s),
+            false => None,
+        })
+    }
+}
+
+/// The maximum number of digits of any integer type
+const MAX_INTEGER_DIGITS: usize = 39;
+
+/// Parses an integer in scientific notation, such as `1.5e3`, returning `None`
+/// if it is not an exact integer
+///
+/// The digits are shifted by the exponent and parsed as an integer, avoiding any
+/// loss of precision from parsing as a floating point number
+fn parse_scientific_integer<T: ArrowPrimitiveType + Parser>(s: &str) -> Option<T::Native> {
+    let (mantissa, exponent) = s.split_once(['e', 'E'])?;
+    let exponent: i64 = exponent.parse().ok()?;
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = || integer.bytes().chain(fraction.bytes());
+    if integer.len() + fraction.len() == 0 || !digits().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // Leading zeros are insignificant, and would otherwise count towards the
+    // number of digits
+    let significant: Vec<u8> = digits().skip_while(|b| *b == b'0').collect();
+    if significant.is_empty() {
+        return T::parse("0");
+    }
+    let shift = exponent.checked_sub(fraction.len() as i64)?;
+    let len = (significant.len() as i64).checked_add(shift)?;
+    if len > MAX_INTEGER_DIGITS as i64 {
+        return None;
+    }
+    let (integer, fraction) = significant.split_at(len.clamp(0, significant.len() as i64) as usize);
+    if fraction.iter().any(|b| *b != b'0') {
+        return None;
+    }
+
+    let mut out = String::with_capacity(MAX_INTEGER_DIGITS + 1);
+    out.push_str(sign);
+    out.extend(integer.iter().map(|b| *b as char));
+    out.extend(std::iter::repeat('0').take(shift.max(0) as usize));
+    T::parse(&out)
+}
+
+/// How to handle bad lines, i.e. records with an incorrect number of fields, or
+/// containing values that cannot be parsed as the corresponding column type
+///
+/// See [`ReaderBuilder::with_bad_line_policy`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadLinePolicy {
+    /// Return an error, the default
+    #[default]
+    Error,
+    /// Skip the record
+    Skip,
+    /// Read missing fields and unparsable values as null, and discard any excess fields
+    NullFill,
+}
+
+/// A callback invoked with the line number, raw bytes and error of each bad line
+type BadLineFn = dyn Fn(usize, &[u8], &ArrowError) + Send + Sync;
+
+/// A cloneable [`BadLineFn`]
+#[derive(Clone)]
+pub(crate) struct BadLineHandler(Arc<BadLineFn>);
+
+impl BadLineHandler {
+    pub(crate) fn call(&self, line: usize, raw: &[u8], error: &ArrowError) {
+        (self.0)(line, raw, error)
+    }
+}
+
+impl Debug for BadLineHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BadLineHandler")
+    }
+}
+
+/// Applies a [`BadLinePolicy`] to values that fail to parse
+///
+/// As columns are parsed one at a time, errors are collected and only handled once
+/// all columns are parsed, so that each bad record is reported once, in line order
+struct BadValues<'a> {
+    policy: BadLinePolicy,
+    handler: Option<&'a BadLineHandler>,
+    /// The row index and error of each value that failed to parse
+    errors: Vec<(usize, ArrowError)>,
+}
+
+impl<'a> BadValues<'a> {
+    fn new(policy: BadLinePolicy, handler: Option<&'a BadLineHandler>) -> Self {
+        Self {
+            policy,
+            handler,
+            errors: vec![],
+        }
+    }
+
+    /// Records `error` encountered parsing the value at `row_index`, returning the
+    /// value to use in its place
+    fn invalid<T>(&mut self, row_index: usize, error: ArrowError) -> Result<Option<T>, ArrowError> {
+        self.errors.push((row_index, error));
+        Ok(None)
+    }
+
+    /// Reports the bad records of `rows` to the handler, returning the error of the
+    /// first if the policy is [`BadLinePolicy::Error`], and otherwise removing any
+    /// skipped rows from `arrays`, returning the filtered arrays and row count
+    fn finish(
+        mut self,
+        rows: &StringRecords<'_>,
+        arrays: Vec<ArrayRef>,
+    ) -> Result<(Vec<ArrayRef>, usize), ArrowError> {
+        // Retain the first error of each row, in the order of the projected columns
+        self.errors.sort_by_key(|(row, _)| *row);
+        self.errors.dedup_by_key(|(row, _)| *row);
+
+        let handler = self.handler;
+        if self.policy == BadLinePolicy::Error {
+            return match self.errors.into_iter().next() {
+                Some((row, error)) => {
+                    if let Some((handler, (line, raw))) = handler.zip(rows.source(row)) {
+                        handler.call(line, raw, &error);
+                    }
+                    Err(error)
+                }
+                None => Ok((arrays, rows.len())),
+            };
         }
+
+        if let Some(handler) = handler {
+            // Merge the records with an incorrect number of fields, which are not in `rows`
+            let mut bad_records = rows.bad_records().iter().peekable();
+            for (row, error) in &self.errors {
+                let Some((line, raw)) = rows.source(*row) else {
+                    continue;
+                };
+                while let Some(bad) = bad_records.next_if(|bad| bad.line < line) {
+                    handler.call(bad.line, &bad.raw, &bad.error);
+                }
+                handler.call(line, raw, error);
+            }
+            for bad in bad_records {
+                handler.call(bad.line, &bad.raw, &bad.error);
+            }
+        }
+
+        if self.policy == BadLinePolicy::NullFill || self.errors.is_empty() {
+            return Ok((arrays, rows.len()));
+        }
+        let mut keep = vec![true; rows.len()];
+        self.errors.iter().for_each(|(row, _)| keep[*row] = false);
+        let predicate = BooleanArray::from(keep);
+        let arrays = arrays
+            .iter()
+            .map(|a| filter(a, &predicate))
+            .collect::<Result<_, _>>()?;
+        Ok((arrays, rows.len() - self.errors.len()))
     }
 }
 
@@ -227,6 +441,85 @@ impl InferredDataType {
     }
 }
 
+/// Returns true if `string` would be inferred as an integer or floating point value
+fn is_numeric(string: &str) -> bool {
+    REGEX_SET
+        .matches(string)
+        .into_iter()
+        .next()
+        .is_some_and(|m| m == 1 || m == 2)
+}
+
+/// Diagnostics for a single column collected by [`Format::infer_schema_with_diagnostics`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnDiagnostics {
+    observed_types: Vec<DataType>,
+    null_count: usize,
+    record_count: usize,
+    samples: Vec<String>,
+}
+
+impl ColumnDiagnostics {
+    /// The distinct types inferred for the individual non-null values
+    ///
+    /// These are ordered [`DataType::Boolean`], [`DataType::Int64`], [`DataType::Float64`],
+    /// [`DataType::Date32`], timestamps of increasing precision, then [`DataType::Utf8`].
+    /// The inferred type of the column is the narrowest type capable of representing all
+    /// of these, falling back to [`DataType::Utf8`] where no such type exists
+    pub fn observed_types(&self) -> &[DataType] {
+        &self.observed_types
+    }
+
+    /// The number of null or missing values
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// The fraction of the records read where this column is null, or `0` if no records were read
+    pub fn null_ratio(&self) -> f64 {
+        match self.record_count {
+            0 => 0.,
+            n => self.null_count as f64 / n as f64,
+        }
+    }
+
+    /// Distinct non-null values in the order they were first encountered
+    pub fn samples(&self) -> &[String] {
+        &self.samples
+    }
+}
+
+/// Accumulates [`ColumnDiagnostics`]
+#[derive(Debug, Clone, Default)]
+struct DiagnosticsBuilder {
+    observed: u16,
+    null_count: usize,
+    samples: Vec<String>,
+}
+
+impl DiagnosticsBuilder {
+    fn update(&mut self, value: &str, value_type: InferredDataType, max_samples: usize) {
+        self.observed |= value_type.packed;
+        if self.samples.len() < max_samples && !self.samples.iter().any(|s| s == value) {
+            self.samples.push(value.to_string());
+        }
+    }
+
+    fn finish(self, record_count: usize) -> ColumnDiagnostics {
+        let observed_types = (0..9)
+            .filter(|bit| self.observed & (1 << bit) != 0)
+            .map(|bit| InferredDataType { packed: 1 << bit }.get())
+            .collect();
+
+        ColumnDiagnostics {
+            observed_types,
+            null_count: self.null_count,
+            record_count,
+            samples: self.samples,
+        }
+    }
+}
+
 /// The format specification for the CSV file
 #[derive(Debug, Clone, Default)]
 pub struct Format {
@@ -237,6 +530,7 @@ pub struct Format {
     terminator: Option<u8>,
     comment: Option<u8>,
     null_regex: NullRegex,
+    number_format: NumberFormat,
     truncated_rows: bool,
 }
 
@@ -283,7 +577,41 @@ impl Format {
 
     /// Provide a regex to match null values, defaults to `^$`
     pub fn with_null_regex(mut self, null_regex: Regex) -> Self {
-        self.null_regex = NullRegex(Some(null_regex));
+        self.null_regex.regex = Some(null_regex);
+        self
+    }
+
+    /// Provide literal tokens, such as `NULL`, `\N` or `-`, that should be read as null
+    ///
+    /// These are checked in addition to the null regex, see [`Self::with_null_regex`]
+    pub fn with_null_tokens(mut self, tokens: Vec<String>) -> Self {
+        self.null_regex.tokens = tokens;
+        self
+    }
+
+    /// Specify a thousands separator, such as `b','` or `b'.'`, defaults to `None`
+    ///
+    /// The separator is removed from integer, floating point and decimal values before parsing
+    pub fn with_thousands_separator(mut self, separator: u8) -> Self {
+        self.number_format.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Specify the decimal separator for floating point and decimal values, defaults to `b'.'`
+    ///
+    /// For example, `1.234,5` can be read with a thousands separator of `b'.'`
+    /// and a decimal separator of `b','`
+    pub fn with_decimal_separator(mut self, separator: u8) -> Self {
+        self.number_format.decimal_separator = Some(separator);
+        self
+    }
+
+    /// Whether integer columns accept values in scientific notation, defaults to `false`
+    ///
+    /// When `true`, values such as `1.5e3` are accepted provided they represent an integer
+    /// exactly. Floating point and decimal columns always accept scientific notation
+    pub fn with_scientific_notation(mut self, allow: bool) -> Self {
+        self.number_format.scientific_notation = allow;
         self
     }
 
@@ -309,6 +637,38 @@ impl Format {
         reader: R,
         max_records: Option<usize>,
     ) -> Result<(Schema, usize), ArrowError> {
+        let (schema, records, _) = self.infer_schema_with_diagnostics(reader, max_records, 0)?;
+        Ok((schema, records))
+    }
+
+    /// Infer schema of CSV records from the provided `reader`, additionally returning
+    /// [`ColumnDiagnostics`] for each column
+    ///
+    /// Up to `max_samples` distinct non-null values are recorded for each column
+    ///
+    /// Returns inferred schema, number of records read and the diagnostics for each column
+    ///
+    /// ```
+    /// # use arrow_csv::reader::Format;
+    /// # use arrow_schema::DataType;
+    /// let data = "a,b\n1,x\n2.5,\n1,y\n";
+    /// let format = Format::default().with_header(true);
+    /// let (schema, records, diagnostics) = format
+    ///     .infer_schema_with_diagnostics(data.as_bytes(), None, 10)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(records, 3);
+    /// assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+    /// assert_eq!(diagnostics[0].observed_types(), &[DataType::Int64, DataType::Float64]);
+    /// assert_eq!(diagnostics[0].samples(), &["1", "2.5"]);
+    /// assert_eq!(diagnostics[1].null_count(), 1);
+    /// ```
+    pub fn infer_schema_with_diagnostics<R: Read>(
+        &self,
+        reader: R,
+        max_records: Option<usize>,
+        max_samples: usize,
+    ) -> Result<(Schema, usize, Vec<ColumnDiagnostics>), ArrowError> {
         let mut csv_reader = self.build_reader(reader);
 
         // get or create header names
@@ -326,6 +686,7 @@ impl Format {
         let header_length = headers.len();
         // keep track of inferred field types
         let mut column_types: Vec<InferredDataType> = vec![Default::default(); header_length];
+        let mut diagnostics: Vec<DiagnosticsBuilder> = vec![Default::default(); header_length];
 
         let mut records_count = 0;
 
@@ -339,11 +700,20 @@ impl Format {
 
             // Note since we may be looking at a sample of the data, we make the safe assumption that
             // they could be nullable
-            for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
-                if let Some(string) = record.get(i) {
-                    if !self.null_regex.is_null(string) {
-                        column_type.update(string)
+            for (i, (column_type, diagnostics)) in
+                column_types.iter_mut().zip(&mut diagnostics).enumerate()
+            {
+                match record.get(i) {
+                    Some(string) if !self.null_regex.is_null(string) => {
+                        let mut value_type = InferredDataType::default();
+                        match self.number_format.normalize(string) {
+                            Cow::Owned(n) if is_numeric(&n) => value_type.update(&n),
+                            _ => value_type.update(string),
+                        }
+                        column_type.packed |= value_type.packed;
+                        diagnostics.update(string, value_type, max_samples);
                     }
+                    _ => diagnostics.null_count += 1,
                 }
             }
         }
@@ -355,7 +725,12 @@ impl Format {
             .map(|(inferred, field_name)| Field::new(field_name, inferred.get(), true))
             .collect();
 
-        Ok((Schema::new(fields), records_count))
+        let diagnostics = diagnostics
+            .into_iter()
+            .map(|d| d.finish(records_count))
+            .collect();
+
+        Ok((Schema::new(fields), records_count, diagnostics))
     }
 
     /// Build a [`csv::Reader`] for this [`Format`]
@@ -574,6 +949,15 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// Options for parsing numeric values
+    number_format: NumberFormat,
+
+    /// How to handle values that cannot be parsed
+    bad_line_policy: BadLinePolicy,
+
+    /// Optional callback invoked for each bad line
+    bad_line_handler: Option<BadLineHandler>,
 }
 
 impl Decoder {
@@ -609,6 +993,7 @@ impl Decoder {
     /// Returns `Ok(None)` if no buffered data
     pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         if self.record_decoder.is_empty() {
+            self.record_decoder.report_bad_records();
             return Ok(None);
         }
 
@@ -620,6 +1005,8 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             &self.null_regex,
+            &self.number_format,
+            (self.bad_line_policy, self.bad_line_handler.as_ref()),
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -632,6 +1019,7 @@ impl Decoder {
 }
 
 /// Parses a slice of [`StringRecords`] into a [RecordBatch]
+#[allow(clippy::too_many_arguments)]
 fn parse(
     rows: &StringRecords<'_>,
     fields: &Fields,
@@ -639,19 +1027,24 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    number_format: &NumberFormat,
+    bad_lines: (BadLinePolicy, Option<&BadLineHandler>),
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
         None => fields.iter().enumerate().map(|(i, _)| i).collect(),
     };
 
+    let mut bad = BadValues::new(bad_lines.0, bad_lines.1);
     let arrays: Result<Vec<ArrayRef>, _> = projection
         .iter()
         .map(|i| {
             let i = *i;
             let field = &fields[i];
             match field.data_type() {
-                DataType::Boolean => build_boolean_array(line_number, rows, i, null_regex),
+                DataType::Boolean => {
+                    build_boolean_array(line_number, rows, i, null_regex, &mut bad)
+                }
                 DataType::Decimal128(precision, scale) => build_decimal_array::<Decimal128Type>(
                     line_number,
                     rows,
@@ -659,6 +1052,8 @@ fn parse(
                     *precision,
                     *scale,
                     null_regex,
+                    number_format,
+                    &mut bad,
                 ),
                 DataType::Decimal256(precision, scale) => build_decimal_array::<Decimal256Type>(
                     line_number,
@@ -667,54 +1062,128 @@ fn parse(
                     *precision,
                     *scale,
                     null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Int8 => build_integer_array::<Int8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Int16 => build_integer_array::<Int16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Int32 => build_integer_array::<Int32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Int64 => build_integer_array::<Int64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::UInt8 => build_integer_array::<UInt8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::UInt16 => build_integer_array::<UInt16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::UInt32 => build_integer_array::<UInt32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::UInt64 => build_integer_array::<UInt64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Float32 => build_float_array::<Float32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
+                ),
+                DataType::Float64 => build_float_array::<Float64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    number_format,
+                    &mut bad,
                 ),
-                DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i, null_regex)
-                }
                 DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex, &mut bad)
                 }
                 DataType::Date64 => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex, &mut bad)
                 }
+                DataType::Time32(TimeUnit::Second) => build_primitive_array::<Time32SecondType>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    &mut bad,
+                ),
                 DataType::Time32(TimeUnit::Millisecond) => {
-                    build_primitive_array::<Time32MillisecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time32MillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        &mut bad,
+                    )
                 }
                 DataType::Time64(TimeUnit::Microsecond) => {
-                    build_primitive_array::<Time64MicrosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64MicrosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        &mut bad,
+                    )
                 }
                 DataType::Time64(TimeUnit::Nanosecond) => {
-                    build_primitive_array::<Time64NanosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64NanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        &mut bad,
+                    )
                 }
                 DataType::Timestamp(TimeUnit::Second, tz) => {
                     build_timestamp_array::<TimestampSecondType>(
@@ -723,6 +1192,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        &mut bad,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Millisecond, tz) => {
@@ -732,6 +1202,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        &mut bad,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Microsecond, tz) => {
@@ -741,6 +1212,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        &mut bad,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
@@ -750,6 +1222,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        &mut bad,
                     )
                 }
                 DataType::Null => Ok(Arc::new({
@@ -860,15 +1333,14 @@ fn parse(
         Some(metadata) => Schema::new_with_metadata(projected_fields, metadata),
     });
 
-    arrays.and_then(|arr| {
-        RecordBatch::try_new_with_options(
-            projected_schema,
-            arr,
-            &RecordBatchOptions::new()
-                .with_match_field_names(true)
-                .with_row_count(Some(rows.len())),
-        )
-    })
+    let (arrays, row_count) = bad.finish(rows, arrays?)?;
+    RecordBatch::try_new_with_options(
+        projected_schema,
+        arrays,
+        &RecordBatchOptions::new()
+            .with_match_field_names(true)
+            .with_row_count(Some(row_count)),
+    )
 }
 
 fn parse_bool(string: &str) -> Option<bool> {
@@ -882,6 +1354,7 @@ fn parse_bool(string: &str) -> Option<bool> {
 }
 
 // parse the column string to an Arrow Array
+#[allow(clippy::too_many_arguments)]
 fn build_decimal_array<T: DecimalType>(
     _line_number: usize,
     rows: &StringRecords<'_>,
@@ -889,21 +1362,24 @@ fn build_decimal_array<T: DecimalType>(
     precision: u8,
     scale: i8,
     null_regex: &NullRegex,
+    number_format: &NumberFormat,
+    bad: &mut BadValues<'_>,
 ) -> Result<ArrayRef, ArrowError> {
     let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
-    for row in rows.iter() {
+    for (row_index, row) in rows.iter().enumerate() {
         let s = row.get(col_idx);
         if null_regex.is_null(s) {
             // append null
             decimal_builder.append_null();
         } else {
-            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(s, precision, scale);
+            let s = number_format.normalize(s);
+            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(&s, precision, scale);
             match decimal_value {
                 Ok(v) => {
                     decimal_builder.append_value(v);
                 }
                 Err(e) => {
-                    return Err(e);
+                    decimal_builder.append_option(bad.invalid(row_index, e)?);
                 }
             }
         }
@@ -921,6 +1397,47 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    bad: &mut BadValues<'_>,
+) -> Result<ArrayRef, ArrowError> {
+    build_parsed_array::<T>(line_number, rows, col_idx, null_regex, bad, T::parse)
+}
+
+// parses a specific integer column (col_idx) into an Arrow Array.
+fn build_integer_array<T: ArrowPrimitiveType + Parser>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    number_format: &NumberFormat,
+    bad: &mut BadValues<'_>,
+) -> Result<ArrayRef, ArrowError> {
+    build_parsed_array::<T>(line_number, rows, col_idx, null_regex, bad, |s| {
+        number_format.parse_integer::<T>(&number_format.normalize(s))
+    })
+}
+
+// parses a specific floating point column (col_idx) into an Arrow Array.
+fn build_float_array<T: ArrowPrimitiveType + Parser>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    number_format: &NumberFormat,
+    bad: &mut BadValues<'_>,
+) -> Result<ArrayRef, ArrowError> {
+    build_parsed_array::<T>(line_number, rows, col_idx, null_regex, bad, |s| {
+        T::parse(&number_format.normalize(s))
+    })
+}
+
+// parses a specific column (col_idx) into an Arrow Array using `parse`.
+fn build_parsed_array<T: ArrowPrimitiveType>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    bad: &mut BadValues<'_>,
+    parse: impl Fn(&str) -> Option<T::Native>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -930,15 +1447,18 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
                 return Ok(None);
             }
 
-            match T::parse(s) {
+            match parse(s) {
                 Some(e) => Ok(Some(e)),
-                None => Err(ArrowError::ParseError(format!(
+                None => {
                     // TODO: we should surface the underlying error here.
-                    "Error while parsing value {} for column {} at line {}",
-                    s,
-                    col_idx,
-                    line_number + row_index
-                ))),
+                    let error = ArrowError::ParseError(format!(
+                        "Error while parsing value {} for column {} at line {}",
+                        s,
+                        col_idx,
+                        line_number + row_index
+                    ));
+                    bad.invalid(row_index, error)
+                }
             }
         })
         .collect::<Result<PrimitiveArray<T>, ArrowError>>()
@@ -951,14 +1471,17 @@ fn build_timestamp_array<T: ArrowTimestampType>(
     col_idx: usize,
     timezone: Option<&str>,
     null_regex: &NullRegex,
+    bad: &mut BadValues<'_>,
 ) -> Result<ArrayRef, ArrowError> {
     Ok(Arc::new(match timezone {
         Some(timezone) => {
             let tz: Tz = timezone.parse()?;
-            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, null_regex)?
+            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, null_regex, bad)?
                 .with_timezone(timezone)
         }
-        None => build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc, null_regex)?,
+        None => {
+            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc, null_regex, bad)?
+        }
     }))
 }
 
@@ -968,6 +1491,7 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
     col_idx: usize,
     timezone: &Tz,
     null_regex: &NullRegex,
+    bad: &mut BadValues<'_>,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
     rows.iter()
         .enumerate()
@@ -995,8 +1519,11 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
                         line_number + row_index,
                         e
                     ))
-                })?;
-            Ok(Some(date))
+                });
+            match date {
+                Ok(date) => Ok(Some(date)),
+                Err(e) => bad.invalid(row_index, e),
+            }
         })
         .collect()
 }
@@ -1007,6 +1534,7 @@ fn build_boolean_array(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    bad: &mut BadValues<'_>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -1018,13 +1546,16 @@ fn build_boolean_array(
             let parsed = parse_bool(s);
             match parsed {
                 Some(e) => Ok(Some(e)),
-                None => Err(ArrowError::ParseError(format!(
+                None => {
                     // TODO: we should surface the underlying error here.
-                    "Error while parsing value {} for column {} at line {}",
-                    s,
-                    col_idx,
-                    line_number + row_index
-                ))),
+                    let error = ArrowError::ParseError(format!(
+                        "Error while parsing value {} for column {} at line {}",
+                        s,
+                        col_idx,
+                        line_number + row_index
+                    ));
+                    bad.invalid(row_index, error)
+                }
             }
         })
         .collect::<Result<BooleanArray, _>>()
@@ -1032,7 +1563,7 @@ fn build_boolean_array(
 }
 
 /// CSV file reader builder
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReaderBuilder {
     /// Schema of the CSV file
     schema: SchemaRef,
@@ -1046,6 +1577,10 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// How to handle bad lines
+    bad_line_policy: BadLinePolicy,
+    /// Optional callback invoked for each bad line
+    bad_line_handler: Option<BadLineHandler>,
 }
 
 impl ReaderBuilder {
@@ -1077,6 +1612,8 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            bad_line_policy: BadLinePolicy::default(),
+            bad_line_handler: None,
         }
     }
 
@@ -1124,7 +1661,39 @@ impl ReaderBuilder {
 
     /// Provide a regex to match null values, defaults to `^$`
     pub fn with_null_regex(mut self, null_regex: Regex) -> Self {
-        self.format.null_regex = NullRegex(Some(null_regex));
+        self.format.null_regex.regex = Some(null_regex);
+        self
+    }
+
+    /// Provide literal tokens, such as `NULL`, `\N` or `-`, that should be read as null
+    ///
+    /// These are checked in addition to the null regex, see [`Self::with_null_regex`]
+    pub fn with_null_tokens(mut self, tokens: Vec<String>) -> Self {
+        self.format = self.format.with_null_tokens(tokens);
+        self
+    }
+
+    /// Specify a thousands separator, such as `b','` or `b'.'`, defaults to `None`
+    ///
+    /// See [`Format::with_thousands_separator`]
+    pub fn with_thousands_separator(mut self, separator: u8) -> Self {
+        self.format = self.format.with_thousands_separator(separator);
+        self
+    }
+
+    /// Specify the decimal separator for floating point and decimal values, defaults to `b'.'`
+    ///
+    /// See [`Format::with_decimal_separator`]
+    pub fn with_decimal_separator(mut self, separator: u8) -> Self {
+        self.format = self.format.with_decimal_separator(separator);
+        self
+    }
+
+    /// Whether integer columns accept values in scientific notation, defaults to `false`
+    ///
+    /// See [`Format::with_scientific_notation`]
+    pub fn with_scientific_notation(mut self, allow: bool) -> Self {
+        self.format = self.format.with_scientific_notation(allow);
         self
     }
 
@@ -1147,6 +1716,51 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set how to handle bad lines, defaults to [`BadLinePolicy::Error`]
+    ///
+    /// A bad line is a record with an incorrect number of fields, or containing a value
+    /// that cannot be parsed as the type of its column. Records with too few fields are
+    /// not considered bad if [`Self::with_truncated_rows`] is enabled
+    pub fn with_bad_line_policy(mut self, policy: BadLinePolicy) -> Self {
+        self.bad_line_policy = policy;
+        self
+    }
+
+    /// Provide a callback invoked with the line number, raw bytes and error of each bad line,
+    /// regardless of the [`BadLinePolicy`]
+    ///
+    /// Each bad record is reported once, with the error of its first unparsable value,
+    /// and bad records are reported in line order as each [`RecordBatch`] is read. With
+    /// [`BadLinePolicy::Error`] only the record whose error is returned is reported.
+    /// Line numbers are one-based physical lines, on which the record starts
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use arrow_csv::reader::{BadLinePolicy, ReaderBuilder};
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    /// let bad_lines = Arc::new(Mutex::new(vec![]));
+    /// let sink = Arc::clone(&bad_lines);
+    ///
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_bad_line_policy(BadLinePolicy::Skip)
+    ///     .with_bad_line_handler(move |line, raw, _| {
+    ///         sink.lock().unwrap().push((line, raw.to_vec()))
+    ///     })
+    ///     .build_buffered("1\nfoo\n3\n".as_bytes())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(reader.next().unwrap().unwrap().num_rows(), 2);
+    /// assert_eq!(*bad_lines.lock().unwrap(), vec![(2, b"foo".to_vec())]);
+    /// ```
+    pub fn with_bad_line_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(usize, &[u8], &ArrowError) + Send + Sync + 'static,
+    {
+        self.bad_line_handler = Some(BadLineHandler(Arc::new(handler)));
+        self
+    }
+
     /// Whether to allow truncated rows when parsing.
     ///
     /// By default this is set to `false` and will error if the CSV rows have different lengths.
@@ -1181,7 +1795,8 @@ impl ReaderBuilder {
             delimiter,
             self.schema.fields().len(),
             self.format.truncated_rows,
-        );
+        )
+        .with_bad_lines(self.bad_line_policy, self.bad_line_handler.clone());
 
         let header = self.format.header as usize;
 
@@ -1199,6 +1814,9 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            number_format: self.format.number_format,
+            bad_line_policy: self.bad_line_policy,
+            bad_line_handler: self.bad_line_handler,
         }
     }
 }
@@ -1701,6 +2319,61 @@ mod tests {
         assert!(!batch.column(1).is_null(4));
     }
 
+    #[test]
+    fn test_infer_schema_with_diagnostics() {
+        let data =
+            "a,b,c,d\n1,x,true,2024-01-01\nfoo,,false\n3,x,,2024-01-01T00:00:00\n4,y,true,\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_truncated_rows(true);
+        let (schema, records, diagnostics) = format
+            .infer_schema_with_diagnostics(data.as_bytes(), None, 2)
+            .unwrap();
+
+        assert_eq!(records, 4);
+        assert_eq!(diagnostics.len(), 4);
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(
+            schema.field(3).data_type(),
+            &DataType::Timestamp(TimeUnit::Second, None)
+        );
+
+        let a = &diagnostics[0];
+        assert_eq!(a.observed_types(), &[DataType::Int64, DataType::Utf8]);
+        assert_eq!(a.samples(), &["1", "foo"]);
+        assert_eq!(a.null_count(), 0);
+        assert_eq!(a.null_ratio(), 0.);
+
+        let b = &diagnostics[1];
+        assert_eq!(b.observed_types(), &[DataType::Utf8]);
+        assert_eq!(b.samples(), &["x", "y"]);
+        assert_eq!(b.null_count(), 1);
+        assert_eq!(b.null_ratio(), 0.25);
+
+        let c = &diagnostics[2];
+        assert_eq!(c.observed_types(), &[DataType::Boolean]);
+        assert_eq!(c.samples(), &["true", "false"]);
+
+        // Missing fields are counted as null
+        let d = &diagnostics[3];
+        assert_eq!(
+            d.observed_types(),
+            &[
+                DataType::Date32,
+                DataType::Timestamp(TimeUnit::Second, None)
+            ]
+        );
+        assert_eq!(d.null_count(), 2);
+        assert_eq!(d.null_ratio(), 0.5);
+
+        // Reading no records produces empty diagnostics
+        let (_, _, diagnostics) = format
+            .infer_schema_with_diagnostics(data.as_bytes(), Some(0), 0)
+            .unwrap();
+        assert!(diagnostics.iter().all(|d| d.samples().is_empty()));
+        assert!(diagnostics.iter().all(|d| d.null_ratio() == 0.));
+    }
+
     #[test]
     fn test_custom_nulls_with_inference() {
         let mut file = File::open("test/data/custom_null_test.csv").unwrap();
@@ -1737,6 +2410,276 @@ mod tests {
         assert_eq!(batch.schema().as_ref(), &expected_schema);
     }
 
+    #[test]
+    fn test_null_tokens() {
+        let data = "a,b,c\n1,NULL,x\n\\N,2.5,-\n,-,\n";
+        let format = Format::default().with_header(true).with_null_tokens(vec![
+            "NULL".to_string(),
+            "\\N".to_string(),
+            "-".to_string(),
+        ]);
+
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        let expected_schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema, expected_schema);
+
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![Some(1), None, None]);
+        let b = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![None, Some(2.5), None]);
+        let c = batch.column(2).as_string::<i32>();
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![Some("x"), None, None]);
+    }
+
+    #[test]
+    fn test_thousands_and_decimal_separator() {
+        let data = "a;b;c\n1.234;1.234,5;12,34\n-5.000.000;0,5;-1.000,01\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_delimiter(b';')
+            .with_thousands_separator(b'.')
+            .with_decimal_separator(b',');
+
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Decimal128(10, 2), true),
+        ]));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_format(format)
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1234, -5000000]);
+        let b = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(b.values(), &[1234.5, 0.5]);
+        let c = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(c.values(), &[1234, -100001]);
+
+        // Without the separators configured the values fail to parse
+        let err = ReaderBuilder::new(batch.schema())
+            .with_header(true)
+            .with_delimiter(b';')
+            .build_buffered(data.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value 1.234 for column 0 at line 1"
+        );
+    }
+
+    #[test]
+    fn test_integer_scientific_notation() {
+        let data = "1e3\n1.5E2\n-2e0\n";
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int16, true)]));
+
+        let err = ReaderBuilder::new(schema.clone())
+            .build_buffered(data.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value 1e3 for column 0 at line 0"
+        );
+
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .with_scientific_notation(true)
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch.column(0).as_primitive::<Int16Type>();
+        assert_eq!(a.values(), &[1000, 150, -2]);
+
+        // Values that are not exact integers or overflow are still rejected
+        for data in ["1.5e0\n", "1e5\n"] {
+            let err = ReaderBuilder::new(schema.clone())
+                .with_scientific_notation(true)
+                .build_buffered(data.as_bytes())
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .starts_with("Parser error: Error while parsing value"));
+        }
+    }
+
+    #[test]
+    fn test_parse_scientific_integer() {
+        let cases = [
+            ("1e3", Some(1000)),
+            ("+1.5E+2", Some(150)),
+            ("-2e0", Some(-2)),
+            ("1500e-2", Some(15)),
+            ("0.00e400", Some(0)),
+            ("-0e-5", Some(0)),
+            ("0012.30e1", Some(123)),
+            // Values beyond the precision of a f64 are parsed exactly
+            ("9.007199254740993e15", Some(9007199254740993)),
+            ("9.223372036854775807e18", Some(i64::MAX)),
+            ("-9.223372036854775808e18", Some(i64::MIN)),
+            ("9.223372036854775808e18", None),
+            ("1.5e0", None),
+            ("15e-1", None),
+            ("1e-400", None),
+            ("1e400", None),
+            ("1e9223372036854775807", None),
+            ("1e", None),
+            ("e5", None),
+            (".e5", None),
+            ("1.2.3e4", None),
+            ("1x3e4", None),
+            ("100", None),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(parse_scientific_integer::<Int64Type>(s), expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn test_bad_line_policy() {
+        let data = "a,b,c\n1,2.5,true\nx,3.5,false\n3,4.5\n4,y,z\n5,6.5,true,extra\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Boolean, true),
+        ]));
+
+        let err = ReaderBuilder::new(schema.clone())
+            .with_header(true)
+            .build_buffered(data.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: incorrect number of fields for line 4, expected 3 got 2"
+        );
+
+        let reported = Arc::new(std::sync::Mutex::new(vec![]));
+        let captured = Arc::clone(&reported);
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .with_header(true)
+            .with_bad_line_policy(BadLinePolicy::Skip)
+            .with_bad_line_handler(move |line, raw, _| {
+                let raw = String::from_utf8(raw.to_vec()).unwrap();
+                captured.lock().unwrap().push((line, raw))
+            })
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[1]);
+        assert_eq!(
+            *reported.lock().unwrap(),
+            vec![
+                (3, "x,3.5,false".to_string()),
+                (4, "3,4.5".to_string()),
+                (5, "4,y,z".to_string()),
+                (6, "5,6.5,true,extra".to_string()),
+            ]
+        );
+
+        // Line numbers account for quoted line breaks, and the Error policy reports the
+        // first bad record
+        let multiline = "a,b,c\n1,\"2\n.5\",true\n2,z,y\n";
+        for policy in [BadLinePolicy::Skip, BadLinePolicy::Error] {
+            let reported = Arc::new(std::sync::Mutex::new(vec![]));
+            let captured = Arc::clone(&reported);
+            let result = ReaderBuilder::new(schema.clone())
+                .with_header(true)
+                .with_bad_line_policy(policy)
+                .with_bad_line_handler(move |line, _, _| captured.lock().unwrap().push(line))
+                .build_buffered(multiline.as_bytes())
+                .unwrap()
+                .next()
+                .unwrap();
+            assert_eq!(result.is_err(), policy == BadLinePolicy::Error);
+            let expected = match policy {
+                BadLinePolicy::Error => vec![2],
+                _ => vec![2, 4],
+            };
+            assert_eq!(*reported.lock().unwrap(), expected);
+        }
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_header(true)
+            .with_bad_line_policy(BadLinePolicy::NullFill)
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            vec![Some(1), None, Some(3), Some(4), Some(5)]
+        );
+        let b = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some(2.5), Some(3.5), Some(4.5), None, Some(6.5)]
+        );
+        let c = batch.column(2).as_boolean();
+        assert_eq!(
+            c.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), None, None, Some(true)]
+        );
+    }
+
+    #[test]
+    fn test_bad_line_policy_non_nullable() {
+        let data = "1\nx\n3\n";
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .with_bad_line_policy(BadLinePolicy::Skip)
+            .build_buffered(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 3]
+        );
+
+        let err = ReaderBuilder::new(schema)
+            .with_bad_line_policy(BadLinePolicy::NullFill)
+            .build_buffered(data.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column 'a' is declared as non-nullable but contains null values"
+        );
+    }
+
     #[test]
     fn test_scientific_notation_with_inference() {
         let mut file = File::open("test/data/scientific_notation_test.csv").unwrap();
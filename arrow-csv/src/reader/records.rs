@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::reader::{BadLineHandler, BadLinePolicy};
 use arrow_schema::ArrowError;
 use csv_core::{ReadRecordResult, Reader};
 
@@ -32,9 +33,15 @@ pub struct RecordDecoder {
     /// The expected number of fields per row
     num_columns: usize,
 
-    /// The current line number
+    /// The line on which the most recently read record starts
     line_number: usize,
 
+    /// The line of the next byte of input, counting `\n`, `\r\n` and `\r` as line breaks
+    line: usize,
+
+    /// Whether the last byte of input was `\r`, so that a following `\n` is not counted
+    after_cr: bool,
+
     /// Offsets delimiting field start positions
     offsets: Vec<usize>,
 
@@ -62,6 +69,89 @@ pub struct RecordDecoder {
     /// Default value is false
     /// When enabled fills in missing columns with null
     truncated_rows: bool,
+
+    /// How to handle records with an incorrect number of fields
+    bad_line_policy: BadLinePolicy,
+
+    /// Optional callback invoked for each bad record
+    bad_line_handler: Option<BadLineHandler>,
+
+    /// The line numbers of the buffered records, and their raw bytes and any records
+    /// with an incorrect number of fields if `bad_line_handler` is set
+    raw: RawRecords,
+
+    /// The raw bytes of the most recently flushed records
+    flushed_raw: RawRecords,
+}
+
+/// The raw bytes and line numbers of buffered records
+#[derive(Debug, Default)]
+struct RawRecords {
+    /// The bytes read from the input
+    data: Vec<u8>,
+    /// The end offset into `data` of each record
+    offsets: Vec<usize>,
+    /// The line number of each record
+    lines: Vec<usize>,
+    /// The records with an incorrect number of fields read since the last flush
+    bad: Vec<BadRecord>,
+}
+
+/// A record with an incorrect number of fields, reported to the [`BadLineHandler`] in
+/// line order along with any values that fail to parse when the records are flushed
+#[derive(Debug)]
+pub struct BadRecord {
+    pub line: usize,
+    pub raw: Vec<u8>,
+    pub error: ArrowError,
+}
+
+impl RawRecords {
+    /// The start offset of the record currently being read
+    fn record_start(&self) -> usize {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the raw bytes of `row` with any line terminators trimmed
+    fn get(&self, row: usize) -> &[u8] {
+        let start = row.checked_sub(1).map(|x| self.offsets[x]).unwrap_or(0);
+        trim_terminators(&self.data[start..self.offsets[row]])
+    }
+
+    /// Clears all complete records, retaining any partially read record
+    fn clear(&mut self) {
+        let start = self.record_start();
+        self.data.drain(..start);
+        self.offsets.clear();
+        self.lines.clear();
+        self.bad.clear();
+    }
+}
+
+/// Returns the number of line breaks in `data`, counting `\r\n` as a single line break
+fn count_line_breaks(data: &[u8]) -> usize {
+    data.iter()
+        .enumerate()
+        .filter(|(idx, b)| match b {
+            b'\n' => true,
+            b'\r' => data.get(idx + 1) != Some(&b'\n'),
+            _ => false,
+        })
+        .count()
+}
+
+/// Trims any leading or trailing line terminators from `raw`
+fn trim_terminators(raw: &[u8]) -> &[u8] {
+    let is_terminator = |b: &u8| *b == b'\n' || *b == b'\r';
+    let start = raw
+        .iter()
+        .position(|b| !is_terminator(b))
+        .unwrap_or(raw.len());
+    let end = raw
+        .iter()
+        .rposition(|b| !is_terminator(b))
+        .map_or(start, |x| x + 1);
+    &raw[start..end]
 }
 
 impl RecordDecoder {
@@ -70,6 +160,8 @@ impl RecordDecoder {
             delimiter,
             num_columns,
             line_number: 1,
+            line: 1,
+            after_cr: false,
             offsets: vec![],
             offsets_len: 1, // The first offset is always 0
             current_field: 0,
@@ -77,11 +169,30 @@ impl RecordDecoder {
             data: vec![],
             num_rows: 0,
             truncated_rows,
+            bad_line_policy: BadLinePolicy::default(),
+            bad_line_handler: None,
+            raw: RawRecords::default(),
+            flushed_raw: RawRecords::default(),
         }
     }
 
+    /// Sets the [`BadLinePolicy`] for records with an incorrect number of fields and
+    /// an optional [`BadLineHandler`] to notify of bad records
+    pub(crate) fn with_bad_lines(
+        mut self,
+        policy: BadLinePolicy,
+        handler: Option<BadLineHandler>,
+    ) -> Self {
+        self.bad_line_policy = policy;
+        self.bad_line_handler = handler;
+        self
+    }
+
     /// Decodes records from `input` returning the number of records and bytes read
     ///
+    /// Records dropped by [`BadLinePolicy::Skip`] are included in the number of records read,
+    /// but are not buffered
+    ///
     /// Note: this expects to be called with an empty `input` to signal EOF
     pub fn decode(&mut self, input: &[u8], to_read: usize) -> Result<(usize, usize), ArrowError> {
         if to_read == 0 {
@@ -114,6 +225,13 @@ impl RecordDecoder {
                         &mut self.offsets[self.offsets_len..],
                     );
 
+                let consumed = &input[input_offset..input_offset + bytes_read];
+                if self.bad_line_handler.is_some() {
+                    self.raw.data.extend_from_slice(consumed);
+                }
+                self.count_lines(consumed);
+                let terminated = matches!(consumed.last(), Some(b'\n' | b'\r'));
+
                 self.current_field += end_positions;
                 self.offsets_len += end_positions;
                 input_offset += bytes_read;
@@ -126,32 +244,37 @@ impl RecordDecoder {
                     }
                     // Need to allocate more capacity
                     ReadRecordResult::OutputFull => break,
-                    ReadRecordResult::OutputEndsFull => {
-                        return Err(ArrowError::CsvError(format!(
-                            "incorrect number of fields for line {}, expected {} got more than {}",
-                            self.line_number, self.num_columns, self.current_field
-                        )));
-                    }
+                    ReadRecordResult::OutputEndsFull => match self.bad_line_policy {
+                        BadLinePolicy::Error => {
+                            return Err(ArrowError::CsvError(format!(
+                                "incorrect number of fields for line {}, expected {} got more than {}",
+                                self.record_line(false), self.num_columns, self.current_field
+                            )));
+                        }
+                        // Grow offsets to read the remainder of the record
+                        _ => {
+                            let len = self.offsets.len() + self.num_columns;
+                            self.offsets.resize(len, 0);
+                        }
+                    },
                     ReadRecordResult::Record => {
-                        if self.current_field != self.num_columns {
-                            if self.truncated_rows && self.current_field < self.num_columns {
-                                // If the number of fields is less than expected, pad with nulls
-                                let fill_count = self.num_columns - self.current_field;
-                                let fill_value = self.offsets[self.offsets_len - 1];
-                                self.offsets[self.offsets_len..self.offsets_len + fill_count]
-                                    .fill(fill_value);
-                                self.offsets_len += fill_count;
-                            } else {
-                                return Err(ArrowError::CsvError(format!(
-                                    "incorrect number of fields for line {}, expected {} got {}",
-                                    self.line_number, self.num_columns, self.current_field
-                                )));
+                        self.line_number = self.record_line(terminated);
+                        if self.current_field != self.num_columns && !self.fix_field_count()? {
+                            // Drop the record
+                            let record_len = self.offsets[self.offsets_len - 1];
+                            self.offsets_len -= self.current_field;
+                            self.data_len -= record_len;
+                            let start = self.raw.record_start();
+                            self.raw.data.truncate(start);
+                        } else {
+                            self.num_rows += 1;
+                            if self.bad_line_handler.is_some() {
+                                self.raw.offsets.push(self.raw.data.len());
                             }
+                            self.raw.lines.push(self.line_number);
                         }
                         read += 1;
                         self.current_field = 0;
-                        self.line_number += 1;
-                        self.num_rows += 1;
 
                         if read == to_read {
                             // Read sufficient rows
@@ -170,6 +293,89 @@ impl RecordDecoder {
         }
     }
 
+    /// Advances the current line past the line breaks in the `consumed` bytes of input
+    fn count_lines(&mut self, consumed: &[u8]) {
+        let Some(last) = consumed.last() else {
+            return;
+        };
+        let consumed = match self.after_cr {
+            true => consumed.strip_prefix(b"\n").unwrap_or(consumed),
+            false => consumed,
+        };
+        self.line += count_line_breaks(consumed);
+        self.after_cr = *last == b'\r';
+    }
+
+    /// Returns the line on which the record currently being read starts, where
+    /// `terminated` is whether its line terminator has been consumed
+    ///
+    /// Any line breaks in the decoded fields of the record are quoted, and blank and
+    /// comment lines preceding it are not included in its fields
+    fn record_line(&self, terminated: bool) -> usize {
+        // The start of a record may have been cleared part way through reading it
+        let record_len = self.offsets[self.offsets_len - 1];
+        let record = &self.data[self.data_len.saturating_sub(record_len)..self.data_len];
+        self.line - terminated as usize - count_line_breaks(record)
+    }
+
+    /// Calls the [`BadLineHandler`] for the records with an incorrect number of fields
+    /// read since the last flush
+    pub fn report_bad_records(&mut self) {
+        if let Some(handler) = &self.bad_line_handler {
+            for bad in self.raw.bad.drain(..) {
+                handler.call(bad.line, &bad.raw, &bad.error);
+            }
+        }
+    }
+
+    /// Handles a record with an incorrect number of fields, returning `true` if the record
+    /// should be kept, or `false` if it should be dropped
+    fn fix_field_count(&mut self) -> Result<bool, ArrowError> {
+        let padding = self.truncated_rows && self.current_field < self.num_columns;
+        if !padding {
+            let error = ArrowError::CsvError(format!(
+                "incorrect number of fields for line {}, expected {} got {}",
+                self.line_number, self.num_columns, self.current_field
+            ));
+            let start = self.raw.record_start();
+            let raw = trim_terminators(&self.raw.data[start..]);
+            match self.bad_line_policy {
+                BadLinePolicy::Error => {
+                    if let Some(handler) = &self.bad_line_handler {
+                        handler.call(self.line_number, raw, &error);
+                    }
+                    return Err(error);
+                }
+                policy => {
+                    // Reported along with any invalid values when the records are flushed
+                    if self.bad_line_handler.is_some() {
+                        let line = self.line_number;
+                        let raw = raw.to_vec();
+                        self.raw.bad.push(BadRecord { line, raw, error });
+                    }
+                    if policy == BadLinePolicy::Skip {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        if self.current_field < self.num_columns {
+            // If the number of fields is less than expected, pad with nulls
+            let fill_count = self.num_columns - self.current_field;
+            let fill_value = self.offsets[self.offsets_len - 1];
+            self.offsets[self.offsets_len..self.offsets_len + fill_count].fill(fill_value);
+            self.offsets_len += fill_count;
+        } else {
+            // If the number of fields is more than expected, discard the excess fields
+            let excess = self.current_field - self.num_columns;
+            let record_len = self.offsets[self.offsets_len - 1];
+            self.offsets_len -= excess;
+            self.data_len -= record_len - self.offsets[self.offsets_len - 1];
+        }
+        Ok(true)
+    }
+
     /// Returns the current number of buffered records
     pub fn len(&self) -> usize {
         self.num_rows
@@ -186,6 +392,8 @@ impl RecordDecoder {
         self.offsets_len = 1;
         self.data_len = 0;
         self.num_rows = 0;
+        self.report_bad_records();
+        self.raw.clear();
     }
 
     /// Flushes the current contents of the reader
@@ -220,8 +428,7 @@ impl RecordDecoder {
                 .unwrap();
 
             let field = idx % self.num_columns + 1;
-            let line_offset = self.line_number - self.num_rows;
-            let line = line_offset + idx / self.num_columns;
+            let line = self.raw.lines[idx / self.num_columns];
 
             ArrowError::CsvError(format!(
                 "Encountered invalid UTF-8 data for line {line} and field {field}"
@@ -231,6 +438,17 @@ impl RecordDecoder {
         let offsets = &self.offsets[..self.offsets_len];
         let num_rows = self.num_rows;
 
+        // Move the raw records aside, retaining any partially read record
+        std::mem::swap(&mut self.raw, &mut self.flushed_raw);
+        self.raw.data.clear();
+        self.raw.offsets.clear();
+        self.raw.lines.clear();
+        self.raw.bad.clear();
+        let start = self.flushed_raw.record_start();
+        self.raw
+            .data
+            .extend_from_slice(&self.flushed_raw.data[start..]);
+
         // Reset state
         self.offsets_len = 1;
         self.data_len = 0;
@@ -241,6 +459,7 @@ impl RecordDecoder {
             num_columns: self.num_columns,
             offsets,
             data,
+            raw: &self.flushed_raw,
         })
     }
 }
@@ -252,9 +471,22 @@ pub struct StringRecords<'a> {
     num_rows: usize,
     offsets: &'a [usize],
     data: &'a str,
+    raw: &'a RawRecords,
 }
 
 impl<'a> StringRecords<'a> {
+    /// Returns the line number and raw bytes of the record at `index`, if tracked
+    pub fn source(&self, index: usize) -> Option<(usize, &'a [u8])> {
+        self.raw.offsets.get(index)?;
+        Some((self.raw.lines[index], self.raw.get(index)))
+    }
+
+    /// Returns the records with an incorrect number of fields read along with these
+    /// records, in line order, if tracked
+    pub fn bad_records(&self) -> &'a [BadRecord] {
+        &self.raw.bad
+    }
+
     fn get(&self, index: usize) -> StringRecord<'a> {
         let field_idx = index * self.num_columns;
         StringRecord {
@@ -293,8 +525,11 @@ impl<'a> StringRecord<'a> {
 #[cfg(test)]
 mod tests {
     use crate::reader::records::RecordDecoder;
+    use crate::reader::{BadLineHandler, BadLinePolicy};
+    use arrow_schema::ArrowError;
     use csv_core::Reader;
     use std::io::{BufRead, BufReader, Cursor};
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_basic() {
@@ -376,6 +611,116 @@ mod tests {
         assert_eq!(bytes, csv.len());
     }
 
+    #[test]
+    fn test_bad_line_policy() {
+        let csv = "a,b\nc\nd,e,f\ng,h\n";
+        let records = |decoder: &mut RecordDecoder| {
+            let flushed = decoder.flush().unwrap();
+            flushed
+                .iter()
+                .map(|r| vec![r.get(0).to_string(), r.get(1).to_string()])
+                .collect::<Vec<_>>()
+        };
+
+        let mut decoder =
+            RecordDecoder::new(Reader::new(), 2, false).with_bad_lines(BadLinePolicy::Skip, None);
+        let (read, bytes) = decoder.decode(csv.as_bytes(), 4).unwrap();
+        assert_eq!(read, 4);
+        assert_eq!(bytes, csv.len());
+        assert_eq!(records(&mut decoder), vec![vec!["a", "b"], vec!["g", "h"]]);
+
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, false)
+            .with_bad_lines(BadLinePolicy::NullFill, None);
+        decoder.decode(csv.as_bytes(), 4).unwrap();
+        assert_eq!(
+            records(&mut decoder),
+            vec![
+                vec!["a", "b"],
+                vec!["c", ""],
+                vec!["d", "e"],
+                vec!["g", "h"]
+            ]
+        );
+
+        // Excess fields in the final record of a batch
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, false)
+            .with_bad_lines(BadLinePolicy::NullFill, None);
+        decoder.decode(b"a,b,c,d,e\n", 1).unwrap();
+        assert_eq!(records(&mut decoder), vec![vec!["a", "b"]]);
+    }
+
+    #[test]
+    fn test_bad_line_handler() {
+        let csv = "a,b\nc\r\n\"d\ne\",f,g\n\nh,i\n";
+        let reported = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&reported);
+        let handler = BadLineHandler(Arc::new(move |line, raw: &[u8], error: &ArrowError| {
+            let raw = String::from_utf8(raw.to_vec()).unwrap();
+            captured
+                .lock()
+                .unwrap()
+                .push((line, raw, error.to_string()))
+        }));
+
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, false)
+            .with_bad_lines(BadLinePolicy::Skip, Some(handler));
+
+        // Feed the input a byte at a time
+        let mut offset = 0;
+        while offset < csv.len() {
+            let (_, bytes) = decoder
+                .decode(&csv.as_bytes()[offset..offset + 1], 4)
+                .unwrap();
+            offset += bytes;
+        }
+        decoder.decode(&[], 4).unwrap();
+
+        // Line numbers account for quoted line breaks and blank lines
+        let flushed = decoder.flush().unwrap();
+        assert_eq!(flushed.len(), 2);
+        assert_eq!(flushed.source(0), Some((1, b"a,b".as_slice())));
+        assert_eq!(flushed.source(1), Some((6, b"h,i".as_slice())));
+
+        // Bad records are reported by the reader of the flushed records, along with
+        // any values that fail to parse
+        let bad_records: Vec<_> = flushed
+            .bad_records()
+            .iter()
+            .map(|bad| (bad.line, bad.raw.as_slice(), bad.error.to_string()))
+            .collect();
+        assert_eq!(
+            bad_records,
+            vec![
+                (
+                    2,
+                    b"c".as_slice(),
+                    "Csv error: incorrect number of fields for line 2, expected 2 got 1"
+                        .to_string()
+                ),
+                (
+                    3,
+                    b"\"d\ne\",f,g".as_slice(),
+                    "Csv error: incorrect number of fields for line 3, expected 2 got 3"
+                        .to_string()
+                ),
+            ]
+        );
+        assert!(reported.lock().unwrap().is_empty());
+
+        // Or reported when the decoder is cleared, such as when skipping records
+        let (skipped, _) = decoder.decode(b"j\nk,l\n", 2).unwrap();
+        assert_eq!(skipped, 2);
+        decoder.clear();
+        assert_eq!(
+            *reported.lock().unwrap(),
+            vec![(
+                7,
+                "j".to_string(),
+                "Csv error: incorrect number of fields for line 7, expected 2 got 1".to_string()
+            )]
+        );
+    }
+
     #[test]
     fn test_truncated_rows() {
         let csv = "a,b\nv\n,1\n,2\n,3\n";
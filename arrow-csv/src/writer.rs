@@ -65,13 +65,142 @@
 
 use arrow_array::*;
 use arrow_cast::display::*;
+use arrow_cast::{cast, cast_with_options, CastOptions};
 use arrow_schema::*;
+use arrow_select::zip::zip;
 use csv::ByteRecord;
+use std::collections::HashMap;
+use std::fmt::Debug;
 use std::io::Write;
+use std::sync::Arc;
 
 use crate::map_csv_error;
 const DEFAULT_NULL_VALUE: &str = "";
 
+/// The quoting style used when writing fields
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote all fields, including the header
+    Always,
+    /// Only quote fields that contain the delimiter, quote character or a line terminator,
+    /// the default
+    #[default]
+    Necessary,
+    /// Quote all fields that do not look like a number
+    NonNumeric,
+    /// Never quote fields, even if this produces invalid CSV
+    Never,
+}
+
+impl From<QuoteStyle> for csv::QuoteStyle {
+    fn from(value: QuoteStyle) -> Self {
+        match value {
+            QuoteStyle::Always => csv::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            QuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+            QuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+/// Transforms a column before it is written, allowing control over how its values are
+/// formatted
+///
+/// The returned array is written using the same options as any other column, and so
+/// returning a [`StringArray`] allows complete control over the written values.
+///
+/// See [`WriterBuilder::with_column_formatter`]
+pub trait ColumnFormatter: Debug + Send + Sync {
+    /// Returns the array to write in place of `array`
+    fn format(&self, array: &ArrayRef) -> Result<ArrayRef, ArrowError>;
+}
+
+/// A [`ColumnFormatter`] that writes integer, floating point and decimal values
+/// with a fixed number of decimal places, rounding half away from zero
+///
+/// Floating point values that cannot be represented as a decimal with the requested
+/// number of decimal places, such as `NaN`, infinities and very large magnitudes, are
+/// written unformatted.
+///
+/// Returns an error if the number of decimal places exceeds the maximum scale of
+/// the decimal type used to format the column.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Float64Array, RecordBatch};
+/// # use arrow_csv::writer::{FixedDecimalPlaces, WriterBuilder};
+/// let batch = RecordBatch::try_from_iter([(
+///     "price",
+///     Arc::new(Float64Array::from(vec![1.0, 2.345, 10.5])) as _,
+/// )])
+/// .unwrap();
+///
+/// let mut out = vec![];
+/// let mut writer = WriterBuilder::new()
+///     .with_column_formatter("price", Arc::new(FixedDecimalPlaces(2)))
+///     .build(&mut out);
+/// writer.write(&batch).unwrap();
+/// drop(writer);
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), "price\n1.00\n2.35\n10.50\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedDecimalPlaces(pub u8);
+
+impl ColumnFormatter for FixedDecimalPlaces {
+    fn format(&self, array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+        let max_scale = match array.data_type() {
+            DataType::Decimal256(_, _) => DECIMAL256_MAX_SCALE,
+            _ => DECIMAL128_MAX_SCALE,
+        };
+        let scale = i8::try_from(self.0)
+            .ok()
+            .filter(|s| *s <= max_scale)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot format {} with {} decimal places, the maximum is {max_scale}",
+                    array.data_type(),
+                    self.0
+                ))
+            })?;
+        let to_type = match array.data_type() {
+            d if d.is_floating() => {
+                let to_type = DataType::Decimal128(DECIMAL128_MAX_PRECISION, scale);
+                let formatted = cast_with_options(array, &to_type, &CastOptions::default())?;
+                // Values that do not fit the decimal are written unformatted
+                let unformatted: BooleanArray = (0..array.len())
+                    .map(|i| Some(formatted.is_null(i) && array.is_valid(i)))
+                    .collect();
+                let formatted = cast(&formatted, &DataType::Utf8)?;
+                let original = cast(array, &DataType::Utf8)?;
+                return zip(&unformatted, &original, &formatted);
+            }
+            d if d.is_integer() => DataType::Decimal128(DECIMAL128_MAX_PRECISION, scale),
+            // Allow an additional digit for rounding
+            DataType::Decimal128(p, s) => {
+                let precision = (*p as i16 - *s as i16 + scale as i16 + 1)
+                    .clamp(1, DECIMAL128_MAX_PRECISION as i16);
+                DataType::Decimal128(precision as u8, scale)
+            }
+            DataType::Decimal256(p, s) => {
+                let precision = (*p as i16 - *s as i16 + scale as i16 + 1)
+                    .clamp(1, DECIMAL256_MAX_PRECISION as i16);
+                DataType::Decimal256(precision as u8, scale)
+            }
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot format {d} with a fixed number of decimal places"
+                )))
+            }
+        };
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        cast_with_options(array, &to_type, &options)
+    }
+}
+
 /// A CSV writer
 #[derive(Debug)]
 pub struct Writer<W: Write> {
@@ -93,6 +222,8 @@ pub struct Writer<W: Write> {
     beginning: bool,
     /// The value to represent null entries, defaults to [`DEFAULT_NULL_VALUE`]
     null_value: Option<String>,
+    /// Formatters for specific columns, keyed by column name
+    column_formatters: HashMap<String, Arc<dyn ColumnFormatter>>,
 }
 
 impl<W: Write> Writer<W> {
@@ -128,8 +259,18 @@ impl<W: Write> Writer<W> {
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
             .with_time_format(self.time_format.as_deref());
 
-        let converters = batch
-            .columns()
+        let columns = batch
+            .schema()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(f, a)| match self.column_formatters.get(f.name()) {
+                Some(formatter) => formatter.format(a),
+                None => Ok(Arc::clone(a)),
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        let converters = columns
             .iter()
             .map(|a| {
                 if a.data_type().is_nested() {
@@ -211,6 +352,12 @@ pub struct WriterBuilder {
     time_format: Option<String>,
     /// Optional value to represent null
     null_value: Option<String>,
+    /// The quoting style. Defaults to [`QuoteStyle::Necessary`]
+    quote_style: QuoteStyle,
+    /// Whether to terminate records with CRLF rather than LF. Defaults to `false`
+    crlf: bool,
+    /// Formatters for specific columns, keyed by column name
+    column_formatters: HashMap<String, Arc<dyn ColumnFormatter>>,
 }
 
 impl Default for WriterBuilder {
@@ -227,6 +374,9 @@ impl Default for WriterBuilder {
             timestamp_tz_format: None,
             time_format: None,
             null_value: None,
+            quote_style: QuoteStyle::default(),
+            crlf: false,
+            column_formatters: HashMap::new(),
         }
     }
 }
@@ -389,15 +539,58 @@ impl WriterBuilder {
         self.null_value.as_deref().unwrap_or(DEFAULT_NULL_VALUE)
     }
 
+    /// Set the quoting style, defaults to [`QuoteStyle::Necessary`]
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Get the quoting style
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    /// Set whether to terminate records with CRLF, as specified by RFC 4180,
+    /// rather than LF. Defaults to `false`
+    pub fn with_crlf(mut self, crlf: bool) -> Self {
+        self.crlf = crlf;
+        self
+    }
+
+    /// Get whether records are terminated with CRLF
+    pub fn crlf(&self) -> bool {
+        self.crlf
+    }
+
+    /// Set a [`ColumnFormatter`] for the column named `column`, replacing any
+    /// previously set for this column
+    pub fn with_column_formatter(
+        mut self,
+        column: impl Into<String>,
+        formatter: Arc<dyn ColumnFormatter>,
+    ) -> Self {
+        self.column_formatters.insert(column.into(), formatter);
+        self
+    }
+
+    /// Get the [`ColumnFormatter`] for the column named `column`, if any
+    pub fn column_formatter(&self, column: &str) -> Option<&Arc<dyn ColumnFormatter>> {
+        self.column_formatters.get(column)
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let mut builder = csv::WriterBuilder::new();
-        let writer = builder
+        builder
             .delimiter(self.delimiter)
             .quote(self.quote)
             .double_quote(self.double_quote)
             .escape(self.escape)
-            .from_writer(writer);
+            .quote_style(self.quote_style.into());
+        if self.crlf {
+            builder.terminator(csv::Terminator::CRLF);
+        }
+        let writer = builder.from_writer(writer);
         Writer {
             writer,
             beginning: true,
@@ -408,6 +601,7 @@ impl WriterBuilder {
             timestamp_format: self.timestamp_format,
             timestamp_tz_format: self.timestamp_tz_format,
             null_value: self.null_value,
+            column_formatters: self.column_formatters,
         }
     }
 }
@@ -844,4 +1038,147 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
             String::from_utf8(buf).unwrap()
         );
     }
+
+    #[test]
+    fn test_write_csv_quote_style_crlf() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "c1",
+                Arc::new(StringArray::from(vec!["a", "b,c"])) as ArrayRef,
+            ),
+            ("c2", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buf = Vec::new();
+            let mut writer = builder.build(&mut buf);
+            writer.write(&batch).unwrap();
+            drop(writer);
+            String::from_utf8(buf).unwrap()
+        };
+
+        let cases = [
+            (QuoteStyle::Necessary, "c1,c2\na,1\n\"b,c\",2\n"),
+            (
+                QuoteStyle::Always,
+                "\"c1\",\"c2\"\n\"a\",\"1\"\n\"b,c\",\"2\"\n",
+            ),
+            (
+                QuoteStyle::NonNumeric,
+                "\"c1\",\"c2\"\n\"a\",1\n\"b,c\",2\n",
+            ),
+            (QuoteStyle::Never, "c1,c2\na,1\nb,c,2\n"),
+        ];
+        for (style, expected) in cases {
+            let builder = WriterBuilder::new().with_quote_style(style);
+            assert_eq!(builder.quote_style(), style);
+            assert_eq!(write(builder), expected);
+        }
+
+        let builder = WriterBuilder::new().with_crlf(true);
+        assert!(builder.crlf());
+        assert_eq!(write(builder), "c1,c2\r\na,1\r\n\"b,c\",2\r\n");
+    }
+
+    #[test]
+    fn test_write_csv_column_formatter() {
+        #[derive(Debug)]
+        struct Upper;
+
+        impl ColumnFormatter for Upper {
+            fn format(&self, array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+                let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+                let upper: StringArray = array.iter().map(|x| x.map(str::to_uppercase)).collect();
+                Ok(Arc::new(upper))
+            }
+        }
+
+        let batch = RecordBatch::try_from_iter([
+            (
+                "name",
+                Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef,
+            ),
+            (
+                "f",
+                Arc::new(Float32Array::from(vec![Some(-1.005), None])) as ArrayRef,
+            ),
+            ("i", Arc::new(Int64Array::from(vec![7, -3])) as ArrayRef),
+            (
+                "d",
+                Arc::new(
+                    Decimal128Array::from(vec![12345, -99995])
+                        .with_precision_and_scale(5, 3)
+                        .unwrap(),
+                ) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let builder = WriterBuilder::new()
+            .with_column_formatter("name", Arc::new(Upper))
+            .with_column_formatter("f", Arc::new(FixedDecimalPlaces(1)))
+            .with_column_formatter("i", Arc::new(FixedDecimalPlaces(2)))
+            .with_column_formatter("d", Arc::new(FixedDecimalPlaces(2)));
+        assert!(builder.column_formatter("name").is_some());
+        assert!(builder.column_formatter("other").is_none());
+
+        let mut writer = builder.build(&mut buf);
+        writer.write(&batch).unwrap();
+        drop(writer);
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "name,f,i,d\nA,-1.0,7.00,12.35\n,,-3.00,-100.00\n"
+        );
+
+        let batch = RecordBatch::try_from_iter([(
+            "b",
+            Arc::new(BooleanArray::from(vec![true])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut writer = WriterBuilder::new()
+            .with_column_formatter("b", Arc::new(FixedDecimalPlaces(2)))
+            .build(Vec::new());
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot format Boolean with a fixed number of decimal places"
+        );
+
+        let batch = RecordBatch::try_from_iter([(
+            "f",
+            Arc::new(Float64Array::from(vec![
+                Some(1.25),
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(f64::NEG_INFINITY),
+                Some(1e40),
+                None,
+            ])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new()
+            .with_column_formatter("f", Arc::new(FixedDecimalPlaces(3)))
+            .build(&mut buf);
+        writer.write(&batch).unwrap();
+        drop(writer);
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "f\n1.250\nNaN\ninf\n-inf\n1e40\n\"\"\n"
+        );
+
+        let batch =
+            RecordBatch::try_from_iter([("i", Arc::new(Int32Array::from(vec![1])) as ArrayRef)])
+                .unwrap();
+        let mut writer = WriterBuilder::new()
+            .with_column_formatter("i", Arc::new(FixedDecimalPlaces(200)))
+            .build(Vec::new());
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot format Int32 with 200 decimal places, the maximum is 38"
+        );
+    }
 }
@@ -28,7 +28,7 @@ use std::mem;
 use std::ops::Range;
 use std::sync::Arc;
 
-use crate::{equal, validate_binary_view, validate_string_view};
+use crate::{equal, validate_binary_view, validate_string_view, MemoryTracker};
 
 #[inline]
 pub(crate) fn contains_nulls(
@@ -514,6 +514,34 @@ impl ArrayData {
         size
     }
 
+    /// Returns the number of bytes of memory retained by this [`ArrayData`]
+    /// and all of its children.
+    ///
+    /// Unlike [`Self::get_buffer_memory_size`], [`Buffer`]s that share an
+    /// underlying allocation, such as slices of the same buffer, are only
+    /// counted once. As with [`Self::get_buffer_memory_size`], the entire
+    /// allocation is counted even if this [`ArrayData`] only refers to a
+    /// subset of it, as that is the memory kept alive by holding onto it.
+    ///
+    /// To account for memory shared between multiple arrays use [`MemoryTracker`].
+    pub fn get_retained_memory_size(&self) -> usize {
+        let mut tracker = MemoryTracker::new();
+        tracker.track_array_data(self);
+        tracker.retained_bytes()
+    }
+
+    /// Calls `f` for each [`Buffer`] of this [`ArrayData`], including the
+    /// null buffer, followed by the buffers of each of its children.
+    pub fn visit_buffers<F: FnMut(&Buffer)>(&self, f: &mut F) {
+        if let Some(nulls) = &self.nulls {
+            f(nulls.buffer());
+        }
+        self.buffers.iter().for_each(&mut *f);
+        for child in &self.child_data {
+            child.visit_buffers(f);
+        }
+    }
+
     /// Creates a zero-copy slice of itself. This creates a new
     /// [`ArrayData`] pointing at the same underlying [`Buffer`]s with a
     /// different offset and len
@@ -948,7 +976,7 @@ impl ArrayData {
     ) -> Result<(), ArrowError> {
         let offsets: &[T] = self.typed_buffer(0, self.len)?;
         let sizes: &[T] = self.typed_buffer(1, self.len)?;
-        for i in 0..values_length {
+        for i in 0..self.len {
             let size = sizes[i].to_usize().ok_or_else(|| {
                 ArrowError::InvalidArgumentError(format!(
                     "Error converting size[{}] ({}) to usize for {}",
@@ -2254,6 +2282,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_retained_memory_size() {
+        let values = make_i32_buffer(16);
+        let capacity = values.capacity();
+        let data = ArrayData::builder(DataType::Int32)
+            .len(16)
+            .add_buffer(values.clone())
+            .build()
+            .unwrap();
+        assert_eq!(data.get_retained_memory_size(), capacity);
+
+        // Slicing retains the entire allocation
+        let slice = data.slice(4, 4);
+        assert_eq!(slice.get_retained_memory_size(), capacity);
+
+        // Children sharing an allocation are only counted once
+        let fields = vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ];
+        let struct_data = ArrayData::builder(DataType::Struct(fields.into()))
+            .len(4)
+            .add_child_data(slice.clone())
+            .add_child_data(data.slice(8, 4))
+            .build()
+            .unwrap();
+        assert_eq!(struct_data.get_buffer_memory_size(), capacity * 2);
+        assert_eq!(struct_data.get_retained_memory_size(), capacity);
+
+        let mut tracker = MemoryTracker::new();
+        tracker.track_array_data(&struct_data);
+        tracker.track_array_data(&data);
+        assert_eq!(tracker.allocation_count(), 1);
+        assert!(tracker.track_buffer(&make_i32_buffer(4)));
+        assert_eq!(tracker.allocation_count(), 2);
+        assert_eq!(
+            tracker.retained_bytes(),
+            capacity + make_i32_buffer(4).capacity()
+        );
+    }
+
     #[test]
     fn test_count_nulls() {
         let buffer = Buffer::from([0b00010110, 0b10011111]);
@@ -34,3 +34,6 @@ pub mod ffi;
 
 mod byte_view;
 pub use byte_view::*;
+
+mod memory;
+pub use memory::*;
@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::ArrayData;
+use arrow_buffer::Buffer;
+use std::collections::HashSet;
+
+/// Tracks the memory retained by a set of [`Buffer`]s, counting each
+/// underlying allocation only once
+///
+/// Slicing an array, or sharing buffers between arrays, does not copy the
+/// underlying data, and so the allocation is retained for as long as any
+/// [`Buffer`] referencing it is alive. This makes summing
+/// [`ArrayData::get_buffer_memory_size`] across arrays overcount memory that is
+/// shared, e.g. by the columns of a batch sliced from a larger batch.
+///
+/// ```
+/// # use arrow_buffer::Buffer;
+/// # use arrow_data::MemoryTracker;
+/// let buffer = Buffer::from_vec(vec![0_u64; 16]);
+/// let slice = buffer.slice(64);
+///
+/// let mut tracker = MemoryTracker::new();
+/// assert!(tracker.track_buffer(&buffer));
+/// assert!(!tracker.track_buffer(&slice));
+/// assert_eq!(tracker.retained_bytes(), 128);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MemoryTracker {
+    allocations: HashSet<usize>,
+    retained_bytes: usize,
+}
+
+impl MemoryTracker {
+    /// Create a new, empty [`MemoryTracker`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Account for the allocation backing `buffer`, returning `true` if it
+    /// had not been seen before
+    pub fn track_buffer(&mut self, buffer: &Buffer) -> bool {
+        let capacity = buffer.capacity();
+        if capacity == 0 {
+            return false;
+        }
+        let inserted = self.allocations.insert(buffer.data_ptr().as_ptr() as usize);
+        if inserted {
+            self.retained_bytes += capacity;
+        }
+        inserted
+    }
+
+    /// Account for all the buffers of `data` and its children
+    pub fn track_array_data(&mut self, data: &ArrayData) {
+        data.visit_buffers(&mut |buffer| {
+            self.track_buffer(buffer);
+        });
+    }
+
+    /// Returns the number of distinct allocations tracked so far
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.len()
+    }
+
+    /// Returns the total number of bytes retained by the tracked allocations
+    pub fn retained_bytes(&self) -> usize {
+        self.retained_bytes
+    }
+}
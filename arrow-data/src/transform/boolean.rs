@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use super::{Extend, _MutableArrayData, utils::resize_for_bits};
+use super::{_MutableArrayData, utils::resize_for_bits, Extend};
 use crate::bit_mask::set_bits;
 use crate::ArrayData;
 
@@ -0,0 +1,45 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use super::{_MutableArrayData, Extend};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+pub(super) fn build_extend<T: ArrowNativeType>(array: &ArrayData) -> Extend<'_> {
+    let offsets = array.buffer::<T>(0);
+    let sizes = array.buffer::<T>(1);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            for i in start..start + len {
+                // the values of each slot are copied contiguously into the child
+                let child_len = mutable.child_data[0].len();
+                let size = sizes[i].as_usize();
+                let offset = offsets[i].as_usize();
+
+                mutable.buffer1.push(T::from_usize(child_len).unwrap());
+                mutable.buffer2.push(sizes[i]);
+                if size > 0 {
+                    mutable.child_data[0].extend(index, offset, offset + size);
+                }
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    mutable.buffer1.extend_zeros(len * std::mem::size_of::<T>());
+    mutable.buffer2.extend_zeros(len * std::mem::size_of::<T>());
+}
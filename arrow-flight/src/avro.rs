@@ -0,0 +1,380 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_avro::reader::{MessageDecoder, ReaderBuilder};
+pub use arrow_avro::schema::AvroSchemaId;
+use arrow_avro::writer::MessageEncoder;
+use bytes::Bytes;
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+
+use crate::error::{FlightError, Result};
+use crate::FlightData;
+
+/// The [`FlightData::app_metadata`] of a message whose [`FlightData::data_body`]
+/// contains an Avro message, framed as described by [`AvroSchemaId`], instead of
+/// Arrow IPC data
+pub const AVRO_PAYLOAD_TAG: &[u8] = b"avro";
+
+/// The Avro schemas, as JSON, that Avro messages may be written with, by [`AvroSchemaId`]
+#[derive(Debug, Clone, Default)]
+pub struct AvroSchemaStore {
+    schemas: HashMap<AvroSchemaId, String>,
+}
+
+impl AvroSchemaStore {
+    /// Create an empty [`AvroSchemaStore`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the Avro schema JSON `schema` with `id`, replacing any
+    /// previously registered schema
+    pub fn register(&mut self, id: AvroSchemaId, schema: impl Into<String>) {
+        self.schemas.insert(id, schema.into());
+    }
+
+    /// Returns the Avro schema JSON registered with `id`, if any
+    pub fn get(&self, id: &AvroSchemaId) -> Option<&str> {
+        self.schemas.get(id).map(|s| s.as_str())
+    }
+}
+
+/// Returns a [`FlightData`] carrying the Avro encoded `record`, written with the
+/// schema `id`, tagged with [`AVRO_PAYLOAD_TAG`]
+///
+/// This frames Avro data produced elsewhere, so it can be decoded with an
+/// [`AvroPayloadDecoder`]. To encode [`RecordBatch`]es see [`AvroPayloadEncoder`]
+pub fn avro_payload(id: AvroSchemaId, record: &[u8]) -> FlightData {
+    FlightData {
+        app_metadata: Bytes::from_static(AVRO_PAYLOAD_TAG),
+        data_body: id.encode(record).into(),
+        ..Default::default()
+    }
+}
+
+/// Decodes [`FlightData`] tagged with [`AVRO_PAYLOAD_TAG`], such as those received
+/// by `DoPut`, into [`RecordBatch`]es
+///
+/// Each [`FlightData`] carries a single Avro message, whose schema is looked up in
+/// an [`AvroSchemaStore`]. Consecutive messages written with the same schema are
+/// decoded into the same [`RecordBatch`], up to the batch size of the [`ReaderBuilder`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use arrow_avro::reader::ReaderBuilder;
+/// # use arrow_flight::avro::{AvroPayloadDecoder, AvroSchemaId, AvroSchemaStore};
+/// # use arrow_flight::FlightData;
+/// # use futures::TryStreamExt;
+/// # use tonic::{Request, Status, Streaming};
+/// # async fn do_put(request: Request<Streaming<FlightData>>) -> Result<(), Status> {
+/// let mut store = AvroSchemaStore::new();
+/// store.register(
+///     AvroSchemaId::Confluent(1),
+///     r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#,
+/// );
+///
+/// let decoder = AvroPayloadDecoder::new(Arc::new(store), ReaderBuilder::new());
+/// let mut batches = decoder.into_stream(request.into_inner());
+/// while let Some(batch) = batches.try_next().await? {
+///     println!("{batch:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AvroPayloadDecoder {
+    store: Arc<AvroSchemaStore>,
+    builder: ReaderBuilder,
+    /// The decoder of the schema of the last decoded message
+    current: Option<(AvroSchemaId, MessageDecoder)>,
+}
+
+impl AvroPayloadDecoder {
+    /// Create a new [`AvroPayloadDecoder`] decoding messages written with the schemas
+    /// in `store`, with the options of `builder`
+    pub fn new(store: Arc<AvroSchemaStore>, builder: ReaderBuilder) -> Self {
+        Self {
+            store,
+            builder,
+            current: None,
+        }
+    }
+
+    /// Decode the Avro message of `data`, returning a [`RecordBatch`] of the
+    /// previously decoded messages, if they were written with a different schema
+    /// or reached the batch size
+    ///
+    /// Messages without a header or body, such as a `DoPut` message containing
+    /// only a [`FlightDescriptor`](crate::FlightDescriptor), are ignored
+    pub fn decode(&mut self, data: &FlightData) -> Result<Option<RecordBatch>> {
+        if data.data_header.is_empty() && data.data_body.is_empty() {
+            return Ok(None);
+        }
+        if data.app_metadata.as_ref() != AVRO_PAYLOAD_TAG {
+            return Err(FlightError::protocol(
+                "Expected FlightData tagged as an Avro payload",
+            ));
+        }
+
+        let (id, record) = AvroSchemaId::parse(&data.data_body)?;
+        let flushed = match &mut self.current {
+            Some((current, decoder)) if *current == id && !decoder.is_full() => None,
+            Some((current, decoder)) if *current == id => decoder.flush()?,
+            _ => {
+                let schema = self.store.get(&id).ok_or_else(|| {
+                    FlightError::ProtocolError(format!("Unknown Avro schema {id:?}"))
+                })?;
+                let decoder = self.builder.clone().build_message_decoder(schema)?;
+                match self.current.replace((id, decoder)) {
+                    Some((_, mut previous)) => previous.flush()?,
+                    None => None,
+                }
+            }
+        };
+
+        let (_, decoder) = self.current.as_mut().unwrap();
+        decoder.decode(record)?;
+        Ok(flushed)
+    }
+
+    /// Flush any decoded messages into a [`RecordBatch`]
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        match &mut self.current {
+            Some((_, decoder)) => Ok(decoder.flush()?),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns a stream of the [`RecordBatch`]es decoded from the Avro messages of `input`
+    pub fn into_stream<S, E>(self, input: S) -> BoxStream<'static, Result<RecordBatch>>
+    where
+        S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+        E: Into<FlightError> + 'static,
+    {
+        let input = input.map_err(Into::into).boxed();
+        futures::stream::try_unfold(Some((self, input)), |state| async move {
+            let Some((mut decoder, mut input)) = state else {
+                return Ok(None);
+            };
+            while let Some(data) = input.try_next().await? {
+                if let Some(batch) = decoder.decode(&data)? {
+                    return Ok(Some((batch, Some((decoder, input)))));
+                }
+            }
+            Ok(decoder.flush()?.map(|batch| (batch, None)))
+        })
+        .boxed()
+    }
+}
+
+/// Encodes [`RecordBatch`]es into [`FlightData`] tagged with [`AVRO_PAYLOAD_TAG`],
+/// such as those sent in response to `DoGet`, the inverse of [`AvroPayloadDecoder`]
+///
+/// Each row is encoded as a separate Avro message, written with the schema of a given
+/// [`AvroSchemaId`]. The columns of each [`RecordBatch`] must have the types of
+/// [`MessageEncoder::schema`], that is those an [`AvroPayloadDecoder`] decodes the
+/// schema to with the default options.
+///
+/// # Example
+///
+/// ```no_run
+/// # use arrow_array::RecordBatch;
+/// # use arrow_flight::avro::{AvroPayloadEncoder, AvroSchemaId};
+/// # use arrow_flight::error::FlightError;
+/// # use arrow_flight::FlightData;
+/// # use futures::stream::BoxStream;
+/// # use futures::StreamExt;
+/// # use tonic::{Response, Status};
+/// # fn do_get(
+/// #     batches: BoxStream<'static, Result<RecordBatch, FlightError>>,
+/// # ) -> Result<Response<BoxStream<'static, Result<FlightData, Status>>>, Status> {
+/// let schema = r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}]}"#;
+/// let encoder = AvroPayloadEncoder::try_new(AvroSchemaId::Confluent(1), schema)?;
+/// let stream = encoder.into_stream(batches).map(|x| x.map_err(Status::from));
+/// Ok(Response::new(stream.boxed()))
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AvroPayloadEncoder {
+    id: AvroSchemaId,
+    encoder: MessageEncoder,
+}
+
+impl AvroPayloadEncoder {
+    /// Create a new [`AvroPayloadEncoder`] encoding records with the Avro schema JSON
+    /// `schema`, identified by `id`
+    pub fn try_new(id: AvroSchemaId, schema: &str) -> Result<Self> {
+        Ok(Self {
+            id,
+            encoder: MessageEncoder::try_new(schema)?,
+        })
+    }
+
+    /// Returns the [`MessageEncoder`] used to encode each row
+    pub fn encoder(&self) -> &MessageEncoder {
+        &self.encoder
+    }
+
+    /// Returns a [`FlightData`] carrying the Avro message of each row of `batch`
+    pub fn encode(&self, batch: &RecordBatch) -> Result<Vec<FlightData>> {
+        let records = self.encoder.encode(batch)?;
+        Ok(records
+            .iter()
+            .map(|record| avro_payload(self.id, record))
+            .collect())
+    }
+
+    /// Returns a stream of the [`FlightData`] encoding the [`RecordBatch`]es of `input`
+    pub fn into_stream<S, E>(self, input: S) -> BoxStream<'static, Result<FlightData>>
+    where
+        S: Stream<Item = std::result::Result<RecordBatch, E>> + Send + 'static,
+        E: Into<FlightError> + 'static,
+    {
+        input
+            .map(move |batch| -> Result<_> {
+                let data = self.encode(&batch.map_err(Into::into)?)?;
+                Ok(futures::stream::iter(data.into_iter().map(Ok)))
+            })
+            .try_flatten()
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int64Array, StringArray};
+
+    /// Returns the Avro encoding of a record with a long and a string
+    fn encode_record(id: i64, name: &str) -> Vec<u8> {
+        let schema = r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": "string"}]}"#;
+        let encoder = MessageEncoder::try_new(schema).unwrap();
+        let columns = vec![
+            Arc::new(Int64Array::from(vec![id])) as _,
+            Arc::new(StringArray::from(vec![name])) as _,
+        ];
+        let batch = RecordBatch::try_new(encoder.schema(), columns).unwrap();
+        encoder.encode(&batch).unwrap().pop().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_avro_payload_decoder() {
+        let schema = r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": "string"}]}"#;
+        let confluent = AvroSchemaId::Confluent(1);
+        let single_object = AvroSchemaId::SingleObject(7);
+        let mut store = AvroSchemaStore::new();
+        store.register(confluent, schema);
+        store.register(single_object, schema);
+
+        let input = vec![
+            FlightData::default(),
+            avro_payload(confluent, &encode_record(1, "a")),
+            avro_payload(confluent, &encode_record(2, "b")),
+            avro_payload(confluent, &encode_record(3, "c")),
+            avro_payload(single_object, &encode_record(4, "d")),
+        ];
+        let decoder =
+            AvroPayloadDecoder::new(Arc::new(store), ReaderBuilder::new().with_batch_size(2));
+        let batches: Vec<_> = decoder
+            .into_stream(futures::stream::iter(
+                input.into_iter().map(Ok::<_, FlightError>),
+            ))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let rows: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(rows, [2, 1, 1]);
+        let expected = RecordBatch::try_from_iter([
+            ("id", std::sync::Arc::new(Int64Array::from(vec![1, 2])) as _),
+            (
+                "name",
+                std::sync::Arc::new(StringArray::from(vec!["a", "b"])) as _,
+            ),
+        ])
+        .unwrap();
+        assert_eq!(batches[0], expected);
+
+        let mut decoder = AvroPayloadDecoder::new(Arc::default(), ReaderBuilder::new());
+        let err = decoder
+            .decode(&avro_payload(confluent, &encode_record(1, "a")))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Unknown Avro schema Confluent(1)"
+        );
+
+        let untagged = FlightData::new().with_data_body(vec![1, 2, 3]);
+        let err = decoder.decode(&untagged).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Expected FlightData tagged as an Avro payload"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_avro_payload_encoder() {
+        let schema = r#"{"type": "record", "name": "r", "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": ["null", "string"]}]}"#;
+        let id = AvroSchemaId::SingleObject(3);
+        let encoder = AvroPayloadEncoder::try_new(id, schema).unwrap();
+        let schema_ref = encoder.encoder().schema();
+        let batches = [vec![Some("a"), None], vec![Some("b")]].map(|names| {
+            let ids = Int64Array::from_iter_values(0..names.len() as i64);
+            RecordBatch::try_new(
+                Arc::clone(&schema_ref),
+                vec![Arc::new(ids), Arc::new(StringArray::from(names))],
+            )
+            .unwrap()
+        });
+
+        let input = futures::stream::iter(batches.clone().map(Ok::<_, FlightError>));
+        let data: Vec<_> = encoder.into_stream(input).try_collect().await.unwrap();
+        assert_eq!(data.len(), 3);
+        assert_eq!(data[0], avro_payload(id, &[0, 2, 2, b'a']));
+
+        // Round trip through an AvroPayloadDecoder
+        let mut store = AvroSchemaStore::new();
+        store.register(id, schema);
+        let decoder = AvroPayloadDecoder::new(Arc::new(store), ReaderBuilder::new());
+        let input = futures::stream::iter(data.into_iter().map(Ok::<_, FlightError>));
+        let decoded: Vec<_> = decoder.into_stream(input).try_collect().await.unwrap();
+        let expected = RecordBatch::try_new(
+            batches[0].schema(),
+            vec![
+                Arc::new(Int64Array::from(vec![0, 1, 0])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("b")])),
+            ],
+        );
+        assert_eq!(decoded, [expected.unwrap()]);
+
+        let encoder = AvroPayloadEncoder::try_new(id, schema).unwrap();
+        let batch =
+            RecordBatch::try_from_iter([("id", Arc::new(StringArray::from(vec!["a"])) as _)])
+                .unwrap();
+        let err = encoder.encode(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow error: Schema error: Expected 2 columns to encode as Avro, got 1"
+        );
+    }
+}
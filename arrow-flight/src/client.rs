@@ -16,14 +16,18 @@
 // under the License.
 
 use crate::{
+    compression::set_accept_compression,
     decode::FlightRecordBatchStream,
+    exchange::exchange_encoder,
     flight_service_client::FlightServiceClient,
     gen::{CancelFlightInfoRequest, CancelFlightInfoResult, RenewFlightEndpointRequest},
     trailers::extract_lazy_trailers,
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
     HandshakeRequest, PollInfo, PutResult, Ticket,
 };
-use arrow_schema::Schema;
+use arrow_array::RecordBatch;
+use arrow_ipc::CompressionType;
+use arrow_schema::{Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{
     future::ready,
@@ -31,9 +35,14 @@ use futures::{
     Stream, StreamExt, TryStreamExt,
 };
 use prost::Message;
+use std::future::Future;
+#[cfg(feature = "retry")]
+use std::sync::Arc;
 use tonic::{metadata::MetadataMap, transport::Channel};
 
 use crate::error::{FlightError, Result};
+#[cfg(feature = "retry")]
+use crate::retry::{Middleware, ResumableDoGet, RetryPolicy, TokenRefresher};
 use crate::streams::{FallibleRequestStream, FallibleTonicResponseStream};
 
 /// A "Mid level" [Apache Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) client.
@@ -73,6 +82,10 @@ pub struct FlightClient {
 
     /// The inner client
     inner: FlightServiceClient<Channel>,
+
+    /// Retry and token refresh configuration
+    #[cfg(feature = "retry")]
+    middleware: Middleware,
 }
 
 impl FlightClient {
@@ -86,6 +99,8 @@ impl FlightClient {
         Self {
             metadata: MetadataMap::new(),
             inner,
+            #[cfg(feature = "retry")]
+            middleware: Middleware::default(),
         }
     }
 
@@ -118,6 +133,54 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Request that the server compress the [`FlightData`] it sends in
+    /// subsequent responses with one of `codecs`, in order of preference.
+    ///
+    /// Compressed responses are decoded transparently, provided the
+    /// corresponding `zstd` or `lz4` feature is enabled. See
+    /// [`supported_compression`] for the codecs enabled in this build, and
+    /// [`negotiate_compression`] for the server side.
+    ///
+    /// [`supported_compression`]: crate::compression::supported_compression
+    /// [`negotiate_compression`]: crate::compression::negotiate_compression
+    pub fn accept_compression(&mut self, codecs: &[CompressionType]) -> Result<()> {
+        set_accept_compression(&mut self.metadata, codecs)
+    }
+
+    /// Retry calls that fail with a transient error, such as
+    /// [`Code::Unavailable`](tonic::Code::Unavailable), according to `policy`.
+    ///
+    /// Calls with a unary request, such as [`Self::get_flight_info`] and
+    /// [`Self::do_get`], are retried. Calls streaming their input, such as
+    /// [`Self::do_put`], are not, as the input cannot be replayed. A `DoGet`
+    /// call can also be resumed after its response stream fails, see
+    /// [`Self::do_get_resumable`].
+    #[cfg(feature = "retry")]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.middleware.policy = Some(policy);
+        self
+    }
+
+    /// Refresh the bearer token with `refresher` when a call fails with
+    /// [`Code::Unauthenticated`](tonic::Code::Unauthenticated), and retry it.
+    ///
+    /// The token is sent as the `authorization` header of subsequent requests.
+    /// See [`TokenRefresher`] for details.
+    #[cfg(feature = "retry")]
+    pub fn with_token_refresher(mut self, refresher: impl TokenRefresher) -> Self {
+        self.middleware.refresher = Some(Arc::new(refresher));
+        self
+    }
+
+    /// Request a new bearer token from the [`TokenRefresher`], sending it as the
+    /// `authorization` header of subsequent requests.
+    ///
+    /// Returns an error if no [`TokenRefresher`] is configured.
+    #[cfg(feature = "retry")]
+    pub async fn refresh_token(&mut self) -> Result<()> {
+        self.middleware.refresh_token(&mut self.metadata).await
+    }
+
     /// Return a reference to the underlying tonic
     /// [`FlightServiceClient`]
     pub fn inner(&self) -> &FlightServiceClient<Channel> {
@@ -204,13 +267,17 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
-        let request = self.make_request(ticket);
+        let response = self
+            .call_with_retry(ticket, |mut inner, request| async move {
+                inner.do_get(request).await
+            })
+            .await?;
 
-        let (md, response_stream, _ext) = self.inner.do_get(request).await?.into_parts();
+        let (md, response_stream, _ext) = response.into_parts();
         let (response_stream, trailers) = extract_lazy_trailers(response_stream);
 
         Ok(FlightRecordBatchStream::new_from_flight_data(
-            response_stream.map_err(FlightError::Tonic),
+            response_stream.map_err(FlightError::from),
         )
         .with_headers(md)
         .with_trailers(trailers))
@@ -252,9 +319,12 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn get_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<FlightInfo> {
-        let request = self.make_request(descriptor);
-
-        let response = self.inner.get_flight_info(request).await?.into_inner();
+        let response = self
+            .call_with_retry(descriptor, |mut inner, request| async move {
+                inner.get_flight_info(request).await
+            })
+            .await?
+            .into_inner();
         Ok(response)
     }
 
@@ -310,9 +380,12 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn poll_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<PollInfo> {
-        let request = self.make_request(descriptor);
-
-        let response = self.inner.poll_flight_info(request).await?.into_inner();
+        let response = self
+            .call_with_retry(descriptor, |mut inner, request| async move {
+                inner.poll_flight_info(request).await
+            })
+            .await?
+            .into_inner();
         Ok(response)
     }
 
@@ -432,6 +505,59 @@ impl FlightClient {
         Ok(FlightRecordBatchStream::new_from_flight_data(error_stream))
     }
 
+    /// Make a `DoExchange` call to the server, sending the `input`
+    /// [`RecordBatch`]es with `schema` and returning a stream of the
+    /// [`RecordBatch`]es sent by the server.
+    ///
+    /// The `descriptor` and `schema` are sent in the first message, before any
+    /// batches. Servers can decode the input with [`ExchangeRequest`] and
+    /// encode their output with [`exchange_response`].
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use futures::TryStreamExt;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::UInt64Array;
+    /// # use arrow_array::RecordBatch;
+    /// # use arrow_flight::{FlightClient, FlightDescriptor};
+    /// # let batch = RecordBatch::try_from_iter(vec![
+    /// #  ("col2", Arc::new(UInt64Array::from_iter([10, 23, 33])) as _)
+    /// # ]).unwrap();
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let descriptor = FlightDescriptor::new_cmd("transform");
+    /// let input = futures::stream::iter(vec![Ok(batch.clone())]);
+    ///
+    /// // send the batches and get the results as `RecordBatches`
+    /// let response: Vec<RecordBatch> = client
+    ///   .do_exchange_batches(descriptor, batch.schema(), input)
+    ///   .await
+    ///   .unwrap()
+    ///   .try_collect() // use TryStreamExt to collect stream
+    ///   .await
+    ///   .expect("error calling do_exchange");
+    /// # }
+    /// ```
+    ///
+    /// [`ExchangeRequest`]: crate::exchange::ExchangeRequest
+    /// [`exchange_response`]: crate::exchange::exchange_response
+    pub async fn do_exchange_batches<S>(
+        &mut self,
+        descriptor: FlightDescriptor,
+        schema: SchemaRef,
+        input: S,
+    ) -> Result<FlightRecordBatchStream>
+    where
+        S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+    {
+        let request = exchange_encoder(schema)
+            .with_flight_descriptor(Some(descriptor))
+            .build(input);
+        self.do_exchange(request).await
+    }
+
     /// Make a `ListFlights` call to the server with the provided
     /// criteria and returning a [`Stream`] of [`FlightInfo`].
     ///
@@ -470,7 +596,7 @@ impl FlightClient {
             .list_flights(request)
             .await?
             .into_inner()
-            .map_err(FlightError::Tonic);
+            .map_err(FlightError::from);
 
         Ok(response.boxed())
     }
@@ -497,9 +623,12 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn get_schema(&mut self, flight_descriptor: FlightDescriptor) -> Result<Schema> {
-        let request = self.make_request(flight_descriptor);
-
-        let schema_result = self.inner.get_schema(request).await?.into_inner();
+        let schema_result = self
+            .call_with_retry(flight_descriptor, |mut inner, request| async move {
+                inner.get_schema(request).await
+            })
+            .await?
+            .into_inner();
 
         // attempt decode from IPC
         let schema: Schema = schema_result.try_into()?;
@@ -537,7 +666,7 @@ impl FlightClient {
             .list_actions(request)
             .await?
             .into_inner()
-            .map_err(FlightError::Tonic);
+            .map_err(FlightError::from);
 
         Ok(action_stream.boxed())
     }
@@ -575,7 +704,7 @@ impl FlightClient {
             .do_action(request)
             .await?
             .into_inner()
-            .map_err(FlightError::Tonic)
+            .map_err(FlightError::from)
             .map(|r| {
                 r.map(|r| {
                     // unwrap inner bytes
@@ -663,6 +792,98 @@ impl FlightClient {
         FlightEndpoint::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
     }
 
+    /// Make a `DoGet` call to the server with the provided ticket,
+    /// returning a [`Stream`] of [`RecordBatch`]es that is resumed if
+    /// it fails with a transient error, according to the [`RetryPolicy`].
+    ///
+    /// The call is resumed by making another `DoGet` call with the same
+    /// ticket, with the rows already received skipped by the client. The
+    /// server is therefore expected to return the same rows, in the same
+    /// order, for each call with a ticket.
+    ///
+    /// If enabled with [`RetryPolicy::with_resume_offset_header`], the number
+    /// of rows already received is also sent in the non-standard
+    /// [`RESUME_OFFSET_HEADER`], and a server that echoes the header in its
+    /// response is trusted to have skipped those rows itself.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use bytes::Bytes;
+    /// # use arrow_flight::{FlightClient, Ticket};
+    /// # use arrow_flight::retry::RetryPolicy;
+    /// # use arrow_array::RecordBatch;
+    /// # use futures::stream::TryStreamExt;
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// # let ticket = Ticket { ticket: Bytes::from("foo") };
+    /// let mut client = FlightClient::new(channel).with_retry_policy(RetryPolicy::new());
+    ///
+    /// let batches: Vec<RecordBatch> = client
+    ///    .do_get_resumable(ticket)
+    ///    .await
+    ///    .expect("error invoking do_get")
+    ///    .try_collect()
+    ///    .await
+    ///    .expect("no stream errors");
+    /// # }
+    /// ```
+    ///
+    /// [`RESUME_OFFSET_HEADER`]: crate::retry::RESUME_OFFSET_HEADER
+    /// [`RetryPolicy::with_resume_offset_header`]: crate::retry::RetryPolicy::with_resume_offset_header
+    #[cfg(feature = "retry")]
+    pub async fn do_get_resumable(
+        &mut self,
+        ticket: Ticket,
+    ) -> Result<BoxStream<'static, Result<RecordBatch>>> {
+        let response = self.do_get(ticket.clone()).await?;
+        let resumable = ResumableDoGet::new(
+            self.inner.clone(),
+            self.metadata.clone(),
+            self.middleware.clone(),
+            ticket,
+            response,
+        );
+        Ok(resumable.into_stream())
+    }
+
+    /// Make a request for `t` with `call`, retrying according to the
+    /// configured [`RetryPolicy`] and [`TokenRefresher`]
+    #[cfg(feature = "retry")]
+    async fn call_with_retry<T, R, F, Fut>(
+        &mut self,
+        t: T,
+        mut call: F,
+    ) -> Result<tonic::Response<R>>
+    where
+        T: Clone,
+        F: FnMut(FlightServiceClient<Channel>, tonic::Request<T>) -> Fut,
+        Fut: Future<Output = std::result::Result<tonic::Response<R>, tonic::Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let request = self.make_request(t.clone());
+            match call(self.inner.clone(), request).await {
+                Ok(response) => return Ok(response),
+                Err(status) => {
+                    self.middleware
+                        .on_error(status, &mut attempt, &mut self.metadata)
+                        .await?
+                }
+            }
+        }
+    }
+
+    /// Make a request for `t` with `call`
+    #[cfg(not(feature = "retry"))]
+    async fn call_with_retry<T, R, F, Fut>(&mut self, t: T, call: F) -> Result<tonic::Response<R>>
+    where
+        F: FnOnce(FlightServiceClient<Channel>, tonic::Request<T>) -> Fut,
+        Fut: Future<Output = std::result::Result<tonic::Response<R>, tonic::Status>>,
+    {
+        let request = self.make_request(t);
+        Ok(call(self.inner.clone(), request).await?)
+    }
+
     /// return a Request, adding any configured metadata
     fn make_request<T>(&self, t: T) -> tonic::Request<T> {
         // Pass along metadata
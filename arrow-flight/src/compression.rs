@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_ipc::CompressionType;
+use tonic::metadata::{MetadataMap, MetadataValue};
+
+use crate::error::{FlightError, Result};
+
+/// The gRPC metadata key a client uses to list, in order of preference, the
+/// IPC body compression codecs it can decode, e.g. `zstd, lz4`
+pub const ACCEPT_COMPRESSION_HEADER: &str = "x-arrow-flight-accept-compression";
+
+/// Returns the IPC body compression codecs enabled in this build, in order of preference
+///
+/// Codecs are enabled with the `zstd` and `lz4` features
+pub fn supported_compression() -> Vec<CompressionType> {
+    [
+        (cfg!(feature = "zstd"), CompressionType::ZSTD),
+        (cfg!(feature = "lz4"), CompressionType::LZ4_FRAME),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, codec)| enabled.then_some(codec))
+    .collect()
+}
+
+/// Returns the name of `codec` used in [`ACCEPT_COMPRESSION_HEADER`]
+fn codec_name(codec: CompressionType) -> Option<&'static str> {
+    match codec {
+        CompressionType::ZSTD => Some("zstd"),
+        CompressionType::LZ4_FRAME => Some("lz4"),
+        _ => None,
+    }
+}
+
+/// Returns the codec named `name` in [`ACCEPT_COMPRESSION_HEADER`]
+fn parse_codec(name: &str) -> Option<CompressionType> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "zstd" => Some(CompressionType::ZSTD),
+        "lz4" | "lz4_frame" => Some(CompressionType::LZ4_FRAME),
+        _ => None,
+    }
+}
+
+/// Sets [`ACCEPT_COMPRESSION_HEADER`] in `metadata` to `codecs`, in order of preference
+///
+/// An empty `codecs` removes the header, requesting uncompressed responses
+pub fn set_accept_compression(
+    metadata: &mut MetadataMap,
+    codecs: &[CompressionType],
+) -> Result<()> {
+    let names = codecs
+        .iter()
+        .map(|c| {
+            codec_name(*c)
+                .ok_or_else(|| FlightError::NotYetImplemented(format!("Compression codec {c:?}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if names.is_empty() {
+        metadata.remove(ACCEPT_COMPRESSION_HEADER);
+        return Ok(());
+    }
+
+    let value = MetadataValue::try_from(names.join(", "))
+        .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+    metadata.insert(ACCEPT_COMPRESSION_HEADER, value);
+    Ok(())
+}
+
+/// Returns the codecs listed in [`ACCEPT_COMPRESSION_HEADER`] of `metadata`, in order of
+/// preference, ignoring any codecs that are not recognised
+pub fn accepted_compression(metadata: &MetadataMap) -> Vec<CompressionType> {
+    metadata
+        .get_all(ACCEPT_COMPRESSION_HEADER)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(parse_codec)
+        .collect()
+}
+
+/// Negotiates the compression of the [`FlightData`](crate::FlightData) bodies sent in
+/// response to a call with request `metadata`
+///
+/// Returns the first codec accepted by the client, in its order of preference, that is
+/// also [supported](supported_compression) by this build, or `None` if there is no such
+/// codec, in which case the response should not be compressed.
+///
+/// The result can be passed to [`FlightDataEncoderBuilder::with_compression`]
+///
+/// [`FlightDataEncoderBuilder::with_compression`]: crate::encode::FlightDataEncoderBuilder::with_compression
+pub fn negotiate_compression(metadata: &MetadataMap) -> Option<CompressionType> {
+    let supported = supported_compression();
+    accepted_compression(metadata)
+        .into_iter()
+        .find(|c| supported.contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::FlightRecordBatchStream;
+    use crate::encode::FlightDataEncoderBuilder;
+    use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    use futures::TryStreamExt;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_compression_roundtrip() {
+        let values = (0..1024).map(|i| format!("value-{}", i % 7));
+        let array = Arc::new(StringArray::from_iter_values(values)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+
+        let encode = |compression| {
+            FlightDataEncoderBuilder::new()
+                .with_compression(compression)
+                .unwrap()
+                .build(futures::stream::iter([Ok(batch.clone())]))
+        };
+        let body_len =
+            |data: &[crate::FlightData]| -> usize { data.iter().map(|d| d.data_body.len()).sum() };
+
+        let uncompressed: Vec<_> = encode(None).try_collect().await.unwrap();
+        for codec in supported_compression() {
+            let mut metadata = MetadataMap::new();
+            set_accept_compression(&mut metadata, &[codec]).unwrap();
+            let compression = negotiate_compression(&metadata);
+            assert_eq!(compression, Some(codec));
+
+            let compressed: Vec<_> = encode(compression).try_collect().await.unwrap();
+            assert!(body_len(&compressed) < body_len(&uncompressed));
+
+            let stream = futures::stream::iter(compressed.into_iter().map(Ok));
+            let batches: Vec<_> = FlightRecordBatchStream::new_from_flight_data(stream)
+                .try_collect()
+                .await
+                .unwrap();
+            assert_eq!(batches, vec![batch.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_accept_compression() {
+        let mut metadata = MetadataMap::new();
+        assert!(accepted_compression(&metadata).is_empty());
+        assert_eq!(negotiate_compression(&metadata), None);
+
+        let codecs = [CompressionType::LZ4_FRAME, CompressionType::ZSTD];
+        set_accept_compression(&mut metadata, &codecs).unwrap();
+        assert_eq!(
+            metadata.get(ACCEPT_COMPRESSION_HEADER).unwrap(),
+            "lz4, zstd"
+        );
+        assert_eq!(accepted_compression(&metadata), codecs);
+
+        let expected = codecs
+            .into_iter()
+            .find(|c| supported_compression().contains(c));
+        assert_eq!(negotiate_compression(&metadata), expected);
+
+        metadata.insert(ACCEPT_COMPRESSION_HEADER, "gzip, ZSTD".parse().unwrap());
+        assert_eq!(accepted_compression(&metadata), [CompressionType::ZSTD]);
+
+        set_accept_compression(&mut metadata, &[]).unwrap();
+        assert!(metadata.get(ACCEPT_COMPRESSION_HEADER).is_none());
+
+        let err = set_accept_compression(&mut metadata, &[CompressionType(5)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Compression codec <UNKNOWN 5>"
+        );
+    }
+}
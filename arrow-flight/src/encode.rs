@@ -15,16 +15,29 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
-
-use crate::{error::Result, FlightData, FlightDescriptor, SchemaAsIpc};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::Arc,
+    task::Poll,
+};
+
+use crate::{
+    error::{FlightError, Result},
+    FlightData, FlightDescriptor, SchemaAsIpc,
+};
 
 use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UnionArray};
-use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_ipc::writer::{DictionaryTracker, EncodedData, IpcDataGenerator, IpcWriteOptions};
+use arrow_ipc::CompressionType;
 
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, UnionMode};
 use bytes::Bytes;
 use futures::{ready, stream::BoxStream, Stream, StreamExt};
+use prost::Message;
 
 /// Creates a [`Stream`] of [`FlightData`]s from a
 /// `Stream` of [`Result`]<[`RecordBatch`], [`FlightError`]>.
@@ -146,6 +159,14 @@ pub struct FlightDataEncoderBuilder {
     /// The maximum approximate target message size in bytes
     /// (see details on [`Self::with_max_flight_data_size`]).
     max_flight_data_size: usize,
+    /// The optional hard limit on the size of each encoded message
+    /// (see details on [`Self::with_max_message_size`]).
+    max_message_size: Option<usize>,
+    /// The optional limit on the number of encoded messages not yet sent
+    /// (see details on [`Self::with_max_in_flight_messages`]).
+    max_in_flight_messages: Option<usize>,
+    /// Optional [`MemoryPool`] to account encoded messages not yet sent
+    memory_pool: Option<Arc<dyn MemoryPool>>,
     /// Ipc writer options
     options: IpcWriteOptions,
     /// Metadata to add to the schema message
@@ -157,6 +178,9 @@ pub struct FlightDataEncoderBuilder {
     /// Deterimines how `DictionaryArray`s are encoded for transport.
     /// See [`DictionaryHandling`] for more information.
     dictionary_handling: DictionaryHandling,
+    /// Whether to allocate message bodies with 64-byte alignment
+    /// (see details on [`Self::with_aligned_buffers`]).
+    aligned_buffers: bool,
 }
 
 /// Default target size for encoded [`FlightData`].
@@ -169,11 +193,15 @@ impl Default for FlightDataEncoderBuilder {
     fn default() -> Self {
         Self {
             max_flight_data_size: GRPC_TARGET_MAX_FLIGHT_SIZE_BYTES,
+            max_message_size: None,
+            max_in_flight_messages: None,
+            memory_pool: None,
             options: IpcWriteOptions::default(),
             app_metadata: Bytes::new(),
             schema: None,
             descriptor: None,
             dictionary_handling: DictionaryHandling::Hydrate,
+            aligned_buffers: false,
         }
     }
 }
@@ -199,6 +227,67 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Set a hard limit, in bytes, on the encoded size of each [`FlightData`]
+    /// produced by this encoder containing a [`RecordBatch`]. Defaults to `None`.
+    ///
+    /// Unlike [`Self::with_max_flight_data_size`], which splits [`RecordBatch`]es
+    /// based on an estimate of their size before encoding, the size of each
+    /// encoded message is checked, and [`RecordBatch`]es are recursively split in
+    /// half by row until each message fits, such as the `max_decoding_message_size`
+    /// of the receiving gRPC server.
+    ///
+    /// When dictionaries are resent (see [`DictionaryHandling::Resend`]), they are
+    /// sent once, before the first piece of a split [`RecordBatch`].
+    ///
+    /// Encoding fails if a single row, or a dictionary, exceeds the limit.
+    pub fn with_max_message_size(mut self, max_message_size: Option<usize>) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Limit the number of encoded [`FlightData`] messages that have not yet
+    /// been sent. Defaults to `None`, encoding each [`RecordBatch`] into
+    /// messages as soon as it is received.
+    ///
+    /// [`RecordBatch`]es split to respect [`Self::with_max_flight_data_size`]
+    /// are then encoded lazily, one piece at a time, as the messages are sent,
+    /// so a slow client does not cause all pieces to be encoded in memory at once.
+    /// The input stream is only polled once all messages have been sent.
+    ///
+    /// Note the limit may be exceeded by a single piece that encodes to more
+    /// than one message, such as one with dictionaries.
+    pub fn with_max_in_flight_messages(mut self, max_in_flight_messages: Option<usize>) -> Self {
+        self.max_in_flight_messages = max_in_flight_messages.map(|m| m.max(1));
+        self
+    }
+
+    /// Account the size of the encoded [`RecordBatch`] and dictionary messages
+    /// that have not yet been sent in `memory_pool`, defaults to `None`.
+    ///
+    /// Memory is reserved when a message is encoded, and released once it is
+    /// returned by the [`FlightDataEncoder`], or the encoder is dropped. The
+    /// encoder returns an error if the reservation fails.
+    pub fn with_memory_pool(mut self, memory_pool: Arc<dyn MemoryPool>) -> Self {
+        self.memory_pool = Some(memory_pool);
+        self
+    }
+
+    /// Allocate the body of each [`FlightData`] at a 64-byte aligned address,
+    /// defaults to `false`.
+    ///
+    /// The IPC body buffers are padded to the alignment of the [`IpcWriteOptions`],
+    /// 64 bytes by default, relative to the start of the body. With this option
+    /// the body itself also starts at a 64-byte aligned address, so each buffer is
+    /// aligned in memory, as is required to hand the body to GPU or RDMA transports,
+    /// or to decode it in process without realigning, without copying it.
+    ///
+    /// The body is encoded directly into an aligned allocation, which is then
+    /// shared with the [`FlightData`] without copying.
+    pub fn with_aligned_buffers(mut self, aligned_buffers: bool) -> Self {
+        self.aligned_buffers = aligned_buffers;
+        self
+    }
+
     /// Set [`DictionaryHandling`] for encoder
     pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
         self.dictionary_handling = dictionary_handling;
@@ -219,6 +308,17 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Compress the buffers of the encoded [`RecordBatch`]es with `compression`,
+    /// or disable compression if `None`.
+    ///
+    /// The receiver must support the codec, see
+    /// [`negotiate_compression`](crate::compression::negotiate_compression). Encoding
+    /// fails at runtime if the corresponding `zstd` or `lz4` feature is not enabled.
+    pub fn with_compression(mut self, compression: Option<CompressionType>) -> Result<Self> {
+        self.options = self.options.try_with_compression(compression)?;
+        Ok(self)
+    }
+
     /// Specify a schema for the RecordBatches being sent. If a schema
     /// is not specified, an encoded Schema message will be sent when
     /// the first [`RecordBatch`], if any, is encoded. Some clients
@@ -244,21 +344,29 @@ impl FlightDataEncoderBuilder {
     {
         let Self {
             max_flight_data_size,
+            max_message_size,
+            max_in_flight_messages,
+            memory_pool,
             options,
             app_metadata,
             schema,
             descriptor,
             dictionary_handling,
+            aligned_buffers,
         } = self;
 
         FlightDataEncoder::new(
             input.boxed(),
             schema,
             max_flight_data_size,
+            max_message_size,
+            max_in_flight_messages,
+            memory_pool,
             options,
             app_metadata,
             descriptor,
             dictionary_handling,
+            aligned_buffers,
         )
     }
 }
@@ -274,12 +382,22 @@ pub struct FlightDataEncoder {
     /// Target maximum size of flight data
     /// (see details on [`FlightDataEncoderBuilder::with_max_flight_data_size`]).
     max_flight_data_size: usize,
+    /// Optional hard limit on the size of each encoded message
+    /// (see details on [`FlightDataEncoderBuilder::with_max_message_size`]).
+    max_message_size: Option<usize>,
+    /// Optional limit on the number of messages in `queue`
+    /// (see details on [`FlightDataEncoderBuilder::with_max_in_flight_messages`]).
+    max_in_flight_messages: Option<usize>,
+    /// Optional memory pool to account the messages in `queue`
+    memory_pool: Option<Arc<dyn MemoryPool>>,
     /// do the encoding / tracking of dictionaries
     encoder: FlightIpcEncoder,
     /// optional metadata to add to schema FlightData
     app_metadata: Option<Bytes>,
-    /// data queued up to send but not yet sent
-    queue: VecDeque<FlightData>,
+    /// data queued up to send but not yet sent, with the bytes reserved in `memory_pool`
+    queue: VecDeque<(FlightData, usize)>,
+    /// split batches not yet encoded, when `max_in_flight_messages` is set
+    pending: VecDeque<RecordBatch>,
     /// Is this stream done (inner is empty or errored)
     done: bool,
     /// cleared after the first FlightData message is sent
@@ -290,25 +408,35 @@ pub struct FlightDataEncoder {
 }
 
 impl FlightDataEncoder {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         inner: BoxStream<'static, Result<RecordBatch>>,
         schema: Option<SchemaRef>,
         max_flight_data_size: usize,
+        max_message_size: Option<usize>,
+        max_in_flight_messages: Option<usize>,
+        memory_pool: Option<Arc<dyn MemoryPool>>,
         options: IpcWriteOptions,
         app_metadata: Bytes,
         descriptor: Option<FlightDescriptor>,
         dictionary_handling: DictionaryHandling,
+        aligned_buffers: bool,
     ) -> Self {
         let mut encoder = Self {
             inner,
             schema: None,
             max_flight_data_size,
+            max_message_size,
+            max_in_flight_messages,
+            memory_pool,
             encoder: FlightIpcEncoder::new(
                 options,
                 dictionary_handling != DictionaryHandling::Resend,
+                aligned_buffers,
             ),
             app_metadata: Some(app_metadata),
             queue: VecDeque::new(),
+            pending: VecDeque::new(),
             done: false,
             descriptor,
             dictionary_handling,
@@ -333,14 +461,50 @@ impl FlightDataEncoder {
         if let Some(descriptor) = self.descriptor.take() {
             data.flight_descriptor = Some(descriptor);
         }
-        self.queue.push_back(data);
+        self.queue.push_back((data, 0));
     }
 
-    /// Place the `FlightData` in the queue to send
-    fn queue_messages(&mut self, datas: impl IntoIterator<Item = FlightData>) {
+    /// Place the `FlightData` in the queue to send, reserving their
+    /// size in the memory pool, if any
+    fn queue_reserved(&mut self, datas: impl IntoIterator<Item = FlightData>) -> Result<()> {
         for data in datas {
-            self.queue_message(data)
+            let Some(pool) = &self.memory_pool else {
+                self.queue_message(data);
+                continue;
+            };
+            let size = data.encoded_len();
+            pool.try_grow(size)?;
+            self.queue_message(data);
+            self.queue.back_mut().unwrap().1 = size;
+        }
+        Ok(())
+    }
+
+    /// Returns the next `FlightData` in the queue, releasing its reservation
+    fn pop_message(&mut self) -> Option<FlightData> {
+        let (data, reserved) = self.queue.pop_front()?;
+        if let Some(pool) = self.memory_pool.as_ref().filter(|_| reserved > 0) {
+            pool.shrink(reserved);
+        }
+        Some(data)
+    }
+
+    /// Discards any queued `FlightData` and pending batches
+    fn clear(&mut self) {
+        while self.pop_message().is_some() {}
+        self.pending.clear();
+    }
+
+    /// Encodes pending batches until `max_in_flight_messages` are queued
+    fn encode_pending(&mut self) -> Result<()> {
+        let max = self.max_in_flight_messages.unwrap_or(usize::MAX);
+        while self.queue.len() < max {
+            match self.pending.pop_front() {
+                Some(batch) => self.encode_split_batch(batch)?,
+                None => break,
+            }
         }
+        Ok(())
     }
 
     /// Encodes schema as a [`FlightData`] in self.queue.
@@ -379,15 +543,61 @@ impl FlightDataEncoder {
             DictionaryHandling::Hydrate => hydrate_dictionaries(&batch, schema)?,
         };
 
-        for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
-            let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
-
-            self.queue_messages(flight_dictionaries);
-            self.queue_message(flight_batch);
+        let batches = split_batch_for_grpc_response(batch, self.max_flight_data_size);
+        match self.max_in_flight_messages {
+            Some(_) => self.pending.extend(batches),
+            None => {
+                for batch in batches {
+                    self.encode_split_batch(batch)?;
+                }
+            }
         }
 
         Ok(())
     }
+
+    /// Encodes batch into `FlightData` messages in self.queue, splitting it in
+    /// half until each message fits within `self.max_message_size`
+    fn encode_split_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
+
+        if let Some(max_message_size) = self.max_message_size {
+            for dictionary in &flight_dictionaries {
+                let size = dictionary.encoded_len();
+                if size > max_message_size {
+                    return Err(FlightError::ProtocolError(format!(
+                        "Encoded dictionary of {size} bytes exceeds the maximum message size of {max_message_size} bytes"
+                    )));
+                }
+            }
+
+            let size = flight_batch.encoded_len();
+            if size > max_message_size {
+                if batch.num_rows() <= 1 {
+                    return Err(FlightError::ProtocolError(format!(
+                        "Encoded row of {size} bytes exceeds the maximum message size of {max_message_size} bytes"
+                    )));
+                }
+
+                // The dictionaries are now tracked as sent, and the slices share
+                // them, so send them before the slices instead
+                self.queue_reserved(flight_dictionaries)?;
+
+                let mid = batch.num_rows() / 2;
+                self.encode_split_batch(batch.slice(0, mid))?;
+                return self.encode_split_batch(batch.slice(mid, batch.num_rows() - mid));
+            }
+        }
+
+        self.queue_reserved(flight_dictionaries)?;
+        self.queue_reserved([flight_batch])
+    }
+}
+
+impl Drop for FlightDataEncoder {
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
 impl Stream for FlightDataEncoder {
@@ -398,15 +608,21 @@ impl Stream for FlightDataEncoder {
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         loop {
-            if self.done && self.queue.is_empty() {
-                return Poll::Ready(None);
+            if let Err(e) = self.encode_pending() {
+                self.done = true;
+                self.clear();
+                return Poll::Ready(Some(Err(e)));
             }
 
             // Any messages queued to send?
-            if let Some(data) = self.queue.pop_front() {
+            if let Some(data) = self.pop_message() {
                 return Poll::Ready(Some(Ok(data)));
             }
 
+            if self.done {
+                return Poll::Ready(None);
+            }
+
             // Get next batch
             let batch = ready!(self.inner.poll_next_unpin(cx));
 
@@ -415,20 +631,20 @@ impl Stream for FlightDataEncoder {
                     // inner is done
                     self.done = true;
                     // queue must also be empty so we are done
-                    assert!(self.queue.is_empty());
+                    assert!(self.queue.is_empty() && self.pending.is_empty());
                     return Poll::Ready(None);
                 }
                 Some(Err(e)) => {
                     // error from inner
                     self.done = true;
-                    self.queue.clear();
+                    self.clear();
                     return Poll::Ready(Some(Err(e)));
                 }
                 Some(Ok(batch)) => {
                     // had data, encode into the queue
                     if let Err(e) = self.encode_batch(batch) {
                         self.done = true;
-                        self.queue.clear();
+                        self.clear();
                         return Poll::Ready(Some(Err(e)));
                     }
                 }
@@ -437,6 +653,71 @@ impl Stream for FlightDataEncoder {
     }
 }
 
+/// Accounts the memory used by encoded messages that have not yet been sent,
+/// see [`FlightDataEncoderBuilder::with_memory_pool`]
+///
+/// A single pool can be shared by the encoders of many concurrent calls, to
+/// bound the memory used by a server regardless of how quickly clients read.
+///
+/// This is separate from the process-wide [`arrow_buffer::alloc::MemoryPool`],
+/// which is notified of the memory owned by arrow buffers. Encoded messages are
+/// not stored in arrow buffers, and so are never reported to that pool, allowing
+/// a host to account both against a single budget without counting any
+/// allocation twice.
+pub trait MemoryPool: Debug + Send + Sync {
+    /// Reserve `bytes`, returning an error if they are not available
+    fn try_grow(&self, bytes: usize) -> Result<()>;
+
+    /// Release `bytes` previously reserved with [`Self::try_grow`]
+    fn shrink(&self, bytes: usize);
+
+    /// Returns the number of bytes currently reserved
+    fn reserved(&self) -> usize;
+}
+
+/// A [`MemoryPool`] that allows reservations up to a fixed limit
+#[derive(Debug)]
+pub struct BoundedMemoryPool {
+    limit: usize,
+    reserved: AtomicUsize,
+}
+
+impl BoundedMemoryPool {
+    /// Create a new [`BoundedMemoryPool`] allowing up to `limit` bytes to be reserved
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            reserved: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl MemoryPool for BoundedMemoryPool {
+    fn try_grow(&self, bytes: usize) -> Result<()> {
+        self.reserved
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                reserved
+                    .checked_add(bytes)
+                    .filter(|new_reserved| *new_reserved <= self.limit)
+            })
+            .map_err(|reserved| {
+                FlightError::from(tonic::Status::resource_exhausted(format!(
+                    "Failed to reserve {bytes} bytes for encoded FlightData, {reserved} of {} bytes already reserved",
+                    self.limit
+                )))
+            })?;
+        Ok(())
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.reserved.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    fn reserved(&self) -> usize {
+        self.reserved.load(Ordering::Relaxed)
+    }
+}
+
 /// Defines how a [`FlightDataEncoder`] encodes [`DictionaryArray`]s
 ///
 /// [`DictionaryArray`]: arrow_array::DictionaryArray
@@ -650,10 +931,11 @@ struct FlightIpcEncoder {
     options: IpcWriteOptions,
     data_gen: IpcDataGenerator,
     dictionary_tracker: DictionaryTracker,
+    aligned_buffers: bool,
 }
 
 impl FlightIpcEncoder {
-    fn new(options: IpcWriteOptions, error_on_replacement: bool) -> Self {
+    fn new(options: IpcWriteOptions, error_on_replacement: bool, aligned_buffers: bool) -> Self {
         #[allow(deprecated)]
         let preserve_dict_id = options.preserve_dict_id();
         Self {
@@ -664,6 +946,7 @@ impl FlightIpcEncoder {
                 error_on_replacement,
                 preserve_dict_id,
             ),
+            aligned_buffers,
         }
     }
 
@@ -675,6 +958,19 @@ impl FlightIpcEncoder {
     /// Convert a `RecordBatch` to a Vec of `FlightData` representing
     /// dictionaries and a `FlightData` representing the batch
     fn encode_batch(&mut self, batch: &RecordBatch) -> Result<(Vec<FlightData>, FlightData)> {
+        if self.aligned_buffers {
+            let (encoded_dictionaries, encoded_batch) = self.data_gen.encoded_batch_aligned(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.options,
+            )?;
+            let flight_dictionaries = encoded_dictionaries
+                .into_iter()
+                .map(aligned_flight_data)
+                .collect();
+            return Ok((flight_dictionaries, aligned_flight_data(encoded_batch)));
+        }
+
         let (encoded_dictionaries, encoded_batch) =
             self.data_gen
                 .encoded_batch(batch, &mut self.dictionary_tracker, &self.options)?;
@@ -686,6 +982,39 @@ impl FlightIpcEncoder {
     }
 }
 
+/// Convert `EncodedData` with an aligned body to `FlightData`, sharing the
+/// body without copying it
+///
+/// The body is allocated with the platform's `ALIGNMENT`, which may be less
+/// than 64 bytes, in which case it is copied into a 64-byte aligned allocation
+fn aligned_flight_data(data: EncodedData<Buffer>) -> FlightData {
+    let mut body = data.arrow_data;
+    if !body.is_empty() && body.as_ptr().align_offset(BODY_ALIGNMENT) != 0 {
+        let mut aligned = MutableBuffer::new(body.len() + BODY_ALIGNMENT);
+        let offset = aligned.as_ptr().align_offset(BODY_ALIGNMENT);
+        aligned.extend_zeros(offset);
+        aligned.extend_from_slice(&body);
+        body = Buffer::from(aligned).slice(offset);
+    }
+    FlightData {
+        data_header: data.ipc_message.into(),
+        data_body: Bytes::from_owner(AlignedBody(body)),
+        ..Default::default()
+    }
+}
+
+/// The alignment of [`FlightData`] bodies with [`FlightDataEncoderBuilder::with_aligned_buffers`]
+const BODY_ALIGNMENT: usize = 64;
+
+/// A [`Buffer`] shared as the body of a [`FlightData`] without copying
+struct AlignedBody(Buffer);
+
+impl AsRef<[u8]> for AlignedBody {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
 /// Hydrates any dictionaries arrays in `batch` to its underlying type. See
 /// hydrate_dictionary for more information.
 fn hydrate_dictionaries(batch: &RecordBatch, schema: SchemaRef) -> Result<RecordBatch> {
@@ -731,7 +1060,7 @@ fn hydrate_dictionary(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef
 
 #[cfg(test)]
 mod tests {
-    use crate::decode::{DecodedPayload, FlightDataDecoder};
+    use crate::decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream};
     use arrow_array::builder::{
         GenericByteDictionaryBuilder, ListBuilder, StringDictionaryBuilder, StructBuilder,
     };
@@ -742,6 +1071,7 @@ mod tests {
     use arrow_ipc::MetadataVersion;
     use arrow_schema::{UnionFields, UnionMode};
     use builder::{GenericStringBuilder, MapBuilder};
+    use futures::TryStreamExt;
     use std::collections::HashMap;
 
     use super::*;
@@ -1569,6 +1899,168 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_max_message_size() {
+        let strings = StringArray::from_iter_values((0..1024).map(|i| "*".repeat(i % 100)));
+        let dictionary: DictionaryArray<Int32Type> =
+            (0..1024).map(|i| ["a", "b", "c"][i % 3]).collect();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("s", Arc::new(strings) as _),
+            ("d", Arc::new(dictionary) as _),
+        ])
+        .unwrap();
+
+        for dictionary_handling in [DictionaryHandling::Hydrate, DictionaryHandling::Resend] {
+            let resend = dictionary_handling == DictionaryHandling::Resend;
+            let max_message_size = 4096;
+            let encoder = FlightDataEncoderBuilder::new()
+                .with_max_flight_data_size(usize::MAX)
+                .with_max_message_size(Some(max_message_size))
+                .with_dictionary_handling(dictionary_handling)
+                .build(futures::stream::iter([Ok(batch.clone())]));
+            let data: Vec<_> = encoder.try_collect().await.unwrap();
+
+            let mut batches = 0;
+            for d in &data {
+                assert!(d.encoded_len() <= max_message_size);
+                let message = arrow_ipc::root_as_message(&d.data_header).unwrap();
+                batches += usize::from(message.header_as_record_batch().is_some());
+            }
+            assert!(batches > 1);
+
+            let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+                futures::stream::iter(data.into_iter().map(Ok)),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+            let expected = match resend {
+                true => batch.clone(),
+                false => hydrate_dictionaries(&batch, decoded[0].schema()).unwrap(),
+            };
+            let mut offset = 0;
+            for b in &decoded {
+                assert_eq!(b, &expected.slice(offset, b.num_rows()));
+                offset += b.num_rows();
+            }
+            assert_eq!(offset, batch.num_rows());
+        }
+
+        let large = StringArray::from_iter_values(["*".repeat(8192)]);
+        let batch = RecordBatch::try_from_iter(vec![("s", Arc::new(large) as _)]).unwrap();
+        let err = FlightDataEncoderBuilder::new()
+            .with_max_message_size(Some(4096))
+            .build(futures::stream::iter([Ok(batch)]))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("exceeds the maximum message size of 4096 bytes"));
+    }
+
+    #[tokio::test]
+    async fn test_max_in_flight_messages() {
+        let array = UInt32Array::from_iter_values(0..4096);
+        let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as _)]).unwrap();
+        let pool = Arc::new(BoundedMemoryPool::new(usize::MAX));
+
+        let mut encoder = FlightDataEncoderBuilder::new()
+            .with_max_flight_data_size(1024)
+            .with_max_in_flight_messages(Some(2))
+            .with_memory_pool(Arc::clone(&pool) as _)
+            .build(futures::stream::iter([
+                Ok(batch.clone()),
+                Ok(batch.clone()),
+            ]));
+
+        let mut data = vec![];
+        while let Some(d) = encoder.next().await {
+            assert!(encoder.queue.len() <= 2);
+            assert!(pool.reserved() <= 2 * 2048);
+            data.push(d.unwrap());
+        }
+        // schema followed by 16 pieces of each batch
+        assert_eq!(data.len(), 33);
+        assert_eq!(pool.reserved(), 0);
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            data.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        let rows: usize = decoded.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, 2 * batch.num_rows());
+
+        // Memory is released when the encoder is dropped before completion
+        let mut encoder = FlightDataEncoderBuilder::new()
+            .with_max_flight_data_size(1024)
+            .with_memory_pool(Arc::clone(&pool) as _)
+            .build(futures::stream::iter([Ok(batch.clone())]));
+        encoder.next().await.unwrap().unwrap();
+        encoder.next().await.unwrap().unwrap();
+        assert!(pool.reserved() > 0);
+        drop(encoder);
+        assert_eq!(pool.reserved(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_aligned_buffers() {
+        let ints = UInt32Array::from_iter_values(0..1024);
+        let strings = StringArray::from_iter_values((0..1024).map(|i| format!("value-{i}")));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("i", Arc::new(ints) as _),
+            ("s", Arc::new(strings) as _),
+        ])
+        .unwrap();
+
+        let data: Vec<_> = FlightDataEncoderBuilder::new()
+            .with_aligned_buffers(true)
+            .build(futures::stream::iter([Ok(batch.clone())]))
+            .try_collect()
+            .await
+            .unwrap();
+        let body = data[1].data_body.clone();
+        assert_eq!(body.as_ptr() as usize % 64, 0);
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            data.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(decoded, vec![batch]);
+
+        // The decoded arrays share the aligned body without copying
+        let body_range = body.as_ptr_range();
+        for column in decoded[0].columns() {
+            for buffer in column.to_data().buffers() {
+                assert_eq!(buffer.as_ptr() as usize % 64, 0);
+                assert!(body_range.contains(&buffer.as_ptr()));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_pool_exhausted() {
+        let array = UInt32Array::from_iter_values(0..4096);
+        let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as _)]).unwrap();
+        let pool = Arc::new(BoundedMemoryPool::new(1024));
+
+        let err = FlightDataEncoderBuilder::new()
+            .with_memory_pool(Arc::clone(&pool) as _)
+            .build(futures::stream::iter([Ok(batch)]))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        let FlightError::Tonic(status) = err else {
+            panic!("unexpected error: {err}")
+        };
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(pool.reserved(), 0);
+    }
+
     #[test]
     fn test_schema_metadata_encoded() {
         let schema = Schema::new(vec![Field::new("data", DataType::Int32, false)]).with_metadata(
@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+
+use crate::decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream};
+use crate::encode::{DictionaryHandling, FlightDataEncoderBuilder};
+use crate::error::{FlightError, Result};
+use crate::{FlightData, FlightDescriptor};
+
+/// Returns a [`FlightDataEncoderBuilder`] for one side of a `DoExchange` call
+///
+/// The schema is sent before any data, so the receiver knows it even if no batches
+/// are sent, and dictionaries are sent as dictionaries rather than hydrated, as
+/// both sides decode them with a [`FlightDataDecoder`]
+pub(crate) fn exchange_encoder(schema: SchemaRef) -> FlightDataEncoderBuilder {
+    FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .with_dictionary_handling(DictionaryHandling::Resend)
+}
+
+/// The decoded input of a `DoExchange` call, received by a server
+///
+/// The client is expected to send the schema of its input, and any
+/// [`FlightDescriptor`], in the first message, as [`FlightClient::do_exchange_batches`]
+/// does. The remaining [`RecordBatch`]es are decoded lazily with [`Self::into_stream`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use arrow_flight::exchange::{exchange_response, ExchangeRequest};
+/// # use arrow_flight::FlightData;
+/// # use futures::stream::BoxStream;
+/// # use futures::StreamExt;
+/// # use tonic::{Request, Response, Status, Streaming};
+/// async fn do_exchange(
+///     request: Request<Streaming<FlightData>>,
+/// ) -> Result<Response<BoxStream<'static, Result<FlightData, Status>>>, Status> {
+///     let request = ExchangeRequest::try_new(request.into_inner()).await?;
+///     // Echo the input batches back to the client
+///     let schema = request.schema().clone();
+///     let output = request.into_stream();
+///     Ok(Response::new(exchange_response(schema, output)))
+/// }
+/// ```
+///
+/// [`FlightClient::do_exchange_batches`]: crate::FlightClient::do_exchange_batches
+#[derive(Debug)]
+pub struct ExchangeRequest {
+    descriptor: Option<FlightDescriptor>,
+    schema: SchemaRef,
+    batches: FlightRecordBatchStream,
+}
+
+impl ExchangeRequest {
+    /// Reads the first message of `request`, which must contain the schema of the input
+    pub async fn try_new<S, E>(request: S) -> Result<Self>
+    where
+        S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+        E: Into<FlightError> + 'static,
+    {
+        let mut decoder = FlightDataDecoder::new(request.map_err(Into::into));
+        let first = match decoder.next().await {
+            Some(first) => first?,
+            None => {
+                return Err(FlightError::protocol(
+                    "DoExchange request contained no data",
+                ))
+            }
+        };
+        let schema = match first.payload {
+            DecodedPayload::Schema(schema) => schema,
+            _ => {
+                return Err(FlightError::protocol(
+                    "Expected the first message of a DoExchange request to contain a schema",
+                ))
+            }
+        };
+
+        Ok(Self {
+            descriptor: first.inner.flight_descriptor,
+            schema,
+            batches: FlightRecordBatchStream::new(decoder),
+        })
+    }
+
+    /// Returns the [`FlightDescriptor`] sent by the client, if any
+    pub fn descriptor(&self) -> Option<&FlightDescriptor> {
+        self.descriptor.as_ref()
+    }
+
+    /// Returns the schema of the [`RecordBatch`]es sent by the client
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns a stream of the [`RecordBatch`]es sent by the client
+    pub fn into_stream(self) -> FlightRecordBatchStream {
+        self.batches
+    }
+}
+
+/// Encodes the `output` [`RecordBatch`]es of a `DoExchange` call with `schema`,
+/// returning a stream suitable for the response of
+/// [`FlightService::do_exchange`](crate::flight_service_server::FlightService::do_exchange)
+///
+/// The schema is sent first, even if `output` is empty, so the client always learns
+/// the schema of the response. See [`ExchangeRequest`] for an example
+pub fn exchange_response<S>(
+    schema: SchemaRef,
+    output: S,
+) -> BoxStream<'static, std::result::Result<FlightData, tonic::Status>>
+where
+    S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+{
+    exchange_encoder(schema)
+        .build(output)
+        .map_err(tonic::Status::from)
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int64Array};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_exchange_roundtrip() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as _),
+            ("b", Arc::new(dict) as _),
+        ])
+        .unwrap();
+        let schema = batch.schema();
+
+        let descriptor = FlightDescriptor::new_cmd("exchange");
+        let request = exchange_encoder(schema.clone())
+            .with_flight_descriptor(Some(descriptor.clone()))
+            .build(futures::stream::iter([Ok(batch.clone())]));
+        let request = ExchangeRequest::try_new(request).await.unwrap();
+        assert_eq!(request.descriptor(), Some(&descriptor));
+        assert_eq!(request.schema(), &schema);
+        let batches: Vec<_> = request.into_stream().try_collect().await.unwrap();
+        assert_eq!(batches, vec![batch]);
+
+        // The schema is sent even without any batches
+        let response = exchange_response(schema.clone(), futures::stream::empty());
+        let response = ExchangeRequest::try_new(response).await.unwrap();
+        assert_eq!(response.descriptor(), None);
+        assert_eq!(response.schema(), &schema);
+        let batches: Vec<_> = response.into_stream().try_collect().await.unwrap();
+        assert!(batches.is_empty());
+
+        let err = ExchangeRequest::try_new(futures::stream::empty::<Result<FlightData>>())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: DoExchange request contained no data"
+        );
+    }
+}
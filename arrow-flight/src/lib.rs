@@ -80,10 +80,19 @@ pub mod flight_service_server {
     pub use gen::flight_service_server::FlightServiceServer;
 }
 
+/// Helpers for carrying Avro messages in [`FlightData`].
+/// See [`AvroPayloadDecoder`](avro::AvroPayloadDecoder).
+#[cfg(feature = "avro")]
+pub mod avro;
+
 /// Mid Level [`FlightClient`]
 pub mod client;
 pub use client::FlightClient;
 
+/// Negotiation of the compression of [`FlightData`] bodies via call metadata.
+/// See [`negotiate_compression`](compression::negotiate_compression).
+pub mod compression;
+
 /// Decoder to create [`RecordBatch`](arrow_array::RecordBatch) streams from [`FlightData`] streams.
 /// See [`FlightRecordBatchStream`](decode::FlightRecordBatchStream).
 pub mod decode;
@@ -95,6 +104,15 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+/// Helpers for exchanging [`RecordBatch`](arrow_array::RecordBatch) streams with `DoExchange`.
+/// See [`ExchangeRequest`](exchange::ExchangeRequest).
+pub mod exchange;
+
+/// Retry and token refresh for [`FlightClient`].
+/// See [`RetryPolicy`](retry::RetryPolicy).
+#[cfg(feature = "retry")]
+pub mod retry;
+
 pub use gen::Action;
 pub use gen::ActionType;
 pub use gen::BasicAuth;
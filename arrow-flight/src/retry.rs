@@ -0,0 +1,396 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt, TryStreamExt};
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::transport::Channel;
+use tonic::{Code, Status};
+
+use crate::decode::FlightRecordBatchStream;
+use crate::error::{FlightError, Result};
+use crate::flight_service_client::FlightServiceClient;
+use crate::Ticket;
+
+/// The gRPC metadata key of the number of rows already received, sent when a
+/// [`FlightClient::do_get_resumable`] call is resumed after a transient error,
+/// if enabled with [`RetryPolicy::with_resume_offset_header`]
+///
+/// This is a non-standard extension, not part of the Arrow Flight protocol, and
+/// so is only understood by servers written to support it. A server that skips
+/// these rows should echo the header in its response metadata. Otherwise the
+/// rows are sent again, and skipped by the client.
+///
+/// [`FlightClient::do_get_resumable`]: crate::FlightClient::do_get_resumable
+pub const RESUME_OFFSET_HEADER: &str = "x-arrow-flight-resume-offset";
+
+/// Determines how [`FlightClient`](crate::FlightClient) calls are retried after
+/// a transient error, with exponential backoff
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    retryable: Vec<Code>,
+    resume_offset_header: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            retryable: vec![Code::Unavailable, Code::Aborted],
+            resume_offset_header: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new [`RetryPolicy`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of consecutive retries of a call, defaults to 3
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the backoff before the first retry, defaults to 100ms
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the maximum backoff between retries, defaults to 10s
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the factor the backoff is multiplied by after each retry, defaults to 2
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the gRPC status codes considered transient, defaults to
+    /// [`Code::Unavailable`] and [`Code::Aborted`]
+    pub fn with_retryable_codes(mut self, codes: Vec<Code>) -> Self {
+        self.retryable = codes;
+        self
+    }
+
+    /// Send the non-standard [`RESUME_OFFSET_HEADER`] when resuming a
+    /// [`FlightClient::do_get_resumable`] call, defaults to `false`
+    ///
+    /// This allows servers that support it to skip the rows already received,
+    /// instead of sending them again. Servers that do not support it ignore it.
+    ///
+    /// [`FlightClient::do_get_resumable`]: crate::FlightClient::do_get_resumable
+    pub fn with_resume_offset_header(mut self, resume_offset_header: bool) -> Self {
+        self.resume_offset_header = resume_offset_header;
+        self
+    }
+
+    /// Returns the maximum number of consecutive retries of a call
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    /// Returns true if a call failing with `status` should be retried
+    pub fn is_retryable(&self, status: &Status) -> bool {
+        self.retryable.contains(&status.code())
+    }
+
+    /// Returns the backoff before retry number `attempt`, starting from 0
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = self.multiplier.powi(attempt.min(i32::MAX as usize) as i32);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// Provides bearer tokens for a [`FlightClient`](crate::FlightClient), such as
+/// from an OAuth token endpoint
+///
+/// When a call fails with [`Code::Unauthenticated`], a new token is requested,
+/// sent as the `authorization` header of subsequent calls, and the call retried.
+///
+/// Implemented for async closures returning a token
+pub trait TokenRefresher: Send + Sync + 'static {
+    /// Returns a new bearer token
+    fn refresh(&self) -> BoxFuture<'static, Result<String>>;
+}
+
+impl<F, Fut> TokenRefresher for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    fn refresh(&self) -> BoxFuture<'static, Result<String>> {
+        self().boxed()
+    }
+}
+
+/// The retry and token refresh configuration of a [`FlightClient`](crate::FlightClient)
+#[derive(Clone, Default)]
+pub(crate) struct Middleware {
+    pub(crate) policy: Option<RetryPolicy>,
+    pub(crate) refresher: Option<Arc<dyn TokenRefresher>>,
+}
+
+impl Debug for Middleware {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Middleware")
+            .field("policy", &self.policy)
+            .field("refresher", &self.refresher.is_some())
+            .finish()
+    }
+}
+
+impl Middleware {
+    /// Requests a new token, and sets it as the `authorization` header of `metadata`
+    pub(crate) async fn refresh_token(&self, metadata: &mut MetadataMap) -> Result<()> {
+        let Some(refresher) = &self.refresher else {
+            return Err(FlightError::protocol("No token refresher configured"));
+        };
+        let token = refresher.refresh().await?;
+        let value = MetadataValue::try_from(format!("Bearer {token}"))
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        metadata.insert("authorization", value);
+        Ok(())
+    }
+
+    /// Handles a call failing with `status` after `attempt` retries, returning `Ok`
+    /// once the call should be retried, after refreshing the token or backing off
+    ///
+    /// Without a [`RetryPolicy`], only a single retry after refreshing the token is made
+    pub(crate) async fn on_error(
+        &self,
+        status: Status,
+        attempt: &mut usize,
+        metadata: &mut MetadataMap,
+    ) -> Result<()> {
+        let max_retries = self.policy.as_ref().map_or(1, |p| p.max_retries);
+        if *attempt >= max_retries {
+            return Err(status.into());
+        }
+
+        match &self.policy {
+            _ if status.code() == Code::Unauthenticated && self.refresher.is_some() => {
+                self.refresh_token(metadata).await?
+            }
+            Some(policy) if policy.is_retryable(&status) => {
+                tokio::time::sleep(policy.backoff(*attempt)).await
+            }
+            _ => return Err(status.into()),
+        }
+        *attempt += 1;
+        Ok(())
+    }
+}
+
+/// The state of a [`FlightClient::do_get_resumable`](crate::FlightClient::do_get_resumable) call
+pub(crate) struct ResumableDoGet {
+    inner: FlightServiceClient<Channel>,
+    metadata: MetadataMap,
+    middleware: Middleware,
+    ticket: Ticket,
+    /// The current response, `None` if it must be resumed
+    stream: Option<FlightRecordBatchStream>,
+    /// The number of rows returned so far
+    rows: usize,
+    /// The number of rows of the current response to skip
+    skip: usize,
+    /// The number of consecutive retries
+    attempt: usize,
+}
+
+impl ResumableDoGet {
+    pub(crate) fn new(
+        inner: FlightServiceClient<Channel>,
+        metadata: MetadataMap,
+        middleware: Middleware,
+        ticket: Ticket,
+        stream: FlightRecordBatchStream,
+    ) -> Self {
+        Self {
+            inner,
+            metadata,
+            middleware,
+            ticket,
+            stream: Some(stream),
+            rows: 0,
+            skip: 0,
+            attempt: 0,
+        }
+    }
+
+    pub(crate) fn into_stream(self) -> BoxStream<'static, Result<RecordBatch>> {
+        futures::stream::try_unfold(self, |mut state| async move {
+            Ok(state.next().await?.map(|batch| (batch, state)))
+        })
+        .boxed()
+    }
+
+    /// Returns the next [`RecordBatch`], resuming the call after a transient error
+    async fn next(&mut self) -> Result<Option<RecordBatch>> {
+        loop {
+            let Some(stream) = &mut self.stream else {
+                self.resume().await?;
+                continue;
+            };
+
+            match stream.next().await {
+                None => return Ok(None),
+                Some(Ok(batch)) => {
+                    self.attempt = 0;
+                    let skip = self.skip.min(batch.num_rows());
+                    self.skip -= skip;
+                    if skip == batch.num_rows() {
+                        continue;
+                    }
+                    let batch = batch.slice(skip, batch.num_rows() - skip);
+                    self.rows += batch.num_rows();
+                    return Ok(Some(batch));
+                }
+                Some(Err(FlightError::Tonic(status))) => {
+                    self.stream = None;
+                    self.middleware
+                        .on_error(*status, &mut self.attempt, &mut self.metadata)
+                        .await?;
+                }
+                Some(Err(e)) => return Err(e),
+            }
+        }
+    }
+
+    /// Makes another `DoGet` call, continuing after the rows already returned
+    async fn resume(&mut self) -> Result<()> {
+        let resume_offset_header = self
+            .middleware
+            .policy
+            .as_ref()
+            .is_some_and(|p| p.resume_offset_header);
+        let mut request = tonic::Request::new(self.ticket.clone());
+        *request.metadata_mut() = self.metadata.clone();
+        if resume_offset_header {
+            request
+                .metadata_mut()
+                .insert(RESUME_OFFSET_HEADER, self.rows.into());
+        }
+
+        match self.inner.do_get(request).await {
+            Ok(response) => {
+                let offset = response
+                    .metadata()
+                    .get(RESUME_OFFSET_HEADER)
+                    .filter(|_| resume_offset_header)
+                    .and_then(|v| v.to_str().ok()?.parse::<usize>().ok());
+                self.skip = match offset == Some(self.rows) {
+                    true => 0,
+                    false => self.rows,
+                };
+                let stream = response.into_inner().map_err(FlightError::from);
+                self.stream = Some(FlightRecordBatchStream::new_from_flight_data(stream));
+                Ok(())
+            }
+            Err(status) => {
+                self.middleware
+                    .on_error(status, &mut self.attempt, &mut self.metadata)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new()
+            .with_initial_backoff(Duration::from_millis(10))
+            .with_max_backoff(Duration::from_millis(50));
+        let backoff: Vec<_> = (0..4).map(|a| policy.backoff(a).as_millis()).collect();
+        assert_eq!(backoff, [10, 20, 40, 50]);
+        assert_eq!(policy.backoff(usize::MAX), Duration::from_millis(50));
+
+        assert!(policy.is_retryable(&Status::unavailable("")));
+        assert!(!policy.is_retryable(&Status::invalid_argument("")));
+    }
+
+    #[tokio::test]
+    async fn test_on_error() {
+        let mut metadata = MetadataMap::new();
+        let mut attempt = 0;
+
+        // Errors are returned without a policy
+        let err = Middleware::default()
+            .on_error(Status::unavailable("down"), &mut attempt, &mut metadata)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FlightError::Tonic(s) if s.code() == Code::Unavailable));
+
+        let middleware = Middleware {
+            policy: Some(
+                RetryPolicy::new()
+                    .with_max_retries(2)
+                    .with_initial_backoff(Duration::ZERO),
+            ),
+            refresher: Some(Arc::new(|| async { Ok("token".to_string()) })),
+        };
+
+        let status = Status::unauthenticated("expired");
+        middleware
+            .on_error(status, &mut attempt, &mut metadata)
+            .await
+            .unwrap();
+        assert_eq!(metadata.get("authorization").unwrap(), "Bearer token");
+
+        let status = Status::invalid_argument("bad");
+        let err = middleware
+            .on_error(status, &mut attempt, &mut metadata)
+            .await;
+        assert!(err.is_err());
+
+        let status = Status::unavailable("down");
+        middleware
+            .on_error(status.clone(), &mut attempt, &mut metadata)
+            .await
+            .unwrap();
+        assert_eq!(attempt, 2);
+        let err = middleware
+            .on_error(status, &mut attempt, &mut metadata)
+            .await;
+        assert!(err.is_err());
+    }
+}
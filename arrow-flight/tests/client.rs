@@ -291,6 +291,111 @@ async fn test_do_get_error_in_record_batch_stream() {
     .await;
 }
 
+#[tokio::test]
+#[cfg(feature = "retry")]
+async fn test_get_flight_info_retry() {
+    use arrow_flight::retry::RetryPolicy;
+    use std::time::Duration;
+
+    do_test(|test_server, client| async move {
+        let policy = RetryPolicy::new().with_initial_backoff(Duration::ZERO);
+        let mut client = client
+            .with_retry_policy(policy)
+            .with_token_refresher(|| async { Ok("new-token".to_string()) });
+
+        let expected_response = test_flight_info(&FlightDescriptor::new_cmd("foo"));
+        test_server.set_get_flight_info_response(Err(Status::unauthenticated("expired")));
+        test_server.set_get_flight_info_response(Err(Status::unavailable("busy")));
+        test_server.set_get_flight_info_response(Ok(expected_response.clone()));
+
+        let response = client
+            .get_flight_info(FlightDescriptor::new_cmd("foo"))
+            .await
+            .unwrap();
+        assert_eq!(response, expected_response);
+
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        assert_eq!(metadata.get("authorization").unwrap(), "Bearer new-token");
+
+        // errors that are not transient are not retried
+        let e = Status::invalid_argument("bad argument");
+        test_server.set_get_flight_info_response(Err(e.clone()));
+        let response = client
+            .get_flight_info(FlightDescriptor::new_cmd("foo"))
+            .await
+            .unwrap_err();
+        expect_status(response, e);
+    })
+    .await;
+}
+
+#[tokio::test]
+#[cfg(feature = "retry")]
+async fn test_do_get_resumable() {
+    use arrow_flight::retry::{RetryPolicy, RESUME_OFFSET_HEADER};
+    use std::time::Duration;
+
+    do_test(|test_server, client| async move {
+        let policy = RetryPolicy::new().with_initial_backoff(Duration::ZERO);
+        let mut client = client.with_retry_policy(policy.clone());
+        let ticket = Ticket {
+            ticket: Bytes::from("my awesome flight ticket"),
+        };
+
+        let batch1 = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+        let batch2 = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([5, 6])) as _,
+        )])
+        .unwrap();
+
+        // the server fails after the first batch, and does not skip the
+        // rows already sent when the call is resumed
+        test_server.set_do_get_response(vec![
+            Ok(batch1.clone()),
+            Err(Status::unavailable("connection reset")),
+        ]);
+        test_server.set_do_get_response(vec![Ok(batch1.clone()), Ok(batch2.clone())]);
+
+        let response: Vec<_> = client
+            .do_get_resumable(ticket.clone())
+            .await
+            .expect("error making request")
+            .try_collect()
+            .await
+            .expect("error streaming data");
+        assert_eq!(response, vec![batch1.clone(), batch2.clone()]);
+
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        assert!(metadata.get(RESUME_OFFSET_HEADER).is_none());
+        assert_eq!(test_server.take_do_get_request(), Some(ticket.clone()));
+
+        // the header is only sent if enabled
+        let mut client = client.with_retry_policy(policy.with_resume_offset_header(true));
+        test_server.set_do_get_response(vec![
+            Ok(batch1.clone()),
+            Err(Status::unavailable("connection reset")),
+        ]);
+        test_server.set_do_get_response(vec![Ok(batch1.clone()), Ok(batch2.clone())]);
+        let response: Vec<_> = client
+            .do_get_resumable(ticket.clone())
+            .await
+            .expect("error making request")
+            .try_collect()
+            .await
+            .expect("error streaming data");
+        assert_eq!(response, vec![batch1, batch2]);
+
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        assert_eq!(metadata.get(RESUME_OFFSET_HEADER).unwrap(), "4");
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_put() {
     do_test(|test_server, mut client| async move {
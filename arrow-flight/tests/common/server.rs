@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
 use arrow_array::RecordBatch;
@@ -71,10 +72,12 @@ impl TestFlightServer {
     }
 
     /// Specify the response returned from the next call to get_flight_info
+    ///
+    /// If called multiple times, the responses are returned in order
     #[allow(dead_code)]
     pub fn set_get_flight_info_response(&self, response: Result<FlightInfo, Status>) {
         let mut state = self.state.lock().expect("mutex not poisoned");
-        state.get_flight_info_response.replace(response);
+        state.get_flight_info_response.push_back(response);
     }
 
     /// Take and return last get_flight_info request sent to the server,
@@ -105,10 +108,12 @@ impl TestFlightServer {
     }
 
     /// Specify the response returned from the next call to `do_get`
+    ///
+    /// If called multiple times, the responses are returned in order
     #[allow(dead_code)]
     pub fn set_do_get_response(&self, response: Vec<Result<RecordBatch, Status>>) {
         let mut state = self.state.lock().expect("mutex not poisoned");
-        state.do_get_response.replace(response);
+        state.do_get_response.push_back(response);
     }
 
     /// Take and return last do_get request send to the server,
@@ -250,16 +255,16 @@ struct State {
     pub handshake_response: Option<Result<HandshakeResponse, Status>>,
     /// The last `get_flight_info` request received
     pub get_flight_info_request: Option<FlightDescriptor>,
-    /// The next response to return from `get_flight_info`
-    pub get_flight_info_response: Option<Result<FlightInfo, Status>>,
+    /// The next responses to return from `get_flight_info`
+    pub get_flight_info_response: VecDeque<Result<FlightInfo, Status>>,
     /// The last `poll_flight_info` request received
     pub poll_flight_info_request: Option<FlightDescriptor>,
     /// The next response to return from `poll_flight_info`
     pub poll_flight_info_response: Option<Result<PollInfo, Status>>,
     /// The last do_get request received
     pub do_get_request: Option<Ticket>,
-    /// The next response returned from `do_get`
-    pub do_get_response: Option<Vec<Result<RecordBatch, Status>>>,
+    /// The next responses returned from `do_get`
+    pub do_get_response: VecDeque<Vec<Result<RecordBatch, Status>>>,
     /// The last do_put request received
     pub do_put_request: Option<Vec<FlightData>>,
     /// The next response returned from `do_put`
@@ -353,7 +358,7 @@ impl FlightService for TestFlightServer {
         state.get_flight_info_request = Some(request.into_inner());
         let response = state
             .get_flight_info_response
-            .take()
+            .pop_front()
             .unwrap_or_else(|| Err(Status::internal("No get_flight_info response configured")))?;
         Ok(Response::new(response))
     }
@@ -404,7 +409,7 @@ impl FlightService for TestFlightServer {
 
         let batches: Vec<_> = state
             .do_get_response
-            .take()
+            .pop_front()
             .ok_or_else(|| Status::internal("No do_get response configured"))?;
 
         let batch_stream = futures::stream::iter(batches).map_err(Into::into);
@@ -15,9 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::writer::MessageBody;
 use crate::CompressionType;
 use arrow_buffer::Buffer;
 use arrow_schema::ArrowError;
+use std::io::Write;
 
 const LENGTH_NO_COMPRESSED_DATA: i64 = -1;
 const LENGTH_OF_PREFIX_DATA: i64 = 8;
@@ -54,10 +56,10 @@ impl CompressionCodec {
     /// [8 bytes]:         uncompressed length
     /// [remaining bytes]: compressed data stream
     /// ```
-    pub(crate) fn compress_to_vec(
+    pub(crate) fn compress_to_vec<B: MessageBody>(
         &self,
         input: &[u8],
-        output: &mut Vec<u8>,
+        output: &mut B,
     ) -> Result<usize, ArrowError> {
         let uncompressed_data_len = input.len();
         let original_output_len = output.len();
@@ -76,13 +78,31 @@ impl CompressionCodec {
                 // length -1 to indicate that we don't compress the
                 // data
                 output.truncate(original_output_len);
-                output.extend_from_slice(&LENGTH_NO_COMPRESSED_DATA.to_le_bytes());
-                output.extend_from_slice(input);
+                Self::store_uncompressed_to_vec(input, output);
             }
         }
         Ok(output.len() - original_output_len)
     }
 
+    /// Appends the data in `input` to `output` without compressing it, in the
+    /// format of a compressed buffer
+    ///
+    /// returns the number of bytes written to the stream
+    ///
+    /// Writes this format to output:
+    /// ```text
+    /// [8 bytes]:         -1
+    /// [remaining bytes]: uncompressed data
+    /// ```
+    pub(crate) fn store_uncompressed_to_vec<B: MessageBody>(input: &[u8], output: &mut B) -> usize {
+        if input.is_empty() {
+            return 0;
+        }
+        output.extend_from_slice(&LENGTH_NO_COMPRESSED_DATA.to_le_bytes());
+        output.extend_from_slice(input);
+        LENGTH_OF_PREFIX_DATA as usize + input.len()
+    }
+
     /// Decompresses the input into a [`Buffer`]
     ///
     /// The input should look like:
@@ -115,7 +135,7 @@ impl CompressionCodec {
 
     /// Compress the data in input buffer and write to output buffer
     /// using the specified compression
-    fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
+    fn compress(&self, input: &[u8], output: &mut impl Write) -> Result<(), ArrowError> {
         match self {
             CompressionCodec::Lz4Frame => compress_lz4(input, output),
             CompressionCodec::Zstd => compress_zstd(input, output),
@@ -140,8 +160,7 @@ impl CompressionCodec {
 }
 
 #[cfg(feature = "lz4")]
-fn compress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
-    use std::io::Write;
+fn compress_lz4(input: &[u8], output: &mut impl Write) -> Result<(), ArrowError> {
     let mut encoder = lz4_flex::frame::FrameEncoder::new(output);
     encoder.write_all(input)?;
     encoder
@@ -151,8 +170,7 @@ fn compress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
 }
 
 #[cfg(not(feature = "lz4"))]
-#[allow(clippy::ptr_arg)]
-fn compress_lz4(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_lz4(_input: &[u8], _output: &mut impl Write) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "lz4 IPC compression requires the lz4 feature".to_string(),
     ))
@@ -175,8 +193,7 @@ fn decompress_lz4(_input: &[u8], _decompressed_size: usize) -> Result<Vec<u8>, A
 }
 
 #[cfg(feature = "zstd")]
-fn compress_zstd(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
-    use std::io::Write;
+fn compress_zstd(input: &[u8], output: &mut impl Write) -> Result<(), ArrowError> {
     let mut encoder = zstd::Encoder::new(output, 0)?;
     encoder.write_all(input)?;
     encoder.finish()?;
@@ -184,8 +201,7 @@ fn compress_zstd(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
 }
 
 #[cfg(not(feature = "zstd"))]
-#[allow(clippy::ptr_arg)]
-fn compress_zstd(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_zstd(_input: &[u8], _output: &mut impl Write) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "zstd IPC compression requires the zstd feature".to_string(),
     ))
@@ -24,6 +24,18 @@ mod stream;
 
 pub use stream::*;
 
+#[cfg(feature = "async")]
+mod async_reader;
+
+#[cfg(feature = "async")]
+pub use async_reader::*;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+
 use flatbuffers::{VectorIter, VerifierOptions};
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
@@ -32,6 +44,7 @@ use std::sync::Arc;
 
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, ScalarBuffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
 
@@ -62,6 +75,76 @@ fn read_buffer(
     }
 }
 
+/// A message that a stream reader in compatibility mode did not understand, and
+/// tolerated rather than returning an error
+///
+/// See [`StreamReader::with_compatibility_mode`] and [`StreamDecoder::with_compatibility_mode`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CompatibilityWarning {
+    /// A message of a type the reader does not support, such as one added by a newer
+    /// version of the format, was skipped
+    SkippedMessage {
+        /// The type of the skipped message
+        header_type: crate::MessageHeader,
+        /// The length of the skipped message body
+        body_length: usize,
+        /// The custom metadata of the skipped message
+        custom_metadata: HashMap<String, String>,
+    },
+    /// A message was written with a newer metadata version than the reader
+    /// supports, and was read as the latest supported version
+    UnknownVersion {
+        /// The metadata version of the message
+        version: MetadataVersion,
+    },
+}
+
+/// The callback of a reader in compatibility mode
+#[derive(Clone)]
+pub(crate) struct CompatibilityHandler(Arc<dyn Fn(&CompatibilityWarning) + Send + Sync>);
+
+impl CompatibilityHandler {
+    pub(crate) fn new(f: impl Fn(&CompatibilityWarning) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Reports `message` if it was written with an unknown metadata version
+    pub(crate) fn check_version(&self, message: &Message) {
+        if message.version() > MetadataVersion::V5 {
+            (self.0)(&CompatibilityWarning::UnknownVersion {
+                version: message.version(),
+            })
+        }
+    }
+
+    /// Reports that `message` was skipped
+    pub(crate) fn skipped(&self, message: &Message) {
+        (self.0)(&CompatibilityWarning::SkippedMessage {
+            header_type: message.header_type(),
+            body_length: message.bodyLength() as usize,
+            custom_metadata: message_custom_metadata(message),
+        })
+    }
+}
+
+impl fmt::Debug for CompatibilityHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompatibilityHandler")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the custom metadata of `message`
+pub(crate) fn message_custom_metadata(message: &Message) -> HashMap<String, String> {
+    message
+        .custom_metadata()
+        .into_iter()
+        .flatten()
+        .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+        .collect()
+}
+
 /// Coordinates reading arrays based on data types.
 ///
 /// `variadic_counts` encodes the number of buffers to read for variadic types (e.g., Utf8View, BinaryView)
@@ -127,6 +210,22 @@ fn create_array(
                 require_alignment,
             )
         }
+        ListView(ref list_field) | LargeListView(ref list_field) => {
+            let list_node = reader.next_node(field)?;
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_buffer()?,
+                reader.next_buffer()?,
+            ];
+            let values = create_array(reader, list_field, variadic_counts, require_alignment)?;
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers,
+                values,
+                require_alignment,
+            )
+        }
         FixedSizeList(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
             let list_buffers = [reader.next_buffer()?];
@@ -327,6 +426,13 @@ fn create_list_array(
             .add_child_data(child_data)
             .null_bit_buffer(null_buffer),
 
+        ListView(_) | LargeListView(_) => ArrayData::builder(data_type.clone())
+            .len(length)
+            .add_buffer(buffers[1].clone())
+            .add_buffer(buffers[2].clone())
+            .add_child_data(child_data)
+            .null_bit_buffer(null_buffer),
+
         FixedSizeList(_, _) => ArrayData::builder(data_type.clone())
             .len(length)
             .add_child_data(child_data)
@@ -433,6 +539,12 @@ impl<'a> ArrayReader<'a> {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
             }
+            ListView(list_field) | LargeListView(list_field) => {
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_field(list_field, variadic_count)?;
+            }
             FixedSizeList(list_field, _) => {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
@@ -591,12 +703,6 @@ fn read_dictionary_impl(
     metadata: &MetadataVersion,
     require_alignment: bool,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::InvalidArgumentError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     #[allow(deprecated)]
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
@@ -630,14 +736,38 @@ fn read_dictionary_impl(
         ArrowError::InvalidArgumentError(format!("dictionary id {id} not found in schema"))
     })?;
 
+    // A delta dictionary batch appends its values to the existing dictionary
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "delta dictionary batch for dictionary id {id} received before the dictionary"
+                ))
+            })?;
+            concat_dictionary_values(existing, &dictionary_values)
+        }
+        false => dictionary_values,
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Appends the values of a delta dictionary batch to `existing`
+fn concat_dictionary_values(existing: &ArrayRef, delta: &ArrayRef) -> ArrayRef {
+    let existing = existing.to_data();
+    let delta = delta.to_data();
+    let capacity = existing.len() + delta.len();
+    let mut values = MutableArrayData::new(vec![&existing, &delta], false, capacity);
+    values.extend(0, 0, existing.len());
+    values.extend(1, 0, delta.len());
+    make_array(values.freeze())
+}
+
 /// Read the data for a given block
 fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
@@ -1148,6 +1278,12 @@ pub struct StreamReader<R> {
 
     /// Optional projection
     projection: Option<(Vec<usize>, Schema)>,
+
+    /// The custom metadata of the last message read
+    message_metadata: HashMap<String, String>,
+
+    /// Optional callback of the compatibility mode
+    compatibility: Option<CompatibilityHandler>,
 }
 
 impl<R> fmt::Debug for StreamReader<R> {
@@ -1158,6 +1294,8 @@ impl<R> fmt::Debug for StreamReader<R> {
             .field("dictionaries_by_id", &self.dictionaries_by_id)
             .field("finished", &self.finished)
             .field("projection", &self.projection)
+            .field("message_metadata", &self.message_metadata)
+            .field("compatibility", &self.compatibility.is_some())
             .finish()
     }
 }
@@ -1210,6 +1348,7 @@ impl<R: Read> StreamReader<R> {
             ArrowError::ParseError("Unable to read IPC message as schema".to_string())
         })?;
         let schema = crate::convert::fb_to_schema(ipc_schema);
+        let message_metadata = message_custom_metadata(&message);
 
         // Create an array of optional dictionary value arrays, one per field.
         let dictionaries_by_id = HashMap::new();
@@ -1227,9 +1366,36 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             dictionaries_by_id,
             projection,
+            message_metadata,
+            compatibility: None,
         })
     }
 
+    /// Enable a compatibility mode for reading streams written by newer writers
+    ///
+    /// Rather than returning an error, messages of types this reader does not support
+    /// are skipped, and `on_warning` is called with a [`CompatibilityWarning`]. The
+    /// callback is also called for messages written with a newer metadata version
+    /// than this reader supports, which are otherwise read as the latest version.
+    pub fn with_compatibility_mode(
+        mut self,
+        on_warning: impl Fn(&CompatibilityWarning) + Send + Sync + 'static,
+    ) -> Self {
+        self.compatibility = Some(CompatibilityHandler::new(on_warning));
+        self
+    }
+
+    /// Return the custom metadata of the last message read, or of the schema
+    /// message if no other message has been read
+    ///
+    /// This is usually the message of the last [`RecordBatch`] returned, but may
+    /// be that of a dictionary batch, or a message skipped in compatibility mode,
+    /// read after it. The custom metadata of the schema itself is available from
+    /// [`Self::schema`]
+    pub fn message_metadata(&self) -> &HashMap<String, String> {
+        &self.message_metadata
+    }
+
     /// Deprecated, use [`StreamReader::try_new`] instead.
     #[deprecated(since = "53.0.0", note = "use `try_new` instead")]
     pub fn try_new_unbuffered(
@@ -1293,6 +1459,10 @@ impl<R: Read> StreamReader<R> {
         let message = crate::root_as_message(vecs).map_err(|err| {
             ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
         })?;
+        if let Some(compatibility) = &self.compatibility {
+            compatibility.check_version(&message);
+        }
+        self.message_metadata = message_custom_metadata(&message);
 
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -1340,9 +1510,24 @@ impl<R: Read> StreamReader<R> {
                 self.maybe_next()
             }
             crate::MessageHeader::NONE => Ok(None),
-            t => Err(ArrowError::InvalidArgumentError(format!(
-                "Reading types other than record batches not yet supported, unable to read {t:?} "
-            ))),
+            t => match &self.compatibility {
+                Some(compatibility) => {
+                    // skip over the message body
+                    let body_length = message.bodyLength() as u64;
+                    let skipped = std::io::copy(
+                        &mut (&mut self.reader).take(body_length),
+                        &mut std::io::sink(),
+                    )?;
+                    if skipped != body_length {
+                        return Err(ArrowError::IpcError("Unexpected End of Stream".to_string()));
+                    }
+                    compatibility.skipped(&message);
+                    self.maybe_next()
+                }
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "Reading types other than record batches not yet supported, unable to read {t:?} "
+                ))),
+            },
         }
     }
 
@@ -2367,4 +2552,248 @@ mod tests {
                 assert_eq!(decoded_batch.expect("Failed to read RecordBatch"), batch);
             });
     }
+
+    /// Encodes a message with an unsupported header type, as a newer writer might
+    pub(super) fn unknown_message(version: MetadataVersion, body: &[u8]) -> Vec<u8> {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let table = fbb.start_table();
+        let header = fbb.end_table(table).as_union_value();
+        let metadata = HashMap::from([("origin".to_string(), "future".to_string())]);
+        let custom_metadata = crate::convert::metadata_to_fb(&mut fbb, &metadata);
+        let message = crate::Message::create(
+            &mut fbb,
+            &crate::MessageArgs {
+                version,
+                header_type: crate::MessageHeader(42),
+                header: Some(header),
+                bodyLength: body.len() as i64,
+                custom_metadata: Some(custom_metadata),
+            },
+        );
+        fbb.finish(message, None);
+
+        let encoded = crate::writer::EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: body.to_vec(),
+        };
+        let mut out = vec![];
+        crate::writer::write_message(&mut out, encoded, &IpcWriteOptions::default()).unwrap();
+        out
+    }
+
+    /// Returns a stream of `batch`, followed by `message`, followed by `batch` again
+    pub(super) fn stream_with_message(batch: &RecordBatch, message: &[u8]) -> Vec<u8> {
+        let mut writer = crate::writer::StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(batch).unwrap();
+        writer.get_mut().extend_from_slice(message);
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_stream_reader_compatibility_mode() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let stream = stream_with_message(&batch, &unknown_message(MetadataVersion::V5, &[1; 64]));
+
+        // Without the compatibility mode unknown messages are an error
+        let reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        let err = reader.collect::<Result<Vec<_>, _>>().unwrap_err();
+        assert!(err.to_string().contains("<UNKNOWN 42>"), "{err}");
+
+        let warnings = Arc::new(std::sync::Mutex::new(vec![]));
+        let captured = warnings.clone();
+        let mut reader = StreamReader::try_new(stream.as_slice(), None)
+            .unwrap()
+            .with_compatibility_mode(move |w| captured.lock().unwrap().push(w.clone()));
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert!(reader.next().is_none());
+
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(
+            warnings.as_slice(),
+            &[CompatibilityWarning::SkippedMessage {
+                header_type: crate::MessageHeader(42),
+                body_length: 64,
+                custom_metadata: HashMap::from([("origin".to_string(), "future".to_string())]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stream_reader_unknown_version() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let stream = stream_with_message(&batch, &unknown_message(MetadataVersion(5), &[]));
+
+        let warnings = Arc::new(std::sync::Mutex::new(vec![]));
+        let captured = warnings.clone();
+        let reader = StreamReader::try_new(stream.as_slice(), None)
+            .unwrap()
+            .with_compatibility_mode(move |w| captured.lock().unwrap().push(w.clone()));
+        assert_eq!(reader.count(), 2);
+
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0],
+            CompatibilityWarning::UnknownVersion {
+                version: MetadataVersion(5)
+            }
+        );
+        assert!(matches!(
+            warnings[1],
+            CompatibilityWarning::SkippedMessage { body_length: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_stream_reader_message_metadata() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let stream = stream_with_message(&batch, &[]);
+        let mut reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        assert!(reader.message_metadata().is_empty());
+        reader.next().unwrap().unwrap();
+        assert!(reader.message_metadata().is_empty());
+    }
+
+    /// Encodes a record batch or dictionary batch message of a single non-null
+    /// Int32 column with `values`, and the provided custom metadata
+    fn int32_message(header_type: crate::MessageHeader, values: &[i32], key: &str) -> Vec<u8> {
+        let mut body: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        // Pad the body to the default alignment of the writer
+        while body.len() % 64 != 0 {
+            body.push(0);
+        }
+
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let nodes = fbb.create_vector(&[crate::FieldNode::new(values.len() as i64, 0)]);
+        let buffers = fbb.create_vector(&[
+            crate::Buffer::new(0, 0),
+            crate::Buffer::new(0, body.len() as i64),
+        ]);
+        let batch = crate::RecordBatch::create(
+            &mut fbb,
+            &crate::RecordBatchArgs {
+                length: values.len() as i64,
+                nodes: Some(nodes),
+                buffers: Some(buffers),
+                ..Default::default()
+            },
+        );
+        let header = match header_type {
+            crate::MessageHeader::RecordBatch => batch.as_union_value(),
+            _ => crate::DictionaryBatch::create(
+                &mut fbb,
+                &crate::DictionaryBatchArgs {
+                    id: 0,
+                    data: Some(batch),
+                    isDelta: false,
+                },
+            )
+            .as_union_value(),
+        };
+        let metadata = HashMap::from([("message".to_string(), key.to_string())]);
+        let custom_metadata = crate::convert::metadata_to_fb(&mut fbb, &metadata);
+        let message = crate::Message::create(
+            &mut fbb,
+            &crate::MessageArgs {
+                version: MetadataVersion::V5,
+                header_type,
+                header: Some(header),
+                bodyLength: body.len() as i64,
+                custom_metadata: Some(custom_metadata),
+            },
+        );
+        fbb.finish(message, None);
+
+        let encoded = crate::writer::EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: body,
+        };
+        let mut out = vec![];
+        crate::writer::write_message(&mut out, encoded, &IpcWriteOptions::default()).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_stream_reader_message_metadata_non_empty() {
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int32));
+        let schema = Schema::new(vec![Field::new("a", data_type, false)]);
+        let mut writer = crate::writer::StreamWriter::try_new(vec![], &schema).unwrap();
+        let stream = writer.get_mut();
+        stream.extend(int32_message(
+            crate::MessageHeader::DictionaryBatch,
+            &[10, 20],
+            "dictionary",
+        ));
+        stream.extend(int32_message(
+            crate::MessageHeader::RecordBatch,
+            &[0, 1, 0],
+            "batch",
+        ));
+        stream.extend(int32_message(
+            crate::MessageHeader::DictionaryBatch,
+            &[30],
+            "replacement",
+        ));
+        writer.finish().unwrap();
+        let stream = writer.into_inner().unwrap();
+
+        let message_metadata =
+            |key: &str| HashMap::from([("message".to_string(), key.to_string())]);
+        let mut reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        assert!(reader.message_metadata().is_empty());
+
+        let batch = reader.next().unwrap().unwrap();
+        let expected: DictionaryArray<Int32Type> = DictionaryArray::new(
+            vec![0, 1, 0].into(),
+            Arc::new(Int32Array::from(vec![10, 20])),
+        );
+        assert_eq!(
+            arrow_array::cast::AsArray::as_dictionary::<Int32Type>(batch.column(0)),
+            &expected
+        );
+        assert_eq!(reader.message_metadata(), &message_metadata("batch"));
+
+        // The replacement dictionary batch is read after the last batch
+        assert!(reader.next().is_none());
+        assert_eq!(reader.message_metadata(), &message_metadata("replacement"));
+
+        // As is a message skipped in compatibility mode
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut writer = crate::writer::StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let message = unknown_message(MetadataVersion::V5, &[]);
+        writer.get_mut().extend_from_slice(&message);
+        writer.finish().unwrap();
+        let stream = writer.into_inner().unwrap();
+
+        let mut reader = StreamReader::try_new(stream.as_slice(), None)
+            .unwrap()
+            .with_compatibility_mode(|_| {});
+        reader.next().unwrap().unwrap();
+        assert!(reader.message_metadata().is_empty());
+        assert!(reader.next().is_none());
+        assert_eq!(
+            reader.message_metadata(),
+            &HashMap::from([("origin".to_string(), "future".to_string())])
+        );
+    }
 }
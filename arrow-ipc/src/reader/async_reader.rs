@@ -0,0 +1,571 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Async readers of the Arrow IPC stream and file formats
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::reader::{read_footer_length, FileDecoder, StreamDecoder};
+use crate::{Block, CONTINUATION_MARKER};
+
+/// The default number of messages read ahead by [`AsyncStreamReader`] and [`AsyncFileReader`]
+pub const DEFAULT_READAHEAD: usize = 2;
+
+/// Reads `messages` in a background task, sending them to the returned channel
+///
+/// Up to `readahead` messages are buffered in the channel, so the next messages
+/// are read while the current one is being decoded and consumed.
+fn spawn_readahead(
+    readahead: usize,
+    mut messages: BoxStream<'static, Result<Buffer, ArrowError>>,
+) -> (mpsc::Receiver<Result<Buffer, ArrowError>>, JoinHandle<()>) {
+    let (sender, receiver) = mpsc::channel(readahead.max(1));
+    let handle = tokio::spawn(async move {
+        while let Some(message) = messages.next().await {
+            let is_err = message.is_err();
+            if sender.send(message).await.is_err() || is_err {
+                return;
+            }
+        }
+    });
+    (receiver, handle)
+}
+
+/// Reads into `buf`, returning `false` if the reader is at EOF before reading any bytes
+async fn read_exact_or_eof<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<bool, ArrowError> {
+    let mut read = 0;
+    while read < buf.len() {
+        match reader.read(&mut buf[read..]).await? {
+            0 if read == 0 => return Ok(false),
+            0 => return Err(ArrowError::IpcError("Unexpected End of Stream".to_string())),
+            n => read += n,
+        }
+    }
+    Ok(true)
+}
+
+/// Reads the next encapsulated message of an IPC stream, including its body,
+/// returning `None` at EOF
+async fn read_stream_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<Buffer>, ArrowError> {
+    let mut prefix = [0; 4];
+    if !read_exact_or_eof(reader, &mut prefix).await? {
+        return Ok(None);
+    }
+    let mut header = prefix.to_vec();
+    if prefix == CONTINUATION_MARKER {
+        reader.read_exact(&mut prefix).await?;
+        header.extend_from_slice(&prefix);
+    }
+    let meta_len = i32::from_le_bytes(prefix);
+    let meta_len = usize::try_from(meta_len)
+        .map_err(|_| ArrowError::ParseError(format!("Invalid metadata length: {meta_len}")))?;
+    if meta_len == 0 {
+        // End of stream marker
+        return Ok(Some(Buffer::from_vec(header)));
+    }
+
+    let mut meta = vec![0; meta_len];
+    reader.read_exact(&mut meta).await?;
+    let message = crate::root_as_message(&meta)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))?;
+    let body_len = usize::try_from(message.bodyLength()).map_err(|_| {
+        ArrowError::ParseError(format!("Invalid body length: {}", message.bodyLength()))
+    })?;
+
+    let mut buf = MutableBuffer::from_len_zeroed(header.len() + meta_len + body_len);
+    buf[..header.len()].copy_from_slice(&header);
+    buf[header.len()..header.len() + meta_len].copy_from_slice(&meta);
+    reader
+        .read_exact(&mut buf[header.len() + meta_len..])
+        .await?;
+    Ok(Some(buf.into()))
+}
+
+/// Reads `block` of an IPC file
+async fn read_block<R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut R,
+    block: &Block,
+) -> Result<Buffer, ArrowError> {
+    reader.seek(SeekFrom::Start(block.offset() as u64)).await?;
+    let body_len = usize::try_from(block.bodyLength())
+        .map_err(|_| ArrowError::ParseError("Invalid block body length".to_string()))?;
+    let metadata_len = usize::try_from(block.metaDataLength())
+        .map_err(|_| ArrowError::ParseError("Invalid block metadata length".to_string()))?;
+
+    let mut buf = MutableBuffer::from_len_zeroed(body_len + metadata_len);
+    reader.read_exact(&mut buf).await?;
+    Ok(buf.into())
+}
+
+/// An async reader of the Arrow IPC stream format, that implements
+/// [`Stream`] of [`RecordBatch`]
+///
+/// Messages are read from the underlying [`AsyncRead`] in a background tokio task,
+/// up to a configurable number of messages ahead of the batches being consumed.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::AsyncStreamReader;
+/// # use arrow_ipc::writer::StreamWriter;
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+/// ]).unwrap();
+///
+/// let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let reader = AsyncStreamReader::try_new(std::io::Cursor::new(bytes), None).await.unwrap();
+/// let batches: Vec<_> = reader.try_collect().await.unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// # }
+/// ```
+pub struct AsyncStreamReader {
+    /// The schema read from the stream, projected if a projection was given
+    schema: SchemaRef,
+    /// Optional projection applied to each batch
+    projection: Option<Vec<usize>>,
+    /// Decodes the messages read ahead
+    decoder: StreamDecoder,
+    /// The messages read ahead
+    messages: mpsc::Receiver<Result<Buffer, ArrowError>>,
+    /// The task reading messages
+    handle: JoinHandle<()>,
+    /// Whether the end of the stream was reached
+    finished: bool,
+}
+
+impl fmt::Debug for AsyncStreamReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncStreamReader")
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AsyncStreamReader {
+    /// Try to create a new async stream reader, reading the schema from `reader`,
+    /// with [`DEFAULT_READAHEAD`] messages read ahead
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if the reader does not encounter a schema
+    /// as the first message in the stream.
+    pub async fn try_new<R>(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        Self::try_new_with_readahead(reader, projection, DEFAULT_READAHEAD).await
+    }
+
+    /// Try to create a new async stream reader, reading up to `readahead`
+    /// messages ahead of the batches being consumed
+    ///
+    /// See [`Self::try_new`] for details
+    pub async fn try_new_with_readahead<R>(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+        readahead: usize,
+    ) -> Result<Self, ArrowError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut decoder = StreamDecoder::new();
+        let mut message = read_stream_message(&mut reader)
+            .await?
+            .ok_or_else(|| ArrowError::IpcError("Unexpected End of Stream".to_string()))?;
+        decoder.decode(&mut message)?;
+        let schema = decoder.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to read IPC message as schema".to_string())
+        })?;
+        let schema = match &projection {
+            Some(projection) => Arc::new(schema.project(projection)?),
+            None => schema,
+        };
+
+        let messages = futures::stream::try_unfold(reader, |mut reader| async move {
+            let message = read_stream_message(&mut reader).await?;
+            Ok(message.map(|message| (message, reader)))
+        });
+        let (messages, handle) = spawn_readahead(readahead, messages.boxed());
+
+        Ok(Self {
+            schema,
+            projection,
+            decoder,
+            messages,
+            handle,
+            finished: false,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl Stream for AsyncStreamReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        while !self.finished {
+            let mut message = match ready!(self.messages.poll_recv(cx)) {
+                Some(Ok(message)) => message,
+                Some(Err(e)) => {
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                None => {
+                    self.finished = true;
+                    return Poll::Ready(self.decoder.finish().err().map(Err));
+                }
+            };
+            match self.decoder.decode(&mut message) {
+                Ok(Some(batch)) => {
+                    let batch = match &self.projection {
+                        Some(projection) => batch.project(projection),
+                        None => Ok(batch),
+                    };
+                    return Poll::Ready(Some(batch));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+impl Drop for AsyncStreamReader {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// An async reader of the Arrow IPC file format, that implements
+/// [`Stream`] of [`RecordBatch`]
+///
+/// The footer and dictionaries are read when the reader is created. The record
+/// batches are then read from the underlying [`AsyncRead`] in a background tokio
+/// task, up to a configurable number of batches ahead of those being consumed.
+pub struct AsyncFileReader {
+    /// Decodes the record batches read ahead
+    decoder: FileDecoder,
+    /// The record batch blocks not yet consumed
+    blocks: std::vec::IntoIter<Block>,
+    /// The number of record batches in the file
+    num_batches: usize,
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+    /// The record batches read ahead
+    messages: mpsc::Receiver<Result<Buffer, ArrowError>>,
+    /// The task reading record batches
+    handle: JoinHandle<()>,
+}
+
+impl fmt::Debug for AsyncFileReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncFileReader")
+            .field("decoder", &self.decoder)
+            .field("num_batches", &self.num_batches)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AsyncFileReader {
+    /// Try to create a new async file reader, with [`DEFAULT_READAHEAD`] record
+    /// batches read ahead
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if:
+    /// - the file does not meet the Arrow Format footer requirements, or
+    /// - file endianness does not match the target endianness.
+    pub async fn try_new<R>(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
+        Self::try_new_with_readahead(reader, projection, DEFAULT_READAHEAD).await
+    }
+
+    /// Try to create a new async file reader, reading up to `readahead` record
+    /// batches ahead of those being consumed
+    ///
+    /// See [`Self::try_new`] for details
+    pub async fn try_new_with_readahead<R>(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+        readahead: usize,
+    ) -> Result<Self, ArrowError>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        let mut buffer = [0; 10];
+        reader.seek(SeekFrom::End(-10)).await?;
+        reader.read_exact(&mut buffer).await?;
+        let footer_len = read_footer_length(buffer)?;
+
+        // read footer
+        let mut footer_data = vec![0; footer_len];
+        reader.seek(SeekFrom::End(-10 - footer_len as i64)).await?;
+        reader.read_exact(&mut footer_data).await?;
+
+        let footer = crate::root_as_footer(&footer_data).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+        let blocks: Vec<Block> = footer
+            .recordBatches()
+            .ok_or_else(|| {
+                ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
+            })?
+            .iter()
+            .copied()
+            .collect();
+        let dictionaries: Vec<Block> = footer
+            .dictionaries()
+            .map(|d| d.iter().copied().collect())
+            .unwrap_or_default();
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        if !ipc_schema.endianness().equals_to_target_endianness() {
+            return Err(ArrowError::IpcError(
+                "the endianness of the source system does not match the endianness of the target system.".to_owned()
+            ));
+        }
+        let schema = crate::convert::fb_to_schema(ipc_schema);
+
+        let custom_metadata = footer
+            .custom_metadata()
+            .into_iter()
+            .flatten()
+            .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+            .collect();
+
+        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        if let Some(projection) = projection {
+            decoder = decoder.with_projection(projection)
+        }
+
+        for block in &dictionaries {
+            let buf = read_block(&mut reader, block).await?;
+            decoder.read_dictionary(block, &buf)?;
+        }
+
+        let num_batches = blocks.len();
+        let state = (reader, blocks.clone().into_iter());
+        let messages = futures::stream::try_unfold(state, |(mut reader, mut blocks)| async move {
+            let Some(block) = blocks.next() else {
+                return Ok(None);
+            };
+            let buf = read_block(&mut reader, &block).await?;
+            Ok(Some((buf, (reader, blocks))))
+        });
+        let (messages, handle) = spawn_readahead(readahead, messages.boxed());
+
+        Ok(Self {
+            decoder,
+            blocks: blocks.into_iter(),
+            num_batches,
+            custom_metadata,
+            messages,
+            handle,
+        })
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.num_batches
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+impl Stream for AsyncFileReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let buf = match ready!(self.messages.poll_recv(cx)) {
+                Some(Ok(buf)) => buf,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+            let Some(block) = self.blocks.next() else {
+                return Poll::Ready(None);
+            };
+            match self.decoder.read_record_batch(&block, &buf) {
+                Ok(Some(batch)) => return Poll::Ready(Some(Ok(batch))),
+                Ok(None) => {}
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+impl Drop for AsyncFileReader {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{FileWriter, StreamWriter};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+    use tokio::io::AsyncWriteExt;
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..5)
+            .map(|i| {
+                let ints = Int32Array::from_iter_values(i * 100..(i + 1) * 100);
+                let strings = StringArray::from_iter_values((0..100).map(|j| format!("{i}-{j}")));
+                let dict: DictionaryArray<Int32Type> =
+                    (0..100).map(|j| ["a", "b", "c"][j % 3]).collect();
+                RecordBatch::try_from_iter([
+                    ("i", Arc::new(ints) as ArrayRef),
+                    ("s", Arc::new(strings) as ArrayRef),
+                    ("d", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = batches();
+        let schema = batches[0].schema();
+        let mut writer = StreamWriter::try_new(vec![], &schema).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        // Write the stream in small chunks, as if received from the network
+        let (mut client, server) = tokio::io::duplex(64);
+        let data = bytes.clone();
+        tokio::spawn(async move {
+            for chunk in data.chunks(50) {
+                client.write_all(chunk).await.unwrap();
+            }
+        });
+        let reader = AsyncStreamReader::try_new_with_readahead(server, None, 1)
+            .await
+            .unwrap();
+        assert_eq!(reader.schema(), schema);
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let reader = AsyncStreamReader::try_new(Cursor::new(bytes.clone()), Some(vec![2, 0]))
+            .await
+            .unwrap();
+        assert_eq!(reader.schema().fields().len(), 2);
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        let expected: Vec<_> = batches
+            .iter()
+            .map(|b| b.project(&[2, 0]).unwrap())
+            .collect();
+        assert_eq!(read, expected);
+
+        // A truncated stream is an error
+        let truncated = bytes[..bytes.len() - 100].to_vec();
+        let reader = AsyncStreamReader::try_new(Cursor::new(truncated), None)
+            .await
+            .unwrap();
+        let err = reader.try_collect::<Vec<_>>().await.unwrap_err();
+        assert!(err.to_string().contains("early eof"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_async_file_reader() {
+        let batches = batches();
+        let schema = batches[0].schema();
+        let mut writer = FileWriter::try_new(vec![], &schema).unwrap();
+        writer.write_metadata("key", "value");
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = AsyncFileReader::try_new(Cursor::new(bytes.clone()), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.num_batches(), 5);
+        assert_eq!(reader.schema(), schema);
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let reader = AsyncFileReader::try_new_with_readahead(Cursor::new(bytes), Some(vec![1]), 1)
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        let expected: Vec<_> = batches.iter().map(|b| b.project(&[1]).unwrap()).collect();
+        assert_eq!(read, expected);
+    }
+}
@@ -0,0 +1,354 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A memory-mapped reader of the Arrow IPC file format
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+use memmap2::Mmap;
+
+use crate::reader::{read_footer_length, FileDecoder};
+use crate::Block;
+
+/// An Arrow IPC file reader over a memory-mapped file, or any other [`Buffer`]
+/// containing a complete IPC file
+///
+/// The footer is validated and the dictionaries decoded when the reader is
+/// created, but each [`RecordBatch`] is only decoded when it is read. The
+/// buffers of the decoded batches reference the mapped region without copying,
+/// unless they are compressed or not aligned, so reading a file does not
+/// duplicate it in memory.
+///
+/// The decoded array data is validated, as with [`FileReader`](crate::reader::FileReader).
+/// Use [`Self::with_require_alignment`] to return an error rather than copy
+/// unaligned buffers.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::MmapFileReader;
+/// # use arrow_ipc::writer::FileWriter;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+/// ]).unwrap();
+///
+/// let mut file = tempfile::tempfile().unwrap();
+/// let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// // Safety: the file is not modified while mapped
+/// let reader = unsafe { MmapFileReader::try_new(&file, None) }.unwrap();
+/// assert_eq!(reader.num_batches(), 1);
+/// assert_eq!(reader.batch(0).unwrap(), batch);
+/// ```
+#[derive(Debug)]
+pub struct MmapFileReader {
+    /// The complete IPC file
+    buffer: Buffer,
+    /// The decoder
+    decoder: FileDecoder,
+    /// The record batch blocks in the file
+    blocks: Vec<Block>,
+    /// The index of the next record batch read by [`Iterator::next`]
+    current_block: usize,
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+}
+
+impl MmapFileReader {
+    /// Try to create a new reader over a memory map of `file`
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, including truncated, while this reader or any
+    /// [`RecordBatch`] read from it exists, as the batches reference the mapped region.
+    /// See [`Mmap::map`] for details.
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if:
+    /// - the file cannot be mapped,
+    /// - the file does not meet the Arrow Format footer requirements, or
+    /// - file endianness does not match the target endianness.
+    pub unsafe fn try_new(file: &File, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
+        let mmap = Arc::new(Mmap::map(file)?);
+        let len = mmap.len();
+        let buffer = match NonNull::new(mmap.as_ptr() as *mut u8) {
+            // Safety: the mapped region is valid for `len` bytes while `mmap` is alive
+            Some(ptr) if len > 0 => Buffer::from_custom_allocation(ptr, len, mmap),
+            _ => Buffer::from_vec(Vec::<u8>::new()),
+        };
+        Self::try_new_from_buffer(buffer, projection)
+    }
+
+    /// Try to create a new reader over `buffer` containing a complete IPC file
+    ///
+    /// See [`Self::try_new`] for details
+    pub fn try_new_from_buffer(
+        buffer: Buffer,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let trailer_start = buffer.len().checked_sub(10).ok_or_else(|| {
+            ArrowError::ParseError("Arrow file does not contain correct footer".to_string())
+        })?;
+        let footer_len = read_footer_length(buffer[trailer_start..].try_into().unwrap())?;
+        let footer_start = trailer_start.checked_sub(footer_len).ok_or_else(|| {
+            ArrowError::ParseError(format!("Invalid footer length: {footer_len}"))
+        })?;
+        let footer =
+            crate::root_as_footer(&buffer[footer_start..trailer_start]).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+            })?;
+
+        let blocks = footer
+            .recordBatches()
+            .ok_or_else(|| {
+                ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
+            })?
+            .iter()
+            .copied()
+            .collect();
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        if !ipc_schema.endianness().equals_to_target_endianness() {
+            return Err(ArrowError::IpcError(
+                "the endianness of the source system does not match the endianness of the target system.".to_owned()
+            ));
+        }
+        let schema = crate::convert::fb_to_schema(ipc_schema);
+
+        let custom_metadata = footer
+            .custom_metadata()
+            .into_iter()
+            .flatten()
+            .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+            .collect();
+
+        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        if let Some(projection) = projection {
+            decoder = decoder.with_projection(projection)
+        }
+
+        for block in footer.dictionaries().iter().flatten() {
+            let data = block_data(&buffer, block)?;
+            decoder.read_dictionary(block, &data)?;
+        }
+
+        Ok(Self {
+            buffer,
+            decoder,
+            blocks,
+            current_block: 0,
+            custom_metadata,
+        })
+    }
+
+    /// Specifies whether the array data in the file is required to be properly aligned.
+    ///
+    /// If `require_alignment` is true, reading a batch returns an error if any array
+    /// data is not properly aligned, instead of copying it to an aligned buffer. This
+    /// guarantees the batches reference the mapped region without copying, except for
+    /// compressed buffers. Defaults to `false`.
+    ///
+    /// Note this does not apply to the dictionaries, which are decoded when the reader
+    /// is created.
+    pub fn with_require_alignment(mut self, require_alignment: bool) -> Self {
+        self.decoder = self.decoder.with_require_alignment(require_alignment);
+        self
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Read the record batch at `index`
+    pub fn batch(&self, index: usize) -> Result<RecordBatch, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot read batch at index {index} from {} total batches",
+                self.blocks.len()
+            ))
+        })?;
+        let data = block_data(&self.buffer, block)?;
+        self.decoder
+            .read_record_batch(block, &data)?
+            .ok_or_else(|| ArrowError::IpcError(format!("Block {index} is not a record batch")))
+    }
+}
+
+/// Returns the data of `block` in `buffer`
+fn block_data(buffer: &Buffer, block: &Block) -> Result<Buffer, ArrowError> {
+    let len = (block.metaDataLength() as i64)
+        .checked_add(block.bodyLength())
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Block at offset {} has an invalid length",
+                block.offset()
+            ))
+        })?;
+    let offset = usize::try_from(block.offset());
+    let len = usize::try_from(len);
+    match (offset, len) {
+        (Ok(offset), Ok(len)) if offset.saturating_add(len) <= buffer.len() => {
+            Ok(buffer.slice_with_length(offset, len))
+        }
+        _ => Err(ArrowError::ParseError(format!(
+            "Block at offset {} exceeds the file length of {}",
+            block.offset(),
+            buffer.len()
+        ))),
+    }
+}
+
+impl Iterator for MmapFileReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_block >= self.blocks.len() {
+            return None;
+        }
+        self.current_block += 1;
+        Some(self.batch(self.current_block - 1))
+    }
+}
+
+impl RecordBatchReader for MmapFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::FileWriter;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array, ArrayRef, DictionaryArray, Int64Array, StringArray};
+
+    fn write_file(file: &mut File, batches: &[RecordBatch]) {
+        let mut writer = FileWriter::try_new(file, &batches[0].schema()).unwrap();
+        writer.write_metadata("key", "value");
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_mmap_file_reader() {
+        let batches: Vec<_> = (0..3)
+            .map(|i| {
+                let ints = Int64Array::from_iter_values(i * 1000..(i + 1) * 1000);
+                let strings = StringArray::from_iter_values((0..1000).map(|j| format!("{j}")));
+                let dict: DictionaryArray<Int32Type> =
+                    (0..1000).map(|j| ["a", "b", "c"][j % 3]).collect();
+                RecordBatch::try_from_iter([
+                    ("i", Arc::new(ints) as ArrayRef),
+                    ("s", Arc::new(strings) as ArrayRef),
+                    ("d", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect();
+
+        let mut file = tempfile::tempfile().unwrap();
+        write_file(&mut file, &batches);
+
+        let reader = unsafe { MmapFileReader::try_new(&file, None) }
+            .unwrap()
+            .with_require_alignment(true);
+        assert_eq!(reader.num_batches(), 3);
+        assert_eq!(reader.schema(), batches[0].schema());
+        assert_eq!(reader.custom_metadata()["key"], "value");
+
+        // Random access, with the buffers referencing the mapped region
+        let batch = reader.batch(2).unwrap();
+        assert_eq!(batch, batches[2]);
+        let mapped = reader.buffer.as_ptr_range();
+        let values = batch.column(0).to_data().buffers()[0].clone();
+        assert!(mapped.contains(&values.as_ptr()));
+
+        let err = reader.batch(3).unwrap_err();
+        assert!(err.to_string().contains("from 3 total batches"));
+
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        let reader = unsafe { MmapFileReader::try_new(&file, Some(vec![2])) }.unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let expected: Vec<_> = batches.iter().map(|b| b.project(&[2]).unwrap()).collect();
+        assert_eq!(read, expected);
+    }
+
+    #[test]
+    fn test_mmap_file_reader_invalid() {
+        let file = tempfile::tempfile().unwrap();
+        let err = unsafe { MmapFileReader::try_new(&file, None) }.unwrap_err();
+        assert!(err.to_string().contains("does not contain correct footer"));
+
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut file = tempfile::tempfile().unwrap();
+        write_file(&mut file, &[batch]);
+        let data = unsafe { Mmap::map(&file) }.unwrap().to_vec();
+
+        // Remove the record batch, leaving the footer referencing it
+        let footer_len = read_footer_length(data[data.len() - 10..].try_into().unwrap()).unwrap();
+        let footer = data[data.len() - 10 - footer_len..].to_vec();
+        let data = [&data[..8], &footer].concat();
+        let reader = MmapFileReader::try_new_from_buffer(Buffer::from_vec(data), None).unwrap();
+        let err = reader.batch(0).unwrap_err();
+        assert!(err.to_string().contains("exceeds the file length"), "{err}");
+
+        let buffer = Buffer::from_vec(vec![0_u8; 64]);
+        let block = Block::new(0, 1, i64::MAX);
+        let err = block_data(&buffer, &block).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Block at offset 0 has an invalid length"
+        );
+        let block = Block::new(8, 8, -16);
+        let err = block_data(&buffer, &block).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Block at offset 8 exceeds the file length of 64"
+        );
+    }
+}
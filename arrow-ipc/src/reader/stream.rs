@@ -24,7 +24,9 @@ use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_schema::{ArrowError, SchemaRef};
 
 use crate::convert::MessageBuffer;
-use crate::reader::{read_dictionary_impl, read_record_batch_impl};
+use crate::reader::{
+    read_dictionary_impl, read_record_batch_impl, CompatibilityHandler, CompatibilityWarning,
+};
 use crate::{MessageHeader, CONTINUATION_MARKER};
 
 /// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
@@ -42,6 +44,8 @@ pub struct StreamDecoder {
     buf: MutableBuffer,
     /// Whether or not array data in input buffers are required to be aligned
     require_alignment: bool,
+    /// Optional callback of the compatibility mode
+    compatibility: Option<CompatibilityHandler>,
 }
 
 #[derive(Debug)]
@@ -102,6 +106,25 @@ impl StreamDecoder {
         self
     }
 
+    /// Enable a compatibility mode for decoding streams written by newer writers
+    ///
+    /// Rather than returning an error, messages of types this decoder does not support
+    /// are skipped, and `on_warning` is called with a [`CompatibilityWarning`]. The
+    /// callback is also called for messages written with a newer metadata version
+    /// than this decoder supports, which are otherwise decoded as the latest version.
+    pub fn with_compatibility_mode(
+        mut self,
+        on_warning: impl Fn(&CompatibilityWarning) + Send + Sync + 'static,
+    ) -> Self {
+        self.compatibility = Some(CompatibilityHandler::new(on_warning));
+        self
+    }
+
+    /// Returns the schema of the stream, if it has been read
+    pub fn schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
     /// Try to read the next [`RecordBatch`] from the provided [`Buffer`]
     ///
     /// [`Buffer::advance`] will be called on `buffer` for any consumed bytes.
@@ -129,7 +152,8 @@ impl StreamDecoder {
     /// }
     /// ```
     pub fn decode(&mut self, buffer: &mut Buffer) -> Result<Option<RecordBatch>, ArrowError> {
-        while !buffer.is_empty() {
+        // A message without a body, such as a schema, is decoded as soon as its flatbuffer is read
+        while !buffer.is_empty() || self.pending_empty_body() {
             match &mut self.state {
                 DecoderState::Header {
                     buf,
@@ -193,6 +217,9 @@ impl StreamDecoder {
                     };
 
                     let version = message.version();
+                    if let Some(compatibility) = &self.compatibility {
+                        compatibility.check_version(&message);
+                    }
                     match message.header_type() {
                         MessageHeader::Schema => {
                             if self.schema.is_some() {
@@ -241,11 +268,17 @@ impl StreamDecoder {
                         MessageHeader::NONE => {
                             self.state = DecoderState::default();
                         }
-                        t => {
-                            return Err(ArrowError::IpcError(format!(
-                                "Message type unsupported by StreamDecoder: {t:?}"
-                            )))
-                        }
+                        t => match &self.compatibility {
+                            Some(compatibility) => {
+                                compatibility.skipped(&message);
+                                self.state = DecoderState::default();
+                            }
+                            None => {
+                                return Err(ArrowError::IpcError(format!(
+                                    "Message type unsupported by StreamDecoder: {t:?}"
+                                )))
+                            }
+                        },
                     }
                 }
                 DecoderState::Finished => {
@@ -256,6 +289,14 @@ impl StreamDecoder {
         Ok(None)
     }
 
+    /// Returns true if the message flatbuffer has been read, and it has no body
+    fn pending_empty_body(&self) -> bool {
+        match &self.state {
+            DecoderState::Body { message } => message.as_ref().bodyLength() == 0,
+            _ => false,
+        }
+    }
+
     /// Signal the end of stream
     ///
     /// Returns an error if any partial data remains in the stream
@@ -275,6 +316,7 @@ impl StreamDecoder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::reader::tests::{stream_with_message, unknown_message};
     use crate::writer::{IpcWriteOptions, StreamWriter};
     use arrow_array::{
         types::Int32Type, DictionaryArray, Int32Array, Int64Array, RecordBatch, RunArray,
@@ -376,4 +418,41 @@ mod tests {
 
         decoder.finish().expect("Failed to finish decoder");
     }
+
+    #[test]
+    fn test_compatibility_mode() {
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _)])
+                .unwrap();
+
+        let message = unknown_message(crate::MetadataVersion::V5, &[0; 64]);
+        let stream = Buffer::from_vec(stream_with_message(&batch, &message));
+
+        let mut decoder = StreamDecoder::new();
+        let mut buf = stream.clone();
+        assert_eq!(decoder.decode(&mut buf).unwrap().unwrap(), batch);
+        let err = decoder.decode(&mut buf).unwrap_err();
+        assert!(err.to_string().contains("<UNKNOWN 42>"), "{err}");
+
+        let skipped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let captured = skipped.clone();
+        let mut decoder = StreamDecoder::new().with_compatibility_mode(move |w| {
+            assert!(matches!(
+                w,
+                CompatibilityWarning::SkippedMessage {
+                    body_length: 64,
+                    ..
+                }
+            ));
+            captured.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        });
+        let mut buf = stream;
+        let mut batches = vec![];
+        while let Some(b) = decoder.decode(&mut buf).unwrap() {
+            batches.push(b);
+        }
+        decoder.finish().unwrap();
+        assert_eq!(batches, vec![batch.clone(), batch]);
+        assert_eq!(skipped.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
 }
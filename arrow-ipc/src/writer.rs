@@ -21,7 +21,7 @@
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
 use std::cmp::min;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufWriter, Write};
 use std::mem::size_of;
 use std::sync::Arc;
@@ -70,6 +70,12 @@ pub struct IpcWriteOptions {
         note = "The ability to preserve dictionary IDs will be removed. With it, all fields related to it."
     )]
     preserve_dict_id: bool,
+    /// How dictionaries that change between batches are written
+    ///
+    /// Defaults to [`DictionaryHandling::Resend`]
+    dictionary_handling: DictionaryHandling,
+    /// Columns whose buffers are not compressed, even if compression is enabled
+    uncompressed_columns: HashSet<String>,
 }
 
 impl IpcWriteOptions {
@@ -92,6 +98,30 @@ impl IpcWriteOptions {
         }
         Ok(self)
     }
+
+    /// Set whether the buffers of the top-level column named `column` are compressed
+    /// when compression is enabled with [`Self::try_with_compression`], defaults to `true`
+    ///
+    /// Disabling compression for columns that compress poorly, such as binary columns
+    /// of already compressed data, avoids the cost of attempting to compress them.
+    ///
+    /// Note the IPC format supports a single compression codec per message, so the
+    /// buffers of each column are either compressed with that codec or stored uncompressed.
+    pub fn with_column_compression(mut self, column: impl Into<String>, compress: bool) -> Self {
+        let column = column.into();
+        match compress {
+            true => self.uncompressed_columns.remove(&column),
+            false => self.uncompressed_columns.insert(column),
+        };
+        self
+    }
+
+    /// Returns whether the buffers of the top-level column named `column` are compressed
+    /// when compression is enabled, see [`Self::with_column_compression`]
+    pub fn column_compression(&self, column: &str) -> bool {
+        !self.uncompressed_columns.contains(column)
+    }
+
     /// Try to create IpcWriteOptions, checking for incompatible settings
     pub fn try_new(
         alignment: usize,
@@ -119,6 +149,8 @@ impl IpcWriteOptions {
                 metadata_version,
                 batch_compression_type: None,
                 preserve_dict_id: false,
+                dictionary_handling: DictionaryHandling::Resend,
+                uncompressed_columns: HashSet::new(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -133,6 +165,8 @@ impl IpcWriteOptions {
                         metadata_version,
                         batch_compression_type: None,
                         preserve_dict_id: false,
+                        dictionary_handling: DictionaryHandling::Resend,
+                        uncompressed_columns: HashSet::new(),
                     })
                 }
             }
@@ -142,6 +176,22 @@ impl IpcWriteOptions {
         }
     }
 
+    /// Set how dictionaries that change between batches are written, defaults to
+    /// [`DictionaryHandling::Resend`]
+    ///
+    /// With [`DictionaryHandling::Delta`], a long-lived stream of batches whose
+    /// dictionaries only grow, such as those created by a dictionary builder that is
+    /// not reset, writes only the new values of each dictionary.
+    pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
+    /// Return how dictionaries that change between batches are written
+    pub fn dictionary_handling(&self) -> DictionaryHandling {
+        self.dictionary_handling
+    }
+
     /// Return whether the writer is configured to preserve the dictionary IDs
     /// defined in the schema
     #[deprecated(
@@ -180,10 +230,41 @@ impl Default for IpcWriteOptions {
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
             preserve_dict_id: false,
+            dictionary_handling: DictionaryHandling::Resend,
+            uncompressed_columns: HashSet::new(),
         }
     }
 }
 
+/// Determines how dictionaries that change between batches are written, see
+/// [`IpcWriteOptions::with_dictionary_handling`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DictionaryHandling {
+    /// Write the entire new dictionary, replacing the previous one
+    #[default]
+    Resend,
+    /// If the new dictionary starts with the values of the previous one, write
+    /// only the new values in a delta dictionary batch, otherwise write the
+    /// entire new dictionary
+    ///
+    /// Delta dictionaries are also permitted in the IPC file format, where
+    /// replacing a dictionary is an error
+    Delta,
+}
+
+/// The result of [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary was already written, and need not be written again
+    None,
+    /// The dictionary has not been written before
+    New,
+    /// The dictionary replaces a previously written dictionary
+    Replaced,
+    /// The dictionary extends a previously written dictionary with these values
+    Delta(ArrayData),
+}
+
 #[derive(Debug, Default)]
 /// Handles low level details of encoding [`Array`] and [`Schema`] into the
 /// [Arrow IPC Format].
@@ -284,10 +365,10 @@ impl IpcDataGenerator {
         }
     }
 
-    fn _encode_dictionaries<I: Iterator<Item = i64>>(
+    fn _encode_dictionaries<I: Iterator<Item = i64>, B: MessageBody>(
         &self,
         column: &ArrayRef,
-        encoded_dictionaries: &mut Vec<EncodedData>,
+        encoded_dictionaries: &mut Vec<EncodedData<B>>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         dict_id: &mut I,
@@ -348,6 +429,28 @@ impl IpcDataGenerator {
                     dict_id,
                 )?;
             }
+            DataType::ListView(field) => {
+                let list = column.as_list_view::<i32>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
+            DataType::LargeListView(field) => {
+                let list = column.as_list_view::<i64>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
             DataType::FixedSizeList(field, _) => {
                 let list = column
                     .as_any()
@@ -410,11 +513,11 @@ impl IpcDataGenerator {
         Ok(())
     }
 
-    fn encode_dictionaries<I: Iterator<Item = i64>>(
+    fn encode_dictionaries<I: Iterator<Item = i64>, B: MessageBody>(
         &self,
         field: &Field,
         column: &ArrayRef,
-        encoded_dictionaries: &mut Vec<EncodedData>,
+        encoded_dictionaries: &mut Vec<EncodedData<B>>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         dict_id_seq: &mut I,
@@ -445,14 +548,30 @@ impl IpcDataGenerator {
                         ArrowError::IpcError(format!("no dict id for field {}", field.name()))
                     })?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            write_options,
+                            false,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            write_options,
+                            true,
+                        )?);
+                    }
                 }
             }
             _ => self._encode_dictionaries(
@@ -476,6 +595,32 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        self.encode_batch(batch, dictionary_tracker, write_options)
+    }
+
+    /// Encodes a batch as [`Self::encoded_batch`], with the body of each message written
+    /// directly to a [`Buffer`] allocated with the alignment of [`MutableBuffer`]
+    ///
+    /// This allows the bodies to be shared without copying them, for example with
+    /// transports that require aligned memory
+    pub fn encoded_batch_aligned(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData<Buffer>>, EncodedData<Buffer>), ArrowError> {
+        let (dictionaries, batch) =
+            self.encode_batch::<AlignedBody>(batch, dictionary_tracker, write_options)?;
+        let dictionaries = dictionaries.into_iter().map(EncodedData::into_buffer);
+        Ok((dictionaries.collect(), batch.into_buffer()))
+    }
+
+    fn encode_batch<B: MessageBody>(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData<B>>, EncodedData<B>), ArrowError> {
         let schema = batch.schema();
         let mut encoded_dictionaries = Vec::with_capacity(schema.flattened_fields().len());
 
@@ -499,16 +644,16 @@ impl IpcDataGenerator {
 
     /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
     /// other for the batch's data
-    fn record_batch_to_bytes(
+    fn record_batch_to_bytes<B: MessageBody>(
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
-    ) -> Result<EncodedData, ArrowError> {
+    ) -> Result<EncodedData<B>, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
+        let mut arrow_data = B::default();
         let mut offset = 0;
 
         // get the type of compression
@@ -526,8 +671,14 @@ impl IpcDataGenerator {
 
         let mut variadic_buffer_counts = vec![];
 
-        for array in batch.columns() {
+        for (field, array) in batch.schema_ref().fields().iter().zip(batch.columns()) {
             let array_data = array.to_data();
+            let compression_codec = compression_codec.map(|codec| {
+                match write_options.column_compression(field.name()) {
+                    true => BufferCompression::Codec(codec),
+                    false => BufferCompression::Uncompressed,
+                }
+            });
             offset = write_array_data(
                 &array_data,
                 &mut buffers,
@@ -589,17 +740,18 @@ impl IpcDataGenerator {
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
     /// other for the data
-    fn dictionary_batch_to_bytes(
+    fn dictionary_batch_to_bytes<B: MessageBody>(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
         write_options: &IpcWriteOptions,
-    ) -> Result<EncodedData, ArrowError> {
+        is_delta: bool,
+    ) -> Result<EncodedData<B>, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
+        let mut arrow_data = B::default();
 
         // get the type of compression
         let batch_compression_type = write_options.batch_compression_type;
@@ -611,9 +763,10 @@ impl IpcDataGenerator {
             c.finish()
         });
 
-        let compression_codec: Option<CompressionCodec> = batch_compression_type
+        let compression_codec = batch_compression_type
             .map(|batch_compression_type| batch_compression_type.try_into())
-            .transpose()?;
+            .transpose()?
+            .map(BufferCompression::Codec);
 
         write_array_data(
             array_data,
@@ -662,6 +815,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -903,6 +1057,65 @@ impl DictionaryTracker {
         self.written.insert(dict_id, dict_data);
         Ok(true)
     }
+
+    /// Keep track of the dictionary of `column` with the given ID, returning
+    /// how it should be written with `dict_handling`. Behavior:
+    ///
+    /// * If this ID has been written already with the same values, return
+    ///   [`DictionaryUpdate::None`].
+    /// * If `dict_handling` is [`DictionaryHandling::Delta`] and the values start with
+    ///   the values previously written, return [`DictionaryUpdate::Delta`] with the
+    ///   new values.
+    /// * If this ID has been written already with different values, return an error
+    ///   if this tracker is configured to error on replacement, otherwise
+    ///   [`DictionaryUpdate::Replaced`].
+    /// * If this dictionary has never been seen before, return [`DictionaryUpdate::New`].
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dict_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
+        let dict_data = column.to_data();
+        let dict_values = &dict_data.child_data()[0];
+
+        let Some(last) = self.written.get(&dict_id) else {
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::New);
+        };
+        let last_values = &last.child_data()[0];
+        if ArrayData::ptr_eq(last_values, dict_values) {
+            return Ok(DictionaryUpdate::None);
+        }
+
+        let last_len = last_values.len();
+        if dict_handling == DictionaryHandling::Delta
+            && dict_values.len() >= last_len
+            && dict_values.slice(0, last_len) == *last_values
+        {
+            if dict_values.len() == last_len {
+                return Ok(DictionaryUpdate::None);
+            }
+            let delta = dict_values.slice(last_len, dict_values.len() - last_len);
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::Delta(delta));
+        }
+
+        if self.error_on_replacement {
+            if last_values == dict_values {
+                return Ok(DictionaryUpdate::None);
+            }
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
+        }
+
+        self.written.insert(dict_id, dict_data);
+        Ok(DictionaryUpdate::Replaced)
+    }
 }
 
 /// Writer for an IPC file
@@ -1317,12 +1530,80 @@ impl<W: Write> RecordBatchWriter for StreamWriter<W> {
 }
 
 /// Stores the encoded data, which is an crate::Message, and optional Arrow data
-pub struct EncodedData {
+///
+/// The Arrow data is a [`Vec`], or a [`Buffer`] if encoded with
+/// [`IpcDataGenerator::encoded_batch_aligned`]
+pub struct EncodedData<B = Vec<u8>> {
     /// An encoded crate::Message
     pub ipc_message: Vec<u8>,
     /// Arrow buffers to be written, should be an empty vec for schema messages
-    pub arrow_data: Vec<u8>,
+    pub arrow_data: B,
+}
+
+impl EncodedData<AlignedBody> {
+    fn into_buffer(self) -> EncodedData<Buffer> {
+        EncodedData {
+            ipc_message: self.ipc_message,
+            arrow_data: self.arrow_data.0.into(),
+        }
+    }
+}
+
+/// A growable buffer the body of an encoded message is written to
+pub(crate) trait MessageBody: Default + Write {
+    /// Returns the number of bytes written to the body
+    fn len(&self) -> usize;
+
+    /// Appends `data` to the body
+    fn extend_from_slice(&mut self, data: &[u8]);
+
+    /// Truncates the body to `len` bytes
+    fn truncate(&mut self, len: usize);
+}
+
+impl MessageBody for Vec<u8> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) {
+        Vec::extend_from_slice(self, data)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len)
+    }
 }
+
+/// A [`MessageBody`] allocated with the alignment of [`MutableBuffer`]
+#[derive(Debug, Default)]
+struct AlignedBody(MutableBuffer);
+
+impl Write for AlignedBody {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl MessageBody for AlignedBody {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
     mut writer: W,
@@ -1517,17 +1798,26 @@ fn get_list_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Arra
     (offsets, child_data)
 }
 
+/// How the buffers of an array are written to a compressed message body
+#[derive(Debug, Clone, Copy)]
+enum BufferCompression {
+    /// Compress the buffers with the codec of the message
+    Codec(CompressionCodec),
+    /// Store the buffers uncompressed
+    Uncompressed,
+}
+
 /// Write array data to a vector of bytes
 #[allow(clippy::too_many_arguments)]
-fn write_array_data(
+fn write_array_data<B: MessageBody>(
     array_data: &ArrayData,
     buffers: &mut Vec<crate::Buffer>,
-    arrow_data: &mut Vec<u8>,
+    arrow_data: &mut B,
     nodes: &mut Vec<crate::FieldNode>,
     offset: i64,
     num_rows: usize,
     null_count: usize,
-    compression_codec: Option<CompressionCodec>,
+    compression_codec: Option<BufferCompression>,
     write_options: &IpcWriteOptions,
 ) -> Result<i64, ArrowError> {
     let mut offset = offset;
@@ -1683,6 +1973,30 @@ fn write_array_data(
             write_options,
         )?;
         return Ok(offset);
+    } else if matches!(
+        data_type,
+        DataType::ListView(_) | DataType::LargeListView(_)
+    ) {
+        assert_eq!(array_data.buffers().len(), 2);
+
+        // Truncate offsets and sizes, the values are written in full as they
+        // may be referenced in any order
+        let byte_width = match data_type {
+            DataType::ListView(_) => 4,
+            _ => 8,
+        };
+        let start = array_data.offset() * byte_width;
+        let end = start + array_data.len() * byte_width;
+        for buffer in array_data.buffers() {
+            offset = write_buffer(
+                &buffer.as_slice()[start..end],
+                buffers,
+                arrow_data,
+                offset,
+                compression_codec,
+                write_options.alignment,
+            )?;
+        }
     } else {
         for buffer in array_data.buffers() {
             offset = write_buffer(
@@ -1750,16 +2064,21 @@ fn write_array_data(
 /// uncompressed length may be set to -1 to indicate that the data that
 /// follows is not compressed, which can be useful for cases where
 /// compression does not yield appreciable savings.
-fn write_buffer(
+fn write_buffer<B: MessageBody>(
     buffer: &[u8],                    // input
     buffers: &mut Vec<crate::Buffer>, // output buffer descriptors
-    arrow_data: &mut Vec<u8>,         // output stream
+    arrow_data: &mut B,               // output stream
     offset: i64,                      // current output stream offset
-    compression_codec: Option<CompressionCodec>,
+    compression_codec: Option<BufferCompression>,
     alignment: u8,
 ) -> Result<i64, ArrowError> {
     let len: i64 = match compression_codec {
-        Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
+        Some(BufferCompression::Codec(compressor)) => {
+            compressor.compress_to_vec(buffer, arrow_data)?
+        }
+        Some(BufferCompression::Uncompressed) => {
+            CompressionCodec::store_uncompressed_to_vec(buffer, arrow_data)
+        }
         None => {
             arrow_data.extend_from_slice(buffer);
             buffer.len()
@@ -1795,6 +2114,7 @@ mod tests {
 
     use arrow_array::builder::GenericListBuilder;
     use arrow_array::builder::MapBuilder;
+    use arrow_array::builder::StringDictionaryBuilder;
     use arrow_array::builder::UnionBuilder;
     use arrow_array::builder::{PrimitiveRunBuilder, UInt32Builder};
     use arrow_array::types::*;
@@ -1841,6 +2161,132 @@ mod tests {
         stream_reader.next().unwrap().unwrap()
     }
 
+    #[test]
+    fn test_delta_dictionaries() {
+        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+        let mut batches = vec![];
+        for values in [vec!["a", "b"], vec!["a", "c"], vec!["d", "b"], vec!["b"]] {
+            builder.extend(values.into_iter().map(Some));
+            let array = Arc::new(builder.finish_cloned()) as ArrayRef;
+            batches.push(RecordBatch::try_from_iter([("d", array)]).unwrap());
+        }
+        // A dictionary that does not extend the previous one is replaced
+        let replaced: DictionaryArray<Int32Type> = vec!["z", "a"].into_iter().collect();
+        batches.push(RecordBatch::try_from_iter([("d", Arc::new(replaced) as _)]).unwrap());
+        let schema = batches[0].schema();
+
+        let write_stream = |dictionary_handling| {
+            let options = IpcWriteOptions::default().with_dictionary_handling(dictionary_handling);
+            let mut writer = StreamWriter::try_new_with_options(vec![], &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.into_inner().unwrap()
+        };
+
+        let resend = write_stream(DictionaryHandling::Resend);
+        let delta = write_stream(DictionaryHandling::Delta);
+        assert!(delta.len() < resend.len());
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(delta);
+        let mut decoded = vec![];
+        while let Some(batch) = decoder.decode(&mut buffer).unwrap() {
+            decoded.push(batch);
+        }
+        assert_eq!(decoded, batches);
+
+        let reader = StreamReader::try_new(Cursor::new(resend), None).unwrap();
+        let decoded = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, batches);
+
+        // Delta dictionaries are permitted in the file format, unlike replacements
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let mut writer = FileWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        for batch in &batches[..4] {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        let reader = FileReader::try_new(Cursor::new(writer.into_inner().unwrap()), None).unwrap();
+        let decoded = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded.last().unwrap(), &batches[3]);
+    }
+
+    #[test]
+    fn test_dictionary_tracker_delta() {
+        let mut tracker = DictionaryTracker::new(true);
+        let first: DictionaryArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let second: DictionaryArray<Int32Type> = vec!["a", "b", "c"].into_iter().collect();
+        let (first, second) = (Arc::new(first) as ArrayRef, Arc::new(second) as ArrayRef);
+
+        let update = tracker.insert_column(0, &first, DictionaryHandling::Delta);
+        assert_eq!(update.unwrap(), DictionaryUpdate::New);
+        let update = tracker.insert_column(0, &first, DictionaryHandling::Delta);
+        assert_eq!(update.unwrap(), DictionaryUpdate::None);
+
+        let update = tracker.insert_column(0, &second, DictionaryHandling::Delta);
+        let expected = StringArray::from(vec!["c"]).into_data();
+        assert_eq!(update.unwrap(), DictionaryUpdate::Delta(expected));
+
+        let err = tracker
+            .insert_column(0, &first, DictionaryHandling::Delta)
+            .unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+
+        let mut tracker = DictionaryTracker::new(false);
+        tracker
+            .insert_column(0, &first, DictionaryHandling::Resend)
+            .unwrap();
+        let update = tracker.insert_column(0, &second, DictionaryHandling::Resend);
+        assert_eq!(update.unwrap(), DictionaryUpdate::Replaced);
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_write_column_compression() {
+        let value = "compressible".repeat(64);
+        let strings = StringArray::from_iter_values((0..64).map(|_| value.as_str()));
+        let binary = BinaryArray::from_iter_values((0..64).map(|_| value.as_bytes()));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("s", Arc::new(strings) as ArrayRef),
+            ("b", Arc::new(binary) as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |options: IpcWriteOptions| {
+            let options = options
+                .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+                .unwrap();
+            let mut writer =
+                StreamWriter::try_new_with_options(vec![], batch.schema_ref(), options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            writer.into_inner().unwrap()
+        };
+        let contains_value =
+            |bytes: &[u8]| bytes.windows(value.len()).any(|w| w == value.as_bytes());
+
+        let compressed = write(IpcWriteOptions::default());
+        assert!(!contains_value(&compressed));
+
+        let options = IpcWriteOptions::default().with_column_compression("b", false);
+        assert!(options.column_compression("s"));
+        assert!(!options.column_compression("b"));
+        let partial = write(options);
+        assert!(contains_value(&partial));
+        assert!(partial.len() > compressed.len());
+
+        let reader = StreamReader::try_new(Cursor::new(partial), None).unwrap();
+        let decoded = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, vec![batch.clone()]);
+
+        let options = IpcWriteOptions::default()
+            .with_column_compression("b", false)
+            .with_column_compression("b", true);
+        assert_eq!(write(options), compressed);
+    }
+
     #[test]
     #[cfg(feature = "lz4")]
     fn test_write_empty_record_batch_lz4_compression() {
@@ -2136,6 +2582,36 @@ mod tests {
         assert!(dict_tracker.written.contains_key(&2));
     }
 
+    #[test]
+    fn test_encoded_batch_aligned() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("b", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+
+        let gen = IpcDataGenerator {};
+        let options = IpcWriteOptions::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let (dicts, encoded) = gen.encoded_batch(&batch, &mut tracker, &options).unwrap();
+        let mut tracker = DictionaryTracker::new(false);
+        let (aligned_dicts, aligned) = gen
+            .encoded_batch_aligned(&batch, &mut tracker, &options)
+            .unwrap();
+
+        assert_eq!(dicts.len(), 1);
+        assert_eq!(aligned_dicts.len(), 1);
+        let expected = dicts.iter().chain(std::iter::once(&encoded));
+        let actual = aligned_dicts.iter().chain(std::iter::once(&aligned));
+        for (expected, actual) in expected.zip(actual) {
+            assert_eq!(expected.ipc_message, actual.ipc_message);
+            assert_eq!(expected.arrow_data, actual.arrow_data.as_slice());
+            let ptr = actual.arrow_data.as_ptr();
+            assert_eq!(ptr.align_offset(arrow_buffer::alloc::ALIGNMENT), 0);
+        }
+    }
+
     fn write_union_file(options: IpcWriteOptions) {
         let schema = Schema::new(vec![Field::new_union(
             "union",
@@ -2647,6 +3123,39 @@ mod tests {
         roundtrip_ensure_sliced_smaller(in_batch, 1000);
     }
 
+    #[test]
+    fn encode_list_views() {
+        use arrow_array::builder::{GenericListViewBuilder, StringDictionaryBuilder};
+
+        fn list_view<O: OffsetSizeTrait>() -> ArrayRef {
+            let mut builder =
+                GenericListViewBuilder::<O, _>::new(StringDictionaryBuilder::<Int32Type>::new());
+            builder.append_value([Some("a"), None, Some("b")]);
+            builder.append_null();
+            builder.append_value([Some("c")]);
+            builder.append_value(Vec::<Option<&str>>::new());
+            builder.append_value([Some("a"), Some("d")]);
+            Arc::new(builder.finish())
+        }
+
+        let small = list_view::<i32>();
+        let large = list_view::<i64>();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("small", small.data_type().clone(), true),
+            Field::new("large", large.data_type().clone(), true),
+        ]));
+        let in_batch = RecordBatch::try_new(schema, vec![small, large]).unwrap();
+
+        let out_batch = deserialize_file(serialize_file(&in_batch));
+        assert_eq!(in_batch, out_batch);
+        let out_batch = deserialize_stream(serialize_stream(&in_batch));
+        assert_eq!(in_batch, out_batch);
+
+        let in_sliced = in_batch.slice(1, 3);
+        let out_sliced = deserialize_file(serialize_file(&in_sliced));
+        assert_eq!(in_sliced, out_sliced);
+    }
+
     #[test]
     fn encode_empty_list() {
         let val_inner = Field::new_list_field(DataType::UInt32, true);
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_field_decoder, ArrayDecoder};
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::buffer::NullBuffer;
@@ -43,12 +43,8 @@ impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
             DataType::LargeList(f) if O::IS_LARGE => f,
             _ => unreachable!(),
         };
-        let decoder = make_decoder(
-            field.data_type().clone(),
-            coerce_primitive,
-            strict_mode,
-            field.is_nullable(),
-        )?;
+        let decoder =
+            make_field_decoder(field, coerce_primitive, strict_mode, field.is_nullable())?;
 
         Ok(Self {
             data_type,
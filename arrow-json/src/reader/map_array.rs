@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_decoder, make_field_decoder, ArrayDecoder};
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_buffer::buffer::NullBuffer;
 use arrow_buffer::ArrowNativeType;
@@ -60,8 +60,8 @@ impl MapArrayDecoder {
             strict_mode,
             fields[0].is_nullable(),
         )?;
-        let values = make_decoder(
-            fields[1].data_type().clone(),
+        let values = make_field_decoder(
+            &fields[1],
             coerce_primitive,
             strict_mode,
             fields[1].is_nullable(),
@@ -143,7 +143,7 @@ use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::{downcast_integer, make_array, RecordBatch, RecordBatchReader, StructArray};
 use arrow_data::ArrayData;
-use arrow_schema::{ArrowError, DataType, FieldRef, Schema, SchemaRef, TimeUnit};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, Schema, SchemaRef, TimeUnit};
 pub use schema::*;
 
 use crate::reader::boolean_array::BooleanArrayDecoder;
@@ -152,8 +152,10 @@ use crate::reader::list_array::ListArrayDecoder;
 use crate::reader::map_array::MapArrayDecoder;
 use crate::reader::null_array::NullArrayDecoder;
 use crate::reader::primitive_array::PrimitiveArrayDecoder;
+use crate::reader::raw_json_array::RawJsonArrayDecoder;
 use crate::reader::string_array::StringArrayDecoder;
-use crate::reader::struct_array::StructArrayDecoder;
+use crate::reader::string_view_array::StringViewArrayDecoder;
+use crate::reader::struct_array::{Projection, StructArrayDecoder};
 use crate::reader::tape::{Tape, TapeDecoder};
 use crate::reader::timestamp_array::TimestampArrayDecoder;
 
@@ -163,9 +165,11 @@ mod list_array;
 mod map_array;
 mod null_array;
 mod primitive_array;
+mod raw_json_array;
 mod schema;
 mod serializer;
 mod string_array;
+mod string_view_array;
 mod struct_array;
 mod tape;
 mod timestamp_array;
@@ -176,6 +180,10 @@ pub struct ReaderBuilder {
     coerce_primitive: bool,
     strict_mode: bool,
     is_field: bool,
+    utf8_view: bool,
+    large_utf8: bool,
+    projection: Option<Vec<String>>,
+    raw_json_fields: Option<Vec<String>>,
 
     schema: SchemaRef,
 }
@@ -195,6 +203,10 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             is_field: false,
+            utf8_view: false,
+            large_utf8: false,
+            projection: None,
+            raw_json_fields: None,
             schema,
         }
     }
@@ -235,6 +247,10 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             is_field: true,
+            utf8_view: false,
+            large_utf8: false,
+            projection: None,
+            raw_json_fields: None,
             schema: Arc::new(Schema::new([field.into()])),
         }
     }
@@ -245,7 +261,7 @@ impl ReaderBuilder {
     }
 
     /// Sets if the decoder should coerce primitive values (bool and number) into string
-    /// when the Schema's column is Utf8 or LargeUtf8.
+    /// when the Schema's column is Utf8, LargeUtf8 or Utf8View.
     pub fn with_coerce_primitive(self, coerce_primitive: bool) -> Self {
         Self {
             coerce_primitive,
@@ -262,6 +278,117 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets if string columns should be decoded as [`DataType::Utf8View`], regardless
+    /// of whether they are [`DataType::Utf8`] or [`DataType::LargeUtf8`] in `schema`
+    ///
+    /// This also applies to strings nested within lists, structs and maps, and takes
+    /// precedence over [`Self::with_large_utf8`]
+    pub fn with_utf8_view(self, utf8_view: bool) -> Self {
+        Self { utf8_view, ..self }
+    }
+
+    /// Sets if [`DataType::Utf8`] columns in `schema` should be decoded as
+    /// [`DataType::LargeUtf8`], that is with i64 offsets
+    ///
+    /// This also applies to strings nested within lists, structs and maps. It has no
+    /// effect if [`Self::with_utf8_view`] is also enabled, in which case string columns
+    /// are decoded as [`DataType::Utf8View`]
+    pub fn with_large_utf8(self, large_utf8: bool) -> Self {
+        Self { large_utf8, ..self }
+    }
+
+    /// Only decode the fields selected by `paths`, of the form `$.a.b` or `a.b`
+    ///
+    /// The schema of the decoded [`RecordBatch`] retains only the selected fields, with
+    /// any parent structs of nested fields, in the order of `schema`. Other fields are
+    /// skipped without being decoded, although they must still be valid JSON, and are not
+    /// reported by [`Self::with_strict_mode`].
+    ///
+    /// Paths may only select fields nested within structs, and are not supported with
+    /// [`Self::new_with_field`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("a", DataType::Utf8, true),
+    ///     Field::new_struct(
+    ///         "b",
+    ///         vec![
+    ///             Field::new("c", DataType::Int64, true),
+    ///             Field::new("d", DataType::Utf8, true),
+    ///         ],
+    ///         true,
+    ///     ),
+    /// ]));
+    /// let data = r#"{"a": "foo", "b": {"c": 1, "d": "bar"}}"#;
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_projection(["$.b.c"])
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    ///
+    /// assert_eq!(batch.num_columns(), 1);
+    /// let b = batch.column(0).as_struct();
+    /// assert_eq!(b.num_columns(), 1);
+    /// assert_eq!(b.column(0).as_primitive::<Int64Type>().value(0), 1);
+    /// ```
+    pub fn with_projection<I, S>(self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            projection: Some(paths.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Decode the fields at `paths`, of the form `$.a.b` or `a.b`, as strings containing
+    /// the raw JSON of their values, rather than parsing them according to `schema`
+    ///
+    /// This allows unpredictable nested values to be captured and parsed later, at query
+    /// time. The selected fields are decoded as [`DataType::Utf8`], or the string type of
+    /// the field in `schema` if any, with the [`JSON_EXTENSION_NAME`] extension type.
+    /// JSON nulls are decoded as nulls.
+    ///
+    /// Any field of `schema` with the [`JSON_EXTENSION_NAME`] extension type is decoded
+    /// in this way, regardless of this option. Paths may only select fields nested within
+    /// structs.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("id", DataType::Int64, true),
+    ///     Field::new("payload", DataType::Null, true),
+    /// ]));
+    /// let data = r#"{"id": 1, "payload": {"a": [1, "b"], "c": null}}"#;
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_raw_json_fields(["$.payload"])
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    ///
+    /// let payload = batch.column(1).as_string::<i32>();
+    /// assert_eq!(payload.value(0), r#"{"a":[1,"b"],"c":null}"#);
+    /// ```
+    pub fn with_raw_json_fields<I, S>(self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            raw_json_fields: Some(paths.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -271,19 +398,61 @@ impl ReaderBuilder {
     }
 
     /// Create a [`Decoder`]
-    pub fn build_decoder(self) -> Result<Decoder, ArrowError> {
-        let (data_type, nullable) = match self.is_field {
-            false => (DataType::Struct(self.schema.fields.clone()), false),
-            true => {
-                let field = &self.schema.fields[0];
-                (field.data_type().clone(), field.is_nullable())
-            }
-        };
+    pub fn build_decoder(mut self) -> Result<Decoder, ArrowError> {
+        if let Some(paths) = &self.raw_json_fields {
+            let paths = Projection::try_new(paths)?;
+            let fields = raw_json_fields(&self.schema.fields, &paths)?;
+            let schema = Schema::new_with_metadata(fields, self.schema.metadata.clone());
+            self.schema = Arc::new(schema);
+        }
 
-        let decoder = make_decoder(data_type, self.coerce_primitive, self.strict_mode, nullable)?;
+        let string_type = match (self.utf8_view, self.large_utf8) {
+            (true, _) => Some(DataType::Utf8View),
+            (false, true) => Some(DataType::LargeUtf8),
+            (false, false) => None,
+        };
+        if let Some(string_type) = string_type {
+            let fields = convert_string_fields(&self.schema.fields, &string_type);
+            let schema = Schema::new_with_metadata(fields, self.schema.metadata.clone());
+            self.schema = Arc::new(schema);
+        }
 
         let num_fields = self.schema.flattened_fields().len();
 
+        let decoder = match (&self.projection, self.is_field) {
+            (Some(_), true) => {
+                return Err(ArrowError::JsonError(
+                    "Projection is not supported when decoding a single field".to_string(),
+                ))
+            }
+            (Some(paths), false) => {
+                let projection = Projection::try_new(paths)?;
+                let decoder = StructArrayDecoder::new_projected(
+                    &DataType::Struct(self.schema.fields.clone()),
+                    &projection,
+                    self.coerce_primitive,
+                    self.strict_mode,
+                    false,
+                )?;
+                let DataType::Struct(fields) = decoder.data_type() else {
+                    unreachable!()
+                };
+                let schema =
+                    Schema::new_with_metadata(fields.clone(), self.schema.metadata.clone());
+                self.schema = Arc::new(schema);
+                Box::new(decoder) as _
+            }
+            (None, false) => {
+                let data_type = DataType::Struct(self.schema.fields.clone());
+                make_decoder(data_type, self.coerce_primitive, self.strict_mode, false)?
+            }
+            (None, true) => {
+                let field = &self.schema.fields[0];
+                let nullable = field.is_nullable();
+                make_field_decoder(field, self.coerce_primitive, self.strict_mode, nullable)?
+            }
+        };
+
         Ok(Decoder {
             decoder,
             is_field: self.is_field,
@@ -294,6 +463,90 @@ impl ReaderBuilder {
     }
 }
 
+pub use arrow_schema::extension::{EXTENSION_TYPE_NAME_KEY, JSON_EXTENSION_NAME};
+
+/// Returns true if `field` has the [`JSON_EXTENSION_NAME`] extension type
+fn is_raw_json(field: &Field) -> bool {
+    field
+        .metadata()
+        .get(EXTENSION_TYPE_NAME_KEY)
+        .map(String::as_str)
+        == Some(JSON_EXTENSION_NAME)
+}
+
+/// Marks the fields of `fields` selected by `paths` as raw JSON fields
+fn raw_json_fields(fields: &Fields, paths: &Projection) -> Result<Fields, ArrowError> {
+    if let Some(name) = paths.names().find(|n| fields.find(n).is_none()) {
+        return Err(ArrowError::JsonError(format!(
+            "Raw JSON field '{name}' not found in schema"
+        )));
+    }
+
+    fields
+        .iter()
+        .map(|f| {
+            let field = match (paths.get(f.name()), f.data_type()) {
+                (None, _) => return Ok(f.clone()),
+                (Some(None), d) => {
+                    let data_type = match d {
+                        DataType::LargeUtf8 | DataType::Utf8View => d.clone(),
+                        _ => DataType::Utf8,
+                    };
+                    let mut metadata = f.metadata().clone();
+                    metadata.insert(
+                        EXTENSION_TYPE_NAME_KEY.to_string(),
+                        JSON_EXTENSION_NAME.to_string(),
+                    );
+                    f.as_ref()
+                        .clone()
+                        .with_data_type(data_type)
+                        .with_metadata(metadata)
+                }
+                (Some(Some(nested)), DataType::Struct(children)) => {
+                    let children = raw_json_fields(children, nested)?;
+                    f.as_ref()
+                        .clone()
+                        .with_data_type(DataType::Struct(children))
+                }
+                (Some(Some(_)), _) => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Cannot select nested fields of non-struct field '{}'",
+                        f.name()
+                    )))
+                }
+            };
+            Ok(Arc::new(field))
+        })
+        .collect()
+}
+
+/// Replaces the string types within `fields` with `string_type`
+fn convert_string_fields(fields: &Fields, string_type: &DataType) -> Fields {
+    fields
+        .iter()
+        .map(|f| {
+            let data_type = convert_string_type(f.data_type(), string_type);
+            Arc::new(f.as_ref().clone().with_data_type(data_type))
+        })
+        .collect()
+}
+
+/// Replaces the string types within `data_type` with `string_type`
+fn convert_string_type(data_type: &DataType, string_type: &DataType) -> DataType {
+    let convert_field = |f: &FieldRef| {
+        let data_type = convert_string_type(f.data_type(), string_type);
+        Arc::new(f.as_ref().clone().with_data_type(data_type))
+    };
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => string_type.clone(),
+        DataType::List(f) => DataType::List(convert_field(f)),
+        DataType::LargeList(f) => DataType::LargeList(convert_field(f)),
+        DataType::Struct(fields) => DataType::Struct(convert_string_fields(fields, string_type)),
+        DataType::Map(f, sorted) => DataType::Map(convert_field(f), *sorted),
+        d => d.clone(),
+    }
+}
+
 /// Reads JSON data with a known schema directly into arrow [`RecordBatch`]
 ///
 /// Lines consisting solely of ASCII whitespace are ignored
@@ -632,6 +885,26 @@ trait ArrayDecoder: Send {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError>;
 }
 
+/// Create a decoder for the values of `field`, see [`make_decoder`]
+fn make_field_decoder(
+    field: &Field,
+    coerce_primitive: bool,
+    strict_mode: bool,
+    is_nullable: bool,
+) -> Result<Box<dyn ArrayDecoder>, ArrowError> {
+    if is_raw_json(field) {
+        return Ok(Box::new(RawJsonArrayDecoder::new(
+            field.data_type().clone(),
+        )?));
+    }
+    make_decoder(
+        field.data_type().clone(),
+        coerce_primitive,
+        strict_mode,
+        is_nullable,
+    )
+}
+
 macro_rules! primitive_decoder {
     ($t:ty, $data_type:expr) => {
         Ok(Box::new(PrimitiveArrayDecoder::<$t>::new($data_type)))
@@ -693,6 +966,7 @@ fn make_decoder(
         DataType::Boolean => Ok(Box::<BooleanArrayDecoder>::default()),
         DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(coerce_primitive))),
         DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(coerce_primitive))),
+        DataType::Utf8View => Ok(Box::new(StringViewArrayDecoder::new(coerce_primitive))),
         DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
         DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
         DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
@@ -848,6 +1122,332 @@ mod tests {
         assert_eq!(col2.value(4), "");
     }
 
+    #[test]
+    fn test_utf8_view() {
+        let buf = r#"
+        {"a": "hello", "b": ["a", null, "a string longer than twelve bytes"], "c": 1}
+        {"a": null, "b": [], "c": true}
+        {"a": "a string longer than twelve bytes", "c": "foo"}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new_list("b", Field::new("element", DataType::LargeUtf8, true), true),
+            Field::new("c", DataType::Utf8View, true),
+        ]));
+
+        let batches = ReaderBuilder::new(schema)
+            .with_utf8_view(true)
+            .with_large_utf8(true)
+            .with_coerce_primitive(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8View);
+        assert_eq!(
+            batch.schema().field(1).data_type(),
+            &DataType::List(Arc::new(Field::new("element", DataType::Utf8View, true)))
+        );
+
+        let col1 = batch.column(0).as_string_view();
+        assert_eq!(col1.value(0), "hello");
+        assert!(col1.is_null(1));
+        assert_eq!(col1.value(2), "a string longer than twelve bytes");
+
+        let col2 = batch.column(1).as_list::<i32>();
+        assert_eq!(col2.value_offsets(), &[0, 3, 3, 3]);
+        assert!(col2.is_null(2));
+        let values = col2.values().as_string_view();
+        assert_eq!(values.value(0), "a");
+        assert!(values.is_null(1));
+        assert_eq!(values.value(2), "a string longer than twelve bytes");
+
+        let col3 = batch.column(2).as_string_view();
+        assert_eq!(col3.value(0), "1");
+        assert_eq!(col3.value(1), "true");
+        assert_eq!(col3.value(2), "foo");
+    }
+
+    #[test]
+    fn test_large_utf8() {
+        let buf = r#"
+        {"a": "hello", "b": {"c": "foo"}}
+        {"a": null, "b": {"c": null}}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new_struct("b", vec![Field::new("c", DataType::Utf8, true)], true),
+        ]));
+
+        let mut decoder = ReaderBuilder::new(schema)
+            .with_large_utf8(true)
+            .build_decoder()
+            .unwrap();
+        decoder.decode(buf.as_bytes()).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+
+        let col1 = batch.column(0).as_string::<i64>();
+        assert_eq!(col1.value(0), "hello");
+        assert!(col1.is_null(1));
+
+        let col2 = batch.column(1).as_struct();
+        assert_eq!(col2.fields()[0].data_type(), &DataType::LargeUtf8);
+        let c = col2.column(0).as_string::<i64>();
+        assert_eq!(c.value(0), "foo");
+        assert!(c.is_null(1));
+    }
+
+    #[test]
+    fn test_utf8_view_error() {
+        let buf = r#"{"a": 1}"#;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8View, true)]));
+        let err = ReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': expected string got 1"
+        );
+    }
+
+    #[test]
+    fn test_path_projection() {
+        let buf = r#"
+        {"a": 1, "b": {"c": "x", "d": {"e": 2, "f": [1, 2]}, "g": true}, "h": [{"i": 1}]}
+        {"b": {"d": null, "c": "y", "g": {"unused": [1, {}]}}, "a": 2}
+        {"h": "ignored", "b": null}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Utf8, true),
+                    Field::new_struct(
+                        "d",
+                        vec![
+                            Field::new("e", DataType::Int64, true),
+                            Field::new_list("f", Field::new("item", DataType::Int64, true), true),
+                        ],
+                        true,
+                    ),
+                    Field::new("g", DataType::Boolean, true),
+                ],
+                true,
+            ),
+            Field::new_list("h", Field::new("i", DataType::Int64, true), true),
+        ]));
+
+        let batch = ReaderBuilder::new(schema.clone())
+            .with_projection(["$.b.d.e", "b.c", "$.a"])
+            .with_strict_mode(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Utf8, true),
+                    Field::new_struct("d", vec![Field::new("e", DataType::Int64, true)], true),
+                ],
+                true,
+            ),
+        ]);
+        assert_eq!(batch.schema().as_ref(), &expected);
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.values(), &[1, 2, 0]);
+        assert!(a.is_null(2));
+
+        let b = batch.column(1).as_struct();
+        assert!(b.is_null(2));
+        let c = b.column(0).as_string::<i32>();
+        assert_eq!(c.value(0), "x");
+        assert_eq!(c.value(1), "y");
+        let d = b.column(1).as_struct();
+        assert!(d.is_null(1));
+        let e = d.column(0).as_primitive::<Int64Type>();
+        assert_eq!(e.value(0), 2);
+
+        // Selecting a whole struct supersedes selecting its children
+        let decoder = ReaderBuilder::new(schema.clone())
+            .with_projection(["$.b.c", "$.b"])
+            .build_decoder()
+            .unwrap();
+        assert_eq!(decoder.schema.fields().len(), 1);
+        assert_eq!(decoder.schema.field(0), schema.field(1));
+
+        // Strict mode still reports fields missing from the schema
+        let err = ReaderBuilder::new(schema.clone())
+            .with_projection(["$.a"])
+            .with_strict_mode(true)
+            .build(Cursor::new(r#"{"a": 1, "z": 2}"#.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: column 'z' missing from schema"
+        );
+    }
+
+    #[test]
+    fn test_path_projection_errors() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_list("b", Field::new("c", DataType::Int64, true), true),
+        ]));
+        let err = |paths: &[&str]| {
+            ReaderBuilder::new(schema.clone())
+                .with_projection(paths.iter().copied())
+                .build_decoder()
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err(&["$.x"]),
+            "Json error: Projected field 'x' not found in schema"
+        );
+        assert_eq!(
+            err(&["$.a.b"]),
+            "Json error: Cannot project nested fields of non-struct field 'a'"
+        );
+        assert_eq!(
+            err(&["$.b.c"]),
+            "Json error: Cannot project nested fields of non-struct field 'b'"
+        );
+        assert_eq!(err(&["$..a"]), "Json error: Invalid projection path '$..a'");
+        assert_eq!(err(&[""]), "Json error: Invalid projection path ''");
+
+        let field = Field::new_struct("s", vec![Field::new("a", DataType::Int64, true)], true);
+        let err = ReaderBuilder::new_with_field(field)
+            .with_projection(["$.a"])
+            .build_decoder()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Projection is not supported when decoding a single field"
+        );
+    }
+
+    #[test]
+    fn test_raw_json_fields() {
+        let buf = r#"
+        {"a": {"x": [1, 2.5, "q\"uote"], "y": {}}, "b": {"c": 1, "d": [true, null]}}
+        {"a": null, "b": {"c": 2, "d": "str"}}
+        {"a": 12345678901, "b": {"d": {"nested": {"deep": false}}}}
+        {"b": null}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Int64, true),
+                    Field::new("d", DataType::Null, true),
+                ],
+                true,
+            ),
+        ]));
+
+        let batch = ReaderBuilder::new(schema.clone())
+            .with_raw_json_fields(["$.a", "b.d"])
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let a_field = batch.schema().field(0).clone();
+        assert_eq!(a_field.data_type(), &DataType::Utf8);
+        assert_eq!(
+            a_field.metadata().get(EXTENSION_TYPE_NAME_KEY).unwrap(),
+            JSON_EXTENSION_NAME
+        );
+
+        let a = batch.column(0).as_string::<i32>();
+        assert_eq!(a.value(0), r#"{"x":[1,2.5,"q\"uote"],"y":{}}"#);
+        assert!(a.is_null(1));
+        assert_eq!(a.value(2), "12345678901");
+        assert!(a.is_null(3));
+
+        let b = batch.column(1).as_struct();
+        assert_eq!(b.column(0).as_primitive::<Int64Type>().value(1), 2);
+        let d = b.column(1).as_string::<i32>();
+        assert_eq!(d.value(0), "[true,null]");
+        assert_eq!(d.value(1), r#""str""#);
+        assert_eq!(d.value(2), r#"{"nested":{"deep":false}}"#);
+        assert!(d.is_null(3));
+
+        // The extension type in the schema alone selects raw decoding
+        let decoded = ReaderBuilder::new(batch.schema())
+            .with_utf8_view(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let a = decoded.column(0).as_string_view();
+        assert_eq!(a.value(0), r#"{"x":[1,2.5,"q\"uote"],"y":{}}"#);
+        assert_eq!(
+            decoded.schema().field(0).metadata(),
+            batch.schema().field(0).metadata()
+        );
+
+        let err = ReaderBuilder::new(schema.clone())
+            .with_raw_json_fields(["$.z"])
+            .build_decoder()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Raw JSON field 'z' not found in schema"
+        );
+
+        let err = ReaderBuilder::new(schema)
+            .with_raw_json_fields(["$.a.b"])
+            .build_decoder()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Cannot select nested fields of non-struct field 'a'"
+        );
+    }
+
+    #[test]
+    fn test_raw_json_list_elements() {
+        let metadata = std::collections::HashMap::from([(
+            EXTENSION_TYPE_NAME_KEY.to_string(),
+            JSON_EXTENSION_NAME.to_string(),
+        )]);
+        let element = Field::new("element", DataType::LargeUtf8, true).with_metadata(metadata);
+        let schema = Arc::new(Schema::new(vec![Field::new_list("a", element, true)]));
+
+        let buf = r#"{"a": [{"b": 1}, null, [2]]}"#;
+        let batch = ReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let a = batch.column(0).as_list::<i32>();
+        let values = a.values().as_string::<i64>();
+        assert_eq!(values.value(0), r#"{"b":1}"#);
+        assert!(values.is_null(1));
+        assert_eq!(values.value(2), "[2]");
+    }
+
     #[test]
     fn test_complex() {
         let buf = r#"
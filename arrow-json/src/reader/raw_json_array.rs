@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::builder::{GenericStringBuilder, StringViewBuilder};
+use arrow_array::Array;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
+
+use crate::reader::tape::{Tape, TapeElement};
+use crate::reader::ArrayDecoder;
+
+/// Decodes JSON values, of any type, to strings containing their compact JSON
+/// representation, with JSON nulls decoded as nulls
+pub struct RawJsonArrayDecoder {
+    data_type: DataType,
+}
+
+impl RawJsonArrayDecoder {
+    pub fn new(data_type: DataType) -> Result<Self, ArrowError> {
+        match data_type {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Ok(Self { data_type }),
+            d => Err(ArrowError::JsonError(format!(
+                "Raw JSON fields must be a string type, got {d}"
+            ))),
+        }
+    }
+}
+
+impl ArrayDecoder for RawJsonArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        macro_rules! decode {
+            ($builder:expr) => {{
+                let mut builder = $builder;
+                let mut buf = Vec::with_capacity(64);
+                for p in pos {
+                    match tape.get(*p) {
+                        TapeElement::Null => builder.append_null(),
+                        _ => {
+                            buf.clear();
+                            tape.serialize_json(&mut buf, *p);
+                            let s = std::str::from_utf8(&buf)
+                                .map_err(|e| ArrowError::JsonError(e.to_string()))?;
+                            builder.append_value(s);
+                        }
+                    }
+                }
+                builder.finish().into_data()
+            }};
+        }
+
+        Ok(match self.data_type {
+            DataType::Utf8 => decode!(GenericStringBuilder::<i32>::with_capacity(pos.len(), 1024)),
+            DataType::LargeUtf8 => {
+                decode!(GenericStringBuilder::<i64>::with_capacity(pos.len(), 1024))
+            }
+            DataType::Utf8View => decode!(StringViewBuilder::with_capacity(pos.len())),
+            _ => unreachable!(),
+        })
+    }
+}
@@ -20,7 +20,7 @@ use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
 use serde_json::Value;
 use std::borrow::Borrow;
-use std::io::{BufRead, Seek};
+use std::io::{BufRead, Read, Seek};
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -29,6 +29,39 @@ enum InferredType {
     Array(Box<InferredType>),
     Object(HashMap<String, InferredType>),
     Any,
+    /// Values of incompatible JSON types, see [`MixedTypePolicy::Utf8`]
+    Mixed,
+}
+
+/// How scalar values of differing types within the same field are reconciled
+/// during schema inference
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeWidening {
+    /// Widen `Int64` to `Float64`, and any other combination of types to `Utf8`
+    #[default]
+    Widen,
+    /// Widen any combination of types, including `Int64` and `Float64`, to `Utf8`
+    Utf8,
+    /// Return an error if a field contains scalar values of different types
+    Strict,
+}
+
+/// How fields containing values of incompatible JSON types, such as both objects and
+/// scalars, are handled during schema inference
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MixedTypePolicy {
+    /// Return an error
+    #[default]
+    Error,
+    /// Infer the field as `Utf8`
+    ///
+    /// Note: [`Reader`] only decodes strings, and with
+    /// [`ReaderBuilder::with_coerce_primitive`] numbers and booleans, into `Utf8` columns.
+    /// Objects and arrays within such fields will result in a decode error.
+    ///
+    /// [`Reader`]: super::Reader
+    /// [`ReaderBuilder::with_coerce_primitive`]: super::ReaderBuilder::with_coerce_primitive
+    Utf8,
 }
 
 impl InferredType {
@@ -47,6 +80,10 @@ impl InferredType {
                     self_map.entry(k).or_insert(InferredType::Any).merge(v)?;
                 }
             }
+            (InferredType::Mixed, _) => {}
+            (s, InferredType::Mixed) => {
+                *s = InferredType::Mixed;
+            }
             (s @ InferredType::Any, v) => {
                 *s = v;
             }
@@ -82,49 +119,129 @@ fn list_type_of(ty: DataType) -> DataType {
 
 /// Coerce data type during inference
 ///
-/// * `Int64` and `Float64` should be `Float64`
+/// * `Int64` and `Float64` should be `Float64`, unless `widening` is [`TypeWidening::Utf8`]
 /// * Lists and scalars are coerced to a list of a compatible scalar
-/// * All other types are coerced to `Utf8`
-fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
+/// * All other types are coerced to `Utf8`, unless `widening` is [`TypeWidening::Strict`]
+fn coerce_data_type(dt: Vec<&DataType>, widening: TypeWidening) -> Result<DataType, ArrowError> {
     let mut dt_iter = dt.into_iter().cloned();
     let dt_init = dt_iter.next().unwrap_or(DataType::Utf8);
 
-    dt_iter.fold(dt_init, |l, r| match (l, r) {
-        (DataType::Null, o) | (o, DataType::Null) => o,
-        (DataType::Boolean, DataType::Boolean) => DataType::Boolean,
-        (DataType::Int64, DataType::Int64) => DataType::Int64,
-        (DataType::Float64, DataType::Float64)
-        | (DataType::Float64, DataType::Int64)
-        | (DataType::Int64, DataType::Float64) => DataType::Float64,
-        (DataType::List(l), DataType::List(r)) => {
-            list_type_of(coerce_data_type(vec![l.data_type(), r.data_type()]))
-        }
-        // coerce scalar and scalar array into scalar array
-        (DataType::List(e), not_list) | (not_list, DataType::List(e)) => {
-            list_type_of(coerce_data_type(vec![e.data_type(), &not_list]))
-        }
-        _ => DataType::Utf8,
+    dt_iter.try_fold(dt_init, |l, r| {
+        Ok(match (l, r) {
+            (DataType::Null, o) | (o, DataType::Null) => o,
+            (DataType::Boolean, DataType::Boolean) => DataType::Boolean,
+            (DataType::Int64, DataType::Int64) => DataType::Int64,
+            (DataType::Float64, DataType::Float64) => DataType::Float64,
+            (DataType::Float64, DataType::Int64) | (DataType::Int64, DataType::Float64)
+                if widening == TypeWidening::Widen =>
+            {
+                DataType::Float64
+            }
+            (DataType::List(l), DataType::List(r)) => list_type_of(coerce_data_type(
+                vec![l.data_type(), r.data_type()],
+                widening,
+            )?),
+            // coerce scalar and scalar array into scalar array
+            (DataType::List(e), not_list) | (not_list, DataType::List(e)) => {
+                list_type_of(coerce_data_type(vec![e.data_type(), &not_list], widening)?)
+            }
+            (l, r) if widening == TypeWidening::Strict => {
+                return Err(ArrowError::JsonError(format!(
+                    "Incompatible type found during schema inference: {l} v.s. {r}"
+                )))
+            }
+            _ => DataType::Utf8,
+        })
     })
 }
 
-fn generate_datatype(t: &InferredType) -> Result<DataType, ArrowError> {
+fn generate_datatype(t: &InferredType, widening: TypeWidening) -> Result<DataType, ArrowError> {
     Ok(match t {
-        InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect()),
-        InferredType::Object(spec) => DataType::Struct(generate_fields(spec)?),
-        InferredType::Array(ele_type) => list_type_of(generate_datatype(ele_type)?),
+        InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect(), widening)?,
+        InferredType::Object(spec) => {
+            let fields = generate_fields(spec, widening, Default::default(), &[])?;
+            DataType::Struct(fields)
+        }
+        InferredType::Array(ele_type) => list_type_of(generate_datatype(ele_type, widening)?),
         InferredType::Any => DataType::Null,
+        InferredType::Mixed => DataType::Utf8,
     })
 }
 
-fn generate_fields(spec: &HashMap<String, InferredType>) -> Result<Fields, ArrowError> {
+/// Generate the fields of `spec`, with the objects at `map_paths` relative to `spec`
+/// generated as maps
+fn generate_fields(
+    spec: &HashMap<String, InferredType>,
+    widening: TypeWidening,
+    policy: MixedTypePolicy,
+    map_paths: &[&[String]],
+) -> Result<Fields, ArrowError> {
     spec.iter()
-        .map(|(k, types)| Ok(Field::new(k, generate_datatype(types)?, true)))
+        .map(|(k, t)| {
+            let nested: Vec<&[String]> = map_paths
+                .iter()
+                .filter_map(|p| match p.split_first() {
+                    Some((first, rest)) if first == k => Some(rest),
+                    _ => None,
+                })
+                .collect();
+
+            let data_type = match t {
+                InferredType::Object(values) if nested.iter().any(|p| p.is_empty()) => {
+                    generate_map_type(k, values, widening, policy)?
+                }
+                InferredType::Object(spec) if !nested.is_empty() => {
+                    DataType::Struct(generate_fields(spec, widening, policy, &nested)?)
+                }
+                t => generate_datatype(t, widening)?,
+            };
+            Ok(Field::new(k, data_type, true))
+        })
         .collect()
 }
 
+/// Generate a `Map<Utf8, T>` type from the fields of an object, where `T` is
+/// inferred from the values of all fields
+fn generate_map_type(
+    name: &str,
+    spec: &HashMap<String, InferredType>,
+    widening: TypeWidening,
+    policy: MixedTypePolicy,
+) -> Result<DataType, ArrowError> {
+    let mut value_type = InferredType::Any;
+    for t in spec.values() {
+        match value_type.merge(t.clone()) {
+            Ok(()) => {}
+            Err(_) if policy == MixedTypePolicy::Utf8 => value_type = InferredType::Mixed,
+            Err(e) => {
+                return Err(ArrowError::JsonError(format!(
+                    "Incompatible value types found in map field '{name}': {e}"
+                )))
+            }
+        }
+    }
+
+    let entries = Field::new_struct(
+        "entries",
+        vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", generate_datatype(&value_type, widening)?, true),
+        ],
+        false,
+    );
+    Ok(DataType::Map(Arc::new(entries), false))
+}
+
 /// Generate schema from JSON field names and inferred data types
-fn generate_schema(spec: HashMap<String, InferredType>) -> Result<Schema, ArrowError> {
-    Ok(Schema::new(generate_fields(&spec)?))
+fn generate_schema(
+    spec: HashMap<String, InferredType>,
+    widening: TypeWidening,
+    policy: MixedTypePolicy,
+    map_paths: &[&[String]],
+) -> Result<Schema, ArrowError> {
+    Ok(Schema::new(generate_fields(
+        &spec, widening, policy, map_paths,
+    )?))
 }
 
 /// JSON file reader that produces a serde_json::Value iterator from a Read trait
@@ -334,13 +451,16 @@ fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Scalar(hs))
 }
 
-fn infer_nested_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+fn infer_nested_array_type(
+    array: &[Value],
+    policy: MixedTypePolicy,
+) -> Result<InferredType, ArrowError> {
     let mut inner_ele_type = InferredType::Any;
 
     for v in array {
         match v {
             Value::Array(inner_array) => {
-                inner_ele_type.merge(infer_array_element_type(inner_array)?)?;
+                inner_ele_type.merge(infer_array_element_type(inner_array, policy)?)?;
             }
             x => {
                 return Err(ArrowError::JsonError(format!(
@@ -353,13 +473,16 @@ fn infer_nested_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Array(Box::new(inner_ele_type)))
 }
 
-fn infer_struct_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+fn infer_struct_array_type(
+    array: &[Value],
+    policy: MixedTypePolicy,
+) -> Result<InferredType, ArrowError> {
     let mut field_types = HashMap::new();
 
     for v in array {
         match v {
             Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
+                collect_field_types_from_object(&mut field_types, map, policy)?;
             }
             _ => {
                 return Err(ArrowError::JsonError(format!(
@@ -372,12 +495,15 @@ fn infer_struct_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Object(field_types))
 }
 
-fn infer_array_element_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+fn infer_array_element_type(
+    array: &[Value],
+    policy: MixedTypePolicy,
+) -> Result<InferredType, ArrowError> {
     match array.iter().take(1).next() {
         None => Ok(InferredType::Any), // empty array, return any type that can be updated later
         Some(a) => match a {
-            Value::Array(_) => infer_nested_array_type(array),
-            Value::Object(_) => infer_struct_array_type(array),
+            Value::Array(_) => infer_nested_array_type(array, policy),
+            Value::Object(_) => infer_struct_array_type(array, policy),
             _ => infer_scalar_array_type(array),
         },
     }
@@ -386,87 +512,108 @@ fn infer_array_element_type(array: &[Value]) -> Result<InferredType, ArrowError>
 fn collect_field_types_from_object(
     field_types: &mut HashMap<String, InferredType>,
     map: &serde_json::map::Map<String, Value>,
+    policy: MixedTypePolicy,
 ) -> Result<(), ArrowError> {
     for (k, v) in map {
-        match v {
-            Value::Array(array) => {
-                let ele_type = infer_array_element_type(array)?;
-
-                if InferredType::is_none_or_any(field_types.get(k)) {
-                    match ele_type {
-                        InferredType::Scalar(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Scalar(HashSet::new()))),
-                            );
-                        }
-                        InferredType::Object(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Object(HashMap::new()))),
-                            );
-                        }
-                        InferredType::Any | InferredType::Array(_) => {
-                            // set inner type to any for nested array as well
-                            // so it can be updated properly from subsequent type merges
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Any)),
-                            );
-                        }
-                    }
+        match field_types.get(k) {
+            Some(InferredType::Mixed) => {}
+            _ => match collect_field_type(field_types, k, v, policy) {
+                Ok(()) => {}
+                Err(_) if policy == MixedTypePolicy::Utf8 => {
+                    field_types.insert(k.to_string(), InferredType::Mixed);
                 }
+                Err(e) => return Err(e),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_field_type(
+    field_types: &mut HashMap<String, InferredType>,
+    k: &str,
+    v: &Value,
+    policy: MixedTypePolicy,
+) -> Result<(), ArrowError> {
+    match v {
+        Value::Array(array) => {
+            let ele_type = infer_array_element_type(array, policy)?;
 
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Array(inner_type) => {
-                        inner_type.merge(ele_type)?;
+            if InferredType::is_none_or_any(field_types.get(k)) {
+                match ele_type {
+                    InferredType::Scalar(_) => {
+                        field_types.insert(
+                            k.to_string(),
+                            InferredType::Array(Box::new(InferredType::Scalar(HashSet::new()))),
+                        );
                     }
-                    // in case of column contains both scalar type and scalar array type, we
-                    // convert type of this column to scalar array.
-                    field_type @ InferredType::Scalar(_) => {
-                        field_type.merge(ele_type)?;
-                        *field_type = InferredType::Array(Box::new(field_type.clone()));
+                    InferredType::Object(_) => {
+                        field_types.insert(
+                            k.to_string(),
+                            InferredType::Array(Box::new(InferredType::Object(HashMap::new()))),
+                        );
                     }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected array json type, found: {t:?}",
-                        )));
+                    InferredType::Any | InferredType::Array(_) | InferredType::Mixed => {
+                        // set inner type to any for nested array as well
+                        // so it can be updated properly from subsequent type merges
+                        field_types.insert(
+                            k.to_string(),
+                            InferredType::Array(Box::new(InferredType::Any)),
+                        );
                     }
                 }
             }
-            Value::Bool(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Boolean)?;
-            }
-            Value::Null => {
-                // we treat json as nullable by default when inferring, so just
-                // mark existence of a field if it wasn't known before
-                if !field_types.contains_key(k) {
-                    field_types.insert(k.to_string(), InferredType::Any);
+
+            match field_types.get_mut(k).unwrap() {
+                InferredType::Array(inner_type) => {
+                    inner_type.merge(ele_type)?;
                 }
-            }
-            Value::Number(n) => {
-                if n.is_i64() {
-                    set_object_scalar_field_type(field_types, k, DataType::Int64)?;
-                } else {
-                    set_object_scalar_field_type(field_types, k, DataType::Float64)?;
+                // in case of column contains both scalar type and scalar array type, we
+                // convert type of this column to scalar array.
+                field_type @ InferredType::Scalar(_) => {
+                    field_type.merge(ele_type)?;
+                    *field_type = InferredType::Array(Box::new(field_type.clone()));
+                }
+                t => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Expected array json type, found: {t:?}",
+                    )));
                 }
             }
-            Value::String(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Utf8)?;
+        }
+        Value::Bool(_) => {
+            set_object_scalar_field_type(field_types, k, DataType::Boolean)?;
+        }
+        Value::Null => {
+            // we treat json as nullable by default when inferring, so just
+            // mark existence of a field if it wasn't known before
+            if !field_types.contains_key(k) {
+                field_types.insert(k.to_string(), InferredType::Any);
+            }
+        }
+        Value::Number(n) => {
+            if n.is_i64() {
+                set_object_scalar_field_type(field_types, k, DataType::Int64)?;
+            } else {
+                set_object_scalar_field_type(field_types, k, DataType::Float64)?;
             }
-            Value::Object(inner_map) => {
-                if let InferredType::Any = field_types.get(k).unwrap_or(&InferredType::Any) {
-                    field_types.insert(k.to_string(), InferredType::Object(HashMap::new()));
+        }
+        Value::String(_) => {
+            set_object_scalar_field_type(field_types, k, DataType::Utf8)?;
+        }
+        Value::Object(inner_map) => {
+            if let InferredType::Any = field_types.get(k).unwrap_or(&InferredType::Any) {
+                field_types.insert(k.to_string(), InferredType::Object(HashMap::new()));
+            }
+            match field_types.get_mut(k).unwrap() {
+                InferredType::Object(inner_field_types) => {
+                    collect_field_types_from_object(inner_field_types, inner_map, policy)?;
                 }
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Object(inner_field_types) => {
-                        collect_field_types_from_object(inner_field_types, inner_map)?;
-                    }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected object json type, found: {t:?}",
-                        )));
-                    }
+                t => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Expected object json type, found: {t:?}",
+                    )));
                 }
             }
         }
@@ -496,24 +643,256 @@ where
     let mut field_types: HashMap<String, InferredType> = HashMap::new();
 
     for record in value_iter {
-        match record?.borrow() {
-            Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
-            }
-            value => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected JSON record to be an object, found {value:?}"
-                )));
+        collect_field_types_from_record(&mut field_types, record?.borrow(), Default::default())?;
+    }
+
+    generate_schema(field_types, Default::default(), Default::default(), &[])
+}
+
+fn collect_field_types_from_record(
+    field_types: &mut HashMap<String, InferredType>,
+    record: &Value,
+    policy: MixedTypePolicy,
+) -> Result<(), ArrowError> {
+    match record {
+        Value::Object(map) => collect_field_types_from_object(field_types, map, policy),
+        value => Err(ArrowError::JsonError(format!(
+            "Expected JSON record to be an object, found {value:?}"
+        ))),
+    }
+}
+
+/// A push-based interface for inferring the schema of newline delimited JSON
+///
+/// Unlike [`infer_json_schema`], this does not require a [`BufRead`], facilitating
+/// integration with sources that yield arbitrarily delimited byte ranges, such as an
+/// async reader or a chunked byte stream received from object storage. Only a sample
+/// of the input, limited by [`Self::with_max_records`] and [`Self::with_max_bytes`],
+/// need be read.
+///
+/// ```
+/// # use arrow_json::reader::{MixedTypePolicy, SchemaInferrer};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use futures::{AsyncBufRead, AsyncBufReadExt};
+/// #
+/// async fn infer_schema<R: AsyncBufRead + Unpin>(mut reader: R) -> Schema {
+///     let mut inferrer = SchemaInferrer::new()
+///         .with_max_records(Some(1000))
+///         .with_mixed_type_policy(MixedTypePolicy::Utf8);
+///     loop {
+///         let buf = reader.fill_buf().await.unwrap();
+///         if buf.is_empty() {
+///             break; // Input exhausted
+///         }
+///         let read = buf.len();
+///         let consumed = inferrer.update(buf).unwrap();
+///         reader.consume_unpin(consumed);
+///         if consumed != read {
+///             break; // Sample complete
+///         }
+///     }
+///     inferrer.finish().unwrap()
+/// }
+///
+/// let data = "{\"a\": 1, \"b\": {\"c\": true}}\n{\"a\": 2.5, \"b\": \"foo\"}\n";
+/// let schema = futures::executor::block_on(infer_schema(data.as_bytes()));
+/// assert_eq!(
+///     schema,
+///     Schema::new(vec![
+///         Field::new("a", DataType::Float64, true),
+///         Field::new("b", DataType::Utf8, true),
+///     ])
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct SchemaInferrer {
+    field_types: HashMap<String, InferredType>,
+    max_records: Option<usize>,
+    max_bytes: Option<usize>,
+    widening: TypeWidening,
+    policy: MixedTypePolicy,
+    map_fields: Vec<Vec<String>>,
+    records_read: usize,
+    bytes_read: usize,
+    // the bytes of a record split across calls to update
+    partial: Vec<u8>,
+}
+
+impl SchemaInferrer {
+    /// Create a new [`SchemaInferrer`] that reads all records
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of records to read
+    pub fn with_max_records(self, max_records: Option<usize>) -> Self {
+        Self {
+            max_records,
+            ..self
+        }
+    }
+
+    /// Sets the number of bytes after which no further records are read
+    ///
+    /// The record containing the `max_bytes`-th byte is read in full
+    pub fn with_max_bytes(self, max_bytes: Option<usize>) -> Self {
+        Self { max_bytes, ..self }
+    }
+
+    /// Sets how scalar values of differing types are reconciled, defaults to
+    /// [`TypeWidening::Widen`]
+    pub fn with_type_widening(self, widening: TypeWidening) -> Self {
+        Self { widening, ..self }
+    }
+
+    /// Sets how fields with values of incompatible JSON types are handled, defaults to
+    /// [`MixedTypePolicy::Error`]
+    pub fn with_mixed_type_policy(self, policy: MixedTypePolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    /// Infer the objects at `paths`, of the form `$.a.b` or `a.b`, as
+    /// `Map<Utf8, T>` instead of structs
+    ///
+    /// This is useful for objects with dynamic keys, such as labels or tags, that
+    /// would otherwise be inferred as structs with a field per distinct key. `T` is
+    /// inferred from the values of all keys, and paths may only select objects
+    /// nested within objects.
+    ///
+    /// ```
+    /// # use arrow_json::reader::SchemaInferrer;
+    /// # use arrow_schema::DataType;
+    /// let data = "{\"labels\": {\"host\": \"a\", \"region\": \"b\"}}\n{\"labels\": {\"az\": \"c\"}}";
+    /// let schema = SchemaInferrer::new()
+    ///     .with_map_fields(["$.labels"])
+    ///     .read(data.as_bytes())
+    ///     .unwrap()
+    ///     .finish()
+    ///     .unwrap();
+    /// let DataType::Map(entries, _) = schema.field(0).data_type() else { unreachable!() };
+    /// assert_eq!(
+    ///     entries.data_type(),
+    ///     &DataType::Struct(
+    ///         vec![
+    ///             arrow_schema::Field::new("keys", DataType::Utf8, false),
+    ///             arrow_schema::Field::new("values", DataType::Utf8, true),
+    ///         ]
+    ///         .into()
+    ///     )
+    /// );
+    /// ```
+    pub fn with_map_fields<I, S>(self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let map_fields = paths
+            .into_iter()
+            .map(|p| {
+                let p = p.as_ref();
+                let p = p.strip_prefix("$.").unwrap_or(p);
+                p.split('.').map(ToString::to_string).collect()
+            })
+            .collect();
+        Self { map_fields, ..self }
+    }
+
+    /// Returns the number of records read
+    pub fn records_read(&self) -> usize {
+        self.records_read
+    }
+
+    /// Returns the number of bytes read
+    pub fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+
+    /// Returns true if the sampling limits have been reached, and no further
+    /// records will be read
+    pub fn is_finished(&self) -> bool {
+        let limited = self.max_records.is_some_and(|max| self.records_read >= max)
+            || self.max_bytes.is_some_and(|max| self.bytes_read >= max);
+        limited && self.partial.is_empty()
+    }
+
+    /// Read newline delimited JSON records from `buf`, returning the number of bytes read
+    ///
+    /// This returns less than `buf.len()` only once [`Self::is_finished`]. There is no
+    /// requirement that `buf` contains a whole number of records
+    pub fn update(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
+        let mut read = 0;
+        while read < buf.len() && !self.is_finished() {
+            let remaining = &buf[read..];
+            match remaining.iter().position(|b| *b == b'\n') {
+                Some(idx) => {
+                    read += idx + 1;
+                    self.bytes_read += idx + 1;
+                    if self.partial.is_empty() {
+                        self.infer_line(&remaining[..idx])?;
+                    } else {
+                        let mut line = std::mem::take(&mut self.partial);
+                        line.extend_from_slice(&remaining[..idx]);
+                        self.infer_line(&line)?;
+                    }
+                }
+                None => {
+                    read = buf.len();
+                    self.bytes_read += remaining.len();
+                    self.partial.extend_from_slice(remaining);
+                }
             }
-        };
+        }
+        Ok(read)
+    }
+
+    /// Read all records, up to the sampling limits, from `reader`
+    ///
+    /// Note: as `reader` is read in chunks, it may have been read past the last
+    /// record sampled
+    pub fn read<R: Read>(mut self, mut reader: R) -> Result<Self, ArrowError> {
+        let mut buf = vec![0; 8 * 1024];
+        while !self.is_finished() {
+            let read = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Failed to read JSON record: {e}"
+                    )))
+                }
+            };
+            self.update(&buf[..read])?;
+        }
+        Ok(self)
+    }
+
+    /// Finish inference returning the inferred [`Schema`]
+    ///
+    /// Any final record not terminated by a newline is included
+    pub fn finish(mut self) -> Result<Schema, ArrowError> {
+        let line = std::mem::take(&mut self.partial);
+        self.infer_line(&line)?;
+        let map_paths: Vec<_> = self.map_fields.iter().map(Vec::as_slice).collect();
+        generate_schema(self.field_types, self.widening, self.policy, &map_paths)
     }
 
-    generate_schema(field_types)
+    fn infer_line(&mut self, line: &[u8]) -> Result<(), ArrowError> {
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            return Ok(());
+        }
+        let value: Value = serde_json::from_slice(line)
+            .map_err(|e| ArrowError::JsonError(format!("Not valid JSON: {e}")))?;
+        self.records_read += 1;
+        collect_field_types_from_record(&mut self.field_types, &value, self.policy)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::Array;
     use flate2::read::GzDecoder;
     use std::fs::File;
     use std::io::{BufReader, Cursor};
@@ -672,20 +1051,36 @@ mod tests {
     fn test_coercion_scalar_and_list() {
         assert_eq!(
             list_type_of(DataType::Float64),
-            coerce_data_type(vec![&DataType::Float64, &list_type_of(DataType::Float64)])
+            coerce_data_type(
+                vec![&DataType::Float64, &list_type_of(DataType::Float64)],
+                TypeWidening::Widen
+            )
+            .unwrap()
         );
         assert_eq!(
             list_type_of(DataType::Float64),
-            coerce_data_type(vec![&DataType::Float64, &list_type_of(DataType::Int64)])
+            coerce_data_type(
+                vec![&DataType::Float64, &list_type_of(DataType::Int64)],
+                TypeWidening::Widen
+            )
+            .unwrap()
         );
         assert_eq!(
             list_type_of(DataType::Int64),
-            coerce_data_type(vec![&DataType::Int64, &list_type_of(DataType::Int64)])
+            coerce_data_type(
+                vec![&DataType::Int64, &list_type_of(DataType::Int64)],
+                TypeWidening::Widen
+            )
+            .unwrap()
         );
         // boolean and number are incompatible, return utf8
         assert_eq!(
             list_type_of(DataType::Utf8),
-            coerce_data_type(vec![&DataType::Boolean, &list_type_of(DataType::Float64)])
+            coerce_data_type(
+                vec![&DataType::Boolean, &list_type_of(DataType::Float64)],
+                TypeWidening::Widen
+            )
+            .unwrap()
         );
     }
 
@@ -739,4 +1134,202 @@ mod tests {
         )]);
         assert_eq!(inferred_schema, schema);
     }
+
+    #[test]
+    fn test_schema_inferrer_chunked() {
+        let data = "{\"a\": 1, \"b\": \"x\"}\n\n{\"a\": 2.5, \"c\": [true]}\n{\"d\": null}";
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", list_type_of(DataType::Boolean), true),
+            Field::new("d", DataType::Null, true),
+        ]);
+
+        for chunk_size in [1, 3, 7, data.len()] {
+            let mut inferrer = SchemaInferrer::new();
+            for chunk in data.as_bytes().chunks(chunk_size) {
+                assert_eq!(inferrer.update(chunk).unwrap(), chunk.len());
+            }
+            assert_eq!(inferrer.records_read(), 2);
+            assert_eq!(inferrer.bytes_read(), data.len());
+            assert_eq!(inferrer.finish().unwrap(), expected);
+        }
+
+        let inferrer = SchemaInferrer::new().read(data.as_bytes()).unwrap();
+        assert_eq!(inferrer.finish().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_schema_inferrer_sampling() {
+        let data = "{\"a\": 1}\n{\"b\": 2}\n{\"c\": 3}\n";
+
+        let mut inferrer = SchemaInferrer::new().with_max_records(Some(2));
+        assert_eq!(inferrer.update(data.as_bytes()).unwrap(), 18);
+        assert!(inferrer.is_finished());
+        let schema = inferrer.finish().unwrap();
+        assert_eq!(schema.fields().len(), 2);
+
+        // The record containing the limit is read in full
+        let mut inferrer = SchemaInferrer::new().with_max_bytes(Some(10));
+        assert_eq!(inferrer.update(&data.as_bytes()[..12]).unwrap(), 12);
+        assert!(!inferrer.is_finished());
+        assert_eq!(inferrer.update(&data.as_bytes()[12..]).unwrap(), 6);
+        assert!(inferrer.is_finished());
+        assert_eq!(inferrer.records_read(), 2);
+
+        let inferrer = SchemaInferrer::new()
+            .with_max_bytes(Some(1))
+            .read(data.as_bytes())
+            .unwrap();
+        assert_eq!(inferrer.records_read(), 1);
+        assert_eq!(inferrer.finish().unwrap().fields().len(), 1);
+    }
+
+    #[test]
+    fn test_schema_inferrer_type_widening() {
+        let data = "{\"a\": 1, \"b\": 1, \"c\": [1]}\n{\"a\": 1.5, \"b\": \"x\", \"c\": [1.5]}\n";
+        let infer = |widening| {
+            SchemaInferrer::new()
+                .with_type_widening(widening)
+                .read(data.as_bytes())
+                .unwrap()
+                .finish()
+        };
+
+        let schema = infer(TypeWidening::Widen).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(
+            schema.field(2).data_type(),
+            &list_type_of(DataType::Float64)
+        );
+
+        let schema = infer(TypeWidening::Utf8).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(2).data_type(), &list_type_of(DataType::Utf8));
+
+        let err = infer(TypeWidening::Strict).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Incompatible type found during schema inference: Int64 v.s. Float64"
+        );
+    }
+
+    #[test]
+    fn test_schema_inferrer_mixed_types() {
+        let data = r#"
+            {"a": {"x": 1}, "b": {"c": 1, "d": [1]}, "e": [{"f": 1}]}
+            {"a": 1, "b": {"c": {"y": 2}, "d": [{"z": 1}]}, "e": [{"f": [2]}]}
+            {"a": [1], "b": {"c": "foo", "d": 1}, "e": 1}
+        "#;
+
+        let err = SchemaInferrer::new()
+            .read(data.as_bytes())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Expected scalar or scalar array JSON type"),
+            "{err}"
+        );
+
+        let schema = SchemaInferrer::new()
+            .with_mixed_type_policy(MixedTypePolicy::Utf8)
+            .read(data.as_bytes())
+            .unwrap()
+            .finish()
+            .unwrap();
+        let b = Fields::from(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Utf8, true),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Struct(b), true),
+            // A list of structs and a scalar are incompatible
+            Field::new("e", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema, expected);
+    }
+
+    #[test]
+    fn test_schema_inferrer_map_fields() {
+        let data = r#"
+            {"labels": {"host": "a", "region": "b"}, "meta": {"tags": {"x": 1}, "id": 1}}
+            {"labels": {"az": "c"}, "meta": {"tags": {"y": 2.5, "z": null}, "id": 2}}
+            {"labels": null, "meta": {"tags": {}}}
+        "#;
+        let schema = SchemaInferrer::new()
+            .with_map_fields(["$.labels", "meta.tags"])
+            .read(data.as_bytes())
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        let map_of = |value_type| {
+            let entries = Field::new_struct(
+                "entries",
+                vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    Field::new("values", value_type, true),
+                ],
+                false,
+            );
+            DataType::Map(Arc::new(entries), false)
+        };
+        let meta = Fields::from(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("tags", map_of(DataType::Float64), true),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("labels", map_of(DataType::Utf8), true),
+            Field::new("meta", DataType::Struct(meta), true),
+        ]);
+        assert_eq!(schema, expected);
+
+        // The inferred schema can be used to decode the data
+        let batch = crate::ReaderBuilder::new(Arc::new(schema))
+            .build(Cursor::new(data))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let labels = batch.column(0).as_map();
+        assert_eq!(labels.value_offsets(), &[0, 2, 3, 3]);
+        assert!(labels.is_null(2));
+        assert_eq!(labels.keys().as_string::<i32>().value(2), "az");
+        let tags = batch.column(1).as_struct().column(1).as_map();
+        assert_eq!(tags.value_offsets(), &[0, 1, 3, 3]);
+    }
+
+    #[test]
+    fn test_schema_inferrer_map_fields_mixed() {
+        let data = "{\"m\": {\"a\": 1, \"b\": {\"c\": 1}}}";
+        let err = SchemaInferrer::new()
+            .with_map_fields(["m"])
+            .read(data.as_bytes())
+            .unwrap()
+            .finish()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Incompatible value types found in map field 'm'"),
+            "{err}"
+        );
+
+        let schema = SchemaInferrer::new()
+            .with_map_fields(["m"])
+            .with_mixed_type_policy(MixedTypePolicy::Utf8)
+            .read(data.as_bytes())
+            .unwrap()
+            .finish()
+            .unwrap();
+        let DataType::Map(entries, _) = schema.field(0).data_type() else {
+            unreachable!()
+        };
+        let DataType::Struct(entries) = entries.data_type() else {
+            unreachable!()
+        };
+        assert_eq!(entries[1].data_type(), &DataType::Utf8);
+    }
 }
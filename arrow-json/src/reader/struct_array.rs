@@ -16,15 +16,68 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_field_decoder, ArrayDecoder};
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_buffer::buffer::NullBuffer;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Fields};
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// The fields of a struct selected by [`ReaderBuilder::with_projection`]
+///
+/// [`ReaderBuilder::with_projection`]: crate::ReaderBuilder::with_projection
+#[derive(Debug, Default)]
+pub struct Projection(IndexMap<String, Option<Projection>>);
+
+impl Projection {
+    /// Returns the names of the selected fields
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    /// Returns the selection of the field `name`, that is `None` if not selected,
+    /// `Some(None)` if selected in whole, or the nested selection
+    pub fn get(&self, name: &str) -> Option<&Option<Projection>> {
+        self.0.get(name)
+    }
+
+    /// Parse a set of paths of the form `$.a.b`, or equivalently `a.b`
+    pub fn try_new<S: AsRef<str>>(paths: &[S]) -> Result<Self, ArrowError> {
+        let mut projection = Self::default();
+        for path in paths {
+            let path = path.as_ref();
+            let trimmed = path.strip_prefix("$.").unwrap_or(path);
+            let segments: Vec<_> = trimmed.split('.').collect();
+            if segments.iter().any(|s| s.is_empty()) {
+                return Err(ArrowError::JsonError(format!(
+                    "Invalid projection path '{path}'"
+                )));
+            }
+            projection.insert(&segments);
+        }
+        Ok(projection)
+    }
+
+    fn insert(&mut self, segments: &[&str]) {
+        let (first, rest) = segments.split_first().unwrap();
+        let child = self.0.entry(first.to_string()).or_insert_with(|| {
+            // An empty rest selects the whole field
+            (!rest.is_empty()).then(Projection::default)
+        });
+        match child {
+            Some(child) if !rest.is_empty() => child.insert(rest),
+            // Selecting the whole field supersedes any nested selection
+            _ => *child = None,
+        }
+    }
+}
 
 pub struct StructArrayDecoder {
     data_type: DataType,
     decoders: Vec<Box<dyn ArrayDecoder>>,
+    /// Fields of the schema omitted by a projection
+    skipped: Vec<String>,
     strict_mode: bool,
     is_nullable: bool,
 }
@@ -43,22 +96,84 @@ impl StructArrayDecoder {
                 // StructArrayDecoder::decode verifies that if the child is not nullable
                 // it doesn't contain any nulls not masked by its parent
                 let nullable = f.is_nullable() || is_nullable;
-                make_decoder(
-                    f.data_type().clone(),
-                    coerce_primitive,
-                    strict_mode,
-                    nullable,
-                )
+                make_field_decoder(f, coerce_primitive, strict_mode, nullable)
             })
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
         Ok(Self {
             data_type,
             decoders,
+            skipped: vec![],
+            strict_mode,
+            is_nullable,
+        })
+    }
+
+    /// Create a decoder of the fields of `data_type` selected by `projection`
+    pub fn new_projected(
+        data_type: &DataType,
+        projection: &Projection,
+        coerce_primitive: bool,
+        strict_mode: bool,
+        is_nullable: bool,
+    ) -> Result<Self, ArrowError> {
+        let fields = struct_fields(data_type);
+        if let Some(name) = projection.0.keys().find(|n| fields.find(n).is_none()) {
+            return Err(ArrowError::JsonError(format!(
+                "Projected field '{name}' not found in schema"
+            )));
+        }
+
+        let mut projected = Vec::with_capacity(projection.0.len());
+        let mut decoders = Vec::with_capacity(projection.0.len());
+        let mut skipped = vec![];
+        for f in fields {
+            let nullable = f.is_nullable() || is_nullable;
+            match projection.0.get(f.name()) {
+                None => skipped.push(f.name().clone()),
+                Some(None) => {
+                    decoders.push(make_field_decoder(
+                        f,
+                        coerce_primitive,
+                        strict_mode,
+                        nullable,
+                    )?);
+                    projected.push(f.clone());
+                }
+                Some(Some(child)) => {
+                    if !matches!(f.data_type(), DataType::Struct(_)) {
+                        return Err(ArrowError::JsonError(format!(
+                            "Cannot project nested fields of non-struct field '{}'",
+                            f.name()
+                        )));
+                    }
+                    let decoder = Self::new_projected(
+                        f.data_type(),
+                        child,
+                        coerce_primitive,
+                        strict_mode,
+                        nullable,
+                    )?;
+                    let field = f.as_ref().clone().with_data_type(decoder.data_type.clone());
+                    projected.push(Arc::new(field));
+                    decoders.push(Box::new(decoder));
+                }
+            }
+        }
+
+        Ok(Self {
+            data_type: DataType::Struct(projected.into()),
+            decoders,
+            skipped,
             strict_mode,
             is_nullable,
         })
     }
+
+    /// Returns the data type of the decoded arrays
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
 }
 
 impl ArrayDecoder for StructArrayDecoder {
@@ -96,7 +211,7 @@ impl ArrayDecoder for StructArrayDecoder {
                 match fields.iter().position(|x| x.name() == field_name) {
                     Some(field_idx) => child_pos[field_idx][row] = cur_idx + 1,
                     None => {
-                        if self.strict_mode {
+                        if self.strict_mode && !self.skipped.iter().any(|s| s == field_name) {
                             return Err(ArrowError::JsonError(format!(
                                 "column '{}' missing from schema",
                                 field_name
@@ -204,6 +204,61 @@ impl<'a> Tape<'a> {
         idx + 1
     }
 
+    /// Serialize the tape element at index `idx` to `out` as compact JSON, returning
+    /// the next field index
+    ///
+    /// Unlike [`Self::serialize`] strings are escaped, yielding valid JSON
+    pub fn serialize_json(&self, out: &mut Vec<u8>, idx: u32) -> u32 {
+        match self.get(idx) {
+            TapeElement::StartObject(end) => {
+                out.push(b'{');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx != idx + 1 {
+                        out.push(b',');
+                    }
+                    cur_idx = self.serialize_json(out, cur_idx);
+                    out.push(b':');
+                    cur_idx = self.serialize_json(out, cur_idx);
+                }
+                out.push(b'}');
+                return end + 1;
+            }
+            TapeElement::StartList(end) => {
+                out.push(b'[');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx != idx + 1 {
+                        out.push(b',');
+                    }
+                    cur_idx = self.serialize_json(out, cur_idx);
+                }
+                out.push(b']');
+                return end + 1;
+            }
+            TapeElement::String(s) => {
+                let mut serializer = serde_json::Serializer::new(out);
+                serde::Serializer::serialize_str(&mut serializer, self.get_string(s)).unwrap();
+            }
+            TapeElement::EndObject(_)
+            | TapeElement::EndList(_)
+            | TapeElement::Number(_)
+            | TapeElement::True
+            | TapeElement::False
+            | TapeElement::Null
+            | TapeElement::I64(_)
+            | TapeElement::I32(_)
+            | TapeElement::F64(_)
+            | TapeElement::F32(_) => {
+                let mut s = String::new();
+                let next = self.serialize(&mut s, idx);
+                out.extend_from_slice(s.as_bytes());
+                return next;
+            }
+        }
+        idx + 1
+    }
+
     /// Returns an error reading index `idx`
     pub fn error(&self, idx: u32, expected: &str) -> ArrowError {
         let mut out = String::with_capacity(64);
@@ -20,7 +20,9 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_cast::display::{ArrayFormatter, FormatOptions};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_schema::{ArrowError, DataType, FieldRef, TimeUnit};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use half::f16;
 use lexical_core::FormattedSize;
 use serde::Serializer;
@@ -29,6 +31,46 @@ use std::io::Write;
 #[derive(Debug, Clone, Default)]
 pub struct EncoderOptions {
     pub explicit_nulls: bool,
+    pub timestamp_format: TimestampFormat,
+    pub decimal_format: DecimalFormat,
+    pub binary_format: BinaryFormat,
+}
+
+/// How timestamps are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// ISO 8601 strings, with an offset only if the timestamp has a timezone,
+    /// e.g. `"2018-11-13T17:11:10.011"`
+    #[default]
+    Iso8601,
+    /// RFC 3339 strings, that always have an offset, with timestamps without a
+    /// timezone assumed to be UTC, e.g. `"2018-11-13T17:11:10.011Z"`
+    Rfc3339,
+    /// Integers counting the time since the UNIX epoch, in the timestamp's [`TimeUnit`]
+    ///
+    /// [`TimeUnit`]: arrow_schema::TimeUnit
+    Epoch,
+}
+
+/// How decimals are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalFormat {
+    /// JSON numbers, e.g. `12.34`
+    #[default]
+    Number,
+    /// Strings, preserving precision for consumers that parse numbers as floats,
+    /// e.g. `"12.34"`
+    String,
+}
+
+/// How binary values are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryFormat {
+    /// Lowercase hexadecimal strings
+    #[default]
+    Hex,
+    /// Standard, padded, base64 strings
+    Base64,
 }
 
 /// A trait to format array values as JSON values
@@ -109,17 +151,17 @@ fn make_encoder_impl<'a>(
 
         DataType::FixedSizeBinary(_) => {
             let array = array.as_fixed_size_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_format)) as _, array.nulls().cloned())
         }
 
         DataType::Binary => {
             let array: &BinaryArray = array.as_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_format)) as _, array.nulls().cloned())
         }
 
         DataType::LargeBinary => {
             let array: &LargeBinaryArray = array.as_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_format)) as _, array.nulls().cloned())
         }
 
         DataType::Struct(fields) => {
@@ -139,21 +181,36 @@ fn make_encoder_impl<'a>(
             (Box::new(encoder) as _, array.nulls().cloned())
         }
         DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
-            let options = FormatOptions::new().with_display_error(true);
-            let formatter = ArrayFormatter::try_new(array, &options)?;
-            (Box::new(RawArrayFormatter(formatter)) as _, array.nulls().cloned())
+            let format_options = FormatOptions::new().with_display_error(true);
+            let formatter = ArrayFormatter::try_new(array, &format_options)?;
+            match options.decimal_format {
+                DecimalFormat::Number => (Box::new(RawArrayFormatter(formatter)) as _, array.nulls().cloned()),
+                DecimalFormat::String => (Box::new(formatter) as _, array.nulls().cloned()),
+            }
         }
-        d => match d.is_temporal() {
-            true => {
+        d => match (d, d.is_temporal()) {
+            (DataType::Timestamp(unit, _), _) if options.timestamp_format == TimestampFormat::Epoch => match unit {
+                TimeUnit::Second => primitive_helper!(TimestampSecondType),
+                TimeUnit::Millisecond => primitive_helper!(TimestampMillisecondType),
+                TimeUnit::Microsecond => primitive_helper!(TimestampMicrosecondType),
+                TimeUnit::Nanosecond => primitive_helper!(TimestampNanosecondType),
+            },
+            (_, true) => {
                 // Note: the implementation of Encoder for ArrayFormatter assumes it does not produce
                 // characters that would need to be escaped within a JSON string, e.g. `'"'`.
                 // If support for user-provided format specifications is added, this assumption
                 // may need to be revisited
-                let options = FormatOptions::new().with_display_error(true);
-                let formatter = ArrayFormatter::try_new(array, &options)?;
+                let format_options = match options.timestamp_format {
+                    TimestampFormat::Rfc3339 => FormatOptions::new()
+                        .with_timestamp_format(Some("%Y-%m-%dT%H:%M:%S%.fZ"))
+                        .with_timestamp_tz_format(Some("%Y-%m-%dT%H:%M:%S%.f%:z")),
+                    _ => FormatOptions::new(),
+                };
+                let format_options = format_options.with_display_error(true);
+                let formatter = ArrayFormatter::try_new(array, &format_options)?;
                 (Box::new(formatter) as _, array.nulls().cloned())
             }
-            false => return Err(ArrowError::InvalidArgumentError(format!("JSON Writer does not support data type: {d}"))),
+            (_, false) => return Err(ArrowError::InvalidArgumentError(format!("JSON Writer does not support data type: {d}"))),
         }
     })
 }
@@ -547,14 +604,14 @@ impl Encoder for MapEncoder<'_> {
 
 /// New-type wrapper for encoding the binary types in arrow: `Binary`, `LargeBinary`
 /// and `FixedSizeBinary` as hex strings in JSON.
-struct BinaryEncoder<B>(B);
+struct BinaryEncoder<B>(B, BinaryFormat);
 
 impl<'a, B> BinaryEncoder<B>
 where
     B: ArrayAccessor<Item = &'a [u8]>,
 {
-    fn new(array: B) -> Self {
-        Self(array)
+    fn new(array: B, format: BinaryFormat) -> Self {
+        Self(array, format)
     }
 }
 
@@ -564,9 +621,18 @@ where
 {
     fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
         out.push(b'"');
-        for byte in self.0.value(idx) {
-            // this write is infallible
-            write!(out, "{byte:02x}").unwrap();
+        match self.1 {
+            BinaryFormat::Hex => {
+                for byte in self.0.value(idx) {
+                    // this write is infallible
+                    write!(out, "{byte:02x}").unwrap();
+                }
+            }
+            BinaryFormat::Base64 => {
+                // base64 output never requires escaping
+                let encoded = BASE64_STANDARD.encode(self.0.value(idx));
+                out.extend_from_slice(encoded.as_bytes());
+            }
         }
         out.push(b'"');
     }
@@ -112,6 +112,7 @@ use arrow_array::*;
 use arrow_schema::*;
 
 use encoder::{make_encoder, EncoderOptions};
+pub use encoder::{BinaryFormat, DecimalFormat, TimestampFormat};
 
 /// This trait defines how to format a sequence of JSON objects to a
 /// byte stream.
@@ -253,6 +254,45 @@ impl WriterBuilder {
         self
     }
 
+    /// Returns the [`TimestampFormat`] used to write timestamps
+    pub fn timestamp_format(&self) -> TimestampFormat {
+        self.0.timestamp_format
+    }
+
+    /// Set how timestamps are written
+    ///
+    /// Default is [`TimestampFormat::Iso8601`]
+    pub fn with_timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.0.timestamp_format = format;
+        self
+    }
+
+    /// Returns the [`DecimalFormat`] used to write decimals
+    pub fn decimal_format(&self) -> DecimalFormat {
+        self.0.decimal_format
+    }
+
+    /// Set how decimals are written
+    ///
+    /// Default is [`DecimalFormat::Number`]
+    pub fn with_decimal_format(mut self, format: DecimalFormat) -> Self {
+        self.0.decimal_format = format;
+        self
+    }
+
+    /// Returns the [`BinaryFormat`] used to write binary values
+    pub fn binary_format(&self) -> BinaryFormat {
+        self.0.binary_format
+    }
+
+    /// Set how binary values are written
+    ///
+    /// Default is [`BinaryFormat::Hex`]
+    pub fn with_binary_format(mut self, format: BinaryFormat) -> Self {
+        self.0.binary_format = format;
+        self
+    }
+
     /// Create a new `Writer` with specified `JsonFormat` and builder options.
     pub fn build<W, F>(self, writer: W) -> Writer<W, F>
     where
@@ -1950,6 +1990,106 @@ mod tests {
             r#"{"decimal":12.34}
 {}
 {"decimal":56.78}
+"#,
+        );
+    }
+
+    fn write_with(builder: WriterBuilder, batch: &RecordBatch) -> Vec<u8> {
+        let mut writer = builder.build::<_, LineDelimited>(Vec::new());
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        writer.into_inner()
+    }
+
+    #[test]
+    fn test_timestamp_format() {
+        let ts_millis = 1542129070011;
+        let naive = TimestampMillisecondArray::from(vec![Some(ts_millis), None]);
+        let tz =
+            TimestampSecondArray::from(vec![Some(ts_millis / 1000), None]).with_timezone("+01:00");
+        let batch = RecordBatch::try_from_iter([
+            ("naive", Arc::new(naive) as ArrayRef),
+            ("tz", Arc::new(tz) as ArrayRef),
+        ])
+        .unwrap();
+
+        let builder = WriterBuilder::new();
+        assert_eq!(builder.timestamp_format(), TimestampFormat::Iso8601);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"naive":"2018-11-13T17:11:10.011","tz":"2018-11-13T18:11:10+01:00"}
+{}
+"#,
+        );
+
+        let builder = WriterBuilder::new().with_timestamp_format(TimestampFormat::Rfc3339);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"naive":"2018-11-13T17:11:10.011Z","tz":"2018-11-13T18:11:10+01:00"}
+{}
+"#,
+        );
+
+        let builder = WriterBuilder::new()
+            .with_timestamp_format(TimestampFormat::Epoch)
+            .with_explicit_nulls(true);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"naive":1542129070011,"tz":1542129070}
+{"naive":null,"tz":null}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_decimal_format() {
+        let array = Decimal128Array::from(vec![Some(1234), None, Some(-5678)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([("decimal", Arc::new(array) as ArrayRef)]).unwrap();
+
+        let builder = WriterBuilder::new().with_decimal_format(DecimalFormat::String);
+        assert_eq!(builder.decimal_format(), DecimalFormat::String);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"decimal":"12.34"}
+{}
+{"decimal":"-56.78"}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_binary_format() {
+        let values: [Option<&[u8]>; 3] = [Some(b"Ned Flanders"), None, Some(b"\xff\x00")];
+        let binary = BinaryArray::from_iter(values);
+        let fixed = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some(b"ab"), None, Some(b"\xff\x00")].into_iter(),
+            2,
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            ("binary", Arc::new(binary) as ArrayRef),
+            ("fixed", Arc::new(fixed) as ArrayRef),
+        ])
+        .unwrap();
+
+        let builder = WriterBuilder::new();
+        assert_eq!(builder.binary_format(), BinaryFormat::Hex);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"binary":"4e656420466c616e64657273","fixed":"6162"}
+{}
+{"binary":"ff00","fixed":"ff00"}
+"#,
+        );
+
+        let builder = WriterBuilder::new().with_binary_format(BinaryFormat::Base64);
+        assert_json_eq(
+            &write_with(builder, &batch),
+            r#"{"binary":"TmVkIEZsYW5kZXJz","fixed":"YWI="}
+{}
+{"binary":"/wA=","fixed":"/wA="}
 "#,
         );
     }
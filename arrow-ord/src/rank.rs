@@ -15,11 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Provides `rank` function to assign a rank to each value in an array
+//! Provides `rank` and `dense_rank` functions to assign a rank to each value in an array
+//!
+//! For columns that are already sorted, [`rank_sorted`], [`dense_rank_sorted`] and
+//! [`percent_rank_sorted`] compute SQL-style window ranks without re-sorting
 
+use crate::partition::partition;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
-use arrow_array::{downcast_primitive_array, Array, ArrowNativeTypeOp, GenericByteArray};
+use arrow_array::{
+    downcast_primitive_array, Array, ArrayRef, ArrowNativeTypeOp, Float64Array, GenericByteArray,
+    UInt32Array,
+};
 use arrow_buffer::NullBuffer;
 use arrow_schema::{ArrowError, DataType, SortOptions};
 use std::cmp::Ordering;
@@ -39,30 +46,148 @@ pub(crate) fn can_rank(data_type: &DataType) -> bool {
 /// leaving gaps in the overall rank assignment
 ///
 /// ```
-/// # use arrow_array::StringArray;
+/// # use arrow_array::{StringArray, UInt32Array};
 /// # use arrow_ord::rank::rank;
 /// let array = StringArray::from(vec![Some("foo"), None, Some("foo"), None, Some("bar")]);
 /// let ranks = rank(&array, None).unwrap();
-/// assert_eq!(ranks, &[5, 2, 5, 2, 3]);
+/// assert_eq!(ranks, UInt32Array::from(vec![5, 2, 5, 2, 3]));
 /// ```
-pub fn rank(array: &dyn Array, options: Option<SortOptions>) -> Result<Vec<u32>, ArrowError> {
+pub fn rank(array: &dyn Array, options: Option<SortOptions>) -> Result<UInt32Array, ArrowError> {
+    Ok(rank_kernel(array, options, false)?.into())
+}
+
+/// Assigns a dense rank to each value in `array` based on its position in the sorted order
+///
+/// Equal values are assigned the same rank, and ranks are consecutive with no gaps,
+/// i.e. the rank of a value is the number of distinct values ordered before it plus one.
+/// All nulls share a single rank
+///
+/// ```
+/// # use arrow_array::{StringArray, UInt32Array};
+/// # use arrow_ord::rank::dense_rank;
+/// let array = StringArray::from(vec![Some("foo"), None, Some("foo"), None, Some("bar")]);
+/// let ranks = dense_rank(&array, None).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![3, 1, 3, 1, 2]));
+/// ```
+pub fn dense_rank(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+) -> Result<UInt32Array, ArrowError> {
+    Ok(rank_kernel(array, options, true)?.into())
+}
+
+pub(crate) fn rank_kernel(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    dense: bool,
+) -> Result<Vec<u32>, ArrowError> {
     let options = options.unwrap_or_default();
     let ranks = downcast_primitive_array! {
-        array => primitive_rank(array.values(), array.nulls(), options),
-        DataType::Utf8 => bytes_rank(array.as_bytes::<Utf8Type>(), options),
-        DataType::LargeUtf8 => bytes_rank(array.as_bytes::<LargeUtf8Type>(), options),
-        DataType::Binary => bytes_rank(array.as_bytes::<BinaryType>(), options),
-        DataType::LargeBinary => bytes_rank(array.as_bytes::<LargeBinaryType>(), options),
+        array => primitive_rank(array.values(), array.nulls(), options, dense),
+        DataType::Utf8 => bytes_rank(array.as_bytes::<Utf8Type>(), options, dense),
+        DataType::LargeUtf8 => bytes_rank(array.as_bytes::<LargeUtf8Type>(), options, dense),
+        DataType::Binary => bytes_rank(array.as_bytes::<BinaryType>(), options, dense),
+        DataType::LargeBinary => bytes_rank(array.as_bytes::<LargeBinaryType>(), options, dense),
         d => return Err(ArrowError::ComputeError(format!("{d:?} not supported in rank")))
     };
     Ok(ranks)
 }
 
+/// Computes the SQL `RANK` of each row of lexicographically sorted `columns`
+///
+/// Rows with equal values across all `columns` are peers and are assigned the rank of
+/// the first row in their group, i.e. one plus the number of rows preceding the group.
+/// This leaves gaps after ties. Nulls compare equal to each other.
+///
+/// Unlike [`rank`], this does not sort the input, it only detects the runs of equal
+/// values, and is therefore intended for inputs that are already sorted, e.g. the
+/// partitions of a window function
+///
+/// Returns an error if no columns are specified or the columns do not have the same
+/// number of rows
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow_ord::rank::rank_sorted;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 2, 3, 3, 3, 4]));
+/// let ranks = rank_sorted(&[a]).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![1, 1, 3, 4, 4, 4, 7]));
+/// ```
+pub fn rank_sorted(columns: &[ArrayRef]) -> Result<UInt32Array, ArrowError> {
+    let len = sorted_len(columns)?;
+    let mut out = Vec::with_capacity(len);
+    for range in partition(columns)?.ranges() {
+        let rank = (range.start + 1) as u32;
+        out.extend(std::iter::repeat(rank).take(range.len()));
+    }
+    Ok(out.into())
+}
+
+/// Computes the SQL `DENSE_RANK` of each row of lexicographically sorted `columns`
+///
+/// Peer rows are assigned the same rank, and ranks are consecutive with no gaps.
+/// See [`rank_sorted`] for the requirements on `columns`
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow_ord::rank::dense_rank_sorted;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 2, 3, 3, 3, 4]));
+/// let ranks = dense_rank_sorted(&[a]).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![1, 1, 2, 3, 3, 3, 4]));
+/// ```
+pub fn dense_rank_sorted(columns: &[ArrayRef]) -> Result<UInt32Array, ArrowError> {
+    let len = sorted_len(columns)?;
+    let mut out = Vec::with_capacity(len);
+    for (idx, range) in partition(columns)?.ranges().into_iter().enumerate() {
+        let rank = (idx + 1) as u32;
+        out.extend(std::iter::repeat(rank).take(range.len()));
+    }
+    Ok(out.into())
+}
+
+/// Computes the SQL `PERCENT_RANK` of each row of lexicographically sorted `columns`
+///
+/// This is `(rank - 1) / (num_rows - 1)` where `rank` is as computed by [`rank_sorted`],
+/// or `0` if there is only a single row. See [`rank_sorted`] for the requirements on `columns`
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Float64Array, Int32Array};
+/// # use arrow_ord::rank::percent_rank_sorted;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 2, 3, 4]));
+/// let ranks = percent_rank_sorted(&[a]).unwrap();
+/// assert_eq!(ranks, Float64Array::from(vec![0.0, 0.0, 0.5, 0.75, 1.0]));
+/// ```
+pub fn percent_rank_sorted(columns: &[ArrayRef]) -> Result<Float64Array, ArrowError> {
+    let len = sorted_len(columns)?;
+    let denominator = len.saturating_sub(1).max(1) as f64;
+    let mut out = Vec::with_capacity(len);
+    for range in partition(columns)?.ranges() {
+        let rank = range.start as f64 / denominator;
+        out.extend(std::iter::repeat(rank).take(range.len()));
+    }
+    Ok(out.into())
+}
+
+/// Returns the number of rows in `columns`, checking the rank is representable as a `u32`
+fn sorted_len(columns: &[ArrayRef]) -> Result<usize, ArrowError> {
+    let len = columns.first().map(|c| c.len()).unwrap_or_default();
+    if len > u32::MAX as usize {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot rank {len} rows, exceeds u32::MAX"
+        )));
+    }
+    Ok(len)
+}
+
 #[inline(never)]
 fn primitive_rank<T: ArrowNativeTypeOp>(
     values: &[T],
     nulls: Option<&NullBuffer>,
     options: SortOptions,
+    dense: bool,
 ) -> Vec<u32> {
     let len: u32 = values.len().try_into().unwrap();
     let to_sort = match nulls.filter(|n| n.null_count() > 0) {
@@ -72,11 +197,18 @@ fn primitive_rank<T: ArrowNativeTypeOp>(
             .collect(),
         None => values.iter().copied().zip(0..len).collect(),
     };
-    rank_impl(values.len(), to_sort, options, T::compare, T::is_eq)
+    match dense {
+        true => dense_rank_impl(values.len(), to_sort, options, T::compare, T::is_eq),
+        false => rank_impl(values.len(), to_sort, options, T::compare, T::is_eq),
+    }
 }
 
 #[inline(never)]
-fn bytes_rank<T: ByteArrayType>(array: &GenericByteArray<T>, options: SortOptions) -> Vec<u32> {
+fn bytes_rank<T: ByteArrayType>(
+    array: &GenericByteArray<T>,
+    options: SortOptions,
+    dense: bool,
+) -> Vec<u32> {
     let to_sort: Vec<(&[u8], u32)> = match array.nulls().filter(|n| n.null_count() > 0) {
         Some(n) => n
             .valid_indices()
@@ -86,7 +218,22 @@ fn bytes_rank<T: ByteArrayType>(array: &GenericByteArray<T>, options: SortOption
             .map(|idx| (array.value(idx).as_ref(), idx as u32))
             .collect(),
     };
-    rank_impl(array.len(), to_sort, options, Ord::cmp, PartialEq::eq)
+    match dense {
+        true => dense_rank_impl(array.len(), to_sort, options, Ord::cmp, PartialEq::eq),
+        false => rank_impl(array.len(), to_sort, options, Ord::cmp, PartialEq::eq),
+    }
+}
+
+fn sort_valid<T, C>(valid: &mut [(T, u32)], options: SortOptions, compare: C)
+where
+    T: Copy,
+    C: Fn(T, T) -> Ordering,
+{
+    // We can use an unstable sort as we combine equal values later
+    valid.sort_unstable_by(|a, b| compare(a.0, b.0));
+    if options.descending {
+        valid.reverse();
+    }
 }
 
 fn rank_impl<T, C, E>(
@@ -101,11 +248,7 @@ where
     C: Fn(T, T) -> Ordering,
     E: Fn(T, T) -> bool,
 {
-    // We can use an unstable sort as we combine equal values later
-    valid.sort_unstable_by(|a, b| compare(a.0, b.0));
-    if options.descending {
-        valid.reverse();
-    }
+    sort_valid(&mut valid, options, compare);
 
     let (mut valid_rank, null_rank) = match options.nulls_first {
         true => (len as u32, (len - valid.len()) as u32),
@@ -135,10 +278,52 @@ where
     out
 }
 
+fn dense_rank_impl<T, C, E>(
+    len: usize,
+    mut valid: Vec<(T, u32)>,
+    options: SortOptions,
+    compare: C,
+    eq: E,
+) -> Vec<u32>
+where
+    T: Copy,
+    C: Fn(T, T) -> Ordering,
+    E: Fn(T, T) -> bool,
+{
+    sort_valid(&mut valid, options, compare);
+
+    let has_nulls = valid.len() != len;
+    let mut rank = (has_nulls && options.nulls_first) as u32;
+
+    // Valid ranks start at 1, so 0 identifies the nulls
+    let mut out: Vec<_> = vec![0; len];
+    let mut prev = None;
+    for (v, idx) in valid {
+        if !prev.is_some_and(|p| eq(p, v)) {
+            rank += 1;
+        }
+        out[idx as usize] = rank;
+        prev = Some(v);
+    }
+
+    if has_nulls {
+        let null_rank = match options.nulls_first {
+            true => 1,
+            false => rank + 1,
+        };
+        out.iter_mut()
+            .filter(|r| **r == 0)
+            .for_each(|r| *r = null_rank);
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow_array::*;
+    use std::sync::Arc;
 
     #[test]
     fn test_primitive() {
@@ -159,22 +344,22 @@ mod tests {
 
         let a = Int32Array::from(vec![Some(1), Some(1), None, Some(3), Some(3), Some(4)]);
         let res = rank(&a, None).unwrap();
-        assert_eq!(res, &[3, 3, 1, 5, 5, 6]);
+        assert_eq!(res, UInt32Array::from(vec![3, 3, 1, 5, 5, 6]));
 
         let res = rank(&a, Some(descending)).unwrap();
-        assert_eq!(res, &[6, 6, 1, 4, 4, 2]);
+        assert_eq!(res, UInt32Array::from(vec![6, 6, 1, 4, 4, 2]));
 
         let res = rank(&a, Some(nulls_last)).unwrap();
-        assert_eq!(res, &[2, 2, 6, 4, 4, 5]);
+        assert_eq!(res, UInt32Array::from(vec![2, 2, 6, 4, 4, 5]));
 
         let res = rank(&a, Some(nulls_last_descending)).unwrap();
-        assert_eq!(res, &[5, 5, 6, 3, 3, 1]);
+        assert_eq!(res, UInt32Array::from(vec![5, 5, 6, 3, 3, 1]));
 
         // Test with non-zero null values
         let nulls = NullBuffer::from(vec![true, true, false, true, false, false]);
         let a = Int32Array::new(vec![1, 4, 3, 4, 5, 5].into(), Some(nulls));
         let res = rank(&a, None).unwrap();
-        assert_eq!(res, &[4, 6, 3, 6, 3, 3]);
+        assert_eq!(res, UInt32Array::from(vec![4, 6, 3, 6, 3, 3]));
     }
 
     #[test]
@@ -182,19 +367,92 @@ mod tests {
         let v = vec!["foo", "fo", "bar", "bar"];
         let values = StringArray::from(v.clone());
         let res = rank(&values, None).unwrap();
-        assert_eq!(res, &[4, 3, 2, 2]);
+        assert_eq!(res, UInt32Array::from(vec![4, 3, 2, 2]));
 
         let values = LargeStringArray::from(v.clone());
         let res = rank(&values, None).unwrap();
-        assert_eq!(res, &[4, 3, 2, 2]);
+        assert_eq!(res, UInt32Array::from(vec![4, 3, 2, 2]));
 
         let v: Vec<&[u8]> = vec![&[1, 2], &[0], &[1, 2, 3], &[1, 2]];
         let values = LargeBinaryArray::from(v.clone());
         let res = rank(&values, None).unwrap();
-        assert_eq!(res, &[3, 1, 4, 3]);
+        assert_eq!(res, UInt32Array::from(vec![3, 1, 4, 3]));
 
         let values = BinaryArray::from(v);
         let res = rank(&values, None).unwrap();
-        assert_eq!(res, &[3, 1, 4, 3]);
+        assert_eq!(res, UInt32Array::from(vec![3, 1, 4, 3]));
+    }
+
+    #[test]
+    fn test_dense_rank() {
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+
+        let nulls_last = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+
+        let a = Int32Array::from(vec![Some(1), Some(1), None, Some(3), Some(3), Some(4)]);
+        let res = dense_rank(&a, None).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![2, 2, 1, 3, 3, 4]));
+
+        let res = dense_rank(&a, Some(descending)).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![4, 4, 1, 3, 3, 2]));
+
+        let res = dense_rank(&a, Some(nulls_last)).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![1, 1, 4, 2, 2, 3]));
+
+        let a = Int32Array::from(vec![5, 2, 5, 7]);
+        let res = dense_rank(&a, None).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![2, 1, 2, 3]));
+
+        let values = StringArray::from(vec!["foo", "fo", "bar", "bar"]);
+        let res = dense_rank(&values, None).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![3, 2, 1, 1]));
+
+        let a = Int32Array::from(vec![None, None]);
+        let res = dense_rank(&a, None).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_sorted() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            None,
+            None,
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(2),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "a", "b", "b", "a"]));
+
+        let res = rank_sorted(std::slice::from_ref(&a)).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![1, 1, 3, 3, 3, 6]));
+
+        let res = rank_sorted(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![1, 1, 3, 4, 4, 6]));
+
+        let res = dense_rank_sorted(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(res, UInt32Array::from(vec![1, 1, 2, 3, 3, 4]));
+
+        let res = percent_rank_sorted(&[a, b]).unwrap();
+        assert_eq!(res, Float64Array::from(vec![0.0, 0.0, 0.4, 0.6, 0.6, 1.0]));
+
+        let single: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let res = percent_rank_sorted(&[single]).unwrap();
+        assert_eq!(res, Float64Array::from(vec![0.0]));
+
+        let empty: ArrayRef = Arc::new(Int32Array::from(Vec::<i32>::new()));
+        assert!(rank_sorted(&[empty]).unwrap().is_empty());
+
+        let err = rank_sorted(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Partition requires at least one column"
+        );
     }
 }
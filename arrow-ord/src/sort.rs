@@ -30,7 +30,7 @@ use arrow_select::take::take;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
-use crate::rank::{can_rank, rank};
+use crate::rank::{can_rank, rank_kernel};
 pub use arrow_schema::SortOptions;
 
 /// Sort the `ArrayRef` using `SortOptions`.
@@ -437,7 +437,7 @@ fn child_rank(values: &dyn Array, options: SortOptions) -> Result<Vec<u32>, Arro
         descending: false,
         nulls_first: options.nulls_first != options.descending,
     });
-    rank(values, value_options)
+    rank_kernel(values, value_options, false)
 }
 
 // Sort run array and return sorted run array.
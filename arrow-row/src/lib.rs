@@ -127,7 +127,9 @@
 
 #![warn(missing_docs)]
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow_array::cast::*;
@@ -143,6 +145,7 @@ use crate::variable::{decode_binary, decode_string};
 
 mod fixed;
 mod list;
+pub mod topk;
 mod variable;
 
 /// Converts [`ArrayRef`] columns into a [row-oriented](self) format.
@@ -362,6 +365,8 @@ pub struct RowConverter {
     fields: Arc<[SortField]>,
     /// State for codecs
     codecs: Vec<Codec>,
+    /// The maximum size in bytes of [`Rows`] produced by this converter
+    memory_limit: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -512,6 +517,12 @@ impl SortField {
     }
 }
 
+macro_rules! dictionary_helper {
+    ($t:ty, $keys:ident, $values:ident) => {
+        Arc::new(build_dictionary::<$t>($keys, $values)?) as ArrayRef
+    };
+}
+
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Result<Self, ArrowError> {
@@ -525,9 +536,29 @@ impl RowConverter {
         Ok(Self {
             fields: fields.into(),
             codecs,
+            memory_limit: None,
         })
     }
 
+    /// Limit the size of the [`Rows`] produced by this [`RowConverter`] to `limit` bytes,
+    /// as reported by [`Rows::size`]
+    ///
+    /// [`Self::convert_columns`] and [`Self::append`] will return [`ArrowError::MemoryError`]
+    /// instead of growing [`Rows`] beyond this limit, whilst
+    /// [`Self::convert_columns_chunked`] will split the output into multiple [`Rows`]
+    ///
+    /// Note: this does not account for the memory used by intermediate state, such as
+    /// the encoded values of dictionaries or nested types
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.memory_limit = Some(limit);
+        self
+    }
+
+    /// Returns the memory limit configured by [`Self::with_memory_limit`]
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
     /// Check if the given fields are supported by the row format.
     pub fn supports_fields(fields: &[SortField]) -> bool {
         fields.iter().all(|x| Self::supports_datatype(&x.data_type))
@@ -594,32 +625,26 @@ impl RowConverter {
             "rows were not produced by this RowConverter"
         );
 
-        if columns.len() != self.fields.len() {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
-                self.fields.len(),
-                columns.len()
-            )));
-        }
+        let encoders = self.encoders(columns)?;
+        let lengths = row_lengths(columns, &encoders);
+        let encoders: Vec<_> = encoders.iter().collect();
+        self.append_encoded(rows, columns, &encoders, &lengths)
+    }
 
-        let encoders = columns
-            .iter()
-            .zip(&self.codecs)
-            .zip(self.fields.iter())
-            .map(|((column, codec), field)| {
-                if !column.data_type().equals_datatype(&field.data_type) {
-                    return Err(ArrowError::InvalidArgumentError(format!(
-                        "RowConverter column schema mismatch, expected {} got {}",
-                        field.data_type,
-                        column.data_type()
-                    )));
-                }
-                codec.encoder(column.as_ref())
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+    /// Appends `columns` to `rows` using `encoders`, where `lengths` are the
+    /// encoded lengths of the rows as computed by [`row_lengths`]
+    fn append_encoded(
+        &self,
+        rows: &mut Rows,
+        columns: &[ArrayRef],
+        encoders: &[&Encoder<'_>],
+        lengths: &[usize],
+    ) -> Result<(), ArrowError> {
+        let num_rows = rows.num_rows() + lengths.len();
+        let data_len = rows.buffer.len() + lengths.iter().sum::<usize>();
+        self.check_memory_limit(rows_size(num_rows, data_len))?;
 
         let write_offset = rows.num_rows();
-        let lengths = row_lengths(columns, &encoders);
 
         // We initialize the offsets shifted down by one row index.
         //
@@ -638,7 +663,7 @@ impl RowConverter {
         // as identifying the offsets of the written rows
         rows.offsets.reserve(lengths.len());
         let mut cur_offset = rows.offsets[write_offset];
-        for l in lengths {
+        for l in lengths.iter().copied() {
             rows.offsets.push(cur_offset);
             cur_offset = cur_offset.checked_add(l).expect("overflow");
         }
@@ -655,7 +680,7 @@ impl RowConverter {
                 &mut rows.offsets[write_offset..],
                 column.as_ref(),
                 field.options,
-                &encoder,
+                encoder,
             )
         }
 
@@ -669,6 +694,163 @@ impl RowConverter {
         Ok(())
     }
 
+    /// Validates `columns` against the schema of this [`RowConverter`] and returns
+    /// the [`Encoder`] for each column
+    fn encoders<'a>(&'a self, columns: &'a [ArrayRef]) -> Result<Vec<Encoder<'a>>, ArrowError> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+
+        columns
+            .iter()
+            .zip(&self.codecs)
+            .zip(self.fields.iter())
+            .map(|((column, codec), field)| {
+                if !column.data_type().equals_datatype(&field.data_type) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "RowConverter column schema mismatch, expected {} got {}",
+                        field.data_type,
+                        column.data_type()
+                    )));
+                }
+                codec.encoder(column.as_ref())
+            })
+            .collect()
+    }
+
+    /// Returns an error if [`Rows`] of `size` bytes would exceed the configured memory limit
+    fn check_memory_limit(&self, size: usize) -> Result<(), ArrowError> {
+        match self.memory_limit {
+            Some(limit) if size > limit => Err(ArrowError::MemoryError(format!(
+                "Rows of {size} bytes would exceed the RowConverter memory limit of {limit} bytes"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the size in bytes, as reported by [`Rows::size`], of the [`Rows`] that
+    /// [`Self::convert_columns`] would produce for `columns`
+    ///
+    /// This computes the exact encoded length of each row without encoding the rows,
+    /// allowing callers such as sort spilling to plan their memory usage before converting.
+    /// Dictionary and nested columns still require encoding their values or children
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let array: ArrayRef = Arc::new(StringArray::from(vec!["hello", "world"]));
+    ///
+    /// let estimate = converter.encoded_size(&[array.clone()]).unwrap();
+    /// let rows = converter.convert_columns(&[array]).unwrap();
+    /// assert_eq!(estimate, rows.size());
+    /// ```
+    pub fn encoded_size(&self, columns: &[ArrayRef]) -> Result<usize, ArrowError> {
+        let encoders = self.encoders(columns)?;
+        let lengths = row_lengths(columns, &encoders);
+        Ok(rows_size(lengths.len(), lengths.iter().sum()))
+    }
+
+    /// Convert [`ArrayRef`] columns into one or more [`Rows`], each within the memory
+    /// limit configured by [`Self::with_memory_limit`]
+    ///
+    /// Consecutive rows are assigned to the same [`Rows`] until adding another row would
+    /// exceed the limit. If no limit is configured, this returns a single [`Rows`]
+    ///
+    /// Returns [`ArrowError::MemoryError`] if a single row exceeds the limit
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int64Array};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new(vec![SortField::new(DataType::Int64)]).unwrap();
+    /// let array: ArrayRef = Arc::new(Int64Array::from_iter_values(0..1000));
+    /// let size = converter.encoded_size(&[array.clone()]).unwrap();
+    ///
+    /// let converter = converter.with_memory_limit(size / 2);
+    /// let chunks = converter.convert_columns_chunked(&[array]).unwrap();
+    /// assert_eq!(chunks.len(), 3);
+    /// assert!(chunks.iter().all(|rows| rows.size() <= size / 2));
+    /// assert_eq!(chunks.iter().map(|rows| rows.num_rows()).sum::<usize>(), 1000);
+    /// ```
+    pub fn convert_columns_chunked(&self, columns: &[ArrayRef]) -> Result<Vec<Rows>, ArrowError> {
+        let limit = match self.memory_limit {
+            Some(limit) => limit,
+            None => return Ok(vec![self.convert_columns(columns)?]),
+        };
+
+        let encoders = self.encoders(columns)?;
+        let lengths = row_lengths(columns, &encoders);
+
+        let mut chunks = vec![];
+        let mut start = 0;
+        let mut data_len = 0;
+        for (idx, len) in lengths.iter().copied().enumerate() {
+            if rows_size(idx + 1 - start, data_len + len) <= limit {
+                data_len += len;
+                continue;
+            }
+            if idx == start || rows_size(1, len) > limit {
+                return Err(ArrowError::MemoryError(format!(
+                    "Row {idx} of {len} bytes exceeds the RowConverter memory limit of {limit} bytes"
+                )));
+            }
+            chunks.push(self.convert_chunk(columns, &encoders, &lengths, start..idx)?);
+            start = idx;
+            data_len = len;
+        }
+        if start < lengths.len() || chunks.is_empty() {
+            chunks.push(self.convert_chunk(columns, &encoders, &lengths, start..lengths.len())?);
+        }
+        Ok(chunks)
+    }
+
+    /// Converts the rows of `columns` within `range`, given the `encoders` and
+    /// row `lengths` of `columns`
+    fn convert_chunk(
+        &self,
+        columns: &[ArrayRef],
+        encoders: &[Encoder<'_>],
+        lengths: &[usize],
+        range: Range<usize>,
+    ) -> Result<Rows, ArrowError> {
+        let sliced: Vec<_> = columns
+            .iter()
+            .map(|c| c.slice(range.start, range.len()))
+            .collect();
+
+        // Slicing a dictionary or list preserves its values, so their encoding is reused,
+        // whereas slicing a struct slices its children, which must be encoded again
+        let struct_encoders = sliced
+            .iter()
+            .zip(&self.codecs)
+            .zip(encoders)
+            .map(|((column, codec), encoder)| match encoder {
+                Encoder::Struct(_, _) => codec.encoder(column.as_ref()).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+        let encoders: Vec<_> = struct_encoders
+            .iter()
+            .zip(encoders)
+            .map(|(s, e)| s.as_ref().unwrap_or(e))
+            .collect();
+
+        let lengths = &lengths[range.clone()];
+        let mut rows = self.empty_rows(range.len(), lengths.iter().sum());
+        self.append_encoded(&mut rows, &sliced, &encoders, lengths)?;
+        Ok(rows)
+    }
+
     /// Convert [`Rows`] columns into [`ArrayRef`]
     ///
     /// # Panics
@@ -697,6 +879,128 @@ impl RowConverter {
         unsafe { self.convert_raw(&mut rows, validate_utf8) }
     }
 
+    /// Convert [`Rows`] columns into [`ArrayRef`], rebuilding [`DictionaryArray`] for
+    /// dictionary encoded columns using the provided dictionary values
+    ///
+    /// By default [`Self::convert_rows`] decodes dictionary encoded columns to their
+    /// plain values. For each column `dictionaries` can instead provide the dictionary
+    /// values, typically those of the original [`DictionaryArray`], in which case the
+    /// column is decoded as a [`DictionaryArray`] with keys indexing these values.
+    ///
+    /// `dictionaries` must contain one entry per column, with `None` for columns that
+    /// should be decoded as by [`Self::convert_rows`]. Dictionaries nested within other
+    /// types are always decoded to their plain values
+    ///
+    /// Returns an error if a dictionary is provided for a column that is not dictionary
+    /// encoded, or if a row contains a value not present in the provided dictionary
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    /// let converter = RowConverter::new(vec![SortField::new(data_type)]).unwrap();
+    ///
+    /// let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+    /// let array = DictionaryArray::new(Int32Array::from(vec![2, 0, 2]), values.clone());
+    /// let rows = converter.convert_columns(&[Arc::new(array)]).unwrap();
+    ///
+    /// let back = converter
+    ///     .convert_rows_with_dictionaries(&rows, &[Some(values)])
+    ///     .unwrap();
+    /// let back = back[0].as_dictionary::<Int32Type>();
+    /// assert_eq!(back.keys(), &Int32Array::from(vec![2, 0, 2]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    pub fn convert_rows_with_dictionaries<'a, I>(
+        &self,
+        rows: I,
+        dictionaries: &[Option<ArrayRef>],
+    ) -> Result<Vec<ArrayRef>, ArrowError>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        if dictionaries.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect number of dictionaries provided to RowConverter, expected {} got {}",
+                self.fields.len(),
+                dictionaries.len()
+            )));
+        }
+
+        for (idx, (field, dictionary)) in self.fields.iter().zip(dictionaries).enumerate() {
+            let values = match dictionary {
+                Some(values) => values,
+                None => continue,
+            };
+            match &field.data_type {
+                DataType::Dictionary(_, v) if v.as_ref() == values.data_type() => {}
+                DataType::Dictionary(_, v) => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Dictionary for column {idx} has type {}, expected {v}",
+                        values.data_type()
+                    )))
+                }
+                d => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Dictionary provided for column {idx} of non-dictionary type {d}"
+                    )))
+                }
+            }
+        }
+
+        let mut validate_utf8 = false;
+        let mut rows: Vec<_> = rows
+            .into_iter()
+            .map(|row| {
+                assert!(
+                    Arc::ptr_eq(&row.config.fields, &self.fields),
+                    "rows were not produced by this RowConverter"
+                );
+                validate_utf8 |= row.config.validate_utf8;
+                row.data
+            })
+            .collect();
+
+        let columns = self.fields.iter().zip(&self.codecs).zip(dictionaries);
+        let mut out = Vec::with_capacity(self.fields.len());
+        for (idx, ((field, codec), dictionary)) in columns.enumerate() {
+            // SAFETY
+            // We have validated that the rows came from this [`RowConverter`]
+            // and therefore must be valid
+            match (codec, dictionary) {
+                (Codec::Dictionary(converter, null), Some(values)) => {
+                    // Decoding advances each row past the encoded value of this column
+                    let encoded = rows.clone();
+                    unsafe { decode_column(field, &mut rows, codec, validate_utf8)? };
+                    let encoded = encoded
+                        .iter()
+                        .zip(&rows)
+                        .map(|(before, after)| &before[..before.len() - after.len()]);
+
+                    let keys = dictionary_keys(converter, null.row(), values, encoded, idx)?;
+                    let key_type = match &field.data_type {
+                        DataType::Dictionary(k, _) => k.as_ref(),
+                        _ => unreachable!(),
+                    };
+                    out.push(downcast_integer! {
+                        key_type => (dictionary_helper, keys, values),
+                        _ => unreachable!("illegal dictionary key type {key_type}")
+                    });
+                }
+                _ => out.push(unsafe { decode_column(field, &mut rows, codec, validate_utf8)? }),
+            }
+        }
+        Ok(out)
+    }
+
     /// Returns an empty [`Rows`] with capacity for `row_capacity` rows with
     /// a total length of `data_capacity`
     ///
@@ -1168,6 +1472,12 @@ fn null_sentinel(options: SortOptions) -> u8 {
     }
 }
 
+/// Returns the size in bytes, as reported by [`Rows::size`], of [`Rows`] containing
+/// `num_rows` rows with a total length of `data_len`
+fn rows_size(num_rows: usize, data_len: usize) -> usize {
+    std::mem::size_of::<Rows>() + data_len + (num_rows + 1) * std::mem::size_of::<usize>()
+}
+
 /// Computes the length of each encoded [`Rows`] and returns an empty [`Rows`]
 fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
     use fixed::FixedLengthEncoding;
@@ -1364,6 +1674,52 @@ pub fn encode_dictionary_values<K: ArrowDictionaryKeyType>(
     }
 }
 
+/// Returns the index within `values` of each of the `encoded` dictionary values of
+/// column `column`, or `None` if the encoded value is `null`
+fn dictionary_keys<'a>(
+    converter: &RowConverter,
+    null: Row<'_>,
+    values: &ArrayRef,
+    encoded: impl Iterator<Item = &'a [u8]>,
+    column: usize,
+) -> Result<Vec<Option<usize>>, ArrowError> {
+    let values_rows = converter.convert_columns(&[Arc::clone(values)])?;
+    let mut lookup = HashMap::with_capacity(values_rows.num_rows());
+    for (idx, row) in values_rows.iter().enumerate() {
+        lookup.entry(row.data).or_insert(idx);
+    }
+
+    encoded
+        .enumerate()
+        .map(|(row_idx, value)| {
+            if value == null.data {
+                return Ok(None);
+            }
+            match lookup.get(value) {
+                Some(key) => Ok(Some(*key)),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "Row {row_idx} contains a value of column {column} not present in the provided dictionary"
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Builds a [`DictionaryArray`] from `keys` indexing `values`
+fn build_dictionary<K: ArrowDictionaryKeyType>(
+    keys: Vec<Option<usize>>,
+    values: &ArrayRef,
+) -> Result<DictionaryArray<K>, ArrowError> {
+    let keys = keys
+        .into_iter()
+        .map(|k| {
+            k.map(|k| K::Native::from_usize(k).ok_or(ArrowError::DictionaryKeyOverflowError))
+                .transpose()
+        })
+        .collect::<Result<PrimitiveArray<K>, _>>()?;
+    DictionaryArray::try_new(keys, Arc::clone(values))
+}
+
 macro_rules! decode_primitive_helper {
     ($t:ty, $rows:ident, $data_type:ident, $options:ident) => {
         Arc::new(decode_primitive::<$t>($rows, $data_type, $options))
@@ -2495,6 +2851,197 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_rows_with_dictionaries() {
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let converter = RowConverter::new(vec![
+            SortField::new(DataType::Int32),
+            SortField::new_with_options(dict_type, options),
+        ])
+        .unwrap();
+
+        let values: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), Some("y"), None]));
+        let keys = Int8Array::from(vec![Some(1), None, Some(0), Some(2), Some(1)]);
+        let dict = DictionaryArray::new(keys, values.clone());
+        let ints = Int32Array::from(vec![1, 2, 3, 4, 5]);
+        let columns = [Arc::new(ints) as ArrayRef, Arc::new(dict) as ArrayRef];
+        let rows = converter.convert_columns(&columns).unwrap();
+
+        // Without dictionaries the values are decoded as by convert_rows
+        let back = converter
+            .convert_rows_with_dictionaries(&rows, &[None, None])
+            .unwrap();
+        assert_eq!(back, converter.convert_rows(&rows).unwrap());
+
+        let back = converter
+            .convert_rows_with_dictionaries(&rows, &[None, Some(values.clone())])
+            .unwrap();
+        assert_eq!(&back[0], &columns[0]);
+        let dict = back[1].as_dictionary::<Int8Type>();
+        assert!(Arc::ptr_eq(dict.values(), &values));
+        // The null value of the dictionary is decoded as a null key
+        let expected = Int8Array::from(vec![Some(1), None, Some(0), None, Some(1)]);
+        assert_eq!(dict.keys(), &expected);
+
+        // Values not present in the dictionary are an error
+        let other: ArrayRef = Arc::new(StringArray::from(vec!["y"]));
+        let err = converter
+            .convert_rows_with_dictionaries(&rows, &[None, Some(other)])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Row 2 contains a value of column 1 not present in the provided dictionary"
+        );
+
+        let err = converter
+            .convert_rows_with_dictionaries(&rows, &[Some(values.clone()), None])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Dictionary provided for column 0 of non-dictionary type Int32"
+        );
+
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let err = converter
+            .convert_rows_with_dictionaries(&rows, &[None, Some(ints)])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Dictionary for column 1 has type Int32, expected Utf8"
+        );
+
+        let err = converter
+            .convert_rows_with_dictionaries(&rows, &[None])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect number of dictionaries provided to RowConverter, expected 2 got 1"
+        );
+    }
+
+    #[test]
+    fn test_encoded_size() {
+        let fields = vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(DataType::Dictionary(
+                Box::new(DataType::Int32),
+                Box::new(DataType::Utf8),
+            )),
+            SortField::new(DataType::List(Arc::new(Field::new_list_field(
+                DataType::Int32,
+                true,
+            )))),
+        ];
+        let converter = RowConverter::new(fields).unwrap();
+        let columns = [
+            Arc::new(generate_strings::<i32>(100, 0.8)) as ArrayRef,
+            Arc::new(generate_dictionary::<Int32Type>(
+                Arc::new(generate_strings::<i32>(10, 0.5)),
+                100,
+                0.8,
+            )) as ArrayRef,
+            Arc::new(generate_list(100, 0.8, |len| {
+                Arc::new(generate_primitive_array::<Int32Type>(len, 0.8))
+            })) as ArrayRef,
+        ];
+
+        let estimate = converter.encoded_size(&columns).unwrap();
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert_eq!(estimate, rows.size());
+
+        let err = converter.encoded_size(&columns[..1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect number of arrays provided to RowConverter, expected 3 got 1"
+        );
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+        let strings = StringArray::from_iter_values((0..100).map(|i| "a".repeat(i % 10)));
+        let columns = [Arc::new(strings) as ArrayRef];
+        let size = converter.encoded_size(&columns).unwrap();
+
+        // Without a limit a single chunk is produced
+        let chunks = converter.convert_columns_chunked(&columns).unwrap();
+        assert_eq!(chunks.len(), 1);
+
+        let converter = converter.with_memory_limit(size / 4);
+        assert_eq!(converter.memory_limit(), Some(size / 4));
+
+        let err = converter.convert_columns(&columns).unwrap_err();
+        assert!(matches!(err, ArrowError::MemoryError(_)), "{err}");
+
+        let chunks = converter.convert_columns_chunked(&columns).unwrap();
+        assert!(chunks.len() >= 4);
+        assert!(chunks.iter().all(|rows| rows.size() <= size / 4));
+        let back: Vec<_> = chunks
+            .iter()
+            .flat_map(|rows| rows.iter())
+            .map(|row| row.owned())
+            .collect();
+        let expected = RowConverter::new(vec![SortField::new(DataType::Utf8)])
+            .unwrap()
+            .convert_columns(&columns)
+            .unwrap();
+        assert!(back.iter().map(|r| r.row()).eq(expected.iter()));
+
+        // Appending beyond the limit fails without modifying the rows
+        let mut rows = converter.empty_rows(0, 0);
+        converter
+            .append(&mut rows, &[columns[0].slice(0, 10)])
+            .unwrap();
+        let err = converter.append(&mut rows, &columns).unwrap_err();
+        assert!(matches!(err, ArrowError::MemoryError(_)), "{err}");
+        assert_eq!(rows.num_rows(), 10);
+
+        // A single row larger than the limit cannot be chunked
+        let converter = converter.with_memory_limit(std::mem::size_of::<Rows>() + 20);
+        let err = converter.convert_columns_chunked(&columns).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Memory error: Row 1 of 10 bytes exceeds the RowConverter memory limit of {} bytes",
+                std::mem::size_of::<Rows>() + 20
+            )
+        );
+    }
+
+    #[test]
+    fn test_convert_columns_chunked_nested() {
+        let columns = [
+            Arc::new(generate_dictionary::<Int32Type>(
+                Arc::new(generate_strings::<i32>(10, 0.5)),
+                100,
+                0.8,
+            )) as ArrayRef,
+            Arc::new(generate_list(100, 0.8, |len| {
+                Arc::new(generate_primitive_array::<Int32Type>(len, 0.8))
+            })) as ArrayRef,
+            Arc::new(generate_struct(100, 0.8)) as ArrayRef,
+        ];
+        let fields = columns
+            .iter()
+            .map(|c| SortField::new(c.data_type().clone()))
+            .collect();
+        let converter = RowConverter::new(fields).unwrap();
+        let expected = converter.convert_columns(&columns).unwrap();
+
+        let converter = converter.with_memory_limit(expected.size() / 5);
+        let chunks = converter.convert_columns_chunked(&columns).unwrap();
+        assert!(chunks.len() >= 5);
+
+        let actual = chunks.iter().flat_map(|rows| rows.iter());
+        assert!(actual
+            .map(|r| r.owned())
+            .eq(expected.iter().map(|r| r.owned())));
+    }
+
     #[test]
     fn test_append_codec_dictionary_binary() {
         use DataType::*;
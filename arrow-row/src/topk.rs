@@ -0,0 +1,419 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Select the first `k` rows of one or more [`RecordBatch`] in sorted order
+
+use std::collections::BinaryHeap;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, SchemaRef, SortOptions};
+use arrow_select::interleave::interleave_record_batch;
+
+use crate::{OwnedRow, RowConverter, SortField};
+
+/// The number of input batches retained by [`TopK`] before they are compacted
+const MAX_RETAINED_BATCHES: usize = 16;
+
+/// The maximum number of rows the [`TopK`] heap is initially allocated for, as `k`
+/// may be far larger than the number of rows inserted
+const MAX_INITIAL_CAPACITY: usize = 1024;
+
+/// A column of a [`RecordBatch`] to sort by, see [`topk`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortExpr {
+    /// The index of the column in the [`RecordBatch`]
+    pub column: usize,
+    /// Sort options for this column
+    pub options: SortOptions,
+}
+
+impl SortExpr {
+    /// Create a new [`SortExpr`] for the column at index `column`
+    pub fn new(column: usize, options: SortOptions) -> Self {
+        Self { column, options }
+    }
+}
+
+/// Returns the first `k` rows of `batch` when sorted lexicographically by `sort_exprs`
+///
+/// This is equivalent to [`lexsort`] followed by a slice, but only
+/// maintains a bounded heap of `k` rows in the [row format](crate), and is therefore
+/// significantly faster when `k` is much smaller than the number of rows. Rows that compare
+/// equal are returned in the order they appear in `batch`.
+///
+/// See [`TopK`] to compute the first `k` rows across multiple batches
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_row::topk::{topk, SortExpr};
+/// # use arrow_schema::SortOptions;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![3, 1, 2, 1, 5]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["c", "b", "d", "a", "e"]));
+/// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+///
+/// let sort_exprs = [
+///     SortExpr::new(0, SortOptions::default()),
+///     SortExpr::new(1, SortOptions::default()),
+/// ];
+/// let top = topk(&batch, &sort_exprs, 3).unwrap();
+/// assert_eq!(top.column(0).as_primitive::<Int32Type>().values(), &[1, 1, 2]);
+/// assert_eq!(top.column(1).as_string::<i32>().value(0), "a");
+/// ```
+///
+/// [`lexsort`]: https://docs.rs/arrow-ord/latest/arrow_ord/sort/fn.lexsort.html
+pub fn topk(
+    batch: &RecordBatch,
+    sort_exprs: &[SortExpr],
+    k: usize,
+) -> Result<RecordBatch, ArrowError> {
+    let mut topk = TopK::try_new(batch.schema(), sort_exprs, k)?;
+    topk.insert_batch(batch)?;
+    topk.finish()
+}
+
+/// An entry in the [`TopK`] heap
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct HeapItem {
+    /// The sort key of the row
+    row: OwnedRow,
+    /// The index of the batch containing this row, later batches compare greater
+    batch: usize,
+    /// The index of the row within its batch
+    index: usize,
+}
+
+/// Computes the first `k` rows across a stream of [`RecordBatch`] sorted by a
+/// list of [`SortExpr`]
+///
+/// Retains at most `k` rows, along with the batches containing them, which are periodically
+/// compacted to bound memory usage.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_row::topk::{SortExpr, TopK};
+/// # use arrow_schema::SortOptions;
+/// let descending = SortOptions::default().desc();
+/// let mut topk = None;
+/// for values in [vec![1, 8, 3], vec![9, 2], vec![7]] {
+///     let values: ArrayRef = Arc::new(Int64Array::from(values));
+///     let batch = RecordBatch::try_from_iter([("v", values)]).unwrap();
+///     let topk = topk.get_or_insert_with(|| {
+///         TopK::try_new(batch.schema(), &[SortExpr::new(0, descending)], 2).unwrap()
+///     });
+///     topk.insert_batch(&batch).unwrap();
+/// }
+///
+/// let top = topk.unwrap().finish().unwrap();
+/// assert_eq!(top.column(0).as_primitive::<Int64Type>().values(), &[9, 8]);
+/// ```
+#[derive(Debug)]
+pub struct TopK {
+    schema: SchemaRef,
+    columns: Vec<usize>,
+    converter: RowConverter,
+    k: usize,
+    heap: BinaryHeap<HeapItem>,
+    batches: Vec<RecordBatch>,
+}
+
+impl TopK {
+    /// Create a new [`TopK`] computing the first `k` rows of batches with `schema`
+    /// when sorted by `sort_exprs`
+    ///
+    /// Returns an error if `sort_exprs` is empty, refers to a column not in `schema`,
+    /// or a column has a type that cannot be sorted
+    pub fn try_new(
+        schema: SchemaRef,
+        sort_exprs: &[SortExpr],
+        k: usize,
+    ) -> Result<Self, ArrowError> {
+        if sort_exprs.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "TopK requires at least one sort expression".to_string(),
+            ));
+        }
+
+        let fields = sort_exprs
+            .iter()
+            .map(|expr| {
+                let field = schema.fields().get(expr.column).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Sort column {} out of bounds for schema with {} fields",
+                        expr.column,
+                        schema.fields().len()
+                    ))
+                })?;
+                Ok(SortField::new_with_options(
+                    field.data_type().clone(),
+                    expr.options,
+                ))
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        Ok(Self {
+            columns: sort_exprs.iter().map(|expr| expr.column).collect(),
+            converter: RowConverter::new(fields)?,
+            schema,
+            k,
+            heap: BinaryHeap::with_capacity(k.min(MAX_INITIAL_CAPACITY)),
+            batches: vec![],
+        })
+    }
+
+    /// Insert the rows of `batch`, retaining those among the first `k` seen so far
+    ///
+    /// Returns an error if `batch` does not have the columns of the schema of this [`TopK`]
+    pub fn insert_batch(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.num_columns() != self.schema.fields().len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "TopK expected a batch with {} columns, got {}",
+                self.schema.fields().len(),
+                batch.num_columns()
+            )));
+        }
+        if self.k == 0 || batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let columns: Vec<ArrayRef> = self
+            .columns
+            .iter()
+            .map(|c| batch.column(*c).clone())
+            .collect();
+        let rows = self.converter.convert_columns(&columns)?;
+
+        let batch_idx = self.batches.len();
+        let mut retained = false;
+        for (index, row) in rows.iter().enumerate() {
+            if self.heap.len() == self.k {
+                // Rows equal to the current maximum are not retained, as they
+                // compare greater due to being from a later batch
+                match self.heap.peek() {
+                    Some(max) if row < max.row.row() => {
+                        self.heap.pop();
+                    }
+                    _ => continue,
+                }
+            }
+            self.heap.push(HeapItem {
+                row: row.owned(),
+                batch: batch_idx,
+                index,
+            });
+            retained = true;
+        }
+
+        if retained {
+            self.batches.push(batch.clone());
+            if self.batches.len() > MAX_RETAINED_BATCHES {
+                self.compact()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of rows currently retained
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if no rows are retained
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the retained rows in sorted order, along with their sort keys
+    fn sorted(&mut self) -> Result<(Vec<HeapItem>, RecordBatch), ArrowError> {
+        let items = std::mem::take(&mut self.heap).into_sorted_vec();
+        let indices: Vec<_> = items.iter().map(|item| (item.batch, item.index)).collect();
+        let batch = match indices.is_empty() {
+            true => RecordBatch::new_empty(self.schema.clone()),
+            false => {
+                let batches: Vec<_> = self.batches.iter().collect();
+                interleave_record_batch(&batches, &indices)?
+            }
+        };
+        Ok((items, batch))
+    }
+
+    /// Replaces the retained batches with a single batch containing only the retained rows
+    fn compact(&mut self) -> Result<(), ArrowError> {
+        let (items, batch) = self.sorted()?;
+        self.heap = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| HeapItem {
+                row: item.row,
+                batch: 0,
+                index,
+            })
+            .collect();
+        self.batches = vec![batch];
+        Ok(())
+    }
+
+    /// Returns the first `k` rows inserted, in sorted order
+    pub fn finish(mut self) -> Result<RecordBatch, ArrowError> {
+        Ok(self.sorted()?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float64Array, Int32Array, StringArray};
+    use arrow_ord::sort::{lexsort_to_indices, SortColumn};
+    use arrow_schema::Schema;
+    use arrow_select::take::take;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::sync::Arc;
+
+    fn random_batch(rng: &mut StdRng, len: usize) -> RecordBatch {
+        let a: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..10)))
+            .collect();
+        let b: StringArray = (0..len)
+            .map(|_| {
+                rng.gen_bool(0.9)
+                    .then(|| format!("{}", rng.gen_range(0..100)))
+            })
+            .collect();
+        let c: Float64Array = (0..len).map(|_| Some(rng.gen::<f64>())).collect();
+        RecordBatch::try_from_iter_with_nullable([
+            ("a", Arc::new(a) as ArrayRef, true),
+            ("b", Arc::new(b) as ArrayRef, true),
+            ("c", Arc::new(c) as ArrayRef, true),
+        ])
+        .unwrap()
+    }
+
+    fn lexsort_batch(batch: &RecordBatch, sort_exprs: &[SortExpr], k: usize) -> RecordBatch {
+        let mut columns: Vec<_> = sort_exprs
+            .iter()
+            .map(|e| SortColumn {
+                values: batch.column(e.column).clone(),
+                options: Some(e.options),
+            })
+            .collect();
+        // Sort by the unique final column to produce a total order
+        columns.push(SortColumn {
+            values: batch.column(2).clone(),
+            options: None,
+        });
+        let indices = lexsort_to_indices(&columns, Some(k)).unwrap();
+        let sorted = batch
+            .columns()
+            .iter()
+            .map(|c| take(c, &indices, None).unwrap())
+            .collect();
+        RecordBatch::try_new(batch.schema(), sorted).unwrap()
+    }
+
+    #[test]
+    fn test_topk_matches_lexsort() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let options = [
+            SortOptions::default(),
+            SortOptions::default().desc(),
+            SortOptions::default().desc().nulls_last(),
+        ];
+
+        for k in [0, 1, 5, 100, 1000] {
+            for a in options {
+                for b in options {
+                    let sort_exprs = [
+                        SortExpr::new(1, b),
+                        SortExpr::new(0, a),
+                        SortExpr::new(2, SortOptions::default()),
+                    ];
+                    let batches: Vec<_> = (0..20).map(|_| random_batch(&mut rng, 30)).collect();
+
+                    let mut top_k = TopK::try_new(batches[0].schema(), &sort_exprs, k).unwrap();
+                    for batch in &batches {
+                        top_k.insert_batch(batch).unwrap();
+                        assert!(top_k.len() <= k);
+                        assert!(top_k.batches.len() <= MAX_RETAINED_BATCHES);
+                    }
+                    let actual = top_k.finish().unwrap();
+
+                    let all = arrow_select::concat::concat_batches(&batches[0].schema(), &batches)
+                        .unwrap();
+                    let expected = lexsort_batch(&all, &sort_exprs[..2], k);
+                    assert_eq!(actual, expected);
+
+                    let single = topk(&all, &sort_exprs, k).unwrap();
+                    assert_eq!(single, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_topk_stable() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 0, 1, 0, 1]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4]));
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+        let sort_exprs = [SortExpr::new(0, SortOptions::default())];
+
+        let mut topk = TopK::try_new(batch.schema(), &sort_exprs, 3).unwrap();
+        topk.insert_batch(&batch.slice(0, 2)).unwrap();
+        topk.insert_batch(&batch.slice(2, 3)).unwrap();
+        let result = topk.finish().unwrap();
+        let b = result
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(b.values(), &[1, 3, 0]);
+    }
+
+    #[test]
+    fn test_topk_errors() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+
+        let err = topk(&batch, &[], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: TopK requires at least one sort expression"
+        );
+
+        let err = topk(&batch, &[SortExpr::new(1, SortOptions::default())], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort column 1 out of bounds for schema with 1 fields"
+        );
+
+        let sort_exprs = [SortExpr::new(0, SortOptions::default())];
+        let mut topk = TopK::try_new(batch.schema(), &sort_exprs, usize::MAX).unwrap();
+        topk.insert_batch(&batch).unwrap();
+        let empty = RecordBatch::new_empty(Arc::new(Schema::empty()));
+        let err = topk.insert_batch(&empty).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: TopK expected a batch with 1 columns, got 0"
+        );
+        assert_eq!(topk.finish().unwrap().num_rows(), 1);
+    }
+}
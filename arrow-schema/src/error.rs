@@ -60,6 +60,8 @@ pub enum ArrowError {
     DictionaryKeyOverflowError,
     /// Error when the run end index in a REE array is bigger than the array length
     RunEndIndexOverflowError,
+    /// Error when an operation would exceed a configured resource limit
+    ResourceExhausted(String),
 }
 
 impl ArrowError {
@@ -126,6 +128,7 @@ impl Display for ArrowError {
             ArrowError::RunEndIndexOverflowError => {
                 write!(f, "Run end encoded array index overflow error")
             }
+            ArrowError::ResourceExhausted(desc) => write!(f, "Resource exhausted: {desc}"),
         }
     }
 }
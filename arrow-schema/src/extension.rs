@@ -0,0 +1,33 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Metadata keys and names of arrow extension types
+//!
+//! <https://arrow.apache.org/docs/format/Columnar.html#extension-types>
+
+/// The field metadata key used to store the name of an arrow extension type
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The canonical extension type name for UUIDs
+///
+/// <https://arrow.apache.org/docs/format/CanonicalExtensions.html#uuid>
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// The canonical extension type name for JSON
+///
+/// <https://arrow.apache.org/docs/format/CanonicalExtensions.html#json>
+pub const JSON_EXTENSION_NAME: &str = "arrow.json";
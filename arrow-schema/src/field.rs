@@ -23,11 +23,17 @@ use std::sync::Arc;
 
 use crate::datatype::DataType;
 use crate::schema::SchemaBuilder;
-use crate::{Fields, UnionFields, UnionMode};
+use crate::{Fields, MergeOptions, TypeConflictPolicy, UnionFields, UnionMode};
 
 /// A reference counted [`Field`]
 pub type FieldRef = Arc<Field>;
 
+/// The metadata key used to store the [`Field::field_id`] of a [`Field`]
+///
+/// This matches the key used by the parquet and avro integrations, allowing
+/// field IDs to round trip through them.
+pub const FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
 /// Describes a single column in a [`Schema`](super::Schema).
 ///
 /// A [`Schema`](super::Schema) is an ordered collection of
@@ -310,6 +316,37 @@ impl Field {
         &self.metadata
     }
 
+    /// Returns the field ID of this [`Field`], if any
+    ///
+    /// Field IDs identify a field independently of its name and position, and are
+    /// stored in the [`Field::metadata`] under [`FIELD_ID_META_KEY`]. Returns `None`
+    /// if the field has no ID, or the stored value is not a valid `i32`.
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let field = Field::new("c1", DataType::Int64, false);
+    /// assert_eq!(field.field_id(), None);
+    ///
+    /// let field = field.with_field_id(5);
+    /// assert_eq!(field.field_id(), Some(5));
+    /// assert_eq!(field.metadata()[FIELD_ID_META_KEY], "5");
+    /// ```
+    pub fn field_id(&self) -> Option<i32> {
+        self.metadata.get(FIELD_ID_META_KEY)?.parse().ok()
+    }
+
+    /// Sets the field ID of this [`Field`], see [`Field::field_id`]
+    pub fn set_field_id(&mut self, id: i32) {
+        self.metadata
+            .insert(FIELD_ID_META_KEY.to_string(), id.to_string());
+    }
+
+    /// Sets the field ID of this [`Field`] and returns self, see [`Field::field_id`]
+    pub fn with_field_id(mut self, id: i32) -> Self {
+        self.set_field_id(id);
+        self
+    }
+
     /// Returns an immutable reference to the `Field`'s name.
     #[inline]
     pub const fn name(&self) -> &String {
@@ -379,12 +416,65 @@ impl Field {
         collected_fields
     }
 
+    /// Returns a copy of this field with IDs assigned to it, and any nested fields,
+    /// that do not already have one, in the same order as [`Field::fields`]
+    pub(crate) fn with_assigned_field_ids(&self, next_id: &mut i32) -> Result<Field, ArrowError> {
+        let mut field = self.clone();
+        if field.field_id().is_none() {
+            field.set_field_id(*next_id);
+            *next_id = next_id.checked_add(1).ok_or_else(|| {
+                ArrowError::SchemaError("Overflow assigning field IDs".to_string())
+            })?;
+        }
+        field.data_type = Field::_with_assigned_field_ids(&self.data_type, next_id)?;
+        Ok(field)
+    }
+
+    fn _with_assigned_field_ids(dt: &DataType, next_id: &mut i32) -> Result<DataType, ArrowError> {
+        let assign =
+            |f: &FieldRef, next_id: &mut i32| f.with_assigned_field_ids(next_id).map(Arc::new);
+        Ok(match dt {
+            DataType::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|f| assign(f, next_id))
+                    .collect::<Result<_, _>>()?,
+            ),
+            DataType::Union(fields, mode) => {
+                let (ids, fields): (Vec<_>, Vec<_>) = fields.iter().unzip();
+                let fields = fields
+                    .into_iter()
+                    .map(|f| assign(f, next_id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                DataType::Union(UnionFields::new(ids, fields), *mode)
+            }
+            DataType::List(field) => DataType::List(assign(field, next_id)?),
+            DataType::LargeList(field) => DataType::LargeList(assign(field, next_id)?),
+            DataType::ListView(field) => DataType::ListView(assign(field, next_id)?),
+            DataType::LargeListView(field) => DataType::LargeListView(assign(field, next_id)?),
+            DataType::FixedSizeList(field, size) => {
+                DataType::FixedSizeList(assign(field, next_id)?, *size)
+            }
+            DataType::Map(field, sorted) => DataType::Map(assign(field, next_id)?, *sorted),
+            DataType::Dictionary(key, value) => DataType::Dictionary(
+                key.clone(),
+                Box::new(Field::_with_assigned_field_ids(value, next_id)?),
+            ),
+            DataType::RunEndEncoded(run_ends, values) => {
+                DataType::RunEndEncoded(run_ends.clone(), assign(values, next_id)?)
+            }
+            dt => dt.clone(),
+        })
+    }
+
     fn _fields(dt: &DataType) -> Vec<&Field> {
         match dt {
             DataType::Struct(fields) => fields.iter().flat_map(|f| f.fields()).collect(),
             DataType::Union(fields, _) => fields.iter().flat_map(|(_, f)| f.fields()).collect(),
             DataType::List(field)
             | DataType::LargeList(field)
+            | DataType::ListView(field)
+            | DataType::LargeListView(field)
             | DataType::FixedSizeList(field, _)
             | DataType::Map(field, _) => field.fields(),
             DataType::Dictionary(_, value_field) => Field::_fields(value_field.as_ref()),
@@ -474,6 +564,25 @@ impl Field {
     /// assert!(field.is_nullable());
     /// ```
     pub fn try_merge(&mut self, from: &Field) -> Result<(), ArrowError> {
+        self.try_merge_with_options(from, &MergeOptions::default())
+    }
+
+    /// Merge this field into self, resolving conflicts according to `options`.
+    ///
+    /// See [`Field::try_merge`] and [`MergeOptions`]
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let options = MergeOptions::new().with_type_conflict(TypeConflictPolicy::Widen);
+    /// let mut field = Field::new("c1", DataType::Int32, false);
+    /// field.try_merge_with_options(&Field::new("c1", DataType::UInt32, false), &options).unwrap();
+    /// assert_eq!(field.data_type(), &DataType::Int64);
+    /// ```
+    pub fn try_merge_with_options(
+        &mut self,
+        from: &Field,
+        options: &MergeOptions,
+    ) -> Result<(), ArrowError> {
         #[allow(deprecated)]
         if from.dict_id != self.dict_id {
             return Err(ArrowError::SchemaError(format!(
@@ -491,18 +600,12 @@ impl Field {
         match (self.metadata().is_empty(), from.metadata().is_empty()) {
             (false, false) => {
                 let mut merged = self.metadata().clone();
-                for (key, from_value) in from.metadata() {
-                    if let Some(self_value) = self.metadata.get(key) {
-                        if self_value != from_value {
-                            return Err(ArrowError::SchemaError(format!(
-                                "Fail to merge field '{}' due to conflicting metadata data value for key {}.
-                                    From value = {} does not match {}", self.name, key, from_value, self_value),
-                            ));
-                        }
-                    } else {
-                        merged.insert(key.clone(), from_value.clone());
-                    }
-                }
+                options.merge_metadata(&mut merged, from.metadata(), |key, self_value, from_value| {
+                    ArrowError::SchemaError(format!(
+                        "Fail to merge field '{}' due to conflicting metadata data value for key {}.
+                            From value = {} does not match {}", self.name, key, from_value, self_value),
+                    )
+                })?;
                 self.set_metadata(merged);
             }
             (true, false) => {
@@ -514,9 +617,10 @@ impl Field {
             DataType::Struct(nested_fields) => match &from.data_type {
                 DataType::Struct(from_nested_fields) => {
                     let mut builder = SchemaBuilder::new();
-                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge(f))?;
+                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge_with_options(f, options))?;
                     *nested_fields = builder.finish().fields;
                 }
+                _ if options.type_conflict == TypeConflictPolicy::PreferLeft => {}
                 _ => {
                     return Err(ArrowError::SchemaError(
                         format!("Fail to merge schema field '{}' because the from data_type = {} is not DataType::Struct",
@@ -527,6 +631,7 @@ impl Field {
                 DataType::Union(from_nested_fields, _) => {
                     nested_fields.try_merge(from_nested_fields)?
                 }
+                _ if options.type_conflict == TypeConflictPolicy::PreferLeft => {}
                 _ => {
                     return Err(ArrowError::SchemaError(
                         format!("Fail to merge schema field '{}' because the from data_type = {} is not DataType::Union",
@@ -537,9 +642,10 @@ impl Field {
             DataType::List(field) => match &from.data_type {
                 DataType::List(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_options(from_field, options)?;
                     (*field) = Arc::new(f);
                 },
+                _ if options.type_conflict == TypeConflictPolicy::PreferLeft => {}
                 _ => {
                     return Err(ArrowError::SchemaError(
                         format!("Fail to merge schema field '{}' because the from data_type = {} is not DataType::List",
@@ -549,9 +655,10 @@ impl Field {
             DataType::LargeList(field) => match &from.data_type {
                 DataType::LargeList(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_options(from_field, options)?;
                     (*field) = Arc::new(f);
                 },
+                _ if options.type_conflict == TypeConflictPolicy::PreferLeft => {}
                 _ => {
                     return Err(ArrowError::SchemaError(
                         format!("Fail to merge schema field '{}' because the from data_type = {} is not DataType::LargeList",
@@ -599,10 +706,13 @@ impl Field {
                 if from.data_type == DataType::Null {
                     self.nullable = true;
                 } else if self.data_type != from.data_type {
-                    return Err(ArrowError::SchemaError(
-                        format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
-                            self.name, from.data_type, self.data_type)
-                    ));
+                    match options.resolve_type(&self.data_type, &from.data_type) {
+                        Some(data_type) => self.data_type = data_type,
+                        None => return Err(ArrowError::SchemaError(
+                            format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
+                                self.name, from.data_type, self.data_type)
+                        )),
+                    }
                 }
             }
         }
@@ -25,10 +25,13 @@ use std::fmt::Display;
 mod datatype_parse;
 mod error;
 pub use error::*;
+pub mod extension;
 mod field;
 pub use field::*;
 mod fields;
 pub use fields::*;
+mod merge;
+pub use merge::*;
 mod schema;
 pub use schema::*;
 use std::ops;
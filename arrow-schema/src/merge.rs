@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{DataType, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION};
+use std::collections::HashMap;
+
+/// How to resolve fields with conflicting data types when merging
+///
+/// See [`MergeOptions`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeConflictPolicy {
+    /// Return an error (default)
+    #[default]
+    Error,
+    /// Widen the data types to a common type able to represent the values of both
+    ///
+    /// * Integers widen to the narrowest integer able to represent both,
+    ///   e.g. `Int32` and `Int64` to `Int64`, or `UInt32` and `Int8` to `Int64`
+    /// * Floating point types widen to the larger type, and integers combined
+    ///   with floating point types widen to `Float64`
+    /// * Decimals widen to the narrowest decimal able to represent both
+    /// * `Utf8` and `Binary` combined with their large variants widen to
+    ///   `LargeUtf8` and `LargeBinary` respectively
+    ///
+    /// Returns an error if no such common type exists
+    Widen,
+    /// Keep the data type of the field encountered first
+    PreferLeft,
+}
+
+/// How to resolve metadata keys with conflicting values when merging
+///
+/// See [`MergeOptions`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetadataConflictPolicy {
+    /// Return an error (default)
+    #[default]
+    Error,
+    /// Keep the value encountered first
+    PreferLeft,
+    /// Keep the value encountered last
+    PreferRight,
+}
+
+/// Options that control how [`Schema`](crate::Schema)s and [`Field`](crate::Field)s are merged
+///
+/// The default options match the behaviour of [`Schema::try_merge`](crate::Schema::try_merge),
+/// returning an error on any conflict.
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, MergeOptions, Schema, TypeConflictPolicy};
+/// let options = MergeOptions::new().with_type_conflict(TypeConflictPolicy::Widen);
+/// let merged = Schema::try_merge_with_options(
+///     vec![
+///         Schema::new(vec![Field::new("a", DataType::Int32, false)]),
+///         Schema::new(vec![Field::new("a", DataType::Int64, false)]),
+///     ],
+///     &options,
+/// )
+/// .unwrap();
+/// assert_eq!(merged.field(0).data_type(), &DataType::Int64);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MergeOptions {
+    /// How to resolve fields with conflicting data types
+    pub type_conflict: TypeConflictPolicy,
+    /// How to resolve metadata keys with conflicting values
+    pub metadata_conflict: MetadataConflictPolicy,
+}
+
+impl MergeOptions {
+    /// Create a new [`MergeOptions`] that errors on any conflict
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how to resolve fields with conflicting data types
+    pub fn with_type_conflict(mut self, type_conflict: TypeConflictPolicy) -> Self {
+        self.type_conflict = type_conflict;
+        self
+    }
+
+    /// Set how to resolve metadata keys with conflicting values
+    pub fn with_metadata_conflict(mut self, metadata_conflict: MetadataConflictPolicy) -> Self {
+        self.metadata_conflict = metadata_conflict;
+        self
+    }
+
+    /// Returns the data type to use when merging `to` and `from`, or `None`
+    /// if the conflict should be reported as an error
+    pub(crate) fn resolve_type(&self, to: &DataType, from: &DataType) -> Option<DataType> {
+        match self.type_conflict {
+            TypeConflictPolicy::Error => None,
+            TypeConflictPolicy::PreferLeft => Some(to.clone()),
+            TypeConflictPolicy::Widen => widen(to, from),
+        }
+    }
+
+    /// Merges `from` into `to`, calling `on_conflict` with the key and the
+    /// existing and new values if they conflict and the policy is to error
+    pub(crate) fn merge_metadata<E>(
+        &self,
+        to: &mut HashMap<String, String>,
+        from: &HashMap<String, String>,
+        on_conflict: impl Fn(&str, &str, &str) -> E,
+    ) -> Result<(), E> {
+        for (key, value) in from {
+            match to.get_mut(key) {
+                Some(existing) if existing != value => match self.metadata_conflict {
+                    MetadataConflictPolicy::Error => {
+                        return Err(on_conflict(key, existing, value));
+                    }
+                    MetadataConflictPolicy::PreferLeft => {}
+                    MetadataConflictPolicy::PreferRight => existing.clone_from(value),
+                },
+                Some(_) => {}
+                None => {
+                    to.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the narrowest type able to represent the values of both `a` and `b`
+fn widen(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (a, b) {
+        _ if a.is_integer() && b.is_integer() => widen_integer(a, b),
+        _ if a.is_floating() && b.is_floating() => {
+            match a.primitive_width()?.max(b.primitive_width()?) {
+                2 => Some(Float16),
+                4 => Some(Float32),
+                _ => Some(Float64),
+            }
+        }
+        _ if (a.is_integer() && b.is_floating()) || (a.is_floating() && b.is_integer()) => {
+            Some(Float64)
+        }
+        (Decimal128(p1, s1) | Decimal256(p1, s1), Decimal128(p2, s2) | Decimal256(p2, s2)) => {
+            let scale = (*s1).max(*s2);
+            let integer_digits = (*p1 as i16 - *s1 as i16).max(*p2 as i16 - *s2 as i16);
+            let precision = u8::try_from(integer_digits + scale as i16).ok()?;
+            let is_256 = matches!(a, Decimal256(_, _)) || matches!(b, Decimal256(_, _));
+            match precision {
+                0 => None,
+                p if p <= DECIMAL128_MAX_PRECISION && !is_256 => Some(Decimal128(p, scale)),
+                p if p <= DECIMAL256_MAX_PRECISION => Some(Decimal256(p, scale)),
+                _ => None,
+            }
+        }
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8) => Some(LargeUtf8),
+        (Binary | LargeBinary, Binary | LargeBinary) => Some(LargeBinary),
+        _ => None,
+    }
+}
+
+/// Returns the narrowest integer type able to represent the values of both `a` and `b`
+fn widen_integer(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    let (a_width, b_width) = (a.primitive_width()?, b.primitive_width()?);
+    let width = match (a.is_signed_integer(), b.is_signed_integer()) {
+        (true, true) | (false, false) => a_width.max(b_width),
+        // A signed integer must be wider than the unsigned integer
+        (true, false) => a_width.max(b_width * 2),
+        (false, true) => b_width.max(a_width * 2),
+    };
+    let signed = a.is_signed_integer() || b.is_signed_integer();
+    match (signed, width) {
+        (true, 1) => Some(Int8),
+        (true, 2) => Some(Int16),
+        (true, 4) => Some(Int32),
+        (true, 8) => Some(Int64),
+        (false, 1) => Some(UInt8),
+        (false, 2) => Some(UInt16),
+        (false, 4) => Some(UInt32),
+        (false, 8) => Some(UInt64),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimeUnit;
+
+    #[test]
+    fn test_widen() {
+        use DataType::*;
+        let cases = [
+            (Int32, Int64, Some(Int64)),
+            (Int8, UInt8, Some(Int16)),
+            (UInt32, Int8, Some(Int64)),
+            (UInt64, Int64, None),
+            (UInt16, UInt64, Some(UInt64)),
+            (Float16, Float32, Some(Float32)),
+            (Float64, Float16, Some(Float64)),
+            (Int64, Float32, Some(Float64)),
+            (Decimal128(10, 2), Decimal128(5, 4), Some(Decimal128(12, 4))),
+            (
+                Decimal128(38, 0),
+                Decimal128(10, 10),
+                Some(Decimal256(48, 10)),
+            ),
+            (Decimal256(5, 0), Decimal128(5, 0), Some(Decimal256(5, 0))),
+            (Utf8, LargeUtf8, Some(LargeUtf8)),
+            (LargeBinary, Binary, Some(LargeBinary)),
+            (Utf8, Binary, None),
+            (Int32, Utf8, None),
+            (Timestamp(TimeUnit::Second, None), Int64, None),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(widen(&a, &b), expected, "{a} {b}");
+            assert_eq!(widen(&b, &a), expected, "{b} {a}");
+        }
+    }
+
+    #[test]
+    fn test_merge_metadata() {
+        let mut to = HashMap::from([("a".to_string(), "1".to_string())]);
+        let from = HashMap::from([
+            ("a".to_string(), "2".to_string()),
+            ("b".to_string(), "3".to_string()),
+        ]);
+
+        let err = MergeOptions::new()
+            .merge_metadata(&mut to.clone(), &from, |k, a, b| format!("{k}: {a} {b}"))
+            .unwrap_err();
+        assert_eq!(err, "a: 1 2");
+
+        let options =
+            MergeOptions::new().with_metadata_conflict(MetadataConflictPolicy::PreferLeft);
+        let mut left = to.clone();
+        options
+            .merge_metadata(&mut left, &from, |_, _, _| ())
+            .unwrap();
+        assert_eq!(left["a"], "1");
+        assert_eq!(left["b"], "3");
+
+        let options = options.with_metadata_conflict(MetadataConflictPolicy::PreferRight);
+        options
+            .merge_metadata(&mut to, &from, |_, _, _| ())
+            .unwrap();
+        assert_eq!(to["a"], "2");
+        assert_eq!(to["b"], "3");
+    }
+}
@@ -21,8 +21,8 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::error::ArrowError;
-use crate::field::Field;
-use crate::{FieldRef, Fields};
+use crate::field::{Field, FIELD_ID_META_KEY};
+use crate::{FieldRef, Fields, MergeOptions};
 
 /// A builder to facilitate building a [`Schema`] from iteratively from [`FieldRef`]
 #[derive(Debug, Default)]
@@ -96,15 +96,27 @@ impl SchemaBuilder {
     ///
     /// If an existing field exists with the same name, calls [`Field::try_merge`]
     pub fn try_merge(&mut self, field: &FieldRef) -> Result<(), ArrowError> {
+        self.try_merge_with_options(field, &MergeOptions::default())
+    }
+
+    /// Appends a [`FieldRef`] to this [`SchemaBuilder`], resolving conflicts with an
+    /// existing field of the same name according to `options`
+    ///
+    /// See [`Field::try_merge_with_options`]
+    pub fn try_merge_with_options(
+        &mut self,
+        field: &FieldRef,
+        options: &MergeOptions,
+    ) -> Result<(), ArrowError> {
         // This could potentially be sped up with a HashMap or similar
         let existing = self.fields.iter_mut().find(|f| f.name() == field.name());
         match existing {
             Some(e) if Arc::ptr_eq(e, field) => {} // Nothing to do
             Some(e) => match Arc::get_mut(e) {
-                Some(e) => e.try_merge(field.as_ref())?,
+                Some(e) => e.try_merge_with_options(field.as_ref(), options)?,
                 None => {
                     let mut t = e.as_ref().clone();
-                    t.try_merge(field)?;
+                    t.try_merge_with_options(field, options)?;
                     *e = Arc::new(t)
                 }
             },
@@ -293,26 +305,55 @@ impl Schema {
     /// );
     /// ```
     pub fn try_merge(schemas: impl IntoIterator<Item = Self>) -> Result<Self, ArrowError> {
+        Self::try_merge_with_options(schemas, &MergeOptions::default())
+    }
+
+    /// Merge schema into self, resolving conflicting field types and metadata
+    /// according to `options`
+    ///
+    /// See [`Schema::try_merge`] and [`MergeOptions`]
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// # use std::collections::HashMap;
+    /// let options = MergeOptions::new()
+    ///     .with_type_conflict(TypeConflictPolicy::Widen)
+    ///     .with_metadata_conflict(MetadataConflictPolicy::PreferRight);
+    ///
+    /// let a = Schema::new(vec![Field::new("c1", DataType::Float32, false)])
+    ///     .with_metadata(HashMap::from([("source".to_string(), "a.avro".to_string())]));
+    /// let b = Schema::new(vec![
+    ///     Field::new("c1", DataType::Int64, false),
+    ///     Field::new("c2", DataType::Utf8, true),
+    /// ])
+    /// .with_metadata(HashMap::from([("source".to_string(), "b.avro".to_string())]));
+    ///
+    /// let merged = Schema::try_merge_with_options(vec![a, b], &options).unwrap();
+    /// assert_eq!(merged.field(0).data_type(), &DataType::Float64);
+    /// assert_eq!(merged.field(1).data_type(), &DataType::Utf8);
+    /// assert_eq!(merged.metadata()["source"], "b.avro");
+    /// ```
+    pub fn try_merge_with_options(
+        schemas: impl IntoIterator<Item = Self>,
+        options: &MergeOptions,
+    ) -> Result<Self, ArrowError> {
         let mut out_meta = HashMap::new();
         let mut out_fields = SchemaBuilder::new();
         for schema in schemas {
             let Schema { metadata, fields } = schema;
 
             // merge metadata
-            for (key, value) in metadata.into_iter() {
-                if let Some(old_val) = out_meta.get(&key) {
-                    if old_val != &value {
-                        return Err(ArrowError::SchemaError(format!(
-                            "Fail to merge schema due to conflicting metadata. \
-                                         Key '{key}' has different values '{old_val}' and '{value}'"
-                        )));
-                    }
-                }
-                out_meta.insert(key, value);
-            }
+            options.merge_metadata(&mut out_meta, &metadata, |key, old_val, value| {
+                ArrowError::SchemaError(format!(
+                    "Fail to merge schema due to conflicting metadata. \
+                                     Key '{key}' has different values '{old_val}' and '{value}'"
+                ))
+            })?;
 
             // merge fields
-            fields.iter().try_for_each(|x| out_fields.try_merge(x))?
+            fields
+                .iter()
+                .try_for_each(|x| out_fields.try_merge_with_options(x, options))?
         }
 
         Ok(out_fields.finish().with_metadata(out_meta))
@@ -412,6 +453,89 @@ impl Schema {
         Ok(idx)
     }
 
+    /// Returns the index of the top-level field with the given [`Field::field_id`]
+    pub fn index_of_field_id(&self, id: i32) -> Result<usize, ArrowError> {
+        self.fields
+            .iter()
+            .position(|f| f.field_id() == Some(id))
+            .ok_or_else(|| ArrowError::SchemaError(format!("Unable to get field with ID {id}")))
+    }
+
+    /// Returns an immutable reference to the [`Field`], including nested fields,
+    /// with the given [`Field::field_id`]
+    pub fn field_with_id(&self, id: i32) -> Result<&Field, ArrowError> {
+        self.flattened_fields()
+            .into_iter()
+            .find(|f| f.field_id() == Some(id))
+            .ok_or_else(|| ArrowError::SchemaError(format!("Unable to get field with ID {id}")))
+    }
+
+    /// Validates the [`Field::field_id`]s of this schema, including nested fields
+    ///
+    /// Returns an error if any field ID is not a valid `i32` or is used by more
+    /// than one field. Fields without an ID are ignored.
+    pub fn validate_field_ids(&self) -> Result<(), ArrowError> {
+        let mut seen = HashMap::new();
+        for field in self.flattened_fields() {
+            let Some(value) = field.metadata().get(FIELD_ID_META_KEY) else {
+                continue;
+            };
+            let id: i32 = value.parse().map_err(|_| {
+                ArrowError::SchemaError(format!(
+                    "Invalid field ID \"{value}\" for field \"{}\"",
+                    field.name()
+                ))
+            })?;
+            if let Some(existing) = seen.insert(id, field.name()) {
+                return Err(ArrowError::SchemaError(format!(
+                    "Duplicate field ID {id} for fields \"{existing}\" and \"{}\"",
+                    field.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Assigns a [`Field::field_id`] to every field, including nested fields, that
+    /// does not already have one
+    ///
+    /// Fields are visited depth-first in the order of [`Schema::flattened_fields`],
+    /// with new IDs allocated sequentially after the largest existing ID. Returns an
+    /// error if the existing IDs are not valid, see [`Schema::validate_field_ids`].
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let schema = Schema::new(vec![
+    ///     Field::new("a", DataType::Int32, false).with_field_id(10),
+    ///     Field::new_list("b", Field::new_list_field(DataType::Utf8, true), true),
+    /// ]);
+    /// let schema = schema.with_assigned_field_ids().unwrap();
+    ///
+    /// assert_eq!(schema.field(0).field_id(), Some(10));
+    /// assert_eq!(schema.field(1).field_id(), Some(11));
+    /// assert_eq!(schema.field_with_id(12).unwrap().name(), "item");
+    /// ```
+    pub fn with_assigned_field_ids(self) -> Result<Self, ArrowError> {
+        self.validate_field_ids()?;
+        let mut next_id = self
+            .flattened_fields()
+            .iter()
+            .filter_map(|f| f.field_id())
+            .max()
+            .map_or(Some(1), |max| max.checked_add(1))
+            .ok_or_else(|| ArrowError::SchemaError("Overflow assigning field IDs".to_string()))?;
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.with_assigned_field_ids(&mut next_id).map(Arc::new))
+            .collect::<Result<Fields, _>>()?;
+        Ok(Self {
+            fields,
+            metadata: self.metadata,
+        })
+    }
+
     /// Returns an immutable reference to the Map of custom metadata key-value pairs.
     #[inline]
     pub const fn metadata(&self) -> &HashMap<String, String> {
@@ -473,7 +597,7 @@ impl Hash for Schema {
 #[cfg(test)]
 mod tests {
     use crate::datatype::DataType;
-    use crate::{TimeUnit, UnionMode};
+    use crate::{TimeUnit, TypeConflictPolicy, UnionMode};
 
     use super::*;
 
@@ -953,6 +1077,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_field_ids() {
+        let nested = Fields::from(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("y", DataType::Int32, false).with_field_id(3),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Struct(nested), true),
+            Field::new_map(
+                "c",
+                "entries",
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int64, true),
+                false,
+                true,
+            )
+            .with_field_id(1),
+        ]);
+        schema.validate_field_ids().unwrap();
+        assert_eq!(schema.index_of_field_id(1).unwrap(), 2);
+        assert_eq!(schema.field_with_id(3).unwrap().name(), "y");
+        let err = schema.index_of_field_id(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unable to get field with ID 3"
+        );
+
+        let schema = schema.with_assigned_field_ids().unwrap();
+        schema.validate_field_ids().unwrap();
+        let ids: Vec<_> = schema
+            .flattened_fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.field_id().unwrap()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("a", 4),
+                ("b", 5),
+                ("x", 6),
+                ("y", 3),
+                ("c", 1),
+                ("entries", 7),
+                ("key", 8),
+                ("value", 9)
+            ]
+        );
+        assert_eq!(schema.index_of_field_id(5).unwrap(), 1);
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, false).with_field_id(1),
+            Field::new_list(
+                "b",
+                Field::new_list_field(DataType::Utf8, true).with_field_id(1),
+                true,
+            ),
+        ]);
+        let err = schema.validate_field_ids().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Duplicate field ID 1 for fields \"a\" and \"item\""
+        );
+
+        let field = Field::new("a", DataType::Utf8, false).with_metadata(HashMap::from([(
+            FIELD_ID_META_KEY.to_string(),
+            "x".to_string(),
+        )]));
+        assert_eq!(field.field_id(), None);
+        let err = Schema::new(vec![field])
+            .with_assigned_field_ids()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Invalid field ID \"x\" for field \"a\""
+        );
+    }
+
+    #[test]
+    fn test_schema_merge_with_options() {
+        let a = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new(
+                "nested",
+                DataType::Struct(vec![Field::new("v", DataType::Float32, true)].into()),
+                false,
+            ),
+            Field::new("name", DataType::Utf8, false),
+        ]);
+        let b = Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new(
+                "nested",
+                DataType::Struct(vec![Field::new("v", DataType::UInt16, true)].into()),
+                false,
+            ),
+            Field::new("name", DataType::Binary, false),
+        ]);
+
+        let err = Schema::try_merge(vec![a.clone(), b.clone()]).unwrap_err();
+        assert_eq!(err.to_string(), "Schema error: Fail to merge schema field 'id' because the from data_type = Int64 does not equal Int32");
+
+        let options = MergeOptions::new().with_type_conflict(TypeConflictPolicy::Widen);
+        let err = Schema::try_merge_with_options(vec![a.clone(), b.clone()], &options).unwrap_err();
+        assert_eq!(err.to_string(), "Schema error: Fail to merge schema field 'name' because the from data_type = Binary does not equal Utf8");
+
+        let b = Schema::new(vec![b.field(0).clone(), b.field(1).clone()]);
+        let merged = Schema::try_merge_with_options(vec![a.clone(), b.clone()], &options).unwrap();
+        assert_eq!(
+            merged,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64, true),
+                Field::new(
+                    "nested",
+                    DataType::Struct(vec![Field::new("v", DataType::Float64, true)].into()),
+                    false,
+                ),
+                Field::new("name", DataType::Utf8, false),
+            ])
+        );
+
+        let options = MergeOptions::new().with_type_conflict(TypeConflictPolicy::PreferLeft);
+        let merged = Schema::try_merge_with_options(vec![a.clone(), b], &options).unwrap();
+        assert_eq!(merged.field(0), &Field::new("id", DataType::Int32, true));
+        assert_eq!(merged.field(1), a.field(1));
+    }
+
     #[test]
     fn test_schema_builder_change_field() {
         let mut builder = SchemaBuilder::new();
@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::ByteViewType;
+use arrow_array::{make_array, Array, ArrayRef, GenericByteViewArray};
+use arrow_schema::DataType;
+
+/// Views of at most this length store their data inline
+const MAX_INLINE_VIEW_LEN: u32 = 12;
+
+/// Compacts the data buffers of `array` if it is a byte view array, or of the byte view
+/// arrays nested within it, see [`compact_byte_view`]
+pub(crate) fn compact_byte_view_array(array: ArrayRef, threshold: f64) -> ArrayRef {
+    match array.data_type() {
+        DataType::Utf8View => {
+            Arc::new(compact_byte_view(array.as_string_view().clone(), threshold))
+        }
+        DataType::BinaryView => {
+            Arc::new(compact_byte_view(array.as_binary_view().clone(), threshold))
+        }
+        d if contains_byte_view(d) => {
+            let data = array.to_data();
+            let children = data
+                .child_data()
+                .iter()
+                .map(|c| compact_byte_view_array(make_array(c.clone()), threshold).to_data())
+                .collect();
+            // SAFETY: compaction does not change the values of the children
+            make_array(unsafe { data.into_builder().child_data(children).build_unchecked() })
+        }
+        _ => array,
+    }
+}
+
+/// Returns true if `data_type` is, or contains, a byte view type
+fn contains_byte_view(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8View | DataType::BinaryView => true,
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::ListView(f)
+        | DataType::LargeListView(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _)
+        | DataType::RunEndEncoded(_, f) => contains_byte_view(f.data_type()),
+        DataType::Struct(fields) => fields.iter().any(|f| contains_byte_view(f.data_type())),
+        DataType::Union(fields, _) => fields
+            .iter()
+            .any(|(_, f)| contains_byte_view(f.data_type())),
+        DataType::Dictionary(_, v) => contains_byte_view(v),
+        _ => false,
+    }
+}
+
+/// Returns `array` with its data buffers compacted by [`GenericByteViewArray::gc`] if the
+/// bytes referenced by its non-null views are less than `threshold` of the total length
+/// of its data buffers, otherwise returns `array` unchanged
+pub(crate) fn compact_byte_view<T: ByteViewType>(
+    array: GenericByteViewArray<T>,
+    threshold: f64,
+) -> GenericByteViewArray<T> {
+    let total: usize = array.data_buffers().iter().map(|b| b.len()).sum();
+    if total == 0 {
+        return array;
+    }
+
+    let view_len = |view: &u128| match *view as u32 {
+        len if len > MAX_INLINE_VIEW_LEN => len as usize,
+        _ => 0,
+    };
+    let views = array.views();
+    let referenced: usize = match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => n.valid_indices().map(|idx| view_len(&views[idx])).sum(),
+        None => views.iter().map(view_len).sum(),
+    };
+
+    match (referenced as f64) < total as f64 * threshold {
+        true => array.gc(),
+        false => array,
+    }
+}
@@ -36,7 +36,8 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer, OffsetBuffer};
 use arrow_data::transform::{Capacities, MutableArrayData};
-use arrow_schema::{ArrowError, DataType, FieldRef, SchemaRef};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaRef};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
@@ -84,6 +85,7 @@ fn fixed_size_list_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capa
 
 fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     arrays: &[&dyn Array],
+    merge_dictionaries: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let mut output_len = 0;
     let dictionaries: Vec<_> = arrays
@@ -92,7 +94,9 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
         .inspect(|d| output_len += d.len())
         .collect();
 
-    if !should_merge_dictionary_values::<K>(&dictionaries, output_len) {
+    // A length of 0 merges any dictionaries that do not share the same values
+    let merge_len = if merge_dictionaries { 0 } else { output_len };
+    if !should_merge_dictionary_values::<K>(&dictionaries, merge_len) {
         return concat_fallback(arrays, Capacities::Array(output_len));
     }
 
@@ -132,6 +136,7 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
 fn concat_lists<OffsetSize: OffsetSizeTrait>(
     arrays: &[&dyn Array],
     field: &FieldRef,
+    merge_dictionaries: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let mut output_len = 0;
     let mut list_has_nulls = false;
@@ -161,7 +166,7 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
         .map(|x| x.values().as_ref())
         .collect::<Vec<_>>();
 
-    let concatenated_values = concat(values.as_slice())?;
+    let concatenated_values = concat_impl(values.as_slice(), merge_dictionaries)?;
 
     // Merge value offsets from the lists
     let value_offset_buffer =
@@ -178,8 +183,8 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
 }
 
 macro_rules! dict_helper {
-    ($t:ty, $arrays:expr) => {
-        return Ok(Arc::new(concat_dictionaries::<$t>($arrays)?) as _)
+    ($t:ty, $arrays:expr, $merge:expr) => {
+        return Ok(Arc::new(concat_dictionaries::<$t>($arrays, $merge)?) as _)
     };
 }
 
@@ -196,6 +201,11 @@ fn get_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capacities {
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    concat_impl(arrays, false)
+}
+
+/// Concatenates `arrays`, always merging the values of dictionaries if `merge_dictionaries`
+fn concat_impl(arrays: &[&dyn Array], merge_dictionaries: bool) -> Result<ArrayRef, ArrowError> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
             "concat requires input of at least one array".to_string(),
@@ -215,12 +225,12 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     match d {
         DataType::Dictionary(k, _) => {
             downcast_integer! {
-                k.as_ref() => (dict_helper, arrays),
+                k.as_ref() => (dict_helper, arrays, merge_dictionaries),
                 _ => unreachable!("illegal dictionary key type {k}")
             }
         }
-        DataType::List(field) => concat_lists::<i32>(arrays, field),
-        DataType::LargeList(field) => concat_lists::<i64>(arrays, field),
+        DataType::List(field) => concat_lists::<i32>(arrays, field, merge_dictionaries),
+        DataType::LargeList(field) => concat_lists::<i64>(arrays, field, merge_dictionaries),
         _ => {
             let capacity = get_capacity(arrays, d);
             concat_fallback(arrays, capacity)
@@ -279,10 +289,161 @@ pub fn concat_batches<'a>(
     RecordBatch::try_new(schema.clone(), arrays)
 }
 
+/// Options that control the behaviour of [`concat_batches_with_options`]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ConcatOptions {
+    /// Merge the values of dictionary columns, removing unreferenced values
+    ///
+    /// If `false` dictionaries are only merged if concatenating their values would
+    /// overflow the key type or exceed the length of the output, as with [`concat`]
+    pub merge_dictionaries: bool,
+
+    /// Allow fields that differ only in nullability, the output field is nullable
+    /// if the field is nullable in any input
+    ///
+    /// This only applies to the top-level fields of the schema, the children of nested
+    /// types, such as the items of a list or the fields of a struct, must still match
+    pub allow_nullability_mismatch: bool,
+}
+
+impl ConcatOptions {
+    /// Creates a new `ConcatOptions`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the merge_dictionaries of ConcatOptions and returns self
+    pub fn with_merge_dictionaries(mut self, merge_dictionaries: bool) -> Self {
+        self.merge_dictionaries = merge_dictionaries;
+        self
+    }
+
+    /// Sets the allow_nullability_mismatch of ConcatOptions and returns self
+    pub fn with_allow_nullability_mismatch(mut self, allow_nullability_mismatch: bool) -> Self {
+        self.allow_nullability_mismatch = allow_nullability_mismatch;
+        self
+    }
+}
+
+/// Concatenates `batches` together into a single [`RecordBatch`] with the provided [`ConcatOptions`]
+///
+/// Unlike [`concat_batches`] the output schema is derived from the inputs, with the
+/// metadata of the schemas and their fields unioned together.
+///
+/// Returns an error if `batches` is empty, if the fields of the inputs differ in name,
+/// type or, unless [`ConcatOptions::allow_nullability_mismatch`], nullability, or if the
+/// inputs have conflicting values for the same metadata key.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_schema::{Field, Schema};
+/// # use arrow_select::concat::{concat_batches_with_options, ConcatOptions};
+/// let a = Schema::new(vec![Field::new("a", arrow_schema::DataType::Int32, false)])
+///     .with_metadata(HashMap::from([("k1".to_string(), "v1".to_string())]));
+/// let b = Schema::new(vec![Field::new("a", arrow_schema::DataType::Int32, true)])
+///     .with_metadata(HashMap::from([("k2".to_string(), "v2".to_string())]));
+///
+/// let a = RecordBatch::try_new(Arc::new(a), vec![Arc::new(Int32Array::from(vec![1, 2]))]).unwrap();
+/// let b = RecordBatch::try_new(Arc::new(b), vec![Arc::new(Int32Array::from(vec![None, Some(3)]))]).unwrap();
+///
+/// let options = ConcatOptions::new().with_allow_nullability_mismatch(true);
+/// let batch = concat_batches_with_options([&a, &b], &options).unwrap();
+/// assert_eq!(batch.num_rows(), 4);
+/// assert!(batch.schema().field(0).is_nullable());
+/// assert_eq!(batch.schema().metadata().len(), 2);
+/// ```
+pub fn concat_batches_with_options<'a>(
+    batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: &ConcatOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let batches: Vec<&RecordBatch> = batches.into_iter().collect();
+    let Some(first) = batches.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_batches_with_options requires at least one batch".to_string(),
+        ));
+    };
+
+    let first_schema = first.schema();
+    let mut fields: Vec<Field> = first_schema
+        .fields()
+        .iter()
+        .map(|f| f.as_ref().clone())
+        .collect();
+    let mut metadata = first_schema.metadata().clone();
+
+    for batch in &batches[1..] {
+        let schema = batch.schema();
+        if schema.fields().len() != fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot concatenate batches with {} and {} columns",
+                fields.len(),
+                schema.fields().len()
+            )));
+        }
+        for (field, other) in fields.iter_mut().zip(schema.fields()) {
+            if field.name() != other.name() || field.data_type() != other.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot concatenate batches with incompatible fields {field:?} and {other:?}"
+                )));
+            }
+            if field.is_nullable() != other.is_nullable() {
+                if !options.allow_nullability_mismatch {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Cannot concatenate batches with fields differing in nullability {field:?} and {other:?}"
+                    )));
+                }
+                *field = field.clone().with_nullable(true);
+            }
+            let mut field_metadata = field.metadata().clone();
+            merge_metadata(&mut field_metadata, other.metadata())?;
+            field.set_metadata(field_metadata);
+        }
+        merge_metadata(&mut metadata, schema.metadata())?;
+    }
+
+    let schema = Arc::new(Schema::new_with_metadata(fields, metadata));
+    let row_count = batches.iter().map(|b| b.num_rows()).sum();
+    let columns = (0..schema.fields().len())
+        .map(|i| {
+            let arrays: Vec<_> = batches.iter().map(|b| b.column(i).as_ref()).collect();
+            concat_impl(&arrays, options.merge_dictionaries)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(row_count));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+/// Adds the entries of `other` to `metadata`, returning an error on conflicting values
+fn merge_metadata(
+    metadata: &mut HashMap<String, String>,
+    other: &HashMap<String, String>,
+) -> Result<(), ArrowError> {
+    for (key, value) in other {
+        match metadata.get(key) {
+            Some(existing) if existing != value => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot concatenate batches with conflicting metadata for key \"{key}\": \"{existing}\" and \"{value}\""
+                )))
+            }
+            Some(_) => {}
+            None => {
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::builder::{GenericListBuilder, StringDictionaryBuilder};
+    use arrow_array::builder::{
+        GenericListBuilder, Int64Builder, ListViewBuilder, StringDictionaryBuilder,
+    };
     use arrow_schema::{Field, Schema};
     use std::fmt::Debug;
 
@@ -493,6 +654,30 @@ mod tests {
         assert_eq!(array_result.as_ref(), &array_expected as &dyn Array);
     }
 
+    #[test]
+    fn test_concat_list_view_arrays() {
+        let mut builder = ListViewBuilder::new(Int64Builder::new());
+        builder.append_value([Some(-1), None]);
+        builder.append_null();
+        builder.append(true);
+        let a = builder.finish();
+
+        let mut builder = ListViewBuilder::new(Int64Builder::new());
+        builder.append_value([Some(10)]);
+        builder.append_value([Some(20), Some(30)]);
+        let b = builder.finish();
+
+        let result = concat(&[&a.slice(1, 2), &b]).unwrap();
+        let result = result.as_list_view::<i32>();
+
+        let mut builder = ListViewBuilder::new(Int64Builder::new());
+        builder.append_null();
+        builder.append(true);
+        builder.append_value([Some(10)]);
+        builder.append_value([Some(20), Some(30)]);
+        assert_eq!(result, &builder.finish());
+    }
+
     #[test]
     fn test_concat_struct_arrays() {
         let field = Arc::new(Field::new("field", DataType::Int64, true));
@@ -833,6 +1018,86 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid argument error: It is not possible to concatenate arrays of different data types.");
     }
 
+    #[test]
+    fn concat_record_batches_with_options() {
+        let metadata = |k: &str, v: &str| HashMap::from([(k.to_string(), v.to_string())]);
+        let schema1 = Schema::new(vec![
+            Field::new("a", DataType::Int32, false).with_metadata(metadata("f1", "x")),
+            Field::new_dictionary("b", DataType::Int8, DataType::Utf8, false),
+        ])
+        .with_metadata(metadata("k1", "v1"));
+        let schema2 = Schema::new(vec![
+            Field::new("a", DataType::Int32, true).with_metadata(metadata("f2", "y")),
+            Field::new_dictionary("b", DataType::Int8, DataType::Utf8, false),
+        ])
+        .with_metadata(metadata("k2", "v2"));
+
+        let dict1: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let dict2: DictionaryArray<Int8Type> = vec!["b", "c"].into_iter().collect();
+        let batch1 = RecordBatch::try_new(
+            Arc::new(schema1),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3])), Arc::new(dict1)],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::try_new(
+            Arc::new(schema2),
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(5)])),
+                Arc::new(dict2),
+            ],
+        )
+        .unwrap();
+
+        let err =
+            concat_batches_with_options([&batch1, &batch2], &ConcatOptions::new()).unwrap_err();
+        assert!(
+            err.to_string().contains("differing in nullability"),
+            "{err}"
+        );
+
+        let options = ConcatOptions::new().with_allow_nullability_mismatch(true);
+        let batch = concat_batches_with_options([&batch1, &batch2], &options).unwrap();
+        assert_eq!(batch.num_rows(), 5);
+        let schema = batch.schema();
+        assert_eq!(schema.metadata().len(), 2);
+        assert!(schema.field(0).is_nullable());
+        assert_eq!(schema.field(0).metadata().len(), 2);
+        assert!(!schema.field(1).is_nullable());
+        // Small dictionaries are not merged by default
+        let dict = batch.column(1).as_dictionary::<Int8Type>();
+        assert_eq!(dict.values().len(), 4);
+
+        let options = options.with_merge_dictionaries(true);
+        let batch = concat_batches_with_options([&batch1, &batch2], &options).unwrap();
+        let dict = batch.column(1).as_dictionary::<Int8Type>();
+        assert_eq!(dict.values().len(), 3);
+        let values: Vec<_> = dict
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            values,
+            vec![Some("a"), Some("b"), Some("a"), Some("b"), Some("c")]
+        );
+
+        // Conflicting metadata
+        let schema3 =
+            Schema::new(batch2.schema().fields().clone()).with_metadata(metadata("k1", "other"));
+        let batch3 = RecordBatch::try_new(Arc::new(schema3), batch2.columns().to_vec()).unwrap();
+        let err = concat_batches_with_options([&batch1, &batch3], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot concatenate batches with conflicting metadata for key \"k1\": \"v1\" and \"other\""
+        );
+
+        let err = concat_batches_with_options([], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: concat_batches_with_options requires at least one batch"
+        );
+    }
+
     #[test]
     fn concat_capacity() {
         let a = Int32Array::from_iter_values(0..100);
@@ -20,10 +20,14 @@ use ahash::RandomState;
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
-    ArrowDictionaryKeyType, BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, Utf8Type,
+    ArrowDictionaryKeyType, ArrowPrimitiveType, BinaryType, BinaryViewType, ByteArrayType,
+    ByteViewType, LargeBinaryType, LargeUtf8Type, StringViewType, Utf8Type,
 };
-use arrow_array::{Array, ArrayRef, DictionaryArray, GenericByteArray};
-use arrow_buffer::{ArrowNativeType, BooleanBuffer, ScalarBuffer};
+use arrow_array::{
+    downcast_primitive_array, Array, ArrayRef, DictionaryArray, GenericByteArray,
+    GenericByteViewArray, PrimitiveArray,
+};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, ScalarBuffer, ToByteSlice};
 use arrow_schema::{ArrowError, DataType};
 
 /// A best effort interner that maintains a fixed number of buckets
@@ -94,6 +98,13 @@ fn bytes_ptr_eq<T: ByteArrayType>(a: &dyn Array, b: &dyn Array) -> bool {
     }
 }
 
+/// Performs a cheap, pointer-based comparison of two arrays of any type
+///
+/// See [`ArrayData::ptr_eq`](arrow_data::ArrayData::ptr_eq)
+pub(crate) fn data_ptr_eq(a: &dyn Array, b: &dyn Array) -> bool {
+    a.to_data().ptr_eq(&b.to_data())
+}
+
 /// A type-erased function that compares two array for pointer equality
 type PtrEq = dyn Fn(&dyn Array, &dyn Array) -> bool;
 
@@ -113,6 +124,8 @@ pub fn should_merge_dictionary_values<K: ArrowDictionaryKeyType>(
         LargeUtf8 => Box::new(bytes_ptr_eq::<LargeUtf8Type>),
         Binary => Box::new(bytes_ptr_eq::<BinaryType>),
         LargeBinary => Box::new(bytes_ptr_eq::<LargeBinaryType>),
+        Utf8View | BinaryView => Box::new(data_ptr_eq),
+        d if d.is_primitive() => Box::new(data_ptr_eq),
         _ => return false,
     };
 
@@ -223,16 +236,50 @@ fn compute_values_mask<K: ArrowNativeType>(
 }
 
 /// Return a Vec containing for each set index in `mask`, the index and byte value of that index
+///
+/// For primitive arrays the byte value is the native representation of the value
 fn get_masked_values<'a>(array: &'a dyn Array, mask: &BooleanBuffer) -> Vec<(usize, &'a [u8])> {
-    match array.data_type() {
+    downcast_primitive_array! {
+        array => masked_primitive(array, mask),
         DataType::Utf8 => masked_bytes(array.as_string::<i32>(), mask),
         DataType::LargeUtf8 => masked_bytes(array.as_string::<i64>(), mask),
         DataType::Binary => masked_bytes(array.as_binary::<i32>(), mask),
         DataType::LargeBinary => masked_bytes(array.as_binary::<i64>(), mask),
+        DataType::Utf8View => masked_byte_view(array.as_byte_view::<StringViewType>(), mask),
+        DataType::BinaryView => masked_byte_view(array.as_byte_view::<BinaryViewType>(), mask),
         _ => unimplemented!(),
     }
 }
 
+/// Compute [`get_masked_values`] for a [`PrimitiveArray`]
+///
+/// Note: this does not check the null mask and will return values contained in null slots
+fn masked_primitive<'a, T: ArrowPrimitiveType>(
+    array: &'a PrimitiveArray<T>,
+    mask: &BooleanBuffer,
+) -> Vec<(usize, &'a [u8])> {
+    let values = array.values();
+    let mut out = Vec::with_capacity(mask.count_set_bits());
+    for idx in mask.set_indices() {
+        out.push((idx, values[idx].to_byte_slice()))
+    }
+    out
+}
+
+/// Compute [`get_masked_values`] for a [`GenericByteViewArray`]
+///
+/// Note: this does not check the null mask and will return values contained in null slots
+fn masked_byte_view<'a, T: ByteViewType>(
+    array: &'a GenericByteViewArray<T>,
+    mask: &BooleanBuffer,
+) -> Vec<(usize, &'a [u8])> {
+    let mut out = Vec::with_capacity(mask.count_set_bits());
+    for idx in mask.set_indices() {
+        out.push((idx, array.value(idx).as_ref()))
+    }
+    out
+}
+
 /// Compute [`get_masked_values`] for a [`GenericByteArray`]
 ///
 /// Note: this does not check the null mask and will return values contained in null slots
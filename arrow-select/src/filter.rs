@@ -20,6 +20,7 @@
 use std::ops::AddAssign;
 use std::sync::Arc;
 
+use crate::byte_view::compact_byte_view_array;
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
@@ -226,6 +227,7 @@ pub struct FilterBuilder {
     filter: BooleanArray,
     count: usize,
     strategy: IterationStrategy,
+    view_compaction_threshold: Option<f64>,
 }
 
 impl FilterBuilder {
@@ -243,9 +245,40 @@ impl FilterBuilder {
             filter,
             count,
             strategy,
+            view_compaction_threshold: None,
         }
     }
 
+    /// Compact the data buffers of filtered byte view arrays, i.e. [`StringViewArray`] and
+    /// [`BinaryViewArray`], including those nested within lists, structs and other nested
+    /// arrays, when the bytes referenced by the selected views are less than `threshold`
+    /// of the total length of the data buffers of the input
+    ///
+    /// By default the filtered views reference the data buffers of the input array, which
+    /// avoids copying the values, but for a selective filter can retain data buffers much
+    /// larger than the values that remain. See [`GenericByteViewArray::gc`]
+    ///
+    /// ```
+    /// # use arrow_array::{Array, BooleanArray, StringViewArray};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_select::filter::FilterBuilder;
+    /// let values: Vec<_> = (0..100).map(|i| format!("a long string value {i}")).collect();
+    /// let array = StringViewArray::from_iter_values(&values);
+    /// let mask = BooleanArray::from_iter((0..100).map(|i| Some(i == 42)));
+    ///
+    /// let predicate = FilterBuilder::new(&mask)
+    ///     .with_view_compaction_threshold(0.5)
+    ///     .build();
+    /// let filtered = predicate.filter(&array).unwrap();
+    /// let filtered = filtered.as_string_view();
+    /// assert_eq!(filtered.value(0), "a long string value 42");
+    /// assert!(filtered.get_buffer_memory_size() < array.get_buffer_memory_size());
+    /// ```
+    pub fn with_view_compaction_threshold(mut self, threshold: f64) -> Self {
+        self.view_compaction_threshold = Some(threshold);
+        self
+    }
+
     /// Compute an optimised representation of the provided `filter` mask that can be
     /// applied to an array more quickly.
     ///
@@ -272,6 +305,7 @@ impl FilterBuilder {
             filter: self.filter,
             count: self.count,
             strategy: self.strategy,
+            view_compaction_threshold: self.view_compaction_threshold,
         }
     }
 }
@@ -323,12 +357,17 @@ pub struct FilterPredicate {
     filter: BooleanArray,
     count: usize,
     strategy: IterationStrategy,
+    view_compaction_threshold: Option<f64>,
 }
 
 impl FilterPredicate {
     /// Selects rows from `values` based on this [`FilterPredicate`]
     pub fn filter(&self, values: &dyn Array) -> Result<ArrayRef, ArrowError> {
-        filter_array(values, self)
+        let filtered = filter_array(values, self)?;
+        Ok(match self.view_compaction_threshold {
+            Some(threshold) => compact_byte_view_array(filtered, threshold),
+            None => filtered,
+        })
     }
 
     /// Number of rows being selected based on this [`FilterPredicate`]
@@ -390,6 +429,12 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             DataType::Struct(_) => {
                 Ok(Arc::new(filter_struct(values.as_struct(), predicate)?))
             }
+            DataType::ListView(_) => {
+                Ok(Arc::new(filter_list_view::<i32>(values.as_list_view(), predicate)))
+            }
+            DataType::LargeListView(_) => {
+                Ok(Arc::new(filter_list_view::<i64>(values.as_list_view(), predicate)))
+            }
             DataType::Union(_, UnionMode::Sparse) => {
                 Ok(Arc::new(filter_sparse_union(values.as_union(), predicate)?))
             }
@@ -741,6 +786,28 @@ fn filter_byte_view<T: ByteViewType>(
     GenericByteViewArray::from(unsafe { builder.build_unchecked() })
 }
 
+/// `filter` implementation for list view arrays
+///
+/// As the values of a list view need not be contiguous, only the offsets and
+/// sizes are filtered, with the values of the output shared with `array`
+fn filter_list_view<OffsetSize: OffsetSizeTrait>(
+    array: &GenericListViewArray<OffsetSize>,
+    predicate: &FilterPredicate,
+) -> GenericListViewArray<OffsetSize> {
+    let mut builder = ArrayDataBuilder::new(array.data_type().clone())
+        .len(predicate.count)
+        .add_buffer(filter_native(array.offsets(), predicate))
+        .add_buffer(filter_native(array.sizes(), predicate))
+        .add_child_data(array.values().to_data());
+
+    if let Some((null_count, nulls)) = filter_null_mask(array.nulls(), predicate) {
+        builder = builder.null_count(null_count).null_bit_buffer(Some(nulls));
+    }
+
+    // SAFETY: offsets and sizes are filtered from a valid list view of the same values
+    GenericListViewArray::from(unsafe { builder.build_unchecked() })
+}
+
 fn filter_fixed_size_binary(
     array: &FixedSizeBinaryArray,
     predicate: &FilterPredicate,
@@ -1120,6 +1187,53 @@ mod tests {
         _test_filter_byte_view::<BinaryViewType>()
     }
 
+    #[test]
+    fn test_filter_byte_view_compaction() {
+        let values: Vec<_> = (0..1000)
+            .map(|i| format!("large payload {i:>20}"))
+            .collect();
+        let array = BinaryViewArray::from_iter_values(&values);
+        let data_len =
+            |a: &BinaryViewArray| a.data_buffers().iter().map(|b| b.len()).sum::<usize>();
+        let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 100 == 0)));
+
+        let filtered = filter(&array, &mask).unwrap();
+        assert_eq!(data_len(filtered.as_binary_view()), data_len(&array));
+
+        let predicate = FilterBuilder::new(&mask)
+            .with_view_compaction_threshold(0.5)
+            .build();
+        let filtered = predicate.filter(&array).unwrap();
+        let filtered = filtered.as_binary_view();
+        assert_eq!(data_len(filtered), 10 * values[0].len());
+        let expected = values.iter().step_by(100).map(|v| v.as_bytes());
+        assert_eq!(filtered, &BinaryViewArray::from_iter_values(expected));
+
+        // Compaction applies to the children of nested arrays
+        let field = Arc::new(Field::new("v", DataType::BinaryView, false));
+        let array = StructArray::new(vec![field].into(), vec![Arc::new(array)], None);
+        let filtered = predicate.filter(&array).unwrap();
+        let child = filtered.as_struct().column(0).as_binary_view();
+        assert_eq!(data_len(child), 10 * values[0].len());
+
+        let field = Arc::new(Field::new("v", DataType::BinaryView, false));
+        let offsets = arrow_buffer::OffsetBuffer::from_lengths(vec![1; 1000]);
+        let list = ListArray::new(field, offsets, array.column(0).clone(), None);
+        let filtered = predicate.filter(&list).unwrap();
+        let list_values = filtered.as_list::<i32>().values();
+        assert_eq!(data_len(list_values.as_binary_view()), 10 * values[0].len());
+        assert_eq!(filtered.as_ref(), &filter(&list, &mask).unwrap());
+
+        // Selecting most of the values does not compact
+        let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 10 != 0)));
+        let predicate = FilterBuilder::new(&mask)
+            .with_view_compaction_threshold(0.5)
+            .build();
+        let filtered = predicate.filter(&array).unwrap();
+        let child = filtered.as_struct().column(0).as_binary_view();
+        assert_eq!(data_len(child), 1000 * values[0].len());
+    }
+
     #[test]
     fn test_filter_fixed_binary() {
         let v1 = [1_u8, 2];
@@ -1367,6 +1481,26 @@ mod tests {
         assert_eq!(&make_array(expected), &result);
     }
 
+    #[test]
+    fn test_filter_list_view() {
+        let mut builder = LargeListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(0), Some(1), Some(2)]);
+        builder.append_value([Some(3), None]);
+        builder.append_null();
+        builder.append_value([Some(6)]);
+        let list = builder.finish();
+
+        let predicate = BooleanArray::from(vec![false, true, true, true]);
+        let result = filter(&list.slice(1, 3), &predicate.slice(1, 3)).unwrap();
+        let result = result.as_list_view::<i64>();
+
+        let mut builder = LargeListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(3), None]);
+        builder.append_null();
+        builder.append_value([Some(6)]);
+        assert_eq!(result, &builder.finish());
+    }
+
     #[test]
     fn test_slice_iterator_bits() {
         let filter_values = (0..64).map(|i| i == 1).collect::<Vec<bool>>();
@@ -17,7 +17,7 @@
 
 //! Interleave elements from multiple arrays
 
-use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
+use crate::dictionary::{data_ptr_eq, merge_dictionary_values, should_merge_dictionary_values};
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder, PrimitiveBuilder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
@@ -194,7 +194,23 @@ fn interleave_dictionaries<K: ArrowDictionaryKeyType>(
 ) -> Result<ArrayRef, ArrowError> {
     let dictionaries: Vec<_> = arrays.iter().map(|x| x.as_dictionary::<K>()).collect();
     if !should_merge_dictionary_values::<K>(&dictionaries, indices.len()) {
-        return interleave_fallback(arrays, indices);
+        let first = dictionaries[0].values();
+        let shared = dictionaries
+            .iter()
+            .skip(1)
+            .all(|d| data_ptr_eq(first.as_ref(), d.values().as_ref()));
+        if !shared {
+            return interleave_fallback(arrays, indices);
+        }
+
+        // All dictionaries share the same values, so only the keys need to be interleaved
+        let keys: Vec<&dyn Array> = dictionaries.iter().map(|d| d.keys() as _).collect();
+        let keys = interleave_primitive::<K>(&keys, indices, &K::DATA_TYPE)?;
+        // Safety: the keys were valid for the shared values
+        let array = unsafe {
+            DictionaryArray::new_unchecked(keys.as_primitive::<K>().clone(), first.clone())
+        };
+        return Ok(Arc::new(array));
     }
 
     let masks: Vec<_> = dictionaries
@@ -402,6 +418,60 @@ mod tests {
         assert_eq!(&collected, &["c", "c", "c"]);
     }
 
+    #[test]
+    fn test_interleave_dictionary_shared_values() {
+        let values: ArrayRef = Arc::new(StringArray::from_iter_values(["a", "b", "c"]));
+        let a = DictionaryArray::new(Int32Array::from(vec![0, 1, 2]), values.clone());
+        let b = DictionaryArray::new(Int32Array::from(vec![Some(2), None]), values.clone());
+
+        let array = interleave(&[&a, &b], &[(1, 0), (0, 1), (1, 1), (0, 0)]).unwrap();
+        let v = array.as_dictionary::<Int32Type>();
+        assert!(Arc::ptr_eq(v.values(), &values));
+        assert_eq!(
+            v.keys(),
+            &Int32Array::from(vec![Some(2), Some(1), None, Some(0)])
+        );
+    }
+
+    #[test]
+    fn test_interleave_dictionary_primitive_values() {
+        // Concatenating both dictionaries would overflow Int8 keys
+        let values_a = Int64Array::from_iter_values(0..100);
+        let a = DictionaryArray::new(Int8Array::from_iter_values(0..100), Arc::new(values_a));
+        let values_b = Int64Array::from_iter_values(50..150);
+        let b = DictionaryArray::new(Int8Array::from_iter_values(0..100), Arc::new(values_b));
+
+        let indices: Vec<_> = (0..60).flat_map(|i| [(0, i), (1, i)]).collect();
+        let array = interleave(&[&a, &b], &indices).unwrap();
+        let v = array.as_dictionary::<Int8Type>();
+        assert_eq!(v.values().len(), 110);
+
+        let typed = v.downcast_dict::<Int64Array>().unwrap();
+        let expected: Vec<_> = (0..60).flat_map(|i| [i, i + 50]).collect();
+        let actual: Vec<_> = typed.into_iter().map(Option::unwrap).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_interleave_dictionary_view_values() {
+        let a = DictionaryArray::new(
+            Int32Array::from(vec![0, 1, 0]),
+            Arc::new(StringViewArray::from(vec!["a long string value", "b"])),
+        );
+        let b = DictionaryArray::new(
+            Int32Array::from(vec![1, 0]),
+            Arc::new(StringViewArray::from(vec!["c", "a long string value"])),
+        );
+
+        let array = interleave(&[&a, &b], &[(0, 0), (1, 0), (0, 2)]).unwrap();
+        let v = array.as_dictionary::<Int32Type>();
+        assert_eq!(v.values().len(), 1);
+
+        let typed = v.downcast_dict::<StringViewArray>().unwrap();
+        let actual: Vec<_> = typed.into_iter().map(Option::unwrap).collect();
+        assert_eq!(actual, ["a long string value"; 3]);
+    }
+
     #[test]
     fn test_lists() {
         // [[1, 2], null, [3]]
@@ -18,6 +18,7 @@
 #![warn(missing_docs)]
 //! Arrow selection kernels
 
+mod byte_view;
 pub mod concat;
 mod dictionary;
 pub mod filter;
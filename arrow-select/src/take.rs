@@ -19,6 +19,7 @@
 
 use std::sync::Arc;
 
+use crate::byte_view::compact_byte_view_array;
 use arrow_array::builder::{BufferBuilder, UInt32Builder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
@@ -92,10 +93,14 @@ pub fn take(
             take_impl($values, &indices)
         }};
     }
-    downcast_integer! {
+    let taken = downcast_integer! {
         indices.data_type() => (helper, values, indices, options),
         d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
-    }
+    }?;
+    Ok(match options.view_compaction_threshold {
+        Some(threshold) => compact_byte_view_array(taken, threshold),
+        None => taken,
+    })
 }
 
 /// For each [ArrayRef] in the [`Vec<ArrayRef>`], take elements by index and create a new
@@ -215,6 +220,12 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         DataType::LargeList(_) => {
             Ok(Arc::new(take_list::<_, Int64Type>(values.as_list(), indices)?))
         }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view::<_, i32>(values.as_list_view(), indices)?))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view::<_, i64>(values.as_list_view(), indices)?))
+        }
         DataType::FixedSizeList(_, length) => {
             let values = values
                 .as_any()
@@ -351,6 +362,12 @@ pub struct TakeOptions {
     /// If enabled, an `ArrowError` is returned if the indices are out of bounds.
     /// If not enabled, and indices exceed bounds, the kernel will panic.
     pub check_bounds: bool,
+    /// If set, compact the data buffers of a taken [`StringViewArray`] or [`BinaryViewArray`],
+    /// including those nested within lists, structs and other nested arrays, when the bytes
+    /// referenced by its views are less than this fraction of the total length of its data
+    /// buffers, see [`GenericByteViewArray::gc`].
+    /// If not set, the taken views always reference the data buffers of `values`
+    pub view_compaction_threshold: Option<f64>,
 }
 
 #[inline(always)]
@@ -591,6 +608,35 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// `take` implementation for list view arrays
+///
+/// As the values of a list view need not be contiguous, only the offsets and
+/// sizes are taken, with the values of the output shared with `values`
+fn take_list_view<IndexType, OffsetSize>(
+    values: &GenericListViewArray<OffsetSize>,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<GenericListViewArray<OffsetSize>, ArrowError>
+where
+    IndexType: ArrowPrimitiveType,
+    OffsetSize: OffsetSizeTrait,
+{
+    let offsets = take_native(values.offsets(), indices);
+    let sizes = take_native(values.sizes(), indices);
+    let nulls = take_nulls(values.nulls(), indices);
+
+    let list_view_data = ArrayDataBuilder::new(values.data_type().clone())
+        .len(indices.len())
+        .nulls(nulls)
+        .add_buffer(offsets.into_inner())
+        .add_buffer(sizes.into_inner())
+        .add_child_data(values.values().to_data());
+
+    // SAFETY: offsets and sizes are taken from a valid list view of the same values
+    Ok(GenericListViewArray::from(unsafe {
+        list_view_data.build_unchecked()
+    }))
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -947,7 +993,7 @@ pub fn take_record_batch(
 mod tests {
     use super::*;
     use arrow_array::builder::*;
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
+    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, OffsetBuffer};
     use arrow_schema::{Field, Fields, TimeUnit, UnionFields};
 
     fn test_take_decimal_arrays(
@@ -1597,6 +1643,75 @@ mod tests {
         _test_byte_view::<BinaryViewType>()
     }
 
+    #[test]
+    fn test_take_byte_view_compaction() {
+        let values: Vec<_> = (0..1000)
+            .map(|i| format!("large payload {i:>20}"))
+            .collect();
+        let array = StringViewArray::from_iter_values(&values);
+        let data_len =
+            |a: &StringViewArray| a.data_buffers().iter().map(|b| b.len()).sum::<usize>();
+        let indices = UInt32Array::from(vec![Some(5), None, Some(998)]);
+
+        let taken = take(&array, &indices, None).unwrap();
+        assert_eq!(data_len(taken.as_string_view()), data_len(&array));
+
+        let options = TakeOptions {
+            view_compaction_threshold: Some(0.5),
+            ..Default::default()
+        };
+        let taken = take(&array, &indices, Some(options.clone())).unwrap();
+        let taken = taken.as_string_view();
+        assert_eq!(data_len(taken), 2 * values[0].len());
+        let expected =
+            StringViewArray::from(vec![Some(values[5].as_str()), None, Some(&values[998])]);
+        assert_eq!(taken, &expected);
+
+        // Selecting most of the values does not compact
+        let indices = UInt32Array::from_iter_values(0..900);
+        let taken = take(&array, &indices, Some(options)).unwrap();
+        assert_eq!(data_len(taken.as_string_view()), data_len(&array));
+    }
+
+    #[test]
+    fn test_take_nested_byte_view_compaction() {
+        let values: Vec<_> = (0..1000)
+            .map(|i| format!("large payload {i:>20}"))
+            .collect();
+        let strings = Arc::new(StringViewArray::from_iter_values(&values)) as ArrayRef;
+        let data_len = |a: &dyn Array| {
+            let a = a.as_string_view();
+            a.data_buffers().iter().map(|b| b.len()).sum::<usize>()
+        };
+        let total = data_len(&strings);
+
+        let field = Arc::new(Field::new("s", DataType::Utf8View, false));
+        let structs = StructArray::new(vec![field.clone()].into(), vec![strings.clone()], None);
+        let offsets = OffsetBuffer::from_lengths(vec![2; 500]);
+        let lists = ListArray::new(field, offsets, strings, None);
+
+        let indices = UInt32Array::from(vec![Some(5), None, Some(498)]);
+        let options = TakeOptions {
+            view_compaction_threshold: Some(0.5),
+            ..Default::default()
+        };
+
+        let taken = take(&structs, &indices, None).unwrap();
+        assert_eq!(data_len(taken.as_struct().column(0)), total);
+        let taken = take(&structs, &indices, Some(options.clone())).unwrap();
+        assert_eq!(data_len(taken.as_struct().column(0)), 2 * values[0].len());
+        assert_eq!(taken.as_ref(), &take(&structs, &indices, None).unwrap());
+
+        let taken = take(&lists, &indices, None).unwrap();
+        assert_eq!(data_len(taken.as_list::<i32>().values()), total);
+        let taken = take(&lists, &indices, Some(options)).unwrap();
+        assert_eq!(
+            data_len(taken.as_list::<i32>().values()),
+            4 * values[0].len()
+        );
+        assert_eq!(taken.as_ref(), &take(&lists, &indices, None).unwrap());
+    }
+
     macro_rules! test_take_list {
         ($offset_type:ty, $list_data_type:ident, $list_array_type:ident) => {{
             // Construct a value array, [[0,0,0], [-1,-2,-1], [], [2,3]]
@@ -1942,6 +2057,34 @@ mod tests {
         assert_eq!(&expected, &result);
     }
 
+    #[test]
+    fn test_take_list_view() {
+        // [[3, 4, 5], [0, 1], null, [5]] with out of order views
+        let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5]));
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let list = ListViewArray::new(
+            field,
+            ScalarBuffer::from(vec![3, 0, 0, 5]),
+            ScalarBuffer::from(vec![3, 2, 0, 1]),
+            values,
+            Some(NullBuffer::from(vec![true, true, false, true])),
+        );
+
+        let indices = UInt32Array::from(vec![Some(3), None, Some(0), Some(2), Some(1)]);
+        let result = take(&list, &indices, None).unwrap();
+        let result = result.as_list_view::<i32>();
+
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(5)]);
+        builder.append_null();
+        builder.append_value([Some(3), Some(4), Some(5)]);
+        builder.append_null();
+        builder.append_value([Some(0), Some(1)]);
+        assert_eq!(result, &builder.finish());
+        // values are shared with the input rather than copied
+        assert_eq!(result.values().len(), 6);
+    }
+
     #[test]
     fn test_take_struct() {
         let array = create_test_struct(vec![
@@ -2001,7 +2144,10 @@ mod tests {
     #[test]
     fn test_take_out_of_bounds() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(6)]);
-        let take_opt = TakeOptions { check_bounds: true };
+        let take_opt = TakeOptions {
+            check_bounds: true,
+            ..Default::default()
+        };
 
         // int64
         let result = test_take_primitive_arrays::<Int64Type>(
@@ -2052,7 +2198,14 @@ mod tests {
         let values = NullArray::new(5);
         let indices = UInt32Array::from(vec![Some(0), None, Some(15)]);
 
-        let result = take(&values, &indices, Some(TakeOptions { check_bounds: true }));
+        let result = take(
+            &values,
+            &indices,
+            Some(TakeOptions {
+                check_bounds: true,
+                ..Default::default()
+            }),
+        );
         assert_eq!(
             result.unwrap_err().to_string(),
             "Compute error: Array index out of bounds, cannot get item at index 15 from 5 entries"
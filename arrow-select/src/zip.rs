@@ -16,9 +16,12 @@
 // under the License.
 
 //! [`zip`]: Combine values from two arrays based on boolean mask
+//!
+//! [`case_when`]: Combine values from any number of arrays based on a list of boolean masks
 
 use crate::filter::SlicesIterator;
 use arrow_array::*;
+use arrow_buffer::BooleanBuffer;
 use arrow_data::transform::MutableArrayData;
 use arrow_schema::ArrowError;
 
@@ -166,9 +169,134 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Select values from `values` by the first matching condition in `conditions`,
+/// as in a SQL `CASE WHEN ... THEN ... ELSE ... END` expression
+///
+/// - Where `conditions[i]` is the first condition that is `true`, values of `values[i]` are taken
+/// - Where no condition is `true`, values of `else_value` are taken, or `NULL` if it is `None`
+///
+/// A `NULL` condition is treated as `false`. Each of `values` and `else_value` may be
+/// an array with the same length as `conditions`, or a [`Scalar`].
+///
+/// This is equivalent to nesting calls to [`zip`], but determines the branch of every
+/// row up front and then copies each row exactly once, without materializing an
+/// intermediate array per branch.
+///
+/// Returns an error if `conditions` is empty, `conditions` and `values` differ in length,
+/// or the arrays do not have matching lengths and data types
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Datum, Int32Array};
+/// # use arrow_select::zip::case_when;
+/// let c1 = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+/// let c2 = BooleanArray::from(vec![Some(true), Some(true), Some(true), Some(false)]);
+/// let v1 = Int32Array::from(vec![1, 2, 3, 4]);
+/// let v2 = Int32Array::from(vec![10, 20, 30, 40]);
+/// let otherwise = Int32Array::new_scalar(0);
+///
+/// let values: [&dyn Datum; 2] = [&v1, &v2];
+/// let result = case_when(&[c1, c2], &values, Some(&otherwise)).unwrap();
+/// let expected: ArrayRef = Arc::new(Int32Array::from(vec![1, 20, 30, 0]));
+/// assert_eq!(&result, &expected);
+/// ```
+pub fn case_when(
+    conditions: &[BooleanArray],
+    values: &[&dyn Datum],
+    else_value: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    if conditions.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "case_when requires at least one condition".into(),
+        ));
+    }
+    if conditions.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "case_when requires one value per condition, got {} conditions and {} values",
+            conditions.len(),
+            values.len()
+        )));
+    }
+
+    let len = conditions[0].len();
+    if conditions.iter().any(|c| c.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+
+    let mut sources = Vec::with_capacity(values.len() + 1);
+    for datum in values.iter().copied().chain(else_value) {
+        let (array, is_scalar) = datum.get();
+        if is_scalar && array.len() != 1 {
+            return Err(ArrowError::InvalidArgumentError(
+                "scalar arrays must have 1 element".into(),
+            ));
+        }
+        if !is_scalar && array.len() != len {
+            return Err(ArrowError::InvalidArgumentError(
+                "all arrays should have the same length".into(),
+            ));
+        }
+        sources.push((array.to_data(), is_scalar));
+    }
+
+    let data_type = sources[0].0.data_type();
+    if sources.iter().any(|(d, _)| d.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "arguments need to have the same data type".into(),
+        ));
+    }
+
+    // The branch selected by each row, rows matching no condition select `else_idx`
+    let else_idx = values.len();
+    let mut branches = vec![else_idx; len];
+    let mut remaining = BooleanBuffer::new_set(len);
+    for (idx, condition) in conditions.iter().enumerate() {
+        let matched = match condition.nulls() {
+            Some(n) => &(condition.values() & n.inner()) & &remaining,
+            None => condition.values() & &remaining,
+        };
+        if matched.count_set_bits() == 0 {
+            continue;
+        }
+        matched.set_indices().for_each(|row| branches[row] = idx);
+        remaining = &remaining & &!&matched;
+        if remaining.count_set_bits() == 0 {
+            break;
+        }
+    }
+
+    let arrays = sources.iter().map(|(d, _)| d).collect();
+    let mut mutable = MutableArrayData::new(arrays, else_value.is_none(), len);
+
+    let mut start = 0;
+    while start < len {
+        let branch = branches[start];
+        let end = branches[start..]
+            .iter()
+            .position(|b| *b != branch)
+            .map_or(len, |run| start + run);
+
+        match sources.get(branch) {
+            // Only the else branch can be missing, in which case the rows are null
+            None => mutable.extend_nulls(end - start),
+            Some((_, true)) => (start..end).for_each(|_| mutable.extend(branch, 0, 1)),
+            Some((_, false)) => mutable.extend(branch, start, end),
+        }
+        start = end;
+    }
+
+    let data = mutable.freeze();
+    Ok(make_array(data))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
 
     #[test]
     fn test_zip_kernel_one() {
@@ -279,4 +407,73 @@ mod test {
         let expected = Int32Array::from(vec![None, None, Some(42), Some(42), None]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_case_when() {
+        let c1 = BooleanArray::from(vec![Some(true), Some(false), None, Some(false), Some(true)]);
+        let c2 = BooleanArray::from(vec![Some(true), Some(false), Some(true), None, Some(false)]);
+        let c3 = BooleanArray::from(vec![false, true, true, false, false]);
+        let v1 = StringArray::from(vec![Some("a"), Some("b"), Some("c"), Some("d"), None]);
+        let v2 = Scalar::new(StringArray::from(vec!["two"]));
+        let v3 = StringArray::from(vec!["v", "w", "x", "y", "z"]);
+        let values: [&dyn Datum; 3] = [&v1, &v2, &v3];
+        let conditions = [c1, c2, c3];
+
+        let out = case_when(&conditions, &values, None).unwrap();
+        let expected = StringArray::from(vec![Some("a"), Some("w"), Some("two"), None, None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let otherwise = StringArray::from(vec!["e0", "e1", "e2", "e3", "e4"]);
+        let out = case_when(&conditions, &values, Some(&otherwise)).unwrap();
+        let expected = StringArray::from(vec![Some("a"), Some("w"), Some("two"), Some("e3"), None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let otherwise = Scalar::new(StringArray::from(vec!["else"]));
+        let out = case_when(&conditions[1..], &values[1..], Some(&otherwise)).unwrap();
+        let expected = StringArray::from(vec!["two", "w", "two", "else", "else"]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_case_when_sliced() {
+        let c1 = BooleanArray::from(vec![true, true, false, false, true, false]).slice(1, 4);
+        let v1 = Int32Array::from(vec![1, 2, 3, 4, 5, 6]).slice(2, 4);
+        let otherwise = Int32Array::from(vec![10, 20, 30, 40]);
+
+        let out = case_when(&[c1], &[&v1], Some(&otherwise)).unwrap();
+        let actual = out.as_primitive::<Int32Type>();
+        assert_eq!(actual, &Int32Array::from(vec![3, 20, 30, 6]));
+    }
+
+    #[test]
+    fn test_case_when_errors() {
+        let conditions = [BooleanArray::from(vec![true, false])];
+        let v1 = Int32Array::from(vec![1, 2]);
+
+        let err = case_when(&[], &[], Some(&v1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when requires at least one condition"
+        );
+
+        let err = case_when(&conditions, &[&v1, &v1], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when requires one value per condition, got 1 conditions and 2 values"
+        );
+
+        let short = Int32Array::from(vec![1]);
+        let err = case_when(&conditions, &[&v1], Some(&short)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length"
+        );
+
+        let strings = StringArray::from(vec!["a", "b"]);
+        let err = case_when(&conditions, &[&v1], Some(&strings)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+    }
 }
@@ -25,11 +25,12 @@ use arrow_array::builder::{
 };
 use arrow_array::cast::AsArray;
 use arrow_array::*;
-use arrow_buffer::NullBuffer;
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{ArrowError, DataType, Field};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
 use regex::Regex;
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -555,6 +556,265 @@ pub fn regexp_match(
     }
 }
 
+/// A cache of compiled regular expressions, keyed by pattern and flags
+///
+/// Compiling a regular expression is typically far more expensive than evaluating it,
+/// a [`RegexCache`] can therefore be retained across batches to avoid recompiling the
+/// same patterns, for use with kernels such as [`regexp_extract`] and [`regexp_replace`]
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::regexp::{regexp_replace, RegexCache};
+/// let mut cache = RegexCache::new();
+/// for batch in [vec!["foo=1"], vec!["bar=2", "baz"]] {
+///     let array = StringArray::from(batch);
+///     // The pattern is only compiled for the first batch
+///     let regex = cache.get(r"(\w+)=(\d+)", None).unwrap();
+///     regexp_replace(&array, regex, "$2=$1", true).unwrap();
+/// }
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegexCache {
+    patterns: HashMap<String, Regex>,
+}
+
+impl RegexCache {
+    /// Create a new, empty, [`RegexCache`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the compiled regular expression for `pattern` with optional `flags`,
+    /// compiling it if it is not already cached
+    ///
+    /// See the documentation [here](https://docs.rs/regex/1.5.4/regex/#grouping-and-flags)
+    /// for the supported flags
+    pub fn get(&mut self, pattern: &str, flags: Option<&str>) -> Result<&Regex, ArrowError> {
+        let pattern = match flags {
+            Some(flags) if !flags.is_empty() => format!("(?{flags}){pattern}"),
+            _ => pattern.to_string(),
+        };
+
+        match self.patterns.entry(pattern) {
+            Entry::Occupied(e) => Ok(e.into_mut()),
+            Entry::Vacant(e) => {
+                let re = Regex::new(e.key()).map_err(|e| {
+                    ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
+                })?;
+                Ok(e.insert(re))
+            }
+        }
+    }
+
+    /// Returns the number of cached regular expressions
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Returns true if no regular expressions are cached
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Removes all cached regular expressions
+    pub fn clear(&mut self) {
+        self.patterns.clear()
+    }
+}
+
+type StringIter<'a> = Box<dyn Iterator<Item = Option<&'a str>> + 'a>;
+
+/// Returns an iterator over the values of a Utf8, LargeUtf8 or Utf8View `array`
+fn string_iter<'a>(array: &'a dyn Array, kernel: &str) -> Result<StringIter<'a>, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Utf8 => Box::new(array.as_string::<i32>().iter()),
+        DataType::LargeUtf8 => Box::new(array.as_string::<i64>().iter()),
+        DataType::Utf8View => Box::new(array.as_string_view().iter()),
+        _ => {
+            return Err(ArrowError::ComputeError(format!(
+                "{kernel}() requires array to be either Utf8, Utf8View or LargeUtf8"
+            )))
+        }
+    })
+}
+
+/// Creates an array of `data_type`, one of Utf8, LargeUtf8 or Utf8View, from `iter`
+fn string_array_from_iter<P, I>(data_type: &DataType, iter: I) -> ArrayRef
+where
+    P: AsRef<str>,
+    I: Iterator<Item = Option<P>>,
+{
+    match data_type {
+        DataType::LargeUtf8 => Arc::new(LargeStringArray::from_iter(iter)),
+        DataType::Utf8View => Arc::new(StringViewArray::from_iter(iter)),
+        _ => Arc::new(StringArray::from_iter(iter)),
+    }
+}
+
+/// Returns the capture groups of `regex` to extract, and their corresponding fields
+///
+/// If `regex` has no capture groups, the whole match is extracted instead
+fn capture_fields(regex: &Regex, data_type: &DataType) -> (Vec<usize>, Fields) {
+    let groups: Vec<usize> = match regex.captures_len() {
+        1 => vec![0],
+        n => (1..n).collect(),
+    };
+    let names: Vec<_> = regex.capture_names().collect();
+    let fields = groups
+        .iter()
+        .map(|group| {
+            let name = names[*group].map(ToString::to_string);
+            let name = name.unwrap_or_else(|| group.to_string());
+            Field::new(name, data_type.clone(), true)
+        })
+        .collect();
+    (groups, fields)
+}
+
+/// Extract the capture groups of the leftmost-first match of `regex` in each string of `array`
+///
+/// Returns a [`StructArray`] with a child for each capture group of `regex`, named after
+/// the group if it is named, or otherwise its index, with the same string type as `array`.
+/// If `regex` contains no capture groups, the single child `"0"` contains the whole match.
+///
+/// A row is NULL if the corresponding string is NULL or `regex` does not match it. A child
+/// value is NULL if its capture group did not participate in the match.
+///
+/// # See Also
+/// * [`regexp_extract_all`] to extract the capture groups of all matches
+/// * [`RegexCache`] to reuse compiled regular expressions across batches
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::{regexp_extract, RegexCache};
+/// let array = StringArray::from(vec![Some("key=value"), Some("invalid"), None]);
+/// let mut cache = RegexCache::new();
+/// let regex = cache.get(r"(?P<key>\w+)=(\w+)", None).unwrap();
+///
+/// let result = regexp_extract(&array, regex).unwrap();
+/// assert_eq!(result.column_names(), vec!["key", "2"]);
+/// assert_eq!(result.column(0).as_string::<i32>().value(0), "key");
+/// assert_eq!(result.column(1).as_string::<i32>().value(0), "value");
+/// assert!(result.is_null(1));
+/// assert!(result.is_null(2));
+/// ```
+pub fn regexp_extract(array: &dyn Array, regex: &Regex) -> Result<StructArray, ArrowError> {
+    let values = string_iter(array, "regexp_extract")?;
+    let (groups, fields) = capture_fields(regex, array.data_type());
+
+    let mut columns = vec![Vec::with_capacity(array.len()); groups.len()];
+    let mut nulls = NullBufferBuilder::new(array.len());
+    let mut locations = regex.capture_locations();
+
+    for value in values {
+        let value = value.filter(|v| regex.captures_read(&mut locations, v).is_some());
+        nulls.append(value.is_some());
+        for (column, group) in columns.iter_mut().zip(&groups) {
+            let capture = value.and_then(|v| locations.get(*group).map(|(s, e)| &v[s..e]));
+            column.push(capture);
+        }
+    }
+
+    let columns = columns
+        .into_iter()
+        .map(|c| string_array_from_iter(array.data_type(), c.into_iter()))
+        .collect();
+    StructArray::try_new(fields, columns, nulls.finish())
+}
+
+/// Extract the capture groups of all non-overlapping matches of `regex` in each string of `array`
+///
+/// Returns a [`ListArray`] containing a [`StructArray`] for each match, with the same
+/// children as [`regexp_extract`]. A row is NULL if the corresponding string is NULL, and
+/// is an empty list if `regex` does not match it.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::{regexp_extract_all, RegexCache};
+/// let array = StringArray::from(vec![Some("a=1,b=2"), Some(""), None]);
+/// let mut cache = RegexCache::new();
+/// let regex = cache.get(r"(\w)=(\d)", None).unwrap();
+///
+/// let result = regexp_extract_all(&array, regex).unwrap();
+/// assert_eq!(result.value_offsets(), &[0, 2, 2, 2]);
+/// assert!(result.is_null(2));
+/// let matches = result.values().as_struct();
+/// assert_eq!(matches.column(0).as_string::<i32>().value(1), "b");
+/// assert_eq!(matches.column(1).as_string::<i32>().value(1), "2");
+/// ```
+pub fn regexp_extract_all(array: &dyn Array, regex: &Regex) -> Result<ListArray, ArrowError> {
+    let values = string_iter(array, "regexp_extract_all")?;
+    let (groups, fields) = capture_fields(regex, array.data_type());
+
+    let mut columns = vec![vec![]; groups.len()];
+    let mut lengths = Vec::with_capacity(array.len());
+    let mut nulls = NullBufferBuilder::new(array.len());
+
+    for value in values {
+        nulls.append(value.is_some());
+        let mut matches = 0;
+        for captures in value.into_iter().flat_map(|v| regex.captures_iter(v)) {
+            matches += 1;
+            for (column, group) in columns.iter_mut().zip(&groups) {
+                column.push(captures.get(*group).map(|m| m.as_str()));
+            }
+        }
+        lengths.push(matches);
+    }
+
+    let columns = columns
+        .into_iter()
+        .map(|c| string_array_from_iter(array.data_type(), c.into_iter()))
+        .collect();
+    let entries = StructArray::try_new(fields, columns, None)?;
+    let field = Arc::new(Field::new_list_field(entries.data_type().clone(), true));
+    ListArray::try_new(
+        field,
+        OffsetBuffer::from_lengths(lengths),
+        Arc::new(entries),
+        nulls.finish(),
+    )
+}
+
+/// Replace matches of `regex` in each string of `array` with `replacement`
+///
+/// If `global` is true all non-overlapping matches are replaced, otherwise only the
+/// leftmost-first match is replaced. `replacement` may refer to capture groups by
+/// index, e.g. `$1`, or by name, e.g. `${name}`, see [`Regex::replace`] for details.
+///
+/// Returns an array of the same type as `array`, which must be one of Utf8,
+/// LargeUtf8 or Utf8View.
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::{regexp_replace, RegexCache};
+/// let array = StringArray::from(vec![Some("2024-01-31"), None]);
+/// let mut cache = RegexCache::new();
+/// let regex = cache.get(r"(?P<y>\d+)-(?P<m>\d+)-(?P<d>\d+)", None).unwrap();
+///
+/// let result = regexp_replace(&array, regex, "${d}/${m}/${y}", false).unwrap();
+/// let result = result.as_string::<i32>();
+/// assert_eq!(result, &StringArray::from(vec![Some("31/01/2024"), None]));
+/// ```
+pub fn regexp_replace(
+    array: &dyn Array,
+    regex: &Regex,
+    replacement: &str,
+    global: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let values = string_iter(array, "regexp_replace")?;
+    let limit = if global { 0 } else { 1 };
+    let replaced = values.map(|v| v.map(|v| regex.replacen(v, limit, replacement)));
+    Ok(string_array_from_iter(array.data_type(), replaced))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,4 +1328,117 @@ mod tests {
         regexp_is_match_scalar::<StringViewArray>,
         [true, true, false, false]
     );
+
+    #[test]
+    fn test_regex_cache() {
+        let mut cache = RegexCache::new();
+        assert!(cache.is_empty());
+        let re = cache.get("^a", Some("i")).unwrap();
+        assert!(re.is_match("ABC"));
+        cache.get("^a", Some("i")).unwrap();
+        cache.get("^a", None).unwrap();
+        cache.get("^a", Some("")).unwrap();
+        assert_eq!(cache.len(), 2);
+
+        let err = cache.get("(", None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile"));
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_regexp_extract() {
+        let regex = Regex::new(r"(?P<name>[a-z]+)(\d+)?(?:-(\d+))?").unwrap();
+        let values = vec![Some("abc12-3"), Some("xyz"), Some("123"), None];
+
+        let array = StringArray::from(values.clone());
+        let result = regexp_extract(&array, &regex).unwrap();
+        assert_eq!(result.column_names(), vec!["name", "2", "3"]);
+        assert_eq!(result.nulls().unwrap().null_count(), 2);
+        assert_eq!(
+            result.column(0).as_string::<i32>(),
+            &StringArray::from(vec![Some("abc"), Some("xyz"), None, None])
+        );
+        assert_eq!(
+            result.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("12"), None, None, None])
+        );
+        assert_eq!(
+            result.column(2).as_string::<i32>(),
+            &StringArray::from(vec![Some("3"), None, None, None])
+        );
+
+        let array = StringViewArray::from(values.clone());
+        let result = regexp_extract(&array, &regex).unwrap();
+        assert_eq!(result.column(0).data_type(), &DataType::Utf8View);
+        assert_eq!(result.column(0).as_string_view().value(1), "xyz");
+
+        // Without capture groups the whole match is extracted
+        let array = LargeStringArray::from(values);
+        let regex = Regex::new(r"\d+").unwrap();
+        let result = regexp_extract(&array, &regex).unwrap();
+        assert_eq!(result.column_names(), vec!["0"]);
+        assert_eq!(
+            result.column(0).as_string::<i64>(),
+            &LargeStringArray::from(vec![Some("12"), None, Some("123"), None])
+        );
+
+        let err = regexp_extract(&Int32Array::from(vec![1]), &regex).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract() requires array to be either Utf8, Utf8View or LargeUtf8"
+        );
+    }
+
+    #[test]
+    fn test_regexp_extract_all() {
+        let regex = Regex::new(r"(\w+)=(\w+)?").unwrap();
+        let array = StringArray::from(vec![Some("a=1 b= c=3"), None, Some("none")]);
+        let result = regexp_extract_all(&array, &regex).unwrap();
+        assert_eq!(result.value_offsets(), &[0, 3, 3, 3]);
+        assert!(result.is_null(1));
+        assert!(result.is_valid(2));
+
+        let matches = result.values().as_struct();
+        assert_eq!(matches.column_names(), vec!["1", "2"]);
+        assert_eq!(
+            matches.column(0).as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            matches.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("1"), None, Some("3")])
+        );
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let regex = Regex::new(r"(?P<first>\w+)@(\w+)").unwrap();
+        let values = vec![Some("a@b c@d"), None, Some("no match")];
+
+        let array = StringArray::from(values.clone());
+        let result = regexp_replace(&array, &regex, "$2:${first}", true).unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec![Some("b:a d:c"), None, Some("no match")])
+        );
+
+        let array = LargeStringArray::from(values.clone());
+        let result = regexp_replace(&array, &regex, "$2:${first}", false).unwrap();
+        assert_eq!(
+            result.as_string::<i64>(),
+            &LargeStringArray::from(vec![Some("b:a c@d"), None, Some("no match")])
+        );
+
+        let array = StringViewArray::from(values);
+        let result = regexp_replace(&array, &regex, "$$", true).unwrap();
+        assert_eq!(
+            result.as_string_view(),
+            &StringViewArray::from(vec![Some("$ $"), None, Some("no match")])
+        );
+    }
 }
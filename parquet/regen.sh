@@ -17,7 +17,7 @@
 # specific language governing permissions and limitations
 # under the License.
 
-REVISION=5b564f3c47679526cf72e54f207013f28f53acc4
+REVISION=apache-parquet-format-2.11.0
 
 SOURCE_DIR="$(cd "$(dirname "${BASH_SOURCE[0]:-$0}")" && pwd)"
 
@@ -177,7 +177,11 @@ impl<I: OffsetSizeTrait> ColumnValueDecoder for ByteArrayColumnValueDecoder<I> {
     type Buffer = OffsetBuffer<I>;
 
     fn new(desc: &ColumnDescPtr) -> Self {
-        let validate_utf8 = desc.converted_type() == ConvertedType::UTF8;
+        // Enums, such as written for Avro enums, are annotated UTF-8 strings
+        let validate_utf8 = matches!(
+            desc.converted_type(),
+            ConvertedType::UTF8 | ConvertedType::ENUM
+        );
         Self {
             dict: None,
             decoder: None,
@@ -602,11 +606,37 @@ impl ByteArrayDecoderDictionary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::arrow::array_reader::test_util::{byte_array_all_encodings, utf8_column};
+    use crate::arrow::array_reader::test_util::{
+        byte_array_all_encodings, encode_byte_array, utf8_column,
+    };
     use crate::arrow::record_reader::buffer::ValuesBuffer;
+    use crate::data_type::ByteArray;
+    use crate::schema::types::{ColumnDescriptor, ColumnPath, Type};
     use arrow_array::{Array, StringArray};
     use arrow_buffer::Buffer;
 
+    #[test]
+    fn test_byte_array_decoder_enum() {
+        let t = Type::primitive_type_builder("col", crate::basic::Type::BYTE_ARRAY)
+            .with_converted_type(ConvertedType::ENUM)
+            .build()
+            .unwrap();
+        let column_desc = Arc::new(ColumnDescriptor::new(
+            Arc::new(t),
+            1,
+            0,
+            ColumnPath::new(vec![]),
+        ));
+        let mut decoder = ByteArrayColumnValueDecoder::<i32>::new(&column_desc);
+
+        let data = [ByteArray::from("a"), ByteArray::from(vec![0xFF, 0xFE])];
+        let page = encode_byte_array(Encoding::PLAIN, &data);
+        decoder.set_data(Encoding::PLAIN, page, 2, Some(2)).unwrap();
+        let mut output = OffsetBuffer::<i32>::default();
+        let err = decoder.read(&mut output, 2).unwrap_err();
+        assert!(err.to_string().contains("invalid utf-8"), "{err}");
+    }
+
     #[test]
     fn test_byte_array_decoder() {
         let (pages, encoded_dictionary) =
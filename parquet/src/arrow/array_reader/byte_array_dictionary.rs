@@ -225,9 +225,13 @@ where
     type Buffer = DictionaryBuffer<K, V>;
 
     fn new(col: &ColumnDescPtr) -> Self {
-        let validate_utf8 = col.converted_type() == ConvertedType::UTF8;
+        // Enums, such as written for Avro enums, are annotated UTF-8 strings
+        let validate_utf8 = matches!(
+            col.converted_type(),
+            ConvertedType::UTF8 | ConvertedType::ENUM
+        );
 
-        let value_type = match (V::IS_LARGE, col.converted_type() == ConvertedType::UTF8) {
+        let value_type = match (V::IS_LARGE, validate_utf8) {
             (true, true) => ArrowType::LargeUtf8,
             (true, false) => ArrowType::LargeBinary,
             (false, true) => ArrowType::Utf8,
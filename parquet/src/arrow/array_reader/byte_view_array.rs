@@ -136,7 +136,11 @@ impl ColumnValueDecoder for ByteViewArrayColumnValueDecoder {
     type Buffer = ViewBuffer;
 
     fn new(desc: &ColumnDescPtr) -> Self {
-        let validate_utf8 = desc.converted_type() == ConvertedType::UTF8;
+        // Enums, such as written for Avro enums, are annotated UTF-8 strings
+        let validate_utf8 = matches!(
+            desc.converted_type(),
+            ConvertedType::UTF8 | ConvertedType::ENUM
+        );
         Self {
             dict: None,
             decoder: None,
@@ -16,7 +16,12 @@
 // under the License.
 
 use crate::arrow::ProjectionMask;
-use arrow_array::{BooleanArray, RecordBatch};
+use crate::basic::Repetition;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::SchemaDescriptor;
+use arrow_array::cast::AsArray;
+use arrow_array::{make_array, Array, ArrayRef, BooleanArray, RecordBatch};
+use arrow_buffer::NullBuffer;
 use arrow_schema::ArrowError;
 
 /// A predicate operating on [`RecordBatch`]
@@ -70,6 +75,96 @@ where
     }
 }
 
+/// An [`ArrowPredicate`] evaluated on a single, possibly nested, column
+///
+/// The column is identified by its dot-separated path, e.g. `attributes.key` for the
+/// field `key` of the struct column `attributes`. Only the leaves of this column are
+/// decoded to evaluate the predicate, and `f` is passed the column as an [`ArrayRef`],
+/// which is null wherever any of its parents are null.
+///
+/// The path may not descend into a list or map, as the predicate must return
+/// a single value for each row
+pub struct ArrowColumnPredicateFn<F> {
+    f: F,
+    projection: ProjectionMask,
+    path: Vec<String>,
+}
+
+impl<F> ArrowColumnPredicateFn<F>
+where
+    F: FnMut(&ArrayRef) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    /// Create a new [`ArrowColumnPredicateFn`] for the column at `path` in `schema`
+    ///
+    /// Returns an error if the column does not exist, or is nested within a list or map
+    pub fn try_new(schema: &SchemaDescriptor, path: &str, f: F) -> Result<Self> {
+        let path: Vec<String> = path.split('.').map(|x| x.to_string()).collect();
+
+        let mut fields = schema.root_schema().get_fields();
+        for (idx, name) in path.iter().enumerate() {
+            let field = fields
+                .iter()
+                .find(|f| f.name() == name)
+                .ok_or_else(|| general_err!("Column \"{}\" not found", path.join(".")))?;
+
+            let info = field.get_basic_info();
+            if info.has_repetition() && info.repetition() == Repetition::REPEATED {
+                return Err(general_err!(
+                    "Column \"{}\" is nested within a repeated field",
+                    path.join(".")
+                ));
+            }
+            if idx + 1 < path.len() {
+                if !field.is_group() {
+                    return Err(general_err!("Column \"{}\" not found", path.join(".")));
+                }
+                fields = field.get_fields();
+            }
+        }
+
+        let leaves = (0..schema.num_columns()).filter(|idx| {
+            let column = schema.column(*idx);
+            let parts = column.path().parts();
+            parts.len() >= path.len() && parts.iter().zip(&path).all(|(a, b)| a == b)
+        });
+        let projection = ProjectionMask::leaves(schema, leaves);
+        Ok(Self {
+            f,
+            projection,
+            path,
+        })
+    }
+}
+
+impl<F> ArrowPredicate for ArrowColumnPredicateFn<F>
+where
+    F: FnMut(&ArrayRef) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        let not_found =
+            || ArrowError::SchemaError(format!("Column \"{}\" not found", self.path.join(".")));
+
+        let (root, children) = self.path.split_first().unwrap();
+        let mut array = batch.column_by_name(root).ok_or_else(not_found)?.clone();
+        for name in children {
+            let parent = array.as_struct_opt().ok_or_else(not_found)?;
+            let child = parent.column_by_name(name).ok_or_else(not_found)?;
+            array = match parent.nulls() {
+                Some(nulls) => {
+                    let nulls = NullBuffer::union(Some(nulls), child.logical_nulls().as_ref());
+                    make_array(child.to_data().into_builder().nulls(nulls).build()?)
+                }
+                None => child.clone(),
+            };
+        }
+        (self.f)(&array)
+    }
+}
+
 /// Filter applied *during* the parquet read process
 ///
 /// [`RowFilter`] applies predicates in order, after decoding only the columns
@@ -77,7 +172,8 @@ where
 /// may be required, thus potentially reducing IO and decode.
 ///
 /// A `RowFilter` consists of a list of [`ArrowPredicate`]s. Only the rows for which
-/// all the predicates evaluate to `true` will be returned.
+/// all the predicates evaluate to `true` will be returned. Predicates on a single,
+/// possibly nested, column can be created with [`ArrowColumnPredicateFn`].
 /// Any [`RowSelection`] provided to the reader will be applied prior
 /// to the first predicate, and each predicate in turn will then be used to compute
 /// a more refined [`RowSelection`] used when evaluating the subsequent predicates.
@@ -21,24 +21,32 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
-use arrow_array::Array;
-use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
+use arrow_array::types::*;
+use arrow_array::{make_array, Array};
+use arrow_array::{Datum, RecordBatch, RecordBatchReader};
+use arrow_buffer::i256;
+use arrow_cast::{can_cast_types, cast_with_options, CastOptions};
+use arrow_schema::{ArrowError, DataType as ArrowType, FieldRef, Fields, Schema, SchemaRef};
 use arrow_select::filter::prep_null_mask_filter;
-pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub use filter::{ArrowColumnPredicateFn, ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub(crate) use pruning::prune_row_groups;
+pub use pruning::{ColumnIndexSidecar, RowGroupPruner, RowGroupPrunerFn, SidecarRowGroupPruner};
 pub use selection::{RowSelection, RowSelector};
 
 pub use crate::arrow::array_reader::RowGroups;
 use crate::arrow::array_reader::{build_array_reader, ArrayReader};
 use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
 use crate::arrow::{parquet_to_arrow_field_levels, FieldLevels, ProjectionMask};
+use crate::basic::Type as PhysicalType;
+use crate::bloom_filter::Sbbf;
 use crate::column::page::{PageIterator, PageReader};
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 use crate::file::reader::{ChunkReader, SerializedPageReader};
-use crate::schema::types::SchemaDescriptor;
+use crate::schema::types::{ColumnDescriptor, SchemaDescriptor};
 
 mod filter;
+mod pruning;
 mod selection;
 pub mod statistics;
 
@@ -63,6 +71,8 @@ pub struct ArrowReaderBuilder<T> {
 
     pub(crate) row_groups: Option<Vec<usize>>,
 
+    pub(crate) row_group_pruner: Option<Box<dyn RowGroupPruner>>,
+
     pub(crate) projection: ProjectionMask,
 
     pub(crate) filter: Option<RowFilter>,
@@ -83,6 +93,7 @@ impl<T> ArrowReaderBuilder<T> {
             fields: metadata.fields,
             batch_size: 1024,
             row_groups: None,
+            row_group_pruner: None,
             projection: ProjectionMask::all(),
             filter: None,
             selection: None,
@@ -124,6 +135,21 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Provide a [`RowGroupPruner`] to skip row groups that need not be read
+    ///
+    /// The pruner is evaluated when the reader is built, against the row groups selected
+    /// by [`Self::with_row_groups`], or all row groups if none were selected. Rows of
+    /// pruned row groups are removed from any [`RowSelection`] provided with
+    /// [`Self::with_row_selection`].
+    ///
+    /// See [`ColumnIndexSidecar`] for pruning using statistics stored outside the file
+    pub fn with_row_group_pruner(self, pruner: impl RowGroupPruner) -> Self {
+        Self {
+            row_group_pruner: Some(Box::new(pruner)),
+            ..self
+        }
+    }
+
     /// Only read data from the provided column indexes
     pub fn with_projection(self, mask: ProjectionMask) -> Self {
         Self {
@@ -240,6 +266,26 @@ impl<T> ArrowReaderBuilder<T> {
     }
 }
 
+/// Returns `field` with any `Utf8` or `LargeUtf8` data types it contains replaced
+/// by a dictionary with `Int32` keys, see [`ArrowReaderOptions::with_string_dictionary`]
+fn dictionary_strings(field: &FieldRef) -> FieldRef {
+    let data_type = match field.data_type() {
+        ArrowType::Utf8 | ArrowType::LargeUtf8 => ArrowType::Dictionary(
+            Box::new(ArrowType::Int32),
+            Box::new(field.data_type().clone()),
+        ),
+        ArrowType::List(f) => ArrowType::List(dictionary_strings(f)),
+        ArrowType::LargeList(f) => ArrowType::LargeList(dictionary_strings(f)),
+        ArrowType::FixedSizeList(f, size) => ArrowType::FixedSizeList(dictionary_strings(f), *size),
+        ArrowType::Struct(fields) => {
+            ArrowType::Struct(fields.iter().map(dictionary_strings).collect())
+        }
+        ArrowType::Map(f, sorted) => ArrowType::Map(dictionary_strings(f), *sorted),
+        _ => return field.clone(),
+    };
+    Arc::new(field.as_ref().clone().with_data_type(data_type))
+}
+
 /// Options that control how metadata is read for a parquet file
 ///
 /// See [`ArrowReaderBuilder`] for how to configure how the column data
@@ -252,6 +298,8 @@ pub struct ArrowReaderOptions {
     supplied_schema: Option<SchemaRef>,
     /// If true, attempt to read `OffsetIndex` and `ColumnIndex`
     pub(crate) page_index: bool,
+    /// If true, read all string columns as dictionaries
+    string_dictionary: bool,
 }
 
 impl ArrowReaderOptions {
@@ -342,6 +390,25 @@ impl ArrowReaderOptions {
     pub fn with_page_index(self, page_index: bool) -> Self {
         Self { page_index, ..self }
     }
+
+    /// If `true`, read all `Utf8` and `LargeUtf8` columns, including those nested within
+    /// lists, structs and maps, as [`DictionaryArray`] with `Int32` keys. Defaults to `false`
+    ///
+    /// Otherwise only columns with a dictionary type in the embedded arrow schema, or in
+    /// the schema provided with [`Self::with_schema`], are read as dictionaries.
+    ///
+    /// The dictionary pages of the parquet file are preserved where possible, with values
+    /// read from pages that have fallen back to another encoding, or from a different
+    /// dictionary, merged into a new dictionary for each batch. This can considerably reduce
+    /// the memory used to read columns with many repeated strings.
+    ///
+    /// [`DictionaryArray`]: arrow_array::DictionaryArray
+    pub fn with_string_dictionary(self, string_dictionary: bool) -> Self {
+        Self {
+            string_dictionary,
+            ..self
+        }
+    }
 }
 
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
@@ -393,7 +460,7 @@ impl ArrowReaderMetadata {
     /// This function does not attempt to load the PageIndex if not present in the metadata.
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
-        match options.supplied_schema {
+        let metadata = match options.supplied_schema {
             Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
@@ -413,7 +480,36 @@ impl ArrowReaderMetadata {
                     fields: fields.map(Arc::new),
                 })
             }
+        }?;
+        match options.string_dictionary {
+            true => metadata.with_string_dictionary(),
+            false => Ok(metadata),
+        }
+    }
+
+    /// Replaces the `Utf8` and `LargeUtf8` types in the schema with dictionaries,
+    /// see [`ArrowReaderOptions::with_string_dictionary`]
+    fn with_string_dictionary(self) -> Result<Self> {
+        if self.fields.is_none() {
+            return Ok(self);
         }
+        let hint: Fields = self
+            .schema
+            .fields()
+            .iter()
+            .map(dictionary_strings)
+            .collect();
+        let levels = parquet_to_arrow_field_levels(
+            self.metadata.file_metadata().schema_descr(),
+            ProjectionMask::all(),
+            Some(&hint),
+        )?;
+        let schema = Schema::new_with_metadata(levels.fields, self.schema.metadata().clone());
+        Ok(Self {
+            schema: Arc::new(schema),
+            fields: levels.levels.map(Arc::new),
+            ..self
+        })
     }
 
     fn with_supplied_schema(
@@ -572,6 +668,63 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         Self::new_builder(SyncReader(input), metadata)
     }
 
+    /// Returns, for each row group, whether it may contain `value` in the leaf column with
+    /// the dot-separated path `column`, as determined by the bloom filter of the column chunk
+    ///
+    /// Row groups without a bloom filter for the column may contain any value. The result can
+    /// be used with [`Self::with_row_groups`] to skip row groups when looking up a value.
+    ///
+    /// `value` must be a single non-null value of the arrow type the column was written from,
+    /// and is encoded as the [`ArrowWriter`](crate::arrow::ArrowWriter) encodes it. Decimals
+    /// are rescaled to the scale of the column. If `value` cannot be converted exactly to the
+    /// type of the column, e.g. it is out of range, every row group may contain it
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{RecordBatch, StringArray};
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let mut file: Vec<u8> = Vec::with_capacity(1024);
+    /// # let col = StringArray::from_iter_values(["a", "b", "c", "d"]);
+    /// # let batch = RecordBatch::try_from_iter([("name", Arc::new(col) as _)]).unwrap();
+    /// # let props = WriterProperties::builder()
+    /// #     .set_bloom_filter_enabled(true)
+    /// #     .set_max_row_group_size(2)
+    /// #     .build();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+    ///
+    /// let value = StringArray::new_scalar("c");
+    /// let contains = builder.row_group_bloom_contains("name", &value).unwrap();
+    /// assert_eq!(contains, vec![false, true]);
+    ///
+    /// let row_groups = (0..contains.len()).filter(|idx| contains[*idx]).collect();
+    /// let reader = builder.with_row_groups(row_groups).build().unwrap();
+    /// ```
+    pub fn row_group_bloom_contains(&self, column: &str, value: &dyn Datum) -> Result<Vec<bool>> {
+        let schema = self.metadata.file_metadata().schema_descr();
+        let idx = (0..schema.num_columns())
+            .find(|idx| schema.column(*idx).path().string() == column)
+            .ok_or_else(|| general_err!("Column \"{}\" not found", column))?;
+        let Some(value) = bloom_filter_value(&schema.column(idx), value.get().0)? else {
+            return Ok(vec![true; self.metadata.num_row_groups()]);
+        };
+
+        self.metadata
+            .row_groups()
+            .iter()
+            .map(|row_group| {
+                let sbbf = Sbbf::read_from_column_chunk(row_group.column(idx), &self.input.0)?;
+                Ok(sbbf.map_or(true, |sbbf| sbbf.check(&value)))
+            })
+            .collect()
+    }
+
     /// Build a [`ParquetRecordBatchReader`]
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
@@ -585,6 +738,17 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .row_groups
             .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
 
+        let mut selection = self.selection;
+        let row_groups = match self.row_group_pruner {
+            Some(mut pruner) => {
+                let pruned =
+                    prune_row_groups(pruner.as_mut(), &self.metadata, row_groups, selection)?;
+                selection = pruned.1;
+                pruned.0
+            }
+            None => row_groups,
+        };
+
         let reader = ReaderRowGroups {
             reader: Arc::new(self.input.0),
             metadata: self.metadata,
@@ -592,7 +756,6 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         };
 
         let mut filter = self.filter;
-        let mut selection = self.selection;
 
         if let Some(filter) = filter.as_mut() {
             for predicate in filter.predicates.iter_mut() {
@@ -627,6 +790,145 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     }
 }
 
+/// Returns the bytes of the single value in `value`, as inserted by the
+/// [`ArrowWriter`](crate::arrow::ArrowWriter) into the bloom filter of a column described by `descr`,
+/// or `None` if `value` cannot be converted exactly to the type of the column
+fn bloom_filter_value(descr: &ColumnDescriptor, value: &dyn Array) -> Result<Option<Vec<u8>>> {
+    if value.len() != 1 || value.is_null(0) {
+        return Err(general_err!(
+            "Bloom filter lookup requires a single non-null value"
+        ));
+    }
+    let value = match value.as_any_dictionary_opt() {
+        Some(dictionary) => {
+            let key = dictionary.normalized_keys()[0];
+            dictionary.values().slice(key, 1)
+        }
+        None => make_array(value.to_data()),
+    };
+    let unsupported = || {
+        ParquetError::NYI(format!(
+            "Bloom filter lookup of {} in {} column {}",
+            value.data_type(),
+            descr.physical_type(),
+            descr.path()
+        ))
+    };
+    // Casts `value` to `to`, returning `None` if the value cannot be converted
+    let checked_cast = |to: &ArrowType| {
+        if !can_cast_types(value.data_type(), to) {
+            return Err(unsupported());
+        }
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        Ok(cast_with_options(&value, to, &options).ok())
+    };
+    // The unscaled value of a decimal `value` in the scale of the column, `None`
+    // if it cannot be represented exactly in that scale
+    let decimal = match value.data_type() {
+        ArrowType::Decimal128(_, scale) => {
+            let v = value.as_primitive::<Decimal128Type>().value(0);
+            Some(rescale_decimal(i256::from_i128(v), *scale, descr))
+        }
+        ArrowType::Decimal256(_, scale) => {
+            let v = value.as_primitive::<Decimal256Type>().value(0);
+            Some(rescale_decimal(v, *scale, descr))
+        }
+        _ => None,
+    };
+
+    let encoded = match descr.physical_type() {
+        PhysicalType::BOOLEAN => {
+            let value = value.as_boolean_opt().ok_or_else(unsupported)?;
+            Some(vec![value.value(0) as u8])
+        }
+        PhysicalType::INT32 => {
+            let value = match (value.data_type(), decimal) {
+                (_, Some(decimal)) => decimal
+                    .and_then(|v| v.to_i128())
+                    .and_then(|v| i32::try_from(v).ok()),
+                (ArrowType::UInt32, _) => Some(value.as_primitive::<UInt32Type>().value(0) as i32),
+                (ArrowType::Date64, _) => checked_cast(&ArrowType::Date32)?
+                    .map(|v| v.as_primitive::<Date32Type>().value(0)),
+                _ => {
+                    checked_cast(&ArrowType::Int32)?.map(|v| v.as_primitive::<Int32Type>().value(0))
+                }
+            };
+            value.map(|v| v.to_le_bytes().to_vec())
+        }
+        PhysicalType::INT64 => {
+            let value = match (value.data_type(), decimal) {
+                (_, Some(decimal)) => decimal
+                    .and_then(|v| v.to_i128())
+                    .and_then(|v| i64::try_from(v).ok()),
+                (ArrowType::UInt64, _) => Some(value.as_primitive::<UInt64Type>().value(0) as i64),
+                _ => {
+                    checked_cast(&ArrowType::Int64)?.map(|v| v.as_primitive::<Int64Type>().value(0))
+                }
+            };
+            value.map(|v| v.to_le_bytes().to_vec())
+        }
+        PhysicalType::FLOAT => {
+            let value = value
+                .as_primitive_opt::<Float32Type>()
+                .ok_or_else(unsupported)?;
+            Some(value.value(0).to_le_bytes().to_vec())
+        }
+        PhysicalType::DOUBLE => {
+            let value = value
+                .as_primitive_opt::<Float64Type>()
+                .ok_or_else(unsupported)?;
+            Some(value.value(0).to_le_bytes().to_vec())
+        }
+        PhysicalType::BYTE_ARRAY => Some(match value.data_type() {
+            ArrowType::Utf8 => value.as_string::<i32>().value(0).as_bytes().to_vec(),
+            ArrowType::LargeUtf8 => value.as_string::<i64>().value(0).as_bytes().to_vec(),
+            ArrowType::Utf8View => value.as_string_view().value(0).as_bytes().to_vec(),
+            ArrowType::Binary => value.as_binary::<i32>().value(0).to_vec(),
+            ArrowType::LargeBinary => value.as_binary::<i64>().value(0).to_vec(),
+            ArrowType::BinaryView => value.as_binary_view().value(0).to_vec(),
+            _ => return Err(unsupported()),
+        }),
+        PhysicalType::FIXED_LEN_BYTE_ARRAY => {
+            let length = descr.type_length() as usize;
+            match (value.data_type(), decimal) {
+                (ArrowType::FixedSizeBinary(_), _) => {
+                    Some(value.as_fixed_size_binary().value(0).to_vec())
+                }
+                // The big-endian two's complement value, sign extended to `length`
+                (_, Some(decimal)) if length <= 32 => decimal.and_then(|v| {
+                    let bytes = v.to_be_bytes();
+                    let (extension, bytes) = bytes.split_at(32 - length);
+                    let sign = if v.is_negative() { 0xFF } else { 0 };
+                    let fits = extension.iter().all(|b| *b == sign)
+                        && bytes.first().map_or(true, |b| (b ^ sign) & 0x80 == 0);
+                    fits.then(|| bytes.to_vec())
+                }),
+                (ArrowType::Float16, _) => {
+                    let value = value.as_primitive::<Float16Type>().value(0);
+                    Some(value.to_le_bytes().to_vec())
+                }
+                _ => return Err(unsupported()),
+            }
+        }
+        PhysicalType::INT96 => return Err(unsupported()),
+    };
+    Ok(encoded)
+}
+
+/// Returns the unscaled decimal `value` with `scale` rescaled to the scale of the
+/// column `descr`, or `None` if it cannot be represented exactly
+fn rescale_decimal(value: i256, scale: i8, descr: &ColumnDescriptor) -> Option<i256> {
+    let diff = descr.type_scale().max(0) - scale as i32;
+    let factor = i256::from_i128(10).checked_pow(diff.unsigned_abs())?;
+    match diff >= 0 {
+        true => value.checked_mul(factor),
+        false => (value.checked_rem(factor)? == i256::ZERO).then(|| value / factor),
+    }
+}
+
 struct ReaderRowGroups<T: ChunkReader> {
     reader: Arc<T>,
 
@@ -944,8 +1246,9 @@ mod tests {
     use arrow_select::concat::concat_batches;
 
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReader,
-        ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+        bloom_filter_value, ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions,
+        ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder, RowFilter, RowSelection,
+        RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
     use crate::arrow::{ArrowWriter, ProjectionMask};
@@ -959,7 +1262,7 @@ mod tests {
     use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
     use crate::file::writer::SerializedFileWriter;
     use crate::schema::parser::parse_message_type;
-    use crate::schema::types::{Type, TypePtr};
+    use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
     use crate::util::test_common::rand_gen::RandGen;
 
     #[test]
@@ -1698,6 +2001,199 @@ mod tests {
         assert_eq!(&written.slice(6, 1), &read[2]);
     }
 
+    #[test]
+    fn test_row_group_bloom_contains() {
+        let strings = ["a", "b", "c", "d"];
+        let dict = Int32DictionaryArray::new(
+            Int32Array::from(vec![0, 1, 0, 1]),
+            Arc::new(StringViewArray::from_iter_values(["x", "y"])),
+        );
+        let decimals = |precision, scale| {
+            Decimal128Array::from(vec![100, 200, 300, 400])
+                .with_precision_and_scale(precision, scale)
+                .unwrap()
+        };
+        let decimal256 = Decimal256Array::from_iter_values((1..5).map(i256::from_i128))
+            .with_precision_and_scale(40, 2)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "utf8_view",
+                Arc::new(StringViewArray::from_iter_values(strings)) as ArrayRef,
+            ),
+            (
+                "binary_view",
+                Arc::new(BinaryViewArray::from_iter_values(strings)) as ArrayRef,
+            ),
+            ("dict_view", Arc::new(dict) as ArrayRef),
+            ("decimal_32", Arc::new(decimals(5, 2)) as ArrayRef),
+            ("decimal_64", Arc::new(decimals(15, 2)) as ArrayRef),
+            ("decimal_fixed", Arc::new(decimals(30, 2)) as ArrayRef),
+            ("decimal_256", Arc::new(decimal256) as ArrayRef),
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut props = WriterProperties::builder().set_max_row_group_size(2);
+        for field in batch.schema().fields().iter().filter(|f| f.name() != "int") {
+            props = props.set_column_bloom_filter_enabled(field.name().as_str().into(), true);
+        }
+        let props = props.build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let contains =
+            |column, value: &dyn Datum| builder.row_group_bloom_contains(column, value).unwrap();
+
+        let decimal = |v, precision, scale| {
+            Scalar::new(
+                Decimal128Array::from(vec![v])
+                    .with_precision_and_scale(precision, scale)
+                    .unwrap(),
+            )
+        };
+
+        let view = StringViewArray::new_scalar("c");
+        assert_eq!(contains("utf8_view", &view), vec![false, true]);
+        let binary = BinaryViewArray::new_scalar(b"a");
+        assert_eq!(contains("binary_view", &binary), vec![true, false]);
+        assert_eq!(contains("dict_view", &view), vec![false, false]);
+        let dict_value = Scalar::new(Int32DictionaryArray::new(
+            Int32Array::from(vec![0]),
+            Arc::new(StringViewArray::from_iter_values(["y"])),
+        ));
+        assert_eq!(contains("dict_view", &dict_value), vec![true, true]);
+        assert_eq!(
+            contains("decimal_32", &decimal(300, 5, 2)),
+            vec![false, true]
+        );
+        assert_eq!(
+            contains("decimal_64", &decimal(100, 15, 2)),
+            vec![true, false]
+        );
+        assert_eq!(
+            contains("decimal_fixed", &decimal(400, 30, 2)),
+            vec![false, true]
+        );
+        assert_eq!(
+            contains("decimal_fixed", &decimal(500, 30, 2)),
+            vec![false, false]
+        );
+        // Decimals in a different scale are rescaled to the scale of the column
+        assert_eq!(
+            contains("decimal_32", &decimal(3000, 9, 3)),
+            vec![false, true]
+        );
+        assert_eq!(
+            contains("decimal_fixed", &decimal(4, 30, 0)),
+            vec![false, true]
+        );
+        // Any row group may contain a value that is not representable in the column
+        assert_eq!(
+            contains("decimal_64", &decimal(1001, 15, 3)),
+            vec![true, true]
+        );
+        let decimal256 = Scalar::new(
+            Decimal256Array::from_iter_values([i256::from_i128(2)])
+                .with_precision_and_scale(40, 2)
+                .unwrap(),
+        );
+        assert_eq!(contains("decimal_256", &decimal256), vec![true, false]);
+
+        // Without a bloom filter any row group may contain the value
+        assert_eq!(
+            contains("int", &Int32Array::new_scalar(7)),
+            vec![true, true]
+        );
+
+        let err = builder
+            .row_group_bloom_contains("missing", &Int32Array::new_scalar(1))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"missing\" not found"
+        );
+
+        let err = builder
+            .row_group_bloom_contains("utf8_view", &Int32Array::new_scalar(1))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Bloom filter lookup of Int32 in BYTE_ARRAY column \"utf8_view\""
+        );
+    }
+
+    #[test]
+    fn test_bloom_filter_value_decimal_length() {
+        let message = "
+            message schema {
+                REQUIRED FIXED_LEN_BYTE_ARRAY (16) d16 (DECIMAL(38, 2));
+                REQUIRED FIXED_LEN_BYTE_ARRAY (17) d17 (DECIMAL(40, 2));
+                REQUIRED FIXED_LEN_BYTE_ARRAY (33) d33 (DECIMAL(76, 2));
+            }
+        ";
+        let schema = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let decimal128 = Decimal128Array::from(vec![-1])
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        let decimal256 = Decimal256Array::from(vec![i256::MINUS_ONE])
+            .with_precision_and_scale(76, 2)
+            .unwrap();
+
+        let value = bloom_filter_value(&schema.column(0), &decimal128).unwrap();
+        assert_eq!(value, Some(vec![0xFF; 16]));
+
+        let value = bloom_filter_value(&schema.column(1), &decimal128).unwrap();
+        assert_eq!(value, Some(vec![0xFF; 17]));
+
+        let err = bloom_filter_value(&schema.column(2), &decimal256).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Bloom filter lookup of Decimal256(76, 2) in FIXED_LEN_BYTE_ARRAY column \"d33\""
+        );
+    }
+
+    #[test]
+    fn test_bloom_filter_value_conversion() {
+        let message = "
+            message schema {
+                REQUIRED INT32 i32;
+                REQUIRED INT32 d32 (DECIMAL(9, 2));
+                REQUIRED FIXED_LEN_BYTE_ARRAY (4) d4 (DECIMAL(9, 2));
+            }
+        ";
+        let schema = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let decimal = |v: i128, scale: i8| {
+            Decimal128Array::from(vec![v])
+                .with_precision_and_scale(38, scale)
+                .unwrap()
+        };
+
+        // Decimals are rescaled to the scale of the column
+        let value = bloom_filter_value(&schema.column(1), &decimal(3000, 3)).unwrap();
+        assert_eq!(value, Some(300_i32.to_le_bytes().to_vec()));
+        let value = bloom_filter_value(&schema.column(1), &decimal(3, 1)).unwrap();
+        assert_eq!(value, Some(30_i32.to_le_bytes().to_vec()));
+        let value = bloom_filter_value(&schema.column(2), &decimal(-3, 0)).unwrap();
+        assert_eq!(value, Some((-300_i32).to_be_bytes().to_vec()));
+
+        // Values that cannot be represented exactly in the column
+        let value = bloom_filter_value(&schema.column(1), &decimal(3001, 3)).unwrap();
+        assert_eq!(value, None);
+        let value = bloom_filter_value(&schema.column(1), &decimal(i32::MAX as i128 + 1, 2));
+        assert_eq!(value.unwrap(), None);
+        let value = bloom_filter_value(&schema.column(2), &decimal(i32::MIN as i128 - 1, 2));
+        assert_eq!(value.unwrap(), None);
+        let value = bloom_filter_value(&schema.column(0), &Int64Array::from(vec![1 << 40]));
+        assert_eq!(value.unwrap(), None);
+    }
+
     #[test]
     fn test_read_decimal_file() {
         use arrow_array::Decimal128Array;
@@ -2927,6 +3423,78 @@ mod tests {
         reader.collect()
     }
 
+    #[test]
+    fn test_string_dictionary() {
+        // Enough distinct values for the dictionary to fall back to plain encoding
+        let strings: Vec<_> = (0..100).map(|i| format!("value {}", i % 50)).collect();
+        let strings = StringArray::from_iter(
+            strings
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (i % 7 != 0).then_some(s.as_str())),
+        );
+        let mut list = ListBuilder::new(StringBuilder::new());
+        for i in 0..100 {
+            list.values().append_value(["a", "b", "c"][i % 3]);
+            list.append(i % 5 != 0);
+        }
+        let ints = Int32Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_from_iter([
+            ("string", Arc::new(strings) as ArrayRef),
+            ("list", Arc::new(list.finish()) as ArrayRef),
+            ("int", Arc::new(ints) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(64)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        assert_eq!(builder.schema(), &batch.schema());
+        let encodings = builder.metadata().row_group(0).column(0).encodings();
+        assert!(encodings.contains(&Encoding::RLE_DICTIONARY));
+        assert!(encodings.contains(&Encoding::PLAIN));
+
+        let options = ArrowReaderOptions::new().with_string_dictionary(false);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buf.clone(), options).unwrap();
+        assert_eq!(builder.schema(), &batch.schema());
+
+        let dict = |value: ArrowDataType| {
+            ArrowDataType::Dictionary(Box::new(ArrowDataType::Int32), Box::new(value))
+        };
+        let options = ArrowReaderOptions::new().with_string_dictionary(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(buf, options).unwrap();
+        let schema = builder.schema().clone();
+        assert_eq!(schema.field(0).data_type(), &dict(ArrowDataType::Utf8));
+        let item = Field::new("item", dict(ArrowDataType::Utf8), true);
+        let list_type = ArrowDataType::List(Arc::new(item));
+        assert_eq!(schema.field(1).data_type(), &list_type);
+        assert_eq!(schema.field(2).data_type(), &ArrowDataType::Int32);
+
+        let batches: Vec<_> = builder
+            .with_batch_size(30)
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 4);
+        let read = concat_batches(&schema, &batches).unwrap();
+        assert_eq!(read.schema(), schema);
+        for (read, expected) in read.columns().iter().zip(batch.columns()) {
+            let read = arrow_cast::cast(read, expected.data_type()).unwrap();
+            assert_eq!(&read, expected);
+        }
+    }
+
     #[test]
     fn test_dictionary_preservation() {
         let fields = vec![Arc::new(
@@ -0,0 +1,395 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row group pruning, optionally using statistics from an external sidecar file
+
+use crate::arrow::arrow_reader::{RowSelection, RowSelector};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use crate::file::reader::ChunkReader;
+
+/// Determines which row groups of a parquet file need to be read
+///
+/// A [`RowGroupPruner`] is evaluated once, when the reader is built, against the row
+/// groups selected by [`ArrowReaderBuilder::with_row_groups`] (or all row groups if
+/// none were selected). See [`ArrowReaderBuilder::with_row_group_pruner`].
+///
+/// [`ArrowReaderBuilder::with_row_groups`]: super::ArrowReaderBuilder::with_row_groups
+/// [`ArrowReaderBuilder::with_row_group_pruner`]: super::ArrowReaderBuilder::with_row_group_pruner
+pub trait RowGroupPruner: Send + 'static {
+    /// Returns, for each of the `row_groups` of the file described by `metadata`,
+    /// whether it may contain rows of interest and must therefore be read
+    ///
+    /// The returned `Vec` must have the same length as `row_groups`
+    fn prune(&mut self, metadata: &ParquetMetaData, row_groups: &[usize]) -> Result<Vec<bool>>;
+}
+
+/// A [`RowGroupPruner`] created from an [`FnMut`]
+pub struct RowGroupPrunerFn<F> {
+    f: F,
+}
+
+impl<F> RowGroupPrunerFn<F>
+where
+    F: FnMut(&ParquetMetaData, &[usize]) -> Result<Vec<bool>> + Send + 'static,
+{
+    /// Create a new [`RowGroupPrunerFn`] from `f`, see [`RowGroupPruner::prune`]
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> RowGroupPruner for RowGroupPrunerFn<F>
+where
+    F: FnMut(&ParquetMetaData, &[usize]) -> Result<Vec<bool>> + Send + 'static,
+{
+    fn prune(&mut self, metadata: &ParquetMetaData, row_groups: &[usize]) -> Result<Vec<bool>> {
+        (self.f)(metadata, row_groups)
+    }
+}
+
+/// Statistics and page indexes for a parquet file, stored in a separate sidecar file
+///
+/// Files are sometimes written with their footer intentionally stripped of statistics,
+/// for example to reduce its size, or to avoid exposing the column values contained in
+/// statistics of encrypted columns. The full metadata can instead be kept in a sidecar
+/// file, in the format written by [`ParquetMetaDataWriter`], and used to prune row groups
+/// without decrypting or otherwise reading any column data of the file itself.
+///
+/// [`ParquetMetaDataWriter`]: crate::file::metadata::ParquetMetaDataWriter
+#[derive(Debug, Clone)]
+pub struct ColumnIndexSidecar {
+    metadata: ParquetMetaData,
+}
+
+impl ColumnIndexSidecar {
+    /// Reads a sidecar file, including its page indexes if present
+    pub fn try_new<R: ChunkReader>(reader: &R) -> Result<Self> {
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_indexes(true)
+            .parse_and_finish(reader)?;
+        Ok(Self { metadata })
+    }
+
+    /// Creates a sidecar from already decoded [`ParquetMetaData`]
+    pub fn new(metadata: ParquetMetaData) -> Self {
+        Self { metadata }
+    }
+
+    /// Returns the [`ParquetMetaData`] stored in this sidecar
+    pub fn metadata(&self) -> &ParquetMetaData {
+        &self.metadata
+    }
+
+    /// Returns an error if this sidecar does not describe the file with `metadata`, i.e.
+    /// if the number of row groups, the number of rows or columns of a row group, or the
+    /// physical type of a column chunk differ
+    ///
+    /// If both the sidecar and `metadata` contain an offset index, the number of pages of
+    /// each column chunk must also match
+    pub fn validate(&self, metadata: &ParquetMetaData) -> Result<()> {
+        if self.metadata.num_row_groups() != metadata.num_row_groups() {
+            return Err(general_err!(
+                "Sidecar contains {} row groups, expected {}",
+                self.metadata.num_row_groups(),
+                metadata.num_row_groups()
+            ));
+        }
+        let row_groups = self.metadata.row_groups().iter();
+        for (idx, (sidecar, file)) in row_groups.zip(metadata.row_groups()).enumerate() {
+            if sidecar.num_rows() != file.num_rows() || sidecar.num_columns() != file.num_columns()
+            {
+                return Err(general_err!(
+                    "Sidecar row group {} does not match the file, expected {} rows and {} columns got {} rows and {} columns",
+                    idx,
+                    file.num_rows(),
+                    file.num_columns(),
+                    sidecar.num_rows(),
+                    sidecar.num_columns()
+                ));
+            }
+            let columns = sidecar.columns().iter();
+            for (col, (sidecar, file)) in columns.zip(file.columns()).enumerate() {
+                if sidecar.column_type() != file.column_type() {
+                    return Err(general_err!(
+                        "Sidecar column {} of row group {} does not match the file, expected {} got {}",
+                        col,
+                        idx,
+                        file.column_type(),
+                        sidecar.column_type()
+                    ));
+                }
+            }
+        }
+
+        if let (Some(sidecar), Some(file)) = (self.metadata.offset_index(), metadata.offset_index())
+        {
+            for (idx, (sidecar, file)) in sidecar.iter().zip(file).enumerate() {
+                for (col, (sidecar, file)) in sidecar.iter().zip(file).enumerate() {
+                    let expected = file.page_locations().len();
+                    let actual = sidecar.page_locations().len();
+                    if expected != actual {
+                        return Err(general_err!(
+                            "Sidecar column {} of row group {} does not match the file, expected {} pages got {}",
+                            col,
+                            idx,
+                            expected,
+                            actual
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`RowGroupPruner`] that evaluates another [`RowGroupPruner`] against the
+/// metadata of a [`ColumnIndexSidecar`] instead of the metadata of the file
+///
+/// The sidecar is validated against the file before pruning
+pub struct SidecarRowGroupPruner<P> {
+    sidecar: ColumnIndexSidecar,
+    inner: P,
+}
+
+impl<P: RowGroupPruner> SidecarRowGroupPruner<P> {
+    /// Create a new [`SidecarRowGroupPruner`] evaluating `inner` against `sidecar`
+    pub fn new(sidecar: ColumnIndexSidecar, inner: P) -> Self {
+        Self { sidecar, inner }
+    }
+}
+
+impl<P: RowGroupPruner> RowGroupPruner for SidecarRowGroupPruner<P> {
+    fn prune(&mut self, metadata: &ParquetMetaData, row_groups: &[usize]) -> Result<Vec<bool>> {
+        self.sidecar.validate(metadata)?;
+        self.inner.prune(self.sidecar.metadata(), row_groups)
+    }
+}
+
+/// Applies `pruner` to `row_groups`, removing the rows of any pruned row groups from
+/// `selection`, which must span exactly `row_groups`
+pub(crate) fn prune_row_groups(
+    pruner: &mut dyn RowGroupPruner,
+    metadata: &ParquetMetaData,
+    row_groups: Vec<usize>,
+    selection: Option<RowSelection>,
+) -> Result<(Vec<usize>, Option<RowSelection>)> {
+    let keep = pruner.prune(metadata, &row_groups)?;
+    if keep.len() != row_groups.len() {
+        return Err(general_err!(
+            "RowGroupPruner returned {} results for {} row groups",
+            keep.len(),
+            row_groups.len()
+        ));
+    }
+
+    let selection = selection.map(|mut selection| {
+        let mut selectors: Vec<RowSelector> = vec![];
+        for (idx, keep) in row_groups.iter().zip(&keep) {
+            let num_rows = metadata.row_group(*idx).num_rows() as usize;
+            let row_group_selection = selection.split_off(num_rows);
+            if *keep {
+                selectors.extend(row_group_selection.iter());
+            }
+        }
+        RowSelection::from(selectors)
+    });
+
+    let row_groups = row_groups
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(idx, keep)| keep.then_some(idx))
+        .collect();
+    Ok((row_groups, selection))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::arrow::ArrowWriter;
+    use crate::file::metadata::ParquetMetaDataWriter;
+    use crate::file::properties::{EnabledStatistics, WriterProperties};
+    use crate::file::statistics::Statistics;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, Int32Array, Int64Array, RecordBatch};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    fn write_file(statistics: EnabledStatistics) -> Bytes {
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(statistics)
+            .set_max_row_group_size(25)
+            .build();
+        write_array(Arc::new(Int32Array::from_iter_values(0..100)), props)
+    }
+
+    fn write_array(array: ArrayRef, props: WriterProperties) -> Bytes {
+        let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        buf.into()
+    }
+
+    /// Keeps row groups whose maximum value of the first column is at least `min`
+    fn max_at_least(
+        min: i32,
+    ) -> RowGroupPrunerFn<impl FnMut(&ParquetMetaData, &[usize]) -> Result<Vec<bool>>> {
+        RowGroupPrunerFn::new(move |metadata: &ParquetMetaData, row_groups: &[usize]| {
+            Ok(row_groups
+                .iter()
+                .map(
+                    |idx| match metadata.row_group(*idx).column(0).statistics() {
+                        Some(Statistics::Int32(s)) => s.max_opt().map_or(true, |max| *max >= min),
+                        _ => true,
+                    },
+                )
+                .collect())
+        })
+    }
+
+    fn read_values(builder: ParquetRecordBatchReaderBuilder<Bytes>) -> Vec<i32> {
+        builder
+            .build()
+            .unwrap()
+            .flat_map(|b| {
+                let b = b.unwrap();
+                b.column(0).as_primitive::<Int32Type>().values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_row_group_pruner() {
+        let file = write_file(EnabledStatistics::Chunk);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file.clone())
+            .unwrap()
+            .with_row_group_pruner(max_at_least(60));
+        assert_eq!(read_values(builder), (50..100).collect::<Vec<_>>());
+
+        // Row groups not selected are not read, and the selection is pruned with them
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(10),
+            RowSelector::select(20),
+            RowSelector::skip(15),
+            RowSelector::select(10),
+            RowSelector::skip(20),
+        ]);
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .with_row_groups(vec![0, 1, 3])
+            .with_row_selection(selection)
+            .with_row_group_pruner(max_at_least(30));
+        let expected: Vec<_> = (25..30).chain(45..50).chain(75..80).collect();
+        assert_eq!(read_values(builder), expected);
+    }
+
+    #[test]
+    fn test_sidecar_row_group_pruner() {
+        let file = write_file(EnabledStatistics::None);
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file.clone()).unwrap();
+        assert!(builder
+            .metadata()
+            .row_group(0)
+            .column(0)
+            .statistics()
+            .is_none());
+
+        // Without statistics, no row groups can be pruned
+        let builder = builder.with_row_group_pruner(max_at_least(60));
+        assert_eq!(read_values(builder), (0..100).collect::<Vec<_>>());
+
+        let full = ParquetMetaDataReader::new()
+            .with_page_indexes(true)
+            .parse_and_finish(&write_file(EnabledStatistics::Page))
+            .unwrap();
+        let mut sidecar = vec![];
+        ParquetMetaDataWriter::new(&mut sidecar, &full)
+            .finish()
+            .unwrap();
+        let sidecar = ColumnIndexSidecar::try_new(&Bytes::from(sidecar)).unwrap();
+        assert!(sidecar.metadata().column_index().is_some());
+
+        let pruner = SidecarRowGroupPruner::new(sidecar.clone(), max_at_least(60));
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file.clone())
+            .unwrap()
+            .with_row_group_pruner(pruner);
+        assert_eq!(read_values(builder), (50..100).collect::<Vec<_>>());
+
+        // A sidecar for a different file is rejected
+        let mismatched = ColumnIndexSidecar::new(ParquetMetaData::new(
+            full.file_metadata().clone(),
+            full.row_groups()[..2].to_vec(),
+        ));
+        let pruner = SidecarRowGroupPruner::new(mismatched, max_at_least(60));
+        let err = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .with_row_group_pruner(pruner)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sidecar contains 2 row groups, expected 4"
+        );
+    }
+
+    #[test]
+    fn test_sidecar_validate_columns() {
+        let read = |page_size| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(25)
+                .set_data_page_row_count_limit(page_size)
+                .set_write_batch_size(page_size)
+                .build();
+            let file = write_array(Arc::new(Int32Array::from_iter_values(0..100)), props);
+            ParquetMetaDataReader::new()
+                .with_page_indexes(true)
+                .parse_and_finish(&file)
+                .unwrap()
+        };
+
+        let file = read(25);
+        let sidecar = ColumnIndexSidecar::new(read(25));
+        sidecar.validate(&file).unwrap();
+
+        // A different number of pages per column chunk is rejected
+        let sidecar = ColumnIndexSidecar::new(read(5));
+        let err = sidecar.validate(&file).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sidecar column 0 of row group 0 does not match the file, expected 1 pages got 5"
+        );
+
+        // A different physical type is rejected
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let other = write_array(Arc::new(Int64Array::from_iter_values(0..100)), props);
+        let sidecar = ColumnIndexSidecar::try_new(&other).unwrap();
+        let err = sidecar.validate(&file).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sidecar column 0 of row group 0 does not match the file, expected INT32 got INT64"
+        );
+    }
+}
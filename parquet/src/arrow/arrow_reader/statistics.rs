@@ -24,7 +24,9 @@ use crate::arrow::parquet_column;
 use crate::basic::Type as PhysicalType;
 use crate::data_type::{ByteArray, FixedLenByteArray};
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{ParquetColumnIndex, ParquetOffsetIndex, RowGroupMetaData};
+use crate::file::metadata::{
+    ParquetColumnIndex, ParquetMetaData, ParquetOffsetIndex, RowGroupMetaData,
+};
 use crate::file::page_index::index::{Index, PageIndex};
 use crate::file::statistics::Statistics as ParquetStatistics;
 use crate::schema::types::SchemaDescriptor;
@@ -35,8 +37,8 @@ use arrow_array::builder::{
 use arrow_array::{
     new_empty_array, new_null_array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array,
     Decimal128Array, Decimal256Array, Float16Array, Float32Array, Float64Array, Int16Array,
-    Int32Array, Int64Array, Int8Array, LargeBinaryArray, Time32MillisecondArray, Time32SecondArray,
-    Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+    Int32Array, Int64Array, Int8Array, LargeBinaryArray, RecordBatch, Time32MillisecondArray,
+    Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt16Array,
     UInt32Array, UInt64Array, UInt8Array,
 };
@@ -1629,6 +1631,138 @@ impl<'a> StatisticsConverter<'a> {
         Ok(Some(UInt64Array::from_iter(row_count_total)))
     }
 
+    /// Returns a [`RecordBatch`] with one row for each data page of the column
+    /// in the specified row groups, combining the [`ParquetColumnIndex`] and
+    /// [`ParquetOffsetIndex`] of `metadata`
+    ///
+    /// This is a convenience over [`Self::data_page_mins`],
+    /// [`Self::data_page_maxes`], [`Self::data_page_null_counts`] and
+    /// [`Self::data_page_row_counts`] that also exposes the page locations.
+    ///
+    /// # Return Value
+    ///
+    /// Returns `None` if the column is not present in the parquet file, or if
+    /// the page index was not loaded (see [`ArrowReaderOptions::with_page_index`]).
+    ///
+    /// Otherwise the returned batch has the following columns:
+    ///
+    /// * `row_group` (`UInt64`): the index of the row group containing the page
+    /// * `offset` (`Int64`): the offset of the page in the file
+    /// * `compressed_page_size` (`Int32`): the size of the page, including its header
+    /// * `first_row_index` (`Int64`): the index of the first row of the page,
+    ///   relative to the start of its row group
+    /// * `row_count` (`UInt64`): the number of rows in the page
+    /// * `null_count` (`UInt64`): the number of nulls in the page, if known
+    /// * `min` and `max`: the statistics of the page, if known, see
+    ///   [`Self::data_page_mins`] for details
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arrow::datatypes::Schema;
+    /// # use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+    /// # use parquet::file::metadata::ParquetMetaData;
+    /// # fn get_parquet_metadata() -> ParquetMetaData { unimplemented!() }
+    /// # fn get_arrow_schema() -> Schema { unimplemented!() }
+    /// let metadata: ParquetMetaData = get_parquet_metadata();
+    /// let arrow_schema: Schema = get_arrow_schema();
+    /// let parquet_schema = metadata.file_metadata().schema_descr();
+    /// let converter = StatisticsConverter::try_new("foo", &arrow_schema, parquet_schema)
+    ///   .unwrap();
+    /// // get the statistics for the pages in the first two row groups
+    /// let pages = converter
+    ///   .data_page_statistics(&metadata, &[0, 1])
+    ///   .unwrap()
+    ///   .expect("page index should be loaded");
+    /// println!("{} pages", pages.num_rows());
+    /// ```
+    ///
+    /// [`ArrowReaderOptions::with_page_index`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_page_index
+    pub fn data_page_statistics<I>(
+        &self,
+        metadata: &'a ParquetMetaData,
+        row_group_indices: I,
+    ) -> Result<Option<RecordBatch>>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let Some(parquet_index) = self.parquet_column_index else {
+            return Ok(None);
+        };
+        let (Some(column_page_index), Some(column_offset_index)) =
+            (metadata.column_index(), metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+
+        let row_group_indices: Vec<_> = row_group_indices.into_iter().collect();
+
+        let num_pages = row_group_indices
+            .iter()
+            .map(|rg| {
+                column_offset_index[**rg][parquet_index]
+                    .page_locations()
+                    .len()
+            })
+            .sum();
+        let mut row_groups = UInt64Array::builder(num_pages);
+        let mut offsets = Int64Array::builder(num_pages);
+        let mut sizes = Int32Array::builder(num_pages);
+        let mut first_row_indices = Int64Array::builder(num_pages);
+        for rg in &row_group_indices {
+            for location in column_offset_index[**rg][parquet_index].page_locations() {
+                row_groups.append_value(**rg as u64);
+                offsets.append_value(location.offset);
+                sizes.append_value(location.compressed_page_size);
+                first_row_indices.append_value(location.first_row_index);
+            }
+        }
+
+        let row_counts = self
+            .data_page_row_counts(
+                column_offset_index,
+                metadata.row_groups(),
+                row_group_indices.iter().copied(),
+            )?
+            .ok_or_else(|| arrow_err!("Unable to determine the row counts of the data pages"))?;
+        let null_counts = self.data_page_null_counts(
+            column_page_index,
+            column_offset_index,
+            row_group_indices.iter().copied(),
+        )?;
+        let mins = self.data_page_mins(
+            column_page_index,
+            column_offset_index,
+            row_group_indices.iter().copied(),
+        )?;
+        let maxes = self.data_page_maxes(
+            column_page_index,
+            column_offset_index,
+            row_group_indices.iter().copied(),
+        )?;
+
+        let schema = Schema::new(vec![
+            Field::new("row_group", DataType::UInt64, false),
+            Field::new("offset", DataType::Int64, false),
+            Field::new("compressed_page_size", DataType::Int32, false),
+            Field::new("first_row_index", DataType::Int64, false),
+            Field::new("row_count", DataType::UInt64, false),
+            Field::new("null_count", DataType::UInt64, true),
+            Field::new("min", mins.data_type().clone(), true),
+            Field::new("max", maxes.data_type().clone(), true),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(row_groups.finish()),
+            Arc::new(offsets.finish()),
+            Arc::new(sizes.finish()),
+            Arc::new(first_row_indices.finish()),
+            Arc::new(row_counts),
+            Arc::new(null_counts),
+            mins,
+            maxes,
+        ];
+        Ok(Some(RecordBatch::try_new(Arc::new(schema), columns)?))
+    }
+
     /// Returns a null array of data_type with one element per row group
     fn make_null_array<I, A>(&self, data_type: &DataType, metadatas: I) -> ArrayRef
     where
@@ -26,10 +26,13 @@ use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::num_required_bits;
 use crate::util::interner::{Interner, Storage};
+use arrow_array::cast::AsArray;
+use arrow_array::types::ByteViewType;
 use arrow_array::{
-    Array, ArrayAccessor, BinaryArray, BinaryViewArray, DictionaryArray, LargeBinaryArray,
-    LargeStringArray, StringArray, StringViewArray,
+    Array, ArrayAccessor, BinaryArray, BinaryViewArray, DictionaryArray, GenericByteViewArray,
+    LargeBinaryArray, LargeStringArray, StringArray, StringViewArray,
 };
+use arrow_data::ByteView;
 use arrow_schema::DataType;
 
 macro_rules! downcast_dict_impl {
@@ -85,6 +88,8 @@ macro_rules! downcast_op {
                 DataType::LargeBinary => {
                     downcast_dict_op!(key, LargeBinaryArray, $array, $op$(, $arg)*)
                 }
+                DataType::Utf8View => downcast_dict_op!(key, StringViewArray, $array, $op$(, $arg)*),
+                DataType::BinaryView => downcast_dict_op!(key, BinaryViewArray, $array, $op$(, $arg)*),
                 d => unreachable!("cannot downcast {} dictionary value to byte array", d),
             },
             d => unreachable!("cannot downcast {} to byte array", d),
@@ -163,11 +168,56 @@ impl FallbackEncoder {
         T: ArrayAccessor + Copy,
         T::Item: AsRef<[u8]>,
     {
-        self.num_values += indices.len();
+        let values = indices.iter().map(|idx| values.value(*idx));
+        self.encode_values(indices.len(), None, values)
+    }
+
+    /// Encode the byte view `values` to the in-progress page
+    ///
+    /// Values are read directly from the views and data buffers of `values`, with the
+    /// total length of the selected values, known from the views alone, used to size
+    /// the page buffer up front
+    fn encode_view<T: ByteViewType + ?Sized>(
+        &mut self,
+        values: &GenericByteViewArray<T>,
+        indices: &[usize],
+    ) {
+        let views = values.views();
+        let raw_views = views.inner().as_slice();
+        let buffers = values.data_buffers();
+
+        let total_len = indices.iter().map(|idx| views[*idx] as u32 as usize).sum();
+
+        let values = indices.iter().map(|idx| {
+            let len = views[*idx] as u32 as usize;
+            if len <= 12 {
+                // Inlined values are stored after the 4 byte length
+                let start = idx * 16 + 4;
+                &raw_views[start..start + len]
+            } else {
+                let view = ByteView::from(views[*idx]);
+                let start = view.offset as usize;
+                &buffers[view.buffer_index as usize][start..start + len]
+            }
+        });
+        self.encode_values(indices.len(), Some(total_len), values)
+    }
+
+    /// Encode `num_values` byte array `values` to the in-progress page, reserving
+    /// space for `total_len` bytes of value data if known
+    fn encode_values<V: AsRef<[u8]>>(
+        &mut self,
+        num_values: usize,
+        total_len: Option<usize>,
+        values: impl Iterator<Item = V>,
+    ) {
+        self.num_values += num_values;
         match &mut self.encoder {
             FallbackEncoderImpl::Plain { buffer } => {
-                for idx in indices {
-                    let value = values.value(*idx);
+                if let Some(total_len) = total_len {
+                    buffer.reserve(total_len + num_values * std::mem::size_of::<u32>());
+                }
+                for value in values {
                     let value = value.as_ref();
                     buffer.extend_from_slice((value.len() as u32).as_bytes());
                     buffer.extend_from_slice(value);
@@ -175,8 +225,10 @@ impl FallbackEncoder {
                 }
             }
             FallbackEncoderImpl::DeltaLength { buffer, lengths } => {
-                for idx in indices {
-                    let value = values.value(*idx);
+                if let Some(total_len) = total_len {
+                    buffer.reserve(total_len);
+                }
+                for value in values {
                     let value = value.as_ref();
                     lengths.put(&[value.len() as i32]).unwrap();
                     buffer.extend_from_slice(value);
@@ -189,8 +241,7 @@ impl FallbackEncoder {
                 prefix_lengths,
                 suffix_lengths,
             } => {
-                for idx in indices {
-                    let value = values.value(*idx);
+                for value in values {
                     let value = value.as_ref();
                     let mut prefix_length = 0;
 
@@ -459,7 +510,11 @@ impl ColumnValueEncoder for ByteArrayEncoder {
     }
 
     fn write_gather(&mut self, values: &Self::Values, indices: &[usize]) -> Result<()> {
-        downcast_op!(values.data_type(), values, encode, indices, self);
+        match values.data_type() {
+            DataType::Utf8View => encode_view(values.as_string_view(), indices, self),
+            DataType::BinaryView => encode_view(values.as_binary_view(), indices, self),
+            _ => downcast_op!(values.data_type(), values, encode, indices, self),
+        }
         Ok(())
     }
 
@@ -542,6 +597,41 @@ fn encode<T>(values: T, indices: &[usize], encoder: &mut ByteArrayEncoder)
 where
     T: ArrayAccessor + Copy,
     T::Item: Copy + Ord + AsRef<[u8]>,
+{
+    update_statistics_and_bloom_filter(values, indices, encoder);
+
+    match &mut encoder.dict_encoder {
+        Some(dict_encoder) => dict_encoder.encode(values, indices),
+        None => encoder.fallback.encode(values, indices),
+    }
+}
+
+/// Encodes the provided byte view `values` and `indices` to `encoder`
+///
+/// Unlike [`encode`], the fallback encoder reads values directly from the views
+fn encode_view<T: ByteViewType + ?Sized>(
+    values: &GenericByteViewArray<T>,
+    indices: &[usize],
+    encoder: &mut ByteArrayEncoder,
+) where
+    T::Native: Ord,
+{
+    update_statistics_and_bloom_filter(values, indices, encoder);
+
+    match &mut encoder.dict_encoder {
+        Some(dict_encoder) => dict_encoder.encode(values, indices),
+        None => encoder.fallback.encode_view(values, indices),
+    }
+}
+
+/// Updates the statistics and bloom filter of `encoder` with the provided `values` and `indices`
+fn update_statistics_and_bloom_filter<T>(
+    values: T,
+    indices: &[usize],
+    encoder: &mut ByteArrayEncoder,
+) where
+    T: ArrayAccessor + Copy,
+    T::Item: Copy + Ord + AsRef<[u8]>,
 {
     if encoder.statistics_enabled != EnabledStatistics::None {
         if let Some((min, max)) = compute_min_max(values, indices.iter().cloned()) {
@@ -562,11 +652,6 @@ where
             bloom_filter.insert(values.value(idx).as_ref());
         }
     }
-
-    match &mut encoder.dict_encoder {
-        Some(dict_encoder) => dict_encoder.encode(values, indices),
-        None => encoder.fallback.encode(values, indices),
-    }
 }
 
 /// Computes the min and max for the provided array and indices
@@ -33,7 +33,7 @@ use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, IntervalUnit, S
 use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
 
 use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
-use crate::arrow::ArrowSchemaConverter;
+use crate::arrow::{ArrowSchemaConverter, AVRO_SCHEMA_META_KEY, PARQUET_AVRO_SCHEMA_META_KEY};
 use crate::column::page::{CompressedPage, PageWriteSpec, PageWriter};
 use crate::column::writer::encoder::ColumnValueEncoder;
 use crate::column::writer::{
@@ -47,17 +47,23 @@ use crate::file::reader::{ChunkReader, Length};
 use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
 use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
 use crate::thrift::TSerializable;
+use crate::util::bit_util::ceil;
 use levels::{calculate_array_levels, ArrayLevels};
+use pool::EncoderPool;
 
 mod byte_array;
 mod levels;
+mod pool;
 
 /// Encodes [`RecordBatch`] to parquet
 ///
 /// Writes Arrow `RecordBatch`es to a Parquet writer. Multiple [`RecordBatch`] will be encoded
 /// to the same row group, up to `max_row_group_size` rows. Any remaining rows will be
 /// flushed on close, leading the final row group in the output file to potentially
-/// contain fewer than `max_row_group_size` rows
+/// contain fewer than `max_row_group_size` rows. Row groups can also be limited to a target
+/// size in bytes, see [`WriterPropertiesBuilder::set_target_row_group_bytes`].
+///
+/// [`WriterPropertiesBuilder::set_target_row_group_bytes`]: crate::file::properties::WriterPropertiesBuilder::set_target_row_group_bytes
 ///
 /// ```
 /// # use std::sync::Arc;
@@ -115,9 +121,11 @@ mod levels;
 /// The writer supports writing all Arrow [`DataType`]s that have a direct mapping to
 /// Parquet types including  [`StructArray`] and [`ListArray`].
 ///
-/// The following are not supported:
+/// The following are only partially supported:
 ///
-/// * [`IntervalMonthDayNanoArray`]: Parquet does not [support nanosecond intervals].
+/// * [`IntervalMonthDayNanoArray`]: Parquet does not [support nanosecond intervals], and so
+///   only values with a whole number of milliseconds, such as those of an Avro `duration`,
+///   can be written
 ///
 /// [`DataType`]: https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html
 /// [`StructArray`]: https://docs.rs/arrow/latest/arrow/array/struct.StructArray.html
@@ -138,6 +146,15 @@ pub struct ArrowWriter<W: Write> {
 
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
+
+    /// The target encoded size of each row group, if any
+    target_row_group_bytes: Option<usize>,
+
+    /// The number of rows used to estimate the encoded size of a row
+    write_batch_size: usize,
+
+    /// The threads encoding the columns of each row group, if encoding in parallel
+    pool: Option<Arc<EncoderPool>>,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -149,6 +166,7 @@ impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
             .field("in_progress_rows", &self.in_progress_rows())
             .field("arrow_schema", &self.arrow_schema)
             .field("max_row_group_size", &self.max_row_group_size)
+            .field("target_row_group_bytes", &self.target_row_group_bytes)
             .finish()
     }
 }
@@ -188,8 +206,24 @@ impl<W: Write + Send> ArrowWriter<W> {
             // add serialized arrow schema
             add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
         }
+        if let Some(avro_schema) = arrow_schema.metadata().get(AVRO_SCHEMA_META_KEY) {
+            // preserve the schema of data decoded from avro for parquet-avro readers
+            let meta = props
+                .key_value_metadata
+                .get_or_insert_with(Default::default);
+            if !meta.iter().any(|kv| kv.key == PARQUET_AVRO_SCHEMA_META_KEY) {
+                meta.push(KeyValue::new(
+                    PARQUET_AVRO_SCHEMA_META_KEY.to_string(),
+                    avro_schema.clone(),
+                ));
+            }
+        }
 
         let max_row_group_size = props.max_row_group_size();
+        let target_row_group_bytes = props.target_row_group_bytes();
+        let write_batch_size = props.write_batch_size();
+        let pool = (props.max_parallelism() > 1)
+            .then(|| Arc::new(EncoderPool::new(props.max_parallelism())));
 
         let file_writer =
             SerializedFileWriter::new(writer, schema.root_schema_ptr(), Arc::new(props))?;
@@ -199,6 +233,9 @@ impl<W: Write + Send> ArrowWriter<W> {
             in_progress: None,
             arrow_schema,
             max_row_group_size,
+            target_row_group_bytes,
+            write_batch_size,
+            pool,
         })
     }
 
@@ -207,6 +244,12 @@ impl<W: Write + Send> ArrowWriter<W> {
         self.writer.flushed_row_groups()
     }
 
+    /// Returns true if the columns of each row group are encoded on a pool of threads
+    #[cfg(feature = "async")]
+    pub(crate) fn encodes_in_parallel(&self) -> bool {
+        self.pool.is_some()
+    }
+
     /// Estimated memory usage, in bytes, of this `ArrowWriter`
     ///
     /// This estimate is formed bu summing the values of
@@ -226,11 +269,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// columns.
     pub fn in_progress_size(&self) -> usize {
         match &self.in_progress {
-            Some(in_progress) => in_progress
-                .writers
-                .iter()
-                .map(|x| x.get_estimated_total_bytes())
-                .sum(),
+            Some(in_progress) => in_progress.get_estimated_total_bytes(),
             None => 0,
         }
     }
@@ -254,6 +293,10 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// rows, the contents of `batch` will be written to one or more row groups such that all but
     /// the final row group in the file contain [`WriterProperties::max_row_group_size`] rows.
     ///
+    /// If [`WriterProperties::target_row_group_bytes`] is set, row groups are additionally
+    /// closed once their estimated encoded size reaches the target, with `batch` split
+    /// between row groups as necessary.
+    ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         if batch.num_rows() == 0 {
@@ -266,6 +309,7 @@ impl<W: Write + Send> ArrowWriter<W> {
                 self.writer.schema_descr(),
                 self.writer.properties(),
                 &self.arrow_schema,
+                self.pool.clone(),
             )?),
         };
 
@@ -278,9 +322,35 @@ impl<W: Write + Send> ArrowWriter<W> {
             return self.write(&b);
         }
 
+        // If would exceed target_row_group_bytes, split batch based on the
+        // average encoded size of the buffered rows
+        if let Some(target) = self.target_row_group_bytes {
+            let to_write = match in_progress.buffered_rows {
+                0 => self.write_batch_size,
+                rows => {
+                    let size = in_progress.get_estimated_total_bytes();
+                    let row_size = ceil(size, rows).max(1);
+                    target.saturating_sub(size) / row_size
+                }
+            };
+            if to_write == 0 {
+                self.flush()?;
+                return self.write(batch);
+            }
+            if to_write < batch.num_rows() {
+                let a = batch.slice(0, to_write);
+                let b = batch.slice(to_write, batch.num_rows() - to_write);
+                self.write(&a)?;
+                return self.write(&b);
+            }
+        }
+
         in_progress.write(batch)?;
 
-        if in_progress.buffered_rows >= self.max_row_group_size {
+        let exceeds_target = self
+            .target_row_group_bytes
+            .is_some_and(|target| in_progress.get_estimated_total_bytes() >= target);
+        if in_progress.buffered_rows >= self.max_row_group_size || exceeds_target {
             self.flush()?
         }
         Ok(())
@@ -684,38 +754,121 @@ struct ArrowRowGroupWriter {
     writers: Vec<ArrowColumnWriter>,
     schema: SchemaRef,
     buffered_rows: usize,
+    max_parallelism: usize,
+    pool: Option<Arc<EncoderPool>>,
 }
 
 impl ArrowRowGroupWriter {
+    fn get_estimated_total_bytes(&self) -> usize {
+        self.writers
+            .iter()
+            .map(|x| x.get_estimated_total_bytes())
+            .sum()
+    }
+
     fn new(
         parquet: &SchemaDescriptor,
         props: &WriterPropertiesPtr,
         arrow: &SchemaRef,
+        pool: Option<Arc<EncoderPool>>,
     ) -> Result<Self> {
         let writers = get_column_writers(parquet, props, arrow)?;
         Ok(Self {
             writers,
             schema: arrow.clone(),
             buffered_rows: 0,
+            max_parallelism: props.max_parallelism(),
+            pool,
         })
     }
 
+    /// Returns the [`EncoderPool`] and the number of columns encoded by each of its
+    /// threads, or `None` if the columns should be encoded on the current thread
+    fn parallel(&self) -> Option<(Arc<EncoderPool>, usize)> {
+        let pool = self.pool.as_ref()?;
+        let chunk_size = ceil(self.writers.len(), self.max_parallelism);
+        (chunk_size < self.writers.len()).then(|| (Arc::clone(pool), chunk_size))
+    }
+
+    /// Splits the column writers into chunks of `chunk_size` to encode on separate threads
+    fn take_writer_chunks(&mut self, chunk_size: usize) -> Vec<Vec<ArrowColumnWriter>> {
+        let mut writers = std::mem::take(&mut self.writers).into_iter();
+        std::iter::from_fn(|| Some(writers.by_ref().take(chunk_size).collect::<Vec<_>>()))
+            .take_while(|chunk| !chunk.is_empty())
+            .collect()
+    }
+
     fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         self.buffered_rows += batch.num_rows();
-        let mut writers = self.writers.iter_mut();
-        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
-            for leaf in compute_leaves(field.as_ref(), column)? {
-                writers.next().unwrap().write(&leaf)?
+        let Some((pool, chunk_size)) = self.parallel() else {
+            let mut writers = self.writers.iter_mut();
+            for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+                for leaf in compute_leaves(field.as_ref(), column)? {
+                    writers.next().unwrap().write(&leaf)?
+                }
             }
+            return Ok(());
+        };
+
+        let mut leaves = Vec::with_capacity(self.writers.len());
+        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+            leaves.extend(compute_leaves(field.as_ref(), column)?);
         }
-        Ok(())
+
+        let mut leaves = leaves.into_iter();
+        let tasks: Vec<_> = self
+            .take_writer_chunks(chunk_size)
+            .into_iter()
+            .map(|mut writers| {
+                let leaves: Vec<_> = leaves.by_ref().take(writers.len()).collect();
+                pool.spawn(move || {
+                    let result = writers
+                        .iter_mut()
+                        .zip(&leaves)
+                        .try_for_each(|(writer, leaf)| writer.write(leaf));
+                    (writers, result)
+                })
+            })
+            .collect();
+
+        // Wait for every task so that all the writers are returned, in schema order
+        let mut result = Ok(());
+        for task in tasks {
+            let (writers, r) = task.join();
+            self.writers.extend(writers);
+            result = result.and(r);
+        }
+        result
     }
 
-    fn close(self) -> Result<Vec<ArrowColumnChunk>> {
-        self.writers
+    fn close(mut self) -> Result<Vec<ArrowColumnChunk>> {
+        let Some((pool, chunk_size)) = self.parallel() else {
+            return self
+                .writers
+                .into_iter()
+                .map(|writer| writer.close())
+                .collect();
+        };
+
+        let tasks: Vec<_> = self
+            .take_writer_chunks(chunk_size)
             .into_iter()
-            .map(|writer| writer.close())
-            .collect()
+            .map(|writers| {
+                pool.spawn(move || {
+                    writers
+                        .into_iter()
+                        .map(|writer| writer.close())
+                        .collect::<Result<Vec<_>>>()
+                })
+            })
+            .collect();
+
+        // Join in order so that the column chunks are returned in schema order
+        let mut chunks = Vec::with_capacity(self.schema.fields().len());
+        for task in tasks {
+            chunks.extend(task.join()?);
+        }
+        Ok(chunks)
     }
 }
 
@@ -929,12 +1082,12 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                             .unwrap();
                         get_interval_dt_array_slice(array, indices)
                     }
-                    _ => {
-                        return Err(ParquetError::NYI(
-                            format!(
-                                "Attempting to write an Arrow interval type {interval_unit:?} to parquet that is not yet implemented"
-                            )
-                        ));
+                    IntervalUnit::MonthDayNano => {
+                        let array = column
+                            .as_any()
+                            .downcast_ref::<arrow_array::IntervalMonthDayNanoArray>()
+                            .unwrap();
+                        get_interval_mdn_array_slice(array, indices)?
                     }
                 },
                 ArrowDataType::FixedSizeBinary(_) => {
@@ -1027,6 +1180,35 @@ fn get_interval_dt_array_slice(
     values
 }
 
+/// Returns 12-byte values representing 3 values of months, days and milliseconds (4-bytes each).
+/// An Arrow MonthDayNano interval, such as decoded from an Avro `duration`, can only be written
+/// if its nanoseconds are a whole number of milliseconds that fits in 4 bytes.
+fn get_interval_mdn_array_slice(
+    array: &arrow_array::IntervalMonthDayNanoArray,
+    indices: &[usize],
+) -> Result<Vec<FixedLenByteArray>> {
+    let mut values = Vec::with_capacity(indices.len());
+    for i in indices {
+        let value = array.value(*i);
+        let millis = match value.nanoseconds % 1_000_000 {
+            0 => i32::try_from(value.nanoseconds / 1_000_000).ok(),
+            _ => None,
+        };
+        let millis = millis.ok_or_else(|| {
+            general_err!(
+                "Cannot write interval with {} nanoseconds to parquet, which supports whole milliseconds",
+                value.nanoseconds
+            )
+        })?;
+        let mut out = [0; 12];
+        out[0..4].copy_from_slice(&value.months.to_le_bytes());
+        out[4..8].copy_from_slice(&value.days.to_le_bytes());
+        out[8..12].copy_from_slice(&millis.to_le_bytes());
+        values.push(FixedLenByteArray::from(ByteArray::from(out.to_vec())));
+    }
+    Ok(values)
+}
+
 fn get_decimal_128_array_slice(
     array: &arrow_array::Decimal128Array,
     indices: &[usize],
@@ -1083,6 +1265,7 @@ fn get_fsb_array_slice(
 mod tests {
     use super::*;
 
+    use std::collections::HashMap;
     use std::fs::File;
 
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
@@ -1906,7 +2089,12 @@ mod tests {
         } = options;
 
         let encodings = match values.data_type() {
-            DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary => {
+            DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::Utf8View
+            | DataType::BinaryView => {
                 vec![
                     Encoding::PLAIN,
                     Encoding::DELTA_BYTE_ARRAY,
@@ -2273,18 +2461,25 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Attempting to write an Arrow interval type MonthDayNano to parquet that is not yet implemented"
-    )]
     fn interval_month_day_nano_single_column() {
         required_and_optional::<IntervalMonthDayNanoArray, _>(vec![
-            IntervalMonthDayNano::new(0, 1, 5),
-            IntervalMonthDayNano::new(0, 3, 2),
-            IntervalMonthDayNano::new(3, -2, -5),
-            IntervalMonthDayNano::new(-200, 4, -1),
+            IntervalMonthDayNano::new(0, 1, 5_000_000),
+            IntervalMonthDayNano::new(0, 3, 2_000_000),
+            IntervalMonthDayNano::new(3, -2, -5_000_000),
+            IntervalMonthDayNano::new(-200, 4, -1_000_000),
         ]);
     }
 
+    #[test]
+    #[should_panic(
+        expected = "Cannot write interval with 5 nanoseconds to parquet, which supports whole milliseconds"
+    )]
+    fn interval_month_day_nano_sub_millisecond() {
+        required_and_optional::<IntervalMonthDayNanoArray, _>(vec![IntervalMonthDayNano::new(
+            0, 1, 5,
+        )]);
+    }
+
     #[test]
     fn binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3385,6 +3580,137 @@ mod tests {
         }
     }
 
+    #[test]
+    fn arrow_writer_avro_schema_metadata() {
+        let avro_schema = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"int"}]}"#;
+        let schema = Arc::new(
+            Schema::new(vec![Field::new("a", DataType::Int32, false)]).with_metadata(
+                HashMap::from([(AVRO_SCHEMA_META_KEY.to_string(), avro_schema.to_string())]),
+            ),
+        );
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3])) as _],
+        )
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let kv = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .unwrap();
+        let value = kv
+            .iter()
+            .find(|kv| kv.key == PARQUET_AVRO_SCHEMA_META_KEY)
+            .and_then(|kv| kv.value.as_deref());
+        assert_eq!(value, Some(avro_schema));
+    }
+
+    #[test]
+    fn arrow_writer_max_parallelism() {
+        let ints = Arc::new(Int32Array::from_iter(
+            (0..100).map(|x| (x % 3 != 0).then_some(x)),
+        ));
+        let strings = Arc::new(StringArray::from_iter_values(
+            (0..100).map(|x| x.to_string()),
+        ));
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                ints.clone() as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, false)),
+                strings.clone() as ArrayRef,
+            ),
+        ]);
+        let lists = ListArray::from_iter_primitive::<Int32Type, _, _>(
+            (0..100).map(|x| Some(vec![Some(x); x as usize % 4])),
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("ints", ints as ArrayRef),
+            ("structs", Arc::new(structs) as ArrayRef),
+            ("lists", Arc::new(lists) as ArrayRef),
+            ("strings", strings as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |max_parallelism| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(30)
+                .set_max_parallelism(max_parallelism)
+                .build();
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            buf
+        };
+
+        // The output does not depend on the number of threads
+        let sequential = write(1);
+        for max_parallelism in [2, 3, 5, 16] {
+            assert_eq!(write(max_parallelism), sequential);
+        }
+
+        let batches = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(sequential))
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0], batch);
+    }
+
+    #[test]
+    fn arrow_writer_target_row_group_bytes() {
+        let write = |num_columns: usize, max_row_group_size: usize| {
+            let columns = (0..num_columns).map(|i| {
+                let values = Int64Array::from_iter_values((0..10_000).map(|x| x * 7919 + i as i64));
+                (format!("c{i}"), Arc::new(values) as ArrayRef)
+            });
+            let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+            let props = WriterProperties::builder()
+                .set_dictionary_enabled(false)
+                .set_write_batch_size(100)
+                .set_max_row_group_size(max_row_group_size)
+                .set_target_row_group_bytes(Some(16 * 1024))
+                .build();
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            for offset in (0..batch.num_rows()).step_by(3000) {
+                let len = 3000.min(batch.num_rows() - offset);
+                writer.write(&batch.slice(offset, len)).unwrap();
+            }
+            let metadata = writer.close().unwrap();
+            assert_eq!(metadata.num_rows, 10_000);
+            metadata.row_groups
+        };
+
+        for num_columns in [1, 4] {
+            let row_groups = write(num_columns, 1024 * 1024);
+            assert!(row_groups.len() > 1);
+            // All but the last row group should be close to the target
+            for rg in &row_groups[..row_groups.len() - 1] {
+                assert_eq!(rg.num_rows as usize, 2048 / num_columns);
+                let size = rg.total_byte_size as usize;
+                assert!((15 * 1024..=17 * 1024).contains(&size), "{size}");
+            }
+        }
+
+        // max_row_group_size is still respected
+        let row_groups = write(1, 1000);
+        assert!(row_groups.iter().all(|rg| rg.num_rows == 1000));
+    }
+
     #[test]
     fn mismatched_schemas() {
         let batch_schema = Schema::new(vec![Field::new("count", DataType::Int32, false)]);
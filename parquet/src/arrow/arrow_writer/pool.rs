@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A pool of threads for encoding column chunks in parallel
+
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, sync_channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed size pool of threads, shared by the row groups of an [`ArrowWriter`]
+///
+/// The threads are started when the pool is created, and exit once it is dropped
+///
+/// [`ArrowWriter`]: super::ArrowWriter
+pub(crate) struct EncoderPool {
+    /// Wrapped in a [`Mutex`] as [`Sender`] is not `Sync` prior to Rust 1.72
+    sender: Mutex<Option<Sender<Job>>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl EncoderPool {
+    /// Create a new [`EncoderPool`] with `size` threads
+    pub(crate) fn new(size: usize) -> Self {
+        let (sender, receiver) = channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let threads = (0..size)
+            .map(|idx| {
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("parquet-encoder-{idx}"))
+                    .spawn(move || loop {
+                        // The lock is released before running the job
+                        let job = match receiver.lock().unwrap().recv() {
+                            Ok(job) => job,
+                            Err(_) => break,
+                        };
+                        job()
+                    })
+                    .expect("failed to spawn parquet encoder thread")
+            })
+            .collect();
+
+        Self {
+            sender: Mutex::new(Some(sender)),
+            threads,
+        }
+    }
+
+    /// Run `f` on a thread of the pool, returning an [`EncoderTask`] to wait for its result
+    pub(crate) fn spawn<T, F>(&self, f: F) -> EncoderTask<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (sender, receiver) = sync_channel(1);
+        let job = Box::new(move || {
+            // The task may have been dropped, in which case the result is discarded
+            let _ = sender.send(catch_unwind(AssertUnwindSafe(f)));
+        });
+        // The threads only exit once the sender is dropped
+        let sender = self.sender.lock().unwrap();
+        sender.as_ref().unwrap().send(job).unwrap();
+        EncoderTask(receiver)
+    }
+}
+
+impl Drop for EncoderPool {
+    fn drop(&mut self) {
+        self.sender.lock().unwrap().take();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The result of a function run by [`EncoderPool::spawn`]
+pub(crate) struct EncoderTask<T>(Receiver<std::thread::Result<T>>);
+
+impl<T> EncoderTask<T> {
+    /// Wait for the function to complete, propagating any panic
+    pub(crate) fn join(self) -> T {
+        match self.0.recv() {
+            Ok(Ok(value)) => value,
+            Ok(Err(panic)) => resume_unwind(panic),
+            Err(_) => unreachable!("encoder jobs always send their result"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoder_pool() {
+        let pool = EncoderPool::new(2);
+        let tasks: Vec<_> = (0..10).map(|x| pool.spawn(move || x * 2)).collect();
+        let results: Vec<_> = tasks.into_iter().map(|t| t.join()).collect();
+        assert_eq!(results, (0..10).map(|x| x * 2).collect::<Vec<_>>());
+
+        // A panic is propagated to the caller, and the thread remains usable
+        let task = pool.spawn(|| panic!("encoder panic"));
+        let panic = catch_unwind(AssertUnwindSafe(|| task.join())).unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"encoder panic"));
+        assert_eq!(pool.spawn(|| 1).join(), 1);
+        assert_eq!(pool.spawn(|| 2).join(), 2);
+    }
+}
@@ -42,15 +42,15 @@ use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 
 use crate::arrow::array_reader::{build_array_reader, RowGroups};
 use crate::arrow::arrow_reader::{
-    apply_range, evaluate_predicate, selects_any, ArrowReaderBuilder, ArrowReaderMetadata,
-    ArrowReaderOptions, ParquetRecordBatchReader, RowFilter, RowSelection,
+    apply_range, evaluate_predicate, prune_row_groups, selects_any, ArrowReaderBuilder,
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader, RowFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
 use crate::bloom_filter::{
     chunk_read_bloom_filter_header_and_offset, Sbbf, SBBF_HEADER_SIZE_ESTIMATE,
 };
-use crate::column::page::{PageIterator, PageReader};
+use crate::column::page::{Page, PageIterator, PageReader};
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
@@ -61,6 +61,10 @@ use crate::format::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHas
 mod metadata;
 pub use metadata::*;
 
+mod page_cache;
+pub use page_cache::PageCache;
+use page_cache::{CachedPageReader, FilePageCache};
+
 #[cfg(feature = "object_store")]
 mod store;
 
@@ -195,7 +199,7 @@ impl ArrowReaderMetadata {
 ///
 /// Allows sharing the same builder for both the sync and async versions, whilst also not
 /// breaking the pre-existing ParquetRecordBatchStreamBuilder API
-pub struct AsyncReader<T>(T);
+pub struct AsyncReader<T>(T, Option<FilePageCache>);
 
 /// A builder for reading parquet files from an `async` source as  [`ParquetRecordBatchStream`]
 ///
@@ -396,7 +400,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// # }
     /// ```
     pub fn new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Self {
-        Self::new_builder(AsyncReader(input), metadata)
+        Self::new_builder(AsyncReader(input, None), metadata)
     }
 
     /// Read bloom filter for a column in a row group
@@ -463,6 +467,19 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Cache the decompressed pages of the column chunks read by this stream in `cache`
+    ///
+    /// `file` uniquely identifies the file read by this builder within the cache, for
+    /// example its path and modification time, allowing streams over the same file
+    /// to reuse each other's pages. See [`PageCache`] for more information.
+    pub fn with_page_cache(mut self, cache: Arc<PageCache>, file: impl Into<Arc<str>>) -> Self {
+        self.input.1 = Some(FilePageCache {
+            cache,
+            file: file.into(),
+        });
+        self
+    }
+
     /// Build a new [`ParquetRecordBatchStream`]
     ///
     /// See examples on [`ParquetRecordBatchStreamBuilder::new`]
@@ -478,17 +495,25 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
                         num_row_groups
                     ));
                 }
-                row_groups.into()
+                row_groups
             }
             None => (0..self.metadata.row_groups().len()).collect(),
         };
 
+        let (row_groups, selection) = match self.row_group_pruner {
+            Some(mut pruner) => {
+                prune_row_groups(pruner.as_mut(), &self.metadata, row_groups, self.selection)?
+            }
+            None => (row_groups, self.selection),
+        };
+
         // Try to avoid allocate large buffer
         let batch_size = self
             .batch_size
             .min(self.metadata.file_metadata().num_rows() as usize);
         let reader = ReaderFactory {
             input: self.input.0,
+            page_cache: self.input.1,
             filter: self.filter,
             metadata: self.metadata.clone(),
             fields: self.fields,
@@ -510,9 +535,9 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Ok(ParquetRecordBatchStream {
             metadata: self.metadata,
             batch_size,
-            row_groups,
+            row_groups: row_groups.into(),
             projection: self.projection,
-            selection: self.selection,
+            selection,
             schema,
             reader: Some(reader),
             state: StreamState::Init,
@@ -531,6 +556,8 @@ struct ReaderFactory<T> {
 
     input: T,
 
+    page_cache: Option<FilePageCache>,
+
     filter: Option<RowFilter>,
 
     limit: Option<usize>,
@@ -567,6 +594,8 @@ where
             // schema: meta.schema_descr_ptr(),
             row_count: meta.num_rows() as usize,
             column_chunks: vec![None; meta.columns().len()],
+            cached_pages: vec![None; meta.columns().len()],
+            page_cache: self.page_cache.as_ref(),
             offset_index,
         };
 
@@ -852,6 +881,9 @@ struct InMemoryRowGroup<'a> {
     metadata: &'a RowGroupMetaData,
     offset_index: Option<&'a [OffsetIndexMetaData]>,
     column_chunks: Vec<Option<Arc<ColumnChunkData>>>,
+    /// The decompressed pages of column chunks found in `page_cache`
+    cached_pages: Vec<Option<Arc<[Page]>>>,
+    page_cache: Option<&'a FilePageCache>,
     row_count: usize,
 }
 
@@ -863,6 +895,18 @@ impl InMemoryRowGroup<'_> {
         projection: &ProjectionMask,
         selection: Option<&RowSelection>,
     ) -> Result<()> {
+        if let Some(page_cache) = self.page_cache {
+            for (idx, cached) in self.cached_pages.iter_mut().enumerate() {
+                if cached.is_none()
+                    && self.column_chunks[idx].is_none()
+                    && projection.leaf_included(idx)
+                {
+                    let (start, _) = self.metadata.column(idx).byte_range();
+                    *cached = page_cache.cache.get(&page_cache.file, start);
+                }
+            }
+        }
+
         if let Some((selection, offset_index)) = selection.zip(self.offset_index) {
             // If we have a `RowSelection` and an `OffsetIndex` then only fetch pages required for the
             // `RowSelection`
@@ -874,7 +918,9 @@ impl InMemoryRowGroup<'_> {
                 .zip(self.metadata.columns())
                 .enumerate()
                 .filter(|&(idx, (chunk, _chunk_meta))| {
-                    chunk.is_none() && projection.leaf_included(idx)
+                    chunk.is_none()
+                        && self.cached_pages[idx].is_none()
+                        && projection.leaf_included(idx)
                 })
                 .flat_map(|(idx, (_chunk, chunk_meta))| {
                     // If the first page does not start at the beginning of the column,
@@ -899,7 +945,10 @@ impl InMemoryRowGroup<'_> {
             let mut page_start_offsets = page_start_offsets.into_iter();
 
             for (idx, chunk) in self.column_chunks.iter_mut().enumerate() {
-                if chunk.is_some() || !projection.leaf_included(idx) {
+                if chunk.is_some()
+                    || self.cached_pages[idx].is_some()
+                    || !projection.leaf_included(idx)
+                {
                     continue;
                 }
 
@@ -920,7 +969,11 @@ impl InMemoryRowGroup<'_> {
                 .column_chunks
                 .iter()
                 .enumerate()
-                .filter(|&(idx, chunk)| chunk.is_none() && projection.leaf_included(idx))
+                .filter(|&(idx, chunk)| {
+                    chunk.is_none()
+                        && self.cached_pages[idx].is_none()
+                        && projection.leaf_included(idx)
+                })
                 .map(|(idx, _chunk)| {
                     let column = self.metadata.column(idx);
                     let (start, length) = column.byte_range();
@@ -931,7 +984,10 @@ impl InMemoryRowGroup<'_> {
             let mut chunk_data = input.get_byte_ranges(fetch_ranges).await?.into_iter();
 
             for (idx, chunk) in self.column_chunks.iter_mut().enumerate() {
-                if chunk.is_some() || !projection.leaf_included(idx) {
+                if chunk.is_some()
+                    || self.cached_pages[idx].is_some()
+                    || !projection.leaf_included(idx)
+                {
                     continue;
                 }
 
@@ -954,23 +1010,49 @@ impl RowGroups for InMemoryRowGroup<'_> {
     }
 
     fn column_chunks(&self, i: usize) -> Result<Box<dyn PageIterator>> {
+        let page_locations = self
+            .offset_index
+            // filter out empty offset indexes (old versions specified Some(vec![]) when no present)
+            .filter(|index| !index.is_empty())
+            .map(|index| index[i].page_locations.clone());
+
+        let (start, _) = self.metadata.column(i).byte_range();
+        let cached = match (&self.cached_pages[i], self.page_cache) {
+            (Some(pages), _) => Some(pages.clone()),
+            (None, Some(page_cache)) => page_cache.cache.get(&page_cache.file, start),
+            (None, None) => None,
+        };
+        if let Some(pages) = cached {
+            let page_reader = CachedPageReader::new(pages, page_locations, self.row_count);
+            return Ok(Box::new(ColumnChunkIterator {
+                reader: Some(Ok(Box::new(page_reader))),
+            }));
+        }
+
         match &self.column_chunks[i] {
             None => Err(ParquetError::General(format!(
                 "Invalid column index {i}, column was not fetched"
             ))),
             Some(data) => {
-                let page_locations = self
-                    .offset_index
-                    // filter out empty offset indexes (old versions specified Some(vec![]) when no present)
-                    .filter(|index| !index.is_empty())
-                    .map(|index| index[i].page_locations.clone());
-                let page_reader: Box<dyn PageReader> = Box::new(SerializedPageReader::new(
+                let mut page_reader: Box<dyn PageReader> = Box::new(SerializedPageReader::new(
                     data.clone(),
                     self.metadata.column(i),
                     self.row_count,
-                    page_locations,
+                    page_locations.clone(),
                 )?);
 
+                // Only column chunks that were fetched in full can be cached
+                if let (Some(page_cache), ColumnChunkData::Dense { .. }) =
+                    (self.page_cache, data.as_ref())
+                {
+                    let pages: Arc<[Page]> = page_reader.collect::<Result<Vec<_>>>()?.into();
+                    page_cache
+                        .cache
+                        .insert(&page_cache.file, start, pages.clone());
+                    page_reader =
+                        Box::new(CachedPageReader::new(pages, page_locations, self.row_count));
+                }
+
                 Ok(Box::new(ColumnChunkIterator {
                     reader: Some(Ok(page_reader)),
                 }))
@@ -1053,7 +1135,8 @@ impl PageIterator for ColumnChunkIterator {}
 mod tests {
     use super::*;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowSelector,
+        ArrowColumnPredicateFn, ArrowPredicate, ArrowPredicateFn, ParquetRecordBatchReaderBuilder,
+        RowGroupPrunerFn, RowSelector,
     };
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
     use crate::arrow::ArrowWriter;
@@ -1068,7 +1151,8 @@ mod tests {
         Array, ArrayRef, Int32Array, Int8Array, RecordBatchReader, Scalar, StringArray,
         StructArray, UInt64Array,
     };
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{DataType, Field, Fields, Schema};
     use futures::{StreamExt, TryStreamExt};
     use rand::{thread_rng, Rng};
     use std::collections::HashMap;
@@ -1596,6 +1680,263 @@ mod tests {
         assert_eq!(requests.lock().unwrap().len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_column_predicate_nested() {
+        let keys = StringArray::from(vec![
+            Some("x"),
+            Some("y"),
+            Some("x"),
+            None,
+            Some("x"),
+            Some("z"),
+        ]);
+        let values = Int32Array::from_iter_values(0..6);
+        let fields = Fields::from(vec![
+            Field::new("key", DataType::Utf8, true),
+            Field::new("value", DataType::Int32, false),
+        ]);
+        let nulls = NullBuffer::from(vec![true, true, true, true, false, true]);
+        let attributes = StructArray::new(
+            fields,
+            vec![Arc::new(keys) as ArrayRef, Arc::new(values) as ArrayRef],
+            Some(nulls),
+        );
+        let mut tags = ListBuilder::new(StringBuilder::new());
+        for _ in 0..6 {
+            tags.append_value([Some("t")]);
+        }
+        let data = RecordBatch::try_from_iter([
+            (
+                "id",
+                Arc::new(Int32Array::from_iter_values(0..6)) as ArrayRef,
+            ),
+            ("attributes", Arc::new(attributes) as ArrayRef),
+            ("tags", Arc::new(tags.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), None).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let data: Bytes = buf.into();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let parquet_schema = metadata.file_metadata().schema_descr_ptr();
+        let (value_start, value_len) = metadata.row_group(0).column(2).byte_range();
+
+        let test = TestReader {
+            data,
+            metadata: Arc::new(metadata),
+            requests: Default::default(),
+        };
+        let requests = test.requests.clone();
+
+        let scalar = StringArray::from_iter_values(["x"]);
+        let predicate =
+            ArrowColumnPredicateFn::try_new(&parquet_schema, "attributes.key", move |array| {
+                // Only the key is decoded, and is null where attributes is null
+                assert_eq!(array.data_type(), &DataType::Utf8);
+                assert_eq!(array.null_count(), 2);
+                eq(array, &Scalar::new(&scalar))
+            })
+            .unwrap();
+        assert_eq!(
+            predicate.projection(),
+            &ProjectionMask::leaves(&parquet_schema, [1])
+        );
+
+        let stream = ParquetRecordBatchStreamBuilder::new(test)
+            .await
+            .unwrap()
+            .with_projection(ProjectionMask::leaves(&parquet_schema, [0]))
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .build()
+            .unwrap();
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+        let ids = batches[0].column(0).as_primitive::<Int32Type>();
+        assert_eq!(ids.values(), &[0, 2]);
+
+        // The value column of attributes is never fetched
+        let value_range = value_start as usize..(value_start + value_len) as usize;
+        for request in requests.lock().unwrap().iter() {
+            assert!(request.end <= value_range.start || request.start >= value_range.end);
+        }
+
+        let err = ArrowColumnPredicateFn::try_new(
+            &parquet_schema,
+            "tags.list.element",
+            |_| unreachable!(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"tags.list.element\" is nested within a repeated field"
+        );
+
+        let err = ArrowColumnPredicateFn::try_new(
+            &parquet_schema,
+            "attributes.missing",
+            |_| unreachable!(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"attributes.missing\" not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_row_group_pruner() {
+        let data = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        )])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let data: Bytes = buf.into();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let test = TestReader {
+            data,
+            metadata: Arc::new(metadata),
+            requests: Default::default(),
+        };
+        let requests = test.requests.clone();
+
+        // Skip the second and third row groups, and the rows selected from them
+        let pruner = RowGroupPrunerFn::new(|_: &ParquetMetaData, row_groups: &[usize]| {
+            Ok(row_groups.iter().map(|idx| *idx % 3 == 0).collect())
+        });
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(20),
+            RowSelector::select(60),
+            RowSelector::skip(20),
+        ]);
+        let stream = ParquetRecordBatchStreamBuilder::new(test)
+            .await
+            .unwrap()
+            .with_row_selection(selection)
+            .with_row_group_pruner(pruner)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        let expected: Vec<_> = (20..25).chain(75..80).collect();
+        assert_eq!(values, expected);
+
+        // Only the first and last row groups are fetched
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_page_cache() {
+        let a = StringArray::from_iter_values((0..100).map(|x| format!("v{}", x % 7)));
+        let b = Int32Array::from_iter((0..100).map(|x| (x % 3 != 0).then_some(x)));
+        let data = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_compression(crate::basic::Compression::SNAPPY)
+            .set_max_row_group_size(50)
+            .set_write_batch_size(10)
+            .set_data_page_row_count_limit(10)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let data_bytes: Bytes = buf.into();
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_indexes(true)
+            .parse_and_finish(&data_bytes)
+            .unwrap();
+        let metadata = Arc::new(metadata);
+
+        let cache = Arc::new(PageCache::new(1024 * 1024));
+        let scan = |file: &'static str, selection: Option<RowSelection>| {
+            let test = TestReader {
+                data: data_bytes.clone(),
+                metadata: metadata.clone(),
+                requests: Default::default(),
+            };
+            let requests = test.requests.clone();
+            let cache = cache.clone();
+            async move {
+                let options = ArrowReaderOptions::new().with_page_index(true);
+                let mut builder = ParquetRecordBatchStreamBuilder::new_with_options(test, options)
+                    .await
+                    .unwrap()
+                    .with_page_cache(cache, file);
+                if let Some(selection) = selection {
+                    builder = builder.with_row_selection(selection);
+                }
+                let batches: Vec<_> = builder.build().unwrap().try_collect().await.unwrap();
+                let batch =
+                    arrow_select::concat::concat_batches(&batches[0].schema(), &batches).unwrap();
+                let requests = requests.lock().unwrap().len();
+                (batch, requests)
+            }
+        };
+
+        // The first scan populates the cache
+        let (batch, requests) = scan("file", None).await;
+        assert_eq!(batch, data);
+        assert!(requests > 0);
+        assert_eq!(cache.len(), 4);
+        let memory_size = cache.memory_size();
+        assert!(memory_size > 0);
+
+        // Subsequent scans of the same file do not fetch any data
+        let (batch, requests) = scan("file", None).await;
+        assert_eq!(batch, data);
+        assert_eq!(requests, 0);
+
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(25),
+            RowSelector::select(10),
+            RowSelector::skip(40),
+            RowSelector::select(25),
+        ]);
+        let (batch, requests) = scan("file", Some(selection)).await;
+        let expected = arrow_select::concat::concat_batches(
+            &data.schema(),
+            &[data.slice(25, 10), data.slice(75, 25)],
+        )
+        .unwrap();
+        assert_eq!(batch, expected);
+        assert_eq!(requests, 0);
+        assert_eq!(cache.memory_size(), memory_size);
+
+        // A different file key does not share pages
+        let (batch, requests) = scan("other", None).await;
+        assert_eq!(batch, data);
+        assert!(requests > 0);
+        assert_eq!(cache.len(), 8);
+    }
+
     #[tokio::test]
     async fn test_limit_multiple_row_groups() {
         let a = StringArray::from_iter_values(["a", "b", "b", "b", "c", "c"]);
@@ -1805,6 +2146,7 @@ mod tests {
             metadata,
             fields: fields.map(Arc::new),
             input: async_reader,
+            page_cache: None,
             filter: None,
             limit: None,
             offset: None,
@@ -0,0 +1,296 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::sync::{Arc, Mutex};
+
+use crate::column::page::{Page, PageMetadata, PageReader};
+use crate::errors::Result;
+use crate::format::PageLocation;
+
+/// A cache of decompressed pages, shared between [`ParquetRecordBatchStream`]s
+///
+/// Interactive query engines often scan the same files repeatedly. Once a column
+/// chunk has been read in full, its decompressed dictionary and data pages are stored
+/// in this cache, and subsequent streams configured with the same cache and file key
+/// (see [`ParquetRecordBatchStreamBuilder::with_page_cache`]) neither fetch nor
+/// decompress that column chunk again.
+///
+/// Column chunks are identified by the file key and their offset within the file.
+/// The cache holds at most [`Self::capacity`] bytes of page data, evicting the least
+/// recently used column chunks once full.
+///
+/// Note: column chunks read through a [`RowSelection`] using the page index are only
+/// partially fetched, and are therefore not cached.
+///
+/// [`ParquetRecordBatchStream`]: super::ParquetRecordBatchStream
+/// [`ParquetRecordBatchStreamBuilder::with_page_cache`]: super::ParquetRecordBatchStreamBuilder::with_page_cache
+/// [`RowSelection`]: crate::arrow::arrow_reader::RowSelection
+pub struct PageCache {
+    capacity: usize,
+    state: Mutex<PageCacheState>,
+}
+
+#[derive(Default)]
+struct PageCacheState {
+    entries: HashMap<(Arc<str>, u64), PageCacheEntry>,
+    memory_size: usize,
+    /// Incremented on every access, used to find the least recently used entry
+    tick: u64,
+}
+
+struct PageCacheEntry {
+    pages: Arc<[Page]>,
+    memory_size: usize,
+    last_used: u64,
+}
+
+impl std::fmt::Debug for PageCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PageCache")
+            .field("capacity", &self.capacity)
+            .field("memory_size", &self.memory_size())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl PageCache {
+    /// Creates a new, empty [`PageCache`] holding at most `capacity` bytes of page data
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Returns the maximum number of bytes of page data held by this cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes of page data currently held by this cache
+    pub fn memory_size(&self) -> usize {
+        self.state.lock().unwrap().memory_size
+    }
+
+    /// Returns the number of column chunks currently held by this cache
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Returns `true` if this cache holds no column chunks
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all column chunks from this cache
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.memory_size = 0;
+    }
+
+    /// Returns the pages of the column chunk at `offset` in `file`, if cached
+    pub(crate) fn get(&self, file: &Arc<str>, offset: u64) -> Option<Arc<[Page]>> {
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(&(file.clone(), offset))?;
+        entry.last_used = tick;
+        Some(entry.pages.clone())
+    }
+
+    /// Inserts the pages of the column chunk at `offset` in `file`, evicting the least
+    /// recently used column chunks as necessary
+    pub(crate) fn insert(&self, file: &Arc<str>, offset: u64, pages: Arc<[Page]>) {
+        let memory_size = pages.iter().map(|p| p.buffer().len()).sum();
+        if memory_size > self.capacity {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let entry = PageCacheEntry {
+            pages,
+            memory_size,
+            last_used: state.tick,
+        };
+        if let Some(old) = state.entries.insert((file.clone(), offset), entry) {
+            state.memory_size -= old.memory_size;
+        }
+        state.memory_size += memory_size;
+
+        while state.memory_size > self.capacity {
+            let key = state
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone())
+                .unwrap();
+            let evicted = state.entries.remove(&key).unwrap();
+            state.memory_size -= evicted.memory_size;
+        }
+    }
+}
+
+/// A [`PageCache`] and the key identifying a file within it
+#[derive(Debug, Clone)]
+pub(crate) struct FilePageCache {
+    pub(crate) cache: Arc<PageCache>,
+    pub(crate) file: Arc<str>,
+}
+
+/// A [`PageReader`] over the cached pages of a column chunk
+pub(crate) struct CachedPageReader {
+    pages: Arc<[Page]>,
+    /// The index of the next page in `pages`
+    next_page: usize,
+    /// The index of the next data page, used to index `page_locations`
+    next_data_page: usize,
+    page_locations: Option<Vec<PageLocation>>,
+    /// The number of rows in the row group
+    row_count: usize,
+}
+
+impl CachedPageReader {
+    pub(crate) fn new(
+        pages: Arc<[Page]>,
+        page_locations: Option<Vec<PageLocation>>,
+        row_count: usize,
+    ) -> Self {
+        Self {
+            pages,
+            next_page: 0,
+            next_data_page: 0,
+            page_locations,
+            row_count,
+        }
+    }
+}
+
+impl Iterator for CachedPageReader {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_page().transpose()
+    }
+}
+
+impl PageReader for CachedPageReader {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        let page = self.pages.get(self.next_page).cloned();
+        if let Some(page) = &page {
+            self.next_page += 1;
+            if !matches!(page, Page::DictionaryPage { .. }) {
+                self.next_data_page += 1;
+            }
+        }
+        Ok(page)
+    }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        let Some(page) = self.pages.get(self.next_page) else {
+            return Ok(None);
+        };
+        let metadata = match page {
+            Page::DictionaryPage { .. } => PageMetadata {
+                num_rows: None,
+                num_levels: None,
+                is_dict: true,
+            },
+            Page::DataPage { num_values, .. } => {
+                let num_rows = self.page_locations.as_ref().and_then(|locations| {
+                    let first = locations.get(self.next_data_page)?.first_row_index as usize;
+                    let next = match locations.get(self.next_data_page + 1) {
+                        Some(next) => next.first_row_index as usize,
+                        None => self.row_count,
+                    };
+                    Some(next - first)
+                });
+                PageMetadata {
+                    num_rows,
+                    num_levels: Some(*num_values as _),
+                    is_dict: false,
+                }
+            }
+            Page::DataPageV2 {
+                num_values,
+                num_rows,
+                ..
+            } => PageMetadata {
+                num_rows: Some(*num_rows as _),
+                num_levels: Some(*num_values as _),
+                is_dict: false,
+            },
+        };
+        Ok(Some(metadata))
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        self.get_next_page()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::Encoding;
+    use bytes::Bytes;
+
+    fn page(len: usize) -> Page {
+        Page::DictionaryPage {
+            buf: Bytes::from(vec![0; len]),
+            num_values: 1,
+            encoding: Encoding::PLAIN,
+            is_sorted: false,
+        }
+    }
+
+    #[test]
+    fn test_page_cache_eviction() {
+        let cache = PageCache::new(100);
+        let a: Arc<str> = "a".into();
+        let b: Arc<str> = "b".into();
+
+        cache.insert(&a, 0, vec![page(40)].into());
+        cache.insert(&a, 40, vec![page(20), page(20)].into());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.memory_size(), 80);
+        assert!(cache.get(&b, 0).is_none());
+
+        // Access the first column chunk, so the second is evicted
+        assert_eq!(cache.get(&a, 0).unwrap().len(), 1);
+        cache.insert(&b, 0, vec![page(30)].into());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.memory_size(), 70);
+        assert!(cache.get(&a, 40).is_none());
+        assert!(cache.get(&a, 0).is_some());
+        assert!(cache.get(&b, 0).is_some());
+
+        // Too large to cache
+        cache.insert(&b, 30, vec![page(101)].into());
+        assert!(cache.get(&b, 30).is_none());
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.memory_size(), 0);
+    }
+}
@@ -18,13 +18,109 @@
 use std::{ops::Range, sync::Arc};
 
 use bytes::Bytes;
-use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use futures::{future::BoxFuture, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use object_store::{path::Path, ObjectMeta, ObjectStore};
 use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
 
 use crate::arrow::async_reader::AsyncFileReader;
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
+
+/// Configures how a [`ParquetObjectReader`] fetches byte ranges
+///
+/// Requests for multiple byte ranges, such as the column chunks of a row group, are
+/// coalesced into fewer, larger requests which are then made concurrently. On high
+/// latency links, such as to S3, wide projections benefit from allowing more concurrent
+/// requests, and from reading ahead the same column chunks of the next row group while
+/// the current one is being decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchOptions {
+    max_gap: usize,
+    max_concurrent_ranges: usize,
+    read_ahead: bool,
+}
+
+impl Default for PrefetchOptions {
+    fn default() -> Self {
+        Self {
+            max_gap: 1024 * 1024,
+            max_concurrent_ranges: 10,
+            read_ahead: false,
+        }
+    }
+}
+
+impl PrefetchOptions {
+    /// Create a new [`PrefetchOptions`] with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Coalesce byte ranges less than or equal to `max_gap` bytes apart into
+    /// a single request. Defaults to 1MiB
+    pub fn with_max_gap(self, max_gap: usize) -> Self {
+        Self { max_gap, ..self }
+    }
+
+    /// Make at most `max_concurrent_ranges` requests concurrently. Defaults to 10
+    ///
+    /// # Panics
+    ///
+    /// If `max_concurrent_ranges` is 0
+    pub fn with_max_concurrent_ranges(self, max_concurrent_ranges: usize) -> Self {
+        assert!(
+            max_concurrent_ranges > 0,
+            "max_concurrent_ranges must be greater than 0"
+        );
+        Self {
+            max_concurrent_ranges,
+            ..self
+        }
+    }
+
+    /// Read ahead the next row group. Defaults to `false`
+    ///
+    /// If enabled, once the column chunks of a row group are requested, the same
+    /// column chunks of the following row group are fetched in the background. This
+    /// requires the reader's metadata to have been loaded with
+    /// [`AsyncFileReader::get_metadata`], and a tokio runtime to spawn the fetch on.
+    ///
+    /// Read ahead is speculative: if the following row group is not read next, for
+    /// example because it was skipped, the fetched data is discarded.
+    pub fn with_read_ahead(self, read_ahead: bool) -> Self {
+        Self { read_ahead, ..self }
+    }
+
+    /// Returns the maximum gap between coalesced byte ranges
+    pub fn max_gap(&self) -> usize {
+        self.max_gap
+    }
+
+    /// Returns the maximum number of concurrent requests
+    pub fn max_concurrent_ranges(&self) -> usize {
+        self.max_concurrent_ranges
+    }
+
+    /// Returns whether the next row group is read ahead
+    pub fn read_ahead(&self) -> bool {
+        self.read_ahead
+    }
+}
+
+/// An in-progress fetch of the column chunks of a row group, see [`PrefetchOptions::with_read_ahead`]
+#[derive(Debug)]
+struct ReadAhead {
+    row_group: usize,
+    ranges: Vec<Range<usize>>,
+    handle: JoinHandle<Result<Vec<Bytes>>>,
+}
+
+impl Drop for ReadAhead {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
 
 /// Reads Parquet files in object storage using [`ObjectStore`].
 ///
@@ -50,7 +146,7 @@ use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 /// print_parquet_metadata(&mut stdout(), builder.metadata());
 /// # }
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct ParquetObjectReader {
     store: Arc<dyn ObjectStore>,
     meta: ObjectMeta,
@@ -58,6 +154,27 @@ pub struct ParquetObjectReader {
     preload_column_index: bool,
     preload_offset_index: bool,
     runtime: Option<Handle>,
+    prefetch: Option<PrefetchOptions>,
+    /// The metadata returned by [`AsyncFileReader::get_metadata`], used for read ahead
+    metadata: Option<Arc<ParquetMetaData>>,
+    read_ahead: Vec<ReadAhead>,
+}
+
+impl Clone for ParquetObjectReader {
+    /// Clones this reader, without any in-progress read ahead
+    fn clone(&self) -> Self {
+        Self {
+            store: Arc::clone(&self.store),
+            meta: self.meta.clone(),
+            metadata_size_hint: self.metadata_size_hint,
+            preload_column_index: self.preload_column_index,
+            preload_offset_index: self.preload_offset_index,
+            runtime: self.runtime.clone(),
+            prefetch: self.prefetch,
+            metadata: self.metadata.clone(),
+            read_ahead: vec![],
+        }
+    }
 }
 
 impl ParquetObjectReader {
@@ -72,6 +189,9 @@ impl ParquetObjectReader {
             preload_column_index: false,
             preload_offset_index: false,
             runtime: None,
+            prefetch: None,
+            metadata: None,
+            read_ahead: vec![],
         }
     }
 
@@ -115,6 +235,16 @@ impl ParquetObjectReader {
         }
     }
 
+    /// Configure how byte ranges are coalesced and prefetched, see [`PrefetchOptions`]
+    ///
+    /// If not set, byte ranges are fetched with [`ObjectStore::get_ranges`]
+    pub fn with_prefetch_options(self, options: PrefetchOptions) -> Self {
+        Self {
+            prefetch: Some(options),
+            ..self
+        }
+    }
+
     fn spawn<F, O, E>(&self, f: F) -> BoxFuture<'_, Result<O>>
     where
         F: for<'a> FnOnce(&'a Arc<dyn ObjectStore>, &'a Path) -> BoxFuture<'a, Result<O, E>>
@@ -154,7 +284,47 @@ impl AsyncFileReader for ParquetObjectReader {
     where
         Self: Send,
     {
-        self.spawn(|store, path| async move { store.get_ranges(path, &ranges).await }.boxed())
+        let Some(options) = self.prefetch else {
+            return self
+                .spawn(|store, path| async move { store.get_ranges(path, &ranges).await }.boxed());
+        };
+
+        Box::pin(async move {
+            let row_group = self
+                .metadata
+                .as_ref()
+                .and_then(|metadata| find_row_group(metadata, &ranges));
+
+            // Take any matching read ahead, and drop, aborting, those made obsolete by this request
+            let mut read_ahead = None;
+            for r in std::mem::take(&mut self.read_ahead) {
+                if read_ahead.is_none() && r.ranges == ranges {
+                    read_ahead = Some(r);
+                } else if !row_group.is_some_and(|row_group| r.row_group <= row_group) {
+                    self.read_ahead.push(r);
+                }
+            }
+
+            if options.read_ahead {
+                if let Some(row_group) = row_group {
+                    self.start_read_ahead(options, row_group + 1, &ranges);
+                }
+            }
+
+            match read_ahead {
+                Some(mut r) => match (&mut r.handle).await {
+                    Ok(result) => result,
+                    Err(e) => match e.try_into_panic() {
+                        Err(e) => Err(ParquetError::External(Box::new(e))),
+                        Ok(p) => std::panic::resume_unwind(p),
+                    },
+                },
+                None => {
+                    self.spawn(move |store, path| fetch_ranges(store, path, ranges, options))
+                        .await
+                }
+            }
+        })
     }
 
     // This method doesn't directly call `self.spawn` because all of the IO that is done down the
@@ -170,13 +340,131 @@ impl AsyncFileReader for ParquetObjectReader {
                 .with_column_indexes(self.preload_column_index)
                 .with_offset_indexes(self.preload_offset_index)
                 .with_prefetch_hint(self.metadata_size_hint)
-                .load_and_finish(self, file_size)
+                .load_and_finish(&mut *self, file_size)
                 .await?;
-            Ok(Arc::new(metadata))
+            let metadata = Arc::new(metadata);
+            self.metadata = Some(Arc::clone(&metadata));
+            Ok(metadata)
         })
     }
 }
 
+impl ParquetObjectReader {
+    /// Starts fetching the column chunks of `row_group` that correspond to the
+    /// column chunks with byte `ranges` in the preceding row group
+    fn start_read_ahead(
+        &mut self,
+        options: PrefetchOptions,
+        row_group: usize,
+        ranges: &[Range<usize>],
+    ) {
+        let Some(metadata) = &self.metadata else {
+            return;
+        };
+        if row_group >= metadata.num_row_groups()
+            || self.read_ahead.iter().any(|r| r.row_group == row_group)
+        {
+            return;
+        }
+        let Some(runtime) = self.runtime.clone().or_else(|| Handle::try_current().ok()) else {
+            return;
+        };
+
+        let previous = metadata.row_group(row_group - 1);
+        let next = metadata.row_group(row_group);
+        let ranges = ranges
+            .iter()
+            .map(|range| {
+                let column = (0..previous.num_columns())
+                    .find(|idx| column_range(previous.column(*idx)) == *range)
+                    .unwrap();
+                column_range(next.column(column))
+            })
+            .collect::<Vec<_>>();
+
+        let store = Arc::clone(&self.store);
+        let path = self.meta.location.clone();
+        let fetch = ranges.clone();
+        let handle =
+            runtime.spawn(async move { fetch_ranges(&store, &path, fetch, options).await });
+        self.read_ahead.push(ReadAhead {
+            row_group,
+            ranges,
+            handle,
+        });
+    }
+}
+
+/// Returns the byte range of the column chunk with `metadata`
+fn column_range(metadata: &ColumnChunkMetaData) -> Range<usize> {
+    let (start, length) = metadata.byte_range();
+    start as usize..(start + length) as usize
+}
+
+/// Returns the index of the row group if `ranges` are the byte ranges of some of its column chunks
+fn find_row_group(metadata: &ParquetMetaData, ranges: &[Range<usize>]) -> Option<usize> {
+    let first = ranges.first()?;
+    let row_group = metadata.row_groups().iter().position(|row_group| {
+        row_group
+            .columns()
+            .iter()
+            .any(|column| column_range(column) == *first)
+    })?;
+    let columns = metadata.row_group(row_group).columns();
+    ranges
+        .iter()
+        .all(|range| columns.iter().any(|column| column_range(column) == *range))
+        .then_some(row_group)
+}
+
+/// Fetches `ranges` from the object at `path`, coalescing and making concurrent
+/// requests as configured by `options`
+fn fetch_ranges<'a>(
+    store: &'a Arc<dyn ObjectStore>,
+    path: &'a Path,
+    ranges: Vec<Range<usize>>,
+    options: PrefetchOptions,
+) -> BoxFuture<'a, Result<Vec<Bytes>>> {
+    async move {
+        let fetch_ranges = merge_ranges(&ranges, options.max_gap);
+        let fetched: Vec<Bytes> = futures::stream::iter(fetch_ranges.iter().cloned())
+            .map(|range| store.get_range(path, range))
+            .buffered(options.max_concurrent_ranges)
+            .try_collect()
+            .await?;
+
+        Ok(ranges
+            .iter()
+            .map(|range| {
+                let idx = fetch_ranges.partition_point(|v| v.start <= range.start) - 1;
+                let fetch_range = &fetch_ranges[idx];
+                let start = range.start - fetch_range.start;
+                let end = range.end - fetch_range.start;
+                fetched[idx].slice(start..end.min(fetched[idx].len()))
+            })
+            .collect())
+    }
+    .boxed()
+}
+
+/// Returns a sorted list of ranges that cover `ranges`, merging ranges at most
+/// `max_gap` bytes apart
+fn merge_ranges(ranges: &[Range<usize>], max_gap: usize) -> Vec<Range<usize>> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(max_gap) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{
@@ -193,8 +481,13 @@ mod tests {
     use object_store::{ObjectMeta, ObjectStore};
 
     use crate::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
-    use crate::arrow::ParquetRecordBatchStreamBuilder;
+    use crate::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
     use crate::errors::ParquetError;
+    use crate::file::properties::WriterProperties;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use object_store::memory::InMemory;
+
+    use super::*;
 
     async fn get_meta_store() -> (ObjectMeta, Arc<dyn ObjectStore>) {
         let res = parquet_test_data();
@@ -317,4 +610,77 @@ mod tests {
 
         assert!(err.to_string().contains("was cancelled"));
     }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert!(merge_ranges(&[], 10).is_empty());
+        assert_eq!(
+            merge_ranges(&[30..40, 0..10, 15..20, 5..12], 2),
+            vec![0..12, 15..20, 30..40]
+        );
+        assert_eq!(merge_ranges(&[30..40, 0..10, 15..20], 10), vec![0..40]);
+        assert_eq!(merge_ranges(&[0..10, 10..20], 0), vec![0..20]);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_options() {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values((0..100).map(|x| format!("value{x}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let path = Path::from("file.parquet");
+        store.put(&path, buf.into()).await.unwrap();
+        let meta = store.head(&path).await.unwrap();
+
+        let options = PrefetchOptions::new()
+            .with_max_gap(0)
+            .with_max_concurrent_ranges(1)
+            .with_read_ahead(true);
+        let reader =
+            ParquetObjectReader::new(store.clone(), meta.clone()).with_prefetch_options(options);
+        let builder = ParquetRecordBatchStreamBuilder::new(reader).await.unwrap();
+        let batches: Vec<_> = builder.build().unwrap().try_collect().await.unwrap();
+        let read = arrow_select::concat::concat_batches(&batch.schema(), &batches).unwrap();
+        assert_eq!(read, batch);
+
+        // Requesting the column chunks of a row group reads ahead the next row group
+        let mut reader =
+            ParquetObjectReader::new(store.clone(), meta).with_prefetch_options(options);
+        let metadata = reader.get_metadata().await.unwrap();
+        let ranges = |row_group: usize| vec![column_range(metadata.row_group(row_group).column(1))];
+
+        let data = reader.get_byte_ranges(ranges(0)).await.unwrap();
+        assert_eq!(data, store.get_ranges(&path, &ranges(0)).await.unwrap());
+        assert_eq!(reader.read_ahead.len(), 1);
+        assert_eq!(reader.read_ahead[0].row_group, 1);
+        assert_eq!(reader.read_ahead[0].ranges, ranges(1));
+
+        // Skipping a row group discards the read ahead
+        let data = reader.get_byte_ranges(ranges(2)).await.unwrap();
+        assert_eq!(data, store.get_ranges(&path, &ranges(2)).await.unwrap());
+        assert_eq!(reader.read_ahead.len(), 1);
+        assert_eq!(reader.read_ahead[0].row_group, 3);
+
+        let data = reader.get_byte_ranges(ranges(3)).await.unwrap();
+        assert_eq!(data, store.get_ranges(&path, &ranges(3)).await.unwrap());
+        assert!(reader.read_ahead.is_empty());
+
+        // Ranges that are not column chunks are not read ahead
+        let data = reader.get_byte_ranges(vec![4..10, 0..4]).await.unwrap();
+        assert_eq!(data, store.get_ranges(&path, &[4..10, 0..4]).await.unwrap());
+        assert_eq!(&data[1], b"PAR1".as_slice());
+        assert!(reader.read_ahead.is_empty());
+    }
 }
@@ -73,6 +73,8 @@ use futures::FutureExt;
 use std::mem;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
+const INVALID_WRITER: &str = "AsyncArrowWriter cannot be used after a cancelled write";
+
 /// The asynchronous interface used by [`AsyncArrowWriter`] to write parquet files.
 pub trait AsyncFileWriter: Send {
     /// Write the provided bytes to the underlying writer
@@ -132,6 +134,21 @@ impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
 /// although this will likely increase overall file size and reduce query performance.
 /// See [ArrowWriter] for more information.
 ///
+/// ## Parallel Encoding
+///
+/// Encoding and compressing column chunks is CPU-bound, and by default happens on the
+/// task calling [`Self::write`]. Setting [`WriterPropertiesBuilder::set_max_parallelism`]
+/// encodes the independent column chunks of each row group concurrently on a pool of
+/// threads, with the encoded column chunks still written to the [`AsyncFileWriter`]
+/// in order.
+///
+/// When encoding in parallel within a tokio runtime, the encoding is awaited with
+/// `tokio::task::spawn_blocking`, and so does not block the executor. If a write is
+/// cancelled whilst encoding, the writer is poisoned: further writes return an error,
+/// and methods such as [`Self::in_progress_size`] report an empty writer.
+///
+/// [`WriterPropertiesBuilder::set_max_parallelism`]: crate::file::properties::WriterPropertiesBuilder::set_max_parallelism
+///
 /// ```no_run
 /// # use tokio::fs::File;
 /// # use arrow_array::RecordBatch;
@@ -150,6 +167,14 @@ pub struct AsyncArrowWriter<W> {
     /// Underlying sync writer
     sync_writer: ArrowWriter<Vec<u8>>,
 
+    /// An empty writer, swapped with `sync_writer` whilst it encodes on a blocking
+    /// thread, see [`Self::encode`]. `None` if not encoding on a blocking thread,
+    /// or once poisoned by a cancelled encode
+    spare_writer: Option<ArrowWriter<Vec<u8>>>,
+
+    /// Whether to encode on a blocking thread, see [`Self::encode`]
+    spawn_blocking: bool,
+
     /// Async writer provided by caller
     async_writer: W,
 }
@@ -171,14 +196,59 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         arrow_schema: SchemaRef,
         options: ArrowWriterOptions,
     ) -> Result<Self> {
-        let sync_writer = ArrowWriter::try_new_with_options(Vec::new(), arrow_schema, options)?;
+        let sync_writer =
+            ArrowWriter::try_new_with_options(Vec::new(), arrow_schema.clone(), options)?;
+        let spawn_blocking = sync_writer.encodes_in_parallel();
+        let spare_writer = match spawn_blocking {
+            true => Some(ArrowWriter::try_new(Vec::new(), arrow_schema, None)?),
+            false => None,
+        };
 
         Ok(Self {
             sync_writer,
+            spare_writer,
+            spawn_blocking,
             async_writer: writer,
         })
     }
 
+    /// Runs `f` with the sync writer, on a blocking thread if encoding in parallel
+    /// within a tokio runtime, so as not to block the executor whilst waiting for
+    /// the encoder threads
+    async fn encode<T, F>(&mut self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ArrowWriter<Vec<u8>>) -> Result<T> + Send + 'static,
+    {
+        if !self.spawn_blocking {
+            return f(&mut self.sync_writer);
+        }
+        let Some(spare_writer) = self.spare_writer.take() else {
+            return Err(general_err!("{}", INVALID_WRITER));
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            self.spare_writer = Some(spare_writer);
+            return f(&mut self.sync_writer);
+        };
+
+        // Until the task completes `sync_writer` is the empty `spare_writer`, which
+        // remains in place, and `spare_writer` `None`, if this future is dropped
+        let mut sync_writer = mem::replace(&mut self.sync_writer, spare_writer);
+        let task = handle.spawn_blocking(move || {
+            let result = f(&mut sync_writer);
+            (sync_writer, result)
+        });
+        let (sync_writer, result) = match task.await {
+            Ok(x) => x,
+            Err(e) => match e.try_into_panic() {
+                Ok(panic) => std::panic::resume_unwind(panic),
+                Err(e) => return Err(ParquetError::External(Box::new(e))),
+            },
+        };
+        self.spare_writer = Some(mem::replace(&mut self.sync_writer, sync_writer));
+        result
+    }
+
     /// Returns metadata for any flushed row groups
     pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
         self.sync_writer.flushed_row_groups()
@@ -213,9 +283,15 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     /// After every sync write by the inner [ArrowWriter], the inner buffer will be
     /// checked and flush if at least half full
     pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
-        let before = self.sync_writer.flushed_row_groups().len();
-        self.sync_writer.write(batch)?;
-        if before != self.sync_writer.flushed_row_groups().len() {
+        let batch = batch.clone();
+        let flushed = self
+            .encode(move |sync_writer| {
+                let before = sync_writer.flushed_row_groups().len();
+                sync_writer.write(&batch)?;
+                Ok(before != sync_writer.flushed_row_groups().len())
+            })
+            .await?;
+        if flushed {
             self.do_write().await?;
         }
         Ok(())
@@ -223,7 +299,7 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
 
     /// Flushes all buffered rows into a new row group
     pub async fn flush(&mut self) -> Result<()> {
-        self.sync_writer.flush()?;
+        self.encode(|sync_writer| sync_writer.flush()).await?;
         self.do_write().await?;
 
         Ok(())
@@ -244,7 +320,7 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     ///
     /// Attempting to write after calling finish will result in an error
     pub async fn finish(&mut self) -> Result<FileMetaData> {
-        let metadata = self.sync_writer.finish()?;
+        let metadata = self.encode(|sync_writer| sync_writer.finish()).await?;
 
         // Force to flush the remaining data.
         self.do_write().await?;
@@ -321,6 +397,73 @@ mod tests {
         assert_eq!(to_write, read);
     }
 
+    #[tokio::test]
+    async fn test_async_writer_parallel() {
+        let columns = (0..5).map(|i| {
+            let col = Int64Array::from_iter_values((0..1000).map(|x| x * i));
+            (format!("col{i}"), Arc::new(col) as ArrayRef)
+        });
+        let to_write = RecordBatch::try_from_iter(columns).unwrap();
+
+        let write = |max_parallelism| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(300)
+                .set_max_parallelism(max_parallelism)
+                .build();
+            let to_write = to_write.clone();
+            async move {
+                let mut buffer = Vec::new();
+                let mut writer =
+                    AsyncArrowWriter::try_new(&mut buffer, to_write.schema(), Some(props)).unwrap();
+                writer.write(&to_write).await.unwrap();
+                writer.close().await.unwrap();
+                buffer
+            }
+        };
+
+        let sequential = write(1).await;
+        let parallel = write(2).await;
+        assert_eq!(sequential, parallel);
+
+        let batches = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(parallel))
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let read = arrow_select::concat::concat_batches(&to_write.schema(), &batches).unwrap();
+        assert_eq!(to_write, read);
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_cancelled() {
+        let columns = (0..5).map(|i| {
+            let col = Int64Array::from_iter_values((0..1_000_000).map(|x| x * i));
+            (format!("col{i}"), Arc::new(col) as ArrayRef)
+        });
+        let to_write = RecordBatch::try_from_iter(columns).unwrap();
+        let props = WriterProperties::builder().set_max_parallelism(2).build();
+
+        let mut buffer = Vec::new();
+        let mut writer =
+            AsyncArrowWriter::try_new(&mut buffer, to_write.schema(), Some(props)).unwrap();
+        writer.write(&to_write.slice(0, 10)).await.unwrap();
+        assert_eq!(writer.in_progress_rows(), 10);
+
+        // Cancel a write whilst it is encoding on a blocking thread
+        assert!(writer.write(&to_write).now_or_never().is_none());
+
+        // The writer is poisoned, but does not panic
+        assert_eq!(writer.in_progress_rows(), 0);
+        assert!(writer.flushed_row_groups().is_empty());
+        let err = writer.write(&to_write).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: AsyncArrowWriter cannot be used after a cancelled write"
+        );
+        assert!(writer.close().await.is_err());
+    }
+
     // Read the data from the test file and write it by the async writer and sync writer.
     // And then compares the results of the two writers.
     #[tokio::test]
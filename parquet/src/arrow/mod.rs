@@ -130,12 +130,42 @@ pub use self::schema::{
 /// Schema metadata key used to store serialized Arrow IPC schema
 pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 
+/// Schema metadata key used to store the JSON encoded Avro schema of data decoded from Avro
+///
+/// If present on the arrow schema passed to [`ArrowWriter`], the Avro schema is written to
+/// the key value metadata of the parquet file under [`PARQUET_AVRO_SCHEMA_META_KEY`]
+pub const AVRO_SCHEMA_META_KEY: &str = "avro.schema";
+
+/// Key value metadata key used by parquet-avro to store the Avro schema of a parquet file
+pub const PARQUET_AVRO_SCHEMA_META_KEY: &str = "parquet.avro.schema";
+
+/// Field metadata key used to store the symbols of an Avro enum, as a JSON array, on
+/// columns decoded from Avro
+///
+/// String and dictionary encoded string fields with this key are annotated with the
+/// parquet [`LogicalType::Enum`] logical type, matching the columns written by parquet-avro
+///
+/// [`LogicalType::Enum`]: crate::basic::LogicalType::Enum
+pub const AVRO_ENUM_SYMBOLS_META_KEY: &str = "avro.enum.symbols";
+
 /// The value of this metadata key, if present on [`Field::metadata`], will be used
 /// to populate [`BasicTypeInfo::id`]
 ///
 /// [`Field::metadata`]: arrow_schema::Field::metadata
 /// [`BasicTypeInfo::id`]: crate::schema::types::BasicTypeInfo::id
-pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+pub use arrow_schema::FIELD_ID_META_KEY as PARQUET_FIELD_ID_META_KEY;
+
+/// The arrow extension type name, stored under `ARROW:extension:name` in [`Field::metadata`],
+/// of columns with the parquet [`LogicalType::Variant`] logical type
+///
+/// Variant columns are read as a [`DataType::Struct`] with a binary `metadata` field,
+/// and an optional binary `value` and, if shredded, an optional `typed_value` field.
+/// Struct fields with this extension type are written as Variant columns.
+///
+/// [`Field::metadata`]: arrow_schema::Field::metadata
+/// [`LogicalType::Variant`]: crate::basic::LogicalType::Variant
+/// [`DataType::Struct`]: arrow_schema::DataType::Struct
+pub const PARQUET_VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
 
 /// A [`ProjectionMask`] identifies a set of columns within a potentially nested schema to project
 ///
@@ -360,6 +390,15 @@ mod test {
 
     use super::ProjectionMask;
 
+    #[test]
+    #[cfg(feature = "avro")]
+    fn test_avro_enum_symbols_meta_key() {
+        assert_eq!(
+            super::AVRO_ENUM_SYMBOLS_META_KEY,
+            arrow_avro::reader::ENUM_SYMBOLS_METADATA_KEY
+        );
+    }
+
     #[test]
     // Reproducer for https://github.com/apache/arrow-rs/issues/6464
     fn test_metadata_read_write_partial_offset() {
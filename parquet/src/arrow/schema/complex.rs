@@ -18,9 +18,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::EXTENSION_TYPE_NAME_KEY;
 use crate::arrow::schema::primitive::convert_primitive;
-use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY};
-use crate::basic::{ConvertedType, Repetition};
+use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY, PARQUET_VARIANT_EXTENSION_NAME};
+use crate::basic::{ConvertedType, LogicalType, Repetition};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
@@ -567,12 +568,20 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
         None => {
             let mut ret = Field::new(name, data_type, nullable);
             let basic_info = parquet_type.get_basic_info();
+            let mut meta = HashMap::new();
             if basic_info.has_id() {
-                let mut meta = HashMap::with_capacity(1);
                 meta.insert(
                     PARQUET_FIELD_ID_META_KEY.to_string(),
                     basic_info.id().to_string(),
                 );
+            }
+            if let Some(LogicalType::Variant { .. }) = basic_info.logical_type() {
+                meta.insert(
+                    EXTENSION_TYPE_NAME_KEY.to_string(),
+                    PARQUET_VARIANT_EXTENSION_NAME.to_string(),
+                );
+            }
+            if !meta.is_empty() {
                 ret.set_metadata(meta);
             }
             ret
@@ -23,6 +23,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_ipc::writer;
+use arrow_schema::extension::{EXTENSION_TYPE_NAME_KEY, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
 use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
 
 use crate::basic::{
@@ -38,7 +39,9 @@ mod primitive;
 use crate::arrow::ProjectionMask;
 pub(crate) use complex::{ParquetField, ParquetFieldType};
 
-use super::PARQUET_FIELD_ID_META_KEY;
+use super::{
+    AVRO_ENUM_SYMBOLS_META_KEY, PARQUET_FIELD_ID_META_KEY, PARQUET_VARIANT_EXTENSION_NAME,
+};
 
 /// Convert Parquet schema to Arrow schema including optional metadata
 ///
@@ -586,7 +589,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 .build()
         }
         DataType::FixedSizeBinary(length) => {
+            let logical_type = match extension_name(field) {
+                Some(UUID_EXTENSION_NAME) if *length == 16 => Some(LogicalType::Uuid),
+                _ => None,
+            };
             Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .with_logical_type(logical_type)
                 .with_repetition(repetition)
                 .with_id(id)
                 .with_length(*length)
@@ -622,8 +630,15 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 .build()
         }
         DataType::Utf8 | DataType::LargeUtf8 => {
+            let logical_type = match extension_name(field) {
+                Some(JSON_EXTENSION_NAME) => LogicalType::Json,
+                _ if field.metadata().contains_key(AVRO_ENUM_SYMBOLS_META_KEY) => {
+                    LogicalType::Enum
+                }
+                _ => LogicalType::String,
+            };
             Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
-                .with_logical_type(Some(LogicalType::String))
+                .with_logical_type(Some(logical_type))
                 .with_repetition(repetition)
                 .with_id(id)
                 .build()
@@ -661,6 +676,15 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             if fields.is_empty() {
                 return Err(arrow_err!("Parquet does not support writing empty structs",));
             }
+            let logical_type = match extension_name(field) {
+                Some(PARQUET_VARIANT_EXTENSION_NAME) => {
+                    check_variant_fields(name, fields)?;
+                    Some(LogicalType::Variant {
+                        specification_version: None,
+                    })
+                }
+                _ => None,
+            };
             // recursively convert children to types/nodes
             let fields = fields
                 .iter()
@@ -668,6 +692,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 .collect::<Result<_>>()?;
             Type::group_type_builder(name)
                 .with_fields(fields)
+                .with_logical_type(logical_type)
                 .with_repetition(repetition)
                 .with_id(id)
                 .build()
@@ -727,6 +752,58 @@ fn field_id(field: &Field) -> Option<i32> {
     value.parse().ok() // Fail quietly if not a valid integer
 }
 
+fn extension_name(field: &Field) -> Option<&str> {
+    field
+        .metadata()
+        .get(EXTENSION_TYPE_NAME_KEY)
+        .map(|x| x.as_str())
+}
+
+/// Validates the fields of a struct with the [`PARQUET_VARIANT_EXTENSION_NAME`] extension type
+fn check_variant_fields(name: &str, fields: &Fields) -> Result<()> {
+    let is_binary = |f: &Field| {
+        matches!(
+            f.data_type(),
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+        )
+    };
+    match fields.find("metadata") {
+        Some((_, f)) if is_binary(f) && !f.is_nullable() => {}
+        _ => {
+            return Err(arrow_err!(
+                "Variant field {} must contain a non-nullable binary metadata field",
+                name
+            ))
+        }
+    }
+    if let Some((_, f)) = fields.find("value") {
+        if !is_binary(f) {
+            return Err(arrow_err!(
+                "Variant field {} must have a binary value field, got {}",
+                name,
+                f.data_type()
+            ));
+        }
+    }
+    if let Some(f) = fields
+        .iter()
+        .find(|f| !matches!(f.name().as_str(), "metadata" | "value" | "typed_value"))
+    {
+        return Err(arrow_err!(
+            "Variant field {} contains unexpected field {}",
+            name,
+            f.name()
+        ));
+    }
+    if fields.len() < 2 {
+        return Err(arrow_err!(
+            "Variant field {} must contain a value or typed_value field",
+            name
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2108,6 +2185,177 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_extension_logical_types() -> Result<()> {
+        let extension = |name: &str| {
+            HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string())])
+        };
+        let schema = Schema::new(vec![
+            Field::new("uuid", DataType::FixedSizeBinary(16), false)
+                .with_metadata(extension(UUID_EXTENSION_NAME)),
+            Field::new("json", DataType::Utf8, true).with_metadata(extension(JSON_EXTENSION_NAME)),
+            Field::new("binary", DataType::FixedSizeBinary(16), false),
+            Field::new("string", DataType::Utf8, true),
+        ]);
+
+        let parquet_schema = ArrowSchemaConverter::new().convert(&schema)?;
+        let logical_types: Vec<_> = parquet_schema
+            .columns()
+            .iter()
+            .map(|c| c.logical_type())
+            .collect();
+        assert_eq!(
+            logical_types,
+            vec![
+                Some(LogicalType::Uuid),
+                Some(LogicalType::Json),
+                None,
+                Some(LogicalType::String)
+            ]
+        );
+
+        // The extension metadata is restored from the serialized arrow schema
+        let file = tempfile::tempfile().unwrap();
+        let writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), Arc::new(schema.clone()), None)?;
+        writer.close()?;
+        let arrow_reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        assert_eq!(&schema, arrow_reader.schema().as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_logical_types() -> Result<()> {
+        use arrow::array::{
+            DictionaryArray, FixedSizeBinaryArray, IntervalMonthDayNanoArray, RecordBatch,
+        };
+        use arrow::datatypes::{Int32Type, IntervalMonthDayNano};
+
+        // The fields of data decoded by arrow-avro
+        let symbols = HashMap::from([(
+            AVRO_ENUM_SYMBOLS_META_KEY.to_string(),
+            r#"["A","B"]"#.to_string(),
+        )]);
+        let enum_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("enum", enum_type, false).with_metadata(symbols),
+            Field::new("uuid", DataType::FixedSizeBinary(16), false).with_metadata(
+                HashMap::from([(
+                    EXTENSION_TYPE_NAME_KEY.to_string(),
+                    UUID_EXTENSION_NAME.to_string(),
+                )]),
+            ),
+            Field::new(
+                "duration",
+                DataType::Interval(IntervalUnit::MonthDayNano),
+                true,
+            ),
+        ]));
+
+        let parquet_schema = ArrowSchemaConverter::new().convert(&schema)?;
+        let columns = parquet_schema.columns();
+        assert_eq!(columns[0].logical_type(), Some(LogicalType::Enum));
+        assert_eq!(columns[0].converted_type(), ConvertedType::ENUM);
+        assert_eq!(columns[1].logical_type(), Some(LogicalType::Uuid));
+        assert_eq!(columns[2].converted_type(), ConvertedType::INTERVAL);
+        assert_eq!(columns[2].type_length(), 12);
+
+        let enums: DictionaryArray<Int32Type> = vec!["B", "A", "B"].into_iter().collect();
+        let uuids = FixedSizeBinaryArray::try_from_iter((0..3u8).map(|x| [x; 16]))?;
+        let durations = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 2, 3_000_000)),
+            None,
+            Some(IntervalMonthDayNano::new(0, -1, 86_400_000_000_000)),
+        ]);
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(enums), Arc::new(uuids), Arc::new(durations)],
+        )?;
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(buf))?.build()?;
+        let read: Vec<_> = reader.collect::<std::result::Result<_, _>>()?;
+        assert_eq!(read, vec![batch]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_variant_logical_type() -> Result<()> {
+        let variant = |fields: Vec<Field>| {
+            Field::new_struct("variant", fields, true).with_metadata(HashMap::from([(
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                PARQUET_VARIANT_EXTENSION_NAME.to_string(),
+            )]))
+        };
+        let schema = Schema::new(vec![variant(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, true),
+        ])]);
+
+        let parquet_schema = ArrowSchemaConverter::new().convert(&schema)?;
+        let variant_type = &parquet_schema.root_schema().get_fields()[0];
+        assert_eq!(
+            variant_type.get_basic_info().logical_type(),
+            Some(LogicalType::Variant {
+                specification_version: None
+            })
+        );
+
+        // The extension type is restored from the parquet logical type
+        let converted = parquet_to_arrow_schema(&parquet_schema, None)?;
+        assert_eq!(converted, schema);
+
+        let err = ArrowSchemaConverter::new()
+            .convert(&Schema::new(vec![variant(vec![
+                Field::new("metadata", DataType::Binary, true),
+                Field::new("value", DataType::Binary, true),
+            ])]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Variant field variant must contain a non-nullable binary metadata field"
+        );
+
+        let err = ArrowSchemaConverter::new()
+            .convert(&Schema::new(vec![variant(vec![
+                Field::new("metadata", DataType::Binary, false),
+                Field::new("value", DataType::Utf8, true),
+            ])]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Variant field variant must have a binary value field, got Utf8"
+        );
+
+        let err = ArrowSchemaConverter::new()
+            .convert(&Schema::new(vec![variant(vec![
+                Field::new("metadata", DataType::Binary, false),
+                Field::new("other", DataType::Binary, true),
+            ])]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Variant field variant contains unexpected field other"
+        );
+
+        let err = ArrowSchemaConverter::new()
+            .convert(&Schema::new(vec![variant(vec![Field::new(
+                "metadata",
+                DataType::Binary,
+                false,
+            )])]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Variant field variant must contain a value or typed_value field"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_arrow_schema_roundtrip_lists() -> Result<()> {
         let metadata: HashMap<String, String> = [("Key".to_string(), "Value".to_string())]
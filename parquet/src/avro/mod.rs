@@ -0,0 +1,288 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Convert [Avro](https://avro.apache.org/) object container files to Parquet
+//!
+//! Records are decoded block by block with [`arrow_avro`] and written with an
+//! [`ArrowWriter`], and so the whole file is never buffered in memory.
+//!
+//! The conversion preserves:
+//!
+//! * The Avro schema, stored under [`PARQUET_AVRO_SCHEMA_META_KEY`], which is the reader
+//!   schema if one is set with [`ConvertOptions::with_reader_schema`]
+//! * The user metadata of the Avro file header, stored as parquet key-value metadata
+//! * Field ids, from the Avro `field-id` attribute
+//! * Logical types, such as `decimal`, `uuid`, `date` and the `timestamp` types
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use std::fs::File;
+//! # use std::io::BufReader;
+//! # use parquet::avro::{convert, ConvertOptions};
+//! # use parquet::basic::Compression;
+//! # use parquet::file::properties::WriterProperties;
+//! let input = BufReader::new(File::open("data.avro").unwrap());
+//! let output = File::create("data.parquet").unwrap();
+//!
+//! let properties = WriterProperties::builder()
+//!     .set_compression(Compression::SNAPPY)
+//!     .build();
+//! let options = ConvertOptions::new().with_properties(properties);
+//!
+//! let metadata = convert(input, output, options).unwrap();
+//! println!("Wrote {} rows", metadata.num_rows);
+//! ```
+//!
+//! [`PARQUET_AVRO_SCHEMA_META_KEY`]: crate::arrow::PARQUET_AVRO_SCHEMA_META_KEY
+
+use crate::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+use crate::arrow::AVRO_SCHEMA_META_KEY;
+use crate::errors::Result;
+use crate::file::metadata::KeyValue;
+use crate::file::properties::WriterProperties;
+use arrow_avro::reader::ReaderBuilder;
+use std::io::{BufRead, Write};
+use std::sync::Arc;
+
+/// Options for [`convert`]
+#[derive(Debug, Clone)]
+pub struct ConvertOptions {
+    properties: WriterProperties,
+    batch_size: usize,
+    reader_schema: Option<String>,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            properties: Default::default(),
+            batch_size: 1024,
+            reader_schema: None,
+        }
+    }
+}
+
+impl ConvertOptions {
+    /// Creates a new [`ConvertOptions`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`WriterProperties`] for writing the parquet file.
+    pub fn with_properties(self, properties: WriterProperties) -> Self {
+        Self { properties, ..self }
+    }
+
+    /// Sets the number of Avro records decoded at a time (defaults to `1024`)
+    ///
+    /// This bounds the memory used by the conversion, the size of the row groups
+    /// is determined by the [`WriterProperties`]
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Sets the Avro reader schema JSON, see [`ReaderBuilder::with_reader_schema`]
+    ///
+    /// This can be used to select, rename or reorder the fields written to parquet
+    pub fn with_reader_schema(self, reader_schema: impl Into<String>) -> Self {
+        Self {
+            reader_schema: Some(reader_schema.into()),
+            ..self
+        }
+    }
+}
+
+/// Converts the Avro object container file read from `reader` to a parquet file
+/// written to `writer`, returning the metadata of the written parquet file
+///
+/// See the [module documentation](self) for more information
+pub fn convert<R: BufRead, W: Write + Send>(
+    reader: R,
+    writer: W,
+    options: ConvertOptions,
+) -> Result<crate::format::FileMetaData> {
+    let mut builder = ReaderBuilder::new()
+        .with_batch_size(options.batch_size)
+        .with_embedded_schema(true);
+    if let Some(reader_schema) = &options.reader_schema {
+        builder = builder.with_reader_schema(reader_schema.clone());
+    }
+    let reader = builder.build(reader)?;
+
+    // The decoded data has the schema it was resolved to, rather than that of the file
+    let mut schema = reader.schema();
+    if let Some(reader_schema) = options.reader_schema {
+        let mut resolved = schema.as_ref().clone();
+        resolved
+            .metadata
+            .insert(AVRO_SCHEMA_META_KEY.to_string(), reader_schema);
+        schema = Arc::new(resolved);
+    }
+
+    let writer_options = ArrowWriterOptions::new().with_properties(options.properties);
+    let mut writer = ArrowWriter::try_new_with_options(writer, schema, writer_options)?;
+
+    let mut metadata: Vec<_> = reader.metadata().iter().collect();
+    metadata.sort_unstable();
+    for (key, value) in metadata {
+        writer.append_key_value_metadata(KeyValue::new(key.clone(), value.clone()));
+    }
+
+    for batch in reader {
+        writer.write(&batch?)?;
+    }
+    writer.close()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::arrow::PARQUET_AVRO_SCHEMA_META_KEY;
+    use crate::basic::LogicalType;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int64Type, TimestampMillisecondType};
+    use arrow_array::{Array, Decimal128Array};
+    use arrow_avro::test_util::{encode_bytes, encode_long, write_ocf_with_metadata};
+    use bytes::Bytes;
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "event",
+        "fields": [
+            {"name": "id", "type": "long", "field-id": 1},
+            {"name": "key", "type": {"type": "string", "logicalType": "uuid"}},
+            {"name": "amount", "type": ["null", {"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2}]},
+            {"name": "time", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+        ]
+    }"#;
+
+    fn write_events(start: i64, count: usize) -> (usize, Vec<u8>) {
+        let mut out = vec![];
+        for id in start..start + count as i64 {
+            encode_long(&mut out, id);
+            encode_bytes(
+                &mut out,
+                format!("00000000-0000-0000-0000-{id:012}").as_bytes(),
+            );
+            match id % 2 {
+                0 => encode_long(&mut out, 0),
+                _ => {
+                    encode_long(&mut out, 1);
+                    encode_bytes(&mut out, &[id as u8]);
+                }
+            }
+            encode_long(&mut out, id * 1000);
+        }
+        (count, out)
+    }
+
+    #[test]
+    fn test_convert() {
+        let file = write_ocf_with_metadata(
+            SCHEMA,
+            &[("avro.codec", b"null"), ("created_by", b"test")],
+            &[write_events(0, 3), write_events(3, 2)],
+        );
+
+        let properties = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let options = ConvertOptions::new()
+            .with_batch_size(4)
+            .with_properties(properties);
+        let mut out = vec![];
+        let metadata = convert(file.as_slice(), &mut out, options).unwrap();
+        assert_eq!(metadata.num_rows, 5);
+        assert_eq!(metadata.row_groups.len(), 3);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(out)).unwrap();
+        let file_metadata = builder.metadata().file_metadata();
+        let kv: Vec<_> = file_metadata
+            .key_value_metadata()
+            .unwrap()
+            .iter()
+            .map(|kv| (kv.key.as_str(), kv.value.as_deref()))
+            .collect();
+        assert!(kv.contains(&("created_by", Some("test"))));
+        assert!(kv.contains(&(PARQUET_AVRO_SCHEMA_META_KEY, Some(SCHEMA))));
+        assert!(!kv.iter().any(|(k, _)| *k == "avro.codec"));
+
+        let columns = file_metadata.schema_descr().columns();
+        assert_eq!(columns[0].self_type().get_basic_info().id(), 1);
+        assert_eq!(columns[1].logical_type(), Some(LogicalType::Uuid));
+        assert_eq!(
+            columns[2].logical_type(),
+            Some(LogicalType::Decimal {
+                scale: 2,
+                precision: 9
+            })
+        );
+
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let batch = arrow_select::concat::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(batch.num_rows(), 5);
+
+        let ids = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(ids.values(), &[0, 1, 2, 3, 4]);
+
+        let keys = batch.column(1).as_fixed_size_binary();
+        let mut expected = [0; 16];
+        expected[15] = 0x03;
+        assert_eq!(keys.value(3), &expected);
+
+        let amounts = batch.column(2);
+        let expected = Decimal128Array::from(vec![None, Some(1), None, Some(3), None])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        assert_eq!(amounts.as_ref(), &expected as &dyn Array);
+
+        let times = batch.column(3).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(times.values(), &[0, 1000, 2000, 3000, 4000]);
+    }
+
+    #[test]
+    fn test_convert_reader_schema() {
+        let file = write_ocf_with_metadata(SCHEMA, &[], &[write_events(0, 2)]);
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [{"name": "id", "type": "long"}]
+        }"#;
+        let mut out = vec![];
+        let options = ConvertOptions::new().with_reader_schema(reader_schema);
+        convert(file.as_slice(), &mut out, options).unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(out)).unwrap();
+        assert_eq!(builder.schema().fields().len(), 1);
+        let kv = builder.metadata().file_metadata().key_value_metadata();
+        let avro_schema = kv
+            .unwrap()
+            .iter()
+            .find(|kv| kv.key == PARQUET_AVRO_SCHEMA_META_KEY)
+            .and_then(|kv| kv.value.as_deref());
+        assert_eq!(avro_schema, Some(reader_schema));
+
+        let err = convert(&b"Obj\x01"[..], vec![], ConvertOptions::new()).unwrap_err();
+        assert_eq!(err.to_string(), "External: Parser error: Unexpected EOF");
+    }
+}
@@ -29,7 +29,7 @@ use crate::errors::{ParquetError, Result};
 // Re-export crate::format types used in this module
 pub use crate::format::{
     BsonType, DateType, DecimalType, EnumType, IntType, JsonType, ListType, MapType, NullType,
-    StringType, TimeType, TimeUnit, TimestampType, UUIDType,
+    StringType, TimeType, TimeUnit, TimestampType, UUIDType, VariantType,
 };
 
 // ----------------------------------------------------------------------
@@ -228,6 +228,12 @@ pub enum LogicalType {
     Uuid,
     /// A 16-bit floating point number.
     Float16,
+    /// A semi-structured Variant value, annotating a group with binary `metadata`
+    /// and `value` fields, and optionally a shredded `typed_value` field.
+    Variant {
+        /// The version of the Variant specification, if known.
+        specification_version: Option<i8>,
+    },
 }
 
 // ----------------------------------------------------------------------
@@ -579,6 +585,7 @@ impl ColumnOrder {
                 LogicalType::Unknown => SortOrder::UNDEFINED,
                 LogicalType::Uuid => SortOrder::UNSIGNED,
                 LogicalType::Float16 => SortOrder::SIGNED,
+                LogicalType::Variant { .. } => SortOrder::UNDEFINED,
             },
             // Fall back to converted type
             None => Self::get_converted_sort_order(converted_type, physical_type),
@@ -841,6 +848,9 @@ impl From<parquet::LogicalType> for LogicalType {
             parquet::LogicalType::BSON(_) => LogicalType::Bson,
             parquet::LogicalType::UUID(_) => LogicalType::Uuid,
             parquet::LogicalType::FLOAT16(_) => LogicalType::Float16,
+            parquet::LogicalType::VARIANT(t) => LogicalType::Variant {
+                specification_version: t.specification_version,
+            },
         }
     }
 }
@@ -882,6 +892,11 @@ impl From<LogicalType> for parquet::LogicalType {
             LogicalType::Bson => parquet::LogicalType::BSON(Default::default()),
             LogicalType::Uuid => parquet::LogicalType::UUID(Default::default()),
             LogicalType::Float16 => parquet::LogicalType::FLOAT16(Default::default()),
+            LogicalType::Variant {
+                specification_version,
+            } => parquet::LogicalType::VARIANT(VariantType {
+                specification_version,
+            }),
         }
     }
 }
@@ -931,9 +946,10 @@ impl From<Option<LogicalType>> for ConvertedType {
                 },
                 LogicalType::Json => ConvertedType::JSON,
                 LogicalType::Bson => ConvertedType::BSON,
-                LogicalType::Uuid | LogicalType::Float16 | LogicalType::Unknown => {
-                    ConvertedType::NONE
-                }
+                LogicalType::Uuid
+                | LogicalType::Float16
+                | LogicalType::Variant { .. }
+                | LogicalType::Unknown => ConvertedType::NONE,
             },
             None => ConvertedType::NONE,
         }
@@ -1182,6 +1198,9 @@ impl str::FromStr for LogicalType {
                 "Interval parquet logical type not yet supported"
             )),
             "FLOAT16" => Ok(LogicalType::Float16),
+            "VARIANT" => Ok(LogicalType::Variant {
+                specification_version: None,
+            }),
             other => Err(general_err!("Invalid parquet logical type {}", other)),
         }
     }
@@ -1705,6 +1724,12 @@ mod tests {
             ConvertedType::from(Some(LogicalType::Json)),
             ConvertedType::JSON
         );
+        assert_eq!(
+            ConvertedType::from(Some(LogicalType::Variant {
+                specification_version: Some(1)
+            })),
+            ConvertedType::NONE
+        );
         assert_eq!(
             ConvertedType::from(Some(LogicalType::String)),
             ConvertedType::UTF8
@@ -2218,7 +2243,13 @@ mod tests {
         check_sort_order(signed, SortOrder::SIGNED);
 
         // Undefined comparison
-        let undefined = vec![LogicalType::List, LogicalType::Map];
+        let undefined = vec![
+            LogicalType::List,
+            LogicalType::Map,
+            LogicalType::Variant {
+                specification_version: None,
+            },
+        ];
         check_sort_order(undefined, SortOrder::UNDEFINED);
     }
 
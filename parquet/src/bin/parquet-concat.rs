@@ -33,11 +33,10 @@
 //! cargo run --features=cli --bin parquet-concat out.parquet a.parquet b.parquet
 //! ```
 //!
-//! Note: this does not currently support preserving the page index or bloom filters
+//! The page index and bloom filters of the input files are preserved
 //!
 
 use clap::Parser;
-use parquet::column::writer::ColumnCloseResult;
 use parquet::errors::{ParquetError, Result};
 use parquet::file::metadata::ParquetMetaDataReader;
 use parquet::file::properties::WriterProperties;
@@ -71,7 +70,9 @@ impl Args {
             .iter()
             .map(|x| {
                 let reader = File::open(x)?;
-                let metadata = ParquetMetaDataReader::new().parse_and_finish(&reader)?;
+                let metadata = ParquetMetaDataReader::new()
+                    .with_page_indexes(true)
+                    .parse_and_finish(&reader)?;
                 Ok((reader, metadata))
             })
             .collect::<Result<Vec<_>>>()?;
@@ -91,20 +92,8 @@ impl Args {
         let mut writer = SerializedFileWriter::new(output, schema, props)?;
 
         for (input, metadata) in inputs {
-            for rg in metadata.row_groups() {
-                let mut rg_out = writer.next_row_group()?;
-                for column in rg.columns() {
-                    let result = ColumnCloseResult {
-                        bytes_written: column.compressed_size() as _,
-                        rows_written: rg.num_rows() as _,
-                        metadata: column.clone(),
-                        bloom_filter: None,
-                        column_index: None,
-                        offset_index: None,
-                    };
-                    rg_out.append_column(&input, result)?;
-                }
-                rg_out.close()?;
+            for rg in 0..metadata.num_row_groups() {
+                writer.append_row_group(&input, &metadata, rg)?;
             }
         }
 
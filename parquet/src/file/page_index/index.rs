@@ -152,6 +152,21 @@ impl Index {
             Index::FIXED_LEN_BYTE_ARRAY(index) => Some(index.boundary_order),
         }
     }
+
+    /// Converts this index to its thrift representation, returns `None` for [`Index::NONE`]
+    pub(crate) fn to_thrift(&self) -> Option<ColumnIndex> {
+        match self {
+            Index::NONE => None,
+            Index::BOOLEAN(index) => Some(index.to_thrift()),
+            Index::INT32(index) => Some(index.to_thrift()),
+            Index::INT64(index) => Some(index.to_thrift()),
+            Index::INT96(index) => Some(index.to_thrift()),
+            Index::FLOAT(index) => Some(index.to_thrift()),
+            Index::DOUBLE(index) => Some(index.to_thrift()),
+            Index::BYTE_ARRAY(index) => Some(index.to_thrift()),
+            Index::FIXED_LEN_BYTE_ARRAY(index) => Some(index.to_thrift()),
+        }
+    }
 }
 
 /// Strongly typed statistics for data pages in a column chunk.
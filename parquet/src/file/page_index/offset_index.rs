@@ -51,8 +51,6 @@ impl OffsetIndexMetaData {
         self.unencoded_byte_array_data_bytes.as_ref()
     }
 
-    // TODO: remove annotation after merge
-    #[allow(dead_code)]
     pub(crate) fn to_thrift(&self) -> OffsetIndex {
         OffsetIndex::new(
             self.page_locations.clone(),
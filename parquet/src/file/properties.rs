@@ -45,6 +45,10 @@ pub const DEFAULT_STATISTICS_ENABLED: EnabledStatistics = EnabledStatistics::Pag
 pub const DEFAULT_MAX_STATISTICS_SIZE: usize = 4096;
 /// Default value for [`WriterProperties::max_row_group_size`]
 pub const DEFAULT_MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
+/// Default value for [`WriterProperties::target_row_group_bytes`]
+pub const DEFAULT_TARGET_ROW_GROUP_BYTES: Option<usize> = None;
+/// Default value for [`WriterProperties::max_parallelism`]
+pub const DEFAULT_MAX_PARALLELISM: usize = 1;
 /// Default value for [`WriterProperties::bloom_filter_position`]
 pub const DEFAULT_BLOOM_FILTER_POSITION: BloomFilterPosition = BloomFilterPosition::AfterRowGroup;
 /// Default value for [`WriterProperties::created_by`]
@@ -158,6 +162,8 @@ pub struct WriterProperties {
     data_page_row_count_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    target_row_group_bytes: Option<usize>,
+    max_parallelism: usize,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
     created_by: String,
@@ -232,6 +238,20 @@ impl WriterProperties {
         self.max_row_group_size
     }
 
+    /// Returns the target encoded size of a row group in bytes, if any.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_target_row_group_bytes`]
+    pub fn target_row_group_bytes(&self) -> Option<usize> {
+        self.target_row_group_bytes
+    }
+
+    /// Returns the maximum number of threads used to encode the columns of a row group.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_max_parallelism`]
+    pub fn max_parallelism(&self) -> usize {
+        self.max_parallelism
+    }
+
     /// Returns maximum number of rows in a row group.
     pub fn bloom_filter_position(&self) -> BloomFilterPosition {
         self.bloom_filter_position
@@ -381,6 +401,8 @@ pub struct WriterPropertiesBuilder {
     data_page_row_count_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    target_row_group_bytes: Option<usize>,
+    max_parallelism: usize,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
     created_by: String,
@@ -403,6 +425,8 @@ impl WriterPropertiesBuilder {
             data_page_row_count_limit: DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT,
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            target_row_group_bytes: DEFAULT_TARGET_ROW_GROUP_BYTES,
+            max_parallelism: DEFAULT_MAX_PARALLELISM,
             bloom_filter_position: DEFAULT_BLOOM_FILTER_POSITION,
             writer_version: DEFAULT_WRITER_VERSION,
             created_by: DEFAULT_CREATED_BY.to_string(),
@@ -425,6 +449,8 @@ impl WriterPropertiesBuilder {
             data_page_row_count_limit: self.data_page_row_count_limit,
             write_batch_size: self.write_batch_size,
             max_row_group_size: self.max_row_group_size,
+            target_row_group_bytes: self.target_row_group_bytes,
+            max_parallelism: self.max_parallelism,
             bloom_filter_position: self.bloom_filter_position,
             writer_version: self.writer_version,
             created_by: self.created_by,
@@ -519,6 +545,48 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets best effort target size of a row group in bytes (defaults to `None`).
+    ///
+    /// When set, the `ArrowWriter` and `AsyncArrowWriter` track the estimated encoded
+    /// size of the in progress row group, and close it once it reaches this size,
+    /// splitting incoming batches based on the average encoded size of the buffered
+    /// rows. Row groups are still limited to
+    /// [`set_max_row_group_size`](Self::set_max_row_group_size) rows.
+    ///
+    /// This results in row groups of similar size in bytes regardless of the width
+    /// of the schema.
+    ///
+    /// Note: this is a best effort limit, as the size is estimated before the data
+    /// is compressed, and based on the value of
+    /// [`set_write_batch_size`](Self::set_write_batch_size).
+    ///
+    /// # Panics
+    /// If the value is set to `Some(0)`.
+    pub fn set_target_row_group_bytes(mut self, value: Option<usize>) -> Self {
+        assert_ne!(value, Some(0), "Cannot have a 0 target row group size");
+        self.target_row_group_bytes = value;
+        self
+    }
+
+    /// Sets the maximum number of threads used to encode the columns of a row group
+    /// (defaults to `1`).
+    ///
+    /// Encoding and compressing column chunks is CPU-bound, and the column chunks of a
+    /// row group are independent. With a value greater than `1` the columns are split
+    /// into at most this many groups, each encoded on a pool of this many threads created
+    /// with the writer, with the resulting column chunks still written to the file in
+    /// schema order.
+    ///
+    /// This applies to both the `ArrowWriter` and the `AsyncArrowWriter`.
+    ///
+    /// # Panics
+    /// If the value is set to 0.
+    pub fn set_max_parallelism(mut self, value: usize) -> Self {
+        assert!(value > 0, "Cannot have a 0 max parallelism");
+        self.max_parallelism = value;
+        self
+    }
+
     /// Sets where in the final file Bloom Filters are written (default `AfterRowGroup`)
     pub fn set_bloom_filter_position(mut self, value: BloomFilterPosition) -> Self {
         self.bloom_filter_position = value;
@@ -1137,6 +1205,11 @@ mod tests {
         );
         assert_eq!(props.write_batch_size(), DEFAULT_WRITE_BATCH_SIZE);
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
+        assert_eq!(
+            props.target_row_group_bytes(),
+            DEFAULT_TARGET_ROW_GROUP_BYTES
+        );
+        assert_eq!(props.max_parallelism(), DEFAULT_MAX_PARALLELISM);
         assert_eq!(props.bloom_filter_position(), DEFAULT_BLOOM_FILTER_POSITION);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
@@ -1220,6 +1293,8 @@ mod tests {
             .set_dictionary_page_size_limit(20)
             .set_write_batch_size(30)
             .set_max_row_group_size(40)
+            .set_target_row_group_bytes(Some(1024))
+            .set_max_parallelism(4)
             .set_created_by("default".to_owned())
             .set_key_value_metadata(Some(vec![KeyValue::new(
                 "key".to_string(),
@@ -1245,6 +1320,8 @@ mod tests {
         assert_eq!(props.dictionary_page_size_limit(), 20);
         assert_eq!(props.write_batch_size(), 30);
         assert_eq!(props.max_row_group_size(), 40);
+        assert_eq!(props.target_row_group_bytes(), Some(1024));
+        assert_eq!(props.max_parallelism(), 4);
         assert_eq!(props.created_by(), "default");
         assert_eq!(
             props.key_value_metadata(),
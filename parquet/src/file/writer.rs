@@ -244,6 +244,78 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         Ok(row_group_writer)
     }
 
+    /// Appends an already encoded row group from another parquet file, without decoding it
+    ///
+    /// `metadata` is the [`ParquetMetaData`] of the file read by `reader`, and `row_group`
+    /// is the index of the row group to copy. The column chunks are copied verbatim and
+    /// only their offsets are rewritten, making this an efficient way to concatenate or
+    /// compact parquet files with the same schema.
+    ///
+    /// The page index of the row group is preserved if it was loaded into `metadata`, see
+    /// [`ParquetMetaDataReader::with_page_indexes`], and any bloom filters are read from
+    /// `reader` and preserved.
+    ///
+    /// Returns an error if `row_group` is out of bounds, or if the schema of the row group
+    /// does not match the schema of this writer. Both are checked, and the bloom filters
+    /// read, before anything is written.
+    pub fn append_row_group<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &ParquetMetaData,
+        row_group: usize,
+    ) -> Result<RowGroupMetaDataPtr> {
+        if row_group >= metadata.num_row_groups() {
+            return Err(general_err!(
+                "row group {} out of bounds, file has {} row groups",
+                row_group,
+                metadata.num_row_groups()
+            ));
+        }
+        let rg = metadata.row_group(row_group);
+        if rg.num_columns() != self.descr.num_columns() {
+            return Err(general_err!(
+                "row group has {} columns, expected {}",
+                rg.num_columns(),
+                self.descr.num_columns()
+            ));
+        }
+        for (idx, column) in rg.columns().iter().enumerate() {
+            let desc = self.descr.column(idx);
+            if column.column_descr() != desc.as_ref() {
+                return Err(general_err!(
+                    "column descriptor mismatch, expected {:?} got {:?}",
+                    desc,
+                    column.column_descr()
+                ));
+            }
+        }
+
+        let column_indexes = metadata.column_index().map(|x| &x[row_group]);
+        let offset_indexes = metadata.offset_index().map(|x| &x[row_group]);
+
+        let results = rg
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                Ok(ColumnCloseResult {
+                    bytes_written: column.compressed_size() as _,
+                    rows_written: rg.num_rows() as _,
+                    metadata: column.clone(),
+                    bloom_filter: Sbbf::read_from_column_chunk(column, reader)?,
+                    column_index: column_indexes.and_then(|x| x[idx].to_thrift()),
+                    offset_index: offset_indexes.map(|x| x[idx].to_thrift()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rg_out = self.next_row_group()?;
+        for result in results {
+            rg_out.append_column(reader, result)?;
+        }
+        rg_out.close()
+    }
+
     /// Returns metadata for any flushed row groups
     pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
         &self.row_groups
@@ -1730,6 +1802,129 @@ mod tests {
         test_read(reader);
     }
 
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_append_row_group() {
+        let schema = Arc::new(arrow_schema::Schema::new(vec![arrow_schema::Field::new(
+            "a",
+            arrow_schema::DataType::Int32,
+            true,
+        )]));
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .set_bloom_filter_enabled(true)
+            .build();
+
+        let write = |values: Vec<Option<i32>>| {
+            let array = Arc::new(arrow_array::Int32Array::from(values));
+            let batch = arrow_array::RecordBatch::try_new(schema.clone(), vec![array]).unwrap();
+            let mut buf = Vec::new();
+            let mut writer =
+                ArrowWriter::try_new(&mut buf, schema.clone(), Some(props.clone())).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            Bytes::from(buf)
+        };
+        let inputs = [
+            write(vec![Some(1), None, Some(3), Some(4)]),
+            write(vec![Some(5), Some(6)]),
+        ];
+
+        let mut out = Vec::new();
+        let parquet_schema = crate::arrow::ArrowSchemaConverter::new()
+            .convert(&schema)
+            .unwrap();
+        let mut writer = SerializedFileWriter::new(
+            &mut out,
+            parquet_schema.root_schema_ptr(),
+            Default::default(),
+        )
+        .unwrap();
+        for input in &inputs {
+            let metadata = ParquetMetaDataReader::new()
+                .with_page_indexes(true)
+                .parse_and_finish(input)
+                .unwrap();
+            for rg in 0..metadata.num_row_groups() {
+                let rg_out = writer.append_row_group(input, &metadata, rg).unwrap();
+                assert_eq!(rg_out.num_rows(), metadata.row_group(rg).num_rows());
+            }
+        }
+        writer.close().unwrap();
+
+        let out = Bytes::from(out);
+        let options = ReadOptionsBuilder::new()
+            .with_page_index()
+            .with_reader_properties(
+                ReaderProperties::builder()
+                    .set_read_bloom_filter(true)
+                    .build(),
+            )
+            .build();
+        let reader = SerializedFileReader::new_with_options(out.clone(), options).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 3);
+        assert_eq!(metadata.column_index().unwrap().len(), 3);
+        match &metadata.column_index().unwrap()[2][0] {
+            Index::INT32(index) => {
+                assert_eq!(index.indexes[0].min, Some(5));
+                assert_eq!(index.indexes[0].max, Some(6));
+            }
+            _ => panic!("wrong stats type"),
+        }
+        let bloom_filter = reader
+            .get_row_group(1)
+            .unwrap()
+            .get_column_bloom_filter(0)
+            .unwrap()
+            .clone();
+        assert!(bloom_filter.check(&4_i32));
+        assert!(!bloom_filter.check(&5_i32));
+
+        let batches = ParquetRecordBatchReaderBuilder::try_new(out)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let batch = arrow_select::concat::concat_batches(&schema, &batches).unwrap();
+        let expected =
+            arrow_array::Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5), Some(6)]);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &expected as &dyn arrow_array::Array
+        );
+
+        // schema mismatch
+        let message_type = "message test_schema { REQUIRED INT64 a; }";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut writer = SerializedFileWriter::new(vec![], schema, Default::default()).unwrap();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&inputs[0])
+            .unwrap();
+        let err = writer
+            .append_row_group(&inputs[0], &metadata, 0)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("column descriptor mismatch"), "{err}");
+
+        // out of bounds row group
+        let err = writer
+            .append_row_group(&inputs[0], &metadata, 2)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Parquet error: row group 2 out of bounds, file has 2 row groups"
+        );
+
+        // nothing was written by the failed appends
+        assert!(writer.flushed_row_groups().is_empty());
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_rows, 0);
+        assert!(metadata.row_groups.is_empty());
+    }
+
     #[test]
     fn test_disabled_statistics() {
         let message_type = "
@@ -133,6 +133,8 @@ pub use self::encodings::{decoding, encoding};
 experimental!(#[macro_use] mod util);
 #[cfg(feature = "arrow")]
 pub mod arrow;
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod column;
 experimental!(mod compression);
 experimental!(mod encodings);
@@ -28,5 +28,5 @@ pub use self::{
         Field, List, ListAccessor, Map, MapAccessor, Row, RowAccessor, RowColumnIter, RowFormatter,
     },
     record_reader::RecordReader,
-    record_writer::RecordWriter,
+    record_writer::{RecordFileWriter, RecordWriter},
 };
@@ -15,6 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::file::metadata::KeyValue;
+use crate::file::properties::WriterProperties;
+use crate::file::writer::SerializedFileWriter;
+use crate::format::FileMetaData;
 use crate::schema::types::TypePtr;
 
 use super::super::errors::ParquetError;
@@ -40,3 +47,114 @@ pub trait RecordWriter<T> {
     /// Generated schema used by `row_group_writer`
     fn schema(&self) -> Result<TypePtr, ParquetError>;
 }
+
+/// Writes records of type `T` to a parquet file, shredding them column by column
+/// using the [`RecordWriter`] implemented for `&[T]`, e.g. by [`ParquetRecordWriter`]
+///
+/// Unlike [`RecordWriter::write_to_row_group`], which writes a slice of records to a
+/// single row group, records are buffered and written in row groups of
+/// [`WriterProperties::max_row_group_size`] records, without any intermediate arrow
+/// [`RecordBatch`].
+///
+/// ```ignore
+/// #[derive(ParquetRecordWriter)]
+/// struct Event {
+///     id: i64,
+///     name: String,
+/// }
+///
+/// let mut writer = RecordFileWriter::try_new(file, None).unwrap();
+/// writer.write(Event { id: 1, name: "start".to_string() }).unwrap();
+/// writer.close().unwrap();
+/// ```
+///
+/// [`ParquetRecordWriter`]: https://docs.rs/parquet_derive/latest/parquet_derive/derive.ParquetRecordWriter.html
+/// [`RecordBatch`]: https://docs.rs/arrow/latest/arrow/record_batch/struct.RecordBatch.html
+pub struct RecordFileWriter<W: Write + Send, T> {
+    writer: SerializedFileWriter<W>,
+    buffer: Vec<T>,
+    max_row_group_size: usize,
+}
+
+impl<W: Write + Send, T> std::fmt::Debug for RecordFileWriter<W, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordFileWriter")
+            .field("buffered_records", &self.buffer.len())
+            .field("max_row_group_size", &self.max_row_group_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write + Send, T> RecordFileWriter<W, T>
+where
+    for<'a> &'a [T]: RecordWriter<T>,
+{
+    /// Create a new [`RecordFileWriter`] writing to `writer`, with the schema
+    /// generated by the [`RecordWriter`] implementation of `&[T]`
+    pub fn try_new(writer: W, props: Option<WriterProperties>) -> Result<Self, ParquetError> {
+        let empty: &[T] = &[];
+        let schema = empty.schema()?;
+        let props = props.unwrap_or_default();
+        let max_row_group_size = props.max_row_group_size();
+        Ok(Self {
+            writer: SerializedFileWriter::new(writer, schema, Arc::new(props))?,
+            buffer: vec![],
+            max_row_group_size,
+        })
+    }
+
+    /// Buffers `record`, writing a row group if [`WriterProperties::max_row_group_size`]
+    /// records are buffered
+    pub fn write(&mut self, record: T) -> Result<(), ParquetError> {
+        self.buffer.push(record);
+        if self.buffer.len() >= self.max_row_group_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Buffers all `records`, see [`Self::write`]
+    pub fn write_all<I: IntoIterator<Item = T>>(&mut self, records: I) -> Result<(), ParquetError> {
+        records
+            .into_iter()
+            .try_for_each(|record| self.write(record))
+    }
+
+    /// Writes any buffered records as a new row group
+    pub fn flush(&mut self) -> Result<(), ParquetError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let mut row_group = self.writer.next_row_group()?;
+        self.buffer.as_slice().write_to_row_group(&mut row_group)?;
+        row_group.close()?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Returns the number of buffered records not yet written to a row group
+    pub fn in_progress_rows(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of bytes written to the underlying writer so far
+    pub fn bytes_written(&self) -> usize {
+        self.writer.bytes_written()
+    }
+
+    /// Appends [`KeyValue`] metadata to be written in the file footer
+    pub fn append_key_value_metadata(&mut self, kv_metadata: KeyValue) {
+        self.writer.append_key_value_metadata(kv_metadata)
+    }
+
+    /// Returns a reference to the underlying writer
+    pub fn inner(&self) -> &W {
+        self.writer.inner()
+    }
+
+    /// Writes any buffered records and the file footer, returning the file metadata
+    pub fn close(mut self) -> Result<FileMetaData, ParquetError> {
+        self.flush()?;
+        self.writer.close()
+    }
+}
@@ -246,7 +246,7 @@ impl Parser<'_> {
 
         // Parse logical or converted type if exists
         let (logical_type, converted_type) = if let Some("(") = self.tokenizer.next() {
-            let tpe = self
+            let mut tpe = self
                 .tokenizer
                 .next()
                 .ok_or_else(|| general_err!("Expected converted type, found None"))
@@ -261,6 +261,25 @@ impl Parser<'_> {
                         Err(_) => Ok((None, upper.parse::<ConvertedType>()?)),
                     }
                 })?;
+            if let Some(LogicalType::Variant { .. }) = tpe.0 {
+                // Parse optional specification version
+                if let Some("(") = self.tokenizer.next() {
+                    let version = parse_i32(
+                        self.tokenizer.next(),
+                        "Expected variant specification version, found None",
+                        "Failed to parse variant specification version for VARIANT type",
+                    )?;
+                    let version = i8::try_from(version).map_err(|_| {
+                        general_err!("Invalid variant specification version {}", version)
+                    })?;
+                    assert_token(self.tokenizer.next(), ")")?;
+                    tpe.0 = Some(LogicalType::Variant {
+                        specification_version: Some(version),
+                    });
+                } else {
+                    self.tokenizer.backtrack();
+                }
+            }
             assert_token(self.tokenizer.next(), ")")?;
             tpe
         } else {
@@ -655,6 +674,38 @@ mod tests {
         parse(schema).unwrap();
     }
 
+    #[test]
+    fn test_parse_message_type_variant() {
+        let schema = "
+            message root {
+              optional group v (VARIANT(300)) {
+                required binary metadata;
+                required binary value;
+              }
+            }
+        ";
+        assert_eq!(
+            parse(schema).unwrap_err().to_string(),
+            "Parquet error: Invalid variant specification version 300"
+        );
+
+        let schema = "
+            message root {
+              optional group v (VARIANT(1)) {
+                required binary metadata;
+                required binary value;
+              }
+            }
+        ";
+        let message = parse(schema).unwrap();
+        assert_eq!(
+            message.get_fields()[0].get_basic_info().logical_type(),
+            Some(LogicalType::Variant {
+                specification_version: Some(1)
+            })
+        );
+    }
+
     #[test]
     fn test_parse_message_type_integer() {
         // Invalid integer syntax
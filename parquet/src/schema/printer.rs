@@ -275,6 +275,12 @@ fn print_logical_and_converted(
             LogicalType::List => "LIST".to_string(),
             LogicalType::Map => "MAP".to_string(),
             LogicalType::Float16 => "FLOAT16".to_string(),
+            LogicalType::Variant {
+                specification_version: None,
+            } => "VARIANT".to_string(),
+            LogicalType::Variant {
+                specification_version: Some(version),
+            } => format!("VARIANT({version})"),
             LogicalType::Unknown => "UNKNOWN".to_string(),
         },
         None => {
@@ -839,6 +845,57 @@ mod tests {
         assert_print_parse_message(message);
     }
 
+    #[test]
+    fn test_print_and_parse_variant() {
+        let metadata = Type::primitive_type_builder("metadata", PhysicalType::BYTE_ARRAY)
+            .with_repetition(Repetition::REQUIRED)
+            .build()
+            .unwrap();
+        let value = Type::primitive_type_builder("value", PhysicalType::BYTE_ARRAY)
+            .with_repetition(Repetition::REQUIRED)
+            .build()
+            .unwrap();
+        let fields = vec![Arc::new(metadata), Arc::new(value)];
+
+        let v1 = Type::group_type_builder("v1")
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(Some(LogicalType::Variant {
+                specification_version: None,
+            }))
+            .with_fields(fields.clone())
+            .build()
+            .unwrap();
+        let v2 = Type::group_type_builder("v2")
+            .with_repetition(Repetition::REQUIRED)
+            .with_logical_type(Some(LogicalType::Variant {
+                specification_version: Some(1),
+            }))
+            .with_fields(fields)
+            .build()
+            .unwrap();
+
+        let message = Type::group_type_builder("schema")
+            .with_fields(vec![Arc::new(v1), Arc::new(v2)])
+            .build()
+            .unwrap();
+
+        let mut s = String::new();
+        Printer::new(&mut s).print(&message);
+        let expected = "message schema {
+  OPTIONAL group v1 (VARIANT) {
+    REQUIRED BYTE_ARRAY metadata;
+    REQUIRED BYTE_ARRAY value;
+  }
+  REQUIRED group v2 (VARIANT(1)) {
+    REQUIRED BYTE_ARRAY metadata;
+    REQUIRED BYTE_ARRAY value;
+  }
+}";
+        assert_eq!(s, expected);
+
+        assert_print_parse_message(message);
+    }
+
     #[test]
     fn test_print_and_parse_decimal() {
         let f1 = Type::primitive_type_builder("f1", PhysicalType::INT32)
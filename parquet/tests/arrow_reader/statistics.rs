@@ -45,7 +45,7 @@ use parquet::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
 };
 use parquet::arrow::ArrowWriter;
-use parquet::file::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
 use parquet::file::properties::{EnabledStatistics, WriterProperties};
 use parquet::file::statistics::{Statistics, ValueStatistics};
 use parquet::schema::types::{SchemaDescPtr, SchemaDescriptor};
@@ -495,6 +495,85 @@ async fn test_multiple_data_pages_nulls_and_negatives() {
     .run()
 }
 
+#[tokio::test]
+async fn test_data_page_statistics() {
+    let reader = Int64Case {
+        null_values: 3,
+        no_null_values_start: -1,
+        no_null_values_end: 10,
+        row_per_group: 8,
+        data_page_row_count_limit: Some(4),
+        enable_stats: Some(EnabledStatistics::Page),
+    }
+    .build();
+
+    // Data layout looks like this:
+    //
+    // row group 0, page 0: [-1, 0, 1, 2]
+    // row group 0, page 1: [3, 4, 5, 6]
+    // row group 1, page 0: [7, 8, 9, null]
+    // row group 1, page 1: [null, null]
+    let converter =
+        StatisticsConverter::try_new("i64", reader.schema(), reader.parquet_schema()).unwrap();
+    let pages = converter
+        .data_page_statistics(reader.metadata(), &[0, 1])
+        .unwrap()
+        .unwrap();
+    assert_eq!(pages.num_rows(), 4);
+
+    let column = |name| pages.column_by_name(name).unwrap().as_ref();
+    assert_eq!(
+        column("row_group"),
+        &UInt64Array::from(vec![0, 0, 1, 1]) as &dyn Array
+    );
+    assert_eq!(
+        column("first_row_index"),
+        &Int64Array::from(vec![0, 4, 0, 4]) as &dyn Array
+    );
+    assert_eq!(
+        column("row_count"),
+        &UInt64Array::from(vec![4, 4, 4, 2]) as &dyn Array
+    );
+    assert_eq!(
+        column("null_count"),
+        &UInt64Array::from(vec![0, 0, 1, 2]) as &dyn Array
+    );
+    assert_eq!(
+        column("min"),
+        &Int64Array::from(vec![Some(-1), Some(3), Some(7), None]) as &dyn Array
+    );
+    assert_eq!(
+        column("max"),
+        &Int64Array::from(vec![Some(2), Some(6), Some(9), None]) as &dyn Array
+    );
+
+    // page locations should match the offset index
+    let offset_index = &reader.metadata().offset_index().unwrap()[1][0];
+    let offsets: Int64Array = offset_index
+        .page_locations()
+        .iter()
+        .map(|l| Some(l.offset))
+        .collect();
+    assert_eq!(
+        column("offset").slice(2, 2).as_ref(),
+        &offsets as &dyn Array
+    );
+
+    // only the requested row groups are returned
+    let pages = converter
+        .data_page_statistics(reader.metadata(), &[1])
+        .unwrap()
+        .unwrap();
+    assert_eq!(pages.num_rows(), 2);
+
+    // no page index loaded
+    let metadata = ParquetMetaData::new(reader.metadata().file_metadata().clone(), vec![]);
+    assert!(converter
+        .data_page_statistics(&metadata, &[])
+        .unwrap()
+        .is_none());
+}
+
 #[tokio::test]
 async fn test_data_page_stats_with_all_null_page() {
     for data_type in &[
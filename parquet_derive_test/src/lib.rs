@@ -109,8 +109,8 @@ mod tests {
     use std::{env, fs, io::Write, sync::Arc};
 
     use parquet::{
-        file::writer::SerializedFileWriter,
-        record::{RecordReader, RecordWriter},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        record::{RecordFileWriter, RecordReader, RecordWriter},
         schema::parser::parse_message_type,
     };
 
@@ -342,6 +342,58 @@ mod tests {
         assert_eq!(drs[0].isize, out[0].isize);
     }
 
+    #[test]
+    fn test_parquet_derive_record_file_writer() {
+        let file = get_temp_file("test_parquet_derive_record_file_writer", &[]);
+        let records = (0..10).map(|i| APartiallyCompleteRecord {
+            bool: i % 2 == 0,
+            string: format!("record {i}"),
+            i16: i as i16,
+            i32: i,
+            u64: i as u64,
+            isize: -(i as isize),
+            float: i as f32,
+            double: i as f64,
+            now: chrono::Utc::now().naive_local(),
+            date: chrono::naive::NaiveDate::from_ymd_opt(2015, 3, 14).unwrap(),
+            uuid: uuid::Uuid::new_v4(),
+            byte_vec: vec![i as u8],
+        });
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(4)
+            .build();
+        let mut writer = RecordFileWriter::try_new(file.try_clone().unwrap(), Some(props)).unwrap();
+        writer.write_all(records).unwrap();
+        assert_eq!(writer.in_progress_rows(), 2);
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_rows, 10);
+
+        use parquet::file::{reader::FileReader, serialized_reader::SerializedFileReader};
+        let reader = SerializedFileReader::new(file).unwrap();
+        let row_group_sizes: Vec<_> = reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect();
+        assert_eq!(row_group_sizes, vec![4, 4, 2]);
+
+        let mut out: Vec<APrunedRecord> = Vec::new();
+        for (idx, num_rows) in row_group_sizes.into_iter().enumerate() {
+            let mut row_group = reader.get_row_group(idx).unwrap();
+            let mut records: Vec<APrunedRecord> = Vec::new();
+            records
+                .read_from_row_group(&mut *row_group, num_rows as usize)
+                .unwrap();
+            out.extend(records);
+        }
+        let values: Vec<_> = out.iter().map(|r| (r.i32, r.string.as_str())).collect();
+        let expected: Vec<_> = (0..10).map(|i| format!("record {i}")).collect();
+        let expected: Vec<_> = (0..10).zip(expected.iter().map(|s| s.as_str())).collect();
+        assert_eq!(values, expected);
+    }
+
     /// Returns file handle for a temp file in 'target' directory with a provided content
     pub fn get_temp_file(file_name: &str, content: &[u8]) -> fs::File {
         // build tmp path to a file in "target/debug/testdata"